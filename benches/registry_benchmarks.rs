@@ -0,0 +1,129 @@
+//! Benchmarks for reduction registry queries.
+//!
+//! Compares the name-indexed registry lookups against the linear scan over
+//! every `inventory`-submitted `ReductionEntry` they replaced.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use problemreductions::rules::registry::{reduction_entries, reductions_from, ReductionEntry};
+use problemreductions::rules::{MinimizeSteps, ReductionGraph, ReductionPath};
+use problemreductions::types::ProblemSize;
+use std::collections::BTreeMap;
+use std::hint::black_box;
+
+type Variant = BTreeMap<String, String>;
+
+/// Linear-scan lookup of the entry for an exact variant-level edge.
+fn find_entry_linear(
+    source: &str,
+    source_variant: &Variant,
+    target: &str,
+    target_variant: &Variant,
+) -> Option<&'static ReductionEntry> {
+    reduction_entries().into_iter().find(|entry| {
+        entry.source_name == source
+            && entry.target_name == target
+            && ReductionGraph::variant_to_map(&entry.source_variant()) == *source_variant
+            && ReductionGraph::variant_to_map(&entry.target_variant()) == *target_variant
+    })
+}
+
+/// Indexed lookup of the entry for an exact variant-level edge.
+fn find_entry_indexed(
+    source: &str,
+    source_variant: &Variant,
+    target: &str,
+    target_variant: &Variant,
+) -> Option<&'static ReductionEntry> {
+    reductions_from(source).iter().copied().find(|entry| {
+        entry.target_name == target
+            && ReductionGraph::variant_to_map(&entry.source_variant()) == *source_variant
+            && ReductionGraph::variant_to_map(&entry.target_variant()) == *target_variant
+    })
+}
+
+/// Cheapest paths from every problem's default variant to QUBO.
+fn paths_to_qubo(graph: &ReductionGraph) -> Vec<ReductionPath> {
+    let target_variant = graph.default_variant_for("QUBO").unwrap();
+    let mut names = graph.problem_types();
+    names.sort_unstable();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let variant = graph.default_variant_for(name)?;
+            graph.find_cheapest_path(
+                name,
+                &variant,
+                "QUBO",
+                &target_variant,
+                &ProblemSize::new(vec![]),
+                &MinimizeSteps,
+            )
+        })
+        .filter(|path| !path.is_empty())
+        .collect()
+}
+
+/// Benchmark resolving the registry entry behind every step of the paths a
+/// path search returns.
+fn bench_path_entry_lookup(c: &mut Criterion) {
+    let graph = ReductionGraph::new();
+    let paths = paths_to_qubo(&graph);
+    let lookup_all =
+        |find: fn(&str, &Variant, &str, &Variant) -> Option<&'static ReductionEntry>| {
+            let mut found = 0usize;
+            for path in &paths {
+                for pair in path.steps.windows(2) {
+                    let entry = find(
+                        &pair[0].name,
+                        &pair[0].variant,
+                        &pair[1].name,
+                        &pair[1].variant,
+                    );
+                    found += usize::from(entry.is_some());
+                }
+            }
+            found
+        };
+    assert_eq!(
+        lookup_all(find_entry_linear),
+        lookup_all(find_entry_indexed)
+    );
+
+    let mut group = c.benchmark_group("path_entry_lookup");
+    group.bench_function("linear_scan", |b| {
+        b.iter(|| black_box(lookup_all(find_entry_linear)))
+    });
+    group.bench_function("indexed", |b| {
+        b.iter(|| black_box(lookup_all(find_entry_indexed)))
+    });
+    group.finish();
+}
+
+/// Benchmark collecting every problem's outgoing reductions.
+fn bench_reductions_from(c: &mut Criterion) {
+    let graph = ReductionGraph::new();
+    let names = graph.problem_types();
+
+    let mut group = c.benchmark_group("reductions_from");
+    group.bench_function("linear_scan", |b| {
+        b.iter(|| {
+            let entries = reduction_entries();
+            names
+                .iter()
+                .map(|name| entries.iter().filter(|e| e.source_name == *name).count())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("indexed", |b| {
+        b.iter(|| {
+            names
+                .iter()
+                .map(|name| reductions_from(black_box(name)).len())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_path_entry_lookup, bench_reductions_from);
+criterion_main!(benches);
@@ -1,13 +1,14 @@
 //! Benchmarks for the BruteForce solver on various problem types.
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use problemreductions::config::{config_to_index, fold_configs, index_to_config, DimsIterator};
 use problemreductions::models::formula::*;
 use problemreductions::models::graph::*;
 use problemreductions::models::misc::*;
 use problemreductions::models::set::*;
 use problemreductions::prelude::*;
-use problemreductions::topology::SimpleGraph;
-use problemreductions::variant::K3;
+use problemreductions::topology::{Graph, SimpleGraph};
+use problemreductions::variant::{K3, K4};
 use std::hint::black_box;
 
 /// Benchmark MaximumIndependentSet on graphs of varying sizes.
@@ -150,6 +151,53 @@ fn bench_coloring(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark exhaustive enumeration of a 4-coloring search space (4^10 configs).
+fn bench_coloring_enumeration(c: &mut Criterion) {
+    let mut group = c.benchmark_group("KColoring_enumeration");
+    group.sample_size(10);
+
+    let edges: Vec<(usize, usize)> = (0..9).map(|i| (i, i + 1)).collect();
+    let problem = KColoring::<K4, _>::new(SimpleGraph::new(10, edges));
+    let solver = BruteForce::new();
+
+    group.bench_function("find_all_witnesses_k4_n10", |b| {
+        b.iter(|| solver.find_all_witnesses(black_box(&problem)))
+    });
+    group.bench_function("dims_iterator_k4_n10", |b| {
+        b.iter(|| DimsIterator::new(black_box(problem.dims())).count())
+    });
+    group.bench_function("fold_configs_k4_n10", |b| {
+        b.iter(|| fold_configs(&black_box(problem.dims()), 0usize, |n, _| n + 1))
+    });
+
+    group.finish();
+}
+
+/// Benchmark index <-> configuration conversions.
+fn bench_config_index(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ConfigIndex");
+
+    let num_configs = 4usize.pow(10);
+    group.bench_function("index_to_config_k4_n10", |b| {
+        b.iter(|| {
+            (0..num_configs)
+                .map(|index| index_to_config(black_box(index), 10, 4)[0])
+                .sum::<usize>()
+        })
+    });
+    let configs: Vec<Vec<usize>> = DimsIterator::new(vec![4; 10]).collect();
+    group.bench_function("config_to_index_k4_n10", |b| {
+        b.iter(|| {
+            configs
+                .iter()
+                .map(|config| config_to_index(black_box(config), 4))
+                .sum::<usize>()
+        })
+    });
+
+    group.finish();
+}
+
 /// Benchmark Matching on varying graph sizes.
 fn bench_matching(c: &mut Criterion) {
     let mut group = c.benchmark_group("Matching");
@@ -240,6 +288,33 @@ fn bench_comparison(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark repeated neighbor queries on a SimpleGraph.
+fn bench_graph_neighbors(c: &mut Criterion) {
+    let mut group = c.benchmark_group("SimpleGraphNeighbors");
+
+    for side in [8, 16, 32].iter() {
+        let graph = SimpleGraph::grid(*side, *side);
+        let n = graph.num_vertices();
+
+        group.bench_with_input(BenchmarkId::new("neighbors", side), side, |b, _| {
+            b.iter(|| {
+                (0..n)
+                    .map(|v| black_box(&graph).neighbors(v).len())
+                    .sum::<usize>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("neighbor_slice", side), side, |b, _| {
+            b.iter(|| {
+                (0..n)
+                    .map(|v| black_box(&graph).neighbor_slice(v).len())
+                    .sum::<usize>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_independent_set,
@@ -249,9 +324,12 @@ criterion_group!(
     bench_spin_glass,
     bench_set_covering,
     bench_coloring,
+    bench_coloring_enumeration,
+    bench_config_index,
     bench_matching,
     bench_paintshop,
     bench_comparison,
+    bench_graph_neighbors,
 );
 
 criterion_main!(benches);
@@ -174,6 +174,7 @@
   "MaxCut": [Max-Cut],
   "GeneralizedHex": [Generalized Hex],
   "GraphPartitioning": [Graph Partitioning],
+  "MaxBisection": [Max Bisection],
   "HamiltonianCircuit": [Hamiltonian Circuit],
   "BiconnectivityAugmentation": [Biconnectivity Augmentation],
   "HamiltonianPath": [Hamiltonian Path],
@@ -195,11 +196,14 @@
   "MaximumAchromaticNumber": [Maximum Achromatic Number],
   "KColoring": [$k$-Coloring],
   "KClique": [$k$-Clique],
+  "MinimumCliqueCover": [Minimum Clique Cover],
   "MinimumCoveringByCliques": [Minimum Covering by Cliques],
   "MinimumIntersectionGraphBasis": [Minimum Intersection Graph Basis],
   "MinimumDominatingSet": [Minimum Dominating Set],
+  "MinimumEdgeDominatingSet": [Minimum Edge Dominating Set],
   "MinimumGeometricConnectedDominatingSet": [Minimum Geometric Connected Dominating Set],
   "MaximumMatching": [Maximum Matching],
+  "MaximumPlanarSubgraph": [Maximum Planar Subgraph],
   "MinimumMaximalMatching": [Minimum Maximal Matching],
   "BottleneckTravelingSalesman": [Bottleneck Traveling Salesman],
   "TravelingSalesman": [Traveling Salesman],
@@ -212,15 +216,20 @@
   "SetSplitting": [Set Splitting],
   "MinimumCardinalityKey": [Minimum Cardinality Key],
   "SpinGlass": [Spin Glass],
+  "PolySpinGlass": [Polynomial Spin Glass],
   "QUBO": [QUBO],
   "ILP": [Integer Linear Programming],
+  "PseudoBoolean": [Pseudo-Boolean Optimization],
   "IntegerKnapsack": [Integer Knapsack],
   "Knapsack": [Knapsack],
   "PartiallyOrderedKnapsack": [Partially Ordered Knapsack],
   "Satisfiability": [SAT],
   "NAESatisfiability": [NAE-SAT],
+  "XorSatisfiability": [CNF-XOR SAT],
+  "CSP": [Constraint Satisfaction],
   "KSatisfiability": [$k$-SAT],
   "Maximum2Satisfiability": [Maximum 2-Satisfiability],
+  "ModelCounting": [Model Counting],
   "NonTautology": [Non-Tautology],
   "OneInThreeSatisfiability": [1-in-3 SAT],
   "Planar3Satisfiability": [Planar 3-SAT],
@@ -231,14 +240,17 @@
   "KingsSubgraph": [King's Subgraph MIS],
   "TriangularSubgraph": [Triangular Subgraph MIS],
   "MaximalIS": [Maximal Independent Set],
+  "MinimumMaximalIS": [Minimum Maximal Independent Set],
   "BMF": [Boolean Matrix Factorization],
   "PaintShop": [Paint Shop],
   "BicliqueCover": [Biclique Cover],
   "BalancedCompleteBipartiteSubgraph": [Balanced Complete Bipartite Subgraph],
+  "MaximumBalancedBiclique": [Maximum Balanced Biclique],
   "BoundedComponentSpanningForest": [Bounded Component Spanning Forest],
   "BinPacking": [Bin Packing],
   "BoyceCoddNormalFormViolation": [Boyce-Codd Normal Form Violation],
   "Clustering": [Clustering],
+  "KCenter": [K-Center],
   "CapacityAssignment": [Capacity Assignment],
   "ConsistencyOfDatabaseFrequencyTables": [Consistency of Database Frequency Tables],
   "ClosestVectorProblem": [Closest Vector Problem],
@@ -264,6 +276,7 @@
   "NumericalMatchingWithTargetSums": [Numerical Matching with Target Sums],
   "PartialFeedbackEdgeSet": [Partial Feedback Edge Set],
   "MinimumFeedbackArcSet": [Minimum Feedback Arc Set],
+  "MaximumAcyclicSubgraph": [Maximum Acyclic Subgraph],
   "MinimumFeedbackVertexSet": [Minimum Feedback Vertex Set],
   "ConjunctiveBooleanQuery": [Conjunctive Boolean Query],
   "ConsecutiveBlockMinimization": [Consecutive Block Minimization],
@@ -294,6 +307,8 @@
   "MultipleCopyFileAllocation": [Multiple Copy File Allocation],
   "ExpectedRetrievalCost": [Expected Retrieval Cost],
   "MultiprocessorScheduling": [Multiprocessor Scheduling],
+  "MinimumMultiprocessorScheduling": [Minimum Multiprocessor Scheduling],
+  "KTrackIntervalScheduling": [K-Track Interval Scheduling],
   "NonLivenessFreePetriNet": [Non-Liveness Free Petri Net],
   "ProductionPlanning": [Production Planning],
   "PartitionIntoCliques": [Partition into Cliques],
@@ -310,6 +325,7 @@
   "QuadraticDiophantineEquations": [Quadratic Diophantine Equations],
   "SimultaneousIncongruences": [Simultaneous Incongruences],
   "QuantifiedBooleanFormulas": [Quantified Boolean Formulas (QBF)],
+  "RectanglePacking": [Rectangle Packing],
   "RectilinearPictureCompression": [Rectilinear Picture Compression],
   "FeasibleRegisterAssignment": [Feasible Register Assignment],
   "MinimumRegisterSufficiencyForLoops": [Minimum Register Sufficiency for Loops],
@@ -337,14 +353,18 @@
   "SequencingWithReleaseTimesAndDeadlines": [Sequencing with Release Times and Deadlines],
   "SequencingWithinIntervals": [Sequencing Within Intervals],
   "ShortestCommonSupersequence": [Shortest Common Supersequence],
+  "ShortestCommonSuperstring": [Shortest Common Superstring],
   "StaffScheduling": [Staff Scheduling],
   "SteinerTree": [Steiner Tree],
+  "MinimumSteinerForest": [Minimum Steiner Forest],
+  "ClusterEditing": [Cluster Editing],
   "SteinerTreeInGraphs": [Steiner Tree in Graphs],
   "MinimumAxiomSet": [Minimum Axiom Set],
   "MinimumExternalMacroDataCompression": [Minimum External Macro Data Compression],
   "MinimumInternalMacroDataCompression": [Minimum Internal Macro Data Compression],
   "MinimumFaultDetectionTestSet": [Minimum Fault Detection Test Set],
   "MinimumWeightAndOrGraph": [Minimum Weight AND/OR Graph],
+  "MinimumWeightTriangulation": [Minimum Weight Triangulation],
   "StringToStringCorrection": [String-to-String Correction],
   "StrongConnectivityAugmentation": [Strong Connectivity Augmentation],
   "SubgraphIsomorphism": [Subgraph Isomorphism],
@@ -794,7 +814,7 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
     #problem-def("MinimumVertexCover")[
       Given $G = (V, E)$ with vertex weights $w: V -> RR$, find $S subset.eq V$ minimizing $sum_(v in S) w(v)$ such that every edge has at least one endpoint in $S$: $forall (u, v) in E: (u in S) or (v in S)$.
     ][
-    One of Karp's 21 NP-complete problems @karp1972. Vertex Cover is the complement of Independent Set: $S$ is a vertex cover iff $V backslash S$ is an independent set, so $|"VC"| + |"IS"| = n$. Central to parameterized complexity, admitting FPT algorithms in $O^*(1.2738^k)$ time parameterized by solution size $k$. The best known exact algorithm runs in $O^*(1.1996^n)$ via the MIS complement @xiao2017.
+    One of Karp's 21 NP-complete problems @karp1972. Vertex Cover is the complement of Independent Set: $S$ is a vertex cover iff $V backslash S$ is an independent set, so $|"VC"| + |"IS"| = n$. Central to parameterized complexity, admitting FPT algorithms in $O^*(1.2738^k)$ time parameterized by solution size $k$. The best known exact algorithm runs in $O^*(1.1996^n)$ via the MIS complement @xiao2017. On a hypergraph, where each hyperedge $e subset.eq V$ must contain a vertex of $S$, the problem is the hitting set problem, the transpose of Set Covering.
 
     *Example.* Consider the house graph $G$ with $n = #nv$ vertices, $|E| = #ne$ edges, and unit weights $w(v) = 1$. A minimum vertex cover is $S = {#cover.map(i => $v_#i$).join(", ")}$ with $w(S) = #wS$: #edges.map(((u, v)) => {
       let by = if cover.contains(u) and cover.contains(v) { "both" } else if cover.contains(u) { $v_#u$ } else { $v_#v$ }
@@ -940,6 +960,43 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
     ]
   ]
 }
+#{
+  let x = load-model-example("MaxBisection")
+  let nv = graph-num-vertices(x.instance)
+  let ne = graph-num-edges(x.instance)
+  let edges = x.instance.graph.edges
+  let side-a = x.optimal_config.enumerate().filter(((i, v)) => v == 0).map(((i, _)) => i)
+  let side-b = x.optimal_config.enumerate().filter(((i, v)) => v == 1).map(((i, _)) => i)
+  let cut-edges = edges.filter(e => x.optimal_config.at(e.at(0)) != x.optimal_config.at(e.at(1)))
+  let cut-val = metric-value(x.optimal_value)
+  [
+    #problem-def("MaxBisection")[
+      Given an undirected graph $G = (V, E)$ with $|V| = n$ (even) and edge weights $w: E -> RR$, find a partition of $V$ into two disjoint sets $A$ and $B$ with $|A| = |B| = n\/2$ maximizing $sum_((u,v) in E: u in A, v in B) w(u, v)$.
+    ][
+    Max Bisection is Max-Cut restricted to balanced partitions, and the maximization counterpart of Graph Partitioning. It is NP-hard already with unit weights, and it is the standard benchmark for semidefinite relaxations with a balance constraint: the Goemans--Williamson rounding combined with a rebalancing step gives a 0.65-approximation @frieze1997. On bipartite graphs whose sides have equal size, the maximum bisection cuts every edge; in general, the balance constraint can force a strictly smaller cut than the unconstrained Max-Cut.
+
+    The best known exact algorithm is brute-force enumeration over all balanced partitions in $O^*(2^n)$ time.
+
+    *Example.* Consider the triangular prism with $n = #nv$ vertices and $|E| = #ne$ unit-weight edges. Each triangle keeps at least one edge uncut, so at most $#ne - 2$ edges cross any partition. The bisection $A = {#side-a.map(i => $v_#i$).join(", ")}$, $B = {#side-b.map(i => $v_#i$).join(", ")}$ attains this bound and cuts #cut-val edges.
+
+    #pred-commands(
+      "pred create --example MaxBisection -o max-bisection.json",
+      "pred solve max-bisection.json",
+      "pred evaluate max-bisection.json --config " + x.optimal_config.map(str).join(","),
+    )
+
+    #figure({
+      let vpos = (
+        (0, 1.2), (-0.8, 0), (0.8, 0),
+        (3, 1.2), (2.2, 0), (3.8, 0),
+      )
+      draw-edge-highlight(vpos, edges, cut-edges, side-a)
+    },
+    caption: [Triangular prism ($n = #nv$, $|E| = #ne$) with maximum bisection $A = {#side-a.map(i => $v_#i$).join(", ")}$ (blue) vs $B = {#side-b.map(i => $v_#i$).join(", ")}$ (white). The #cut-val cut edges are shown in bold blue.],
+    ) <fig:max-bisection>
+    ]
+  ]
+}
 #{
   let x = load-model-example("MinimumCutIntoBoundedSets", variant: (graph: "SimpleGraph", weight: "i32"))
   let nv = graph-num-vertices(x.instance)
@@ -2307,6 +2364,27 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
     ]
   ]
 }
+#{
+  let x = load-model-example("MinimumEdgeDominatingSet")
+  let nv = graph-num-vertices(x.instance)
+  let edges = x.instance.graph.edges
+  let D = x.optimal_config.enumerate().filter(((i, v)) => v == 1).map(((i, _)) => edges.at(i))
+  [
+    #problem-def("MinimumEdgeDominatingSet")[
+      Given $G = (V, E)$ with weights $w: E -> RR$, find $D subset.eq E$ minimizing $sum_(e in D) w(e)$ s.t. every $e in E$ is in $D$ or shares an endpoint with an edge of $D$.
+    ][
+    Edge Dominating Set (GT2 in @garey1979) is Dominating Set on the line graph $L(G)$. Yannakakis and Gavril showed it NP-hard even on planar and bipartite graphs of maximum degree 3, and that a minimum edge dominating set can always be taken to be a maximal matching, so unweighted EDS coincides with Minimum Maximal Matching @yannakakis1980. The implementation enumerates edge subsets in $O^*(2^m)$ time.
+
+    *Example.* Consider the cycle $C_#nv$ with unit weights. Each edge touches only itself and its two neighbours, so one edge cannot dominate all $#edges.len()$ edges, while the non-adjacent edges $D = {#D.map(((u, v)) => $(v_#u, v_#v)$).join(", ")}$ touch four vertices and hence every edge: the optimum is $#metric-value(x.optimal_value)$.
+
+    #pred-commands(
+      "pred create --example MinimumEdgeDominatingSet -o meds.json",
+      "pred solve meds.json",
+      "pred evaluate meds.json --config " + x.optimal_config.map(str).join(","),
+    )
+    ]
+  ]
+}
 #{
   let x = load-model-example("MinimumGeometricConnectedDominatingSet")
   let n = x.instance.points.len()
@@ -2510,6 +2588,30 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("MaximumPlanarSubgraph")
+  let nv = graph-num-vertices(x.instance)
+  let ne = graph-num-edges(x.instance)
+  let edges = x.instance.graph.edges
+  let ew = x.instance.edge_weights
+  let dropped = x.optimal_config.enumerate().filter(((i, v)) => v == 0).map(((i, _)) => edges.at(i))
+  [
+    #problem-def("MaximumPlanarSubgraph")[
+      Given $G = (V, E)$ with weights $w: E -> RR$, find $E' subset.eq E$ maximizing $sum_(e in E') w(e)$ such that $(V, E')$ is planar.
+    ][
+    Deciding whether a graph has a planar subgraph with at least $k$ edges is NP-complete @garey1979 @liu1979, even though planarity itself is testable in linear time. The complement, the fewest edges to delete for planarity, is the _skewness_ of $G$. Feasibility is checked with the path-addition planarity test of Demoucron, Malgrange and Pertuiset @demoucron1964 on each biconnected block.
+
+    *Example.* Consider $K_(3,3)$ with $n = #nv$ vertices and $|E| = #ne$ edges, where edge $(v_#(edges.at(0).at(0)), v_#(edges.at(0).at(1)))$ has weight #ew.at(0) and all others weight 1. $K_(3,3)$ is not planar but deleting any single edge makes it planar, so the optimum drops one light edge, e.g. #dropped.map(((u, v)) => $(v_#u, v_#v)$).join(", "), keeping weight #metric-value(x.optimal_value).
+
+    #pred-commands(
+      "pred create --example MaximumPlanarSubgraph -o maximum-planar-subgraph.json",
+      "pred solve maximum-planar-subgraph.json --solver brute-force",
+      "pred evaluate maximum-planar-subgraph.json --config " + x.optimal_config.map(str).join(","),
+    )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("BottleneckTravelingSalesman")
   let nv = graph-num-vertices(x.instance)
@@ -2633,7 +2735,7 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
     #problem-def("TravelingSalesman")[
       Given an undirected graph $G=(V,E)$ with edge weights $w: E -> RR$, find an edge set $C subset.eq E$ that forms a cycle visiting every vertex exactly once and minimizes $sum_(e in C) w(e)$.
     ][
-    One of the most intensely studied NP-hard problems, with applications in logistics, circuit board drilling, and DNA sequencing. The best known exact algorithm runs in $O^*(2^n)$ time and space via Held-Karp dynamic programming @heldkarp1962. No $O^*((2-epsilon)^n)$ algorithm is known, and improving the exponential space remains open.
+    One of the most intensely studied NP-hard problems, with applications in logistics, circuit board drilling, and DNA sequencing. The best known exact algorithm runs in $O^*(2^n)$ time and space via Held-Karp dynamic programming @heldkarp1962. No $O^*((2-epsilon)^n)$ algorithm is known, and improving the exponential space remains open. The asymmetric variant on a directed graph selects arcs instead of edges, so $d(i, j) != d(j, i)$ is allowed and a tour and its reversal may differ in cost.
 
     *Example.* Consider the complete graph $K_#nv$ with vertices ${#range(nv).map(i => $v_#i$).join(", ")}$ and edge weights #weight-labels.map(l => $w(v_#(l.u), v_#(l.v)) = #(int(l.w))$).join(", "). The optimal tour is $#tour-order.map(v => $v_#v$).join($arrow$) arrow v_#(tour-order.at(0))$ with cost $#tour-edges.map(((u, v)) => {
       let idx = edges.position(e => e == (u, v) or e == (v, u))
@@ -2745,6 +2847,53 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
     ]
   ]
 }
+#{
+  let x = load-model-example("MinimumSteinerForest")
+  let edges = x.instance.graph.edges
+  let weights = x.instance.edge_weights
+  let pairs = x.instance.terminal_pairs
+  let forest-edges = x.optimal_config.enumerate().filter(((i, v)) => v == 1).map(((i, _)) => edges.at(i))
+  [
+    #problem-def("MinimumSteinerForest")[
+      Given an undirected graph $G = (V, E)$ with edge weights $w: E -> RR_(>= 0)$ and terminal pairs $(s_1, t_1), dots, (s_k, t_k)$ with $s_i != t_i$, find a forest $F subset.eq E$ such that $s_i$ and $t_i$ lie in the same tree of $F$ for every $i$, minimizing $sum_(e in F) w(e)$.
+    ][
+    The Steiner forest (generalized Steiner) problem generalizes Steiner Tree (@def:SteinerTree), which is the special case where every pair shares one terminal. Unlike a Steiner tree, the optimum may consist of several disconnected trees, so the hard part is deciding which demands to route together. Agrawal, Klein, and Ravi gave the classic primal--dual 2-approximation @agrawal1995trees. No exact algorithm better than enumerating the $2^m$ edge subsets is implemented here.
+
+    *Example.* Consider $G$ with $n = #x.instance.graph.num_vertices$ vertices, $m = #edges.len()$ edges, and pairs ${#pairs.map(p => $(v_#(p.at(0)), v_#(p.at(1)))$).join(", ")}$. The optimal forest ${#forest-edges.map(e => $(v_#(e.at(0)), v_#(e.at(1)))$).join(", ")}$ consists of two disjoint paths with total weight #metric-value(x.optimal_value); joining the pairs through the cross edge $(v_1, v_4)$ would cost $7$.
+
+    #pred-commands(
+      "pred create --example MinimumSteinerForest -o steiner-forest.json",
+      "pred solve steiner-forest.json",
+      "pred evaluate steiner-forest.json --config " + x.optimal_config.map(str).join(","),
+    )
+    ]
+  ]
+}
+#{
+  let x = load-model-example("ClusterEditing")
+  let edges = x.instance.graph.edges
+  let weights = x.instance.edge_weights
+  let config = x.optimal_config
+  let clusters = config.dedup().map(c => range(config.len()).filter(v => config.at(v) == c))
+  let deleted = edges.enumerate().filter(((i, e)) => config.at(e.at(0)) != config.at(e.at(1))).map(((i, e)) => e)
+  [
+    #problem-def("ClusterEditing")[
+      Given an undirected graph $G = (V, E)$ with edge weights $w: E -> RR_(>= 0)$, find a partition $cal(C)$ of $V$ minimizing
+      $ sum_({u, v} in E, thin C(u) != C(v)) w({u, v}) + |{{u, v} in.not E : C(u) = C(v)}|, $
+      where $C(v)$ is the cluster containing $v$: the total cost of deleting edges between clusters and inserting the missing edges inside clusters so that $G$ becomes a disjoint union of cliques.
+    ][
+    Cluster Editing is the complete-graph case of correlation clustering @bansal2004correlation: edges of $G$ are "similar" pairs and non-edges are "dissimilar" pairs. It is NP-hard even with unit weights @shamir2004cluster and is a standard model for clustering gene-expression and protein-similarity data. Every partition is feasible, so the only difficulty is the objective. Dynamic programming over vertex subsets, choosing one cluster at a time, solves it in $O^*(3^n)$ time.
+
+    *Example.* Consider $G$ with $n = #x.instance.graph.num_vertices$ vertices and weighted edges ${#edges.enumerate().map(((i, e)) => $(v_#(e.at(0)), v_#(e.at(1))): #weights.at(i)$).join(", ")}$. The optimal clustering ${#clusters.map(c => ${#c.map(v => $v_#v$).join(", ")}$).join(", ")}$ deletes ${#deleted.map(e => $(v_#(e.at(0)), v_#(e.at(1)))$).join(", ")}$ and inserts nothing, for a cost of #metric-value(x.optimal_value). Merging $v_3$ into the triangle instead costs $3$ (insert $(v_0, v_3)$, delete $(v_3, v_4)$).
+
+    #pred-commands(
+      "pred create --example ClusterEditing -o cluster-editing.json",
+      "pred solve cluster-editing.json",
+      "pred evaluate cluster-editing.json --config " + config.map(str).join(","),
+    )
+    ]
+  ]
+}
 #{
   let x = load-model-example("StrongConnectivityAugmentation")
   let nv = x.instance.graph.num_vertices
@@ -3104,6 +3253,35 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
     ]
   ]
 }
+#{
+  let x = load-model-example("MinimumMaximalIS")
+  let nv = graph-num-vertices(x.instance)
+  let edges = x.instance.graph.edges
+  let w = x.instance.weights
+  let S = x.optimal_config.enumerate().filter(((i, v)) => v == 1).map(((i, _)) => i)
+  let w-opt = metric-value(x.optimal_value)
+  [
+    #problem-def("MinimumMaximalIS")[
+      Given $G = (V, E)$ with vertex weights $w: V -> RR$, find $S subset.eq V$ minimizing $sum_(v in S) w(v)$ such that $S$ is independent and maximal: every vertex $u in V backslash S$ has a neighbor in $S$.
+    ][
+    Also known as Minimum Independent Dominating Set, since a maximal independent set is exactly an independent set that dominates $G$. The feasible sets are those of Maximal Independent Set, but the objective is reversed; compared with Minimum Dominating Set, the additional independence constraint can make the optimum strictly heavier. The problem is NP-hard and hard to approximate within $n^(1 - epsilon)$ @halldorsson1993. The best known exact algorithm runs in $O^*(1.3575^n)$ @gaspers2006.
+
+    *Example.* Consider the path $P_#nv$ with weights $w = (#w.map(str).join(", "))$. The maximum-weight independent set ${v_0, v_3}$ has weight 4, and the minimum dominating set ${v_1, v_2}$ has weight 2 but is not independent. The optimum here is $S = {#S.map(i => $v_#i$).join(", ")}$ with $w(S) = #w-opt$; ${v_1, v_3}$ ties with it.
+
+    #pred-commands(
+      "pred create --example MinimumMaximalIS -o minimum-maximal-is.json",
+      "pred solve minimum-maximal-is.json",
+      "pred evaluate minimum-maximal-is.json --config " + x.optimal_config.map(str).join(","),
+    )
+
+    #figure({
+      draw-node-highlight(((0, 0), (1, 0), (2, 0), (3, 0)), edges, S)
+    },
+    caption: [Path $P_#nv$ with minimum maximal independent set $S = {#S.map(i => $v_#i$).join(", ")}$ (blue, $w(S) = #w-opt$).],
+    ) <fig:path-minimum-maximal-is>
+    ]
+  ]
+}
 
 #{
   let x = load-model-example("MinimumMaximalMatching")
@@ -3563,7 +3741,7 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
     #problem-def("MinimumSetCovering")[
       Given universe $U$, collection $cal(S)$ with weights $w: cal(S) -> RR$, find $cal(C) subset.eq cal(S)$ minimizing $sum_(S in cal(C)) w(S)$ s.t. $union.big_(S in cal(C)) S = U$.
     ][
-    One of Karp's 21 NP-complete problems @karp1972. Arises in facility location, crew scheduling, and test suite minimization. The greedy algorithm achieves an $O(ln n)$-approximation where $n = |U|$, which is essentially optimal: cannot be approximated within $(1-o(1)) ln n$ unless P = NP. The best known exact algorithm runs in $O^*(2^m)$ by brute-force enumeration over the $m$ sets#footnote[No algorithm improving on brute-force enumeration is known for general weighted set covering.].
+    One of Karp's 21 NP-complete problems @karp1972. Arises in facility location, crew scheduling, and test suite minimization. The greedy algorithm achieves an $O(ln n)$-approximation where $n = |U|$, which is essentially optimal: cannot be approximated within $(1-o(1)) ln n$ unless P = NP. The best known exact algorithm runs in $O^*(2^m)$ by brute-force enumeration over the $m$ sets#footnote[No algorithm improving on brute-force enumeration is known for general weighted set covering.]. The _multicover_ generalization requires each element $e$ to lie in at least $r_e$ chosen sets, which models fault-tolerant covering; $r_e = 1$ recovers the classic problem, and the ILP formulation only changes the right-hand sides of the covering constraints.
 
     *Example.* Let $U = {1, 2, dots, #U-size}$ and $cal(S) = {#range(m).map(i => $S_#(i + 1)$).join(", ")}$ with #range(m).map(i => $S_#(i + 1) = #fmt-set(sets.at(i))$).join(", "), and unit weights $w(S_i) = 1$. A minimum cover is $cal(C) = {#selected.map(i => $S_#(i + 1)$).join(", ")}$ with $w(cal(C)) = #wC$: $#selected.map(i => $S_#(i + 1)$).join($union$) = {1, 2, dots, #U-size} = U$. No single set covers all of $U$, so at least two sets are required.
 
@@ -4486,6 +4664,29 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("PolySpinGlass")
+  let n = x.instance.graph.num_vertices
+  let edges = x.instance.graph.edges
+  let spins = x.optimal_config.map(v => if v == 1 { 1 } else { -1 })
+  let spin-str = spins.map(s => if s > 0 { "+" } else { "-" }).join(", ")
+  [
+    #problem-def("PolySpinGlass")[
+      Given $n$ spin variables $s_i in {-1, +1}$, a hypergraph $G = (V, E)$ whose hyperedges $e subset.eq V$ carry couplings $J_e in RR$, and external fields $h_i in RR$, minimize $H(bold(s)) = sum_(e in E) J_e product_(i in e) s_i + sum_i h_i s_i$.
+    ][
+    Higher-order Ising models arise in lattice gauge theory, error-correcting codes, and quantum annealing compilers, where constraints couple three or more spins at once. The pairwise #link(<fig:spin-glass>)[Spin Glass] is the special case in which every hyperedge has two spins, so it embeds directly; conversely, every $k$-body term can be quadratized with auxiliary spins @rosenberg1975, at the cost of extra variables and penalty weights. Ground-state computation is NP-hard already in the pairwise case @barahona1982, and the best known general algorithm is brute-force enumeration in $O^*(2^n)$.
+
+    *Example.* Consider $n = #n$ spins with a 3-body term $J_({0,1,2}) = 1$, a pair term $J_({2,3}) = -1$, and a single field $h_0 = -1$, so $H(bold(s)) = s_0 s_1 s_2 - s_2 s_3 - s_0$. Each term is bounded below by $-1$, giving $H >= -3$, and $bold(s) = (#spin-str)$ attains it: the 3-body product is $-1$ because exactly one spin of the triple is down. Flipping the sign of $J_({0,1,2})$ instead favors an even number of down spins in the triple, which moves the ground state.
+
+    #pred-commands(
+      "pred create --example PolySpinGlass -o poly-spinglass.json",
+      "pred solve poly-spinglass.json",
+      "pred evaluate poly-spinglass.json --config " + x.optimal_config.map(str).join(","),
+    )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("QUBO")
   let n = x.instance.num_vars
@@ -4618,6 +4819,27 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("PseudoBoolean")
+  let obj = x.instance.objective
+  let sol = x.optimal_config
+  [
+    #problem-def("PseudoBoolean")[
+      Given $n$ binary variables $bold(x) in {0, 1}^n$, an integer objective $bold(c) in ZZ^n$, and linear constraints $bold(a)_k^top bold(x) space.thin ast_k space.thin b_k$ with $bold(a)_k in ZZ^n$, $b_k in ZZ$ and $ast_k in {>=, <=, =}$, find $bold(x)$ satisfying every constraint that minimizes $bold(c)^top bold(x)$.
+    ][
+      Pseudo-Boolean Optimization (PBO) is binary ILP with integer data, and the native problem of the OPB format used by the pseudo-Boolean solver competitions @roussel2009. With an empty objective it is pseudo-Boolean satisfaction, which generalizes CNF-SAT (a clause is the constraint $sum_(i in C) ell_i >= 1$) and is solved by conflict-driven learners that reason on linear constraints directly. The implementation enumerates all $2^n$ assignments.
+
+      *Example.* Minimize $#obj.map(((i, c)) => if c == 1 { $x_#(i + 1)$ } else { $#c x_#(i + 1)$ }).join($+$)$ subject to $x_1 + x_2 + x_3 >= 2$ and $x_1 + x_2 <= 1$. At least two variables are set but not both of $x_1, x_2$, so $x_3 = 1$ and the cheaper partner is $x_1$: the optimum $bold(x)^* = (#sol.map(str).join(", "))$ has value $#metric-value(x.optimal_value)$.
+
+      #pred-commands(
+        "pred create --example PseudoBoolean -o pbo.json",
+        "pred solve pbo.json --solver brute-force",
+        "pred evaluate pbo.json --config " + sol.map(str).join(","),
+      )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("QuadraticAssignment")
   let C = x.instance.cost_matrix
@@ -5193,7 +5415,7 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   let sat-count = clauses.filter(clause-sat).len()
   [
     #problem-def("Maximum2Satisfiability")[
-      Given a set $U$ of $n$ Boolean variables and a collection $C = {C_1, dots, C_m}$ of $m$ clauses over $U$ with $|C_j| = 2$ for each $j$, find a truth assignment $bold(x) in {0,1}^n$ that maximizes the number of simultaneously satisfied clauses.
+      Given a set $U$ of $n$ Boolean variables and a collection $C = {C_1, dots, C_m}$ of $m$ clauses over $U$ with $|C_j| <= 2$ for each $j$ and weights $w: C -> ZZ$, find a truth assignment $bold(x) in {0,1}^n$ that maximizes the total weight of satisfied clauses. With unit weights this is the number of satisfied clauses.
     ][
     Maximum 2-Satisfiability (MAX-2-SAT) is one of the fundamental NP-hard optimization problems. While the decision version of 2-SAT is solvable in linear time by implication-graph analysis, the optimization variant---maximizing the number of satisfied clauses---is NP-hard @garey1979. The best known exact algorithm by Williams @williams2005 runs in $O^*(2^(0.7905n))$ time by reducing to a maximum-weight triangle problem and applying fast matrix multiplication.
 
@@ -5207,6 +5429,32 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
     ]
   ]
 }
+#{
+  let x = load-model-example("ModelCounting")
+  let n = x.instance.num_vars
+  let clauses = x.instance.clauses
+  let fmt-lit(l) = if l > 0 { $x_#l$ } else { $not x_#(-l)$ }
+  let fmt-clause(c) = $paren.l #c.literals.map(fmt-lit).join($or$) paren.r$
+  // Enumerate all assignments to list the models
+  let assignments = range(calc.pow(2, n)).map(i => range(n).map(j => calc.rem(calc.quo(i, calc.pow(2, n - 1 - j)), 2)))
+  let eval-lit(a, l) = if l > 0 { a.at(l - 1) } else { 1 - a.at(-l - 1) }
+  let models = assignments.filter(a => clauses.all(c => c.literals.any(l => eval-lit(a, l) == 1)))
+  [
+    #problem-def("ModelCounting")[
+      Given a CNF formula $phi = and.big_(j=1)^m C_j$ over $n$ Boolean variables, compute the number of models $|{bold(x) in {0, 1}^n : phi(bold(x)) = 1}|$.
+    ][
+    Model counting (\#SAT) is the canonical \#P-complete problem @valiant1979complexity: counting stays hard even for formulas whose satisfiability is decidable in polynomial time, such as monotone 2-CNF. Each configuration contributes 1 to the aggregate exactly when it satisfies $phi$, so the exhaustive solver returns the count directly. Exact counters extend DPLL search @birnbaum1999good: a branch is abandoned as soon as a clause is falsified, unit clauses force their literal, and once every clause is satisfied the $k$ unassigned variables contribute $2^k$ models at once.
+
+    *Example.* The formula $#clauses.map(fmt-clause).join($and$)$ over $n = #n$ variables has #models.len() models: #models.map(a => $(#a.map(str).join(", "))$).join(" and "). The configuration $(#x.optimal_config.map(str).join(", "))$ is one of them and contributes #metric-value(x.optimal_value) to the count.
+
+    #pred-commands(
+      "pred create --example ModelCounting -o model-counting.json",
+      "pred solve model-counting.json --solver brute-force",
+      "pred evaluate model-counting.json --config " + x.optimal_config.map(str).join(","),
+    )
+    ]
+  ]
+}
 #{
   let x = load-model-example("NonTautology")
   let n = x.instance.num_vars
@@ -5510,9 +5758,9 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   let q = range(nb).fold(0, (acc, i) => acc + sol.at(mb + i) * calc.pow(2, i)) + 2
   [
     #problem-def("Factoring")[
-      Given a composite integer $N$ and bit sizes $m, n$, find integers $p in [2, 2^m - 1]$ and $q in [2, 2^n - 1]$ such that $p times q = N$. Here $p$ has $m$ bits and $q$ has $n$ bits.
+      Given a composite integer $N$ and bit sizes $m, n$, find integers $p in [2, 2^m - 1]$ and $q in [2, 2^n - 1]$ such that $p times q = N$. Here $p$ has $m$ bits and $q$ has $n$ bits. A prime $N$ admits no such pair, while an $N$ with more than two prime factors admits every split into two factors of at least 2 (e.g. $30 = 2 times 15 = 3 times 10 = 5 times 6$).
     ][
-    The hardness of integer factorization underpins RSA cryptography and other public-key systems. Unlike most problems in this collection, Factoring is not known to be NP-complete; it lies in NP $inter$ co-NP, suggesting it may be of intermediate complexity. The best classical algorithm is the General Number Field Sieve @lenstra1993 running in sub-exponential time $e^(O(b^(1 slash 3)(log b)^(2 slash 3)))$ where $b$ is the bit length. Shor's algorithm @shor1994 solves Factoring in polynomial time on a quantum computer.
+    The hardness of integer factorization underpins RSA cryptography and other public-key systems. Unlike most problems in this collection, Factoring is not known to be NP-complete; it lies in NP $inter$ co-NP, suggesting it may be of intermediate complexity. The best classical algorithm is the General Number Field Sieve @lenstra1993 running in sub-exponential time $e^(O(b^(1 slash 3)(log b)^(2 slash 3)))$ where $b$ is the bit length. Shor's algorithm @shor1994 solves Factoring in polynomial time on a quantum computer. Fixing the exact bit widths of $p$ and $q$ (their top bits set to 1) excludes the trivial factor 1 and yields the balanced instances used in cryptographic benchmarks.
 
     *Example.* Let $N = #N$ with $m = #mb$ bits and $n = #nb$ bits, so $p in [2, #(calc.pow(2, mb) - 1)]$ and $q in [2, #(calc.pow(2, nb) - 1)]$. The solution is $p = #p$, $q = #q$, since $#p times #q = #N = N$. Note $p = #p$ fits in #mb bits and $q = #q$ fits in #nb bits. The alternative factorization $#q times #p$ requires $m = #nb$, $n = #mb$.
 
@@ -5551,6 +5799,54 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("XorSatisfiability")
+  let n = x.instance.num_vars
+  let clauses = x.instance.clauses
+  let xors = x.instance.xor_clauses
+  let assign = x.optimal_config
+  let fmt-lit(l) = if l > 0 { $x_#l$ } else { $not x_#(-l)$ }
+  let fmt-clause(c) = $paren.l #c.literals.map(fmt-lit).join($or$) paren.r$
+  let fmt-xor(c) = $paren.l #c.literals.map(fmt-lit).join($xor$) paren.r$
+  [
+    #problem-def("XorSatisfiability")[
+      Given $n$ Boolean variables, a CNF formula $phi = and.big_(j=1)^m C_j$, and XOR clauses $X_1, dots, X_r$ where each $X_t = ell_(t 1) xor dots xor ell_(t k)$ is a parity over literals, find an assignment $bold(x) in {0, 1}^n$ that satisfies every $C_j$ and makes every $X_t$ evaluate to $1$ (an odd number of its literals true).
+    ][
+    Parity constraints arise in cryptanalysis, where stream ciphers and hash functions are largely linear over GF(2), and in hashing-based approximate model counting, which conjoins random XORs with a CNF formula. Without CNF clauses the problem is XOR-SAT, solvable by Gaussian elimination in polynomial time @schaefer1978; the combination is NP-complete, and dedicated solvers such as CryptoMiniSat interleave CDCL search with Gauss--Jordan elimination on the XOR part @soos2009. Expanding a $k$-literal XOR directly into CNF needs $2^(k-1)$ clauses, so long parities are chained through auxiliary variables. The best known general algorithm is brute-force enumeration in $O^*(2^n)$.
+
+    *Example.* Consider $n = #n$ variables, CNF clauses #clauses.map(fmt-clause).join($and$), and parities #xors.map(fmt-xor).join(", "). The assignment $(#range(n).map(i => $x_#(i + 1)$).join(",")) = (#assign.map(str).join(", "))$ satisfies both CNF clauses, makes $x_1 xor x_2 xor x_3 = 1$, and sets $x_2 = x_4$ as required by $x_2 xor not x_4 = 1$.
+
+    #pred-commands(
+      "pred create --example XorSatisfiability -o xor-satisfiability.json",
+      "pred solve xor-satisfiability.json",
+      "pred evaluate xor-satisfiability.json --config " + x.optimal_config.map(str).join(","),
+    )
+    ]
+  ]
+}
+
+#{
+  let x = load-model-example("CSP")
+  let domains = x.instance.domains
+  let n = domains.len()
+  let assign = x.optimal_config
+  [
+    #problem-def("CSP")[
+      Given variables $x_0, dots, x_(n-1)$ with finite domains $x_i in {0, dots, d_i - 1}$ and constraints $(S_1, R_1), dots, (S_m, R_m)$, where each scope $S_j$ is a tuple of variables and $R_j$ is the set of value tuples allowed on $S_j$, find an assignment whose restriction to every scope $S_j$ lies in $R_j$.
+    ][
+    The constraint satisfaction problem is the common skeleton of SAT (Boolean domains, clauses as relations), graph $k$-coloring (domain size $k$, a disequality per edge), and list coloring (unary constraints restrict each vertex to its list). Constraints are stored extensionally as tables of allowed tuples, so any finite relation can be expressed. CSP is NP-complete already for binary constraints over domains of size 3; the dichotomy theorem of Bulatov and Zhuk settles exactly which fixed constraint languages are polynomial. The best known general algorithm is brute-force enumeration in $O^*(d^n)$ for maximum domain size $d$.
+
+    *Example.* List-color the triangle $0, 1, 2$ with a pendant vertex $3$ on vertex $2$: vertices $0, 1$ have domains of size #domains.at(0), vertices $2, 3$ of size #domains.at(2), every edge carries a disequality constraint, and a unary constraint fixes $x_3 = 1$. Vertices $0$ and $1$ exhaust colors $0$ and $1$, so $x_2 = 2$; the assignment $(#range(n).map(i => $x_#i$).join(",")) = (#assign.map(str).join(", "))$ satisfies all constraints.
+
+    #pred-commands(
+      "pred create --example CSP -o csp.json",
+      "pred solve csp.json",
+      "pred evaluate csp.json --config " + x.optimal_config.map(str).join(","),
+    )
+    ]
+  ]
+}
+
 == Specialized Problems
 
 #{
@@ -5885,6 +6181,30 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("MaximumBalancedBiclique")
+  let left-size = x.instance.graph.left_size
+  let config = x.optimal_config
+  let left-selected = range(left-size).filter(i => config.at(i) == 1)
+  let right-selected = range(x.instance.graph.right_size).filter(i => config.at(left-size + i) == 1)
+  let opt-val = metric-value(x.optimal_value)
+  [
+    #problem-def("MaximumBalancedBiclique")[
+      Given a bipartite graph $G = (A, B, E)$, find subsets $A' subset.eq A$ and $B' subset.eq B$ with $|A'| = |B'|$ and $A' times B' subset.eq E$ such that $|A'|$ is maximized.
+    ][
+      Maximum Balanced Biclique is the optimization form of Balanced Complete Bipartite Subgraph (GT24 in Garey and Johnson @garey1979): the decision instance with parameter $k$ is a yes-instance exactly when the optimum is at least $k$. The balance requirement is what makes the problem hard. Without it, a maximum-vertex biclique is an independent set in the bipartite complement of $G$, which König's theorem turns into a bipartite matching computation. The catalog records the same $O^*(1.3803^n)$ bound as the decision version.
+
+      *Example.* On the instance of @fig:balanced-complete-bipartite-subgraph, the optimum selects $A' = {#left-selected.map(i => $ell_#(i + 1)$).join(", ")}$ and $B' = {#right-selected.map(i => $r_#(i + 1)$).join(", ")}$, a $K_(#opt-val,#opt-val)$. No $K_(4,4)$ exists because $(ell_4, r_3) in.not E$.
+
+      #pred-commands(
+        "pred create --example MaximumBalancedBiclique -o maximum-balanced-biclique.json",
+        "pred solve maximum-balanced-biclique.json",
+        "pred evaluate maximum-balanced-biclique.json --config " + x.optimal_config.map(str).join(","),
+      )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("PartitionIntoTriangles")
   let nv = graph-num-vertices(x.instance)
@@ -6085,6 +6405,29 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("MinimumCliqueCover")
+  let nv = graph-num-vertices(x.instance)
+  let edges = x.instance.graph.edges
+  let k = metric-value(x.optimal_value)
+  let groups = range(nv).map(c => x.optimal_config.enumerate().filter(((i, v)) => v == c).map(((i, _)) => i)).filter(g => g.len() > 0)
+  [
+    #problem-def("MinimumCliqueCover")[
+      Given an undirected graph $G = (V, E)$, find a partition of $V$ into the minimum number of groups $V_1, dots, V_k$ such that each group $V_i$ induces a complete subgraph (clique) in $G$.
+    ][
+      Minimum Clique Cover is the optimization version of Partition Into Cliques @garey1979[GT15]. Cliques of $G$ are independent sets of the complement $overline(G)$, so the clique cover number of $G$ equals the chromatic number of $overline(G)$, and exact coloring algorithms apply directly: inclusion--exclusion over independent sets gives $O^*(2^n)$ time @bjorklund2009.
+
+      *Example.* The 5-cycle $C_#nv$ with edges #edges.map(((u, v)) => [${#u, #v}$]).join(", ") has no triangles, so every clique has at most two vertices and at least $ceil(5 slash 2) = 3$ cliques are needed. The partition #groups.map(c => ${#c.map(v => $v_#v$).join(", ")}$).join(", ") achieves $k = #k$.
+
+      #pred-commands(
+        "pred create --example MinimumCliqueCover -o minimum-clique-cover.json",
+        "pred solve minimum-clique-cover.json",
+        "pred evaluate minimum-clique-cover.json --config " + x.optimal_config.map(str).join(","),
+      )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("PartitionIntoPerfectMatchings")
   let nv = graph-num-vertices(x.instance)
@@ -6230,6 +6573,30 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   *Example.* Let $n = 6$ items with weights $(2, 3, 4, 1, 2, 3)$, values $(3, 2, 5, 4, 3, 8)$, and capacity $C = 11$. The partial order has cover relations $0 prec 2$, $0 prec 3$, $1 prec 4$, $3 prec 5$, $4 prec 5$. Selecting $S = {0, 1, 3, 4, 5}$ is downward-closed (all predecessors included), has total weight $2 + 3 + 1 + 2 + 3 = 11 lt.eq C$, and total value $3 + 2 + 4 + 3 + 8 = 20$. Adding item 2 would exceed capacity ($15 > 11$).
 ]
 
+#{
+  let x = load-model-example("RectanglePacking")
+  let rects = x.instance.rectangles
+  let n = rects.len()
+  let W = x.instance.container_width
+  let H = x.instance.container_height
+  let cfg = x.optimal_config
+  [
+    #problem-def("RectanglePacking")[
+      Given rectangles $(w_0, h_0), dots, (w_(n-1), h_(n-1))$ with positive integer sides and a $W times H$ container, determine whether there are integer positions $(x_i, y_i)$ such that every rectangle $[x_i, x_i + w_i] times [y_i, y_i + h_i]$ lies inside $[0, W] times [0, H]$ and no two rectangles share interior points. When rotations are allowed, each rectangle may instead occupy $h_i times w_i$.
+    ][
+    Rectangle Packing is the decision version of two-dimensional strip and bin packing. It is NP-complete in the strong sense even when all rectangles are squares, by a reduction from 3-Partition. Two rectangles are disjoint exactly when one lies entirely left of, right of, below, or above the other, which yields the classical big-$M$ disjunctive ILP formulation. The brute-force solver enumerates all $O((W H)^n)$ placements (times $2^n$ with rotation).
+
+    *Example.* Let $n = #n$ rectangles #rects.map(r => $#(r.at(0)) times #(r.at(1))$).join(", ") and a $#W times #H$ container without rotation. Placing them at #range(n).map(i => $(#cfg.at(2 * i), #cfg.at(2 * i + 1))$).join(", ") tiles the container exactly: the upright $1 times 2$ rectangle fills column $0$ and the two $2 times 1$ rectangles stack in columns $1..2$, so the answer is YES.
+
+    #pred-commands(
+      "pred create --example RectanglePacking -o rectangle-packing.json",
+      "pred solve rectangle-packing.json",
+      "pred evaluate rectangle-packing.json --config " + x.optimal_config.map(str).join(","),
+    )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("RectilinearPictureCompression")
   let mat = x.instance.matrix
@@ -7480,6 +7847,29 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("KCenter")
+  let n = x.instance.distances.len()
+  let K = x.instance.num_centers
+  let config = x.optimal_config
+  let centers = range(n).filter(i => config.at(i) == 1)
+  [
+    #problem-def("KCenter")[
+      Given $n$ sites with a symmetric distance matrix $d$ (zero diagonal) and an integer $K$, find a set $C$ of exactly $K$ sites minimizing the covering radius $max_i min_(c in C) d(i, c)$.
+    ][
+      K-Center is the bottleneck counterpart of facility location: every client must be served, and only the worst service distance counts. It is NP-hard @karivhakimi1979, and for metric distances the greedy farthest-point rule and the threshold method of Hochbaum and Shmoys @hochbaumshmoys1985 both give 2-approximations, which is best possible unless P = NP. Unlike Min-Max Multicenter, distances are given directly rather than as shortest paths in a graph. The optimal radius is always one of the matrix entries; exact search enumerates the $binom(n, K)$ center sets.
+
+      *Example.* Place $n = #n$ sites on a line at positions $0, 1, 2, 6, 7, 8$ with $K = #K$. The centers ${#centers.map(str).join(", ")}$ sit in the middle of the two groups, so every site is within distance $#x.optimal_value$ of a center; radius 0 would require every site to be a center.
+
+      #pred-commands(
+        "pred create --example KCenter -o kcenter.json",
+        "pred solve kcenter.json",
+        "pred evaluate kcenter.json --config " + config.map(str).join(","),
+      )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("DynamicStorageAllocation")
   let items = x.instance.items
@@ -7706,6 +8096,31 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("ShortestCommonSuperstring")
+  let strings = x.instance.strings
+  let order = x.optimal_config
+  // Alphabet mapping: 0->A, 1->C, 2->G, 3->T
+  let alpha-map = ("A", "C", "G", "T")
+  let fmt-str(s) = "\"" + s.map(c => alpha-map.at(c)).join("") + "\""
+  let r-strs = strings.map(s => fmt-str(s))
+  [
+    #problem-def("ShortestCommonSuperstring")[
+      Given a finite alphabet $Sigma$ and a set $R = {r_1, dots, r_m}$ of strings over $Sigma^*$, find a string $w in Sigma^*$ of minimum length such that every $r_i in R$ is a _substring_ of $w$, i.e. $w = u r_i v$ for some $u, v in Sigma^*$.
+    ][
+      Listed as problem SR9 in Garey and Johnson @garey1979; NP-hard even over a binary alphabet. Every shortest superstring arises from merging the strings in some order, overlapping each string with the text built so far as much as possible, so a solution is a permutation of $R$ and the search space has $m!$ points. The reduction to the Travelling Salesman Problem on the overlap graph underlies constant-factor approximations and the $O(2^m m^2)$ dynamic program. Applications include DNA fragment assembly and data compression.
+
+      *Example.* Let $R = {#r-strs.join(", ")}$. Merging in the order $(#order.map(i => str(i + 1)).join(", "))$ overlaps consecutive strings in three symbols and yields $w = "CATGCA"$ of length #x.optimal_value, which is optimal.
+
+      #pred-commands(
+        "pred create --example ShortestCommonSuperstring -o shortest-common-superstring.json",
+        "pred solve shortest-common-superstring.json --solver brute-force",
+        "pred evaluate shortest-common-superstring.json --config " + order.map(str).join(","),
+      )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("StringToStringCorrection")
   let source = x.instance.source
@@ -7933,6 +8348,34 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("MinimumWeightTriangulation")
+  let pts = x.instance.positions
+  let n = pts.len()
+  let diags = ()
+  for i in range(n) {
+    for j in range(i + 2, n) {
+      if not (i == 0 and j == n - 1) { diags.push((i, j)) }
+    }
+  }
+  let chosen = range(diags.len()).filter(k => x.optimal_config.at(k) == 1).map(k => diags.at(k))
+  [
+    #problem-def("MinimumWeightTriangulation")[
+      Given points $p_0, dots, p_(n-1) in RR^2$ forming a strictly convex polygon in cyclic order, find a set $D$ of pairwise non-crossing diagonals $p_i p_j$ that triangulates the polygon, minimizing the total Euclidean length of the polygon sides plus the diagonals in $D$.
+    ][
+    Every triangulation of a convex $n$-gon uses exactly $n - 3$ diagonals, and there are $C_(n-2)$ of them (a Catalan number). On convex polygons the problem is solvable in $O(n^3)$ time by interval dynamic programming @klincsek1980; for general point sets it is NP-hard @mulzer2008. The model enumerates all $n(n-3)\/2$ diagonal subsets and accepts exactly the maximal non-crossing ones.
+
+    *Example.* Consider the pentagon with vertices #pts.enumerate().map(((i, p)) => $p_#i = (#p.at(0), #p.at(1))$).join(", "), where $p_2$ sticks out far to the right. Any triangulation using a diagonal at $p_2$ pays for a long chord, so the optimum cuts $p_2$ off with #chosen.map(((i, j)) => $p_#i p_#j$).join(" and "), for total length #calc.round(metric-value(x.optimal_value), digits: 3).
+
+    #pred-commands(
+      "pred create --example MinimumWeightTriangulation -o mwt.json",
+      "pred solve mwt.json --solver brute-force",
+      "pred evaluate mwt.json --config " + x.optimal_config.map(str).join(","),
+    )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("MinimumFaultDetectionTestSet")
   let n = x.instance.num_vertices
@@ -7987,6 +8430,30 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("MaximumAcyclicSubgraph")
+  let nv = x.instance.graph.num_vertices
+  let arcs = x.instance.graph.arcs.map(a => (a.at(0), a.at(1)))
+  let config = x.optimal_config
+  let opt-val = metric-value(x.optimal_value)
+  let dropped = range(arcs.len()).filter(i => config.at(i) == 0)
+  [
+    #problem-def("MaximumAcyclicSubgraph")[
+      Given a directed graph $G = (V, A)$ with arc weights $w: A -> RR$, find a subset $A' subset.eq A$ such that $(V, A')$ is a directed acyclic graph and $sum_(a in A') w(a)$ is maximized.
+    ][
+      Maximum Acyclic Subgraph (MAS) is the complement of Minimum Feedback Arc Set: $A'$ is acyclic iff $A backslash A'$ meets every directed cycle, so the two optima sum to the total arc weight. Exact solvability therefore transfers directly, including the $O^*(2^n)$ dynamic program over vertex orderings @bodlaender2012. The complement does not preserve approximation: every digraph has an acyclic subgraph with at least half the arcs (keep the forward or the backward arcs of any vertex order), giving a trivial $1\/2$-approximation, while FAS has no known constant-factor approximation. On tournaments, MAS is the problem of ranking players so that the order agrees with as many match outcomes as possible.
+
+      *Example.* Consider $G$ with $V = {#range(nv).map(v => str(v)).join(", ")}$ and arcs #arcs.map(a => $(#(a.at(0)) arrow #(a.at(1)))$).join($,$). Dropping #dropped.map(i => {let a = arcs.at(i); $(#(a.at(0)) arrow #(a.at(1)))$}).join($,$) breaks both directed triangles; the remaining #opt-val arcs are consistent with the order $1, 2, 3, 0$.
+
+      #pred-commands(
+        "pred create --example MaximumAcyclicSubgraph -o maximum-acyclic-subgraph.json",
+        "pred solve maximum-acyclic-subgraph.json",
+        "pred evaluate maximum-acyclic-subgraph.json --config " + x.optimal_config.map(str).join(","),
+      )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("PartialFeedbackEdgeSet")
   let nv = graph-num-vertices(x.instance)
@@ -8705,6 +9172,54 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
   ]
 }
 
+#{
+  let x = load-model-example("MinimumMultiprocessorScheduling")
+  let lengths = x.instance.lengths
+  let m = x.instance.num_processors
+  let loads = range(m).map(p => range(lengths.len()).filter(i => x.optimal_config.at(i) == p).map(i => lengths.at(i)).sum())
+  [
+    #problem-def("MinimumMultiprocessorScheduling")[
+      Given a finite set $T$ of tasks with processing lengths $ell: T -> ZZ^+$ and a number $m in ZZ^+$ of identical processors, find an assignment $p: T -> {1, dots, m}$ minimizing the makespan $max_(i in {1, dots, m}) sum_(t in T: p(t) = i) ell(t)$.
+    ][
+      The optimization form of Multiprocessor Scheduling, written $P || C_max$ in the three-field notation of Graham et al. @graham1979. Only the most loaded processor counts, so the objective is a bottleneck rather than a sum. It is strongly NP-hard for general $m$ and weakly NP-hard already for $m = 2$, where it contains Partition. Graham's longest-processing-time rule is a $4/3$-approximation, and the implementation enumerates all $m^n$ assignments.
+
+      *Example.* Let $T = {t_1, dots, t_5}$ with lengths $(#lengths.map(str).join(", "))$ and $m = #m$. Any schedule has makespan at least half the total, $#lengths.sum() / 2 = #(lengths.sum() / 2)$. The assignment $(#x.optimal_config.map(p => str(p + 1)).join(", "))$ reaches this bound with loads #loads.map(l => $#l$).join(" and "), so the optimum is $#metric-value(x.optimal_value)$.
+
+      #pred-commands(
+        "pred create --example MinimumMultiprocessorScheduling -o pcmax.json",
+        "pred solve pcmax.json",
+        "pred evaluate pcmax.json --config " + x.optimal_config.map(str).join(","),
+      )
+    ]
+  ]
+}
+
+#{
+  let x = load-model-example("KTrackIntervalScheduling")
+  let starts = x.instance.starts
+  let ends = x.instance.ends
+  let weights = x.instance.weights
+  let k = x.instance.num_tracks
+  let n = starts.len()
+  let tracks = range(k).map(t => range(n).filter(i => x.optimal_config.at(i) == t + 1))
+  let rejected = range(n).filter(i => x.optimal_config.at(i) == 0)
+  [
+    #problem-def("KTrackIntervalScheduling")[
+      Given $n$ half-open intervals $[s_i, e_i)$ with weights $w_i in ZZ_(>= 0)$ and a number $K in ZZ^+$ of identical tracks, find an assignment $tau: {1, dots, n} -> {0, 1, dots, K}$ maximizing $sum_(i: tau(i) != 0) w_i$ such that no two intervals $i != j$ with $tau(i) = tau(j) != 0$ overlap, i.e.\ $s_i < e_j$ and $s_j < e_i$. Here $tau(i) = 0$ rejects interval $i$.
+    ][
+      With $K = 1$ and unit weights this is activity selection, which the earliest-finish-time greedy rule solves exactly. The weighted problem on $K$ tracks stays polynomial: Arkin and Silverberg @arkin1987 solve it as a min-cost flow in $O(n^2 log n)$ time. It is the fixed-interval special case of parallel-machine scheduling, and becomes NP-hard once each interval may only use a subset of the tracks. The implementation enumerates all $(K + 1)^n$ assignments.
+
+      *Example.* Let $n = #n$ with intervals #range(n).map(i => $[#starts.at(i), #ends.at(i))$).join(", ") and weights $(#weights.map(str).join(", "))$ on $K = #k$ tracks. #range(k).map(t => [Track #(t + 1) takes intervals ${#tracks.at(t).map(i => str(i + 1)).join(", ")}$]).join("; ") and intervals ${#rejected.map(i => str(i + 1)).join(", ")}$ are rejected, for total weight $#metric-value(x.optimal_value)$. A single track accepts at most weight 6 on this instance.
+
+      #pred-commands(
+        "pred create --example KTrackIntervalScheduling -o ktis.json",
+        "pred solve ktis.json",
+        "pred evaluate ktis.json --config " + x.optimal_config.map(str).join(","),
+      )
+    ]
+  ]
+}
+
 #{
   let x = load-model-example("ProductionPlanning")
   let n = x.instance.num_periods
@@ -10658,7 +11173,7 @@ In all graph problems below, $G = (V, E)$ denotes an undirected graph with $|V|
 Each reduction is presented as a *Rule* (with linked problem names and overhead from the graph data), followed by a *Proof* (construction, correctness, variable mapping, solution extraction), and optionally a *Concrete Example* (a small instance with verified solution). Problem names in the rule title link back to their definitions in @sec:problems.
 
 
-#let max2sat_mc = load-example("Maximum2Satisfiability", "MaxCut")
+#let max2sat_mc = load-example("Maximum2Satisfiability", "MaxCut", source-variant: (weight: "One"))
 #let max2sat_mc_sol = max2sat_mc.solutions.at(0)
 #reduction-rule("Maximum2Satisfiability", "MaxCut",
   example: true,
@@ -10682,7 +11197,7 @@ Each reduction is presented as a *Rule* (with linked problem names and overhead
 )[
   This $O(n + m)$ reduction @karp1972 @garey1979 builds a signed weighted graph with one reference vertex $s$ and one vertex per Boolean variable. Each 2-clause contributes two reference-variable terms and, when the clause uses two different variables, one variable-variable term. After doubling the affine clause identity to clear fractions, the target has $n + 1$ vertices and at most $n + m$ nonzero edges.
 ][
-  _Construction._ Let $phi$ be a MAX-2-SAT instance on variables $x_1, dots, x_n$. Create one reference vertex $s = v_0$ and one vertex $v_i$ for each variable $x_i$. For a literal $ell$ over variable $x_i$, define $sigma(ell) = 1$ when $ell = x_i$ and $sigma(ell) = -1$ when $ell = not x_i$. For each clause $C = (ell_a or ell_b)$, add $-sigma(ell_a)$ to edge $(s, v_a)$ and $-sigma(ell_b)$ to edge $(s, v_b)$. If $a != b$, also add $sigma(ell_a) sigma(ell_b)$ to edge $(v_a, v_b)$. Repeated contributions accumulate; zero-weight edges are omitted. Interpret a cut by setting $x_i = 1$ exactly when $v_i$ lies on the same side of the cut as $s$.
+  _Construction._ Let $phi$ be a MAX-2-SAT instance on variables $x_1, dots, x_n$. Create one reference vertex $s = v_0$ and one vertex $v_i$ for each variable $x_i$. For a literal $ell$ over variable $x_i$, define $sigma(ell) = 1$ when $ell = x_i$ and $sigma(ell) = -1$ when $ell = not x_i$. For each clause $C = (ell_a or ell_b)$, add $-sigma(ell_a)$ to edge $(s, v_a)$ and $-sigma(ell_b)$ to edge $(s, v_b)$. If $a != b$, also add $sigma(ell_a) sigma(ell_b)$ to edge $(v_a, v_b)$. A unit clause $(ell_a)$ adds $-2 sigma(ell_a)$ to edge $(s, v_a)$. For a clause of weight $w_C$, every contribution is scaled by $w_C$. Repeated contributions accumulate; zero-weight edges are omitted. Interpret a cut by setting $x_i = 1$ exactly when $v_i$ lies on the same side of the cut as $s$.
 
   _Correctness._ Let $delta(u, v) in {0, 1}$ indicate whether vertices $u$ and $v$ lie on opposite sides of the cut. For each variable define $y_i = 1 - 2 delta(s, v_i)$, so $y_i = 1$ iff $x_i = 1$ and $y_i = -1$ iff $x_i = 0$. For clause $C = (ell_a or ell_b)$ with $sigma_a = sigma(ell_a)$ and $sigma_b = sigma(ell_b)$, its satisfaction indicator is
   $
@@ -10709,7 +11224,7 @@ Each reduction is presented as a *Rule* (with linked problem names and overhead
   _Solution extraction._ Return the source bit $x_i = 1$ iff $v_i$ and $s$ lie on the same side of the cut. Because this depends only on equality with $s$, globally swapping the two cut sides leaves the extracted assignment unchanged.
 ]
 
-#let max2sat_ilp = load-example("Maximum2Satisfiability", "ILP")
+#let max2sat_ilp = load-example("Maximum2Satisfiability", "ILP", source-variant: (weight: "One"))
 #let max2sat_ilp_sol = max2sat_ilp.solutions.at(0)
 #reduction-rule("Maximum2Satisfiability", "ILP",
   example: true,
@@ -11097,6 +11612,26 @@ Each reduction is presented as a *Rule* (with linked problem names and overhead
   _Solution extraction._ For VC solution $C$, return $S = V backslash C$, i.e.\ flip each variable: $s_v = 1 - c_v$.
 ]
 
+#reduction-rule("MaximumAcyclicSubgraph", "MinimumFeedbackArcSet")[
+  Acyclic arc sets and feedback arc sets are complements in $A$: the arcs left out of an acyclic subgraph hit every directed cycle, and vice versa. Since the weights of the two sets sum to $w(A)$, maximizing one is equivalent to minimizing the other. The digraph and arc weights are preserved unchanged.
+][
+  _Construction._ Given MAS instance $(G, bold(w))$, create FAS instance $(G, bold(w))$ with identical digraph and weights. Arc $a$ in the source maps to arc $a$ in the target.
+
+  _Correctness._ ($arrow.r.double$) If $(V, A')$ is acyclic, every directed cycle of $G$ uses some arc outside $A'$, so $A backslash A'$ is a feedback arc set. ($arrow.l.double$) If $F$ is a feedback arc set, $G - F = (V, A backslash F)$ is a DAG. Since $w(A') + w(A backslash A') = w(A)$ is constant, a minimum feedback arc set corresponds to a maximum acyclic subgraph.
+
+  _Solution extraction._ For FAS solution $F$, return $A' = A backslash F$, i.e.\ flip each variable: $x_a = 1 - f_a$.
+]
+
+#reduction-rule("MinimumFeedbackArcSet", "MaximumAcyclicSubgraph")[
+  The exact reverse of MAS $arrow.r$ FAS: the arcs kept by an acyclic subgraph are exactly those not removed by a feedback arc set. The digraph and weights are preserved unchanged.
+][
+  _Construction._ Given FAS instance $(G, bold(w))$, create MAS instance $(G, bold(w))$ with identical digraph and weights.
+
+  _Correctness._ ($arrow.r.double$) If $G - F$ is a DAG, then $A backslash F$ is an acyclic arc set. ($arrow.l.double$) If $(V, A')$ is acyclic, every directed cycle leaves $A'$, so $A backslash A'$ is a feedback arc set. The weights of the two sets sum to $w(A)$, so optimality carries over.
+
+  _Solution extraction._ For MAS solution $A'$, return $F = A backslash A'$, i.e.\ flip each variable: $f_a = 1 - x_a$.
+]
+
 
 #let mis_clique = load-example(
   "MaximumIndependentSet",
@@ -11141,6 +11676,39 @@ Each reduction is presented as a *Rule* (with linked problem names and overhead
   _Solution extraction._ For packing ${S_v : v in P}$, return IS $= P$ (same variable assignment).
 ]
 
+#let mis_mc = load-example("MaximumIndependentSet", "MaxCut")
+#let mis_mc_sol = mis_mc.solutions.at(0)
+#reduction-rule("MaximumIndependentSet", "MaxCut",
+  example: true,
+  example-caption: [Weighted 5-cycle ($n = #mis_mc.source.instance.graph.num_vertices$, $|E| = #mis_mc.source.instance.graph.edges.len()$) to MaxCut with an apex vertex],
+  extra: [
+    #pred-commands(
+      "pred create --example " + problem-spec(mis_mc.source) + " -o mis.json",
+      "pred reduce mis.json --to " + target-spec(mis_mc) + " -o bundle.json",
+      "pred solve bundle.json",
+      "pred evaluate mis.json --config " + mis_mc_sol.source_config.map(str).join(","),
+    )
+
+    *Step 1 -- Source instance.* The cycle $v_0 v_1 v_2 v_3 v_4$ has weights $(#mis_mc.source.instance.weights.map(str).join(", "))$. Its unique maximum independent set is ${v_0, v_2}$ with weight $5$.
+
+    *Step 2 -- Build the cut graph.* The penalty weight is $d = max_i w_i = 3$, so every cycle edge gets weight $3$. Each vertex has degree $2$, so the apex edge to $v_i$ gets $w_i - 6$, giving weights $(-3, -5, -4, -5, -4)$.
+
+    *Step 3 -- Verify the witness.* The target witness $(#mis_mc_sol.target_config.map(str).join(", "))$ cuts $v_0$ and $v_2$ away from the apex $a = v_5$. Crossing edges are the four cycle edges at $v_0$ and $v_2$ ($4 dot 3 = 12$) and their apex edges ($-3 - 4 = -7$), for cut value $5 = w(v_0) + w(v_2)$ #sym.checkmark.
+  ],
+)[
+  This $O(n + |E|)$ reduction writes the independent-set objective with an edge penalty as a quadratic pseudo-Boolean function and realizes it as a cut function with one apex vertex @lucas2014. The target has $n + 1$ vertices and $n + |E|$ edges with signed integer weights; the apex makes the target non-planar in general even for planar sources.
+][
+  _Construction._ Let $G = (V, E)$ with weights $w$ and set $d = max(max_v w(v), 1)$. The target graph keeps $V$ and $E$ and adds an apex vertex $a$ adjacent to every $v in V$. Source edges get weight $d$; apex edge $(v, a)$ gets weight $w(v) - deg(v) dot d$. Write $x_v = 1$ iff $v$ lies on the opposite side of the cut from $a$.
+
+  _Correctness._ A source edge $(u, v)$ crosses the cut iff $x_u + x_v - 2 x_u x_v = 1$, and an apex edge $(v, a)$ crosses iff $x_v = 1$. Summing, the cut value is
+  $
+    sum_v x_v (w(v) - deg(v) d) + d sum_((u, v) in E) (x_u + x_v - 2 x_u x_v) = sum_v w(v) x_v - 2 d dot |{(u, v) in E : x_u = x_v = 1}|.
+  $
+  ($arrow.r.double$) An independent set $S$ placed opposite $a$ therefore has cut value exactly $w(S)$. ($arrow.l.double$) If a cut selects both endpoints of some edge, deselecting one endpoint $v$ changes the cut by at least $2d - w(v) > 0$, because $2d > max_v w(v)$ whether or not the maximum weight is positive. So every maximum cut selects an independent set, whose weight equals the cut value and is hence maximum.
+
+  _Solution extraction._ Return $x_v = 1$ iff $v$ and $a$ lie on different sides. Comparing with $a$ rather than reading raw sides makes both labelings of a partition extract to the same set; the apex bit itself is dropped.
+]
+
 #reduction-rule("MaximumSetPacking", "MaximumIndependentSet")[
   The _intersection graph_ captures set overlap as adjacency: two sets that share an element become neighbors, so a packing (mutually disjoint sets) corresponds exactly to an independent set (mutually non-adjacent vertices). This is the standard reduction from set packing to independent set.
 ][
@@ -11159,6 +11727,28 @@ Each reduction is presented as a *Rule* (with linked problem names and overhead
   _Correctness._ ($arrow.r.double$) If $C$ is a vertex cover, every edge $e_i$ has at least one endpoint $v in C$, so $i in S_v$ for some selected set — hence $union.big_(v in C) S_v = U$, a valid covering. ($arrow.l.double$) If ${S_v : v in C}$ covers $U$, then every edge index $i in U$ appears in some $S_v$ with $v in C$, meaning edge $e_i$ is incident to some $v in C$ — hence $C$ is a vertex cover. Weight sums are identical, so optimality is preserved.
 
   _Solution extraction._ For covering ${S_v : v in C}$, return VC $= C$ (same variable assignment).
+
+  The construction only uses that each edge is a set of vertices, so it applies verbatim to hypergraphs: hyperedge $e_i$ becomes element $i$ of every $S_v$ with $v in e_i$.
+]
+
+#reduction-rule("MinimumSetCovering", "MinimumVertexCover")[
+  Set covering is hypergraph vertex cover with the roles of vertices and edges exchanged: the sets become vertices, and each element becomes the hyperedge of the sets containing it.
+][
+  _Construction._ Given universe $U$ and sets $S_1, ..., S_m$ with weights $w$, build the hypergraph $H = ({v_1, ..., v_m}, {e_u : u in U})$ with $e_u = {v_j : u in S_j}$ and $w(v_j) = w(S_j)$. The target has $m$ vertices and $|U|$ hyperedges. Elements with coverage requirement 0 get no hyperedge; multicover requirements above 1 are not expressible and are rejected.
+
+  _Correctness._ A subcollection ${S_j : j in J}$ covers $u$ iff some $j in J$ has $u in S_j$, i.e., iff ${v_j : j in J}$ meets $e_u$. Weights agree, so optimal covers correspond to optimal hypergraph vertex covers. An element in no set yields an empty hyperedge, and both sides are infeasible.
+
+  _Solution extraction._ Set $S_j$ is selected iff vertex $v_j$ is (same variable assignment).
+]
+
+#reduction-rule("MinimumVertexCover", "MinimumDominatingSet")[
+  This $O(n + m)$ reduction @garey1979 adds, for every edge ${u, v}$, a new vertex $w_(u v)$ adjacent to both endpoints while keeping the edge, so every edge becomes a triangle. Dominating $w_(u v)$ forces $u$, $v$ or $w_(u v)$ into the set, and $w_(u v)$ can always be traded for an endpoint, so minimum dominating sets correspond to minimum vertex covers.
+][
+  _Construction._ Given a vertex-weighted graph $G = (V, E)$ with positive weights $w$, build $G' = (V union {w_e : e in E}, E union {{u, w_e}, {v, w_e} : e = {u, v} in E})$ with $w(w_e) = max(w(u), w(v))$. The target has $n + m$ vertices and $3 m$ edges. Let $I$ be the set of isolated vertices of $G$.
+
+  _Correctness._ ($arrow.r.double$) If $C$ is a vertex cover, then $C union I$ dominates $G'$: every $w_e$ and every non-isolated vertex outside $C$ has a neighbor in $C$. ($arrow.l.double$) Let $D$ dominate $G'$. Replacing each $w_e in D$ by the lighter endpoint of $e$ keeps $D$ dominating and does not increase its weight. Afterwards every $w_e$ is dominated by an endpoint, so $D without I$ covers $E$, and $I subset.eq D$ because isolated vertices dominate only themselves. Hence the target optimum is the source optimum plus $w(I)$.
+
+  _Solution extraction._ Keep the selected original vertices, add the lighter endpoint of every selected $w_e$ whose edge is still uncovered, and drop isolated vertices.
 ]
 
 #reduction-rule("MinimumVertexCover", "EnsembleComputation")[
@@ -11575,21 +12165,43 @@ where $P$ is a penalty weight large enough that any constraint violation costs m
 #reduction-rule("ILP", "QUBO")[
   A binary ILP optimizes a linear objective over binary variables subject to linear constraints. The penalty method converts each equality constraint $bold(a)_k^top bold(x) = b_k$ into the quadratic penalty $(bold(a)_k^top bold(x) - b_k)^2$, which is zero if and only if the constraint is satisfied. Inequality constraints are first converted to equalities using binary slack variables with powers-of-two coefficients. The resulting unconstrained quadratic over binary variables is a QUBO whose matrix $Q$ combines the negated objective (as diagonal terms) with the expanded constraint penalties (as a Gram matrix $A^top A$).
 ][
-  _Construction._ First, normalize all constraints to equalities. Inequalities $bold(a)_k^top bold(x) <= b_k$ become $bold(a)_k^top bold(x) + sum_(s=0)^(S_k - 1) 2^s y_(k,s) = b_k$ where $S_k = ceil(log_2 (b_k + 1))$ binary slack bits. For $>=$ constraints, the slack has a negative sign. The extended system is $A' bold(x)' = bold(b)$ with $bold(x)' = (bold(x), bold(y)) in {0,1}^(n')$. For minimization, negate $bold(c)$ to convert to maximization.
+  _Construction._ First, normalize all constraints to equalities. Let $delta$ be the grid resolution of the constraint data: the largest $10^(-d)$, $d <= 6$, of which every entry of $A$ and $bold(b)$ is a multiple ($delta = 1$ for integer data). If no such $delta$ exists (e.g., an entry $1\/3$), the reduction falls back to $delta = 1$ with the slack step count rounded down, and its optimum is no longer guaranteed to be feasible. Inequalities $bold(a)_k^top bold(x) <= b_k$ become $bold(a)_k^top bold(x) + delta sum_(s=0)^(S_k - 1) 2^s y_(k,s) = b_k$ where $S_k = ceil(log_2 (r_k \/ delta + 1))$ binary slack bits cover the slack range $r_k = b_k - min_bold(x) bold(a)_k^top bold(x)$. For $>=$ constraints, the slack has a negative sign. The extended system is $A' bold(x)' = bold(b)$ with $bold(x)' = (bold(x), bold(y)) in {0,1}^(n')$. For minimization, negate $bold(c)$ to convert to maximization.
 
   Applying the penalty method (@sec:penalty-method), combine the negated objective with quadratic constraint penalties:
   $ f(bold(x)') = -bold(c')^top bold(x)' + P sum_(k=1)^m (bold(a)'_k^(top) bold(x)' - b_k)^2 $
-  where $bold(c)' = (bold(c), bold(0))$ and $P = 1 + ||bold(c)||_1 + ||bold(b)||_1$. Expanding the quadratic penalty:
+  where $bold(c)' = (bold(c), bold(0))$ and the penalty weight defaults to $P = (||bold(c)||_1 + 1) \/ delta^2$ (an explicit $P$ may be supplied instead). Expanding the quadratic penalty:
   $ sum_k (bold(a)'_k^(top) bold(x)' - b_k)^2 = bold(x)'^(top) A'^(top) A' bold(x)' - 2 bold(b)^top A' bold(x)' + ||bold(b)||_2^2 $
   Combining with $-bold(c')^top bold(x)'$ and dropping the constant $||bold(b)||_2^2$:
   $ Q = -"diag"(bold(c)' + 2P bold(b)^top A') + P A'^(top) A' $
   The diagonal contains linear terms (objective plus constraint); the upper triangle of $A'^(top) A'$ gives quadratic cross-terms.
 
-  _Correctness._ ($arrow.r.double$) If $bold(x)'^*$ is an optimal ILP solution, then $A' bold(x)'^* = bold(b)$ and all penalty terms vanish, so $f(bold(x)'^*) = -bold(c')^top bold(x)'^*$. ($arrow.l.double$) If any constraint is violated, $(bold(a)'_k^(top) bold(x)' - b_k)^2 >= 1$ and the penalty $P > ||bold(c)||_1$ exceeds the entire objective range, so $bold(x)'$ cannot be a QUBO minimizer. Among feasible assignments (all penalties zero), $f$ reduces to $-bold(c')^top bold(x)'$, minimized at the ILP optimum.
+  _Correctness._ ($arrow.r.double$) If $bold(x)'^*$ is an optimal ILP solution, then $A' bold(x)'^* = bold(b)$ and all penalty terms vanish, so $f(bold(x)'^*) = -bold(c')^top bold(x)'^*$. ($arrow.l.double$) All entries of $A'$ and $bold(b)$ are multiples of $delta$, so any violated constraint has $(bold(a)'_k^(top) bold(x)' - b_k)^2 >= delta^2$ and contributes at least $P delta^2 > ||bold(c)||_1$, which exceeds the entire objective range. This bound $P > ||bold(c)||_1 \/ delta^2$ is what guarantees a feasible QUBO optimum; smaller fixed penalties can let an infeasible assignment win, so $bold(x)'$ cannot be a QUBO minimizer. Among feasible assignments (all penalties zero), $f$ reduces to $-bold(c')^top bold(x)'$, minimized at the ILP optimum.
 
   _Solution extraction._ Discard slack variables: return $bold(x)' [0..n]$.
 ]
 
+#reduction-rule("PseudoBoolean", "ILP")[
+  A pseudo-Boolean instance is already a binary ILP with integer data and a minimized objective.
+][
+  _Construction._ Keep the $n$ variables as binary ILP variables, copy every constraint $bold(a)_k^top bold(x) space.thin ast_k space.thin b_k$ term by term, and minimize $bold(c)^top bold(x)$.
+
+  _Correctness._ Both problems have the same variables, feasible set and objective.
+
+  _Solution extraction._ Identity.
+]
+
+#reduction-rule("PseudoBoolean", "QUBO")[
+  Integer coefficients make the penalty method exact with a small weight: every violated constraint misses its right-hand side by at least one.
+][
+  _Construction._ Turn $bold(a)_k^top bold(x) >= b_k$ into $bold(a)_k^top bold(x) - sum_(s=0)^(S_k - 1) 2^s y_(k,s) = b_k$ with $S_k = ceil(log_2 (r_k + 1))$ slack bits for the range $r_k = sum_i max(a_(k,i), 0) - b_k$, and $<=$ constraints symmetrically with $+$ slack and $r_k = b_k - sum_i min(a_(k,i), 0)$; equalities need no slack. The QUBO minimizes
+  $ f(bold(x), bold(y)) = bold(c)^top bold(x) + P sum_k (bold(a)'_k^(top) (bold(x), bold(y)) - b_k)^2, quad P = ||bold(c)||_1 + 1, $
+  expanded with $z^2 = z$ and the constants $P b_k^2$ dropped.
+
+  _Correctness._ Feasible $bold(x)$ admit slack values zeroing all penalties, so $f = bold(c)^top bold(x)$. An infeasible assignment has an integer residual of magnitude at least $1$ in some constraint, so its penalty alone is at least $P > max bold(c)^top bold(x) - min bold(c)^top bold(x)$.
+
+  _Solution extraction._ Discard the slack bits.
+]
+
 #let part_cpi = load-example("Partition", "CosineProductIntegration")
 #let part_cpi_sol = part_cpi.solutions.at(0)
 #let part_cpi_sizes = part_cpi.source.instance.sizes
@@ -11807,6 +12419,18 @@ where $P$ is a penalty weight large enough that any constraint violation costs m
   _Solution extraction._ The $n$-variable QUBO assignment directly encodes the vertex-side assignment: $x_v = 0$ means side $A$, $x_v = 1$ means side $B$.
 ]
 
+#reduction-rule("MaxBisection", "QUBO")[
+  Max Bisection is encoded as a QUBO with one binary variable per vertex by negating the weighted cut objective and adding the same balance penalty as for Graph Partitioning, with the penalty scaled by the total absolute edge weight.
+][
+  _Construction._ Given $G = (V, E)$ with $n = |V|$ and edge weights $w$, set $P = sum_(e in E) |w(e)| + 1$ and minimize
+  $ H(bold(x)) = -sum_((u,v) in E) w(u, v) (x_u + x_v - 2 x_u x_v) + P (sum_i x_i - n\/2)^2. $
+  Dropping the constant $P n^2\/4$, the QUBO matrix is $Q_(i i) = -w(i) + P(1 - n)$, where $w(i)$ is the total weight of edges at $i$, and $Q_(i j) = 2P + 2 w(i, j)$ for $i < j$, where $w(i, j) = 0$ for non-edges.
+
+  _Correctness._ ($arrow.r.double$) A balanced partition with cut weight $c$ has zero penalty and energy $-c$. ($arrow.l.double$) An imbalanced assignment pays at least $P$, which exceeds the difference $sum_e |w(e)|$ between any two cut values, so every minimizer is balanced, and among balanced assignments the energy is the negated cut weight.
+
+  _Solution extraction._ The QUBO assignment is the vertex-side assignment: $x_v = 0$ means side $A$, $x_v = 1$ means side $B$.
+]
+
 #let qubo_ilp = load-example("QUBO", "ILP")
 #let qubo_ilp_sol = qubo_ilp.solutions.at(0)
 #reduction-rule("QUBO", "ILP",
@@ -11930,6 +12554,36 @@ where $P$ is a penalty weight large enough that any constraint violation costs m
   _Solution extraction._ For $v_(j,i) in S$ with literal $x_k$: set $x_k = 1$; for $overline(x_k)$: set $x_k = 0$.
 ]
 
+#let sat_clq = load-example("Satisfiability", "MaximumClique")
+#let sat_clq_sol = sat_clq.solutions.at(0)
+#reduction-rule("Satisfiability", "MaximumClique",
+  example: true,
+  example-caption: [SAT with 3 variables and 3 clauses],
+  extra: [
+    #pred-commands(
+      "pred create --example SAT -o sat.json",
+      "pred reduce sat.json --to " + target-spec(sat_clq) + " -o bundle.json",
+      "pred solve bundle.json",
+      "pred evaluate sat.json --config " + sat_clq_sol.source_config.map(str).join(","),
+    )
+    SAT assignment: $(x_1, x_2, x_3) = (#sat_clq_sol.source_config.map(str).join(", "))$ \
+    Clique graph: #graph-num-vertices(sat_clq.target.instance) vertices (one per literal occurrence), #graph-num-edges(sat_clq.target.instance) edges \
+    Clique of size #sat-num-clauses(sat_clq.source.instance) $= m$: one compatible literal per clause $arrow.r$ satisfying assignment #sym.checkmark
+  ],
+)[
+  @karp1972 This is the complement view of the independent-set construction: instead of forbidding two literals from the same clause or two complementary literals, the graph connects exactly the pairs that _may_ be chosen together. A clique therefore picks at most one literal per clause, all mutually consistent, and the clique number equals the number of clauses $m$ iff the formula is satisfiable.
+][
+  _Construction._ For $phi = and.big_(j=1)^m C_j$ with $C_j = (ell_(j,1) or ... or ell_(j,k_j))$:
+
+  _Vertices:_ For each literal $ell_(j,i)$ in clause $C_j$, create $v_(j,i)$. Total: $|V| = sum_j k_j$, all with weight 1.
+
+  _Edges:_ $E = {(v_(j,i), v_(j',i')) : j != j', ell_(j,i) != overline(ell_(j',i'))}$, so $|E| = O((sum_j k_j)^2)$.
+
+  _Correctness._ ($arrow.r.double$) A satisfying assignment selects one true literal per clause; these $m$ vertices lie in distinct clauses and are never complementary, so they form a clique. ($arrow.l.double$) Vertices of one clause are pairwise non-adjacent, so a clique of size $m$ contains exactly one vertex per clause; its literals are pairwise consistent and can all be made true, satisfying $phi$.
+
+  _Solution extraction._ For $v_(j,i) in K$ with literal $x_k$: set $x_k = 1$; for $overline(x_k)$: set $x_k = 0$. Unconstrained variables default to $0$.
+]
+
 #let sat_kc = load-example("Satisfiability", "KColoring")
 #let sat_kc_sol = sat_kc.solutions.at(0)
 #reduction-rule("Satisfiability", "KColoring",
@@ -11984,6 +12638,32 @@ where $P$ is a penalty weight large enough that any constraint violation costs m
   _Solution extraction._ Set $x_i = 1$ if $"pos"_i$ selected; $x_i = 0$ if $"neg"_i$ selected.
 ]
 
+#let sat_vc = load-example("Satisfiability", "MinimumVertexCover")
+#let sat_vc_sol = sat_vc.solutions.at(0)
+#reduction-rule("Satisfiability", "MinimumVertexCover",
+  example: true,
+  example-caption: [SAT with clauses of width 2 and 3 to vertex cover],
+  extra: [
+    #pred-commands(
+      "pred create --example SAT -o sat.json",
+      "pred reduce sat.json --to " + target-spec(sat_vc) + " -o bundle.json",
+      "pred solve bundle.json",
+      "pred evaluate sat.json --config " + sat_vc_sol.source_config.map(str).join(","),
+    )
+    SAT assignment: $(x_1, x_2, x_3) = (#sat_vc_sol.source_config.map(str).join(", "))$ \
+    VC graph: #graph-num-vertices(sat_vc.target.instance) vertices ($2n$ literal vertices + one per literal occurrence; no clause is longer than 3), #graph-num-edges(sat_vc.target.instance) edges \
+    Cover of size #sat_vc_sol.target_config.filter(x => x == 1).len() $= n + sum_j (k_j - 1)$: the threshold is met $arrow.r$ satisfying assignment #sym.checkmark
+  ],
+)[
+  @garey1979 The 3-SAT construction of Garey and Johnson, applied after splitting long clauses into 3-clauses and skipping variables that occur in no clause. The graph has at most $7 L$ vertices and $8 L$ edges for $L = sum_j k_j$ literal occurrences, so it is linear in the total clause length; a clique per clause would have $sum_j binom(k_j, 2)$ edges instead. The formula is satisfiable iff the graph has a vertex cover of size $n' + sum_j (k_j - 1)$ over the split formula, which is $n + 2m$ for 3-SAT in which every variable occurs.
+][
+  _Construction._ For $phi = and.big_(j=1)^m C_j$ with $C_j = (ell_(j,1) or ... or ell_(j,k_j))$, first replace each clause with $k > 3$ literals by $(ell_1 or ell_2 or y_1) and (not y_1 or ell_3 or y_2) and ... and (not y_(k-3) or ell_(k-1) or ell_k)$ over $k - 3$ fresh variables; this preserves satisfiability, and the restriction of a satisfying assignment is one for $phi$. Let $n'$ be the number of variables that occur in the resulting formula. (1) _Truth-setting edges_ $(u_i, overline(u)_i)$ for each of them, with $u_i$ at index $2i$ and $overline(u)_i$ at $2i+1$. (2) _Clause clique_ on vertices $t_(j,1), ..., t_(j,k_j)$ for each clause, with $k_j <= 3$. (3) _Communication edges_ from $t_(j,i)$ to the literal vertex of $ell_(j,i)$. All weights are 1, so $|V| = 2n' + sum_j k_j$ and $|E| = n' + sum_j binom(k_j, 2) + sum_j k_j$.
+
+  _Correctness._ Every cover contains an endpoint of each truth-setting edge and all but at most one vertex of each clique, so it has size at least $n' + sum_j (k_j - 1)$. ($arrow.r.double$) Given a satisfying assignment, cover the true literal vertices and, in each clause, every clique vertex except one whose literal is true. ($arrow.l.double$) A cover of threshold size has exactly one literal vertex per variable and leaves exactly one vertex $t_(j,i)$ per clique uncovered; its communication edge forces $ell_(j,i)$ into the cover, so setting the covered literals true satisfies every clause. An empty clause lowers the threshold without adding vertices, so it can never be met.
+
+  _Solution extraction._ Set $x_i = 1$ iff $u_i$ is in the cover, i.e.\ the uncovered literal vertex of each variable is the false one. Variables that occur in no clause are set to 0, and the auxiliary $y$ variables are dropped.
+]
+
 #let sat_ifha = load-example("Satisfiability", "IntegralFlowHomologousArcs")
 #let sat_ifha_sol = sat_ifha.solutions.at(0)
 #reduction-rule("Satisfiability", "IntegralFlowHomologousArcs",
@@ -12169,6 +12849,26 @@ where $P$ is a penalty weight large enough that any constraint violation costs m
   _Solution extraction._ If the sentinel $s = 0$, return the first $n$ variables. If $s = 1$, return the complement of the first $n$ variables.
 ]
 
+#reduction-rule("XorSatisfiability", "Satisfiability")[
+  Keep the CNF clauses and expand each XOR clause into CNF by forbidding its even-parity assignments, chaining long XORs through auxiliary variables so every expanded piece has at most three literals.
+][
+  _Construction._ A parity $ell_1 xor dots xor ell_k = 1$ with $k <= 3$ becomes the $2^(k-1)$ clauses that each exclude one assignment with an even number of true literals; the empty parity becomes the empty clause. For $k > 3$, introduce a fresh variable $a$, emit the 3-literal parity $ell_1 xor ell_2 xor not a = 1$ (i.e. $a = ell_1 xor ell_2$), and continue with $a xor ell_3 xor dots xor ell_k = 1$. A $k$-literal parity thus costs $k - 3$ auxiliaries and $4(k - 2)$ clauses.
+
+  _Correctness._ Each chaining step replaces $ell_1 xor ell_2$ by an equal variable, so the parity is preserved, and the short expansions are exact. Every source solution extends uniquely to the auxiliaries, and every target solution restricts to a source solution.
+
+  _Solution extraction._ Return the first $n$ variables.
+]
+
+#reduction-rule("XorSatisfiability", "PolySpinGlass")[
+  Map each clause to a penalty polynomial in the spins $s_i = 2 x_i - 1$ that vanishes exactly on satisfying assignments; parities become single multi-spin terms.
+][
+  _Construction._ Write $sigma_ell = s_i$ for a positive literal on $x_i$ and $sigma_ell = -s_i$ for a negated one, and let $M = 2^max(1, k_max)$ with $k_max$ the largest CNF clause size. A $k$-literal parity holds iff $(-1)^(k-1) product_ell sigma_ell = 1$, so its penalty is $M/2 (1 - (-1)^(k-1) product_ell sigma_ell)$. A CNF clause is violated iff all its literals are false, giving the penalty $M product_ell (1 - sigma_ell) / 2$, expanded into one term per literal subset with integer coefficients $plus.minus M / 2^k$. Monomials are reduced with $s_i^2 = 1$ and merged, single-spin terms become fields, and the constant is dropped.
+
+  _Correctness._ Each violated clause contributes exactly $M$ and each satisfied clause $0$, so the Hamiltonian equals $M$ times the number of violated clauses minus the dropped constant. The formula is satisfiable iff the ground-state energy reaches this minimum, and the ground states are then exactly the satisfying assignments.
+
+  _Solution extraction._ Read $x_i = (s_i + 1) / 2$.
+]
+
 #let cs_sat = load-example("CircuitSAT", "Satisfiability")
 #let cs_sat_sol = cs_sat.solutions.at(0)
 #reduction-rule("CircuitSAT", "Satisfiability",
@@ -12287,7 +12987,9 @@ where $P$ is a penalty weight large enough that any constraint violation costs m
 
   _Output constraint:_ Fix output wires to the binary representation of $N$: $M_k := "bit"_k(N)$ for $k = 1, dots, m+n$.
 
-  _Correctness._ ($arrow.r.double$) If $N = p times q$ with $p < 2^m$ and $q < 2^n$, setting the input bits to the binary representations of $p$ and $q$ produces output bits matching $N$, satisfying all constraints. ($arrow.l.double$) Any satisfying assignment to the circuit computes a valid multiplication (the gates enforce arithmetic correctness), and the output constraint ensures the product equals $N$.
+  _Nontrivial factors:_ $p_"nt" := or.big_(i >= 1) p_i$ and $q_"nt" := or.big_(j >= 1) q_j$, both fixed to true, so $p, q >= 2$.
+
+  _Correctness._ ($arrow.r.double$) If $N = p times q$ with $2 <= p < 2^m$ and $2 <= q < 2^n$, setting the input bits to the binary representations of $p$ and $q$ produces output bits matching $N$, satisfying all constraints. ($arrow.l.double$) Any satisfying assignment to the circuit computes a valid multiplication (the gates enforce arithmetic correctness), and the output constraint ensures the product equals $N$.
 
   _Solution extraction._ Read off factor bits: $p = sum_i p_i 2^(i-1)$, $q = sum_j q_j 2^(j-1)$.
 ]
@@ -12395,6 +13097,8 @@ where $P$ is a penalty weight large enough that any constraint violation costs m
 
   _No overflow:_ $c_(m+n-1) = 0$.
 
+  _Nontrivial factors:_ $sum_(i >= 1) p_i >= 1$ and $sum_(j >= 1) q_j >= 1$, so $p, q >= 2$.
+
   The ILP is:
   $
     "find" quad & bold(x) \
@@ -12403,6 +13107,7 @@ where $P$ is a penalty weight large enough that any constraint violation costs m
     & z_(i j) >= p_i + q_j - 1 quad forall i, j \
     & sum_(i+j=k) z_(i j) + c_(k-1) = N_k + 2 c_k quad forall k in {0, dots, m + n - 1} \
     & c_(m+n-1) = 0 \
+    & sum_(i >= 1) p_i >= 1, quad sum_(j >= 1) q_j >= 1 \
     & p_i, q_j, z_(i j) in {0, 1}, c_k in ZZ_(>=0).
   $
 
@@ -12475,6 +13180,31 @@ The following reductions to Integer Linear Programming are straightforward formu
   _Solution extraction._ $D = {v : x_v = 1}$.
 ]
 
+#reduction-rule("MinimumEdgeDominatingSet", "MinimumDominatingSet")[
+  An edge set dominates $G$ exactly when the corresponding vertex set dominates the line graph $L(G)$ @yannakakis1980.
+][
+  _Construction._ Build $L(G)$ with one vertex $v_e$ per edge $e in E$, joining $v_e$ and $v_f$ iff $e$ and $f$ share an endpoint, and give $v_e$ weight $w(e)$. $L(G)$ has $|E|$ vertices and at most $binom(|E|, 2)$ edges.
+
+  _Correctness._ $e$ is dominated by $D subset.eq E$ iff $e in D$ or some $f in D$ shares an endpoint with $e$, i.e. iff $v_e$ is in ${v_f : f in D}$ or adjacent to it in $L(G)$. Weights are preserved, so optima coincide.
+
+  _Solution extraction._ $D = {e : v_e "selected"}$.
+]
+
+#reduction-rule("MinimumEdgeDominatingSet", "ILP")[
+  Every edge must be selected or share an endpoint with a selected edge, which is a lower-bound constraint on the edges incident to its endpoints.
+][
+  _Construction._ Variables: $x_e in {0, 1}$ for each $e in E$. Writing $delta(v)$ for the edges incident to $v$, the ILP is:
+  $
+    min quad & sum_e w_e x_e \
+    "subject to" quad & sum_(f in delta(u) union delta(v)) x_f >= 1 quad forall e = {u, v} in E \
+    & x_e in {0, 1} quad forall e in E.
+  $
+
+  _Correctness._ ($arrow.r.double$) An edge dominating set selects, for every edge, the edge itself or an edge at one of its endpoints. ($arrow.l.double$) Any feasible solution dominates every edge; the objective minimizes total weight.
+
+  _Solution extraction._ $D = {e : x_e = 1}$.
+]
+
 #let mfdts_ilp = load-example("MinimumFaultDetectionTestSet", "ILP")
 #let mfdts_ilp_sol = mfdts_ilp.solutions.at(0)
 #reduction-rule("MinimumFaultDetectionTestSet", "ILP",
@@ -13386,6 +14116,18 @@ The following reductions to Integer Linear Programming are straightforward formu
   _Solution extraction._ $I = {v : x_v = 1}$.
 ]
 
+#reduction-rule("MaximalIS", "Satisfiability")[
+  A maximal independent set is exactly an independent dominating set, so both conditions are clausal: $n$ variables and $n + |E|$ clauses with $n + 4|E|$ literal occurrences. Satisfying assignments are in bijection with maximal independent sets. Vertex weights are not encoded, so one query preserves only feasibility; the rule is a Turing reduction that enumerates maximal independent sets, blocking each one found, and keeps the heaviest.
+][
+  _Construction._ Introduce one Boolean variable $x_v$ per vertex $v in V$. For every edge $(u, v) in E$ add the independence clause $(not x_u or not x_v)$. For every vertex $u in V$ add the maximality clause $(x_u or or.big_(w in N(u)) x_w)$.
+
+  _Correctness._ ($arrow.r.double$) If $S$ is a maximal independent set, setting $x_v = 1$ iff $v in S$ satisfies every independence clause, and every vertex $u in.not S$ has a neighbor in $S$ (otherwise $S union {u}$ would be independent), so every maximality clause holds. ($arrow.l.double$) A satisfying assignment selects no two adjacent vertices, and every unselected vertex has a selected neighbor, so it cannot be added: the selected set is independent and maximal.
+
+  _Search._ Distinct maximal independent sets are incomparable, so after finding $S$ the blocking clause $(or.big_(v in.not S) x_v)$ excludes exactly $S$. Querying until the formula is unsatisfiable lists every maximal independent set; the heaviest is the optimum.
+
+  _Solution extraction._ Identity: $v in S$ iff $x_v = 1$.
+]
+
 #reduction-rule("MinimumMaximalMatching", "ILP")[
   Each edge is either selected or not; matching and maximality constraints are both directly linear in binary edge indicators.
 ][
@@ -13441,6 +14183,24 @@ The following reductions to Integer Linear Programming are straightforward formu
   _Solution extraction._ Selected items: ${i : x_i = 1}$.
 ]
 
+#reduction-rule("RectanglePacking", "ILP")[
+  Position each rectangle with integer corner variables and encode pairwise non-overlap as a four-way big-$M$ disjunction.
+][
+  _Construction._ Variables: integers $x_i, y_i >= 0$ and a rotation bit $r_i in {0, 1}$ per rectangle, plus binary selectors $s_(i j)^k$ for each pair $i < j$ and direction $k in {"L", "R", "B", "A"}$. Let $w'_i = w_i + (h_i - w_i) r_i$ and $h'_i = h_i + (w_i - h_i) r_i$ be the oriented sides. The ILP is:
+  $
+    "find" quad & (bold(x), bold(y), bold(r), bold(s)) \
+    "subject to" quad & x_i + w'_i <= W, quad y_i + h'_i <= H quad forall i \
+    & x_i + w'_i <= x_j + W (1 - s_(i j)^"L"), quad x_j + w'_j <= x_i + W (1 - s_(i j)^"R") quad forall i < j \
+    & y_i + h'_i <= y_j + H (1 - s_(i j)^"B"), quad y_j + h'_j <= y_i + H (1 - s_(i j)^"A") quad forall i < j \
+    & sum_k s_(i j)^k >= 1 quad forall i < j \
+    & r_i = 0 "if rotation is not allowed".
+  $
+
+  _Correctness._ ($arrow.r.double$) A valid packing separates every pair along some axis; setting the matching selector to 1 and the others to 0 satisfies all constraints. ($arrow.l.double$) Each pair has an active selector whose constraint forces separation in that direction. Containment bounds every coordinate difference by $W$ (resp. $H$), so inactive constraints are always satisfied.
+
+  _Solution extraction._ Read $(x_i, y_i)$, and $r_i$ when rotation is allowed.
+]
+
 #reduction-rule("RectilinearPictureCompression", "ILP")[
   Cover all 1-cells with at most $B$ maximal all-1 rectangles.
 ][
@@ -13529,6 +14289,22 @@ The following reductions to Integer Linear Programming are straightforward formu
   _Solution extraction._ Centers: ${j : x_j = 1}$.
 ]
 
+#reduction-rule("KCenter", "ILP")[
+  Encode the bottleneck radius with one binary threshold variable per distinct distance, so the ILP stays purely binary.
+][
+  _Construction._ Let $0 = r_0 < r_1 < dots < r_(T-1)$ be the distinct entries of $d$. Variables: binary $x_j$ (site $j$ is a center) and binary $u_t$ for $t = 1, dots, T-1$ (the radius is at least $r_t$). The ILP is:
+  $
+    "minimize" quad & sum_(t=1)^(T-1) (r_t - r_(t-1)) u_t \
+    "subject to" quad & sum_j x_j = K \
+    & sum_(j : d(i,j) <= r_(t-1)) x_j + u_t >= 1 quad forall i, t \
+    & x_j, u_t in {0, 1}.
+  $
+
+  _Correctness._ For fixed centers with radius $r_s$, every site is covered within $r_t$ for $t >= s$, while some site is uncovered within $r_(t-1)$ for each $t <= s$. Hence the cheapest feasible $u$ sets exactly $u_1, dots, u_s$, and the objective telescopes to $r_s$. Minimizing over $x$ yields the optimal radius.
+
+  _Solution extraction._ Centers: ${j : x_j = 1}$.
+]
+
 #reduction-rule("MultiprocessorScheduling", "ILP")[
   Assign tasks to processors so that no processor's total load exceeds the deadline.
 ][
@@ -13545,6 +14321,38 @@ The following reductions to Integer Linear Programming are straightforward formu
   _Solution extraction._ Task $j$ goes to processor $arg max_p x_(j,p)$.
 ]
 
+#reduction-rule("MinimumMultiprocessorScheduling", "ILP")[
+  Assign tasks to processors and bound every processor's load by a makespan variable that is minimized.
+][
+  _Construction._ Variables: binary $x_(j,p)$ (task $j$ on processor $p$) and an integer makespan $C$. The ILP is:
+  $
+    min quad & C \
+    "subject to" quad & sum_p x_(j,p) = 1 quad forall j \
+    & sum_j l_j x_(j,p) <= C quad forall p \
+    & x_(j,p) in {0, 1}, quad C in ZZ_(>= 0).
+  $
+
+  _Correctness._ One-hot constraints assign each task to exactly one processor. $C$ is at least every load, and minimization drives it down to the largest one, which is the makespan.
+
+  _Solution extraction._ Task $j$ goes to processor $arg max_p x_(j,p)$.
+]
+
+#reduction-rule("KTrackIntervalScheduling", "ILP")[
+  Place each interval on at most one track and cap every track by the intervals that are active at a common time.
+][
+  _Construction._ Variables: binary $x_(i,t)$ (interval $i$ on track $t$). The ILP is:
+  $
+    max quad & sum_(i,t) w_i x_(i,t) \
+    "subject to" quad & sum_t x_(i,t) <= 1 quad forall i \
+    & sum_(j: s_j <= s_i < e_j) x_(j,t) <= 1 quad forall i, t \
+    & x_(i,t) in {0, 1}.
+  $
+
+  _Correctness._ Intervals active at one time point pairwise overlap, so each capacity row is valid. Conversely, two overlapping intervals are both active at the later of their start times, so every conflict appears in some row. The $n K$ rows therefore forbid exactly the overlapping pairs on each track.
+
+  _Solution extraction._ Interval $i$ goes to track $t + 1$ if $x_(i,t) = 1$ and is rejected otherwise.
+]
+
 #reduction-rule("CapacityAssignment", "ILP")[
   Assign a capacity level to each link to minimize total cost subject to a delay budget.
 ][
@@ -13596,6 +14404,16 @@ The following reductions to Integer Linear Programming are straightforward formu
   _Solution extraction._ Vertex $v$ goes to group $arg max_g x_(v,g)$.
 ]
 
+#reduction-rule("PartitionIntoTriangles", "ExactCoverBy3Sets")[
+  The triangles of $G$ are the candidate blocks of an exact cover of $V$.
+][
+  _Construction._ The universe is $V$ and the collection contains one triple ${u, v, w}$ for every triangle of $G$, at most $n m$ of them.
+
+  _Correctness._ A partition of $V$ into triangles is a set of pairwise disjoint triangles covering every vertex, which is exactly an exact cover by the listed triples.
+
+  _Solution extraction._ Number the selected triples in order; each vertex goes to the group of the triple covering it.
+]
+
 #reduction-rule("PartitionIntoPathsOfLength2", "ILP")[
   Partition vertices into groups of 3 such that each group induces a path of length 2 (at least 2 edges within the group).
 ][
@@ -14032,6 +14850,38 @@ The following reductions to Integer Linear Programming are straightforward formu
   _Solution extraction._ Output the binary edge-selection vector $(y_e)_(e in E)$.
 ]
 
+#reduction-rule("MinimumSteinerForest", "ILP")[
+  Select edges and certify every demand by routing one unit of its own flow commodity from $s_i$ to $t_i$ through the selected subgraph. The binary ILP has $m + 2 m k$ variables and $n k + 2 m k$ constraints for $k$ terminal pairs.
+][
+  _Construction._ Given $G = (V, E, w)$ with strictly positive edge weights and pairs $(s_1, t_1), dots, (s_k, t_k)$, introduce binary edge selectors $y_e$ for every $e in E$ and, for every pair $i$, binary flow variables $f^i_(u,v)$ and $f^i_(v,u)$ on both orientations of every edge $\{u, v\}$. The ILP is:
+  $
+    min quad & sum_(e in E) w_e y_e \
+    "subject to" quad & sum_(u) f^i_(u,v) - sum_(u) f^i_(v,u) = b_(i,v) quad forall i in {1, dots, k}, v in V \
+    & f^i_(u,v) <= y_e, quad f^i_(v,u) <= y_e quad forall e = {u, v} in E, i in {1, dots, k} \
+    & y_e, f^i_(u,v) in {0, 1},
+  $
+  where $b_(i,v) = -1$ if $v = s_i$, $b_(i,v) = 1$ if $v = t_i$, and $b_(i,v) = 0$ otherwise.
+
+  _Correctness._ ($arrow.r.double$) In a Steiner forest each pair is joined by a unique path inside its tree; routing commodity $i$ along that path satisfies conservation and uses only selected edges, at the same total weight. ($arrow.l.double$) A feasible flow for commodity $i$ certifies an $s_i$--$t_i$ path in the selected subgraph, so every demand is connected. If the selected subgraph contained a cycle, removing any cycle edge would keep every pair connected and strictly decrease the positive-weight objective, so an optimal solution is a forest.
+
+  _Solution extraction._ Output the binary edge-selection vector $(y_e)_(e in E)$, the first $m$ target variables.
+]
+
+#reduction-rule("ClusterEditing", "ILP")[
+  Decide for every vertex pair whether it shares a cluster, and enforce transitivity on every triple so that the decisions form an equivalence relation @grotschel1989cutting. The binary ILP has $n(n-1)/2$ variables and $3 binom(n, 3)$ constraints.
+][
+  _Construction._ Given $G = (V, E, w)$, introduce a binary variable $x_(u v)$ for every pair $u < v$. The ILP is:
+  $
+    min quad & sum_({u, v} in.not E) x_(u v) - sum_({u, v} in E) w_(u v) x_(u v) \
+    "subject to" quad & x_(u v) + x_(v t) - x_(u t) <= 1, quad x_(u v) + x_(u t) - x_(v t) <= 1, quad x_(u t) + x_(v t) - x_(u v) <= 1 quad forall u < v < t \
+    & x_(u v) in {0, 1}.
+  $
+
+  _Correctness._ ($arrow.r.double$) A partition induces $x_(u v) = 1$ exactly for co-clustered pairs, which is transitive, so all triangle constraints hold. ($arrow.l.double$) The triangle constraints forbid $x_(u v) = x_(v t) = 1$ with $x_(u t) = 0$ for every choice of middle vertex, so $x$ is an equivalence relation whose classes form a partition. In both directions the editing cost $sum_(e in E) w_e (1 - x_e) + sum_({u, v} in.not E) x_(u v)$ equals the ILP objective plus the constant $sum_(e in E) w_e$, so optimal solutions correspond.
+
+  _Solution extraction._ Label each vertex $v$ with the smallest $u <= v$ such that $u = v$ or $x_(u v) = 1$.
+]
+
 // Scheduling
 
 #reduction-rule("FlowShopScheduling", "ILP")[
@@ -14471,6 +15321,23 @@ The following reductions to Integer Linear Programming are straightforward formu
   _Solution extraction._ Output the concatenated left/right binary selection vector.
 ]
 
+#reduction-rule("MaximumBalancedBiclique", "ILP")[
+  Replace the two cardinality constraints of the decision version by a single balance constraint and maximize the size of the left side.
+][
+  _Construction._ Let $L$ and $R$ be the bipartition. Variables: binary $x_l$ for $l in L$ and $y_r$ for $r in R$. The ILP is:
+  $
+    max quad & sum_(l in L) x_l \
+    "subject to" quad & sum_(l in L) x_l - sum_(r in R) y_r = 0 \
+    & x_l + y_r <= 1 quad forall (l, r) in.not E \
+    & x_l, y_r in {0, 1}.
+  $
+  This has $|L| + |R|$ variables and $|L| |R| - |E| + 1$ constraints.
+
+  _Correctness._ ($arrow.r.double$) A balanced biclique $(A', B')$ has equal sides and no selected non-edge, so its indicator vector is feasible with objective $|A'|$. ($arrow.l.double$) In a feasible solution every selected left vertex is adjacent to every selected right vertex, and the balance row makes the sides equal, so the selection is a balanced biclique whose size is the objective value. The all-zero vector is always feasible.
+
+  _Solution extraction._ Output the concatenated left/right binary selection vector.
+]
+
 #reduction-rule("BiconnectivityAugmentation", "ILP")[
   Select candidate edges under the budget and, for every deleted vertex, certify that the remaining augmented graph stays connected by a flow witness.
 ][
@@ -14917,11 +15784,38 @@ The following reductions to Integer Linear Programming are straightforward formu
 
   Adjacent pairs where both positions are the same car always produce a switch (constant term), and are skipped. The QUBO objective is $min bold(x)^top Q bold(x)$; the minimum number of color switches equals the QUBO minimum plus the total constant offset (number of different-parity pairs plus number of same-car pairs).
 
+  The "each car gets both colors exactly once" constraint carries no penalty. An occurrence-level encoding with variables $y_1, y_2$ per car would add $P (y_1 + y_2 - 1)^2$; substituting $y_2 = 1 - y_1$ makes this term identically zero, so one variable per car is exact for any $P$.
+
   _Correctness._ ($arrow.r.double$) Any PaintShop coloring corresponds to a binary assignment $bold(x)$ with the same number of switches (up to the constant offset). ($arrow.l.double$) Any QUBO minimizer $bold(x)$ defines a valid coloring (each car's two occurrences get opposite colors), and the offset-adjusted objective equals the switch count. Since the correspondence is bijective and value-preserving, optimality is preserved.
 
   _Solution extraction._ The QUBO solution $(x_1, ..., x_n)$ maps directly back: car $i$'s first occurrence gets color $x_i$, second gets $1 - x_i$.
 ]
 
+#let ps_sg = load-example("PaintShop", "SpinGlass")
+#let ps_sg_sol = ps_sg.solutions.at(0)
+#reduction-rule("PaintShop", "SpinGlass",
+  example: true,
+  example-caption: [4 cars, sequence length 8],
+  extra: [
+    #pred-commands(
+      "pred create --example " + problem-spec(ps_sg.source) + " -o paintshop.json",
+      "pred reduce paintshop.json --to " + target-spec(ps_sg) + " -o bundle.json",
+      "pred solve bundle.json",
+      "pred evaluate paintshop.json --config " + ps_sg_sol.source_config.map(str).join(","),
+    )
+    *Step 1 -- Couplings.* For the sequence $(A, B, C, A, D, B, D, C)$ the seven adjacent pairs give $J_(A B) = J_(B C) = J_(C D) = -1$ and $J_(A C) = J_(A D) = +1$; the pairs $(D, B)$ and $(B, D)$ have opposite parity and cancel, so $K = 7$. \
+    *Step 2 -- Verify.* The spins $bold(s) = (#ps_sg_sol.target_config.map(x => str(2 * x - 1)).join(", "))$ have energy $-3$, giving $(7 - 3) \/ 2 = 2$ color switches #sym.checkmark
+  ],
+)[
+  The Ising form of the PaintShop QUBO @Streif2021. Each car becomes one spin fixing the colors of both of its occurrences, and every adjacent pair of distinct cars becomes a ferromagnetic or antiferromagnetic coupling; the ground-state energy is an affine function of the minimum number of color switches.
+][
+  _Construction._ Introduce one spin $s_i = 2 x_i - 1$ per car, where $x_i$ is the color of car $i$'s first occurrence; the second occurrence gets $1 - x_i$. For each adjacent pair of positions $(j, j+1)$ with distinct cars $a, b$, add $-1$ to $J_(a b)$ if both positions have the same parity and $+1$ otherwise. Couplings of a pair that accumulate to zero are dropped, and all fields are $h_i = 0$. Let $K$ be the number of adjacent pairs of distinct cars plus twice the number of adjacent same-car pairs.
+
+  _Correctness._ A same-parity pair switches iff $s_a s_b = -1$ and a different-parity pair switches iff $s_a s_b = +1$, so each contributes $(1 + J s_a s_b) \/ 2$ switches; a same-car pair always contributes one. Summing gives $2 dot "switches" = K + sum_(a < b) J_(a b) s_a s_b$ for every assignment, so minimizing the energy minimizes the switch count. As in the QUBO form, one spin per car satisfies the "both colors exactly once" constraint by construction and needs no penalty.
+
+  _Solution extraction._ Read back $x_i = (s_i + 1) \/ 2$, the color of car $i$'s first occurrence.
+]
+
 #reduction-rule("PaintShop", "ILP")[
   One binary variable per car determines its first color, the second occurrence receives the opposite color automatically, and switch indicators count color changes along the sequence.
 ][
@@ -15493,7 +16387,9 @@ The following table shows concrete variable overhead for example instances, take
     #problem-def("MaximumDomaticNumber")[
       Given an undirected graph $G = (V, E)$, find the maximum $k$ such that $V$ can be partitioned into $k$ disjoint dominating sets $V_1, dots, V_k$ where each $V_i$ dominates all of $V$.
     ][
-      Maximum Domatic Number (GT3) @garey1979. NP-complete for any fixed $k >= 3$ (Garey, Johnson, Tarjan 1976). Polynomial for $k = 2$.
+      Maximum Domatic Number (GT3) @garey1979, also called Domatic Partition. NP-complete for any fixed $k >= 3$ (Garey, Johnson, Tarjan 1976). Polynomial for $k = 2$.
+
+      Every class of a domatic partition is a dominating set, so the domatic number $d(G)$ ties to Minimum Dominating Set through $d(G) dot gamma(G) <= n$, and $d(G) <= delta(G) + 1$ since a vertex of minimum degree must be dominated by every class. Complete graphs reach $d(K_n) = n$, while stars have $d(K_(1,m)) = 2$. Deciding $d(G) >= 3$ is the NP-complete case used in GT3.
 
       The best known exact algorithm runs in $O^*(2.695^n)$ (Riege, Rothe, Spakowski, Yamamoto 2007).
 
@@ -15782,6 +16678,16 @@ The following table shows concrete variable overhead for example instances, take
   _Solution extraction._ The processor assignment $p_i in {0, 1}$ is the partition assignment directly.
 ]
 
+#reduction-rule("Partition", "MinimumMultiprocessorScheduling")[
+  Each element $a_i$ becomes a task of length $a_i$ on $m = 2$ processors. A balanced partition exists iff the minimum makespan equals $S / 2$.
+][
+  _Construction._ Let $A = (a_1, dots, a_n)$ with total sum $S$. Set task lengths $ell_i = a_i$ and $m = 2$.
+
+  _Correctness._ The two loads sum to $S$, so every schedule has makespan at least $S / 2$, with equality iff both loads equal $S / 2$. ($arrow.r.double$) A balanced partition gives a schedule of makespan $S / 2$, which is optimal. ($arrow.l.double$) An optimal schedule of makespan $S / 2$ splits $A$ into two halves of equal sum; if the optimum exceeds $S / 2$, no balanced partition exists.
+
+  _Solution extraction._ The processor assignment $p_i in {0, 1}$ is the partition assignment directly.
+]
+
 #let hc_btsp = load-example("HamiltonianCircuit", "BottleneckTravelingSalesman")
 #let hc_btsp_sol = hc_btsp.solutions.at(0)
 #reduction-rule("HamiltonianCircuit", "BottleneckTravelingSalesman",
@@ -17018,6 +17924,36 @@ The following table shows concrete variable overhead for example instances, take
   _Solution extraction._ Given a partition $V_0, dots, V_(k-1)$ into cliques, assign color $i$ to every vertex in $V_i$.
 ]
 
+#reduction-rule("KColoring", "MaximumIndependentSet")[
+  This $O(K^2 n + K |E|)$ reduction @garey1979 builds one vertex per vertex-color pair. Each source vertex contributes a $K$-clique of its pairs and each source edge joins the pairs of its endpoints that share a color, so $G$ is $K$-colorable if and only if the target has an independent set of size $n$.
+][
+  _Construction._ Given a $K$-Coloring instance $(G = (V, E), K)$ with $n = |V|$, let $H$ have vertex set $V times {0, dots, K-1}$. Join $(v, c)$ and $(v, d)$ for every $v in V$ and $c != d$, and join $(u, c)$ and $(v, c)$ for every ${u, v} in E$ and every color $c$. Output the unit-weight Maximum Independent Set instance $H$, which has $K n$ vertices and $n binom(K, 2) + K |E|$ edges.
+
+  _Correctness._ ($arrow.r.double$) A proper coloring $c$ gives the set ${(v, c(v)) : v in V}$ of size $n$: it contains one pair per vertex, and adjacent vertices have different colors, so no two chosen pairs are joined. ($arrow.l.double$) The $n$ cliques ${(v, c) : c}$ cover $H$, so an independent set has at most one pair per vertex and size at most $n$. One of size $n$ picks exactly one color for every vertex, and the conflict edges rule out equal colors on adjacent vertices, so the choice is a proper $K$-coloring.
+
+  _Solution extraction._ Give each vertex $v$ the color $c$ of its selected pair $(v, c)$, or color 0 if none is selected.
+]
+
+#reduction-rule("KColoring", "KSatisfiability")[
+  This $O(n + |E|)$ reduction encodes a 3-coloring in one-hot variables and pads every two-literal clause with a single shared variable, so the output is a 3-SAT instance with exactly three literals per clause.
+][
+  _Construction._ Given a 3-Coloring instance $G = (V, E)$ with $n = |V|$, introduce $x_(v,c)$ for $v in V$, $c in {0, 1, 2}$, and one padding variable $y$. For each vertex add $(x_(v,0) or x_(v,1) or x_(v,2))$. For each vertex and $c < d$, and for each edge ${u, v}$ and color $c$, the two-literal clauses $(not x_(v,c) or not x_(v,d))$ and $(not x_(u,c) or not x_(v,c))$ are each added as $(a or b or y) and (a or b or not y)$. The result has $3n + 1$ variables and $7n + 6|E|$ clauses.
+
+  _Correctness._ The pair $(a or b or y) and (a or b or not y)$ is equivalent to $a or b$ for either value of $y$. The remaining constraints say each vertex has exactly one color and adjacent vertices differ, so satisfying assignments restricted to $x$ are exactly the one-hot encodings of proper 3-colorings.
+
+  _Solution extraction._ Give each vertex $v$ the color $c$ with $x_(v,c) = 1$.
+]
+
+#reduction-rule("KColoring", "MinimumCliqueCover")[
+  This $O(n^2)$ reduction takes the complement graph $overline(G)$. Color classes of $G$ are independent sets, hence cliques of $overline(G)$, so the minimum clique cover of $overline(G)$ has exactly $chi(G)$ cliques, and $G$ is $K$-colorable if and only if this optimum is at most $K$.
+][
+  _Construction._ Given a $K$-Coloring instance $(G = (V, E), K)$, output the Minimum Clique Cover instance $overline(G) = (V, overline(E))$ with $overline(E) = {{u, v} : u != v, {u, v} in.not E}$.
+
+  _Correctness._ ($arrow.r.double$) A proper $K$-coloring partitions $V$ into at most $K$ color classes, each independent in $G$ and therefore a clique in $overline(G)$, so the optimum is at most $K$. ($arrow.l.double$) A clique cover of $overline(G)$ with $k <= K$ cliques partitions $V$ into $k$ independent sets of $G$; giving each its own color is a proper $k$-coloring.
+
+  _Solution extraction._ Renumber the clique groups $0, 1, dots$ in order of first appearance and use the new numbers as colors. An optimal cover of $overline(G)$ uses $chi(G)$ groups, so the colors stay below $K$ whenever the source is satisfiable.
+]
+
 // 4. KColoring → Clustering (#924)
 #reduction-rule("KColoring", "Clustering")[
   This $O(n^2)$ reduction @garey1979[MS9] @brucker1978clustering keeps the vertex set as the ground set, assigns distance 1 to adjacent pairs and distance 0 to nonadjacent pairs, and fixes $K = 3$ and $B = 0$. A feasible clustering is therefore exactly a partition of the graph into at most three independent sets.
@@ -22,6 +22,14 @@ Piping (use - to read from stdin):
 JSON output (any command):
   pred list --json                 # JSON to stdout
   pred show MIS --json | jq '.'   # pipe to jq
+With --json, stdout holds exactly one JSON document; failures print
+{\"error\": {\"kind\", \"code\", \"message\"}} there instead.
+
+Exit codes:
+  0  success
+  2  no reduction path, or the instance has no feasible solution
+  3  invalid input (bad flags, unreadable or malformed files)
+  4  internal error
 
 Use `pred <command> --help` for detailed usage of each command.
 Use `pred list` to see all available problem types.
@@ -34,7 +42,7 @@ pub struct Cli {
     #[arg(long, short, global = true)]
     pub output: Option<PathBuf>,
 
-    /// Suppress informational messages on stderr
+    /// Suppress informational messages and hints on stderr (errors are still shown)
     #[arg(long, short, global = true)]
     pub quiet: bool,
 
@@ -53,11 +61,23 @@ pub enum Commands {
 Examples:
   pred list                   # list problem types
   pred list --rules           # list all reduction rules
+  pred list --complexity np-complete
+  pred list --category graph  # only graph problems
+  pred list --group-by category
   pred list -o problems.json  # save as JSON")]
     List {
         /// List reduction rules instead of problem types
         #[arg(long)]
         rules: bool,
+        /// Only list problems in this complexity class (p, np-complete, np-hard, pspace-complete, unknown)
+        #[arg(long, conflicts_with = "rules")]
+        complexity: Option<String>,
+        /// Only list problems in this category (graph, formula, csp, set, algebraic, misc)
+        #[arg(long, conflicts_with = "rules")]
+        category: Option<String>,
+        /// Group the listing by category or complexity class
+        #[arg(long, value_enum, conflicts_with = "rules")]
+        group_by: Option<ListGroupBy>,
     },
 
     /// Show details for a problem type or variant (fields, reductions, complexity)
@@ -67,12 +87,36 @@ Examples:
   pred show MIS/UnitDiskGraph     # specific variant
   pred show MIS/UnitDiskGraph/i32 # fully qualified variant
   pred show KSAT/K3               # KSatisfiability with K=3
+  pred show MIS --example         # small instance with a brute-force optimum
+  pred show MIS --example -o mis.json  # write a runnable example instance
 
 Use `pred list` to see all available problem types and variants.")]
     Show {
         /// Problem name or variant (e.g., MIS, MIS/UnitDiskGraph, KSAT/K3)
         #[arg(value_parser = crate::problem_name::ProblemNameParser)]
         problem: String,
+        /// Show a canonical example instance solved by brute force instead of the problem details
+        #[arg(long)]
+        example: bool,
+    },
+
+    /// Print the JSON Schema of a problem file (for validating hand-written instances)
+    #[command(after_help = "\
+Examples:
+  pred schema MIS                      # accepts every MIS variant
+  pred schema MIS/UnitDiskGraph/i32    # one specific variant
+  pred schema MIS -o mis.schema.json
+  pred schema --all -o schemas/        # one <Name>.schema.json per problem
+
+The schema describes the {\"type\", \"variant\", \"data\"} file written by
+`pred create` (JSON Schema draft 2020-12).")]
+    Schema {
+        /// Problem name or variant (e.g., MIS, MIS/UnitDiskGraph, KSAT/K3)
+        #[arg(value_parser = crate::problem_name::ProblemNameParser, required_unless_present = "all")]
+        problem: Option<String>,
+        /// Write schemas for all problems into the -o directory
+        #[arg(long, conflicts_with = "problem")]
+        all: bool,
     },
 
     /// Explore problems that reduce TO this one (incoming neighbors)
@@ -141,8 +185,24 @@ Use `pred list` to see available problems.")]
     #[command(after_help = "\
 Examples:
   pred export-graph                           # print to stdout
-  pred export-graph -o reduction_graph.json   # save to file")]
-    ExportGraph,
+  pred export-graph -o reduction_graph.json   # save to file
+  pred export-graph --condensed               # clusters of mutually reducible problems
+  pred export-graph --format mermaid          # Mermaid flowchart of problem types
+  pred export-graph --format mermaid -o graph.mmd
+
+With --condensed, variant nodes that reduce to each other (strongly connected
+components) are merged into clusters, and the DAG between clusters is exported.
+
+With --format mermaid, each problem type is a node and each arrow is labeled
+with the degree of its overhead polynomial (super-poly if not polynomial).")]
+    ExportGraph {
+        /// Export strongly connected clusters and the DAG between them
+        #[arg(long, conflicts_with = "format")]
+        condensed: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = GraphFormat::Json)]
+        format: GraphFormat,
+    },
 
     /// Create a problem instance and save as JSON
     Create(Box<CreateArgs>),
@@ -157,8 +217,56 @@ Examples:
   pred inspect bundle.json
   pred create MIS --graph 0-1,1-2 | pred inspect -")]
     Inspect(InspectArgs),
+    /// Show instance statistics (variables, graph density, degrees, pathwidth)
+    #[command(after_help = "\
+Examples:
+  pred stats problem.json
+  pred stats problem.json --json
+  pred create MIS --graph 0-1,1-2,2-0 | pred stats -
+
+Graph problems also report density, min/max/avg degree, connected components,
+and a greedy pathwidth upper bound (which also bounds treewidth).")]
+    Stats(StatsArgs),
+    /// Check a reduction's declared overhead against random instances
+    #[command(after_help = "\
+Examples:
+  pred audit MIS MVC --sizes 4,8,16
+  pred audit MIS MaximumSetPacking --sizes 5,10,20 --edge-prob 0.3 --seed 7
+  pred audit MaxCut SpinGlass --sizes 6,12 --tolerance 1.5 --json
+
+Generates one random instance per size (as `pred create --random`), reduces it,
+and compares the measured target size against the declared overhead. A field
+fails when it exceeds its declared value by more than --tolerance times.")]
+    Audit(AuditArgs),
     /// Solve a problem instance
     Solve(SolveArgs),
+    /// Time a solver on one instance over repeated runs
+    #[command(after_help = "\
+Examples:
+  pred bench problem.json --solver brute-force --repeat 10
+  pred bench problem.json --solver ilp --repeat 5 --json
+  pred bench problem.json --solver brute-force --out timings.csv
+  pred create MIS --graph 0-1,1-2,2-3 | pred bench - --solver brute-force
+
+The instance is loaded once; each run times only the solver call. Reports the
+objective and the min/median/mean wall time. Use --out to also write the summary
+as a one-row CSV, e.g. to compare solvers on the same instance.")]
+    Bench(BenchArgs),
+    /// Solve or reduce many instance files and summarize the results
+    #[command(after_help = "\
+Examples:
+  pred batch solve 'instances/*.json' --solver brute-force --out results.csv
+  pred batch solve 'instances/*.json' --jobs 4 --out-dir solutions/
+  pred batch reduce 'instances/*.json' --to QUBO --out-dir reduced/
+
+Inputs are files or glob patterns (quote them so the shell does not expand
+them). Each instance is processed independently: a file that fails to parse,
+reduce, or solve is reported as an error row and the batch continues.
+--out writes the summary (file, problem, n, objective, time_ms, status, error)
+as CSV. --out-dir receives one JSON output per instance, named
+<stem>.solution.json for solve and <stem>.reduced.json for reduce.
+The command exits with an error after the summary if any instance failed.")]
+    Batch(BatchArgs),
     /// Extract a source-space solution from a reduction bundle and a target-space config
     #[command(after_help = "\
 Examples:
@@ -219,13 +327,26 @@ Setup: add one line to your shell rc file:
     },
 }
 
-#[derive(Clone, Debug, ValueEnum)]
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ListGroupBy {
+    Category,
+    Complexity,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormat {
+    Json,
+    Mermaid,
+}
+
+#[derive(Clone, Debug, Default, ValueEnum)]
 pub enum ExampleSide {
+    #[default]
     Source,
     Target,
 }
 
-#[derive(clap::Args)]
+#[derive(clap::Args, Default)]
 #[command(after_help = "\
 TIP: Run `pred create <PROBLEM>` (no other flags) to see problem-specific help.
      Not every flag applies to every problem — the above list shows ALL flags.
@@ -233,22 +354,28 @@ TIP: Run `pred create <PROBLEM>` (no other flags) to see problem-specific help.
 Flags by problem type:
   MIS, MVC, MaxClique, MinDomSet  --graph, --weights
   MaxCut, MaxMatching, TSP, BottleneckTravelingSalesman --graph, --edge-weights
+  MaxBisection                    --graph, --edge-weights
+  MinimumEdgeDominatingSet        --graph, --edge-weights
   LongestPath                     --graph, --edge-lengths, --source-vertex, --target-vertex
   HamiltonianPathBetweenTwoVertices --graph, --source-vertex, --target-vertex
   ShortestWeightConstrainedPath   --graph, --edge-lengths, --edge-weights, --source-vertex, --target-vertex, --weight-bound
   GraphPartitioning               --graph, --num-partitions
-  MaximalIS                       --graph, --weights
-  SAT, NAESAT                     --num-vars, --clauses
+  MaximalIS, MinimumMaximalIS     --graph, --weights
+  SAT, NAESAT, ModelCounting      --num-vars, --clauses
   KSAT                            --num-vars, --clauses [--k]
+  XORSAT                          --num-vars, --clauses, --xor-clauses
   NonTautology                    --num-vars, --disjuncts
+  CSP                             --domains, --constraints
   QUBO                            --matrix
   SpinGlass                       --graph, --couplings, --fields
+  PolySpinGlass                   --hyperedges, --couplings, --fields [--num-vertices]
   KColoring                       --graph, --k
   KClique                         --graph, --k
   DecisionMinimumVertexCover      --graph, --weights, --bound
   MinimumMultiwayCut              --graph, --terminals, --edge-weights
   MonochromaticTriangle           --graph
   PartitionIntoTriangles          --graph
+  MinimumCliqueCover              --graph
   GeneralizedHex                  --graph, --source, --sink
   IntegralFlowWithMultipliers     --arcs, --capacities, --source, --sink, --multipliers, --requirement
   MinimumEdgeCostFlow             --arcs, --edge-weights (prices), --capacities, --source, --sink, --requirement
@@ -266,9 +393,11 @@ Flags by problem type:
   KthBestSpanningTree             --graph, --edge-weights, --k, --bound
   LengthBoundedDisjointPaths      --graph, --source, --sink, --max-length
   PathConstrainedNetworkFlow      --arcs, --capacities, --source, --sink, --paths, --requirement
-  Factoring                       --target, --m, --n
+  Factoring                       --target, --m, --n [--p-bits, --q-bits]
   BinPacking                      --sizes, --capacity
   Clustering                      --distance-matrix, --k, --diameter-bound
+  KCenter                         --distance-matrix, --k
+  RectanglePacking                --rectangles, --container-width, --container-height [--allow-rotation]
   CapacityAssignment              --capacities, --cost-matrix, --delay-matrix, --cost-budget, --delay-budget
   ProductionPlanning             --num-periods, --demands, --capacities, --setup-costs, --production-costs, --inventory-costs, --cost-bound
   SubsetProduct                    --sizes, --target
@@ -288,7 +417,7 @@ Flags by problem type:
   PaintShop                       --sequence
   MaximumSetPacking               --subsets [--weights]
   MinimumHittingSet               --universe-size, --subsets
-  MinimumSetCovering              --universe-size, --subsets [--weights]
+  MinimumSetCovering              --universe-size, --subsets [--weights] [--requirements]
   EnsembleComputation             --universe-size, --subsets, --budget
   ComparativeContainment          --universe-size, --r-sets, --s-sets [--r-weights] [--s-weights]
   X3C (ExactCoverBy3Sets)         --universe-size, --subsets (3 elements each)
@@ -301,6 +430,7 @@ Flags by problem type:
   TwoDimensionalConsecutiveSets   --alphabet-size, --subsets
   BicliqueCover                   --left, --right, --biedges, --k
   BalancedCompleteBipartiteSubgraph --left, --right, --biedges, --k
+  MaximumBalancedBiclique         --left, --right, --biedges
   BiconnectivityAugmentation      --graph, --potential-weights, --budget [--num-vertices]
   PartialFeedbackEdgeSet          --graph, --budget, --max-cycle-length [--num-vertices]
   BMF                             --matrix (0/1), --rank
@@ -313,12 +443,16 @@ Flags by problem type:
   MinimumWeightDecoding           --matrix (JSON 2D bool), --rhs (comma-separated booleans)
   FeasibleBasisExtension          --matrix (JSON 2D i64), --rhs, --required-columns
   SteinerTree                     --graph, --edge-weights, --terminals
+  MinimumSteinerForest            --graph, --edge-weights, --terminal-pairs
+  ClusterEditing                  --graph, --edge-weights
   MultipleCopyFileAllocation      --graph, --usage, --storage
   AcyclicPartition                --arcs [--weights] [--arc-weights] --weight-bound --cost-bound [--num-vertices]
   CVP                             --basis, --target-vec [--bounds]
   MultiprocessorScheduling        --lengths, --num-processors, --deadline
+  MinimumMultiprocessorScheduling --lengths, --num-processors
   SchedulingToMinimizeWeightedCompletionTime  --lengths, --weights, --num-processors
   SequencingWithinIntervals       --release-times, --deadlines, --lengths
+  KTrackIntervalScheduling        --starts, --ends, --weights, --k
   OptimalLinearArrangement        --graph
   RootedTreeArrangement           --graph, --bound
   MinMaxMulticenter (pCenter)     --graph, --weights, --edge-weights, --k
@@ -332,6 +466,7 @@ Flags by problem type:
   GroupingBySwapping             --string, --bound [--alphabet-size]
   LCS                             --strings [--alphabet-size]
   FAS                             --arcs [--weights] [--num-vertices]
+  MAS                             --arcs [--weights] [--num-vertices]
   FVS                             --arcs [--weights] [--num-vertices]
   QBF                             --num-vars, --clauses, --quantifiers
   SteinerTreeInGraphs             --graph, --edge-weights, --terminals
@@ -356,6 +491,7 @@ Flags by problem type:
   MinimumExternalMacroDataCompression --string, --pointer-cost [--alphabet-size]
   MinimumInternalMacroDataCompression --string, --pointer-cost [--alphabet-size]
   SCS                             --strings [--alphabet-size]
+  ShortestCommonSuperstring       --strings [--alphabet-size]
   StringToStringCorrection         --source-string, --target-string, --bound [--alphabet-size]
   D2CIF                           --arcs, --capacities, --source-1, --sink-1, --source-2, --sink-2, --requirement-1, --requirement-2
   MinimumDummyActivitiesPert      --arcs [--num-vertices]
@@ -370,10 +506,12 @@ Flags by problem type:
   CBQ                              --domain-size, --relations, --conjuncts-spec
   IntegerExpressionMembership     --expression (JSON), --target
   MinimumGeometricConnectedDominatingSet --positions (float x,y pairs), --radius
+  MinimumWeightTriangulation (MWT) --positions (convex polygon, float x,y pairs in order)
   MinimumDecisionTree             --test-matrix (JSON 2D bool), --num-objects, --num-tests
   MinimumDisjunctiveNormalForm (MinDNF) --num-vars, --truth-table
   SquareTiling (WangTiling)       --num-colors, --tiles, --grid-size
   ILP, CircuitSAT                 (via reduction only)
+  PseudoBoolean (PBO)             (Rust API / OPB files only)
 
 Geometry graph variants (use slash notation, e.g., MIS/KingsSubgraph):
   KingsSubgraph, TriangularSubgraph   --positions (integer x,y pairs)
@@ -493,6 +631,15 @@ pub struct CreateArgs {
     /// Clauses for SAT problems (semicolon-separated, e.g., "1,2;-1,3")
     #[arg(long)]
     pub clauses: Option<String>,
+    /// XOR parity clauses for XorSatisfiability (semicolon-separated, e.g., "1,2,3;-1,4")
+    #[arg(long)]
+    pub xor_clauses: Option<String>,
+    /// Domain size of each CSP variable (comma-separated, e.g., "2,2,3")
+    #[arg(long)]
+    pub domains: Option<String>,
+    /// Table constraints for CSP (semicolon-separated scope:tuples, tuples separated by '|', e.g., "0,1:0,1|1,0;2:2")
+    #[arg(long)]
+    pub constraints: Option<String>,
     /// Disjuncts for NonTautology (semicolon-separated, e.g., "1,2;-1,3")
     #[arg(long)]
     pub disjuncts: Option<String>,
@@ -536,6 +683,12 @@ pub struct CreateArgs {
     /// Bits for second factor (for Factoring)
     #[arg(long)]
     pub n: Option<usize>,
+    /// Exact bit width of the first factor (for Factoring; defaults --m)
+    #[arg(long)]
+    pub p_bits: Option<usize>,
+    /// Exact bit width of the second factor (for Factoring; defaults --n)
+    #[arg(long)]
+    pub q_bits: Option<usize>,
     /// Vertex positions for geometry-based graphs (semicolon-separated x,y pairs, e.g., "0,0;1,0;1,1")
     #[arg(long)]
     pub positions: Option<String>,
@@ -599,13 +752,16 @@ pub struct CreateArgs {
     /// Universe size for set-system problems such as MinimumHittingSet, MinimumSetCovering, and ComparativeContainment
     #[arg(long = "universe-size", alias = "universe")]
     pub universe: Option<usize>,
-    /// Bipartite graph edges for BicliqueCover / BalancedCompleteBipartiteSubgraph (e.g., "0-0,0-1,1-2" for left-right pairs)
+    /// Bipartite graph edges for BicliqueCover / BalancedCompleteBipartiteSubgraph / MaximumBalancedBiclique (e.g., "0-0,0-1,1-2" for left-right pairs)
     #[arg(long)]
     pub biedges: Option<String>,
-    /// Left partition size for BicliqueCover / BalancedCompleteBipartiteSubgraph
+    /// Hyperedges for PolySpinGlass (semicolon-separated vertex sets, e.g., "0,1,2;2,3")
+    #[arg(long)]
+    pub hyperedges: Option<String>,
+    /// Left partition size for BicliqueCover / BalancedCompleteBipartiteSubgraph / MaximumBalancedBiclique
     #[arg(long)]
     pub left: Option<usize>,
-    /// Right partition size for BicliqueCover / BalancedCompleteBipartiteSubgraph
+    /// Right partition size for BicliqueCover / BalancedCompleteBipartiteSubgraph / MaximumBalancedBiclique
     #[arg(long)]
     pub right: Option<usize>,
     /// Rank for BMF
@@ -623,13 +779,19 @@ pub struct CreateArgs {
     /// Release times for SequencingWithinIntervals (comma-separated, e.g., "0,0,5")
     #[arg(long)]
     pub release_times: Option<String>,
+    /// Interval start times for KTrackIntervalScheduling (comma-separated, e.g., "0,1,2")
+    #[arg(long)]
+    pub starts: Option<String>,
+    /// Interval end times (exclusive) for KTrackIntervalScheduling (comma-separated, e.g., "4,3,6")
+    #[arg(long)]
+    pub ends: Option<String>,
     /// Processing lengths (comma-separated, e.g., "4,5,3,2,6")
     #[arg(long)]
     pub lengths: Option<String>,
     /// Terminal vertices for SteinerTree or MinimumMultiwayCut (comma-separated indices, e.g., "0,2,4")
     #[arg(long)]
     pub terminals: Option<String>,
-    /// Terminal pairs for DisjointConnectingPaths (comma-separated pairs, e.g., "0-3,2-5")
+    /// Terminal pairs for DisjointConnectingPaths or MinimumSteinerForest (comma-separated pairs, e.g., "0-3,2-5")
     #[arg(long = "terminal-pairs")]
     pub terminal_pairs: Option<String>,
     /// Tree edge list for IsomorphicSpanningTree (e.g., 0-1,1-2,2-3)
@@ -669,7 +831,7 @@ pub struct CreateArgs {
     /// Pattern graph edge list for SubgraphIsomorphism (e.g., 0-1,1-2,2-0)
     #[arg(long)]
     pub pattern: Option<String>,
-    /// Input strings for LCS (e.g., "ABAC;BACA" or "0,1,0;1,0,1") or SCS (e.g., "0,1,2;1,2,0")
+    /// Input strings for LCS (e.g., "ABAC;BACA" or "0,1,0;1,0,1") SCS (e.g., "0,1,2;1,2,0"), or ShortestCommonSuperstring (e.g., "CATG;ATGC;TGCA")
     #[arg(long)]
     pub strings: Option<String>,
     /// Input string for GroupingBySwapping (comma-separated symbol indices, e.g., "0,1,2,0,1,2")
@@ -756,7 +918,7 @@ pub struct CreateArgs {
     /// Binary schedule patterns for StaffScheduling (semicolon-separated rows, e.g., "1,1,0;0,1,1")
     #[arg(long)]
     pub schedules: Option<String>,
-    /// Requirements for StaffScheduling (comma-separated) or TimetableDesign (semicolon-separated rows)
+    /// Requirements for StaffScheduling or MinimumSetCovering (comma-separated) or TimetableDesign (semicolon-separated rows)
     #[arg(long)]
     pub requirements: Option<String>,
     /// Number of available workers for StaffScheduling
@@ -777,7 +939,7 @@ pub struct CreateArgs {
     /// Task availability rows for TimetableDesign (semicolon-separated 0/1 rows)
     #[arg(long)]
     pub task_avail: Option<String>,
-    /// Alphabet size for GroupingBySwapping, LCS, SCS, StringToStringCorrection, or TwoDimensionalConsecutiveSets (optional; inferred from the input strings if omitted)
+    /// Alphabet size for GroupingBySwapping, LCS, SCS, ShortestCommonSuperstring, StringToStringCorrection, or TwoDimensionalConsecutiveSets (optional; inferred from the input strings if omitted)
     #[arg(long)]
     pub alphabet_size: Option<usize>,
 
@@ -928,6 +1090,18 @@ pub struct CreateArgs {
     /// Number of colors for SquareTiling
     #[arg(long)]
     pub num_colors: Option<usize>,
+    /// Rectangles for RectanglePacking as width x height (e.g., "1x2,2x1,2x1")
+    #[arg(long)]
+    pub rectangles: Option<String>,
+    /// Container width W for RectanglePacking
+    #[arg(long)]
+    pub container_width: Option<usize>,
+    /// Container height H for RectanglePacking
+    #[arg(long)]
+    pub container_height: Option<usize>,
+    /// Allow 90-degree rotations (RectanglePacking)
+    #[arg(long)]
+    pub allow_rotation: bool,
 }
 
 impl CreateArgs {
@@ -964,6 +1138,9 @@ impl CreateArgs {
         insert!("couplings", self.couplings.as_deref());
         insert!("fields", self.fields.as_deref());
         insert!("clauses", self.clauses.as_deref());
+        insert!("xor-clauses", self.xor_clauses.as_deref());
+        insert!("domains", self.domains.as_deref());
+        insert!("constraints", self.constraints.as_deref());
         insert!("disjuncts", self.disjuncts.as_deref());
         insert!("num-vars", self.num_vars);
         insert!("matrix", self.matrix.as_deref());
@@ -978,6 +1155,8 @@ impl CreateArgs {
         insert!("target", self.target.as_deref());
         insert!("m", self.m);
         insert!("n", self.n);
+        insert!("p-bits", self.p_bits);
+        insert!("q-bits", self.q_bits);
         insert!("positions", self.positions.as_deref());
         insert!("radius", self.radius);
         insert!("source-1", self.source_1);
@@ -1001,6 +1180,7 @@ impl CreateArgs {
         insert!("universe-size", self.universe);
         insert!("universe", self.universe); // PrimeAttributeName maps num_attributes → --universe
         insert!("biedges", self.biedges.as_deref());
+        insert!("hyperedges", self.hyperedges.as_deref());
         insert!("left", self.left);
         insert!("right", self.right);
         insert!("rank", self.rank);
@@ -1008,6 +1188,8 @@ impl CreateArgs {
         insert!("target-vec", self.target_vec.as_deref());
         insert!("bounds", self.bounds.as_deref());
         insert!("release-times", self.release_times.as_deref());
+        insert!("starts", self.starts.as_deref());
+        insert!("ends", self.ends.as_deref());
         insert!("lengths", self.lengths.as_deref().or(self.sizes.as_deref()));
         insert!("terminals", self.terminals.as_deref());
         insert!("terminal-pairs", self.terminal_pairs.as_deref());
@@ -1134,6 +1316,13 @@ impl CreateArgs {
         insert!("tiles", self.tiles.as_deref());
         insert!("grid-size", self.grid_size);
         insert!("num-colors", self.num_colors);
+        insert!("rectangles", self.rectangles.as_deref());
+        insert!("container-width", self.container_width);
+        insert!("container-height", self.container_height);
+        flags.insert(
+            "allow-rotation",
+            self.allow_rotation.then(|| "true".to_string()),
+        );
 
         flags.insert(
             "source",
@@ -1166,6 +1355,8 @@ Examples:
   pred create StringToStringCorrection --source-string \"0,1,2,3,1,0\" --target-string \"0,1,3,2,1\" --bound 2 | pred solve - --solver brute-force
   pred create TwoDimensionalConsecutiveSets --alphabet-size 6 --sets \"0,1,2;3,4,5;1,3;2,4;0,5\" | pred solve - --solver brute-force
   pred solve problem.json --timeout 10           # abort after 10 seconds
+  pred solve sat.json --count                    # count satisfying assignments (#SAT)
+  pred solve problem.json --solver brute-force --telemetry  # report search effort
 
 Typical workflow:
   pred create MIS --graph 0-1,1-2,2-3 -o problem.json
@@ -1182,6 +1373,13 @@ Problems without an ILP reduction path, such as `GroupingBySwapping`,
 `LengthBoundedDisjointPaths`, `MinMaxMulticenter`, and `StringToStringCorrection`,
 currently need `--solver brute-force`.
 
+With --count, a Satisfiability or ModelCounting instance is not solved; instead
+the number of satisfying assignments is computed by DPLL-style model counting
+with unit propagation.
+
+With --telemetry, the brute-force solver also reports how many configurations it
+evaluated, how many branches it pruned, and the wall time of the search.
+
 Customized solver: exact witness recovery for select problems via structure-exploiting
 backends. Currently supports MinimumCardinalityKey, AdditionalKey, PrimeAttributeName,
 BoyceCoddNormalFormViolation, PartialFeedbackEdgeSet, and RootedTreeArrangement.
@@ -1198,6 +1396,12 @@ pub struct SolveArgs {
     /// Timeout in seconds (0 = no limit)
     #[arg(long, default_value = "0")]
     pub timeout: u64,
+    /// Count satisfying assignments instead of solving (Satisfiability, ModelCounting)
+    #[arg(long)]
+    pub count: bool,
+    /// Report evaluated configurations, pruned branches and wall time (brute-force only)
+    #[arg(long, conflicts_with = "count")]
+    pub telemetry: bool,
 }
 
 #[derive(clap::Args)]
@@ -1206,12 +1410,20 @@ Examples:
   pred reduce problem.json --to QUBO -o reduced.json
   pred reduce problem.json --to ILP -o reduced.json
   pred reduce problem.json --via path.json -o reduced.json
+  pred reduce problem.json --to QUBO --estimate  # size estimate only
+  pred reduce problem.json --to MIS/KingsSubgraph --export-target-graph target.net
   pred create MIS --graph 0-1,1-2 | pred reduce - --to QUBO  # read from stdin
 
 Input: a problem JSON from `pred create`. Use - to read from stdin.
 The --via path file is from `pred path <SRC> <DST> -o path.json`.
 When --via is given, --to is inferred from the path file.
 Output is a reduction bundle with source, target, and path.
+With --estimate (alias --dry-run), only the target size predicted by the
+overhead formulas and a rough memory estimate are printed; the reduction itself
+is not executed. A warning is printed when the memory estimate exceeds
+--warn-bytes.
+With --export-target-graph, the graph of a graph-based target is also written
+as Pajek (.net) or a CSV edge list (.csv), chosen by the file extension.
 Use `pred solve reduced.json` to solve and map the solution back.")]
 pub struct ReduceArgs {
     /// Problem JSON file (from `pred create`). Use - for stdin.
@@ -1222,6 +1434,87 @@ pub struct ReduceArgs {
     /// Reduction route file (from `pred path ... -o`)
     #[arg(long)]
     pub via: Option<PathBuf>,
+    /// Print the estimated target size without executing the reduction
+    #[arg(long, visible_alias = "dry-run")]
+    pub estimate: bool,
+    /// With --estimate, warn when the estimated target memory exceeds this many bytes
+    #[arg(long, default_value_t = 1 << 30, requires = "estimate")]
+    pub warn_bytes: u64,
+    /// Also write the target's graph to this file (.net for Pajek, .csv for an edge list)
+    #[arg(long, conflicts_with = "estimate")]
+    pub export_target_graph: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+pub struct BenchArgs {
+    /// Problem JSON file (from `pred create`). Use - for stdin.
+    pub input: PathBuf,
+    /// Solver: ilp (default), brute-force, or customized
+    #[arg(long, default_value = "ilp")]
+    pub solver: String,
+    /// Number of timed runs
+    #[arg(long, default_value_t = 5, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub repeat: usize,
+    /// Write the timing summary as CSV to this file
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+pub struct BatchArgs {
+    #[command(subcommand)]
+    pub command: BatchCommand,
+}
+
+#[derive(Subcommand)]
+pub enum BatchCommand {
+    /// Solve every instance (problem JSON or reduction bundle)
+    Solve(BatchSolveArgs),
+    /// Reduce every problem instance to a target type
+    Reduce(BatchReduceArgs),
+}
+
+#[derive(clap::Args)]
+pub struct BatchInputArgs {
+    /// Instance files or glob patterns (e.g., 'instances/*.json')
+    #[arg(required = true)]
+    pub inputs: Vec<String>,
+    /// Number of instances processed in parallel
+    #[arg(long, default_value_t = 1)]
+    pub jobs: usize,
+    /// Write the summary as CSV to this file
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+pub struct BatchSolveArgs {
+    #[command(flatten)]
+    pub common: BatchInputArgs,
+    /// Solver: ilp (default), brute-force, or customized
+    #[arg(long, default_value = "ilp")]
+    pub solver: String,
+    /// Timeout in seconds per instance (0 = no limit)
+    #[arg(long, default_value = "0")]
+    pub timeout: u64,
+    /// Directory for per-instance solution JSON files
+    #[arg(long)]
+    pub out_dir: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+pub struct BatchReduceArgs {
+    #[command(flatten)]
+    pub common: BatchInputArgs,
+    /// Target problem type (e.g., QUBO, SpinGlass). Inferred from --via if omitted.
+    #[arg(long, value_parser = crate::problem_name::ProblemNameParser)]
+    pub to: Option<String>,
+    /// Reduction route file (from `pred path ... -o`)
+    #[arg(long)]
+    pub via: Option<PathBuf>,
+    /// Directory for per-instance reduction bundles
+    #[arg(long)]
+    pub out_dir: PathBuf,
 }
 
 #[derive(clap::Args)]
@@ -1239,20 +1532,61 @@ pub struct InspectArgs {
     pub input: PathBuf,
 }
 
+#[derive(clap::Args)]
+pub struct StatsArgs {
+    /// Problem JSON file. Use - for stdin.
+    pub input: PathBuf,
+}
+
+#[derive(clap::Args)]
+pub struct AuditArgs {
+    /// Source problem (e.g., MIS, MIS/UnitDiskGraph)
+    #[arg(value_parser = crate::problem_name::ProblemNameParser)]
+    pub source: String,
+    /// Target problem (e.g., MVC, QUBO)
+    #[arg(value_parser = crate::problem_name::ProblemNameParser)]
+    pub target: String,
+    /// Instance sizes (number of vertices), comma-separated
+    #[arg(long, value_delimiter = ',', default_value = "4,8,16")]
+    pub sizes: Vec<usize>,
+    /// Edge probability for random graphs [default: 0.5]
+    #[arg(long)]
+    pub edge_prob: Option<f64>,
+    /// Random seed for reproducibility
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Factor by which a measured size may exceed its declared overhead
+    #[arg(long, default_value_t = problemreductions::rules::audit::DEFAULT_AUDIT_TOLERANCE)]
+    pub tolerance: f64,
+}
+
 #[derive(clap::Args)]
 #[command(after_help = "\
 Examples:
   pred evaluate problem.json --config 1,0,1,0
   pred evaluate problem.json --config 1,0,1,0 -o result.json
   pred create MIS --graph 0-1,1-2 | pred evaluate - --config 1,0,1  # read from stdin
+  pred evaluate problem.json --config-file config.txt --breakdown
 
-Input: a problem JSON from `pred create`. Use - to read from stdin.")]
+Input: a problem JSON from `pred create`. Use - to read from stdin.
+A config file holds one value per line, or a JSON array such as [1, 0, 1, 0].
+--breakdown lists the per-edge, per-vertex, or per-clause contributions.")]
 pub struct EvaluateArgs {
     /// Problem JSON file (from `pred create`). Use - for stdin.
     pub input: PathBuf,
     /// Configuration to evaluate (comma-separated, e.g., 1,0,1,0)
+    #[arg(
+        long,
+        required_unless_present = "config_file",
+        conflicts_with = "config_file"
+    )]
+    pub config: Option<String>,
+    /// Read the configuration from a file (one value per line, or a JSON array)
     #[arg(long)]
-    pub config: String,
+    pub config_file: Option<PathBuf>,
+    /// Print each labeled term contributing to the objective
+    #[arg(long)]
+    pub breakdown: bool,
 }
 
 /// Print the after_help text for a subcommand on parse error.
@@ -1270,8 +1604,13 @@ pub fn print_subcommand_help_hint(error_msg: &str) {
         ("pred create", "create"),
         ("pred evaluate", "evaluate"),
         ("pred inspect", "inspect"),
+        ("pred stats", "stats"),
+        ("pred audit", "audit"),
+        ("pred batch", "batch"),
+        ("pred bench", "bench"),
         ("pred path", "path"),
         ("pred show", "show"),
+        ("pred schema", "schema"),
         ("pred to", "to"),
         ("pred from", "from"),
         ("pred export-graph", "export-graph"),
@@ -0,0 +1,136 @@
+use crate::cli::{AuditArgs, CreateArgs};
+use crate::commands::create::random_problem;
+use crate::dispatch::load_problem;
+use crate::output::OutputConfig;
+use crate::problem_name::resolve_problem_ref;
+use anyhow::{bail, Result};
+use problemreductions::rules::audit::audit_entry;
+use problemreductions::rules::registry::reduction_entries;
+use problemreductions::rules::{ReductionEntry, ReductionGraph};
+use std::any::Any;
+use std::collections::BTreeMap;
+
+pub fn audit(args: &AuditArgs, out: &OutputConfig) -> Result<()> {
+    if args.sizes.is_empty() {
+        bail!("--sizes must list at least one instance size");
+    }
+    let graph = ReductionGraph::new();
+    let source = resolve_problem_ref(&args.source, &graph)?;
+    let target = resolve_problem_ref(&args.target, &graph)?;
+
+    let mut instances = Vec::with_capacity(args.sizes.len());
+    for &size in &args.sizes {
+        let create_args = CreateArgs {
+            random: true,
+            num_vertices: Some(size),
+            edge_prob: args.edge_prob,
+            seed: args.seed,
+            ..Default::default()
+        };
+        let output = random_problem(&create_args, &source.name, &source.variant)?;
+        instances.push(load_problem(
+            &output.problem_type,
+            &output.variant,
+            output.data,
+        )?);
+    }
+
+    // The generator may settle on a different variant than requested
+    // (e.g. a default graph type), so match the rule on what it produced.
+    let source_variant = instances[0].variant_map();
+    let entry = select_entry(&source.name, &source_variant, &target.name, &target.variant)?;
+    if entry.reduce_fn.is_none() {
+        bail!(
+            "{} -> {} has no witness reduction to execute",
+            entry.source_name,
+            entry.target_name
+        );
+    }
+
+    let anys: Vec<&dyn Any> = instances.iter().map(|p| p.as_any()).collect();
+    let report = audit_entry(entry, &anys, args.tolerance);
+
+    let mut text = report.to_string();
+    text.push_str("Samples (source -> measured target):");
+    for sample in &report.samples {
+        text.push_str(&format!(
+            "\n  {} -> {}",
+            format_size(&sample.source_size.components),
+            format_size(&sample.measured.components)
+        ));
+    }
+    out.emit_with_default_name("", &text, &serde_json::to_value(&report)?)?;
+
+    let violations: Vec<&str> = report.violations().map(|f| f.field.as_str()).collect();
+    if !violations.is_empty() {
+        bail!(
+            "Measured size exceeds declared overhead by more than {}x for: {}",
+            report.tolerance,
+            violations.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Pick the registered rule from the source variant to the target problem,
+/// preferring the requested target variant when several exist.
+fn select_entry(
+    source_name: &str,
+    source_variant: &BTreeMap<String, String>,
+    target_name: &str,
+    target_variant: &BTreeMap<String, String>,
+) -> Result<&'static ReductionEntry> {
+    let candidates: Vec<&'static ReductionEntry> = reduction_entries()
+        .into_iter()
+        .filter(|entry| {
+            entry.source_name == source_name
+                && entry.target_name == target_name
+                && ReductionGraph::variant_to_map(&entry.source_variant()) == *source_variant
+        })
+        .collect();
+
+    if let Some(entry) = candidates
+        .iter()
+        .find(|entry| ReductionGraph::variant_to_map(&entry.target_variant()) == *target_variant)
+    {
+        return Ok(entry);
+    }
+    match candidates.as_slice() {
+        [entry] => Ok(entry),
+        [] => Err(crate::exit::no_result(format!(
+            "No direct reduction from {source_name}{} to {target_name}.\n\n\
+             Hint: use `pred path {source_name} {target_name}` to find multi-step paths.",
+            format_variant(source_variant)
+        ))),
+        _ => {
+            let variants: Vec<String> = candidates
+                .iter()
+                .map(|entry| {
+                    let variant = ReductionGraph::variant_to_map(&entry.target_variant());
+                    format!("  {target_name}{}", format_variant(&variant))
+                })
+                .collect();
+            bail!(
+                "Several reductions from {source_name} to {target_name}; \
+                 pick a target variant:\n{}",
+                variants.join("\n")
+            )
+        }
+    }
+}
+
+fn format_variant(variant: &BTreeMap<String, String>) -> String {
+    if variant.is_empty() {
+        return String::new();
+    }
+    let values: Vec<&str> = variant.values().map(String::as_str).collect();
+    format!("/{}", values.join("/"))
+}
+
+fn format_size(components: &[(String, usize)]) -> String {
+    components
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
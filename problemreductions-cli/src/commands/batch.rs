@@ -0,0 +1,297 @@
+use crate::cli::{BatchArgs, BatchCommand, BatchReduceArgs, BatchSolveArgs};
+use crate::commands::timing::time_ms;
+use crate::dispatch::{load_problem, read_input, ProblemJson, ReductionBundle};
+use crate::output::{format_table, Align, OutputConfig};
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// One line of the batch summary.
+#[derive(Debug, Serialize)]
+struct BatchRow {
+    file: String,
+    problem: Option<String>,
+    n: Option<usize>,
+    objective: Option<String>,
+    time_ms: f64,
+    status: &'static str,
+    error: Option<String>,
+}
+
+/// What a successful instance contributes to its summary row.
+struct InstanceResult {
+    problem: String,
+    n: usize,
+    objective: Option<String>,
+}
+
+pub fn batch(args: &BatchArgs, out: &OutputConfig) -> Result<()> {
+    match &args.command {
+        BatchCommand::Solve(args) => batch_solve(args, out),
+        BatchCommand::Reduce(args) => batch_reduce(args, out),
+    }
+}
+
+fn batch_solve(args: &BatchSolveArgs, out: &OutputConfig) -> Result<()> {
+    let files = expand_inputs(&args.common.inputs)?;
+    if let Some(dir) = &args.out_dir {
+        create_out_dir(dir)?;
+    }
+    let rows = run_parallel(&files, args.common.jobs, |file| {
+        let (problem, n) = instance_size(file)?;
+        let output =
+            crate::commands::solve::solve_file(file, &args.solver, args.timeout, false, false)?;
+        if let Some(dir) = &args.out_dir {
+            write_json(&instance_output_path(dir, file, "solution"), &output.json)?;
+        }
+        Ok(InstanceResult {
+            problem,
+            n,
+            objective: output.json["evaluation"].as_str().map(str::to_string),
+        })
+    });
+    report(&rows, args.common.out.as_deref(), out)
+}
+
+fn batch_reduce(args: &BatchReduceArgs, out: &OutputConfig) -> Result<()> {
+    if args.to.is_none() && args.via.is_none() {
+        bail!("pred batch reduce requires --to or --via");
+    }
+    let files = expand_inputs(&args.common.inputs)?;
+    create_out_dir(&args.out_dir)?;
+    let rows = run_parallel(&files, args.common.jobs, |file| {
+        let (problem, n) = instance_size(file)?;
+        let (bundle, _) = crate::commands::reduce::reduce_to_bundle(
+            file,
+            args.to.as_deref(),
+            args.via.as_deref(),
+        )?;
+        let json = serde_json::to_value(&bundle)?;
+        write_json(&instance_output_path(&args.out_dir, file, "reduced"), &json)?;
+        Ok(InstanceResult {
+            problem,
+            n,
+            objective: None,
+        })
+    });
+    report(&rows, args.common.out.as_deref(), out)
+}
+
+/// Expand glob patterns; plain paths are kept as given so that a missing
+/// file shows up as a failed row rather than silently disappearing.
+fn expand_inputs(inputs: &[String]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for input in inputs {
+        if !input.contains(['*', '?', '[']) {
+            files.push(PathBuf::from(input));
+            continue;
+        }
+        let mut matched: Vec<PathBuf> = glob::glob(input)
+            .with_context(|| format!("Invalid glob pattern: {input}"))?
+            .filter_map(|entry| entry.ok())
+            .filter(|path| path.is_file())
+            .collect();
+        if matched.is_empty() {
+            bail!("Pattern matched no files: {input}");
+        }
+        matched.sort();
+        files.extend(matched);
+    }
+    Ok(files)
+}
+
+/// Name and variable count of a problem file, or of a bundle's source problem.
+fn instance_size(path: &Path) -> Result<(String, usize)> {
+    let content = read_input(path)?;
+    let mut json: serde_json::Value =
+        serde_json::from_str(&content).context("Failed to parse JSON")?;
+    if json.get("source").is_some() && json.get("target").is_some() && json.get("path").is_some() {
+        let bundle: ReductionBundle =
+            serde_json::from_value(json).context("Failed to parse reduction bundle")?;
+        json = serde_json::to_value(bundle.source)?;
+    }
+    let problem_json: ProblemJson =
+        serde_json::from_value(json).context("Failed to parse problem JSON")?;
+    let problem = load_problem(
+        &problem_json.problem_type,
+        &problem_json.variant,
+        problem_json.data,
+    )?;
+    Ok((
+        problem.problem_name().to_string(),
+        problem.num_variables_dyn(),
+    ))
+}
+
+/// Process every file with up to `jobs` worker threads, keeping input order.
+fn run_parallel<F>(files: &[PathBuf], jobs: usize, process: F) -> Vec<BatchRow>
+where
+    F: Fn(&Path) -> Result<InstanceResult> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let rows: Mutex<Vec<Option<BatchRow>>> = Mutex::new(files.iter().map(|_| None).collect());
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, files.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(file) = files.get(index) else {
+                    break;
+                };
+                let (result, time_ms) = time_ms(|| process(file));
+                let row = summary_row(file, result, time_ms);
+                rows.lock().unwrap()[index] = Some(row);
+            });
+        }
+    });
+    rows.into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|row| row.expect("every file is processed"))
+        .collect()
+}
+
+fn summary_row(file: &Path, result: Result<InstanceResult>, time_ms: f64) -> BatchRow {
+    let file = file.display().to_string();
+    match result {
+        Ok(result) => BatchRow {
+            file,
+            problem: Some(result.problem),
+            n: Some(result.n),
+            objective: result.objective,
+            time_ms,
+            status: "ok",
+            error: None,
+        },
+        Err(err) => BatchRow {
+            file,
+            problem: None,
+            n: None,
+            objective: None,
+            time_ms,
+            status: "error",
+            error: Some(format!("{err:#}")),
+        },
+    }
+}
+
+fn create_out_dir(dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory {}", dir.display()))
+}
+
+/// `<out_dir>/<input stem>.<suffix>.json`, so outputs never overwrite inputs.
+fn instance_output_path(out_dir: &Path, input: &Path, suffix: &str) -> PathBuf {
+    let stem = input
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    out_dir.join(format!("{stem}.{suffix}.json"))
+}
+
+fn write_json(path: &Path, json: &serde_json::Value) -> Result<()> {
+    let content = serde_json::to_string_pretty(json).context("Failed to serialize JSON")?;
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+const CSV_HEADER: &str = "file,problem,n,objective,time_ms,status,error";
+
+fn to_csv(rows: &[BatchRow]) -> String {
+    let mut csv = format!("{CSV_HEADER}\n");
+    for row in rows {
+        let fields = [
+            row.file.clone(),
+            row.problem.clone().unwrap_or_default(),
+            row.n.map(|n| n.to_string()).unwrap_or_default(),
+            row.objective.clone().unwrap_or_default(),
+            format!("{:.3}", row.time_ms),
+            row.status.to_string(),
+            row.error.clone().unwrap_or_default(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quote a CSV field when it contains a separator, quote, or line break.
+pub(super) fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn report(rows: &[BatchRow], csv_path: Option<&Path>, out: &OutputConfig) -> Result<()> {
+    if let Some(path) = csv_path {
+        std::fs::write(path, to_csv(rows))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        out.info(&format!("Wrote {}", path.display()));
+    }
+
+    let failed = rows.iter().filter(|row| row.status != "ok").count();
+    let table_rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            vec![
+                row.file.clone(),
+                row.n.map(|n| n.to_string()).unwrap_or_default(),
+                row.objective.clone().unwrap_or_default(),
+                format!("{:.1}", row.time_ms),
+                match &row.error {
+                    Some(err) => format!("error: {}", err.lines().next().unwrap_or_default()),
+                    None => row.status.to_string(),
+                },
+            ]
+        })
+        .collect();
+    let mut text = format_table(
+        &[
+            ("File", Align::Left, 4),
+            ("n", Align::Right, 1),
+            ("Objective", Align::Left, 9),
+            ("Time (ms)", Align::Right, 9),
+            ("Status", Align::Left, 6),
+        ],
+        &table_rows,
+        &[],
+    );
+    text.push_str(&format!(
+        "\n{} instances, {} succeeded, {} failed",
+        rows.len(),
+        rows.len() - failed,
+        failed
+    ));
+    let json = serde_json::json!({
+        "instances": rows,
+        "succeeded": rows.len() - failed,
+        "failed": failed,
+    });
+    out.emit_with_default_name("", &text, &json)?;
+
+    if failed > 0 {
+        bail!("{failed} of {} instances failed", rows.len());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quotes_separators() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_instance_output_path_keeps_stem() {
+        let path = instance_output_path(Path::new("out"), Path::new("in/mis.json"), "reduced");
+        assert_eq!(path, Path::new("out/mis.reduced.json"));
+    }
+}
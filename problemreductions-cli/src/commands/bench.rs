@@ -0,0 +1,84 @@
+use crate::cli::BenchArgs;
+use crate::commands::batch::csv_field;
+use crate::commands::solve::{check_solver_name, run_solver};
+use crate::commands::timing::{time_ms, TimingStats};
+use crate::dispatch::{load_problem, read_input, ProblemJson};
+use crate::output::OutputConfig;
+use anyhow::{bail, Context, Result};
+
+const CSV_HEADER: &str = "problem,solver,objective,runs,min_ms,median_ms,mean_ms";
+
+/// Solve one problem file `--repeat` times and report wall-time statistics.
+///
+/// The instance is parsed once up front; only the solver call is timed.
+pub fn bench(args: &BenchArgs, out: &OutputConfig) -> Result<()> {
+    check_solver_name(&args.solver)?;
+    let content = read_input(&args.input)?;
+    let problem_json: ProblemJson = serde_json::from_str(&content)
+        .context("Expected a problem JSON (bench does not accept reduction bundles)")?;
+    let problem = load_problem(
+        &problem_json.problem_type,
+        &problem_json.variant,
+        problem_json.data,
+    )?;
+    let name = problem.problem_name();
+
+    let mut objective: Option<String> = None;
+    let mut samples = Vec::with_capacity(args.repeat);
+    for run in 0..args.repeat {
+        let (result, ms) = time_ms(|| run_solver(&problem, &args.solver));
+        let result = result?;
+        match &objective {
+            None => objective = Some(result.evaluation),
+            Some(first) if *first != result.evaluation => bail!(
+                "{} returned {} on run {} but {} on run 1",
+                args.solver,
+                result.evaluation,
+                run + 1,
+                first
+            ),
+            Some(_) => {}
+        }
+        samples.push(ms);
+    }
+    let objective = objective.expect("--repeat is at least 1");
+    let stats = TimingStats::from_samples(&samples);
+
+    if let Some(path) = &args.out {
+        let row = [
+            name.to_string(),
+            args.solver.clone(),
+            objective.clone(),
+            stats.runs.to_string(),
+            format!("{:.3}", stats.min_ms),
+            format!("{:.3}", stats.median_ms),
+            format!("{:.3}", stats.mean_ms),
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",");
+        std::fs::write(path, format!("{CSV_HEADER}\n{row}\n"))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        out.info(&format!("Wrote {}", path.display()));
+    }
+
+    let text = format!(
+        "Problem: {name}\nSolver: {}\nObjective: {objective}\nRuns: {}\n\
+         Time (ms): min {:.3}, median {:.3}, mean {:.3}",
+        args.solver, stats.runs, stats.min_ms, stats.median_ms, stats.mean_ms
+    );
+    let mut json = serde_json::json!({
+        "problem": name,
+        "variant": problem.variant_map(),
+        "solver": args.solver,
+        "objective": objective,
+        "times_ms": samples,
+    });
+    if let (Some(obj), serde_json::Value::Object(fields)) =
+        (json.as_object_mut(), serde_json::to_value(&stats)?)
+    {
+        obj.extend(fields);
+    }
+    out.emit_with_default_name("", &text, &json)
+}
@@ -12,6 +12,7 @@ use problemreductions::models::algebraic::{
     ClosestVectorProblem, ConsecutiveBlockMinimization, ConsecutiveOnesMatrixAugmentation,
     SparseMatrixCompression,
 };
+use problemreductions::models::csp::CspConstraint;
 use problemreductions::models::formula::Quantifier;
 use problemreductions::models::graph::{
     GeneralizedHex, HamiltonianCircuit, HamiltonianPath, HamiltonianPathBetweenTwoVertices,
@@ -27,7 +28,7 @@ use problemreductions::models::Decision;
 use problemreductions::prelude::*;
 use problemreductions::registry::collect_schemas;
 use problemreductions::topology::{
-    BipartiteGraph, DirectedGraph, Graph, KingsSubgraph, MixedGraph, SimpleGraph,
+    BipartiteGraph, DirectedGraph, Graph, HyperGraph, KingsSubgraph, MixedGraph, SimpleGraph,
     TriangularSubgraph, UnitDiskGraph,
 };
 use serde::Serialize;
@@ -69,6 +70,9 @@ fn all_data_flags_empty(args: &CreateArgs) -> bool {
         && args.couplings.is_none()
         && args.fields.is_none()
         && args.clauses.is_none()
+        && args.xor_clauses.is_none()
+        && args.domains.is_none()
+        && args.constraints.is_none()
         && args.disjuncts.is_none()
         && args.num_vars.is_none()
         && args.matrix.is_none()
@@ -77,6 +81,8 @@ fn all_data_flags_empty(args: &CreateArgs) -> bool {
         && args.target.is_none()
         && args.m.is_none()
         && args.n.is_none()
+        && args.p_bits.is_none()
+        && args.q_bits.is_none()
         && args.num_vertices.is_none()
         && args.source_vertex.is_none()
         && args.target_vertex.is_none()
@@ -104,6 +110,7 @@ fn all_data_flags_empty(args: &CreateArgs) -> bool {
         && args.bundles.is_none()
         && args.universe.is_none()
         && args.biedges.is_none()
+        && args.hyperedges.is_none()
         && args.left.is_none()
         && args.right.is_none()
         && args.rank.is_none()
@@ -111,6 +118,8 @@ fn all_data_flags_empty(args: &CreateArgs) -> bool {
         && args.target_vec.is_none()
         && args.bounds.is_none()
         && args.release_times.is_none()
+        && args.starts.is_none()
+        && args.ends.is_none()
         && args.deadlines.is_none()
         && args.lengths.is_none()
         && args.terminals.is_none()
@@ -210,19 +219,17 @@ fn all_data_flags_empty(args: &CreateArgs) -> bool {
         && args.tiles.is_none()
         && args.grid_size.is_none()
         && args.num_colors.is_none()
+        && args.rectangles.is_none()
+        && args.container_width.is_none()
+        && args.container_height.is_none()
 }
 
 fn emit_problem_output(output: &ProblemJsonOutput, out: &OutputConfig) -> Result<()> {
     let json = serde_json::to_value(output)?;
-    if let Some(ref path) = out.output {
-        let content = serde_json::to_string_pretty(&json).context("Failed to serialize JSON")?;
-        std::fs::write(path, &content)
-            .with_context(|| format!("Failed to write {}", path.display()))?;
-        out.info(&format!("Wrote {}", path.display()));
-    } else {
-        println!("{}", serde_json::to_string_pretty(&json)?);
+    match out.output {
+        Some(ref path) => out.write_json(path, &json),
+        None => out.print_json(&json),
     }
-    Ok(())
 }
 
 fn format_problem_ref(problem: &ProblemRef) -> String {
@@ -603,7 +610,8 @@ pub fn create(args: &CreateArgs, out: &OutputConfig) -> Result<()> {
     let resolved_variant = resolved.variant.clone();
 
     if args.random {
-        return create_random(args, canonical, &resolved_variant, out);
+        let output = random_problem(args, canonical, &resolved_variant)?;
+        return emit_problem_output(&output, out);
     }
 
     // ILP and CircuitSAT have complex input structures not suited for CLI flags.
@@ -616,11 +624,17 @@ pub fn create(args: &CreateArgs, out: &OutputConfig) -> Result<()> {
              Or use the Rust API for direct construction."
         );
     }
+    if canonical == "PseudoBoolean" {
+        bail!(
+            "CLI creation is not yet supported for PseudoBoolean.\n\n\
+             Parse an OPB file with problemreductions::io::from_opb in the Rust API."
+        );
+    }
 
     // Show schema-driven help when no data flags are provided
     if all_data_flags_empty(args) {
         print_problem_help(canonical, &resolved_variant)?;
-        std::process::exit(2);
+        bail!("pred create {canonical} needs instance data; see the parameters above");
     }
 
     let (data, variant) = create_schema_driven(args, canonical, &resolved_variant)?
@@ -669,6 +683,7 @@ fn ser_vertex_weight_problem_with<G: Graph + Serialize>(
         "MaximumClique" => ser(MaximumClique::new(graph, weights)),
         "MinimumDominatingSet" => ser(MinimumDominatingSet::new(graph, weights)),
         "MaximalIS" => ser(MaximalIS::new(graph, weights)),
+        "MinimumMaximalIS" => ser(MinimumMaximalIS::new(graph, weights)),
         _ => unreachable!(),
     }
 }
@@ -920,8 +935,9 @@ fn parse_terminals(args: &CreateArgs, num_vertices: usize) -> Result<Vec<usize>>
     Ok(terminals)
 }
 
-/// Parse `--terminal-pairs` as comma-separated `u-v` vertex pairs.
-fn parse_terminal_pairs(args: &CreateArgs, num_vertices: usize) -> Result<Vec<(usize, usize)>> {
+/// Parse `--terminal-pairs` as comma-separated `u-v` vertex pairs with
+/// in-range, distinct endpoints. Pairs may share vertices.
+fn parse_demand_pairs(args: &CreateArgs, num_vertices: usize) -> Result<Vec<(usize, usize)>> {
     let raw = args
         .terminal_pairs
         .as_deref()
@@ -932,7 +948,6 @@ fn parse_terminal_pairs(args: &CreateArgs, num_vertices: usize) -> Result<Vec<(u
         "at least 1 terminal pair required"
     );
 
-    let mut used = BTreeSet::new();
     for &(source, sink) in &terminal_pairs {
         anyhow::ensure!(
             source < num_vertices,
@@ -943,6 +958,18 @@ fn parse_terminal_pairs(args: &CreateArgs, num_vertices: usize) -> Result<Vec<(u
             "terminal pair sink {sink} >= num_vertices ({num_vertices})"
         );
         anyhow::ensure!(source != sink, "terminal pair endpoints must be distinct");
+    }
+
+    Ok(terminal_pairs)
+}
+
+/// Parse `--terminal-pairs` as comma-separated `u-v` vertex pairs whose
+/// endpoints are pairwise disjoint across all pairs.
+fn parse_terminal_pairs(args: &CreateArgs, num_vertices: usize) -> Result<Vec<(usize, usize)>> {
+    let terminal_pairs = parse_demand_pairs(args, num_vertices)?;
+
+    let mut used = BTreeSet::new();
+    for &(source, sink) in &terminal_pairs {
         anyhow::ensure!(
             used.insert(source) && used.insert(sink),
             "terminal vertices must be pairwise disjoint across terminal pairs"
@@ -1744,6 +1771,20 @@ fn parse_mixed_graph(args: &CreateArgs, usage: &str) -> Result<MixedGraph> {
     ))
 }
 
+fn parse_hypergraph(args: &CreateArgs, usage: &str) -> Result<HyperGraph> {
+    let edges = parse_named_sets(args.hyperedges.as_deref(), "--hyperedges")
+        .map_err(|e| anyhow::anyhow!("{e}\n\n{usage}"))?;
+    let min_vertices = edges.iter().flatten().map(|&v| v + 1).max().unwrap_or(0);
+    let num_vertices = args.num_vertices.unwrap_or(min_vertices);
+    if num_vertices < min_vertices {
+        bail!(
+            "--hyperedges references vertex {} but --num-vertices is {num_vertices}\n\n{usage}",
+            min_vertices - 1
+        );
+    }
+    Ok(HyperGraph::new(num_vertices, edges))
+}
+
 /// Parse `--weights` as arc weights (i32), defaulting to all 1s.
 fn parse_arc_weights(args: &CreateArgs, num_arcs: usize) -> Result<Vec<i32>> {
     match &args.weights {
@@ -1784,12 +1825,12 @@ fn parse_arc_costs(args: &CreateArgs, num_arcs: usize) -> Result<Vec<i32>> {
 
 /// Parse `--candidate-arcs` as `u>v:w` entries for StrongConnectivityAugmentation.
 /// Handle `pred create <PROBLEM> --random ...`
-fn create_random(
+/// Generate a random instance of `canonical` from the `--random` flags.
+pub(crate) fn random_problem(
     args: &CreateArgs,
     canonical: &str,
     resolved_variant: &BTreeMap<String, String>,
-    out: &OutputConfig,
-) -> Result<()> {
+) -> Result<ProblemJsonOutput> {
     let num_vertices = args.num_vertices.ok_or_else(|| {
         anyhow::anyhow!(
             "--random requires --num-vertices\n\n\
@@ -1863,7 +1904,8 @@ fn create_random(
         | "MinimumVertexCover"
         | "MaximumClique"
         | "MinimumDominatingSet"
-        | "MaximalIS" => {
+        | "MaximalIS"
+        | "MinimumMaximalIS" => {
             let weights = vec![1i32; num_vertices];
             match graph_type {
                 "KingsSubgraph" => {
@@ -2148,7 +2190,13 @@ fn create_random(
         }
 
         // Graph problems with edge weights
-        "BottleneckTravelingSalesman" | "MaxCut" | "MaximumMatching" | "TravelingSalesman" => {
+        "BottleneckTravelingSalesman"
+        | "MaxBisection"
+        | "MaxCut"
+        | "MaximumMatching"
+        | "MaximumPlanarSubgraph"
+        | "MinimumEdgeDominatingSet"
+        | "TravelingSalesman" => {
             let edge_prob = args.edge_prob.unwrap_or(0.5);
             if !(0.0..=1.0).contains(&edge_prob) {
                 bail!("--edge-prob must be between 0.0 and 1.0");
@@ -2164,8 +2212,13 @@ fn create_random(
                 "BottleneckTravelingSalesman" => {
                     ser(BottleneckTravelingSalesman::new(graph, edge_weights))?
                 }
+                "MaxBisection" => ser(MaxBisection::new(graph, edge_weights))?,
                 "MaxCut" => ser(MaxCut::new(graph, edge_weights))?,
                 "MaximumMatching" => ser(MaximumMatching::new(graph, edge_weights))?,
+                "MaximumPlanarSubgraph" => ser(MaximumPlanarSubgraph::new(graph, edge_weights))?,
+                "MinimumEdgeDominatingSet" => {
+                    ser(MinimumEdgeDominatingSet::new(graph, edge_weights))?
+                }
                 "TravelingSalesman" => ser(TravelingSalesman::new(graph, edge_weights))?,
                 _ => unreachable!(),
             };
@@ -2279,20 +2332,18 @@ fn create_random(
 
         _ => bail!(
             "Random generation is not supported for {canonical}. \
-             Supported: graph-based problems (MIS, MVC, MaxCut, MaxClique, \
-             MaximumMatching, MinimumDominatingSet, SpinGlass, KColoring, KClique, DecisionMinimumVertexCover, TravelingSalesman, \
+             Supported: graph-based problems (MIS, MVC, MaxCut, MaxBisection, MaxClique, \
+             MaximumMatching, MaximumPlanarSubgraph, MinimumDominatingSet, MinimumEdgeDominatingSet, SpinGlass, KColoring, KClique, DecisionMinimumVertexCover, TravelingSalesman, \
              BottleneckTravelingSalesman, SteinerTreeInGraphs, HamiltonianCircuit, MaximumLeafSpanningTree, SteinerTree, \
              OptimalLinearArrangement, RootedTreeArrangement, HamiltonianPath, LongestCircuit, GeneralizedHex)"
         ),
     };
 
-    let output = ProblemJsonOutput {
+    Ok(ProblemJsonOutput {
         problem_type: canonical.to_string(),
         variant,
         data,
-    };
-
-    emit_problem_output(&output, out)
+    })
 }
 
 /// Parse implication rules from semicolon-separated "antecedents>consequent" strings.
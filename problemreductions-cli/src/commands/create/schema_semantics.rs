@@ -624,6 +624,20 @@ pub(super) fn validate_schema_driven_semantics(
                 "LongestCommonSubsequence requires a positive alphabet. Provide --alphabet-size when all strings are empty.\n\n{usage}"
             );
         }
+        "ShortestCommonSuperstring" => {
+            let usage = "Usage: pred create ShortestCommonSuperstring --strings \"CATG;ATGC;TGCA\" [--alphabet-size 4]";
+            let strings_str = args.strings.as_deref().ok_or_else(|| {
+                anyhow::anyhow!("ShortestCommonSuperstring requires --strings\n\n{usage}")
+            })?;
+            let (_, inferred_alphabet_size) = parse_lcs_strings(strings_str)?;
+            let alphabet_size = args.alphabet_size.unwrap_or(inferred_alphabet_size);
+            anyhow::ensure!(
+                alphabet_size >= inferred_alphabet_size,
+                "--alphabet-size {} is smaller than the inferred alphabet size ({})",
+                alphabet_size,
+                inferred_alphabet_size
+            );
+        }
         "LongestPath" => {
             let usage = "pred create LongestPath --graph 0-1,0-2,1-3,2-3,2-4,3-5,4-5,4-6,5-6,1-6 --edge-lengths 3,2,4,1,5,2,3,2,4,1 --source-vertex 0 --target-vertex 6";
             let (graph, _) =
@@ -689,11 +703,19 @@ pub(super) fn validate_schema_driven_semantics(
         | "MinimumVertexCover"
         | "MaximumClique"
         | "MinimumDominatingSet"
-        | "MaximalIS" => {
+        | "MaximalIS"
+        | "MinimumMaximalIS" => {
             let graph_type = resolved_graph_type(resolved_variant);
             let num_vertices = match graph_type {
                 "KingsSubgraph" | "TriangularSubgraph" => parse_int_positions(args)?.len(),
                 "UnitDiskGraph" => parse_float_positions(args)?.len(),
+                "HyperGraph" => {
+                    let usage = format!(
+                        "Usage: pred create {canonical} {}",
+                        example_for(canonical, Some(graph_type))
+                    );
+                    parse_hypergraph(args, &usage)?.num_vertices()
+                }
                 _ => {
                     parse_graph(args)
                         .map_err(|e| {
@@ -734,6 +756,20 @@ pub(super) fn validate_schema_driven_semantics(
                 }
             }
         }
+        "MinimumSetCovering" => {
+            if let (Some(universe), Some(requirements_str)) =
+                (args.universe, args.requirements.as_deref())
+            {
+                let requirements: Vec<usize> = util::parse_comma_list(requirements_str)?;
+                if requirements.len() != universe {
+                    bail!(
+                        "--requirements must list one value per universe element: expected {}, got {}",
+                        universe,
+                        requirements.len()
+                    );
+                }
+            }
+        }
         "MinimumDummyActivitiesPert" => {
             let usage = "Usage: pred create MinimumDummyActivitiesPert --arcs \"0>2,0>3,1>3,1>4,2>5\" [--num-vertices N]";
             let arcs_str = args.arcs.as_deref().ok_or_else(|| {
@@ -1225,6 +1261,13 @@ pub(super) fn validate_schema_driven_semantics(
             let _ = parse_terminals(args, graph.num_vertices())
                 .map_err(|e| anyhow::anyhow!("{e}\n\n{usage}"))?;
         }
+        "MinimumSteinerForest" => {
+            let usage = "Usage: pred create MinimumSteinerForest --graph 0-1,1-2,2-3,3-4,4-5 --edge-weights 1,1,1,1,1 --terminal-pairs 0-2,3-5";
+            let (graph, _) = parse_graph(args).map_err(|e| anyhow::anyhow!("{e}\n\n{usage}"))?;
+            let _ = parse_edge_weights(args, graph.num_edges())?;
+            let _ = parse_demand_pairs(args, graph.num_vertices())
+                .map_err(|e| anyhow::anyhow!("{e}\n\n{usage}"))?;
+        }
         "TimetableDesign" => {
             let usage = "Usage: pred create TimetableDesign --num-periods 3 --num-craftsmen 5 --num-tasks 5 --craftsman-avail \"1,1,1;1,1,0;0,1,1;1,0,1;1,1,1\" --task-avail \"1,1,0;0,1,1;1,0,1;1,1,1;1,1,1\" --requirements \"1,0,1,0,0;0,1,0,0,1;0,0,0,1,0;0,0,0,0,1;0,1,0,0,0\"";
             let num_periods = args.num_periods.ok_or_else(|| {
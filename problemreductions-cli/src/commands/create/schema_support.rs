@@ -66,6 +66,16 @@ impl CreateContext {
                     .and_then(serde_json::Value::as_array)
                     .map(Vec::len);
             }
+            "HyperGraph" => {
+                self.num_vertices = value
+                    .get("num_vertices")
+                    .and_then(serde_json::Value::as_u64)
+                    .and_then(|raw| usize::try_from(raw).ok());
+                self.num_edges = value
+                    .get("edges")
+                    .and_then(serde_json::Value::as_array)
+                    .map(Vec::len);
+            }
             "UnitDiskGraph" => {
                 self.num_vertices = value
                     .get("positions")
@@ -174,6 +184,10 @@ pub(super) fn create_schema_driven(
             ));
         };
 
+        // Omitted optional fields fall back to the model's serde default.
+        if value.is_null() && normalize_type_name(&concrete_type).starts_with("Option<") {
+            continue;
+        }
         context.remember(&field.name, &concrete_type, &value);
         json_map.insert(field.name.clone(), value);
     }
@@ -250,6 +264,7 @@ pub(super) fn parse_schema_field_value(
     context: &CreateContext,
 ) -> Result<serde_json::Value> {
     match (canonical, field_name) {
+        ("RectanglePacking", "rectangles") => parse_rectangle_list_value(raw),
         ("BoyceCoddNormalFormViolation", "functional_deps") => {
             let num_attributes = args.n.ok_or_else(|| {
                 anyhow::anyhow!("BoyceCoddNormalFormViolation requires --n, --sets, and --target")
@@ -319,10 +334,14 @@ pub(super) fn parse_schema_field_value(
             })?;
             Ok(serde_json::json!(max_length))
         }
-        ("LongestCommonSubsequence", "strings") => {
+        ("LongestCommonSubsequence", "strings") | ("ShortestCommonSuperstring", "strings") => {
             let (strings, _) = parse_lcs_strings(raw)?;
             Ok(serde_json::to_value(strings)?)
         }
+        // The pattern's vertex count is independent of the host graph's.
+        ("SubgraphIsomorphism", "pattern_graph") => {
+            parse_simple_graph_value(raw, &CreateContext::default())
+        }
         ("MinimumDecisionTree", "test_matrix") => {
             let usage = "Usage: pred create MinimumDecisionTree --test-matrix '[[true,true,false,false],[true,false,false,false],[false,true,false,true]]' --num-objects 4 --num-tests 3";
             let matrix: Vec<Vec<bool>> = serde_json::from_str(raw).map_err(|err| {
@@ -398,7 +417,7 @@ pub(super) fn parse_schema_field_value(
 }
 
 pub(super) fn schema_driven_supported_problem(canonical: &str) -> bool {
-    canonical != "ILP" && canonical != "CircuitSAT"
+    canonical != "ILP" && canonical != "CircuitSAT" && canonical != "PseudoBoolean"
 }
 
 pub(super) fn schema_field_flag_keys(
@@ -499,6 +518,14 @@ pub(super) fn derive_schema_field_value(
         )?)?));
     }
 
+    if field_name == "graph" && concrete_type == "HyperGraph" {
+        let usage = format!(
+            "Usage: pred create {canonical} {}",
+            example_for(canonical, Some("HyperGraph"))
+        );
+        return Ok(Some(serde_json::to_value(parse_hypergraph(args, &usage)?)?));
+    }
+
     if field_name == "graph" && concrete_type == "BipartiteGraph" {
         let left = args
             .left
@@ -517,6 +544,25 @@ pub(super) fn derive_schema_field_value(
         ))?));
     }
 
+    // PaintShop stores the sequence pre-indexed; derive every field from --sequence.
+    if canonical == "PaintShop" {
+        let usage = "Usage: pred create PaintShop --sequence a,b,a,c,c,b";
+        let raw = args
+            .sequence
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("PaintShop requires --sequence\n\n{usage}"))?;
+        let labels: Vec<String> = raw.split(',').map(|s| s.trim().to_string()).collect();
+        let mut counts = BTreeMap::<&str, usize>::new();
+        for label in &labels {
+            *counts.entry(label.as_str()).or_default() += 1;
+        }
+        if let Some((label, count)) = counts.iter().find(|(_, &count)| count != 2) {
+            bail!("PaintShop car {label:?} appears {count} times; each car must appear exactly twice\n\n{usage}");
+        }
+        let problem = serde_json::to_value(PaintShop::new(labels))?;
+        return Ok(problem.get(field_name).cloned());
+    }
+
     if canonical == "ClosestVectorProblem"
         && field_name == "bounds"
         && normalize_type_name(concrete_type) == "Vec<VarBounds>"
@@ -538,6 +584,18 @@ pub(super) fn derive_schema_field_value(
         return Ok(Some(serde_json::json!(infer_cbq_num_variables(raw)?)));
     }
 
+    // Exact factor widths double as the bit budgets when --m/--n are omitted.
+    if canonical == "Factoring" && matches!(field_name, "m" | "n") {
+        let width = if field_name == "m" {
+            args.p_bits
+        } else {
+            args.q_bits
+        };
+        if let Some(width) = width {
+            return Ok(Some(serde_json::json!(width)));
+        }
+    }
+
     if canonical == "GroupingBySwapping"
         && field_name == "alphabet_size"
         && normalize_type_name(concrete_type) == "usize"
@@ -579,14 +637,16 @@ pub(super) fn derive_schema_field_value(
         return Ok(Some(serde_json::json!(num_processors)));
     }
 
-    if canonical == "LongestCommonSubsequence"
-        && field_name == "alphabet_size"
+    if matches!(
+        canonical,
+        "LongestCommonSubsequence" | "ShortestCommonSuperstring"
+    ) && field_name == "alphabet_size"
         && normalize_type_name(concrete_type) == "usize"
     {
         let raw = args
             .strings
             .as_deref()
-            .ok_or_else(|| anyhow::anyhow!("LongestCommonSubsequence requires --strings"))?;
+            .ok_or_else(|| anyhow::anyhow!("{canonical} requires --strings"))?;
         let (_, inferred_alphabet_size) = parse_lcs_strings(raw)?;
         return Ok(Some(serde_json::json!(args
             .alphabet_size
@@ -710,6 +770,22 @@ pub(super) fn derive_schema_default_value(
     };
 
     let derived = match field_name {
+        _ if normalized.starts_with("Option<") => Some(serde_json::Value::Null),
+        // Boolean fields are switches: an absent flag means false.
+        _ if normalized == "bool" => Some(serde_json::json!(false)),
+        "weights"
+            if matches!(
+                canonical,
+                "MinimumFeedbackArcSet" | "MaximumAcyclicSubgraph"
+            ) =>
+        {
+            context.num_arcs.and_then(one_list)
+        }
+        "weights" if canonical == "Maximum2Satisfiability" => context
+            .parsed_fields
+            .get("clauses")
+            .and_then(serde_json::Value::as_array)
+            .and_then(|clauses| one_list(clauses.len())),
         "weights" | "vertex_weights" => context.num_vertices.and_then(one_list),
         "edge_weights" | "edge_lengths" => context.num_edges.and_then(one_list),
         "arc_weights" | "arc_lengths" if context.num_arcs.is_some() => {
@@ -718,8 +794,11 @@ pub(super) fn derive_schema_default_value(
         "capacities" if canonical == "PathConstrainedNetworkFlow" => {
             context.num_arcs.and_then(one_list)
         }
-        "couplings" if canonical == "SpinGlass" => context.num_edges.and_then(one_list),
-        "fields" if canonical == "SpinGlass" => match normalized.as_str() {
+        "couplings" if matches!(canonical, "SpinGlass" | "PolySpinGlass") => {
+            context.num_edges.and_then(one_list)
+        }
+        "fields" if matches!(canonical, "SpinGlass" | "PolySpinGlass") => match normalized.as_str()
+        {
             "Vec<i32>" => context
                 .num_vertices
                 .map(|len| serde_json::json!(vec![0_i32; len])),
@@ -735,7 +814,11 @@ pub(super) fn derive_schema_default_value(
 }
 
 pub(super) fn schema_field_requires_derived_input(field_name: &str, concrete_type: &str) -> bool {
-    field_name == "graph" && matches!(concrete_type, "MixedGraph" | "BipartiteGraph")
+    field_name == "graph"
+        && matches!(
+            concrete_type,
+            "MixedGraph" | "BipartiteGraph" | "HyperGraph"
+        )
 }
 
 pub(super) fn with_schema_usage(
@@ -771,7 +854,7 @@ pub(super) fn parse_field_value(
         "Vec<f64>" => parse_numeric_list_value::<f64>(raw)?,
         "Vec<u64>" => parse_numeric_list_value::<u64>(raw)?,
         "Vec<i64>" => parse_numeric_list_value::<i64>(raw)?,
-        "Vec<usize>" => parse_numeric_list_value::<usize>(raw)?,
+        "Vec<usize>" | "Option<Vec<usize>>" => parse_numeric_list_value::<usize>(raw)?,
         "Vec<One>" => parse_numeric_list_value::<i32>(raw)?,
         "Vec<bool>" => parse_bool_list_value(raw)?,
         "Vec<Vec<usize>>" => parse_nested_numeric_list_value::<usize>(raw)?,
@@ -784,7 +867,8 @@ pub(super) fn parse_field_value(
         "Vec<Vec<Vec<usize>>>" => parse_3d_numeric_list_value::<usize>(raw)?,
         "Vec<Vec<Vec<i64>>>" => parse_3d_numeric_list_value::<i64>(raw)?,
         "Vec<[usize;3]>" => parse_triple_array_list_value(raw)?,
-        "Vec<CNFClause>" => serde_json::to_value(parse_clauses_raw(raw)?)?,
+        "Vec<CNFClause>" | "Vec<XorClause>" => serde_json::to_value(parse_clauses_raw(raw)?)?,
+        "Vec<CspConstraint>" => parse_csp_constraints_value(raw)?,
         "Vec<(usize,usize)>" => parse_pair_list_value(raw)?,
         "Vec<(u64,u64)>" => parse_semicolon_tuple_list_value::<u64, 2>(raw)?,
         "Vec<(usize,f64)>" => parse_indexed_numeric_pairs_value::<f64>(raw)?,
@@ -815,7 +899,7 @@ pub(super) fn parse_field_value(
         "IntExpr" => parse_json_passthrough_value(raw)?,
         "bool" => serde_json::to_value(parse_bool_token(raw.trim())?)?,
         "One" => serde_json::json!(1),
-        "usize" => parse_scalar_value::<usize>(raw)?,
+        "usize" | "Option<usize>" => parse_scalar_value::<usize>(raw)?,
         "u64" => parse_scalar_value::<u64>(raw)?,
         "i32" => parse_scalar_value::<i32>(raw)?,
         "i64" => parse_scalar_value::<i64>(raw)?,
@@ -944,6 +1028,21 @@ pub(super) fn parse_pair_list_value(raw: &str) -> Result<serde_json::Value> {
     Ok(serde_json::to_value(pairs)?)
 }
 
+/// Parse rectangles written as `WxH` (e.g., "1x2,2x1").
+pub(super) fn parse_rectangle_list_value(raw: &str) -> Result<serde_json::Value> {
+    let rectangles: Vec<(usize, usize)> = raw
+        .split(',')
+        .map(|entry| {
+            let entry = entry.trim();
+            let Some((width, height)) = entry.split_once(['x', 'X']) else {
+                bail!("Invalid rectangle '{entry}': expected WIDTHxHEIGHT (e.g., 1x2)");
+            };
+            Ok((width.trim().parse()?, height.trim().parse()?))
+        })
+        .collect::<Result<_>>()?;
+    Ok(serde_json::to_value(rectangles)?)
+}
+
 pub(super) fn infer_cbq_num_variables(raw: &str) -> Result<usize> {
     let mut num_vars = 0usize;
     for conjunct in raw.split(';').filter(|entry| !entry.trim().is_empty()) {
@@ -1187,6 +1286,34 @@ pub(super) fn parse_indexed_usize_lists_value(raw: &str) -> Result<serde_json::V
     Ok(serde_json::to_value(entries)?)
 }
 
+pub(super) fn parse_csp_constraints_value(raw: &str) -> Result<serde_json::Value> {
+    let constraints: Vec<CspConstraint> = raw
+        .split(';')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let entry = entry.trim();
+            let (scope, tuples) = entry.split_once(':').ok_or_else(|| {
+                anyhow::anyhow!("Invalid constraint '{entry}': expected scope:tuples")
+            })?;
+            let scope: Vec<usize> = util::parse_comma_list(scope.trim())?;
+            let allowed = tuples
+                .split('|')
+                .filter(|tuple| !tuple.trim().is_empty())
+                .map(|tuple| {
+                    let tuple: Vec<usize> = util::parse_comma_list(tuple.trim())?;
+                    anyhow::ensure!(
+                        tuple.len() == scope.len(),
+                        "Invalid constraint '{entry}': tuple {tuple:?} does not match scope {scope:?}"
+                    );
+                    Ok(tuple)
+                })
+                .collect::<Result<_>>()?;
+            Ok(CspConstraint::new(scope, allowed))
+        })
+        .collect::<Result<_>>()?;
+    Ok(serde_json::to_value(constraints)?)
+}
+
 pub(super) fn parse_string_list_value(raw: &str) -> Result<serde_json::Value> {
     let values: Vec<String> = raw
         .split(';')
@@ -1498,9 +1625,15 @@ pub(super) fn type_format_hint(type_name: &str, graph_type: Option<&str>) -> &'s
         }
         "Vec<Vec<usize>>" => "semicolon-separated sets: \"0,1;1,2;0,2\"",
         "Vec<CNFClause>" => "semicolon-separated clauses: \"1,2;-1,3\"",
+        "Vec<XorClause>" => "semicolon-separated parity clauses: \"1,2,3;-1,4\"",
+        "Vec<CspConstraint>" => {
+            "semicolon-separated scope:tuples, tuples split by '|': \"0,1:0,1|1,0;2:2\""
+        }
         "Vec<Vec<bool>>" => "JSON 2D bool array: '[[true,false],[false,true]]'",
         "Vec<Vec<W>>" => "semicolon-separated rows: \"1,0.5;0.5,2\"",
         "usize" => "integer",
+        "Option<usize>" => "optional integer",
+        "Option<Vec<usize>>" => "optional comma-separated integers: 2,1,1",
         "u64" => "integer",
         "i64" => "integer",
         "BigUint" => "nonnegative decimal integer",
@@ -1520,6 +1653,7 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
             Some("KingsSubgraph") => "--positions \"0,0;1,0;1,1;0,1\"",
             Some("TriangularSubgraph") => "--positions \"0,0;0,1;1,0;1,1\"",
             Some("UnitDiskGraph") => "--positions \"0,0;1,0;0.5,0.8\" --radius 1.5",
+            Some("HyperGraph") => "--hyperedges \"0,1,2;2,3\" --weights 1,1,1,1",
             _ => "--graph 0-1,1-2,2-3 --weights 1,1,1,1",
         },
         "DecisionMinimumVertexCover" => match graph_type {
@@ -1585,6 +1719,11 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
         "BottleneckTravelingSalesman" | "MaxCut" | "MaximumMatching" | "TravelingSalesman" => {
             "--graph 0-1,1-2,2-3 --edge-weights 1,1,1"
         }
+        "MaximumPlanarSubgraph" => {
+            "--graph 0-1,0-2,0-3,0-4,1-2,1-3,1-4,2-3,2-4,3-4 --edge-weights 1,1,1,1,1,1,1,1,1,2"
+        }
+        "MaxBisection" => "--graph 0-1,1-2,2-3,3-0 --edge-weights 1,1,1,1",
+        "MinimumEdgeDominatingSet" => "--graph 0-1,1-2,2-3,3-4,4-0 --edge-weights 1,1,1,1,1",
         "ShortestWeightConstrainedPath" => {
             "--graph 0-1,0-2,1-3,2-3,2-4,3-5,4-5,1-4 --edge-lengths 2,4,3,1,5,4,2,6 --edge-weights 5,1,2,3,2,3,1,1 --source-vertex 0 --target-vertex 5 --weight-bound 8"
         }
@@ -1597,6 +1736,9 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
         }
         "Satisfiability" => "--num-vars 3 --clauses \"1,2;-1,3\"",
         "NAESatisfiability" => "--num-vars 3 --clauses \"1,2,-3;-1,2,3\"",
+        "XorSatisfiability" => "--num-vars 3 --clauses \"3,-1\" --xor-clauses \"1,2,3;1,-2\"",
+        "CSP" => "--domains 2,2,3 --constraints \"0,1:0,1|1,0;1,2:0,1|0,2|1,0|1,2;2:2\"",
+        "ModelCounting" => "--num-vars 3 --clauses \"1,2;-1,3;-2,-3\"",
         "QuantifiedBooleanFormulas" => {
             "--num-vars 3 --clauses \"1,2;-1,3\" --quantifiers \"E,A,E\""
         }
@@ -1614,6 +1756,7 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
         "QUBO" => "--matrix \"1,0.5;0.5,2\"",
         "QuadraticAssignment" => "--matrix \"0,5;5,0\" --distance-matrix \"0,1;1,0\"",
         "SpinGlass" => "--graph 0-1,1-2 --couplings 1,1",
+        "PolySpinGlass" => "--hyperedges \"0,1,2;2,3\" --couplings 1,-1",
         "KColoring" => "--graph 0-1,1-2,2-0 --k 3",
         "HamiltonianCircuit" => "--graph 0-1,1-2,2-3,3-0",
         "MaximumLeafSpanningTree" => "--graph 0-1,0-2,0-3,1-4,2-4,2-5,3-5,4-5,1-3",
@@ -1630,6 +1773,9 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
         "BalancedCompleteBipartiteSubgraph" => {
             "--left 4 --right 4 --biedges 0-0,0-1,0-2,1-0,1-1,1-2,2-0,2-1,2-2,3-0,3-1,3-3 --k 3"
         }
+        "MaximumBalancedBiclique" => {
+            "--left 4 --right 4 --biedges 0-0,0-1,0-2,1-0,1-1,1-2,2-0,2-1,2-2,3-0,3-1,3-3"
+        }
         "MaximumAchromaticNumber" => "--graph 0-1,1-2,2-3,3-4,4-5,5-0",
         "MaximumDomaticNumber" => "--graph 0-1,1-2,0-2",
         "MinimumCoveringByCliques" => "--graph 0-1,1-2,0-2,2-3",
@@ -1638,6 +1784,7 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
         "DegreeConstrainedSpanningTree" => "--graph 0-1,0-2,0-3,1-2,1-4,2-3,3-4 --k 2",
         "MonochromaticTriangle" => "--graph 0-1,0-2,0-3,1-2,1-3,2-3",
         "PartitionIntoTriangles" => "--graph 0-1,1-2,0-2",
+        "MinimumCliqueCover" => "--graph 0-1,1-2,2-3,3-4,4-0",
         "PartitionIntoCliques" => "--graph 0-1,0-2,1-2,3-4,3-5,4-5 --k 3",
         "PartitionIntoForests" => "--graph 0-1,1-2,2-0,3-4,4-5,5-3 --k 2",
         "PartitionIntoPerfectMatchings" => "--graph 0-1,2-3,0-2,1-3 --k 2",
@@ -1649,6 +1796,7 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
             "--num-periods 6 --demands 5,3,7,2,8,5 --capacities 12,12,12,12,12,12 --setup-costs 10,10,10,10,10,10 --production-costs 1,1,1,1,1,1 --inventory-costs 1,1,1,1,1,1 --cost-bound 80"
         }
         "MultiprocessorScheduling" => "--lengths 4,5,3,2,6 --num-processors 2 --deadline 10",
+        "MinimumMultiprocessorScheduling" => "--lengths 3,3,2,2,2 --num-processors 2",
         "PreemptiveScheduling" => {
             "--lengths 2,1,3,2,1 --num-processors 2 --precedences \"0>2,1>3\""
         }
@@ -1661,6 +1809,9 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
         "MinimumMultiwayCut" => "--graph 0-1,1-2,2-3 --terminals 0,2 --edge-weights 1,1,1",
         "ExpectedRetrievalCost" => EXPECTED_RETRIEVAL_COST_EXAMPLE_ARGS,
         "SequencingWithinIntervals" => "--release-times 0,0,5 --deadlines 11,11,6 --lengths 3,1,1",
+        "KTrackIntervalScheduling" => {
+            "--starts 0,1,2,4,5,7 --ends 4,3,6,7,8,9 --weights 3,2,4,2,3,1 --k 2"
+        }
         "StaffScheduling" => {
             "--schedules \"1,1,1,1,1,0,0;0,1,1,1,1,1,0;0,0,1,1,1,1,1;1,0,0,1,1,1,1;1,1,0,0,1,1,1\" --requirements 2,2,2,3,3,2,1 --num-workers 4 --k 5"
         }
@@ -1668,6 +1819,10 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
             "--num-periods 3 --num-craftsmen 5 --num-tasks 5 --craftsman-avail \"1,1,1;1,1,0;0,1,1;1,0,1;1,1,1\" --task-avail \"1,1,0;0,1,1;1,0,1;1,1,1;1,1,1\" --requirements \"1,0,1,0,0;0,1,0,0,1;0,0,0,1,0;0,0,0,0,1;0,1,0,0,0\""
         }
         "SteinerTree" => "--graph 0-1,1-2,1-3,3-4 --edge-weights 2,2,1,1 --terminals 0,2,4",
+        "ClusterEditing" => "--graph 0-1,0-2,1-2,1-3,2-3,3-4 --edge-weights 3,3,3,1,1,2",
+        "MinimumSteinerForest" => {
+            "--graph 0-1,1-2,2-3,3-4,4-5 --edge-weights 1,1,1,1,1 --terminal-pairs 0-2,3-5"
+        }
         "MultipleCopyFileAllocation" => {
             MULTIPLE_COPY_FILE_ALLOCATION_EXAMPLE_ARGS
         }
@@ -1683,6 +1838,7 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
             "--arcs \"0>1,0>2,0>3,1>4,2>4,3>4\" --edge-weights 3,1,2,0,0,0 --capacities 2,2,2,2,2,2 --source 0 --sink 4 --requirement 3"
         }
         "MinimumFeedbackArcSet" => "--arcs \"0>1,1>2,2>0\"",
+        "MaximumAcyclicSubgraph" => "--arcs \"0>1,1>2,2>0,1>3,3>0,2>3\"",
         "DirectedHamiltonianPath" => {
             "--arcs \"0>1,0>3,1>3,1>4,2>0,2>4,3>2,3>5,4>5,5>1\" --num-vertices 6"
         }
@@ -1690,6 +1846,7 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
         "MinimumGeometricConnectedDominatingSet" => {
             "--positions \"0,0;3,0;6,0;9,0;0,3;3,3;6,3;9,3\" --radius 3.5"
         }
+        "MinimumWeightTriangulation" => "--positions \"0,0;2,0;6,2;2,4;0,3\"",
         "MinimumDummyActivitiesPert" => "--arcs \"0>2,0>3,1>3,1>4,2>5\" --num-vertices 6",
         "FeasibleRegisterAssignment" => {
             "--arcs \"0>1,0>2,1>3\" --assignment 0,1,0,0 --k 2 --num-vertices 4"
@@ -1760,6 +1917,8 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
         "Clustering" => {
             "--distance-matrix \"0,1,1,3;1,0,1,3;1,1,0,3;3,3,3,0\" --k 2 --diameter-bound 1"
         }
+        "KCenter" => "--distance-matrix \"0,1,2,3;1,0,1,2;2,1,0,1;3,2,1,0\" --k 2",
+        "RectanglePacking" => "--rectangles 1x2,2x1,2x1 --container-width 3 --container-height 2",
         "SumOfSquaresPartition" => "--sizes 5,3,8,2,7,1 --num-groups 3",
         "ComparativeContainment" => {
             "--universe-size 4 --r-sets \"0,1,2,3;0,1\" --s-sets \"0,1,2,3;2,3\" --r-weights 2,5 --s-weights 3,6"
@@ -1783,6 +1942,7 @@ pub(super) fn example_for(canonical: &str, graph_type: Option<&str>) -> &'static
             "--alphabet-size 6 --subsets \"0,1,2;3,4,5;1,3;2,4;0,5\""
         }
         "ShortestCommonSupersequence" => "--strings \"0,1,2;1,2,0\"",
+        "ShortestCommonSuperstring" => "--strings \"CATG;ATGC;TGCA\"",
         "ConsecutiveBlockMinimization" => "--matrix '[[true,false,true],[false,true,true]]' --bound-k 2",
         "ConsecutiveOnesMatrixAugmentation" => {
             "--matrix \"1,0,0,1,1;1,1,0,0,0;0,1,1,0,1;0,0,1,1,0\" --bound 2"
@@ -1834,8 +1994,11 @@ pub(super) fn uses_edge_weights_flag(canonical: &str) -> bool {
             | "BoundedDiameterSpanningTree"
             | "KthBestSpanningTree"
             | "LongestCircuit"
+            | "MaxBisection"
             | "MaxCut"
             | "MaximumMatching"
+            | "MaximumPlanarSubgraph"
+            | "MinimumEdgeDominatingSet"
             | "MixedChinesePostman"
             | "RuralPostman"
             | "TravelingSalesman"
@@ -1845,7 +2008,7 @@ pub(super) fn uses_edge_weights_flag(canonical: &str) -> bool {
 pub(super) fn uses_edge_weights_flag_for_edge_lengths(canonical: &str) -> bool {
     matches!(
         canonical,
-        "LongestCircuit" | "MinMaxMulticenter" | "MinimumSumMulticenter"
+        "LongestCircuit" | "MinMaxMulticenter" | "MinimumSumMulticenter" | "RuralPostman"
     )
 }
 
@@ -1858,6 +2021,8 @@ pub(super) fn help_flag_name(canonical: &str, field_name: &str) -> String {
         ("BoyceCoddNormalFormViolation", "functional_deps") => return "sets".to_string(),
         ("BoyceCoddNormalFormViolation", "target_subset") => return "target".to_string(),
         ("CapacityAssignment", "cost") => return "cost-matrix".to_string(),
+        ("SubgraphIsomorphism", "host_graph") => return "graph".to_string(),
+        ("SubgraphIsomorphism", "pattern_graph") => return "pattern".to_string(),
         ("CapacityAssignment", "delay") => return "delay-matrix".to_string(),
         ("FlowShopScheduling", "num_processors")
         | ("JobShopScheduling", "num_processors")
@@ -1866,7 +2031,11 @@ pub(super) fn help_flag_name(canonical: &str, field_name: &str) -> String {
             return "num-processors/--m".to_string();
         }
         ("JobShopScheduling", "jobs") => return "jobs".to_string(),
+        ("KCenter", "distances") => return "distance-matrix".to_string(),
+        ("KCenter", "num_centers") => return "k".to_string(),
+        ("KTrackIntervalScheduling", "num_tracks") => return "k".to_string(),
         ("LengthBoundedDisjointPaths", "max_length") => return "max-length".to_string(),
+        ("MinimumSetCovering", "coverage_requirements") => return "requirements".to_string(),
         ("ConsecutiveBlockMinimization", "bound") => return "bound-k".to_string(),
         ("GroupingBySwapping", "budget") => return "bound".to_string(),
         ("RectilinearPictureCompression", "bound") => return "bound".to_string(),
@@ -1948,11 +2117,12 @@ pub(super) fn help_flag_hint(
         ("DynamicStorageAllocation", "deadlines") => "comma-separated departure times: 3,2,4,5,5",
         ("DynamicStorageAllocation", "sizes") => "comma-separated item sizes: 2,3,1,3,2",
         ("DynamicStorageAllocation", "capacity") => "memory size D: 6",
-        ("DisjointConnectingPaths", "terminal_pairs") => "comma-separated pairs: 0-3,2-5",
+        ("DisjointConnectingPaths", "terminal_pairs")
+        | ("MinimumSteinerForest", "terminal_pairs") => "comma-separated pairs: 0-3,2-5",
         ("PrimeAttributeName", "dependencies") => {
             "semicolon-separated dependencies: \"0,1>2,3;2,3>0,1\""
         }
-        ("LongestCommonSubsequence", "strings") => {
+        ("LongestCommonSubsequence", "strings") | ("ShortestCommonSuperstring", "strings") => {
             "raw strings: \"ABAC;BACA\" or symbol lists: \"0,1,0;1,0,1\""
         }
         ("GroupingBySwapping", "string") => "symbol list: \"0,1,2,0,1,2\"",
@@ -2161,6 +2331,25 @@ pub(super) fn print_problem_help(
                     "  --{:<16} Directed arcs A of the mixed graph (directed arcs: 0>1,1>2,2>0)",
                     "arcs"
                 );
+            } else if field.type_name == "HyperGraph"
+                || (field.type_name == "G" && graph_type == Some("HyperGraph"))
+            {
+                eprintln!(
+                    "  --{:<16} {} (vertex sets: \"0,1,2;2,3\")",
+                    "hyperedges", field.description
+                );
+                eprintln!(
+                    "  --{:<16} Number of vertices [default: largest index + 1]",
+                    "num-vertices"
+                );
+            } else if canonical == "PaintShop" {
+                // Every stored field is derived from the single --sequence flag.
+                if field.name == "sequence_indices" {
+                    eprintln!(
+                        "  --{:<16} Car labels, each appearing exactly twice (list: a,b,a,c,c,b)",
+                        "sequence"
+                    );
+                }
             } else if field.type_name == "BipartiteGraph" {
                 eprintln!(
                     "  --{:<16} Vertices in the left partition (integer)",
@@ -2290,7 +2479,8 @@ pub(super) fn format_schema_help_example_value(
     match normalize_type_name(concrete_type).as_str() {
         "SimpleGraph" => format_simple_graph_example(value),
         "DirectedGraph" => format_directed_graph_example(value),
-        "Vec<CNFClause>" => format_cnf_clause_list_example(value),
+        "Vec<CNFClause>" | "Vec<XorClause>" => format_cnf_clause_list_example(value),
+        "Vec<CspConstraint>" => format_csp_constraint_list_example(value),
         "Vec<Quantifier>" => format_quantifier_list_example(value),
         "Vec<Vec<(usize,u64)>>" => format_job_shop_example(value),
         "Vec<(Vec<usize>,Vec<usize>)>" => format_dependency_example(value),
@@ -2371,6 +2561,27 @@ pub(super) fn format_cnf_clause_list_example(value: &serde_json::Value) -> Optio
     )
 }
 
+pub(super) fn format_csp_constraint_list_example(value: &serde_json::Value) -> Option<String> {
+    Some(
+        value
+            .as_array()?
+            .iter()
+            .map(|constraint| {
+                let scope = format_scalar_array_example(constraint.get("scope")?)?;
+                let tuples = constraint
+                    .get("allowed")?
+                    .as_array()?
+                    .iter()
+                    .map(format_scalar_array_example)
+                    .collect::<Option<Vec<_>>>()?
+                    .join("|");
+                Some(format!("{scope}:{tuples}"))
+            })
+            .collect::<Option<Vec<_>>>()?
+            .join(";"),
+    )
+}
+
 pub(super) fn format_bool_matrix_example(value: &serde_json::Value) -> Option<String> {
     Some(
         value
@@ -2492,6 +2703,9 @@ pub(super) fn problem_help_flag_name(
     if field_type == "MixedGraph" {
         return "graph".to_string();
     }
+    if field_type == "HyperGraph" {
+        return "hyperedges".to_string();
+    }
     if canonical == "LengthBoundedDisjointPaths" && field_name == "max_length" {
         return "max-length".to_string();
     }
@@ -383,6 +383,36 @@ fn test_create_schema_driven_builds_mixed_chinese_postman() {
     assert_eq!(data["edge_weights"], serde_json::json!([2, 3, 1, 2]));
 }
 
+#[test]
+fn test_create_schema_driven_builds_rural_postman_from_edge_weights_flag() {
+    let cli = Cli::parse_from([
+        "pred",
+        "create",
+        "RuralPostman",
+        "--graph",
+        "0-1,1-2,2-3,3-0",
+        "--edge-weights",
+        "1,2,3,4",
+        "--required-edges",
+        "0,2",
+    ]);
+
+    let Commands::Create(args) = cli.command else {
+        panic!("expected create command");
+    };
+
+    let resolved_variant = variant_map(&[("graph", "SimpleGraph"), ("weight", "i32")]);
+    let (data, variant) = create_schema_driven(&args, "RuralPostman", &resolved_variant)
+        .expect("schema-driven create should parse")
+        .expect("schema-driven path should support rural postman");
+
+    let entry = problemreductions::registry::find_variant_entry("RuralPostman", &variant)
+        .expect("variant entry");
+    (entry.factory)(data.clone()).expect("factory should deserialize generated JSON");
+    assert_eq!(data["edge_lengths"], serde_json::json!([1, 2, 3, 4]));
+    assert_eq!(data["required_edges"], serde_json::json!([0, 2]));
+}
+
 #[test]
 fn test_create_schema_driven_builds_unit_disk_graph_problem_with_default_radius() {
     let cli = Cli::parse_from([
@@ -495,6 +525,7 @@ fn test_create_scheduling_with_individual_deadlines_accepts_m_alias() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     create(&args, &out).expect("`--m` should satisfy --num-processors alias");
 
@@ -532,6 +563,7 @@ fn test_create_prime_attribute_name_accepts_canonical_flags() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).expect("create PrimeAttributeName JSON");
@@ -659,6 +691,7 @@ fn test_create_staff_scheduling_outputs_problem_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -674,6 +707,86 @@ fn test_create_staff_scheduling_outputs_problem_json() {
     std::fs::remove_file(output_path).unwrap();
 }
 
+#[test]
+fn test_create_minimum_set_covering_with_requirements() {
+    let cli = Cli::try_parse_from([
+        "pred",
+        "create",
+        "MinimumSetCovering",
+        "--universe-size",
+        "3",
+        "--subsets",
+        "0,1;1,2;2,0",
+        "--weights",
+        "1,1,1",
+        "--requirements",
+        "2,2,1",
+    ])
+    .unwrap();
+
+    let args = match cli.command {
+        Commands::Create(args) => args,
+        _ => panic!("expected create command"),
+    };
+
+    let suffix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let output_path = std::env::temp_dir().join(format!("set-multicover-create-{suffix}.json"));
+    let out = OutputConfig {
+        output: Some(output_path.clone()),
+        quiet: true,
+        json: false,
+        auto_json: false,
+        ..Default::default()
+    };
+
+    create(&args, &out).unwrap();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&output_path).unwrap()).unwrap();
+    assert_eq!(json["type"], "MinimumSetCovering");
+    assert_eq!(
+        json["data"]["coverage_requirements"],
+        serde_json::json!([2, 2, 1])
+    );
+    std::fs::remove_file(output_path).unwrap();
+}
+
+#[test]
+fn test_create_minimum_set_covering_rejects_requirements_length_mismatch() {
+    let cli = Cli::try_parse_from([
+        "pred",
+        "create",
+        "MinimumSetCovering",
+        "--universe-size",
+        "3",
+        "--subsets",
+        "0,1;1,2;2,0",
+        "--weights",
+        "1,1,1",
+        "--requirements",
+        "2,2",
+    ])
+    .unwrap();
+
+    let args = match cli.command {
+        Commands::Create(args) => args,
+        _ => panic!("expected create command"),
+    };
+    let out = OutputConfig {
+        output: None,
+        quiet: true,
+        json: false,
+        auto_json: false,
+        ..Default::default()
+    };
+
+    let err = create(&args, &out).unwrap_err().to_string();
+    assert!(err.contains("--requirements must list one value per universe element"));
+}
+
 #[test]
 fn test_create_path_constrained_network_flow_outputs_problem_json() {
     let cli = Cli::try_parse_from([
@@ -706,6 +819,7 @@ fn test_create_path_constrained_network_flow_outputs_problem_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).expect("create PathConstrainedNetworkFlow JSON");
@@ -752,6 +866,7 @@ fn test_create_path_constrained_network_flow_rejects_invalid_paths() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -785,9 +900,10 @@ fn test_create_staff_scheduling_reports_invalid_schedule_without_panic() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
-    let result = std::panic::catch_unwind(|| create(&args, &out));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| create(&args, &out)));
     assert!(result.is_ok(), "create should return an error, not panic");
     let err = result.unwrap().unwrap_err().to_string();
     // parse_bool_rows catches ragged rows before validate_staff_scheduling_args
@@ -847,6 +963,7 @@ fn test_create_three_partition_outputs_problem_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).expect("create ThreePartition JSON");
@@ -878,6 +995,7 @@ fn test_create_three_partition_requires_bound() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -907,6 +1025,7 @@ fn test_create_three_partition_rejects_invalid_instance() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -949,6 +1068,7 @@ fn test_create_timetable_design_outputs_problem_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -1023,9 +1143,10 @@ fn test_create_timetable_design_reports_invalid_matrix_without_panic() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
-    let result = std::panic::catch_unwind(|| create(&args, &out));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| create(&args, &out)));
     assert!(result.is_ok(), "create should return an error, not panic");
     let err = result.unwrap().unwrap_err().to_string();
     assert!(
@@ -1057,6 +1178,7 @@ fn test_create_generalized_hex_serializes_problem_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1091,6 +1213,7 @@ fn test_create_generalized_hex_requires_sink() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1125,6 +1248,7 @@ fn test_create_capacity_assignment_serializes_problem_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1171,6 +1295,7 @@ fn test_create_production_planning_serializes_problem_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1232,6 +1357,7 @@ fn test_create_production_planning_requires_all_period_vectors() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1271,6 +1397,7 @@ fn test_create_production_planning_rejects_mismatched_period_lengths() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1300,6 +1427,7 @@ fn test_create_example_production_planning_uses_canonical_example() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1341,6 +1469,7 @@ fn test_create_longest_path_serializes_problem_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1391,6 +1520,7 @@ fn test_create_undirected_flow_lower_bounds_serializes_problem_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1433,6 +1563,7 @@ fn test_create_capacity_assignment_rejects_non_monotone_cost_row() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1465,6 +1596,7 @@ fn test_create_capacity_assignment_rejects_matrix_width_mismatch() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1495,6 +1627,7 @@ fn test_create_longest_path_requires_edge_lengths() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1530,6 +1663,7 @@ fn test_create_longest_path_rejects_weights_flag() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1565,6 +1699,7 @@ fn test_create_undirected_flow_lower_bounds_requires_lower_bounds() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
     let args = match cli.command {
         Commands::Create(args) => args,
@@ -1605,6 +1740,9 @@ fn empty_args() -> CreateArgs {
         couplings: None,
         fields: None,
         clauses: None,
+        xor_clauses: None,
+        domains: None,
+        constraints: None,
         disjuncts: None,
         num_vars: None,
         matrix: None,
@@ -1619,6 +1757,8 @@ fn empty_args() -> CreateArgs {
         target: None,
         m: None,
         n: None,
+        p_bits: None,
+        q_bits: None,
         positions: None,
         radius: None,
         source_1: None,
@@ -1641,6 +1781,7 @@ fn empty_args() -> CreateArgs {
         bundles: None,
         universe: None,
         biedges: None,
+        hyperedges: None,
         left: None,
         right: None,
         rank: None,
@@ -1648,6 +1789,8 @@ fn empty_args() -> CreateArgs {
         target_vec: None,
         bounds: None,
         release_times: None,
+        starts: None,
+        ends: None,
         lengths: None,
         terminals: None,
         terminal_pairs: None,
@@ -1747,6 +1890,10 @@ fn empty_args() -> CreateArgs {
         tiles: None,
         grid_size: None,
         num_colors: None,
+        rectangles: None,
+        container_width: None,
+        container_height: None,
+        allow_rotation: false,
     }
 }
 
@@ -1829,6 +1976,7 @@ fn test_create_disjoint_connecting_paths_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -1862,12 +2010,47 @@ fn test_create_disjoint_connecting_paths_rejects_overlapping_terminal_pairs() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
     assert!(err.contains("pairwise disjoint"));
 }
 
+#[test]
+fn test_create_minimum_steiner_forest_allows_shared_terminals() {
+    use crate::dispatch::ProblemJsonOutput;
+    use problemreductions::models::graph::MinimumSteinerForest;
+
+    let mut args = empty_args();
+    args.problem = Some("MinimumSteinerForest".to_string());
+    args.graph = Some("0-1,1-2,2-3,3-4".to_string());
+    args.edge_weights = Some("1,1,1,1".to_string());
+    args.terminal_pairs = Some("0-2,2-4".to_string());
+
+    let output_path = std::env::temp_dir().join(format!("msf-create-{}.json", std::process::id()));
+    let out = OutputConfig {
+        output: Some(output_path.clone()),
+        quiet: true,
+        json: false,
+        auto_json: false,
+        ..Default::default()
+    };
+
+    create(&args, &out).unwrap();
+
+    let json = std::fs::read_to_string(&output_path).unwrap();
+    let created: ProblemJsonOutput = serde_json::from_str(&json).unwrap();
+    assert_eq!(created.problem_type, "MinimumSteinerForest");
+
+    let problem: MinimumSteinerForest<SimpleGraph, i32> =
+        serde_json::from_value(created.data).unwrap();
+    assert_eq!(problem.edge_weights(), &[1, 1, 1, 1]);
+    assert_eq!(problem.terminal_pairs(), &[(0, 2), (2, 4)]);
+
+    let _ = std::fs::remove_file(output_path);
+}
+
 #[test]
 fn test_parse_homologous_pairs() {
     let mut args = empty_args();
@@ -1930,6 +2113,7 @@ fn test_create_biconnectivity_augmentation_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -1960,6 +2144,7 @@ fn test_create_biconnectivity_augmentation_json_with_isolated_vertices() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -1992,6 +2177,7 @@ fn test_create_partial_feedback_edge_set_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2024,6 +2210,7 @@ fn test_create_partial_feedback_edge_set_requires_max_cycle_length() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2044,6 +2231,7 @@ fn test_create_ensemble_computation_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2080,6 +2268,7 @@ fn test_create_expected_retrieval_cost_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2118,6 +2307,7 @@ fn test_create_job_shop_scheduling_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2149,6 +2339,7 @@ fn test_create_job_shop_scheduling_requires_job_tasks() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2166,6 +2357,7 @@ fn test_create_job_shop_scheduling_rejects_malformed_operation() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2183,6 +2375,7 @@ fn test_create_job_shop_scheduling_rejects_consecutive_same_processor() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2204,6 +2397,7 @@ fn test_create_rooted_tree_storage_assignment_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2237,6 +2431,7 @@ fn test_create_stacker_crane_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2267,6 +2462,7 @@ fn test_create_stacker_crane_rejects_mismatched_arc_lengths() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2289,6 +2485,7 @@ fn test_create_stacker_crane_rejects_out_of_range_vertices() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2311,6 +2508,7 @@ fn test_create_minimum_dummy_activities_pert_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2339,6 +2537,7 @@ fn test_create_minimum_dummy_activities_pert_rejects_cycles() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2364,6 +2563,7 @@ fn test_create_balanced_complete_bipartite_subgraph() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2397,6 +2597,7 @@ fn test_create_balanced_complete_bipartite_subgraph_rejects_out_of_range_biedges
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2420,6 +2621,7 @@ fn test_create_kclique() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2452,6 +2654,7 @@ fn test_create_kclique_requires_valid_k() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err();
@@ -2483,6 +2686,7 @@ fn test_create_sparse_matrix_compression_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2518,6 +2722,7 @@ fn test_create_sparse_matrix_compression_requires_bound() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2537,6 +2742,7 @@ fn test_create_sparse_matrix_compression_rejects_zero_bound() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2570,6 +2776,7 @@ fn test_create_graph_partitioning_with_num_partitions() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2609,6 +2816,7 @@ fn test_create_nontautology_with_disjuncts_flag() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2637,6 +2845,7 @@ fn test_create_consecutive_ones_matrix_augmentation_json() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     create(&args, &out).unwrap();
@@ -2672,6 +2881,7 @@ fn test_create_consecutive_ones_matrix_augmentation_requires_bound() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
@@ -2691,6 +2901,7 @@ fn test_create_consecutive_ones_matrix_augmentation_negative_bound() {
         quiet: true,
         json: false,
         auto_json: false,
+        ..Default::default()
     };
 
     let err = create(&args, &out).unwrap_err().to_string();
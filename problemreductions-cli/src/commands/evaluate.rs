@@ -3,7 +3,13 @@ use crate::output::OutputConfig;
 use anyhow::{Context, Result};
 use std::path::Path;
 
-pub fn evaluate(input: &Path, config_str: &str, out: &OutputConfig) -> Result<()> {
+pub fn evaluate(
+    input: &Path,
+    config_str: Option<&str>,
+    config_file: Option<&Path>,
+    breakdown: bool,
+    out: &OutputConfig,
+) -> Result<()> {
     let content = read_input(input)?;
     let json: serde_json::Value =
         serde_json::from_str(&content).context("Input is not valid JSON")?;
@@ -25,14 +31,11 @@ pub fn evaluate(input: &Path, config_str: &str, out: &OutputConfig) -> Result<()
         problem_json.data,
     )?;
 
-    let config: Vec<usize> = config_str
-        .split(',')
-        .map(|s| {
-            s.trim()
-                .parse::<usize>()
-                .map_err(|e| anyhow::anyhow!("Invalid config value '{}': {}", s.trim(), e))
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let config = match (config_str, config_file) {
+        (Some(config_str), _) => parse_config_values(config_str.split(','))?,
+        (None, Some(path)) => read_config_file(path)?,
+        (None, None) => anyhow::bail!("Provide a configuration with --config or --config-file"),
+    };
 
     let dims = problem.dims_dyn();
     if config.len() != dims.len() {
@@ -54,12 +57,49 @@ pub fn evaluate(input: &Path, config_str: &str, out: &OutputConfig) -> Result<()
 
     let result = problem.evaluate_dyn(&config);
 
-    let text = result.to_string();
-    let json = serde_json::json!({
+    let mut text = result.to_string();
+    let mut json = serde_json::json!({
         "problem": problem.problem_name(),
         "config": config,
         "result": result,
     });
 
+    if breakdown {
+        let terms = problem.objective_terms_dyn(&config);
+        if terms.is_empty() {
+            anyhow::bail!(
+                "{} does not provide a per-term objective breakdown",
+                problem.problem_name()
+            );
+        }
+        for term in &terms {
+            text.push_str(&format!("\n  {}: {}", term.label, term.value));
+        }
+        let total: f64 = terms.iter().map(|term| term.value).sum();
+        text.push_str(&format!("\n  total: {total}"));
+        json["breakdown"] = serde_json::to_value(&terms)?;
+    }
+
     out.emit_with_default_name("pred_evaluate.json", &text, &json)
 }
+
+/// Read a configuration file holding one value per line or a JSON array.
+fn read_config_file(path: &Path) -> Result<Vec<usize>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file {}", path.display()))?;
+    if content.trim_start().starts_with('[') {
+        return serde_json::from_str(&content)
+            .with_context(|| format!("Config file {} is not a JSON array", path.display()));
+    }
+    parse_config_values(content.lines().filter(|line| !line.trim().is_empty()))
+}
+
+fn parse_config_values<'a>(values: impl Iterator<Item = &'a str>) -> Result<Vec<usize>> {
+    values
+        .map(|s| {
+            s.trim()
+                .parse::<usize>()
+                .map_err(|e| anyhow::anyhow!("Invalid config value '{}': {}", s.trim(), e))
+        })
+        .collect()
+}
@@ -1,13 +1,26 @@
+use crate::cli::{GraphFormat, ListGroupBy};
+use crate::dispatch::ProblemJsonOutput;
 use crate::output::OutputConfig;
 use crate::problem_name::{aliases_for, parse_problem_spec, resolve_problem_ref};
 use anyhow::{Context, Result};
-use problemreductions::registry::collect_schemas;
-use problemreductions::rules::{Minimize, MinimizeSteps, ReductionGraph, TraversalFlow};
+use problemreductions::export::ProblemRef;
+use problemreductions::registry::{
+    collect_schemas, find_problem_type, problems_in_category, problems_with_complexity,
+    ComplexityClass, ProblemCategory,
+};
+use problemreductions::rules::{
+    Minimize, MinimizeSteps, ReductionCluster, ReductionGraph, TraversalFlow,
+};
 use problemreductions::types::ProblemSize;
 use problemreductions::{big_o_normal_form, Expr};
 use std::collections::BTreeMap;
 
-pub fn list(out: &OutputConfig) -> Result<()> {
+pub fn list(
+    complexity: Option<&str>,
+    category: Option<&str>,
+    group_by: Option<ListGroupBy>,
+    out: &OutputConfig,
+) -> Result<()> {
     use crate::output::{format_table, Align};
 
     let graph = ReductionGraph::new();
@@ -15,6 +28,38 @@ pub fn list(out: &OutputConfig) -> Result<()> {
     let mut types = graph.problem_types();
     types.sort();
 
+    let mut filters: Vec<String> = Vec::new();
+    if let Some(name) = complexity {
+        let class = ComplexityClass::from_name(name).ok_or_else(|| {
+            let valid: Vec<&str> = ComplexityClass::ALL.iter().map(|c| c.name()).collect();
+            anyhow::anyhow!(
+                "Unknown complexity class '{name}'. Valid classes: {}",
+                valid.join(", ")
+            )
+        })?;
+        let names: Vec<&str> = problems_with_complexity(class)
+            .into_iter()
+            .map(|info| info.name)
+            .collect();
+        types.retain(|name| names.contains(name));
+        filters.push(format!("complexity {class}"));
+    }
+    if let Some(name) = category {
+        let category = ProblemCategory::from_name(name).ok_or_else(|| {
+            let valid: Vec<&str> = ProblemCategory::ALL.iter().map(|c| c.name()).collect();
+            anyhow::anyhow!(
+                "Unknown category '{name}'. Valid categories: {}",
+                valid.join(", ")
+            )
+        })?;
+        let names: Vec<&str> = problems_in_category(category)
+            .into_iter()
+            .map(|info| info.name)
+            .collect();
+        types.retain(|name| names.contains(name));
+        filters.push(format!("category {category}"));
+    }
+
     // Collect data: one row per variant, grouped by problem type.
     struct VariantRow {
         /// Full problem/variant name (e.g., "MIS/SimpleGraph/i32")
@@ -23,14 +68,30 @@ pub fn list(out: &OutputConfig) -> Result<()> {
         aliases: String,
         /// Whether this variant is the default
         is_default: bool,
+        /// Whether this is the first listed variant of its problem type
+        is_first: bool,
         /// Number of outgoing reductions from this variant
         rules: usize,
         /// Best-known complexity
         complexity: String,
+        /// Group label for `--group-by` (category or complexity class name)
+        group: &'static str,
     }
 
     let mut rows_data: Vec<VariantRow> = Vec::new();
     for name in &types {
+        let problem_type = find_problem_type(name);
+        let group = match group_by {
+            Some(ListGroupBy::Category) => problem_type
+                .as_ref()
+                .and_then(|pt| pt.category())
+                .map_or("other", |category| category.name()),
+            Some(ListGroupBy::Complexity) => problem_type
+                .as_ref()
+                .map_or(ComplexityClass::Unknown, |pt| pt.complexity_class())
+                .name(),
+            None => "",
+        };
         let variants = graph.variants_for(name);
         let default_variant = graph.default_variant_for(name);
         let problem_aliases = aliases_for(name);
@@ -69,18 +130,47 @@ pub fn list(out: &OutputConfig) -> Result<()> {
                 display,
                 aliases: parts.join(", "),
                 is_default,
+                is_first: i == 0,
                 rules: if i == 0 { rules } else { 0 },
                 complexity,
+                group,
             });
         }
     }
 
-    let summary = format!(
-        "Registered problems: {} types, {} reductions, {} variant nodes\n",
-        graph.num_types(),
-        graph.num_reductions(),
-        graph.num_variant_nodes(),
-    );
+    // Order groups as the category / complexity class enums list them.
+    let group_order: Vec<&str> = match group_by {
+        Some(ListGroupBy::Category) => ProblemCategory::ALL.iter().map(|c| c.name()).collect(),
+        Some(ListGroupBy::Complexity) => ComplexityClass::ALL.iter().map(|c| c.name()).collect(),
+        None => Vec::new(),
+    };
+    let mut groups: Vec<(&str, Vec<&VariantRow>)> = Vec::new();
+    if group_by.is_some() {
+        for label in group_order.iter().copied().chain(["other"]) {
+            let members: Vec<&VariantRow> = rows_data.iter().filter(|r| r.group == label).collect();
+            if !members.is_empty() {
+                groups.push((label, members));
+            }
+        }
+    } else {
+        groups.push(("", rows_data.iter().collect()));
+    }
+
+    let summary = if filters.is_empty() {
+        format!(
+            "Registered problems: {} types, {} reductions, {} variant nodes\n",
+            graph.num_types(),
+            graph.num_reductions(),
+            graph.num_variant_nodes(),
+        )
+    } else {
+        format!(
+            "Matching problems ({}): {} types, {} variant nodes\n",
+            filters.join(", "),
+            types.len(),
+            rows_data.len(),
+        )
+    };
 
     let columns: Vec<(&str, Align, usize)> = vec![
         ("Problem", Align::Left, 7),
@@ -89,51 +179,78 @@ pub fn list(out: &OutputConfig) -> Result<()> {
         ("Complexity", Align::Left, 10),
     ];
 
-    let rows: Vec<Vec<String>> = rows_data
-        .iter()
-        .map(|r| {
-            let label = if r.is_default {
-                format!("{} *", r.display)
-            } else {
-                r.display.clone()
-            };
-            vec![
-                label,
-                r.aliases.clone(),
-                if r.rules > 0 {
-                    r.rules.to_string()
+    let table_rows = |members: &[&VariantRow]| -> Vec<Vec<String>> {
+        members
+            .iter()
+            .map(|r| {
+                let label = if r.is_default {
+                    format!("{} *", r.display)
                 } else {
-                    String::new()
-                },
-                r.complexity.clone(),
-            ]
-        })
-        .collect();
+                    r.display.clone()
+                };
+                vec![
+                    label,
+                    r.aliases.clone(),
+                    if r.rules > 0 {
+                        r.rules.to_string()
+                    } else {
+                        String::new()
+                    },
+                    r.complexity.clone(),
+                ]
+            })
+            .collect()
+    };
 
     let color_fns: Vec<Option<crate::output::CellFormatter>> =
         vec![Some(crate::output::fmt_problem_name), None, None, None];
 
     let mut text = String::new();
     text.push_str(&crate::output::fmt_section(&summary));
-    text.push('\n');
-    text.push_str(&format_table(&columns, &rows, &color_fns));
+    for (label, members) in &groups {
+        text.push('\n');
+        if group_by.is_some() {
+            let num_types = members.iter().filter(|r| r.is_first).count();
+            let plural = if num_types == 1 { "" } else { "s" };
+            text.push_str(&crate::output::fmt_section(&format!(
+                "{label} ({num_types} type{plural})\n"
+            )));
+        }
+        text.push_str(&format_table(&columns, &table_rows(members), &color_fns));
+    }
     text.push_str("\n* = default variant\n");
     text.push_str("Use `pred show <problem>` to see reductions and fields.\n");
 
-    let json = serde_json::json!({
+    let variant_json = |r: &VariantRow| {
+        serde_json::json!({
+            "name": r.display,
+            "aliases": r.aliases,
+            "default": r.is_default,
+            "rules": r.rules,
+            "complexity": r.complexity,
+        })
+    };
+    let mut json = serde_json::json!({
         "num_types": graph.num_types(),
         "num_reductions": graph.num_reductions(),
         "num_variant_nodes": graph.num_variant_nodes(),
-        "variants": rows_data.iter().map(|r| {
-            serde_json::json!({
-                "name": r.display,
-                "aliases": r.aliases,
-                "default": r.is_default,
-                "rules": r.rules,
-                "complexity": r.complexity,
-            })
-        }).collect::<Vec<_>>(),
+        "num_matching_types": types.len(),
+        "filters": filters,
     });
+    if group_by.is_some() {
+        json["groups"] = groups
+            .iter()
+            .map(|(label, members)| {
+                serde_json::json!({
+                    "name": label,
+                    "num_types": members.iter().filter(|r| r.is_first).count(),
+                    "variants": members.iter().map(|r| variant_json(r)).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+    } else {
+        json["variants"] = rows_data.iter().map(variant_json).collect();
+    }
 
     out.emit_with_default_name("pred_graph_list.json", &text, &json)
 }
@@ -205,9 +322,12 @@ pub fn list_rules(out: &OutputConfig) -> Result<()> {
     out.emit_with_default_name("pred_rules_list.json", &text, &json)
 }
 
-pub fn show(problem: &str, out: &OutputConfig) -> Result<()> {
+pub fn show(problem: &str, example: bool, out: &OutputConfig) -> Result<()> {
     let graph = ReductionGraph::new();
     let resolved = resolve_problem_ref(problem, &graph)?;
+    if example {
+        return show_example(&resolved, out);
+    }
     let name = &resolved.name;
     let variant = &resolved.variant;
 
@@ -341,6 +461,118 @@ pub fn show(problem: &str, out: &OutputConfig) -> Result<()> {
     out.emit_with_default_name(&default_name, &text, &json)
 }
 
+/// Largest search space (as log2 of the number of configurations) that
+/// `pred show --example` explores by brute force.
+const EXAMPLE_BRUTE_FORCE_LOG2_LIMIT: f64 = 16.0;
+
+/// Emit the canonical example instance for a problem, solved by brute force.
+///
+/// The JSON form is a `pred create` file, so it can be passed straight to
+/// `pred solve` or `pred reduce`; the optimum rides along in `solution`,
+/// `evaluation` and `solver`, which problem loaders ignore. Examples whose
+/// search space exceeds the brute-force limit report the optimum recorded in
+/// the example database instead.
+fn show_example(problem: &ProblemRef, out: &OutputConfig) -> Result<()> {
+    let example = problemreductions::example_db::find_problem_example(problem)?;
+    if example.variant != problem.variant {
+        out.info(&format!(
+            "No example for {}{}; showing {}{}",
+            problem.name,
+            variant_to_full_slash(&problem.variant),
+            example.problem,
+            variant_to_full_slash(&example.variant)
+        ));
+    }
+    let loaded = crate::dispatch::load_problem(
+        &example.problem,
+        &example.variant,
+        example.instance.clone(),
+    )?;
+    // Beyond the brute-force limit, fall back to the optimum recorded with the example
+    let log2_search_space: f64 = loaded.dims_dyn().iter().map(|&d| (d as f64).log2()).sum();
+    let (result, solver) = if log2_search_space <= EXAMPLE_BRUTE_FORCE_LOG2_LIMIT {
+        (loaded.solve_brute_force(), "brute force")
+    } else {
+        let result = crate::dispatch::SolveResult {
+            evaluation: loaded.evaluate_dyn(&example.optimal_config),
+            config: Some(example.optimal_config.clone()),
+        };
+        (result, "example database")
+    };
+    let grid = grid_embedding(&example.problem, &example.variant, &example.instance);
+
+    let header = format!(
+        "{}{}",
+        example.problem,
+        variant_to_full_slash(&example.variant)
+    );
+    let mut text = format!(
+        "{} example\n\n{}\n",
+        crate::output::fmt_problem_name(&header),
+        crate::output::fmt_section("Instance:"),
+    );
+    match example.instance.as_object() {
+        Some(fields) => {
+            for (field, value) in fields {
+                text.push_str(&format!("  {field}: {value}\n"));
+            }
+        }
+        None => text.push_str(&format!("  {}\n", example.instance)),
+    }
+    text.push_str(&format!(
+        "\n{}\n",
+        crate::output::fmt_section(&format!("Optimal solution ({solver}):"))
+    ));
+    if let Some(config) = &result.config {
+        text.push_str(&format!("  Solution: {config:?}\n"));
+    }
+    text.push_str(&format!("  Evaluation: {}\n", result.evaluation));
+    if let Some(grid) = grid {
+        text.push_str(&format!(
+            "\n{}\n{grid}\n",
+            crate::output::fmt_section("King's subgraph embedding:")
+        ));
+    }
+
+    let output = ProblemJsonOutput {
+        problem_type: example.problem,
+        variant: example.variant,
+        data: example.instance,
+    };
+    let mut json = serde_json::to_value(&output)?;
+    if let Some(config) = &result.config {
+        json["solution"] = serde_json::json!(config);
+    }
+    json["evaluation"] = serde_json::json!(result.evaluation);
+    json["solver"] = serde_json::json!(solver);
+    let default_name = format!("pred_example_{}.json", output.problem_type);
+    out.emit_with_default_name(&default_name, text.trim_end(), &json)
+}
+
+/// ASCII rendering of the King's subgraph embedding for problems that the
+/// unit-disk mapping accepts (independent set on a general graph).
+fn grid_embedding(
+    name: &str,
+    variant: &BTreeMap<String, String>,
+    instance: &serde_json::Value,
+) -> Option<String> {
+    use problemreductions::rules::unitdiskmapping::ksg;
+    use problemreductions::topology::{Graph, SimpleGraph};
+
+    if name != "MaximumIndependentSet"
+        || variant.get("graph").map(String::as_str) != Some("SimpleGraph")
+    {
+        return None;
+    }
+    let graph: SimpleGraph = serde_json::from_value(instance.get("graph")?.clone()).ok()?;
+    let (n, edges) = (graph.num_vertices(), graph.edges());
+    if variant.get("weight").map(String::as_str) == Some("One") {
+        Some(ksg::map_unweighted(n, &edges).to_string())
+    } else {
+        Some(ksg::map_weighted(n, &edges).to_string())
+    }
+}
+
 /// Format an expression as Big O notation using asymptotic normalization.
 /// Falls back to wrapping the original expression if normalization fails.
 fn big_o_of(expr: &Expr) -> String {
@@ -577,17 +809,14 @@ pub fn path(
         }
         None => {
             let variant_hint = variant_hint_for(&graph, &dst_spec.name);
-            anyhow::bail!(
+            Err(crate::exit::no_result(format!(
                 "No reduction path from {} to {}\n\
                  {variant_hint}\n\
                  Usage: pred path <SOURCE> <TARGET>\n\
                  Example: pred path MIS QUBO\n\n\
                  Run `pred show {}` and `pred show {}` to check available reductions.",
-                src_spec.name,
-                dst_spec.name,
-                src_spec.name,
-                dst_spec.name,
-            );
+                src_spec.name, dst_spec.name, src_spec.name, dst_spec.name,
+            )))
         }
     }
 }
@@ -607,17 +836,14 @@ fn path_all(
 
     if all_paths.is_empty() {
         let variant_hint = variant_hint_for(graph, dst_name);
-        anyhow::bail!(
+        return Err(crate::exit::no_result(format!(
             "No reduction path from {} to {}\n\
              {variant_hint}\n\
              Usage: pred path <SOURCE> <TARGET> --all\n\
              Example: pred path MIS QUBO --all\n\n\
              Run `pred show {}` and `pred show {}` to check available reductions.",
-            src_name,
-            dst_name,
-            src_name,
-            dst_name,
-        );
+            src_name, dst_name, src_name, dst_name,
+        )));
     }
 
     // Sort by path length (shortest first)
@@ -692,19 +918,23 @@ fn path_all(
             }
         ));
     } else if out.json {
-        println!(
-            "{}",
-            serde_json::to_string_pretty(&json).context("Failed to serialize JSON")?
-        );
+        out.print_json(&json)?;
     } else {
         println!("{text}");
+        out.emitted.set(true);
     }
 
     Ok(())
 }
 
-pub fn export(out: &OutputConfig) -> Result<()> {
+pub fn export(condensed: bool, format: GraphFormat, out: &OutputConfig) -> Result<()> {
     let graph = ReductionGraph::new();
+    if condensed {
+        return export_condensed(&graph, out);
+    }
+    if format == GraphFormat::Mermaid {
+        return export_mermaid(&graph, out);
+    }
 
     let json_str = graph
         .to_json_string()
@@ -723,6 +953,49 @@ pub fn export(out: &OutputConfig) -> Result<()> {
     out.emit_with_default_name("reduction_graph.json", &text, &json)
 }
 
+/// Mermaid text goes to `-o` verbatim, or to stdout; `--json` does not apply.
+fn export_mermaid(graph: &ReductionGraph, out: &OutputConfig) -> Result<()> {
+    let diagram = problemreductions::export::mermaid(graph);
+    if let Some(ref path) = out.output {
+        std::fs::write(path, &diagram)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        out.info(&format!("Wrote {}", path.display()));
+    } else {
+        print!("{diagram}");
+    }
+    out.emitted.set(true);
+    Ok(())
+}
+
+fn export_condensed(graph: &ReductionGraph, out: &OutputConfig) -> Result<()> {
+    let condensed = graph.condensation();
+    let json = serde_json::to_value(&condensed)?;
+
+    let nontrivial: Vec<(usize, &ReductionCluster)> = condensed
+        .clusters
+        .iter()
+        .enumerate()
+        .filter(|(_, cluster)| cluster.is_nontrivial())
+        .collect();
+    let mut text = format!(
+        "Condensed reduction graph: {} clusters ({} with mutual reductions), {} edges between clusters\n",
+        condensed.clusters.len(),
+        nontrivial.len(),
+        condensed.edges.len(),
+    );
+    for (index, cluster) in nontrivial {
+        let members: Vec<String> = cluster
+            .members
+            .iter()
+            .map(|m| fmt_node(graph, m.name, &m.variant))
+            .collect();
+        text.push_str(&format!("\n  [{index}] {}", members.join(", ")));
+    }
+    text.push_str("\n\nUse -o to save as JSON.");
+
+    out.emit_with_default_name("reduction_graph_condensed.json", &text, &json)
+}
+
 fn push_alias_part(parts: &mut Vec<String>, alias: &str) {
     if !parts.iter().any(|part| part.eq_ignore_ascii_case(alias)) {
         parts.push(alias.to_string());
@@ -1,7 +1,13 @@
+pub mod audit;
+pub mod batch;
+pub mod bench;
 pub mod create;
 pub mod evaluate;
 pub mod extract;
 pub mod graph;
 pub mod inspect;
 pub mod reduce;
+pub mod schema;
 pub mod solve;
+pub mod stats;
+pub mod timing;
@@ -5,6 +5,7 @@ use crate::dispatch::{
 use crate::output::OutputConfig;
 use crate::problem_name::resolve_problem_ref;
 use anyhow::{Context, Result};
+use problemreductions::export;
 use problemreductions::rules::{
     MinimizeSteps, ReductionGraph, ReductionMode, ReductionPath, ReductionStep,
 };
@@ -51,44 +52,33 @@ fn parse_path_node(node: &serde_json::Value) -> Result<ReductionStep> {
     Ok(ReductionStep { name, variant })
 }
 
-pub fn reduce(
+/// Resolve the reduction path for `source`: read it from `--via` or
+/// auto-discover the fewest-step witness-capable path to `--to`.
+fn resolve_reduction_path(
+    graph: &ReductionGraph,
     input: &Path,
+    source_name: &str,
+    source_variant: &BTreeMap<String, String>,
     target: Option<&str>,
     via: Option<&Path>,
-    out: &OutputConfig,
-) -> Result<()> {
-    // 1. Load source problem
-    let content = read_input(input)?;
-    let problem_json: ProblemJson = serde_json::from_str(&content)?;
-
-    let source = load_problem(
-        &problem_json.problem_type,
-        &problem_json.variant,
-        problem_json.data.clone(),
-    )?;
-
-    let source_name = source.problem_name();
-    let source_variant = source.variant_map();
-    let graph = ReductionGraph::new();
-
-    // 3. Get reduction path: from --via file or auto-discover
-    let reduction_path = if let Some(path_file) = via {
+) -> Result<ReductionPath> {
+    if let Some(path_file) = via {
         let path = load_path_file(path_file)?;
         // Validate that the path starts with the source
         let first = path.steps.first().unwrap();
         let last = path.steps.last().unwrap();
-        if first.name != source_name || first.variant != source_variant {
+        if first.name != source_name || first.variant != *source_variant {
             anyhow::bail!(
                 "Path file starts with {}{} but source problem is {}{}",
                 first.name,
                 variant_to_full_slash(&first.variant),
                 source_name,
-                variant_to_full_slash(&source_variant),
+                variant_to_full_slash(source_variant),
             );
         }
         // If --to is given, validate it matches the path's target
         if let Some(target) = target {
-            let dst_ref = resolve_problem_ref(target, &graph)?;
+            let dst_ref = resolve_problem_ref(target, graph)?;
             if last.name != dst_ref.name || last.variant != dst_ref.variant {
                 anyhow::bail!(
                     "Path file ends with {}{} but --to specifies {}{}",
@@ -99,7 +89,7 @@ pub fn reduce(
                 );
             }
         }
-        path
+        Ok(path)
     } else {
         // --to is required when --via is not given
         let target = target.ok_or_else(|| {
@@ -110,13 +100,13 @@ pub fn reduce(
                    pred reduce problem.json --via path.json"
             )
         })?;
-        let dst_ref = resolve_problem_ref(target, &graph)?;
+        let dst_ref = resolve_problem_ref(target, graph)?;
 
         // Auto-discover cheapest path
         let input_size = ProblemSize::new(vec![]);
         let best_path = graph.find_cheapest_path_mode(
             source_name,
-            &source_variant,
+            source_variant,
             &dst_ref.name,
             &dst_ref.variant,
             ReductionMode::Witness,
@@ -124,9 +114,9 @@ pub fn reduce(
             &MinimizeSteps,
         );
 
-        best_path.ok_or_else(|| {
-            let variant_hint = variant_hint_for(&graph, &dst_ref.name);
-            anyhow::anyhow!(
+        let path = best_path.ok_or_else(|| {
+            let variant_hint = variant_hint_for(graph, &dst_ref.name);
+            crate::exit::no_result(format!(
                 "No witness-capable reduction path from {} to {}\n\
                  {variant_hint}\n\
                  Hint: generate a path file first, then pass it with --via:\n\
@@ -137,9 +127,69 @@ pub fn reduce(
                 source_name,
                 dst_ref.name,
                 input.display(),
-            )
-        })?
-    };
+            ))
+        })?;
+        Ok(path)
+    }
+}
+
+pub fn reduce(
+    input: &Path,
+    target: Option<&str>,
+    via: Option<&Path>,
+    export_target_graph: Option<&Path>,
+    out: &OutputConfig,
+) -> Result<()> {
+    let (bundle, reduction_path) = reduce_to_bundle(input, target, via)?;
+    let json = serde_json::to_value(&bundle)?;
+
+    let mut text = format!(
+        "Reduced {} to {} ({} steps)\n",
+        bundle.source.problem_type,
+        bundle.target.problem_type,
+        reduction_path.len(),
+    );
+    text.push_str(&format!("\nPath: {}\n", reduction_path));
+    if let Some(graph_path) = export_target_graph {
+        write_target_graph(&bundle.target, graph_path)?;
+        text.push_str(&format!(
+            "\nTarget graph written to {}\n",
+            graph_path.display()
+        ));
+    }
+
+    out.emit_with_default_name("", &text, &json)?;
+    out.hint(
+        "\nHint: use -o to save the reduction bundle as JSON, or --json to print JSON to stdout.",
+    );
+
+    Ok(())
+}
+
+/// Reduce a problem file along `--via` or the auto-discovered path to
+/// `target`, returning the reduction bundle and the path taken.
+pub(crate) fn reduce_to_bundle(
+    input: &Path,
+    target: Option<&str>,
+    via: Option<&Path>,
+) -> Result<(ReductionBundle, ReductionPath)> {
+    // 1. Load source problem
+    let content = read_input(input)?;
+    let problem_json: ProblemJson = serde_json::from_str(&content)?;
+
+    let source = load_problem(
+        &problem_json.problem_type,
+        &problem_json.variant,
+        problem_json.data.clone(),
+    )?;
+
+    let source_name = source.problem_name();
+    let source_variant = source.variant_map();
+    let graph = ReductionGraph::new();
+
+    // 3. Get reduction path: from --via file or auto-discover
+    let reduction_path =
+        resolve_reduction_path(&graph, input, source_name, &source_variant, target, via)?;
 
     // 4. Execute reduction chain via reduce_along_path
     let chain = graph
@@ -180,22 +230,171 @@ pub fn reduce(
             .collect(),
     };
 
-    let json = serde_json::to_value(&bundle)?;
+    Ok((bundle, reduction_path))
+}
+
+/// Write the graph of a graph-based target problem as Pajek or CSV.
+///
+/// The format follows the file extension: `.net` for Pajek (with vertex
+/// weights and coordinates when present) and `.csv` for an edge list (with
+/// edge weights when present).
+fn write_target_graph(target: &ProblemJsonOutput, path: &Path) -> Result<()> {
+    let graph_type = target.variant.get("graph").ok_or_else(|| {
+        anyhow::anyhow!(
+            "Target {} is not a graph problem; --export-target-graph needs a graph-based target",
+            target.problem_type
+        )
+    })?;
+    let graph = export::graph::graph_from_json(graph_type, &target.data["graph"])?;
+    // Numeric weight arrays, kept only when they have one entry per element.
+    let numbers = |field: &str, expected: usize| -> Option<Vec<f64>> {
+        let weights: Vec<f64> = target.data[field]
+            .as_array()?
+            .iter()
+            .map(|w| w.as_f64())
+            .collect::<Option<_>>()?;
+        (weights.len() == expected).then_some(weights)
+    };
+
+    let content = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("net") => {
+            let weights = numbers("weights", graph.vertex_count());
+            export::graph::to_pajek(graph.as_ref(), weights.as_deref())
+        }
+        Some("csv") => {
+            let weights = numbers("edge_weights", graph.edge_list().len());
+            export::graph::to_csv_edgelist(graph.as_ref(), weights.as_deref())
+        }
+        _ => anyhow::bail!(
+            "Unsupported graph export format for {}: use a .net (Pajek) or .csv extension",
+            path.display()
+        ),
+    };
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Estimate the target size of a reduction from the overhead formulas alone.
+///
+/// Resolves the same path as [`reduce`] but never calls `reduce_to`, so it is
+/// cheap even when the materialized target would be huge.
+pub fn estimate(
+    input: &Path,
+    target: Option<&str>,
+    via: Option<&Path>,
+    warn_bytes: u64,
+    out: &OutputConfig,
+) -> Result<()> {
+    let content = read_input(input)?;
+    let problem_json: ProblemJson = serde_json::from_str(&content)?;
+    let source = load_problem(
+        &problem_json.problem_type,
+        &problem_json.variant,
+        problem_json.data,
+    )?;
+
+    let source_name = source.problem_name();
+    let source_variant = source.variant_map();
+    let graph = ReductionGraph::new();
+    let reduction_path =
+        resolve_reduction_path(&graph, input, source_name, &source_variant, target, via)?;
+
+    let source_size = ReductionGraph::compute_source_size_for_variant(
+        source_name,
+        &source_variant,
+        source.as_any(),
+    );
+    let target_size = graph
+        .evaluate_path_overhead(&reduction_path, &source_size)
+        .ok_or_else(|| anyhow::anyhow!("Reduction path is not part of the reduction graph"))?;
+    let target_step = reduction_path.steps.last().unwrap();
 
     let mut text = format!(
-        "Reduced {} to {} ({} steps)\n",
+        "Estimated size of {} reduced to {} ({} steps)\n",
         source_name,
         target_step.name,
         reduction_path.len(),
     );
     text.push_str(&format!("\nPath: {}\n", reduction_path));
-    text.push_str(
-        "\nHint: use -o to save the reduction bundle as JSON, or --json to print JSON to stdout.",
-    );
+    text.push_str(&format!("\nSource size ({source_name}):\n"));
+    for (field, value) in &source_size.components {
+        text.push_str(&format!("  {field} = {value}\n"));
+    }
+    text.push_str(&format!(
+        "\nEstimated target size ({}):\n",
+        target_step.name
+    ));
+    for (field, value) in &target_size.components {
+        text.push_str(&format!("  {field} = {value}\n"));
+    }
+    let memory_bytes = estimated_memory_bytes(&target_step.name, &target_size);
+    text.push_str(&format!(
+        "\nEstimated target memory: {}\n",
+        format_bytes(memory_bytes)
+    ));
+    if memory_bytes > warn_bytes {
+        out.info(&format!(
+            "Warning: estimated target memory {} exceeds --warn-bytes {}",
+            format_bytes(memory_bytes),
+            format_bytes(warn_bytes),
+        ));
+    }
 
-    out.emit_with_default_name("", &text, &json)?;
+    let size_json = |size: &ProblemSize| -> serde_json::Map<String, serde_json::Value> {
+        size.components
+            .iter()
+            .map(|(field, value)| (field.clone(), serde_json::json!(value)))
+            .collect()
+    };
+    let json = serde_json::json!({
+        "source": source_name,
+        "target": target_step.name,
+        "path": reduction_path
+            .steps
+            .iter()
+            .map(|s| PathStep {
+                name: s.name.clone(),
+                variant: s.variant.clone(),
+            })
+            .collect::<Vec<_>>(),
+        "source_size": size_json(&source_size),
+        "estimated_target_size": size_json(&target_size),
+        "estimated_memory_bytes": memory_bytes,
+        "exceeds_warn_bytes": memory_bytes > warn_bytes,
+    });
 
-    Ok(())
+    out.emit_with_default_name("", &text, &json)
+}
+
+/// Rough memory footprint of a target of the given size, in bytes.
+///
+/// Counts one 8-byte word per element named by the size fields (vertices,
+/// edges, variables, constraints, ...). QUBO stores a dense `num_vars x
+/// num_vars` matrix, so it is counted quadratically. Other targets may hold
+/// more per element than one word, so treat the result as an order of magnitude.
+fn estimated_memory_bytes(target_name: &str, size: &ProblemSize) -> u64 {
+    const WORD: u64 = 8;
+    let elements = match (target_name, size.get("num_vars")) {
+        ("QUBO", Some(n)) => (n as u64).saturating_mul(n as u64),
+        _ => size.components.iter().fold(0u64, |total, (_, value)| {
+            total.saturating_add(*value as u64)
+        }),
+    };
+    elements.saturating_mul(WORD)
+}
+
+/// Format a byte count with a binary unit, e.g. `1.5 MiB`.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
 }
 
 use super::graph::{variant_hint_for, variant_to_full_slash};
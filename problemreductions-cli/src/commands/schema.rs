@@ -0,0 +1,55 @@
+use crate::output::OutputConfig;
+use crate::problem_name::{parse_problem_spec, resolve_problem_ref};
+use anyhow::{bail, Context, Result};
+use problemreductions::registry::{collect_schemas, problem_json_schema};
+use problemreductions::rules::ReductionGraph;
+use std::path::Path;
+
+/// Print the JSON Schema for a problem file.
+///
+/// A bare name (`MIS`) yields a schema accepting every registered variant; a
+/// slash spec (`MIS/UnitDiskGraph`) narrows it to that variant.
+pub fn schema(problem: Option<&str>, all: bool, out: &OutputConfig) -> Result<()> {
+    if all {
+        let dir = out.output.as_deref().ok_or_else(|| {
+            anyhow::anyhow!("`pred schema --all` writes one file per problem; pass -o <DIR>")
+        })?;
+        return write_all(dir, out);
+    }
+    let problem = problem.ok_or_else(|| {
+        anyhow::anyhow!(
+            "Missing problem type.\n\nUsage: pred schema <PROBLEM> | pred schema --all -o <DIR>"
+        )
+    })?;
+
+    let graph = ReductionGraph::new();
+    let resolved = resolve_problem_ref(problem, &graph)?;
+    let spec = parse_problem_spec(problem)?;
+    let variant = (!spec.variant_values.is_empty()).then_some(&resolved.variant);
+    let schema = problem_json_schema(&resolved.name, variant)
+        .ok_or_else(|| anyhow::anyhow!("No schema registered for {}", resolved.name))?;
+
+    let text = serde_json::to_string_pretty(&schema)?;
+    out.emit_with_default_name("", &text, &schema)
+}
+
+/// Write `<Name>.schema.json` for every registered problem into `dir`.
+fn write_all(dir: &Path, out: &OutputConfig) -> Result<()> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+    let mut written = 0;
+    for entry in collect_schemas() {
+        let Some(schema) = problem_json_schema(&entry.name, None) else {
+            continue;
+        };
+        let path = dir.join(format!("{}.schema.json", entry.name));
+        std::fs::write(&path, serde_json::to_string_pretty(&schema)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        written += 1;
+    }
+    if written == 0 {
+        bail!("No problem schemas are registered");
+    }
+    out.info(&format!("Wrote {written} schemas to {}", dir.display()));
+    Ok(())
+}
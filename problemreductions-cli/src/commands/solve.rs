@@ -1,7 +1,15 @@
-use crate::dispatch::{load_problem, read_input, BundleReplay, ProblemJson, ReductionBundle};
+use crate::cli::SolveArgs;
+use crate::dispatch::{
+    load_problem, read_input, BundleReplay, LoadedProblem, ProblemJson, ReductionBundle,
+    SolveResult,
+};
+use crate::exit::Failure;
 use crate::output::OutputConfig;
 use anyhow::{Context, Result};
+use problemreductions::models::formula::{ModelCounting, Satisfiability};
+use problemreductions::solvers::SolverTelemetry;
 use std::path::Path;
+use std::sync::mpsc::RecvTimeoutError;
 use std::time::Duration;
 
 /// Input can be either a problem JSON or a reduction bundle JSON.
@@ -28,7 +36,7 @@ fn parse_input(path: &Path) -> Result<SolveInput> {
     }
 }
 
-fn solve_result_text(problem: &str, solver: &str, result: &crate::dispatch::SolveResult) -> String {
+fn solve_result_text(problem: &str, solver: &str, result: &SolveResult) -> String {
     let mut text = format!("Problem: {}\nSolver: {}", problem, solver);
     if let Some(config) = &result.config {
         text.push_str(&format!("\nSolution: {:?}", config));
@@ -37,11 +45,7 @@ fn solve_result_text(problem: &str, solver: &str, result: &crate::dispatch::Solv
     text
 }
 
-fn solve_result_json(
-    problem: &str,
-    solver: &str,
-    result: &crate::dispatch::SolveResult,
-) -> serde_json::Value {
+fn solve_result_json(problem: &str, solver: &str, result: &SolveResult) -> serde_json::Value {
     let mut json = serde_json::json!({
         "problem": problem,
         "solver": solver,
@@ -56,7 +60,7 @@ fn solve_result_json(
 fn plain_problem_output(
     problem: &str,
     solver: &str,
-    result: &crate::dispatch::SolveResult,
+    result: &SolveResult,
 ) -> (String, serde_json::Value) {
     (
         solve_result_text(problem, solver, result),
@@ -64,107 +68,245 @@ fn plain_problem_output(
     )
 }
 
-pub fn solve(input: &Path, solver_name: &str, timeout: u64, out: &OutputConfig) -> Result<()> {
-    if solver_name != "brute-force" && solver_name != "ilp" && solver_name != "customized" {
-        anyhow::bail!(
-            "Unknown solver: {}. Available solvers: brute-force, ilp, customized",
-            solver_name
-        );
+/// A rendered solve result, ready to emit.
+#[derive(Debug)]
+pub(crate) struct SolveOutput {
+    pub text: String,
+    pub json: serde_json::Value,
+    /// Shown on an interactive stderr after the result when no `-o` is given.
+    hint: Option<&'static str>,
+}
+
+impl SolveOutput {
+    /// Whether the solver proved that no feasible solution exists.
+    fn is_infeasible(&self) -> bool {
+        self.json["evaluation"]
+            .as_str()
+            .is_some_and(|evaluation| matches!(evaluation, "Min(None)" | "Max(None)" | "Or(false)"))
+    }
+}
+
+const SAVE_HINT: &str = "\nHint: use -o to save full solution details as JSON.";
+
+pub fn solve(args: &SolveArgs, out: &OutputConfig) -> Result<()> {
+    let output = solve_file(
+        &args.input,
+        &args.solver,
+        args.timeout,
+        args.count,
+        args.telemetry,
+    )?;
+    out.emit_with_default_name("", &output.text, &output.json)?;
+    if let Some(hint) = output.hint {
+        out.hint(hint);
+    }
+    if output.is_infeasible() {
+        return Err(crate::exit::no_result(format!(
+            "{} has no feasible solution",
+            output.json["problem"].as_str().unwrap_or("the problem")
+        )));
+    }
+    Ok(())
+}
+
+/// Solve a problem file or reduction bundle without emitting the result.
+pub(crate) fn solve_file(
+    input: &Path,
+    solver_name: &str,
+    timeout: u64,
+    count: bool,
+    telemetry: bool,
+) -> Result<SolveOutput> {
+    check_solver_name(solver_name)?;
+    if telemetry && solver_name != "brute-force" {
+        anyhow::bail!("--telemetry is only available with --solver brute-force");
     }
 
     let parsed = parse_input(input)?;
 
     if timeout > 0 {
         let solver_name = solver_name.to_string();
-        let out = out.clone();
         let (tx, rx) = std::sync::mpsc::channel();
         std::thread::spawn(move || {
-            let result = match parsed {
-                SolveInput::Problem(pj) => {
-                    solve_problem(&pj.problem_type, &pj.variant, pj.data, &solver_name, &out)
-                }
-                SolveInput::Bundle(b) => solve_bundle(b, &solver_name, &out),
-            };
+            let result = solve_parsed(parsed, &solver_name, count, telemetry);
             tx.send(result).ok();
         });
         match rx.recv_timeout(Duration::from_secs(timeout)) {
             Ok(result) => result,
-            Err(_) => anyhow::bail!("Solve timed out after {} seconds", timeout),
+            Err(RecvTimeoutError::Timeout) => {
+                anyhow::bail!("Solve timed out after {} seconds", timeout)
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(Failure::internal("Solver thread stopped without a result").into())
+            }
         }
     } else {
-        match parsed {
-            SolveInput::Problem(pj) => {
-                solve_problem(&pj.problem_type, &pj.variant, pj.data, solver_name, out)
-            }
-            SolveInput::Bundle(b) => solve_bundle(b, solver_name, out),
+        solve_parsed(parsed, solver_name, count, telemetry)
+    }
+}
+
+fn solve_parsed(
+    parsed: SolveInput,
+    solver_name: &str,
+    count: bool,
+    telemetry: bool,
+) -> Result<SolveOutput> {
+    match parsed {
+        SolveInput::Problem(pj) if count => count_problem(&pj.problem_type, &pj.variant, pj.data),
+        SolveInput::Problem(pj) if telemetry => {
+            solve_problem_with_telemetry(&pj.problem_type, &pj.variant, pj.data)
+        }
+        SolveInput::Problem(pj) => {
+            solve_problem(&pj.problem_type, &pj.variant, pj.data, solver_name)
+        }
+        SolveInput::Bundle(_) if count => {
+            anyhow::bail!("--count expects a problem JSON, not a reduction bundle")
+        }
+        SolveInput::Bundle(_) if telemetry => {
+            anyhow::bail!("--telemetry expects a problem JSON, not a reduction bundle")
         }
+        SolveInput::Bundle(b) => solve_bundle(b, solver_name),
     }
 }
 
-/// Solve a plain problem file directly.
-fn solve_problem(
+/// Count the satisfying assignments of a CNF formula.
+fn count_problem(
     problem_type: &str,
     variant: &std::collections::BTreeMap<String, String>,
     data: serde_json::Value,
-    solver_name: &str,
-    out: &OutputConfig,
-) -> Result<()> {
+) -> Result<SolveOutput> {
     let problem = load_problem(problem_type, variant, data)?;
     let name = problem.problem_name();
+    let any = problem.as_any();
+    let count = if let Some(sat) = any.downcast_ref::<Satisfiability>() {
+        sat.count_models()
+    } else if let Some(mc) = any.downcast_ref::<ModelCounting>() {
+        mc.count()
+    } else {
+        anyhow::bail!("--count supports Satisfiability and ModelCounting, not {name}");
+    };
+    let Some(count) = count else {
+        anyhow::bail!(
+            "--count supports formulas with at most {} variables and no zero literals",
+            Satisfiability::MAX_COUNT_VARS
+        );
+    };
+
+    let text = format!("Problem: {name}\nSolver: model counting\nModels: {count}");
+    let json = serde_json::json!({
+        "problem": name,
+        "solver": "model-counting",
+        "count": count,
+    });
+    Ok(SolveOutput {
+        text,
+        json,
+        hint: None,
+    })
+}
+
+/// Reject solver names other than the ones `pred solve` knows.
+pub(crate) fn check_solver_name(solver_name: &str) -> Result<()> {
+    if solver_name != "brute-force" && solver_name != "ilp" && solver_name != "customized" {
+        anyhow::bail!(
+            "Unknown solver: {}. Available solvers: brute-force, ilp, customized",
+            solver_name
+        );
+    }
+    Ok(())
+}
 
+/// Run one solver on an already loaded problem.
+pub(crate) fn run_solver(problem: &LoadedProblem, solver_name: &str) -> Result<SolveResult> {
     match solver_name {
-        "brute-force" => {
-            let result = problem.solve_brute_force();
-            let (text, json) = plain_problem_output(name, "brute-force", &result);
-            let result = out.emit_with_default_name("", &text, &json);
-            if out.output.is_none() && crate::output::stderr_is_tty() {
-                out.info("\nHint: use -o to save full solution details as JSON.");
-            }
-            result
-        }
+        "brute-force" => Ok(problem.solve_brute_force()),
         "ilp" => {
             let result = problem.solve_with_ilp().map_err(add_ilp_solver_hint)?;
-            let solver_desc = if name == "ILP" {
-                "ilp".to_string()
-            } else {
-                "ilp (via ILP)".to_string()
-            };
-            let result = crate::dispatch::SolveResult {
+            Ok(SolveResult {
                 config: Some(result.config),
                 evaluation: result.evaluation,
-            };
-            let text = solve_result_text(name, &solver_desc, &result);
-            let mut json = solve_result_json(name, "ilp", &result);
-            if name != "ILP" {
-                json["reduced_to"] = serde_json::json!("ILP");
-            }
-            let result = out.emit_with_default_name("", &text, &json);
-            if out.output.is_none() && crate::output::stderr_is_tty() {
-                out.info("\nHint: use -o to save full solution details as JSON.");
-            }
-            result
+            })
         }
         "customized" => {
             let result = problem
                 .solve_with_customized()
                 .map_err(add_customized_solver_hint)?;
-            let result = crate::dispatch::SolveResult {
+            Ok(SolveResult {
                 config: Some(result.config),
                 evaluation: result.evaluation,
-            };
-            let (text, json) = plain_problem_output(name, "customized", &result);
-            let result = out.emit_with_default_name("", &text, &json);
-            if out.output.is_none() && crate::output::stderr_is_tty() {
-                out.info("\nHint: use -o to save full solution details as JSON.");
-            }
-            result
+            })
         }
         _ => unreachable!(),
     }
 }
 
+/// Solve a plain problem file directly.
+fn solve_problem(
+    problem_type: &str,
+    variant: &std::collections::BTreeMap<String, String>,
+    data: serde_json::Value,
+    solver_name: &str,
+) -> Result<SolveOutput> {
+    let problem = load_problem(problem_type, variant, data)?;
+    let name = problem.problem_name();
+
+    let result = run_solver(&problem, solver_name)?;
+    let (text, json) = if solver_name == "ilp" {
+        let solver_desc = if name == "ILP" {
+            "ilp".to_string()
+        } else {
+            "ilp (via ILP)".to_string()
+        };
+        let text = solve_result_text(name, &solver_desc, &result);
+        let mut json = solve_result_json(name, "ilp", &result);
+        if name != "ILP" {
+            json["reduced_to"] = serde_json::json!("ILP");
+        }
+        (text, json)
+    } else {
+        plain_problem_output(name, solver_name, &result)
+    };
+    Ok(SolveOutput {
+        text,
+        json,
+        hint: Some(SAVE_HINT),
+    })
+}
+
+/// Brute-force solve a plain problem file and append the solver telemetry.
+fn solve_problem_with_telemetry(
+    problem_type: &str,
+    variant: &std::collections::BTreeMap<String, String>,
+    data: serde_json::Value,
+) -> Result<SolveOutput> {
+    let problem = load_problem(problem_type, variant, data)?;
+    let name = problem.problem_name();
+
+    let (result, telemetry) = problem.solve_brute_force_with_telemetry();
+    let (mut text, mut json) = plain_problem_output(name, "brute-force", &result);
+    append_telemetry(&mut text, &mut json, &telemetry);
+    Ok(SolveOutput {
+        text,
+        json,
+        hint: Some(SAVE_HINT),
+    })
+}
+
+fn append_telemetry(text: &mut String, json: &mut serde_json::Value, telemetry: &SolverTelemetry) {
+    let wall_time_ms = telemetry.wall_time.as_secs_f64() * 1000.0;
+    text.push_str(&format!(
+        "\nEvaluations: {}\nPruned: {}\nWall time: {:.3} ms",
+        telemetry.evaluations, telemetry.pruned, wall_time_ms
+    ));
+    json["telemetry"] = serde_json::json!({
+        "evaluations": telemetry.evaluations,
+        "pruned": telemetry.pruned,
+        "wall_time_ms": wall_time_ms,
+    });
+}
+
 /// Solve a reduction bundle: solve the target problem, then map the solution back.
-fn solve_bundle(bundle: ReductionBundle, solver_name: &str, out: &OutputConfig) -> Result<()> {
+fn solve_bundle(bundle: ReductionBundle, solver_name: &str) -> Result<SolveOutput> {
     let replay = BundleReplay::prepare(&bundle)?;
 
     let target_result = match solver_name {
@@ -203,18 +345,23 @@ fn solve_bundle(bundle: ReductionBundle, solver_name: &str, out: &OutputConfig)
         },
     });
 
-    let result = out.emit_with_default_name("", &text, &json);
-    if out.output.is_none() && crate::output::stderr_is_tty() {
-        out.info("\nHint: use -o to save full solution details (including intermediate results) as JSON.");
-    }
-    result
+    Ok(SolveOutput {
+        text,
+        json,
+        hint: Some(
+            "\nHint: use -o to save full solution details (including intermediate results) as JSON.",
+        ),
+    })
 }
 
 fn add_customized_solver_hint(err: anyhow::Error) -> anyhow::Error {
     let message = err.to_string();
     if message.contains("unsupported by customized solver") {
-        anyhow::anyhow!(
-            "{message}\n\nHint: the customized solver only supports select problems (FD-based models, PartialFeedbackEdgeSet, RootedTreeArrangement).\nTry `--solver brute-force` or `--solver ilp` instead."
+        crate::exit::reword(
+            &err,
+            format!(
+                "{message}\n\nHint: the customized solver only supports select problems (FD-based models, PartialFeedbackEdgeSet, RootedTreeArrangement).\nTry `--solver brute-force` or `--solver ilp` instead."
+            ),
         )
     } else {
         err
@@ -226,8 +373,11 @@ fn add_ilp_solver_hint(err: anyhow::Error) -> anyhow::Error {
     if (message.starts_with("No reduction path from ") && message.ends_with(" to ILP"))
         || message.contains("witness-capable")
     {
-        anyhow::anyhow!(
-            "{message}\n\nHint: try `--solver brute-force` for direct exhaustive search on small instances."
+        crate::exit::reword(
+            &err,
+            format!(
+                "{message}\n\nHint: try `--solver brute-force` for direct exhaustive search on small instances."
+            ),
         )
     } else {
         err
@@ -237,10 +387,30 @@ fn add_ilp_solver_hint(err: anyhow::Error) -> anyhow::Error {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::dispatch::SolveResult;
-    use crate::output::OutputConfig;
     use crate::test_support::aggregate_bundle;
 
+    #[test]
+    fn test_append_telemetry_adds_text_lines_and_json_object() {
+        let result = SolveResult {
+            config: Some(vec![1, 0]),
+            evaluation: "Max(1)".to_string(),
+        };
+        let (mut text, mut json) =
+            plain_problem_output("MaximumIndependentSet", "brute-force", &result);
+        let telemetry = SolverTelemetry {
+            evaluations: 4,
+            pruned: 0,
+            wall_time: Duration::from_millis(2),
+        };
+        append_telemetry(&mut text, &mut json, &telemetry);
+
+        assert!(text.contains("Evaluations: 4"), "{text}");
+        assert!(text.contains("Pruned: 0"), "{text}");
+        assert!(text.contains("Wall time: 2.000 ms"), "{text}");
+        assert_eq!(json["telemetry"]["evaluations"], 4);
+        assert_eq!(json["telemetry"]["wall_time_ms"], 2.0);
+    }
+
     #[test]
     fn test_solve_value_only_problem_omits_solution() {
         let result = SolveResult {
@@ -257,14 +427,8 @@ mod tests {
     #[test]
     fn test_solve_bundle_rejects_aggregate_only_path() {
         let bundle = aggregate_bundle();
-        let out = OutputConfig {
-            output: None,
-            quiet: true,
-            json: false,
-            auto_json: false,
-        };
 
-        let err = solve_bundle(bundle, "brute-force", &out).unwrap_err();
+        let err = solve_bundle(bundle, "brute-force").unwrap_err();
         assert!(
             err.to_string().contains("witness"),
             "unexpected error: {err}"
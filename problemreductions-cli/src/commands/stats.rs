@@ -0,0 +1,67 @@
+use crate::dispatch::{load_problem, read_input, ProblemJson};
+use crate::output::OutputConfig;
+use anyhow::{Context, Result};
+use problemreductions::statistics::ProblemStatistics;
+use std::path::Path;
+
+pub fn stats(input: &Path, out: &OutputConfig) -> Result<()> {
+    let content = read_input(input)?;
+    let problem_json: ProblemJson = serde_json::from_str(&content)
+        .context("Expected a problem JSON (use `pred inspect` for reduction bundles)")?;
+    let problem = load_problem(
+        &problem_json.problem_type,
+        &problem_json.variant,
+        problem_json.data,
+    )?;
+    let name = problem.problem_name();
+    let variant = problem.variant_map();
+    let stats = problem.statistics_dyn();
+
+    let variant_str = if variant.is_empty() {
+        String::new()
+    } else {
+        let pairs: Vec<String> = variant.iter().map(|(k, v)| format!("{k}={v}")).collect();
+        format!(" {{{}}}", pairs.join(", "))
+    };
+    let text = format!("Type: {name}{variant_str}\n{}", format_stats(&stats));
+
+    let mut json_val = serde_json::json!({
+        "type": name,
+        "variant": variant,
+    });
+    if let (Some(obj), serde_json::Value::Object(fields)) =
+        (json_val.as_object_mut(), serde_json::to_value(&stats)?)
+    {
+        obj.extend(fields);
+    }
+
+    out.emit_with_default_name("", &text, &json_val)
+}
+
+fn format_stats(stats: &ProblemStatistics) -> String {
+    let domains: Vec<String> = stats
+        .domain_sizes
+        .iter()
+        .map(|(size, count)| format!("{size} (x{count})"))
+        .collect();
+    let mut text = format!("Variables: {}\n", stats.num_variables);
+    text.push_str(&format!("Domain sizes: {}\n", domains.join(", ")));
+    text.push_str(&format!("Search space: 2^{:.1}\n", stats.log2_search_space));
+    if let Some(graph) = &stats.graph {
+        text.push_str(&format!(
+            "Graph: {} vertices, {} edges\n",
+            graph.num_vertices, graph.num_edges
+        ));
+        text.push_str(&format!("  Density: {:.3}\n", graph.density));
+        text.push_str(&format!(
+            "  Degree: min {}, max {}, avg {:.2}\n",
+            graph.min_degree, graph.max_degree, graph.avg_degree
+        ));
+        text.push_str(&format!("  Components: {}\n", graph.num_components));
+        text.push_str(&format!(
+            "  Pathwidth (upper bound): {}\n",
+            graph.pathwidth_upper_bound
+        ));
+    }
+    text
+}
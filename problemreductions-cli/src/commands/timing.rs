@@ -0,0 +1,67 @@
+//! Wall-clock timing shared by `pred batch` and `pred bench`.
+
+use serde::Serialize;
+use std::time::Instant;
+
+/// Run `f` once and return its result with the elapsed wall time in milliseconds.
+pub(crate) fn time_ms<T>(f: impl FnOnce() -> T) -> (T, f64) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed().as_secs_f64() * 1000.0)
+}
+
+/// Summary statistics over repeated timings, in milliseconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct TimingStats {
+    pub runs: usize,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub mean_ms: f64,
+}
+
+impl TimingStats {
+    /// Summarize a non-empty list of samples. The median of an even number of
+    /// samples is the mean of the two middle ones.
+    pub fn from_samples(samples: &[f64]) -> Self {
+        assert!(!samples.is_empty(), "timing needs at least one sample");
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let mid = sorted.len() / 2;
+        let median_ms = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        };
+        Self {
+            runs: sorted.len(),
+            min_ms: sorted[0],
+            median_ms,
+            mean_ms: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_stats_odd_and_even() {
+        let stats = TimingStats::from_samples(&[3.0, 1.0, 2.0]);
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.min_ms, 1.0);
+        assert_eq!(stats.median_ms, 2.0);
+        assert_eq!(stats.mean_ms, 2.0);
+
+        let stats = TimingStats::from_samples(&[4.0, 1.0, 2.0, 3.0]);
+        assert_eq!(stats.median_ms, 2.5);
+        assert_eq!(stats.mean_ms, 2.5);
+    }
+
+    #[test]
+    fn test_time_ms_returns_result() {
+        let (value, ms) = time_ms(|| 7);
+        assert_eq!(value, 7);
+        assert!(ms >= 0.0);
+    }
+}
@@ -1,7 +1,11 @@
 use anyhow::{Context, Result};
 use problemreductions::registry::{DynProblem, LoadedDynProblem};
 use problemreductions::rules::{MinimizeSteps, ReductionGraph, ReductionMode};
-use problemreductions::solvers::{CustomizedSolver, ILPSolver};
+#[cfg(feature = "ilp-solver")]
+use problemreductions::solvers::ilp::SolveViaReductionError;
+#[cfg(feature = "ilp-solver")]
+use problemreductions::solvers::ILPSolver;
+use problemreductions::solvers::{CustomizedSolver, SolverTelemetry};
 use problemreductions::types::ProblemSize;
 use serde_json::Value;
 use std::any::Any;
@@ -52,7 +56,18 @@ impl LoadedProblem {
         SolveResult { config, evaluation }
     }
 
+    /// Brute-force solve, also reporting how many configurations the value
+    /// search evaluated and how long it took.
+    pub fn solve_brute_force_with_telemetry(&self) -> (SolveResult, SolverTelemetry) {
+        let (evaluation, telemetry) = self.inner.solve_brute_force_telemetry();
+        let config = self.solve_brute_force_witness().map(|result| result.config);
+        (SolveResult { config, evaluation }, telemetry)
+    }
+
     pub fn supports_ilp_solver(&self) -> bool {
+        if !cfg!(feature = "ilp-solver") {
+            return false;
+        }
         let name = self.problem_name();
         let variant = self.variant_map();
         name == "ILP" || {
@@ -102,16 +117,31 @@ impl LoadedProblem {
     }
 
     /// Solve using the ILP solver. If the problem is not ILP, auto-reduce to ILP first.
+    #[cfg(feature = "ilp-solver")]
     pub fn solve_with_ilp(&self) -> Result<WitnessSolveResult> {
         let name = self.problem_name();
         let variant = self.variant_map();
         let solver = ILPSolver::new();
         let config = solver
             .try_solve_via_reduction(name, &variant, self.as_any())
-            .map_err(|err| anyhow::anyhow!(err))?;
+            .map_err(|err| match err {
+                SolveViaReductionError::NoReductionPath { .. }
+                | SolveViaReductionError::NoSolution { .. } => {
+                    crate::exit::no_result(err.to_string())
+                }
+                SolveViaReductionError::WitnessPathRequired { .. } => anyhow::anyhow!(err),
+            })?;
         let evaluation = self.evaluate_dyn(&config);
         Ok(WitnessSolveResult { config, evaluation })
     }
+
+    /// Without an ILP backend, `--solver ilp` reports how to get one.
+    #[cfg(not(feature = "ilp-solver"))]
+    pub fn solve_with_ilp(&self) -> Result<WitnessSolveResult> {
+        anyhow::bail!(
+            "ILP solver not available: rebuild pred with an ILP backend feature (highs, cplex or lp-solvers)"
+        )
+    }
 }
 
 /// A validated reduction bundle ready to replay:
@@ -439,6 +469,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "ilp-solver")]
     #[test]
     fn test_solve_with_ilp_rejects_aggregate_only_problem() {
         let loaded = load_problem(
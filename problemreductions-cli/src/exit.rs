@@ -0,0 +1,153 @@
+//! Exit-code contract shared by every `pred` subcommand.
+//!
+//! | Code | Meaning                                                        |
+//! |------|----------------------------------------------------------------|
+//! | 0    | success                                                        |
+//! | 2    | no result: no reduction path, or the instance is infeasible    |
+//! | 3    | invalid input: bad flags, unreadable or malformed files        |
+//! | 4    | internal error: failure writing output, or a panic             |
+//!
+//! Errors are classified by a [`Failure`] anywhere in their context chain;
+//! unclassified errors count as invalid input, which is what the bulk of the
+//! `bail!` sites in the commands report.
+
+use std::fmt;
+
+/// The non-success outcomes of a `pred` invocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitKind {
+    /// No reduction path exists, or the instance has no feasible solution.
+    NoResult,
+    /// The command line or an input file was rejected.
+    InvalidInput,
+    /// Something went wrong that the input does not explain.
+    Internal,
+}
+
+impl ExitKind {
+    /// Process exit code for this outcome.
+    pub fn code(self) -> i32 {
+        match self {
+            ExitKind::NoResult => 2,
+            ExitKind::InvalidInput => 3,
+            ExitKind::Internal => 4,
+        }
+    }
+
+    /// Stable identifier used in JSON error documents.
+    pub fn name(self) -> &'static str {
+        match self {
+            ExitKind::NoResult => "no_result",
+            ExitKind::InvalidInput => "invalid_input",
+            ExitKind::Internal => "internal",
+        }
+    }
+}
+
+/// An error message tagged with its [`ExitKind`].
+///
+/// Usable both as a root error and as `anyhow` context, e.g.
+/// `.with_context(|| Failure::internal(format!("Failed to write {}", ...)))`.
+#[derive(Debug)]
+pub struct Failure {
+    kind: ExitKind,
+    message: String,
+}
+
+impl Failure {
+    pub fn new(kind: ExitKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(ExitKind::Internal, message)
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Failure {}
+
+/// Error for a missing reduction path or an infeasible instance.
+pub fn no_result(message: impl Into<String>) -> anyhow::Error {
+    Failure::new(ExitKind::NoResult, message).into()
+}
+
+/// Classify an error; errors without a [`Failure`] are invalid input.
+pub fn exit_kind(err: &anyhow::Error) -> ExitKind {
+    err.downcast_ref::<Failure>()
+        .or_else(|| {
+            err.chain()
+                .find_map(|cause| cause.downcast_ref::<Failure>())
+        })
+        .map_or(ExitKind::InvalidInput, |failure| failure.kind)
+}
+
+/// Replace an error's message while keeping its classification.
+pub fn reword(err: &anyhow::Error, message: impl Into<String>) -> anyhow::Error {
+    Failure::new(exit_kind(err), message).into()
+}
+
+/// The single JSON document `--json` prints on stdout when a command fails.
+pub fn error_json(kind: ExitKind, message: &str) -> serde_json::Value {
+    serde_json::json!({
+        "error": {
+            "kind": kind.name(),
+            "code": kind.code(),
+            "message": message,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_unclassified_errors_are_invalid_input() {
+        let err = anyhow::anyhow!("Invalid edge '0-'");
+        assert_eq!(exit_kind(&err), ExitKind::InvalidInput);
+        assert_eq!(exit_kind(&err).code(), 3);
+    }
+
+    #[test]
+    fn test_failure_is_found_as_root_or_context() {
+        let root = no_result("No reduction path from A to B");
+        assert_eq!(exit_kind(&root), ExitKind::NoResult);
+        let wrapped = root.context("while solving");
+        assert_eq!(exit_kind(&wrapped), ExitKind::NoResult);
+
+        let io: std::io::Result<()> = Err(std::io::Error::other("disk full"));
+        let err = io
+            .with_context(|| Failure::internal("Failed to write out.json"))
+            .unwrap_err();
+        assert_eq!(exit_kind(&err), ExitKind::Internal);
+        assert_eq!(err.to_string(), "Failed to write out.json");
+    }
+
+    #[test]
+    fn test_reword_keeps_kind() {
+        let err = reword(
+            &no_result("No reduction path"),
+            "No reduction path\n\nHint: ...",
+        );
+        assert_eq!(exit_kind(&err), ExitKind::NoResult);
+        assert_eq!(err.to_string(), "No reduction path\n\nHint: ...");
+    }
+
+    #[test]
+    fn test_error_json_shape() {
+        let json = error_json(ExitKind::NoResult, "no path");
+        assert_eq!(json["error"]["kind"], "no_result");
+        assert_eq!(json["error"]["code"], 2);
+        assert_eq!(json["error"]["message"], "no path");
+    }
+}
@@ -1,6 +1,7 @@
 mod cli;
 mod commands;
 mod dispatch;
+mod exit;
 #[cfg(feature = "mcp")]
 mod mcp;
 mod output;
@@ -11,9 +12,11 @@ mod util;
 
 use clap::{CommandFactory, Parser};
 use cli::{Cli, Commands};
+use exit::ExitKind;
 use output::OutputConfig;
+use std::panic::{self, AssertUnwindSafe};
 
-fn main() -> anyhow::Result<()> {
+fn main() {
     let cli = match Cli::try_parse() {
         Ok(cli) => cli,
         Err(e) => {
@@ -27,7 +30,13 @@ fn main() -> anyhow::Result<()> {
             eprint!("{e}");
             // Show the subcommand's after_help (defined once in cli.rs)
             cli::print_subcommand_help_hint(&msg);
-            std::process::exit(e.exit_code());
+            // Parsing failed, so --json is only visible in the raw arguments.
+            if std::env::args().any(|arg| arg == "--json") {
+                let first_line = msg.lines().next().unwrap_or_default();
+                let message = first_line.trim_start_matches("error: ");
+                print_json_error(ExitKind::InvalidInput, message);
+            }
+            std::process::exit(ExitKind::InvalidInput.code());
         }
     };
 
@@ -38,7 +47,10 @@ fn main() -> anyhow::Result<()> {
             | Commands::Solve(_)
             | Commands::Evaluate(_)
             | Commands::Inspect(_)
+            | Commands::Stats(_)
             | Commands::Extract(_)
+            | Commands::Batch(_)
+            | Commands::Bench(_)
     );
 
     let out = OutputConfig {
@@ -46,37 +58,102 @@ fn main() -> anyhow::Result<()> {
         quiet: cli.quiet,
         json: cli.json,
         auto_json,
+        ..Default::default()
+    };
+
+    // The default panic hook has already printed the message and location;
+    // only the exit code and the --json document are left to report.
+    let (kind, message) = match panic::catch_unwind(AssertUnwindSafe(|| run(cli.command, &out))) {
+        Ok(Ok(())) => return,
+        Ok(Err(err)) => {
+            eprintln!("Error: {err:?}");
+            (exit::exit_kind(&err), format!("{err:#}"))
+        }
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "panic".to_string());
+            (ExitKind::Internal, format!("internal error: {message}"))
+        }
     };
+    if out.json_errors() {
+        print_json_error(kind, &message);
+    }
+    std::process::exit(kind.code());
+}
+
+fn print_json_error(kind: ExitKind, message: &str) {
+    let json = exit::error_json(kind, message);
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&json).unwrap_or_else(|_| json.to_string())
+    );
+}
 
-    match cli.command {
-        Commands::List { rules } => {
+fn run(command: Commands, out: &OutputConfig) -> anyhow::Result<()> {
+    match command {
+        Commands::List {
+            rules,
+            complexity,
+            category,
+            group_by,
+        } => {
             if rules {
-                commands::graph::list_rules(&out)
+                commands::graph::list_rules(out)
             } else {
-                commands::graph::list(&out)
+                commands::graph::list(complexity.as_deref(), category.as_deref(), group_by, out)
             }
         }
-        Commands::Show { problem } => commands::graph::show(&problem, &out),
-        Commands::To { problem, hops } => commands::graph::neighbors(&problem, hops, "in", &out),
-        Commands::From { problem, hops } => commands::graph::neighbors(&problem, hops, "out", &out),
+        Commands::Show { problem, example } => commands::graph::show(&problem, example, out),
+        Commands::Schema { problem, all } => commands::schema::schema(problem.as_deref(), all, out),
+        Commands::To { problem, hops } => commands::graph::neighbors(&problem, hops, "in", out),
+        Commands::From { problem, hops } => commands::graph::neighbors(&problem, hops, "out", out),
         Commands::Path {
             source,
             target,
             cost,
             all,
             max_paths,
-        } => commands::graph::path(&source, &target, &cost, all, max_paths, &out),
-        Commands::ExportGraph => commands::graph::export(&out),
-        Commands::Inspect(args) => commands::inspect::inspect(&args.input, &out),
-        Commands::Create(args) => commands::create::create(&args, &out),
-        Commands::Solve(args) => {
-            commands::solve::solve(&args.input, &args.solver, args.timeout, &out)
+        } => commands::graph::path(&source, &target, &cost, all, max_paths, out),
+        Commands::ExportGraph { condensed, format } => {
+            commands::graph::export(condensed, format, out)
         }
+        Commands::Inspect(args) => commands::inspect::inspect(&args.input, out),
+        Commands::Stats(args) => commands::stats::stats(&args.input, out),
+        Commands::Audit(args) => commands::audit::audit(&args, out),
+        Commands::Create(args) => commands::create::create(&args, out),
+        Commands::Solve(args) => commands::solve::solve(&args, out),
+        Commands::Batch(args) => commands::batch::batch(&args, out),
+        Commands::Bench(args) => commands::bench::bench(&args, out),
         Commands::Reduce(args) => {
-            commands::reduce::reduce(&args.input, args.to.as_deref(), args.via.as_deref(), &out)
+            if args.estimate {
+                commands::reduce::estimate(
+                    &args.input,
+                    args.to.as_deref(),
+                    args.via.as_deref(),
+                    args.warn_bytes,
+                    out,
+                )
+            } else {
+                commands::reduce::reduce(
+                    &args.input,
+                    args.to.as_deref(),
+                    args.via.as_deref(),
+                    args.export_target_graph.as_deref(),
+                    out,
+                )
+            }
         }
-        Commands::Evaluate(args) => commands::evaluate::evaluate(&args.input, &args.config, &out),
-        Commands::Extract(args) => commands::extract::extract(&args.input, &args.config, &out),
+        Commands::Evaluate(args) => commands::evaluate::evaluate(
+            &args.input,
+            args.config.as_deref(),
+            args.config_file.as_deref(),
+            args.breakdown,
+            out,
+        ),
+        Commands::Extract(args) => commands::extract::extract(&args.input, &args.config, out),
         #[cfg(feature = "mcp")]
         Commands::Mcp => mcp::run(),
         Commands::Completions { shell } => {
@@ -1,4 +1,5 @@
 mod prompts;
+mod resources;
 #[cfg(test)]
 mod tests;
 mod tools;
@@ -0,0 +1,47 @@
+use rmcp::model::{AnnotateAble, RawResource, ReadResourceResult, Resource, ResourceContents};
+
+/// URI prefix of the per-problem JSON Schema resources.
+pub const SCHEMA_URI_PREFIX: &str = "pred://schema/";
+
+/// Return one JSON Schema resource per registered problem type.
+pub fn list_resources() -> Vec<Resource> {
+    problemreductions::registry::collect_schemas()
+        .into_iter()
+        .map(|entry| {
+            let mut resource = RawResource::new(
+                format!("{SCHEMA_URI_PREFIX}{}", entry.name),
+                format!("{} schema", entry.name),
+            );
+            resource.description = Some(format!(
+                "JSON Schema of a {} problem file (as written by create_problem)",
+                entry.name
+            ));
+            resource.mime_type = Some("application/schema+json".to_string());
+            resource.no_annotation()
+        })
+        .collect()
+}
+
+/// Read a `pred://schema/<Problem>` resource.
+///
+/// The problem part accepts the same specs as the CLI (`MIS`, `MIS/UnitDiskGraph`).
+pub fn read_resource(uri: &str) -> Option<ReadResourceResult> {
+    let text = schema_text(uri)?;
+    Some(ReadResourceResult::new(vec![ResourceContents::text(
+        text, uri,
+    )]))
+}
+
+/// Pretty-printed schema for a resource URI, or `None` if it names no problem.
+pub(crate) fn schema_text(uri: &str) -> Option<String> {
+    let spec = uri.strip_prefix(SCHEMA_URI_PREFIX)?;
+    let graph = problemreductions::rules::ReductionGraph::new();
+    let resolved = crate::problem_name::resolve_problem_ref(spec, &graph).ok()?;
+    let explicit_variant = !crate::problem_name::parse_problem_spec(spec)
+        .ok()?
+        .variant_values
+        .is_empty();
+    let variant = explicit_variant.then_some(&resolved.variant);
+    let schema = problemreductions::registry::problem_json_schema(&resolved.name, variant)?;
+    serde_json::to_string_pretty(&schema).ok()
+}
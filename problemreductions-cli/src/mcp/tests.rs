@@ -22,6 +22,19 @@ mod tests {
         assert_eq!(json["name"], "MaximumIndependentSet");
     }
 
+    #[test]
+    fn test_show_problem_embeds_solvable_example() {
+        let server = McpServer::new();
+        let result = server.show_problem_inner("MIS").unwrap();
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let example = &json["example"];
+        assert_eq!(example["type"], "MaximumIndependentSet");
+        assert_eq!(example["variant"], json["variant"]);
+
+        let solved = server.solve_inner(&example.to_string(), Some("brute-force"), None);
+        assert!(solved.is_ok(), "{:?}", solved.err());
+    }
+
     #[test]
     fn test_show_problem_unknown() {
         let server = McpServer::new();
@@ -166,6 +179,32 @@ mod tests {
         assert!(json.is_object());
     }
 
+    #[test]
+    fn test_condensed_graph() {
+        let server = McpServer::new();
+        let json: serde_json::Value =
+            serde_json::from_str(&server.condensed_graph_inner().unwrap()).unwrap();
+        let clusters = json["clusters"].as_array().unwrap();
+        let mvc_cluster = clusters
+            .iter()
+            .find(|c| {
+                c["members"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .any(|m| m["name"] == "MinimumVertexCover")
+            })
+            .unwrap();
+        let names: Vec<&str> = mvc_cluster["members"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["name"].as_str().unwrap())
+            .collect();
+        assert!(names.contains(&"MaximumIndependentSet"));
+        assert!(json["edges"].as_array().unwrap().len() > 0);
+    }
+
     // -- Instance tool tests --------------------------------------------------
 
     fn create_test_mis(server: &McpServer) -> String {
@@ -293,6 +332,22 @@ mod tests {
         assert!(json["num_variables"].as_u64().unwrap() > 0);
     }
 
+    #[test]
+    fn test_instance_stats() {
+        let server = McpServer::new();
+        let params = serde_json::json!({
+            "edges": "0-1,0-4,0-5,1-2,1-6,2-3,2-7,3-4,3-8,4-9,5-7,5-8,6-8,6-9,7-9"
+        });
+        let problem_json = server.create_problem_inner("MIS", &params).unwrap();
+        let result = server.instance_stats_inner(&problem_json);
+        assert!(result.is_ok(), "instance_stats failed: {:?}", result);
+        let json: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(json["type"], "MaximumIndependentSet");
+        assert_eq!(json["num_variables"], 10);
+        assert_eq!(json["graph"]["max_degree"], 3);
+        assert_eq!(json["graph"]["pathwidth_upper_bound"], 5);
+    }
+
     #[test]
     fn test_evaluate() {
         let server = McpServer::new();
@@ -532,4 +587,28 @@ mod tests {
         let err = result.unwrap_err().to_string();
         assert!(err.contains("witness"), "unexpected error: {err}");
     }
+
+    #[test]
+    fn test_schema_resources_cover_every_problem() {
+        let resources = crate::mcp::resources::list_resources();
+        assert_eq!(
+            resources.len(),
+            problemreductions::registry::collect_schemas().len()
+        );
+        assert!(resources
+            .iter()
+            .any(|r| r.raw.uri == "pred://schema/MaximumIndependentSet"));
+    }
+
+    #[test]
+    fn test_read_schema_resource() {
+        let text = crate::mcp::resources::schema_text("pred://schema/MIS/SimpleGraph/i32").unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            schema["properties"]["type"]["const"],
+            "MaximumIndependentSet"
+        );
+        assert!(crate::mcp::resources::schema_text("pred://schema/NoSuchProblem").is_none());
+        assert!(crate::mcp::resources::schema_text("file:///etc/passwd").is_none());
+    }
 }
@@ -82,6 +82,12 @@ pub struct InspectParams {
     pub problem_json: String,
 }
 
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct StatsParams {
+    #[schemars(description = "Problem JSON string (from create_problem)")]
+    pub problem_json: String,
+}
+
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub struct EvaluateParams {
     #[schemars(description = "Problem JSON string (from create_problem)")]
@@ -216,6 +222,20 @@ impl McpServer {
                 obj.insert("schema".to_string(), schema_val);
             }
         }
+        // Embed a runnable instance in the same format as `create_problem`
+        if let (Some(obj), Ok(example)) = (
+            json.as_object_mut(),
+            problemreductions::example_db::find_problem_example(&resolved),
+        ) {
+            obj.insert(
+                "example".to_string(),
+                serde_json::json!({
+                    "type": example.problem,
+                    "variant": example.variant,
+                    "data": example.instance,
+                }),
+            );
+        }
 
         Ok(serde_json::to_string_pretty(&json)?)
     }
@@ -361,6 +381,12 @@ impl McpServer {
         Ok(json_str)
     }
 
+    pub fn condensed_graph_inner(&self) -> anyhow::Result<String> {
+        let graph = ReductionGraph::new();
+        let json = serde_json::to_value(graph.condensation())?;
+        Ok(serde_json::to_string_pretty(&json)?)
+    }
+
     // -- instance tool inner helpers ------------------------------------------
 
     pub fn create_problem_inner(
@@ -766,6 +792,23 @@ impl McpServer {
         Ok(serde_json::to_string_pretty(&result)?)
     }
 
+    pub fn instance_stats_inner(&self, problem_json: &str) -> anyhow::Result<String> {
+        let pj: ProblemJson = serde_json::from_str(problem_json)?;
+        let problem = load_problem(&pj.problem_type, &pj.variant, pj.data)?;
+
+        let mut result = serde_json::json!({
+            "type": problem.problem_name(),
+            "variant": problem.variant_map(),
+        });
+        if let (Some(obj), serde_json::Value::Object(fields)) = (
+            result.as_object_mut(),
+            serde_json::to_value(problem.statistics_dyn())?,
+        ) {
+            obj.extend(fields);
+        }
+        Ok(serde_json::to_string_pretty(&result)?)
+    }
+
     pub fn evaluate_inner(&self, problem_json: &str, config: &[usize]) -> anyhow::Result<String> {
         let pj: ProblemJson = serde_json::from_str(problem_json)?;
         let problem = load_problem(&pj.problem_type, &pj.variant, pj.data)?;
@@ -934,7 +977,7 @@ impl McpServer {
         self.list_problems_inner().map_err(|e| e.to_string())
     }
 
-    /// Show details for a problem type: variants, fields, size fields, and reductions
+    /// Show details for a problem type: variants, fields, size fields, reductions, and an example instance
     #[tool(
         name = "show_problem",
         annotations(read_only_hint = true, open_world_hint = false)
@@ -981,6 +1024,15 @@ impl McpServer {
         self.export_graph_inner().map_err(|e| e.to_string())
     }
 
+    /// Export clusters of mutually reducible problems (strongly connected components) and the DAG between them
+    #[tool(
+        name = "condensed_graph",
+        annotations(read_only_hint = true, open_world_hint = false)
+    )]
+    fn condensed_graph(&self) -> Result<String, String> {
+        self.condensed_graph_inner().map_err(|e| e.to_string())
+    }
+
     /// Create a problem instance from parameters and return its JSON representation
     #[tool(
         name = "create_problem",
@@ -1007,6 +1059,19 @@ impl McpServer {
             .map_err(|e| e.to_string())
     }
 
+    /// Compute instance statistics: variables, domain sizes, and for graph problems density, degrees, components, and a pathwidth upper bound
+    #[tool(
+        name = "instance_stats",
+        annotations(read_only_hint = true, open_world_hint = false)
+    )]
+    fn instance_stats(
+        &self,
+        Parameters(params): Parameters<StatsParams>,
+    ) -> Result<String, String> {
+        self.instance_stats_inner(&params.problem_json)
+            .map_err(|e| e.to_string())
+    }
+
     /// Evaluate a configuration against a problem instance and return the result
     #[tool(
         name = "evaluate",
@@ -1052,6 +1117,7 @@ impl rmcp::ServerHandler for McpServer {
         let capabilities = rmcp::model::ServerCapabilities::builder()
             .enable_tools()
             .enable_prompts()
+            .enable_resources()
             .build();
         let server_info =
             rmcp::model::Implementation::new("problemreductions", env!("CARGO_PKG_VERSION"));
@@ -1062,7 +1128,8 @@ impl rmcp::ServerHandler for McpServer {
                  Graph query tools: list_problems, show_problem, neighbors, find_path, export_graph. \
                  Instance tools: create_problem to build instances, inspect_problem for details, \
                  evaluate to test configurations, reduce to transform between problem types, \
-                 solve to find optimal solutions.",
+                 solve to find optimal solutions. \
+                 Resources: pred://schema/<Problem> serves the JSON Schema of each problem file.",
             )
     }
 
@@ -1086,6 +1153,26 @@ impl rmcp::ServerHandler for McpServer {
             rmcp::ErrorData::invalid_params(format!("Unknown prompt: {}", request.name), None)
         })
     }
+
+    async fn list_resources(
+        &self,
+        _request: Option<rmcp::model::PaginatedRequestParams>,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<rmcp::model::ListResourcesResult, rmcp::ErrorData> {
+        Ok(rmcp::model::ListResourcesResult::with_all_items(
+            super::resources::list_resources(),
+        ))
+    }
+
+    async fn read_resource(
+        &self,
+        request: rmcp::model::ReadResourceRequestParams,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<rmcp::model::ReadResourceResult, rmcp::ErrorData> {
+        super::resources::read_resource(&request.uri).ok_or_else(|| {
+            rmcp::ErrorData::resource_not_found(format!("Unknown resource: {}", request.uri), None)
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -1,20 +1,29 @@
+use crate::exit::Failure;
 use anyhow::Context;
 use owo_colors::OwoColorize;
+use std::cell::Cell;
 use std::io::IsTerminal;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Output configuration derived from CLI flags.
-#[derive(Debug, Clone)]
+///
+/// Stream contract: results go to stdout (or the `-o` file), everything else
+/// goes to stderr. With `--json`, stdout carries exactly one JSON document:
+/// the result, or an `{"error": ...}` document written by `main` when the
+/// command fails before emitting anything.
+#[derive(Debug, Clone, Default)]
 pub struct OutputConfig {
     /// Output file path. When set, output is saved as JSON.
     pub output: Option<PathBuf>,
-    /// Suppress informational messages on stderr.
+    /// Suppress informational messages and hints on stderr.
     pub quiet: bool,
     /// Output JSON to stdout instead of human-readable text.
     pub json: bool,
     /// When true, auto-output JSON if stdout is not a TTY (piped).
     /// Used for data-producing commands (reduce, solve, evaluate, inspect).
     pub auto_json: bool,
+    /// Set once the command has written its result.
+    pub emitted: Cell<bool>,
 }
 
 impl OutputConfig {
@@ -25,6 +34,40 @@ impl OutputConfig {
         }
     }
 
+    /// Print a usage hint to stderr when a person is likely reading it:
+    /// not quiet, no `-o` file, and stderr is a terminal.
+    pub fn hint(&self, msg: &str) {
+        if self.output.is_none() && stderr_is_tty() {
+            self.info(msg);
+        }
+    }
+
+    /// Whether a failed command should report its error as a JSON document
+    /// on stdout.
+    pub fn json_errors(&self) -> bool {
+        self.json && self.output.is_none() && !self.emitted.get()
+    }
+
+    /// Print a JSON document to stdout.
+    pub fn print_json(&self, json_value: &serde_json::Value) -> anyhow::Result<()> {
+        let content = serde_json::to_string_pretty(json_value)
+            .context(Failure::internal("Failed to serialize JSON"))?;
+        println!("{content}");
+        self.emitted.set(true);
+        Ok(())
+    }
+
+    /// Write a JSON document to `path`, announcing it on stderr.
+    pub fn write_json(&self, path: &Path, json_value: &serde_json::Value) -> anyhow::Result<()> {
+        let content = serde_json::to_string_pretty(json_value)
+            .context(Failure::internal("Failed to serialize JSON"))?;
+        std::fs::write(path, &content)
+            .with_context(|| Failure::internal(format!("Failed to write {}", path.display())))?;
+        self.emitted.set(true);
+        self.info(&format!("Wrote {}", path.display()));
+        Ok(())
+    }
+
     /// Emit output: `-o` saves JSON to file, `--json` prints JSON to stdout,
     /// otherwise prints human-readable text.
     pub fn emit_with_default_name(
@@ -34,20 +77,14 @@ impl OutputConfig {
         json_value: &serde_json::Value,
     ) -> anyhow::Result<()> {
         if let Some(ref path) = self.output {
-            let content =
-                serde_json::to_string_pretty(json_value).context("Failed to serialize JSON")?;
-            std::fs::write(path, &content)
-                .with_context(|| format!("Failed to write {}", path.display()))?;
-            self.info(&format!("Wrote {}", path.display()));
+            self.write_json(path, json_value)
         } else if self.json || (self.auto_json && !std::io::stdout().is_terminal()) {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(json_value).context("Failed to serialize JSON")?
-            );
+            self.print_json(json_value)
         } else {
             println!("{human_text}");
+            self.emitted.set(true);
+            Ok(())
         }
-        Ok(())
     }
 }
 
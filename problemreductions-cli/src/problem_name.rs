@@ -343,6 +343,8 @@ mod tests {
         assert_eq!(resolve_alias("DMVC"), "DecisionMinimumVertexCover");
         assert_eq!(resolve_alias("VC"), "DecisionMinimumVertexCover");
         assert_eq!(resolve_alias("VertexCover"), "DecisionMinimumVertexCover");
+        assert_eq!(resolve_alias("DomaticPartition"), "MaximumDomaticNumber");
+        assert_eq!(resolve_alias("domaticnumber"), "MaximumDomaticNumber");
         // Pass-through for full names
         assert_eq!(
             resolve_alias("MaximumIndependentSet"),
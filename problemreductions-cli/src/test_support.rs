@@ -3,7 +3,7 @@ use problemreductions::models::algebraic::{ObjectiveSense, ILP};
 use problemreductions::registry::VariantEntry;
 use problemreductions::rules::registry::{EdgeCapabilities, ReductionEntry, ReductionOverhead};
 use problemreductions::rules::{AggregateReductionResult, ReductionAutoCast};
-use problemreductions::solvers::{BruteForce, Solver};
+use problemreductions::solvers::{BruteForce, Solver, SolverTelemetry};
 use problemreductions::traits::Problem;
 use problemreductions::types::{Extremum, ProblemSize, Sum};
 use serde::{Deserialize, Serialize};
@@ -119,6 +119,21 @@ where
     Some((config, evaluation))
 }
 
+fn solve_telemetry<P>(any: &dyn Any) -> (String, SolverTelemetry)
+where
+    P: Problem + Serialize + 'static,
+    P::Value: problemreductions::types::Aggregate + std::fmt::Display,
+{
+    let problem = any
+        .downcast_ref::<P>()
+        .expect("test solve_telemetry downcast failed");
+    let (value, telemetry) = BruteForce::new().solve_with_telemetry(problem);
+    (
+        problemreductions::registry::format_metric(&value),
+        telemetry,
+    )
+}
+
 problemreductions::inventory::submit! {
     VariantEntry {
         name: AggregateValueSource::NAME,
@@ -137,6 +152,7 @@ problemreductions::inventory::submit! {
         },
         solve_value_fn: solve_value::<AggregateValueSource>,
         solve_witness_fn: solve_witness::<AggregateValueSource>,
+        solve_telemetry_fn: solve_telemetry::<AggregateValueSource>,
     }
 }
 
@@ -158,6 +174,7 @@ problemreductions::inventory::submit! {
         },
         solve_value_fn: solve_value::<AggregateValueTarget>,
         solve_witness_fn: solve_witness::<AggregateValueTarget>,
+        solve_telemetry_fn: solve_telemetry::<AggregateValueTarget>,
     }
 }
 
@@ -211,6 +211,34 @@ fn test_path() {
     assert!(stdout.contains("step"));
 }
 
+#[test]
+fn test_path_from_kings_subgraph_starts_with_topology_cast() {
+    let output = pred()
+        .args(["path", "MIS/KingsSubgraph", "QUBO", "--json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let steps = json["path"].as_array().unwrap();
+    let first = &steps[0];
+    assert_eq!(first["from"]["name"], "MaximumIndependentSet");
+    assert_eq!(first["from"]["variant"]["graph"], "KingsSubgraph");
+    assert_eq!(first["to"]["name"], "MaximumIndependentSet");
+    for item in first["overhead"].as_array().unwrap() {
+        assert_eq!(item["formula"], item["field"]);
+    }
+    // Graph and weight casts tie on step count, so either may come first;
+    // the path must still relax the topology through UnitDiskGraph.
+    assert!(steps
+        .iter()
+        .any(|step| step["to"]["name"] == "MaximumIndependentSet"
+            && step["to"]["variant"]["graph"] == "UnitDiskGraph"));
+}
+
 #[test]
 fn test_path_save() {
     let tmp = std::env::temp_dir().join("pred_test_path.json");
@@ -279,6 +307,53 @@ fn test_export() {
     std::fs::remove_file(&tmp).ok();
 }
 
+#[test]
+fn test_export_condensed() {
+    let tmp = std::env::temp_dir().join("pred_test_export_condensed.json");
+    let output = pred()
+        .args(["export-graph", "--condensed", "-o", tmp.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let content = std::fs::read_to_string(&tmp).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+    let clusters = json["clusters"].as_array().unwrap();
+    let cluster_of = |name: &str| {
+        clusters
+            .iter()
+            .position(|c| {
+                c["members"].as_array().unwrap().iter().any(|m| {
+                    m["name"] == name
+                        && m["variant"]
+                            == serde_json::json!({"graph": "SimpleGraph", "weight": "i32"})
+                })
+            })
+            .unwrap()
+    };
+    assert_eq!(
+        cluster_of("MaximumIndependentSet"),
+        cluster_of("MinimumVertexCover")
+    );
+    for edge in json["edges"].as_array().unwrap() {
+        assert!(edge[0].as_u64().unwrap() < edge[1].as_u64().unwrap());
+    }
+    std::fs::remove_file(&tmp).ok();
+
+    let output = pred()
+        .args(["export-graph", "--condensed"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("Condensed reduction graph:"),
+        "stdout should contain summary, got: {stdout}"
+    );
+}
+
 #[test]
 fn test_export_stdout() {
     let output = pred().args(["export-graph"]).output().unwrap();
@@ -295,6 +370,44 @@ fn test_export_stdout() {
     );
 }
 
+#[test]
+fn test_export_mermaid() {
+    let output = pred()
+        .args(["export-graph", "--format", "mermaid"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("graph LR\n"), "got: {stdout}");
+    assert!(stdout.contains("\n    MaximumIndependentSet\n"));
+    assert!(stdout.contains("\n    MaximumIndependentSet -->|1| MinimumVertexCover\n"));
+
+    let tmp = std::env::temp_dir().join("pred_test_export_mermaid.mmd");
+    let output = pred()
+        .args([
+            "export-graph",
+            "--format",
+            "mermaid",
+            "-o",
+            tmp.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(std::fs::read_to_string(&tmp).unwrap(), stdout);
+    std::fs::remove_file(&tmp).ok();
+
+    let output = pred()
+        .args(["export-graph", "--condensed", "--format", "mermaid"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
 #[test]
 fn test_show_includes_fields() {
     let output = pred().args(["show", "MIS"]).output().unwrap();
@@ -321,6 +434,140 @@ fn test_create_balanced_complete_bipartite_subgraph_help_uses_bipartite_flags()
     assert!(!stderr.contains("--edges"), "stderr: {stderr}");
 }
 
+#[test]
+fn test_create_and_solve_maximum_balanced_biclique() {
+    let path = std::env::temp_dir().join("pred_test_maximum_balanced_biclique.json");
+    let create = pred()
+        .args([
+            "create",
+            "MaximumBalancedBiclique",
+            "--left",
+            "3",
+            "--right",
+            "3",
+            "--biedges",
+            "0-0,0-1,1-0,1-1,2-1,2-2",
+            "-o",
+            path.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        create.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&create.stderr)
+    );
+
+    let solve = pred()
+        .args([
+            "solve",
+            path.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        solve.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&solve.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&solve.stdout).unwrap();
+    assert_eq!(json["solution"], serde_json::json!([1, 1, 0, 1, 1, 0]));
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_create_maximum_balanced_biclique_rejects_out_of_range_edge() {
+    let output = pred()
+        .args([
+            "create",
+            "MaximumBalancedBiclique",
+            "--left",
+            "2",
+            "--right",
+            "2",
+            "--biedges",
+            "0-0,1-2",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("out of bounds"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_create_and_solve_k_track_interval_scheduling() {
+    let path = std::env::temp_dir().join("pred_test_k_track_interval_scheduling.json");
+    let create = pred()
+        .args([
+            "create",
+            "KTrackIntervalScheduling",
+            "--starts",
+            "0,1,2,3",
+            "--ends",
+            "3,4,5,6",
+            "--weights",
+            "1,3,3,1",
+            "--k",
+            "2",
+            "-o",
+            path.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        create.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&create.stderr)
+    );
+
+    let solve = pred()
+        .args([
+            "solve",
+            path.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        solve.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&solve.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&solve.stdout).unwrap();
+    assert_eq!(json["evaluation"], "Max(6)");
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_create_k_track_interval_scheduling_rejects_empty_interval() {
+    let output = pred()
+        .args([
+            "create",
+            "KTrackIntervalScheduling",
+            "--starts",
+            "0,3",
+            "--ends",
+            "2,3",
+            "--weights",
+            "1,1",
+            "--k",
+            "1",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("interval 1 is empty"), "stderr: {stderr}");
+}
+
 #[test]
 fn test_list_json() {
     let tmp = std::env::temp_dir().join("pred_test_list.json");
@@ -336,6 +583,114 @@ fn test_list_json() {
     std::fs::remove_file(&tmp).ok();
 }
 
+#[test]
+fn test_list_filter_by_complexity() {
+    let output = pred()
+        .args(["list", "--complexity", "pspace-complete"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("QuantifiedBooleanFormulas"));
+    assert!(stdout.contains("GeneralizedHex"));
+    assert!(!stdout.contains("MaximumIndependentSet"));
+}
+
+#[test]
+fn test_list_filter_by_complexity_and_category() {
+    let output = pred()
+        .args(["list", "--complexity", "np-complete", "--category", "graph"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("MaximumIndependentSet"));
+    assert!(stdout.contains("MaxCut"));
+    assert!(!stdout.contains("Satisfiability"));
+    assert!(!stdout.contains("GeneralizedHex"));
+}
+
+#[test]
+fn test_list_group_by_category() {
+    let output = pred()
+        .args([
+            "list",
+            "--group-by",
+            "category",
+            "--complexity",
+            "pspace-complete",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let graph_at = stdout.find("graph (1 type)").expect("graph group header");
+    let formula_at = stdout
+        .find("formula (1 type)")
+        .expect("formula group header");
+    let hex_at = stdout.find("GeneralizedHex").unwrap();
+    let qbf_at = stdout.find("QuantifiedBooleanFormulas").unwrap();
+    assert!(graph_at < hex_at && hex_at < formula_at && formula_at < qbf_at);
+}
+
+#[test]
+fn test_list_group_by_json_is_nested() {
+    let output = pred()
+        .args([
+            "list",
+            "--group-by",
+            "category",
+            "--category",
+            "graph",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let groups = json["groups"].as_array().unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0]["name"], "graph");
+    let names: Vec<&str> = groups[0]["variants"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["name"].as_str().unwrap())
+        .collect();
+    assert!(names.iter().any(|n| n.starts_with("MaximalIS/")));
+    assert!(names
+        .iter()
+        .any(|n| n.starts_with("MaximumIndependentSet/")));
+    assert!(!names.iter().any(|n| n.starts_with("Satisfiability")));
+}
+
+#[test]
+fn test_list_filter_rejects_subcategory() {
+    let output = pred()
+        .args(["list", "--category", "graph/independent"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown category"), "stderr: {stderr}");
+    assert!(stderr.contains("graph, formula"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_list_filter_rejects_unknown_class() {
+    let output = pred()
+        .args(["list", "--complexity", "exptime"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("NP-complete"), "stderr: {stderr}");
+}
+
 #[test]
 fn test_unknown_problem() {
     let output = pred().args(["show", "NonExistent"]).output().unwrap();
@@ -423,34 +778,129 @@ fn test_evaluate_sat() {
 }
 
 #[test]
-fn test_evaluate_consecutive_block_minimization_rejects_ragged_matrix() {
+fn test_evaluate_maxcut_breakdown_from_config_file() {
     let problem_json = r#"{
-        "type": "ConsecutiveBlockMinimization",
+        "type": "MaxCut",
+        "variant": {"graph": "SimpleGraph", "weight": "i32"},
         "data": {
-            "matrix": [[true, false], [true]],
-            "bound": 1
+            "graph": {"num_vertices": 4, "edges": [[0,1],[1,2],[2,3],[0,3],[0,2]]},
+            "edge_weights": [1, 2, 3, 4, 5]
         }
     }"#;
-    let tmp = std::env::temp_dir().join("pred_test_eval_cbm_ragged_matrix.json");
-    std::fs::write(&tmp, problem_json).unwrap();
+    let problem_file = std::env::temp_dir().join("pred_test_eval_breakdown.json");
+    let config_file = std::env::temp_dir().join("pred_test_eval_breakdown_config.txt");
+    let result_file = std::env::temp_dir().join("pred_test_eval_breakdown_out.json");
+    std::fs::write(&problem_file, problem_json).unwrap();
+    std::fs::write(&config_file, "1\n0\n0\n1\n").unwrap();
 
     let output = pred()
-        .args(["evaluate", tmp.to_str().unwrap(), "--config", "0,1"])
+        .args([
+            "evaluate",
+            problem_file.to_str().unwrap(),
+            "--config-file",
+            config_file.to_str().unwrap(),
+            "--breakdown",
+        ])
         .output()
         .unwrap();
-    assert!(!output.status.success());
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    assert!(stderr.contains("same length"));
-    assert!(!stderr.contains("panicked at"), "stderr: {stderr}");
-    std::fs::remove_file(&tmp).ok();
-}
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    // Partition {0, 3} vs {1, 2} cuts (0,1), (2,3) and (0,2).
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"], "Max(9)");
+    assert_eq!(json["breakdown"][4]["label"], "edge (0, 2)");
+    assert_eq!(json["breakdown"][4]["value"], 5.0);
+    assert_eq!(json["breakdown"][3]["value"], 0.0);
 
-#[test]
-fn test_evaluate_multiple_choice_branching_rejects_invalid_partition_without_panicking() {
-    let problem_json = r#"{
-        "type": "MultipleChoiceBranching",
-        "variant": {"weight": "i32"},
-        "data": {
+    // A JSON array config gives the same breakdown, which sums to the total.
+    std::fs::write(&config_file, "[1, 0, 0, 1]").unwrap();
+    let output = pred()
+        .args([
+            "-o",
+            result_file.to_str().unwrap(),
+            "evaluate",
+            problem_file.to_str().unwrap(),
+            "--config-file",
+            config_file.to_str().unwrap(),
+            "--breakdown",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&result_file).unwrap()).unwrap();
+    let terms = json["breakdown"].as_array().unwrap();
+    assert_eq!(terms.len(), 5);
+    let total: f64 = terms.iter().map(|t| t["value"].as_f64().unwrap()).sum();
+    assert_eq!(total, 9.0);
+    assert_eq!(json["result"], "Max(9)");
+
+    std::fs::remove_file(&problem_file).ok();
+    std::fs::remove_file(&config_file).ok();
+    std::fs::remove_file(&result_file).ok();
+}
+
+#[test]
+fn test_evaluate_breakdown_unsupported_problem() {
+    let problem_json = r#"{
+        "type": "SubsetSum",
+        "data": {"sizes": ["3", "5"], "target": "8"}
+    }"#;
+    let tmp = std::env::temp_dir().join("pred_test_eval_breakdown_unsupported.json");
+    std::fs::write(&tmp, problem_json).unwrap();
+
+    let output = pred()
+        .args([
+            "evaluate",
+            tmp.to_str().unwrap(),
+            "--config",
+            "1,1",
+            "--breakdown",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("breakdown"), "stderr: {stderr}");
+    std::fs::remove_file(&tmp).ok();
+}
+
+#[test]
+fn test_evaluate_consecutive_block_minimization_rejects_ragged_matrix() {
+    let problem_json = r#"{
+        "type": "ConsecutiveBlockMinimization",
+        "data": {
+            "matrix": [[true, false], [true]],
+            "bound": 1
+        }
+    }"#;
+    let tmp = std::env::temp_dir().join("pred_test_eval_cbm_ragged_matrix.json");
+    std::fs::write(&tmp, problem_json).unwrap();
+
+    let output = pred()
+        .args(["evaluate", tmp.to_str().unwrap(), "--config", "0,1"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("same length"));
+    assert!(!stderr.contains("panicked at"), "stderr: {stderr}");
+    std::fs::remove_file(&tmp).ok();
+}
+
+#[test]
+fn test_evaluate_multiple_choice_branching_rejects_invalid_partition_without_panicking() {
+    let problem_json = r#"{
+        "type": "MultipleChoiceBranching",
+        "variant": {"weight": "i32"},
+        "data": {
             "graph": {"num_vertices": 2, "arcs": [[0,1]]},
             "weights": [1],
             "partition": [[1]],
@@ -1216,6 +1666,289 @@ fn test_reduce_via_path() {
     std::fs::remove_file(&output_file).ok();
 }
 
+#[test]
+fn test_reduce_estimate_matches_real_size() {
+    // A 200-vertex circulant graph: every vertex adjacent to the next three.
+    let n = 200;
+    let edges: Vec<String> = (0..n)
+        .flat_map(|u| (1..=3).map(move |d| format!("{}-{}", u, (u + d) % n)))
+        .collect();
+    let weights = vec!["1"; n].join(",");
+    let problem_file = std::env::temp_dir().join("pred_test_reduce_estimate_in.json");
+    let create_out = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "MIS/SimpleGraph/i32",
+            "--graph",
+            &edges.join(","),
+            "--weights",
+            &weights,
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        create_out.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&create_out.stderr)
+    );
+
+    // Pin the route so both runs use the same chain of reductions.
+    let path_file = std::env::temp_dir().join("pred_test_reduce_estimate_path.json");
+    let mis = serde_json::json!({
+        "name": "MaximumIndependentSet",
+        "variant": {"graph": "SimpleGraph", "weight": "i32"},
+    });
+    let msp = serde_json::json!({"name": "MaximumSetPacking", "variant": {"weight": "i32"}});
+    let ilp = serde_json::json!({"name": "ILP", "variant": {"variable": "bool"}});
+    let route = serde_json::json!({"path": [{"from": mis, "to": msp}, {"from": msp, "to": ilp}]});
+    std::fs::write(&path_file, route.to_string()).unwrap();
+
+    let estimate_out = pred()
+        .args([
+            "--json",
+            "reduce",
+            problem_file.to_str().unwrap(),
+            "--via",
+            path_file.to_str().unwrap(),
+            "--estimate",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        estimate_out.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&estimate_out.stderr)
+    );
+    let estimate: serde_json::Value = serde_json::from_slice(&estimate_out.stdout).unwrap();
+    assert_eq!(estimate["target"], "ILP");
+    assert_eq!(estimate["source_size"]["num_vertices"], n);
+    assert_eq!(estimate["source_size"]["num_edges"], 3 * n);
+
+    let reduce_out = pred()
+        .args([
+            "--json",
+            "reduce",
+            problem_file.to_str().unwrap(),
+            "--via",
+            path_file.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(reduce_out.status.success());
+    let bundle: serde_json::Value = serde_json::from_slice(&reduce_out.stdout).unwrap();
+    let target = &bundle["target"]["data"];
+    assert_eq!(
+        estimate["estimated_target_size"]["num_vars"],
+        target["num_vars"]
+    );
+    assert_eq!(
+        estimate["estimated_target_size"]["num_constraints"],
+        target["constraints"].as_array().unwrap().len()
+    );
+
+    std::fs::remove_file(&problem_file).ok();
+    std::fs::remove_file(&path_file).ok();
+}
+
+#[test]
+fn test_reduce_dry_run_predicts_qubo_size() {
+    for seed in [1, 2, 3] {
+        let problem_file =
+            std::env::temp_dir().join(format!("pred_test_reduce_dry_run_{seed}.json"));
+        let create_out = pred()
+            .args([
+                "-o",
+                problem_file.to_str().unwrap(),
+                "create",
+                "MIS",
+                "--random",
+                "--num-vertices",
+                "12",
+                "--edge-prob",
+                "0.3",
+                "--seed",
+                &seed.to_string(),
+            ])
+            .output()
+            .unwrap();
+        assert!(
+            create_out.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&create_out.stderr)
+        );
+
+        let dry_run_out = pred()
+            .args([
+                "--json",
+                "reduce",
+                problem_file.to_str().unwrap(),
+                "--to",
+                "QUBO",
+                "--dry-run",
+            ])
+            .output()
+            .unwrap();
+        assert!(
+            dry_run_out.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&dry_run_out.stderr)
+        );
+        let estimate: serde_json::Value = serde_json::from_slice(&dry_run_out.stdout).unwrap();
+        assert_eq!(estimate["target"], "QUBO");
+        assert_eq!(estimate["exceeds_warn_bytes"], false);
+
+        let reduce_out = pred()
+            .args([
+                "--json",
+                "reduce",
+                problem_file.to_str().unwrap(),
+                "--to",
+                "QUBO",
+            ])
+            .output()
+            .unwrap();
+        assert!(reduce_out.status.success());
+        let bundle: serde_json::Value = serde_json::from_slice(&reduce_out.stdout).unwrap();
+        assert_eq!(estimate["path"], bundle["path"]);
+        let num_vars = bundle["target"]["data"]["num_vars"].as_u64().unwrap();
+        assert_eq!(estimate["estimated_target_size"]["num_vars"], num_vars);
+        assert_eq!(
+            bundle["target"]["data"]["matrix"].as_array().unwrap().len() as u64,
+            num_vars
+        );
+        assert_eq!(estimate["estimated_memory_bytes"], 8 * num_vars * num_vars);
+
+        std::fs::remove_file(&problem_file).ok();
+    }
+}
+
+#[test]
+fn test_reduce_dry_run_warns_above_threshold() {
+    let create_out = pred()
+        .args(["create", "MIS", "--graph", "0-1,1-2,2-3,3-4"])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    use std::io::Write;
+    let mut child = pred()
+        .args([
+            "--json",
+            "reduce",
+            "-",
+            "--to",
+            "QUBO",
+            "--dry-run",
+            "--warn-bytes",
+            "100",
+        ])
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(&create_out.stdout)
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let estimate: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(estimate["estimated_memory_bytes"], 8 * 5 * 5);
+    assert_eq!(estimate["exceeds_warn_bytes"], true);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("exceeds --warn-bytes 100 B"));
+}
+
+#[test]
+fn test_reduce_warn_bytes_requires_estimate() {
+    let output = pred()
+        .args([
+            "reduce",
+            "problem.json",
+            "--to",
+            "QUBO",
+            "--warn-bytes",
+            "10",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_reduce_mis_through_qubo_to_ilp() {
+    let problem_file = std::env::temp_dir().join("pred_test_reduce_qubo_ilp_in.json");
+    let create_out = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "MIS/SimpleGraph/i32",
+            "--graph",
+            "0-1,1-2,2-3",
+            "--weights",
+            "1,1,1,1",
+        ])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    let mis = serde_json::json!({
+        "name": "MaximumIndependentSet",
+        "variant": {"graph": "SimpleGraph", "weight": "i32"},
+    });
+    let msp_i32 = serde_json::json!({"name": "MaximumSetPacking", "variant": {"weight": "i32"}});
+    let msp_f64 = serde_json::json!({"name": "MaximumSetPacking", "variant": {"weight": "f64"}});
+    let qubo = serde_json::json!({"name": "QUBO", "variant": {"weight": "f64"}});
+    let ilp = serde_json::json!({"name": "ILP", "variant": {"variable": "bool"}});
+    let route = serde_json::json!({"path": [
+        {"from": mis, "to": msp_i32},
+        {"from": msp_i32, "to": msp_f64},
+        {"from": msp_f64, "to": qubo},
+        {"from": qubo, "to": ilp},
+    ]});
+    let path_file = std::env::temp_dir().join("pred_test_reduce_qubo_ilp_path.json");
+    std::fs::write(&path_file, route.to_string()).unwrap();
+
+    let output = pred()
+        .args([
+            "--json",
+            "reduce",
+            problem_file.to_str().unwrap(),
+            "--via",
+            path_file.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let bundle: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(bundle["target"]["type"], "ILP");
+    // 4 QUBO variables plus one product variable per path edge.
+    assert_eq!(bundle["target"]["data"]["num_vars"], 7);
+    let names: Vec<&str> = bundle["path"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|step| step["name"].as_str().unwrap())
+        .collect();
+    assert!(names.contains(&"QUBO"), "path: {names:?}");
+
+    std::fs::remove_file(&problem_file).ok();
+    std::fs::remove_file(&path_file).ok();
+}
+
 #[test]
 fn test_reduce_via_infer_target() {
     // --via without --to: target is inferred from the path file
@@ -1365,48 +2098,133 @@ fn test_reduce_missing_to_and_via() {
 }
 
 #[test]
-fn test_create_mis() {
-    let output_file = std::env::temp_dir().join("pred_test_create_mis.json");
-    let output = pred()
+fn test_reduce_export_target_graph() {
+    let problem_file = std::env::temp_dir().join("pred_test_reduce_export_graph.json");
+    let pajek_file = std::env::temp_dir().join("pred_test_reduce_export_graph.net");
+    let csv_file = std::env::temp_dir().join("pred_test_reduce_export_graph.csv");
+    let create_out = pred()
         .args([
             "-o",
-            output_file.to_str().unwrap(),
+            problem_file.to_str().unwrap(),
             "create",
             "MIS",
             "--graph",
-            "0-1,1-2,2-3",
+            "0-1,1-2,0-2",
         ])
         .output()
         .unwrap();
-    assert!(
-        output.status.success(),
-        "stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
-    assert!(output_file.exists());
+    assert!(create_out.status.success());
 
-    let content = std::fs::read_to_string(&output_file).unwrap();
-    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
-    assert_eq!(json["type"], "MaximumIndependentSet");
-    assert!(json["data"].is_object());
+    for graph_file in [&pajek_file, &csv_file] {
+        let output = pred()
+            .args([
+                "reduce",
+                problem_file.to_str().unwrap(),
+                "--to",
+                "MIS/KingsSubgraph",
+                "--export-target-graph",
+                graph_file.to_str().unwrap(),
+            ])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
 
-    std::fs::remove_file(&output_file).ok();
+    let pajek = std::fs::read_to_string(&pajek_file).unwrap();
+    assert!(pajek.starts_with("*Vertices 15\n1 \"0\" 2 6 weight 1\n"));
+    assert!(pajek.contains("*Edges\n1 2\n"));
+    let csv = std::fs::read_to_string(&csv_file).unwrap();
+    assert!(csv.starts_with("source,target\n0,1\n"));
+    assert_eq!(csv.lines().count(), 18);
+
+    std::fs::remove_file(&problem_file).ok();
+    std::fs::remove_file(&pajek_file).ok();
+    std::fs::remove_file(&csv_file).ok();
 }
 
 #[test]
-fn test_create_multiprocessor_scheduling() {
-    let output_file = std::env::temp_dir().join("pred_test_create_multiprocessor_scheduling.json");
-    let output = pred()
+fn test_reduce_export_target_graph_non_graph_target() {
+    let problem_file = std::env::temp_dir().join("pred_test_reduce_export_qubo.json");
+    let graph_file = std::env::temp_dir().join("pred_test_reduce_export_qubo.net");
+    let create_out = pred()
         .args([
             "-o",
-            output_file.to_str().unwrap(),
+            problem_file.to_str().unwrap(),
             "create",
-            "MultiprocessorScheduling",
-            "--lengths",
-            "4,5,3,2,6",
-            "--num-processors",
-            "2",
-            "--deadline",
+            "MIS",
+            "--graph",
+            "0-1",
+        ])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    let output = pred()
+        .args([
+            "reduce",
+            problem_file.to_str().unwrap(),
+            "--to",
+            "QUBO",
+            "--export-target-graph",
+            graph_file.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not a graph problem"), "stderr: {stderr}");
+    assert!(!graph_file.exists());
+
+    std::fs::remove_file(&problem_file).ok();
+}
+
+#[test]
+fn test_create_mis() {
+    let output_file = std::env::temp_dir().join("pred_test_create_mis.json");
+    let output = pred()
+        .args([
+            "-o",
+            output_file.to_str().unwrap(),
+            "create",
+            "MIS",
+            "--graph",
+            "0-1,1-2,2-3",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output_file.exists());
+
+    let content = std::fs::read_to_string(&output_file).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(json["type"], "MaximumIndependentSet");
+    assert!(json["data"].is_object());
+
+    std::fs::remove_file(&output_file).ok();
+}
+
+#[test]
+fn test_create_multiprocessor_scheduling() {
+    let output_file = std::env::temp_dir().join("pred_test_create_multiprocessor_scheduling.json");
+    let output = pred()
+        .args([
+            "-o",
+            output_file.to_str().unwrap(),
+            "create",
+            "MultiprocessorScheduling",
+            "--lengths",
+            "4,5,3,2,6",
+            "--num-processors",
+            "2",
+            "--deadline",
             "10",
         ])
         .output()
@@ -2256,6 +3074,41 @@ fn test_create_sat() {
     std::fs::remove_file(&output_file).ok();
 }
 
+#[test]
+fn test_create_xor_sat() {
+    let output_file = std::env::temp_dir().join("pred_test_create_xor_sat.json");
+    let output = pred()
+        .args([
+            "-o",
+            output_file.to_str().unwrap(),
+            "create",
+            "XORSAT",
+            "--num-vars",
+            "3",
+            "--clauses",
+            "3,-1",
+            "--xor-clauses",
+            "1,2,3;1,-2",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let content = std::fs::read_to_string(&output_file).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(json["type"], "XorSatisfiability");
+    assert_eq!(
+        json["data"]["xor_clauses"][1]["literals"],
+        serde_json::json!([1, -2])
+    );
+
+    std::fs::remove_file(&output_file).ok();
+}
+
 #[test]
 fn test_create_multiple_choice_branching() {
     let output_file = std::env::temp_dir().join("pred_test_create_mcb.json");
@@ -2871,6 +3724,153 @@ fn test_solve_json_output() {
     std::fs::remove_file(&result_file).ok();
 }
 
+#[test]
+fn test_solve_count_sat() {
+    let problem_file = std::env::temp_dir().join("pred_test_solve_count_sat.json");
+    let create_out = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "SAT",
+            "--num-vars",
+            "5",
+            "--clauses",
+            "1,2;3,-4",
+        ])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    let output = pred()
+        .args(["solve", problem_file.to_str().unwrap(), "--count", "--json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["problem"], "Satisfiability");
+    // Independent clauses multiply (3 * 3), doubled by the free x5.
+    assert_eq!(json["count"], 18);
+
+    std::fs::remove_file(&problem_file).ok();
+}
+
+#[test]
+fn test_solve_count_rejects_too_many_variables() {
+    let problem_file = std::env::temp_dir().join("pred_test_solve_count_64_vars.json");
+    let create_out = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "SAT",
+            "--num-vars",
+            "64",
+            "--clauses",
+            "1,2",
+        ])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    let output = pred()
+        .args(["solve", problem_file.to_str().unwrap(), "--count"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("at most 30 variables"), "stderr: {stderr}");
+    assert!(!stderr.contains("panicked"), "stderr: {stderr}");
+
+    std::fs::remove_file(&problem_file).ok();
+}
+
+#[test]
+fn test_solve_count_rejects_non_cnf_problem() {
+    let problem_file = std::env::temp_dir().join("pred_test_solve_count_mis.json");
+    let create_out = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "MIS",
+            "--graph",
+            "0-1,1-2",
+        ])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    let output = pred()
+        .args(["solve", problem_file.to_str().unwrap(), "--count"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--count supports Satisfiability and ModelCounting"),
+        "stderr: {stderr}"
+    );
+
+    std::fs::remove_file(&problem_file).ok();
+}
+
+#[test]
+fn test_solve_telemetry_reports_brute_force_evaluations() {
+    let problem_file = std::env::temp_dir().join("pred_test_solve_telemetry_mis.json");
+    let create_out = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "MIS",
+            "--graph",
+            "0-1,1-2,2-3,3-4",
+        ])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    let output = pred()
+        .args([
+            "solve",
+            problem_file.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+            "--telemetry",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["evaluation"], "Max(3)");
+    assert_eq!(json["telemetry"]["evaluations"], 32);
+    assert_eq!(json["telemetry"]["pruned"], 0);
+    assert!(json["telemetry"]["wall_time_ms"].as_f64().unwrap() >= 0.0);
+
+    let output = pred()
+        .args(["solve", problem_file.to_str().unwrap(), "--telemetry"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--telemetry is only available with --solver brute-force"),
+        "stderr: {stderr}"
+    );
+
+    std::fs::remove_file(&problem_file).ok();
+}
+
 #[test]
 fn test_solve_bundle() {
     // Create → Reduce → Solve bundle
@@ -3145,16 +4145,18 @@ fn test_create_maxcut() {
 }
 
 #[test]
-fn test_create_mvc() {
-    let output_file = std::env::temp_dir().join("pred_test_create_mvc.json");
+fn test_create_and_solve_max_bisection() {
+    let output_file = std::env::temp_dir().join("pred_test_create_max_bisection.json");
     let output = pred()
         .args([
             "-o",
             output_file.to_str().unwrap(),
             "create",
-            "MVC",
+            "MaxBisection",
             "--graph",
-            "0-1,1-2",
+            "0-1,0-2,0-3,1-2,1-3,2-3",
+            "--edge-weights",
+            "5,5,5,1,1,1",
         ])
         .output()
         .unwrap();
@@ -3165,23 +4167,19 @@ fn test_create_mvc() {
     );
     let content = std::fs::read_to_string(&output_file).unwrap();
     let json: serde_json::Value = serde_json::from_str(&content).unwrap();
-    assert_eq!(json["type"], "MinimumVertexCover");
-    std::fs::remove_file(&output_file).ok();
-}
+    assert_eq!(json["type"], "MaxBisection");
+    assert_eq!(
+        json["data"]["edge_weights"],
+        serde_json::json!([5, 5, 5, 1, 1, 1])
+    );
 
-#[test]
-fn test_create_kcoloring() {
-    let output_file = std::env::temp_dir().join("pred_test_create_kcol.json");
     let output = pred()
         .args([
-            "-o",
+            "solve",
             output_file.to_str().unwrap(),
-            "create",
-            "KColoring",
-            "--graph",
-            "0-1,1-2,2-0",
-            "--k",
-            "3",
+            "--solver",
+            "brute-force",
+            "--json",
         ])
         .output()
         .unwrap();
@@ -3190,29 +4188,24 @@ fn test_create_kcoloring() {
         "stderr: {}",
         String::from_utf8_lossy(&output.stderr)
     );
-    let content = std::fs::read_to_string(&output_file).unwrap();
-    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
-    assert_eq!(json["type"], "KColoring");
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["evaluation"], "Max(12)");
     std::fs::remove_file(&output_file).ok();
 }
 
 #[test]
-fn test_create_bounded_component_spanning_forest() {
-    let output_file = std::env::temp_dir().join("pred_test_create_bcsf.json");
+fn test_create_and_solve_minimum_edge_dominating_set() {
+    let output_file = std::env::temp_dir().join("pred_test_create_meds.json");
     let output = pred()
         .args([
             "-o",
             output_file.to_str().unwrap(),
             "create",
-            "BoundedComponentSpanningForest",
+            "EDS",
             "--graph",
-            "0-1,1-2,2-3,3-4,4-5,5-6,6-7,0-7,1-5,2-6",
-            "--weights",
-            "2,3,1,2,3,1,2,1",
-            "--k",
-            "3",
-            "--bound",
-            "6",
+            "0-1,1-2,2-3,3-4,4-0",
+            "--edge-weights",
+            "1,1,1,1,1",
         ])
         .output()
         .unwrap();
@@ -3223,14 +4216,226 @@ fn test_create_bounded_component_spanning_forest() {
     );
     let content = std::fs::read_to_string(&output_file).unwrap();
     let json: serde_json::Value = serde_json::from_str(&content).unwrap();
-    assert_eq!(json["type"], "BoundedComponentSpanningForest");
-    assert_eq!(json["data"]["max_components"], 3);
-    assert_eq!(json["data"]["max_weight"], 6);
-    std::fs::remove_file(&output_file).ok();
-}
+    assert_eq!(json["type"], "MinimumEdgeDominatingSet");
 
-#[test]
-fn test_create_bounded_component_spanning_forest_rejects_zero_k() {
+    let output = pred()
+        .args([
+            "solve",
+            output_file.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["evaluation"], "Min(2)");
+    std::fs::remove_file(&output_file).ok();
+}
+
+#[test]
+fn test_create_and_solve_minimum_multiprocessor_scheduling() {
+    let output_file = std::env::temp_dir().join("pred_test_create_pcmax.json");
+    let output = pred()
+        .args([
+            "-o",
+            output_file.to_str().unwrap(),
+            "create",
+            "PCmax",
+            "--lengths",
+            "3,3,2,2,2",
+            "--num-processors",
+            "2",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let content = std::fs::read_to_string(&output_file).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(json["type"], "MinimumMultiprocessorScheduling");
+
+    let output = pred()
+        .args([
+            "solve",
+            output_file.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["evaluation"], "Min(6)");
+    std::fs::remove_file(&output_file).ok();
+}
+
+#[test]
+fn test_pseudo_boolean_example_reduces_to_qubo() {
+    let problem_file = std::env::temp_dir().join("pred_test_pbo_example.json");
+    let bundle_file = std::env::temp_dir().join("pred_test_pbo_bundle.json");
+    let output = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "--example",
+            "PBO",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = pred()
+        .args([
+            "-o",
+            bundle_file.to_str().unwrap(),
+            "reduce",
+            problem_file.to_str().unwrap(),
+            "--to",
+            "QUBO",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = pred()
+        .args([
+            "solve",
+            bundle_file.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["evaluation"], "Min(4)");
+    std::fs::remove_file(&problem_file).ok();
+    std::fs::remove_file(&bundle_file).ok();
+}
+
+#[test]
+fn test_create_pseudo_boolean_points_to_opb() {
+    let output = pred().args(["create", "PBO"]).output().unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("from_opb"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_create_mvc() {
+    let output_file = std::env::temp_dir().join("pred_test_create_mvc.json");
+    let output = pred()
+        .args([
+            "-o",
+            output_file.to_str().unwrap(),
+            "create",
+            "MVC",
+            "--graph",
+            "0-1,1-2",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let content = std::fs::read_to_string(&output_file).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(json["type"], "MinimumVertexCover");
+    std::fs::remove_file(&output_file).ok();
+}
+
+#[test]
+fn test_create_kcoloring() {
+    let output_file = std::env::temp_dir().join("pred_test_create_kcol.json");
+    let output = pred()
+        .args([
+            "-o",
+            output_file.to_str().unwrap(),
+            "create",
+            "KColoring",
+            "--graph",
+            "0-1,1-2,2-0",
+            "--k",
+            "3",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let content = std::fs::read_to_string(&output_file).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(json["type"], "KColoring");
+    std::fs::remove_file(&output_file).ok();
+}
+
+#[test]
+fn test_create_bounded_component_spanning_forest() {
+    let output_file = std::env::temp_dir().join("pred_test_create_bcsf.json");
+    let output = pred()
+        .args([
+            "-o",
+            output_file.to_str().unwrap(),
+            "create",
+            "BoundedComponentSpanningForest",
+            "--graph",
+            "0-1,1-2,2-3,3-4,4-5,5-6,6-7,0-7,1-5,2-6",
+            "--weights",
+            "2,3,1,2,3,1,2,1",
+            "--k",
+            "3",
+            "--bound",
+            "6",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let content = std::fs::read_to_string(&output_file).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(json["type"], "BoundedComponentSpanningForest");
+    assert_eq!(json["data"]["max_components"], 3);
+    assert_eq!(json["data"]["max_weight"], 6);
+    std::fs::remove_file(&output_file).ok();
+}
+
+#[test]
+fn test_create_bounded_component_spanning_forest_rejects_zero_k() {
     let output = pred()
         .args([
             "create",
@@ -4228,6 +5433,32 @@ fn test_create_lcs_with_raw_strings_infers_alphabet() {
     );
 }
 
+#[test]
+fn test_create_shortest_common_superstring_infers_alphabet() {
+    let output = pred()
+        .args([
+            "create",
+            "ShortestCommonSuperstring",
+            "--strings",
+            "CATG;ATGC;TGCA",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["type"], "ShortestCommonSuperstring");
+    assert_eq!(json["data"]["alphabet_size"], 4);
+    assert_eq!(
+        json["data"]["strings"],
+        serde_json::json!([[0, 1, 2, 3], [1, 2, 3, 0], [2, 3, 0, 1]])
+    );
+}
+
 #[test]
 fn test_create_lcs_rejects_empty_strings_without_panicking() {
     let output = pred()
@@ -4980,31 +6211,189 @@ fn test_show_json_output() {
 }
 
 #[test]
-fn test_show_size_fields() {
-    let output = pred().args(["show", "MIS"]).output().unwrap();
-    assert!(output.status.success());
+fn test_show_example_is_solvable() {
+    for problem in ["MIS", "SAT", "QUBO"] {
+        let tmp = std::env::temp_dir().join(format!("pred_test_show_example_{problem}.json"));
+        let output = pred()
+            .args(["-o", tmp.to_str().unwrap(), "show", problem, "--example"])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&tmp).unwrap()).unwrap();
+        assert!(json["type"].is_string());
+        assert!(json["data"].is_object());
+
+        let solve = pred()
+            .args(["solve", tmp.to_str().unwrap(), "--solver", "brute-force"])
+            .output()
+            .unwrap();
+        assert!(
+            solve.status.success(),
+            "{problem}: {}",
+            String::from_utf8_lossy(&solve.stderr)
+        );
+        std::fs::remove_file(&tmp).ok();
+    }
+}
+
+#[test]
+fn test_show_example_prints_solved_instance() {
+    let output = pred().args(["show", "MIS", "--example"]).output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("Size fields"));
+    assert!(stdout.contains("Instance:"), "stdout: {stdout}");
+    assert!(stdout.contains("graph: "), "stdout: {stdout}");
+    assert!(
+        stdout.contains("King's subgraph embedding:"),
+        "stdout: {stdout}"
+    );
+
+    // The built-in MIS example is the Petersen graph, whose optimum is 4
+    let solution = stdout
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Solution: "))
+        .expect("missing solution line");
+    let selected = solution
+        .trim_matches(|c| c == '[' || c == ']')
+        .split(", ")
+        .filter(|v| *v == "1")
+        .count();
+    assert_eq!(selected, 4, "stdout: {stdout}");
+    assert!(stdout.contains("Evaluation: Max(4)"), "stdout: {stdout}");
 }
 
 #[test]
-fn test_reduce_unknown_target() {
-    let problem_file = std::env::temp_dir().join("pred_test_reduce_unknown.json");
-    let create_out = pred()
-        .args([
-            "-o",
-            problem_file.to_str().unwrap(),
-            "create",
-            "MIS",
-            "--graph",
-            "0-1",
-        ])
+fn test_show_example_json_includes_optimum() {
+    let output = pred()
+        .args(["show", "SAT", "--example", "--json"])
         .output()
         .unwrap();
-    assert!(create_out.status.success());
-
-    let output = pred()
-        .args([
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["type"], "Satisfiability");
+    assert_eq!(json["evaluation"], "Or(true)");
+    assert_eq!(json["solver"], "brute force");
+    assert!(json["solution"].is_array());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("King's subgraph"));
+}
+
+#[test]
+fn test_show_example_uses_recorded_optimum_for_large_search_spaces() {
+    let output = pred()
+        .args(["show", "TimetableDesign", "--example", "--json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["solver"], "example database");
+    assert!(json["solution"].is_array());
+}
+
+#[test]
+fn test_show_example_falls_back_to_another_variant() {
+    let output = pred()
+        .args([
+            "show",
+            "MaximumClique/SimpleGraph/One",
+            "--example",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("No example for MaximumClique/SimpleGraph/One"));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["type"], "MaximumClique");
+    assert_eq!(json["variant"]["weight"], "i32");
+}
+
+#[test]
+fn test_show_example_loads_for_every_problem() {
+    // Every registered problem must provide an example that `pred` can load;
+    // `inspect` goes through the same loader as `solve` without running a solver.
+    let list = pred().args(["list", "--json"]).output().unwrap();
+    assert!(list.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&list.stdout).unwrap();
+    let names: std::collections::BTreeSet<String> = json["variants"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| {
+            v["name"]
+                .as_str()
+                .unwrap()
+                .split('/')
+                .next()
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+    assert!(!names.is_empty());
+
+    let tmp = std::env::temp_dir().join("pred_test_show_example_every_problem.json");
+    for name in &names {
+        let output = pred()
+            .args(["-q", "-o", tmp.to_str().unwrap(), "show", name, "--example"])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{name}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let inspect = pred()
+            .args(["inspect", tmp.to_str().unwrap()])
+            .output()
+            .unwrap();
+        assert!(
+            inspect.status.success(),
+            "{name}: {}",
+            String::from_utf8_lossy(&inspect.stderr)
+        );
+    }
+    std::fs::remove_file(&tmp).ok();
+}
+
+#[test]
+fn test_show_size_fields() {
+    let output = pred().args(["show", "MIS"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Size fields"));
+}
+
+#[test]
+fn test_reduce_unknown_target() {
+    let problem_file = std::env::temp_dir().join("pred_test_reduce_unknown.json");
+    let create_out = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "MIS",
+            "--graph",
+            "0-1",
+        ])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    let output = pred()
+        .args([
             "reduce",
             problem_file.to_str().unwrap(),
             "--to",
@@ -5746,6 +7135,242 @@ fn test_inspect_problem() {
     std::fs::remove_file(&problem_file).ok();
 }
 
+#[test]
+fn test_stats_petersen() {
+    let problem_file = std::env::temp_dir().join("pred_test_stats_petersen.json");
+    let create_out = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "MIS",
+            "--graph",
+            "0-1,0-4,0-5,1-2,1-6,2-3,2-7,3-4,3-8,4-9,5-7,5-8,6-8,6-9,7-9",
+        ])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    let output = pred()
+        .args(["stats", problem_file.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["type"], "MaximumIndependentSet");
+    assert_eq!(json["num_variables"], 10);
+    assert_eq!(json["domain_sizes"]["2"], 10);
+    let graph = &json["graph"];
+    assert_eq!(graph["num_edges"], 15);
+    assert!((graph["density"].as_f64().unwrap() - 1.0 / 3.0).abs() < 1e-9);
+    assert_eq!(graph["min_degree"], 3);
+    assert_eq!(graph["max_degree"], 3);
+    assert_eq!(graph["num_components"], 1);
+    assert_eq!(graph["pathwidth_upper_bound"], 5);
+
+    std::fs::remove_file(&problem_file).ok();
+}
+
+#[test]
+fn test_stats_non_graph_problem_omits_graph() {
+    let output = pred()
+        .args(["create", "SAT", "--num-vars", "3", "--clauses", "1,2;-1,3"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let problem_file = std::env::temp_dir().join("pred_test_stats_sat.json");
+    std::fs::write(&problem_file, &output.stdout).unwrap();
+
+    let output = pred()
+        .args(["stats", problem_file.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["num_variables"], 3);
+    assert!(json.get("graph").is_none());
+
+    std::fs::remove_file(&problem_file).ok();
+}
+
+#[test]
+fn test_audit_mis_to_mvc() {
+    let output = pred()
+        .args([
+            "audit", "MIS", "MVC", "--sizes", "4,6,8", "--seed", "1", "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["source_name"], "MaximumIndependentSet");
+    assert_eq!(json["target_name"], "MinimumVertexCover");
+    assert_eq!(json["samples"].as_array().unwrap().len(), 3);
+    for field in json["fields"].as_array().unwrap() {
+        assert_eq!(field["exceeded"], false);
+        assert_eq!(field["max_ratio"], 1.0);
+    }
+}
+
+#[test]
+fn test_audit_without_direct_rule_fails() {
+    let output = pred()
+        .args(["audit", "MIS", "QUBO", "--sizes", "4"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No direct reduction"), "stderr: {stderr}");
+}
+
+/// Three tiny instances for the batch tests; `c_broken.json` is malformed.
+fn write_batch_fixtures(dir: &std::path::Path) {
+    let _ = std::fs::remove_dir_all(dir);
+    std::fs::create_dir_all(dir).unwrap();
+    for (name, args) in [
+        ("a_mis.json", ["create", "MIS", "--graph", "0-1,1-2,2-3"]),
+        (
+            "b_maxcut.json",
+            ["create", "MaxCut", "--graph", "0-1,1-2,2-0"],
+        ),
+    ] {
+        let file = dir.join(name);
+        let output = pred()
+            .args(["-o", file.to_str().unwrap()])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    std::fs::write(
+        dir.join("c_broken.json"),
+        r#"{"type": "MaximumIndependentSet", "data": "#,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_batch_solve_reports_failures_without_aborting() {
+    let dir = std::env::temp_dir().join("pred_test_batch_solve");
+    write_batch_fixtures(&dir);
+    let csv = dir.join("results.csv");
+    let out_dir = dir.join("solutions");
+    let pattern = dir.join("*.json");
+
+    let output = pred()
+        .args([
+            "batch",
+            "solve",
+            pattern.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+            "--jobs",
+            "2",
+            "--out",
+            csv.to_str().unwrap(),
+            "--out-dir",
+            out_dir.to_str().unwrap(),
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("1 of 3 instances failed"),
+        "stderr: {stderr}"
+    );
+
+    let summary: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(summary["succeeded"], 2);
+    assert_eq!(summary["failed"], 1);
+    let rows = summary["instances"].as_array().unwrap();
+    assert_eq!(rows[0]["problem"], "MaximumIndependentSet");
+    assert_eq!(rows[0]["n"], 4);
+    assert_eq!(rows[0]["objective"], "Max(2)");
+    assert_eq!(rows[1]["problem"], "MaxCut");
+    assert_eq!(rows[1]["objective"], "Max(2)");
+    assert_eq!(rows[2]["status"], "error");
+    assert!(rows[2]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Failed to parse JSON"));
+
+    let csv = std::fs::read_to_string(&csv).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "file,problem,n,objective,time_ms,status,error");
+    assert_eq!(lines.len(), 4);
+    assert!(lines[1].contains("a_mis.json,MaximumIndependentSet,4,Max(2),"));
+    assert!(lines[3].contains(",error,"));
+
+    assert!(out_dir.join("a_mis.solution.json").exists());
+    assert!(out_dir.join("b_maxcut.solution.json").exists());
+    assert!(!out_dir.join("c_broken.solution.json").exists());
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_batch_reduce_writes_bundles() {
+    let dir = std::env::temp_dir().join("pred_test_batch_reduce");
+    write_batch_fixtures(&dir);
+    let out_dir = dir.join("reduced");
+
+    let output = pred()
+        .args([
+            "batch",
+            "reduce",
+            dir.join("a_mis.json").to_str().unwrap(),
+            dir.join("b_maxcut.json").to_str().unwrap(),
+            dir.join("c_broken.json").to_str().unwrap(),
+            "--to",
+            "QUBO",
+            "--out-dir",
+            out_dir.to_str().unwrap(),
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let summary: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(summary["succeeded"], 2);
+    assert_eq!(summary["failed"], 1);
+
+    let bundle = std::fs::read_to_string(out_dir.join("a_mis.reduced.json")).unwrap();
+    let bundle: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+    assert_eq!(bundle["source"]["type"], "MaximumIndependentSet");
+    assert_eq!(bundle["target"]["type"], "QUBO");
+    assert!(out_dir.join("b_maxcut.reduced.json").exists());
+    assert!(!out_dir.join("c_broken.reduced.json").exists());
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_batch_unmatched_pattern_fails() {
+    let pattern = std::env::temp_dir().join("pred_test_batch_nothing_here_*.json");
+    let output = pred()
+        .args(["batch", "solve", pattern.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Pattern matched no files"),
+        "stderr: {stderr}"
+    );
+}
+
 #[test]
 fn test_inspect_minmaxmulticenter_lists_ilp_and_bruteforce() {
     let problem_file = std::env::temp_dir().join("pred_test_inspect_minmaxmulticenter.json");
@@ -6596,6 +8221,66 @@ fn test_create_factoring_with_bits() {
     std::fs::remove_file(&output_file).ok();
 }
 
+#[test]
+fn test_create_factoring_with_exact_factor_bits() {
+    let output = pred()
+        .args([
+            "create",
+            "Factoring",
+            "--target",
+            "3233",
+            "--p-bits",
+            "6",
+            "--q-bits",
+            "6",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    // The widths double as the bit budgets when --m/--n are omitted.
+    assert_eq!(json["data"]["m"], 6);
+    assert_eq!(json["data"]["n"], 6);
+    assert_eq!(json["data"]["p_bits"], 6);
+    assert_eq!(json["data"]["q_bits"], 6);
+
+    use std::io::Write;
+    let mut child = pred()
+        .args(["solve", "-", "--solver", "brute-force"])
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(&output.stdout)
+        .unwrap();
+    let solve = child.wait_with_output().unwrap();
+    assert!(
+        solve.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&solve.stderr)
+    );
+    let result: serde_json::Value = serde_json::from_slice(&solve.stdout).unwrap();
+    let bits: Vec<u64> = result["solution"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b.as_u64().unwrap())
+        .collect();
+    let value = |bits: &[u64]| bits.iter().rev().fold(0, |acc, &b| 2 * acc + b);
+    let (p, q) = (value(&bits[..6]), value(&bits[6..]));
+    assert_eq!(p * q, 3233);
+    assert!(p == 53 || p == 61, "p = {p}");
+}
+
 #[test]
 fn test_create_factoring_no_flags_shows_help() {
     // pred create Factoring with no data flags shows schema-driven help and exits non-zero
@@ -7295,12 +8980,34 @@ fn test_create_mis_unit_disk_graph() {
 }
 
 #[test]
-fn test_create_mvc_kings_subgraph_unsupported_variant() {
-    // MVC doesn't have a KingsSubgraph variant registered
+fn test_create_mvc_kings_subgraph() {
     let output = pred()
         .args(["create", "MVC/KingsSubgraph", "--positions", "0,0;1,0;1,1"])
         .output()
         .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["type"], "MinimumVertexCover");
+    assert_eq!(json["variant"]["graph"], "KingsSubgraph");
+    assert_eq!(json["variant"]["weight"], "i32");
+}
+
+#[test]
+fn test_create_kings_subgraph_unsupported_variant() {
+    // MaximumClique doesn't have a KingsSubgraph variant registered
+    let output = pred()
+        .args([
+            "create",
+            "MaximumClique/KingsSubgraph",
+            "--positions",
+            "0,0;1,0;1,1",
+        ])
+        .output()
+        .unwrap();
     assert!(!output.status.success());
     let stderr = String::from_utf8(output.stderr).unwrap();
     assert!(
@@ -9206,3 +10913,591 @@ fn test_extract_reads_bundle_from_stdin() {
     std::fs::remove_file(&problem_file).ok();
     std::fs::remove_file(&bundle_file).ok();
 }
+
+#[test]
+fn test_create_poly_spin_glass_from_hyperedges() {
+    let output = pred()
+        .args([
+            "create",
+            "PolySpinGlass",
+            "--hyperedges",
+            "0,1,2;2,3",
+            "--couplings",
+            "1,-1",
+            "--num-vertices",
+            "5",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["type"], "PolySpinGlass");
+    assert_eq!(json["variant"]["weight"], "i32");
+    assert_eq!(json["data"]["graph"]["num_vertices"], 5);
+    assert_eq!(
+        json["data"]["graph"]["edges"],
+        serde_json::json!([[0, 1, 2], [2, 3]])
+    );
+    assert_eq!(json["data"]["couplings"], serde_json::json!([1, -1]));
+    assert_eq!(json["data"]["fields"], serde_json::json!([0, 0, 0, 0, 0]));
+}
+
+#[test]
+fn test_create_poly_spin_glass_rejects_too_few_vertices() {
+    let output = pred()
+        .args([
+            "create",
+            "PolySpinGlass",
+            "--hyperedges",
+            "0,1,4",
+            "--num-vertices",
+            "3",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("references vertex 4"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_create_mvc_hypergraph_from_hyperedges() {
+    let output = pred()
+        .args([
+            "create",
+            "MVC/HyperGraph",
+            "--hyperedges",
+            "0,1,2;2,3",
+            "--weights",
+            "1,2,1,1",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["type"], "MinimumVertexCover");
+    assert_eq!(json["variant"]["graph"], "HyperGraph");
+    assert_eq!(json["data"]["graph"]["num_vertices"], 4);
+    assert_eq!(
+        json["data"]["graph"]["edges"],
+        serde_json::json!([[0, 1, 2], [2, 3]])
+    );
+    assert_eq!(json["data"]["weights"], serde_json::json!([1, 2, 1, 1]));
+}
+
+#[test]
+fn test_create_mvc_hypergraph_rejects_weight_count_mismatch() {
+    let output = pred()
+        .args([
+            "create",
+            "MVC/HyperGraph",
+            "--hyperedges",
+            "0,1,2;2,3",
+            "--weights",
+            "1,2",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Expected 4 weights"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_create_rectangle_packing() {
+    let output = pred()
+        .args([
+            "create",
+            "RectanglePacking",
+            "--rectangles",
+            "1x2,2x1",
+            "--container-width",
+            "3",
+            "--container-height",
+            "2",
+            "--allow-rotation",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["type"], "RectanglePacking");
+    assert_eq!(
+        json["data"]["rectangles"],
+        serde_json::json!([[1, 2], [2, 1]])
+    );
+    assert_eq!(json["data"]["container_width"], 3);
+    assert_eq!(json["data"]["container_height"], 2);
+    assert_eq!(json["data"]["allow_rotation"], true);
+}
+
+#[test]
+fn test_create_rectangle_packing_rejects_oversized_rectangle() {
+    let output = pred()
+        .args([
+            "create",
+            "RectanglePacking",
+            "--rectangles",
+            "3x1",
+            "--container-width",
+            "2",
+            "--container-height",
+            "2",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("does not fit"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_schema_single_problem() {
+    let output = pred().args(["schema", "MIS"]).output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        schema["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
+    assert_eq!(schema["title"], "Maximum Independent Set");
+    // A bare name accepts every registered variant.
+    assert!(schema["anyOf"].as_array().unwrap().len() > 1);
+
+    let output = pred()
+        .args(["schema", "MIS/SimpleGraph/i32"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let data = &schema["properties"]["data"];
+    assert_eq!(data["properties"]["weights"]["items"]["type"], "integer");
+    assert_eq!(data["required"], serde_json::json!(["graph", "weights"]));
+}
+
+#[test]
+fn test_schema_unknown_problem() {
+    let output = pred().args(["schema", "NoSuchProblem"]).output().unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_schema_all_writes_directory() {
+    let dir = std::env::temp_dir().join("pred_test_schema_all");
+    std::fs::remove_dir_all(&dir).ok();
+    let output = pred()
+        .args(["schema", "--all", "-o", dir.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let mis = std::fs::read_to_string(dir.join("MaximumIndependentSet.schema.json")).unwrap();
+    let schema: serde_json::Value = serde_json::from_str(&mis).unwrap();
+    assert_eq!(schema["title"], "Maximum Independent Set");
+    let count = std::fs::read_dir(&dir).unwrap().count();
+    assert!(count > 100, "expected one schema per problem, got {count}");
+
+    let output = pred().args(["schema", "--all"]).output().unwrap();
+    assert!(!output.status.success());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_create_paintshop_and_subgraph_isomorphism() {
+    let output = pred()
+        .args(["create", "PaintShop", "--sequence", "a,b,a,c,c,b"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["data"]["num_cars"], 3);
+
+    let output = pred()
+        .args([
+            "create",
+            "SubgraphIsomorphism",
+            "--graph",
+            "0-1,1-2,2-0",
+            "--pattern",
+            "0-1",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["data"]["pattern_graph"]["num_vertices"], 2);
+}
+
+#[test]
+fn test_create_csp_from_table_constraints() {
+    let output = pred()
+        .args([
+            "create",
+            "CSP",
+            "--domains",
+            "2,2,3",
+            "--constraints",
+            "0,1:0,1|1,0;1,2:0,1|0,2|1,0|1,2;2:2",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["type"], "CSP");
+    assert_eq!(json["data"]["domains"], serde_json::json!([2, 2, 3]));
+    assert_eq!(
+        json["data"]["constraints"][2],
+        serde_json::json!({"scope": [2], "allowed": [[2]]})
+    );
+
+    let bad = pred()
+        .args([
+            "create",
+            "CSP",
+            "--domains",
+            "2,2",
+            "--constraints",
+            "0,1:0",
+        ])
+        .output()
+        .unwrap();
+    assert!(!bad.status.success());
+    assert!(String::from_utf8_lossy(&bad.stderr).contains("does not match scope"));
+}
+
+#[test]
+fn test_create_arc_weighted_problems_default_one_weight_per_arc() {
+    for problem in ["MAS", "FAS"] {
+        let output = pred()
+            .args(["create", problem, "--arcs", "0>1,1>2,2>0,1>3,3>0,2>3"])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(
+            json["data"]["weights"],
+            serde_json::json!([1, 1, 1, 1, 1, 1])
+        );
+    }
+}
+
+#[test]
+fn test_bench_emits_timing_json() {
+    let problem_file = std::env::temp_dir().join("pred_test_bench_mis.json");
+    let csv_file = std::env::temp_dir().join("pred_test_bench_mis.csv");
+    let create_out = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "MIS",
+            "--graph",
+            "0-1,1-2,2-3",
+        ])
+        .output()
+        .unwrap();
+    assert!(create_out.status.success());
+
+    let output = pred()
+        .args([
+            "bench",
+            problem_file.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+            "--repeat",
+            "3",
+            "--out",
+            csv_file.to_str().unwrap(),
+            "--json",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["problem"], "MaximumIndependentSet");
+    assert_eq!(json["solver"], "brute-force");
+    assert_eq!(json["objective"], "Max(2)");
+    assert_eq!(json["runs"], 3);
+    assert_eq!(json["times_ms"].as_array().unwrap().len(), 3);
+    let min = json["min_ms"].as_f64().unwrap();
+    let median = json["median_ms"].as_f64().unwrap();
+    let mean = json["mean_ms"].as_f64().unwrap();
+    assert!(0.0 <= min && min <= median && min <= mean);
+
+    let csv = std::fs::read_to_string(&csv_file).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "problem,solver,objective,runs,min_ms,median_ms,mean_ms"
+    );
+    assert!(lines[1].starts_with("MaximumIndependentSet,brute-force,Max(2),3,"));
+
+    std::fs::remove_file(&problem_file).ok();
+    std::fs::remove_file(&csv_file).ok();
+}
+
+#[test]
+fn test_bench_rejects_unknown_solver_and_zero_repeat() {
+    let output = pred()
+        .args(["bench", "missing.json", "--solver", "annealing"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown solver"));
+
+    let output = pred()
+        .args(["bench", "missing.json", "--repeat", "0"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+// ---- Exit-code contract and stream separation ----
+
+/// Parse stdout as exactly one JSON document.
+fn single_json_document(stdout: &[u8]) -> serde_json::Value {
+    let text = String::from_utf8_lossy(stdout);
+    let mut stream = serde_json::Deserializer::from_str(&text).into_iter::<serde_json::Value>();
+    let doc = stream
+        .next()
+        .unwrap_or_else(|| panic!("no JSON document on stdout: {text}"))
+        .unwrap_or_else(|e| panic!("stdout is not JSON ({e}): {text}"));
+    assert!(
+        stream.next().is_none(),
+        "more than one JSON document: {text}"
+    );
+    doc
+}
+
+#[test]
+fn test_exit_code_success_json_keeps_stderr_clean() {
+    let problem_file = std::env::temp_dir().join("pred_test_exit_success.json");
+    let create = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "MIS",
+            "--graph",
+            "0-1,1-2",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(create.status.code(), Some(0));
+
+    let output = pred()
+        .args([
+            "--json",
+            "-q",
+            "solve",
+            problem_file.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+        ])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&problem_file).ok();
+
+    assert_eq!(output.status.code(), Some(0));
+    let json = single_json_document(&output.stdout);
+    assert_eq!(json["evaluation"], "Max(2)");
+    assert!(
+        output.stderr.is_empty(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_exit_code_no_path() {
+    let output = pred()
+        .args(["--json", "path", "MIS", "Factoring"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+
+    let json = single_json_document(&output.stdout);
+    assert_eq!(json["error"]["kind"], "no_result");
+    assert_eq!(json["error"]["code"], 2);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No reduction path"), "stderr: {stderr}");
+
+    // Human mode: same code, nothing on stdout
+    let output = pred().args(["path", "MIS", "Factoring"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn test_exit_code_infeasible_solve_still_prints_result() {
+    let problem_file = std::env::temp_dir().join("pred_test_exit_infeasible.json");
+    let create = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "SAT",
+            "--num-vars",
+            "1",
+            "--clauses",
+            "1;-1",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(create.status.code(), Some(0));
+
+    let output = pred()
+        .args([
+            "--json",
+            "solve",
+            problem_file.to_str().unwrap(),
+            "--solver",
+            "brute-force",
+        ])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&problem_file).ok();
+
+    assert_eq!(output.status.code(), Some(2));
+    let json = single_json_document(&output.stdout);
+    assert_eq!(json["evaluation"], "Or(false)");
+    assert!(json.get("error").is_none(), "{json}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("no feasible solution"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_exit_code_malformed_file() {
+    let problem_file = std::env::temp_dir().join("pred_test_exit_malformed.json");
+    std::fs::write(&problem_file, "{ not json").unwrap();
+
+    let output = pred()
+        .args(["--json", "solve", problem_file.to_str().unwrap()])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&problem_file).ok();
+
+    assert_eq!(output.status.code(), Some(3));
+    let json = single_json_document(&output.stdout);
+    assert_eq!(json["error"]["kind"], "invalid_input");
+    let message = json["error"]["message"].as_str().unwrap();
+    assert!(message.contains("Failed to parse JSON"), "{message}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Failed to parse JSON"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_exit_code_missing_file_and_bad_flags() {
+    let output = pred()
+        .args(["evaluate", "pred_test_exit_missing.json", "--config", "1"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    assert!(output.stdout.is_empty());
+
+    let output = pred()
+        .args(["--json", "list", "--no-such-flag"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    let json = single_json_document(&output.stdout);
+    assert_eq!(json["error"]["kind"], "invalid_input");
+}
+
+#[test]
+fn test_exit_code_internal_error_on_panic() {
+    // Set multicover is rejected by a panic inside the reduction
+    let problem_file = std::env::temp_dir().join("pred_test_exit_panic.json");
+    let create = pred()
+        .args([
+            "-o",
+            problem_file.to_str().unwrap(),
+            "create",
+            "MinimumSetCovering",
+            "--universe-size",
+            "2",
+            "--subsets",
+            "0,1;1",
+            "--weights",
+            "1,1",
+            "--requirements",
+            "1,2",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(create.status.code(), Some(0));
+
+    let output = pred()
+        .args([
+            "--json",
+            "reduce",
+            problem_file.to_str().unwrap(),
+            "--to",
+            "MVC/HyperGraph",
+        ])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&problem_file).ok();
+
+    assert_eq!(output.status.code(), Some(4));
+    let json = single_json_document(&output.stdout);
+    assert_eq!(json["error"]["kind"], "internal");
+    let message = json["error"]["message"].as_str().unwrap();
+    assert!(message.contains("set multicover"), "{message}");
+}
+
+#[test]
+fn test_create_without_data_is_invalid_input() {
+    let output = pred().args(["--json", "create", "MVC"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    let json = single_json_document(&output.stdout);
+    assert_eq!(json["error"]["kind"], "invalid_input");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Parameters:"), "stderr: {stderr}");
+}
@@ -162,6 +162,7 @@ mod mcp_tests {
             "neighbors",
             "find_path",
             "export_graph",
+            "condensed_graph",
             "create_problem",
             "inspect_problem",
             "evaluate",
@@ -660,6 +660,15 @@ fn generate_declare_variants(input: &DeclareVariantsInput) -> syn::Result<TokenS
                 let evaluation = crate::registry::format_metric(&crate::traits::Problem::evaluate(p, &config));
                 Some((config, evaluation))
             },
+            solve_telemetry_fn: |any: &dyn std::any::Any| -> (String, crate::solvers::SolverTelemetry) {
+                let p = any
+                    .downcast_ref::<#ty>()
+                    .expect("type-erased solve_telemetry downcast failed");
+                let solver = crate::solvers::BruteForce::new();
+                let (total, telemetry) =
+                    <crate::solvers::BruteForce as crate::solvers::Solver>::solve_with_telemetry(&solver, p);
+                (crate::registry::format_metric(&total), telemetry)
+            },
         };
 
         output.extend(quote! {
@@ -864,6 +873,10 @@ mod tests {
             tokens.contains("solve_witness_fn :"),
             "expected solve_witness_fn field"
         );
+        assert!(
+            tokens.contains("solve_telemetry_fn :"),
+            "expected solve_telemetry_fn field"
+        );
         assert!(
             !tokens.contains("factory : None"),
             "factory should not be None"
@@ -87,6 +87,104 @@ impl DimsIterator {
     pub fn total(&self) -> usize {
         self.total_configs
     }
+
+    /// Advance `config` to the next configuration in place.
+    ///
+    /// The config is treated as a mixed-radix number whose last entry is the
+    /// lowest digit: that digit is incremented and carries propagate towards
+    /// index 0, so no division or allocation is needed. Returns `false` when
+    /// the config wraps around from the last configuration back to all zeros.
+    pub fn increment_in_place(config: &mut [usize], dims: &[usize]) -> bool {
+        debug_assert_eq!(config.len(), dims.len());
+        for (digit, &dim) in config.iter_mut().zip(dims).rev() {
+            *digit += 1;
+            if *digit < dim {
+                return true;
+            }
+            *digit = 0;
+        }
+        false
+    }
+}
+
+/// Fold over all configurations for `dims`, reusing a single config buffer.
+///
+/// Visits configurations in the same order as [`DimsIterator`], but lends each
+/// one as a slice instead of allocating a fresh `Vec` per configuration.
+pub fn fold_configs<B>(dims: &[usize], init: B, mut f: impl FnMut(B, &[usize]) -> B) -> B {
+    if dims.contains(&0) {
+        return init;
+    }
+    let mut config = vec![0; dims.len()];
+    let mut acc = f(init, &config);
+    while DimsIterator::increment_in_place(&mut config, dims) {
+        acc = f(acc, &config);
+    }
+    acc
+}
+
+/// A binary configuration of at most 64 variables packed into a bitmask.
+///
+/// Variable `i` is set iff bit `i` of the mask is set. Used by
+/// [`BruteForce`](crate::BruteForce) to evaluate dense binary problems without
+/// materializing a `Vec<usize>` per configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BitConfig(pub u64);
+
+impl BitConfig {
+    /// Maximum number of variables a `BitConfig` can hold.
+    pub const MAX_VARIABLES: usize = 64;
+
+    /// Whether variable `i` is set.
+    pub fn get(self, i: usize) -> bool {
+        (self.0 >> i) & 1 == 1
+    }
+
+    /// Pack a binary configuration into a bitmask.
+    pub fn from_config(config: &[usize]) -> Self {
+        debug_assert!(config.len() <= Self::MAX_VARIABLES);
+        Self(
+            config
+                .iter()
+                .enumerate()
+                .filter(|&(_, &value)| value != 0)
+                .fold(0, |mask, (i, _)| mask | (1 << i)),
+        )
+    }
+
+    /// Unpack the first `num_variables` bits into a binary configuration.
+    pub fn to_config(self, num_variables: usize) -> Vec<usize> {
+        (0..num_variables)
+            .map(|i| usize::from(self.get(i)))
+            .collect()
+    }
+}
+
+/// Fold over all binary configurations of `num_variables <= 64` variables.
+///
+/// Visits configurations in the same order as [`fold_configs`] with
+/// `dims = [2; num_variables]`, i.e. the last variable changes fastest.
+pub fn fold_bit_configs<B>(
+    num_variables: usize,
+    init: B,
+    mut f: impl FnMut(B, BitConfig) -> B,
+) -> B {
+    assert!(
+        num_variables <= BitConfig::MAX_VARIABLES,
+        "BitConfig supports at most 64 variables"
+    );
+    if num_variables == 0 {
+        return f(init, BitConfig(0));
+    }
+    // Counting upwards makes the lowest bit of the counter the fastest
+    // digit; reversing maps it to the last variable.
+    let shift = u64::BITS as usize - num_variables;
+    let last = u64::MAX >> shift;
+    let mut acc = init;
+    for counter in 0..=last {
+        acc = f(acc, BitConfig(counter.reverse_bits() >> shift));
+    }
+    acc
 }
 
 impl Iterator for DimsIterator {
@@ -98,17 +196,7 @@ impl Iterator for DimsIterator {
 
         // Advance to next configuration
         let mut next = current;
-        let mut carry = true;
-        for i in (0..self.dims.len()).rev() {
-            if carry {
-                next[i] += 1;
-                if next[i] >= self.dims[i] {
-                    next[i] = 0;
-                } else {
-                    carry = false;
-                }
-            }
-        }
+        Self::increment_in_place(&mut next, &self.dims);
 
         self.current_index += 1;
         if self.current_index < self.total_configs {
@@ -126,6 +214,76 @@ impl Iterator for DimsIterator {
 
 impl ExactSizeIterator for DimsIterator {}
 
+/// Iterator over all permutations of `0..n` in lexicographic order.
+///
+/// Produces `n!` permutations, starting from the identity. For `n = 0`,
+/// produces exactly one permutation (the empty one).
+pub struct PermutationIterator {
+    current: Option<Vec<usize>>,
+    remaining: usize,
+}
+
+impl PermutationIterator {
+    /// Create a new iterator over the permutations of `0..n`.
+    pub fn new(n: usize) -> Self {
+        let remaining = (1..=n)
+            .try_fold(1usize, |acc, k| acc.checked_mul(k))
+            .unwrap_or(usize::MAX);
+        Self {
+            current: Some((0..n).collect()),
+            remaining,
+        }
+    }
+
+    /// Advance `perm` to its lexicographic successor in place.
+    ///
+    /// Returns `false` (leaving `perm` unchanged) when `perm` is already the
+    /// last, descending permutation.
+    pub fn next_in_place(perm: &mut [usize]) -> bool {
+        let Some(pivot) = (1..perm.len()).rev().find(|&i| perm[i - 1] < perm[i]) else {
+            return false;
+        };
+        let pivot = pivot - 1;
+        let successor = (pivot + 1..perm.len())
+            .rev()
+            .find(|&j| perm[j] > perm[pivot])
+            .expect("a larger element exists right of the pivot");
+        perm.swap(pivot, successor);
+        perm[pivot + 1..].reverse();
+        true
+    }
+}
+
+/// Fold over all permutations of `0..n`, reusing a single buffer.
+///
+/// Visits permutations in the same order as [`PermutationIterator`].
+pub fn fold_permutations<B>(n: usize, init: B, mut f: impl FnMut(B, &[usize]) -> B) -> B {
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut acc = f(init, &perm);
+    while PermutationIterator::next_in_place(&mut perm) {
+        acc = f(acc, &perm);
+    }
+    acc
+}
+
+impl Iterator for PermutationIterator {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.take()?;
+        self.remaining = self.remaining.saturating_sub(1);
+        let mut next = current.clone();
+        if Self::next_in_place(&mut next) {
+            self.current = Some(next);
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
 #[cfg(test)]
 #[path = "unit_tests/config.rs"]
 mod tests;
@@ -40,6 +40,10 @@ pub enum ProblemError {
     /// Serialization/deserialization error.
     #[error("serialization error: {0}")]
     SerializationError(String),
+
+    /// A saved solver result does not match the problem it is checked against.
+    #[error("solver result mismatch: {0}")]
+    ResultMismatch(String),
 }
 
 /// Result type alias for problemreductions operations.
@@ -105,6 +105,32 @@ pub fn find_model_example(problem: &ProblemRef) -> Result<ModelExample> {
             ))
         })
 }
+
+/// Find a canonical model example to show for a problem.
+///
+/// Prefers the requested variant; when it has no example of its own, falls
+/// back to the first example of another variant of the same problem, so every
+/// registered problem name resolves to a runnable instance.
+pub fn find_problem_example(problem: &ProblemRef) -> Result<ModelExample> {
+    let db = build_model_db()?;
+    let mut fallback = None;
+    for model in db.models {
+        if model.problem != problem.name {
+            continue;
+        }
+        if model.variant == problem.variant {
+            return Ok(model);
+        }
+        fallback.get_or_insert(model);
+    }
+    fallback.ok_or_else(|| {
+        ProblemError::InvalidProblem(format!(
+            "No canonical model example exists for {}",
+            problem.name
+        ))
+    })
+}
+
 #[cfg(test)]
 #[path = "../unit_tests/example_db.rs"]
 mod tests;
@@ -0,0 +1,185 @@
+//! BQP-JSON import/export for QUBO instances.
+//!
+//! [BQP-JSON](https://github.com/lanl-ansi/bqpjson) is the JSON exchange format
+//! for binary quadratic programs used by D-Wave and quantum annealing tooling.
+//! A document describes the objective
+//!
+//! E(x) = scale * (Σ_i a_i x_i + Σ_{i<j} b_ij x_i x_j + offset)
+//!
+//! over variables with a `"boolean"` (x ∈ {0, 1}) or `"spin"` (x ∈ {-1, 1})
+//! domain. QUBO maps onto the boolean domain: diagonal entries `Q[i][i]`
+//! become linear biases and off-diagonal entries `Q[i][j]` become quadratic
+//! couplers.
+
+use crate::error::{ProblemError, Result};
+use crate::models::algebraic::QUBO;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// BQP-JSON format version emitted by [`to_bqpjson`].
+pub const BQPJSON_VERSION: &str = "1.0.0";
+
+/// A BQP-JSON document.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BqpJson {
+    /// Format version (e.g., `"1.0.0"`).
+    pub version: String,
+    /// Instance identifier.
+    pub id: i64,
+    /// Free-form metadata.
+    #[serde(default)]
+    pub metadata: serde_json::Value,
+    /// Variable identifiers; need not be contiguous.
+    pub variable_ids: Vec<i64>,
+    /// Variable domain: `"boolean"` or `"spin"`.
+    pub variable_domain: String,
+    /// Multiplicative scale applied to the whole objective.
+    pub scale: f64,
+    /// Constant term added to the objective before scaling.
+    pub offset: f64,
+    /// Linear biases.
+    pub linear_terms: Vec<LinearTerm>,
+    /// Quadratic couplers.
+    pub quadratic_terms: Vec<QuadraticTerm>,
+    /// Optional human-readable description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// A linear bias `coeff * x_id`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LinearTerm {
+    pub id: i64,
+    pub coeff: f64,
+}
+
+/// A quadratic coupler `coeff * x_tail * x_head`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct QuadraticTerm {
+    pub id_tail: i64,
+    pub id_head: i64,
+    pub coeff: f64,
+}
+
+impl BqpJson {
+    /// Build a BQP-JSON document from a QUBO.
+    ///
+    /// Variable `i` is given id `i`. Zero coefficients are omitted, and the
+    /// scale and offset are the identity (`1.0` and `0.0`).
+    pub fn from_qubo(qubo: &QUBO<f64>) -> Self {
+        let n = qubo.num_vars();
+        let mut linear_terms = Vec::new();
+        let mut quadratic_terms = Vec::new();
+        for i in 0..n {
+            for j in i..n {
+                let coeff = qubo.get(i, j).copied().unwrap_or(0.0);
+                if coeff == 0.0 {
+                    continue;
+                }
+                if i == j {
+                    linear_terms.push(LinearTerm {
+                        id: i as i64,
+                        coeff,
+                    });
+                } else {
+                    quadratic_terms.push(QuadraticTerm {
+                        id_tail: i as i64,
+                        id_head: j as i64,
+                        coeff,
+                    });
+                }
+            }
+        }
+
+        Self {
+            version: BQPJSON_VERSION.to_string(),
+            id: 0,
+            metadata: serde_json::json!({}),
+            variable_ids: (0..n as i64).collect(),
+            variable_domain: "boolean".to_string(),
+            scale: 1.0,
+            offset: 0.0,
+            linear_terms,
+            quadratic_terms,
+            description: None,
+        }
+    }
+
+    /// Convert this document into a QUBO plus a constant energy offset.
+    ///
+    /// Variable ids are mapped to QUBO indices in ascending id order. The
+    /// scale is folded into the coefficients, so for every assignment
+    /// `E(x) = qubo.evaluate(x) + offset`. Repeated terms are summed, and a
+    /// coupler between a variable and itself is treated as a linear bias
+    /// since `x * x = x` for binary `x`.
+    ///
+    /// Returns an error for the `"spin"` domain or for terms that reference
+    /// undeclared variable ids.
+    pub fn to_qubo(&self) -> Result<(QUBO<f64>, f64)> {
+        if self.variable_domain != "boolean" {
+            return Err(ProblemError::InvalidProblem(format!(
+                "unsupported BQP-JSON variable domain \"{}\": QUBO requires \"boolean\"",
+                self.variable_domain
+            )));
+        }
+
+        let mut ids = self.variable_ids.clone();
+        ids.sort_unstable();
+        ids.dedup();
+        let index: BTreeMap<i64, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let lookup = |id: i64| {
+            index.get(&id).copied().ok_or_else(|| {
+                ProblemError::InvalidProblem(format!(
+                    "BQP-JSON term references undeclared variable id {id}"
+                ))
+            })
+        };
+
+        let n = ids.len();
+        let mut matrix = vec![vec![0.0; n]; n];
+        for term in &self.linear_terms {
+            let i = lookup(term.id)?;
+            matrix[i][i] += self.scale * term.coeff;
+        }
+        for term in &self.quadratic_terms {
+            let a = lookup(term.id_tail)?;
+            let b = lookup(term.id_head)?;
+            let (i, j) = if a <= b { (a, b) } else { (b, a) };
+            matrix[i][j] += self.scale * term.coeff;
+        }
+
+        Ok((QUBO::from_matrix(matrix), self.scale * self.offset))
+    }
+}
+
+/// Serialize a QUBO to a pretty-printed BQP-JSON string.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::export::bqpjson::{from_bqpjson, to_bqpjson};
+/// use problemreductions::models::algebraic::QUBO;
+///
+/// let qubo = QUBO::from_matrix(vec![vec![-1.0, 2.0], vec![0.0, -1.0]]);
+/// let json = to_bqpjson(&qubo);
+/// let (restored, offset) = from_bqpjson(&json).unwrap();
+/// assert_eq!(restored.matrix(), qubo.matrix());
+/// assert_eq!(offset, 0.0);
+/// ```
+pub fn to_bqpjson(qubo: &QUBO<f64>) -> String {
+    serde_json::to_string_pretty(&BqpJson::from_qubo(qubo)).expect("Failed to serialize BQP-JSON")
+}
+
+/// Parse a BQP-JSON string into a QUBO plus a constant energy offset.
+///
+/// See [`BqpJson::to_qubo`] for how the document is mapped.
+pub fn from_bqpjson(json: &str) -> Result<(QUBO<f64>, f64)> {
+    let doc: BqpJson = serde_json::from_str(json).map_err(|e| {
+        ProblemError::SerializationError(format!("Failed to parse BQP-JSON: {}", e))
+    })?;
+    doc.to_qubo()
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/export/bqpjson.rs"]
+mod tests;
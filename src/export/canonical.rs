@@ -0,0 +1,219 @@
+//! Deterministic canonical form of exported problem instances.
+//!
+//! Equivalent graph instances may list their edges in different orders or
+//! orientations. Canonicalization rewrites every graph object
+//! (`{"num_vertices", "edges"}`) so that each edge is `[u, v]` with `u <= v`
+//! and the edge list is sorted. Instance fields that the problem schema
+//! documents as per-edge data (edge weights, capacities, ...) are permuted
+//! along with the edges, and edge-index fields are renumbered. Duplicate
+//! edges are removed only when no such edge-aligned data exists, since
+//! merging their values would change the instance.
+
+use super::ProblemSide;
+use crate::registry::ProblemSchemaEntry;
+use serde_json::{Map, Value};
+
+impl ProblemSide {
+    /// Return the canonical form of this problem side.
+    ///
+    /// Two instances that differ only in edge order, edge orientation, or
+    /// repeated unweighted edges canonicalize identically. For problems
+    /// whose configuration is indexed by edges, configurations of the
+    /// canonical instance follow the canonical edge order.
+    pub fn canonicalize(&self) -> ProblemSide {
+        let mut instance = self.instance.clone();
+        canonicalize_instance(&self.problem, &mut instance);
+        ProblemSide {
+            problem: self.problem.clone(),
+            variant: self.variant.clone(),
+            instance: sort_keys(instance),
+        }
+    }
+
+    /// Canonical JSON encoding of this problem side, with sorted object keys.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(&self.canonicalize()).expect("Failed to serialize problem side")
+    }
+
+    /// Stable 64-bit hash of [`Self::canonical_bytes`] (FNV-1a), suitable
+    /// as a cache key across processes and platforms.
+    pub fn canonical_hash(&self) -> u64 {
+        fnv1a(&self.canonical_bytes())
+    }
+}
+
+/// How a top-level instance field relates to the graph's edge list.
+enum EdgeField {
+    /// One entry per edge, in graph edge order.
+    PerEdge,
+    /// A list of edge indices.
+    Indices,
+}
+
+/// Classify the schema fields of `problem` that depend on edge order.
+fn edge_fields(problem: &str) -> Vec<(&'static str, EdgeField)> {
+    let Some(entry) = inventory::iter::<ProblemSchemaEntry>
+        .into_iter()
+        .find(|entry| entry.name == problem)
+    else {
+        return vec![];
+    };
+    entry
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let description = field.description.to_lowercase();
+            if !field.type_name.starts_with("Vec<")
+                || field.type_name == "Vec<(usize, usize)>"
+                || !description.contains("edge")
+                || description.contains("hyperedge")
+            {
+                return None;
+            }
+            if description.contains("edge indices") {
+                Some((field.name, EdgeField::Indices))
+            } else {
+                Some((field.name, EdgeField::PerEdge))
+            }
+        })
+        .collect()
+}
+
+fn canonicalize_instance(problem: &str, instance: &mut Value) {
+    let mut graphs = Vec::new();
+    collect_graph_paths(instance, &mut Vec::new(), &mut graphs);
+
+    let fields = edge_fields(problem);
+    let has_edge_data = fields.iter().any(|(name, _)| instance.get(*name).is_some());
+    if has_edge_data && graphs.len() != 1 {
+        // Edge-aligned data cannot be attributed to a single edge list.
+        return;
+    }
+
+    for path in graphs {
+        let Some(graph) = pointer_mut(instance, &path) else {
+            continue;
+        };
+        let Some(edges) = graph.get("edges").and_then(parse_edges) else {
+            continue;
+        };
+
+        // Per-edge values travel with their edge and break ties between
+        // duplicate edges deterministically.
+        let per_edge: Vec<Vec<String>> = (0..edges.len())
+            .map(|e| {
+                fields
+                    .iter()
+                    .filter(|(_, kind)| matches!(kind, EdgeField::PerEdge))
+                    .filter_map(|(name, _)| instance.get(*name)?.as_array()?.get(e))
+                    .map(Value::to_string)
+                    .collect()
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..edges.len()).collect();
+        order.sort_by(|&a, &b| (edges[a], &per_edge[a]).cmp(&(edges[b], &per_edge[b])));
+        if !has_edge_data {
+            order.dedup_by_key(|&mut e| edges[e]);
+        }
+
+        let canonical: Vec<Value> = order
+            .iter()
+            .map(|&e| Value::from(vec![edges[e].0, edges[e].1]))
+            .collect();
+        if let Some(graph) = pointer_mut(instance, &path) {
+            graph["edges"] = Value::Array(canonical);
+        }
+
+        if has_edge_data {
+            let mut new_index = vec![0; edges.len()];
+            for (new, &old) in order.iter().enumerate() {
+                new_index[old] = new;
+            }
+            for (name, kind) in &fields {
+                let Some(Value::Array(values)) = instance.get_mut(*name) else {
+                    continue;
+                };
+                match kind {
+                    EdgeField::PerEdge if values.len() == edges.len() => {
+                        *values = order.iter().map(|&e| values[e].clone()).collect();
+                    }
+                    EdgeField::Indices => {
+                        for value in values.iter_mut() {
+                            if let Some(old) = value.as_u64().map(|e| e as usize) {
+                                if old < new_index.len() {
+                                    *value = Value::from(new_index[old]);
+                                }
+                            }
+                        }
+                        values.sort_by_key(|value| value.as_u64());
+                    }
+                    EdgeField::PerEdge => {}
+                }
+            }
+        }
+    }
+}
+
+/// Record the path of every object whose keys are exactly `num_vertices`
+/// and `edges` (the serialized form of an undirected simple graph).
+fn collect_graph_paths(value: &Value, path: &mut Vec<String>, out: &mut Vec<Vec<String>>) {
+    let Value::Object(map) = value else {
+        return;
+    };
+    if map.len() == 2 && map.contains_key("num_vertices") && map.contains_key("edges") {
+        out.push(path.clone());
+        return;
+    }
+    for (key, child) in map {
+        path.push(key.clone());
+        collect_graph_paths(child, path, out);
+        path.pop();
+    }
+}
+
+fn pointer_mut<'a>(value: &'a mut Value, path: &[String]) -> Option<&'a mut Value> {
+    path.iter()
+        .try_fold(value, |current, key| current.get_mut(key.as_str()))
+}
+
+/// Parse an edge list into pairs with `u <= v`.
+fn parse_edges(value: &Value) -> Option<Vec<(u64, u64)>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|edge| match edge.as_array()?.as_slice() {
+            [u, v] => {
+                let (u, v) = (u.as_u64()?, v.as_u64()?);
+                Some((u.min(v), u.max(v)))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Rebuild every object with its keys in sorted order.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect::<Map<String, Value>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        other => other,
+    }
+}
+
+/// 64-bit FNV-1a hash.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    bytes.iter().fold(OFFSET, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
@@ -0,0 +1,122 @@
+//! Pajek and CSV export of graph topologies.
+//!
+//! These writers accept any [`GraphView`], so the graph underlying a reduction
+//! target can be inspected in external tools (Gephi, NetworkX, Pajek) without
+//! knowing its concrete type at compile time.
+//!
+//! Vertices are written with their 0-based index as label. Pajek vertex ids are
+//! 1-based as the format requires; CSV edge lists keep 0-based indices.
+
+use crate::error::{ProblemError, Result};
+use crate::topology::{
+    BipartiteGraph, GraphView, KingsSubgraph, PlanarGraph, SimpleGraph, TriangularSubgraph,
+    UnitDiskGraph,
+};
+use std::fmt::Write;
+
+/// Serialize a graph in the Pajek `.net` format.
+///
+/// Each vertex line is `id "label"`, followed by `x y` when the graph has
+/// coordinates and by a `weight w` attribute when `vertex_weights` is given.
+///
+/// # Panics
+///
+/// Panics if `vertex_weights` does not have one entry per vertex.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::export::graph::to_pajek;
+/// use problemreductions::topology::SimpleGraph;
+///
+/// let graph = SimpleGraph::new(2, vec![(0, 1)]);
+/// assert_eq!(to_pajek(&graph, None), "*Vertices 2\n1 \"0\"\n2 \"1\"\n*Edges\n1 2\n");
+/// ```
+pub fn to_pajek(graph: &dyn GraphView, vertex_weights: Option<&[f64]>) -> String {
+    let n = graph.vertex_count();
+    if let Some(weights) = vertex_weights {
+        assert_eq!(weights.len(), n, "expected one weight per vertex");
+    }
+    let coordinates = graph.coordinates();
+
+    let mut out = String::new();
+    writeln!(out, "*Vertices {n}").unwrap();
+    for v in 0..n {
+        write!(out, "{} \"{v}\"", v + 1).unwrap();
+        if let Some(coords) = &coordinates {
+            let (x, y) = coords[v];
+            write!(out, " {x} {y}").unwrap();
+        }
+        if let Some(weights) = vertex_weights {
+            write!(out, " weight {}", weights[v]).unwrap();
+        }
+        out.push('\n');
+    }
+    out.push_str("*Edges\n");
+    for (u, v) in graph.edge_list() {
+        writeln!(out, "{} {}", u + 1, v + 1).unwrap();
+    }
+    out
+}
+
+/// Serialize the edges of a graph as CSV.
+///
+/// The header is `source,target`, plus a `weight` column when `edge_weights`
+/// is given. Rows follow the order of [`GraphView::edge_list`].
+///
+/// # Panics
+///
+/// Panics if `edge_weights` does not have one entry per edge.
+pub fn to_csv_edgelist(graph: &dyn GraphView, edge_weights: Option<&[f64]>) -> String {
+    let edges = graph.edge_list();
+    if let Some(weights) = edge_weights {
+        assert_eq!(weights.len(), edges.len(), "expected one weight per edge");
+    }
+
+    let mut out = String::from("source,target");
+    if edge_weights.is_some() {
+        out.push_str(",weight");
+    }
+    out.push('\n');
+    for (i, (u, v)) in edges.into_iter().enumerate() {
+        write!(out, "{u},{v}").unwrap();
+        if let Some(weights) = edge_weights {
+            write!(out, ",{}", weights[i]).unwrap();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Deserialize a graph from its JSON form, given the graph type name.
+///
+/// `graph_type` is the value of the `"graph"` variant key (e.g.,
+/// `"KingsSubgraph"`), and `value` is the serialized graph field of a problem
+/// instance.
+pub fn graph_from_json(graph_type: &str, value: &serde_json::Value) -> Result<Box<dyn GraphView>> {
+    fn parse<G>(value: &serde_json::Value) -> Result<Box<dyn GraphView>>
+    where
+        G: GraphView + serde::de::DeserializeOwned + 'static,
+    {
+        let graph: G = serde_json::from_value(value.clone()).map_err(|e| {
+            ProblemError::SerializationError(format!("Failed to parse graph: {}", e))
+        })?;
+        Ok(Box::new(graph))
+    }
+
+    match graph_type {
+        "SimpleGraph" => parse::<SimpleGraph>(value),
+        "PlanarGraph" => parse::<PlanarGraph>(value),
+        "BipartiteGraph" => parse::<BipartiteGraph>(value),
+        "UnitDiskGraph" => parse::<UnitDiskGraph>(value),
+        "KingsSubgraph" => parse::<KingsSubgraph>(value),
+        "TriangularSubgraph" => parse::<TriangularSubgraph>(value),
+        other => Err(ProblemError::InvalidProblem(format!(
+            "graph export does not support graph type \"{other}\""
+        ))),
+    }
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/export/graph.rs"]
+mod tests;
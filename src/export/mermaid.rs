@@ -0,0 +1,71 @@
+//! Mermaid flowchart export of the reduction graph.
+//!
+//! The diagram is drawn at the problem-type level: one node per problem name
+//! and one arrow per ordered pair of problems with at least one variant-level
+//! reduction between them. Variant casts within a problem are omitted.
+
+use crate::rules::ReductionGraph;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Label for an edge whose overhead is not polynomial in the source size.
+pub const NON_POLYNOMIAL_LABEL: &str = "super-poly";
+
+/// Render the reduction graph as a Mermaid `graph LR` flowchart.
+///
+/// Each arrow is labeled with the overhead degree: the largest
+/// [`Expr::polynomial_degree`](crate::expr::Expr::polynomial_degree) over the
+/// output size fields, maximized over the variant-level reductions it stands
+/// for. Arrows with a non-polynomial overhead are labeled
+/// [`NON_POLYNOMIAL_LABEL`]. Nodes and arrows are sorted by name, so the
+/// output is deterministic.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::export::mermaid;
+/// use problemreductions::rules::ReductionGraph;
+///
+/// let diagram = mermaid(&ReductionGraph::new());
+/// assert!(diagram.starts_with("graph LR\n"));
+/// assert!(diagram.contains("    MaximumIndependentSet -->|1| MinimumVertexCover\n"));
+/// ```
+pub fn mermaid(graph: &ReductionGraph) -> String {
+    let mut names = graph.problem_types();
+    names.sort_unstable();
+
+    // `None` marks a non-polynomial overhead and dominates any degree.
+    let mut edges: BTreeMap<(&str, &str), Option<u32>> = BTreeMap::new();
+    for &name in &names {
+        for edge in graph.outgoing_reductions(name) {
+            if edge.source_name == edge.target_name {
+                continue;
+            }
+            let degree = edge
+                .overhead
+                .output_size
+                .iter()
+                .try_fold(0, |max, (_, expr)| Some(max.max(expr.polynomial_degree()?)));
+            edges
+                .entry((edge.source_name, edge.target_name))
+                .and_modify(|current| {
+                    *current = current.zip(degree).map(|(a, b)| a.max(b));
+                })
+                .or_insert(degree);
+        }
+    }
+
+    let mut out = String::from("graph LR\n");
+    for name in &names {
+        writeln!(out, "    {name}").unwrap();
+    }
+    for ((source, target), degree) in &edges {
+        let label = degree.map_or_else(|| NON_POLYNOMIAL_LABEL.to_string(), |d| d.to_string());
+        writeln!(out, "    {source} -->|{label}| {target}").unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/export/mermaid.rs"]
+mod tests;
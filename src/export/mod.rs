@@ -1,5 +1,13 @@
 //! JSON export schema for example payloads.
 
+pub mod bqpjson;
+mod canonical;
+pub mod graph;
+mod mermaid;
+pub mod recipe;
+
+pub use mermaid::{mermaid, NON_POLYNOMIAL_LABEL};
+
 use crate::rules::registry::ReductionOverhead;
 use crate::rules::ReductionGraph;
 use crate::traits::Problem;
@@ -212,5 +220,5 @@ pub fn write_example_db_to(dir: &Path, db: &ExampleDb) {
 }
 
 #[cfg(test)]
-#[path = "unit_tests/export.rs"]
+#[path = "../unit_tests/export.rs"]
 mod tests;
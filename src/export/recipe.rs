@@ -0,0 +1,139 @@
+//! Reduction recipes: resolved reduction paths as replayable JSON.
+//!
+//! A recipe records every step of a [`ReductionPath`] together with the
+//! exact intermediate problem variants and the overhead each rule declares,
+//! so a multi-step pipeline can be saved once and re-run later without
+//! searching the reduction graph again. Load a recipe back with
+//! [`ReductionPath::from_recipe`] and execute it with
+//! [`ReductionGraph::reduce_along_path`].
+
+use super::ProblemRef;
+use crate::error::{ProblemError, Result};
+use crate::rules::{ReductionGraph, ReductionPath, ReductionStep};
+use serde::{Deserialize, Serialize};
+
+/// Recipe format version emitted by [`Recipe::from_path`].
+pub const RECIPE_VERSION: u32 = 1;
+
+/// A serialized reduction path.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Recipe {
+    /// Format version (see [`RECIPE_VERSION`]).
+    pub version: u32,
+    /// Problem variant the recipe starts from.
+    pub source: ProblemRef,
+    /// Problem variant the recipe ends at.
+    pub target: ProblemRef,
+    /// Reduction steps in execution order.
+    pub steps: Vec<RecipeStep>,
+}
+
+/// One reduction in a recipe.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecipeStep {
+    pub from: ProblemRef,
+    pub to: ProblemRef,
+    /// Declared target size of this step, over the size fields of `from`.
+    pub overhead: Vec<RecipeOverhead>,
+}
+
+/// A declared output size `field = formula`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecipeOverhead {
+    pub field: String,
+    pub formula: String,
+}
+
+impl Recipe {
+    /// Record a reduction path found in `graph`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path has fewer than two steps or if a step is not an
+    /// edge of `graph`.
+    pub fn from_path(graph: &ReductionGraph, path: &ReductionPath) -> Self {
+        assert!(
+            path.steps.len() >= 2,
+            "A recipe needs at least one reduction step"
+        );
+        let steps = path
+            .steps
+            .windows(2)
+            .zip(graph.path_overheads(path))
+            .map(|(pair, overhead)| RecipeStep {
+                from: step_ref(&pair[0]),
+                to: step_ref(&pair[1]),
+                overhead: overhead
+                    .output_size
+                    .iter()
+                    .map(|(field, expr)| RecipeOverhead {
+                        field: field.to_string(),
+                        formula: expr.to_string(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            version: RECIPE_VERSION,
+            source: step_ref(&path.steps[0]),
+            target: step_ref(path.steps.last().unwrap()),
+            steps,
+        }
+    }
+}
+
+fn step_ref(step: &ReductionStep) -> ProblemRef {
+    ProblemRef {
+        name: step.name.clone(),
+        variant: step.variant.clone(),
+    }
+}
+
+/// Serialize a reduction path to a pretty-printed recipe string.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::export::recipe::{from_recipe, to_recipe};
+/// use problemreductions::models::graph::{MaximumIndependentSet, MinimumVertexCover};
+/// use problemreductions::rules::{MinimizeSteps, ReductionGraph};
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::traits::Problem;
+/// use problemreductions::types::ProblemSize;
+///
+/// let graph = ReductionGraph::new();
+/// let src = ReductionGraph::variant_to_map(&MaximumIndependentSet::<SimpleGraph, i32>::variant());
+/// let dst = ReductionGraph::variant_to_map(&MinimumVertexCover::<SimpleGraph, i32>::variant());
+/// let path = graph
+///     .find_cheapest_path(
+///         "MaximumIndependentSet",
+///         &src,
+///         "MinimumVertexCover",
+///         &dst,
+///         &ProblemSize::new(vec![]),
+///         &MinimizeSteps,
+///     )
+///     .unwrap();
+///
+/// let json = to_recipe(&graph, &path);
+/// let restored = from_recipe(&graph, &json).unwrap();
+/// assert_eq!(restored.steps.len(), path.steps.len());
+/// ```
+pub fn to_recipe(graph: &ReductionGraph, path: &ReductionPath) -> String {
+    serde_json::to_string_pretty(&Recipe::from_path(graph, path))
+        .expect("Failed to serialize recipe")
+}
+
+/// Parse a recipe string into a reduction path of `graph`.
+///
+/// See [`ReductionPath::from_recipe`] for the checks performed.
+pub fn from_recipe(graph: &ReductionGraph, json: &str) -> Result<ReductionPath> {
+    let recipe: Recipe = serde_json::from_str(json)
+        .map_err(|e| ProblemError::SerializationError(format!("Failed to parse recipe: {}", e)))?;
+    ReductionPath::from_recipe(graph, &recipe)
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/export/recipe.rs"]
+mod tests;
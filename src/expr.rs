@@ -130,6 +130,31 @@ impl Expr {
         }
     }
 
+    /// Total degree of this expression as a polynomial in its variables.
+    ///
+    /// Constant subexpressions (including divisions by constants) have degree 0.
+    /// Returns `None` when the expression is not polynomial in its variables,
+    /// e.g. `2^n`, `log(n)` or `n^(1/2)`. No cancellation is detected, so
+    /// `n^2 - n^2` reports degree 2.
+    pub fn polynomial_degree(&self) -> Option<u32> {
+        if self.constant_value().is_some() {
+            return Some(0);
+        }
+        match self {
+            Expr::Const(_) => Some(0),
+            Expr::Var(_) => Some(1),
+            Expr::Add(a, b) => Some(a.polynomial_degree()?.max(b.polynomial_degree()?)),
+            Expr::Mul(a, b) => Some(a.polynomial_degree()? + b.polynomial_degree()?),
+            Expr::Pow(base, exp) => match exp.as_ref() {
+                Expr::Const(c) if *c >= 0.0 && (*c - c.round()).abs() < 1e-10 => {
+                    Some(base.polynomial_degree()? * c.round() as u32)
+                }
+                _ => None,
+            },
+            Expr::Exp(_) | Expr::Log(_) | Expr::Sqrt(_) | Expr::Factorial(_) => None,
+        }
+    }
+
     /// Check whether this expression is suitable for asymptotic complexity notation.
     ///
     /// This is intentionally conservative for symbolic size formulas:
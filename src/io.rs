@@ -1,14 +1,21 @@
 //! File I/O utilities for problem serialization.
 //!
 //! This module provides functions for reading and writing problems
-//! to various file formats using serde.
+//! to various file formats using serde, plus the OPB text format for
+//! [`PseudoBoolean`] instances. Solver results with provenance live in
+//! [`result`].
 
 use crate::error::{ProblemError, Result};
+use crate::models::algebraic::{PseudoBoolean, PseudoBooleanComparison, PseudoBooleanConstraint};
 use serde::{de::DeserializeOwned, Serialize};
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::Path;
 
+pub mod result;
+
+pub use result::{read_result, write_result, SolverResult};
+
 /// Supported file formats.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileFormat {
@@ -128,6 +135,162 @@ pub fn write_file<P: AsRef<Path>>(path: P, contents: &str) -> Result<()> {
     Ok(())
 }
 
+/// Write a [`PseudoBoolean`] instance in the OPB format.
+///
+/// Variables are written 1-indexed (`x1` is variable 0). OPB only knows `>=`
+/// and `=`, so `<=` constraints are written negated. An empty objective
+/// produces a satisfaction instance without a `min:` line.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::io::{from_opb, to_opb};
+/// use problemreductions::models::algebraic::{PseudoBoolean, PseudoBooleanConstraint};
+///
+/// let problem = PseudoBoolean::new(
+///     2,
+///     vec![(0, 1), (1, -2)],
+///     vec![PseudoBooleanConstraint::le(vec![(0, 1), (1, 1)], 1)],
+/// );
+/// let opb = to_opb(&problem);
+/// assert!(opb.contains("min: +1 x1 -2 x2 ;"));
+/// assert!(opb.contains("-1 x1 -1 x2 >= -1 ;"));
+/// assert_eq!(from_opb(&opb).unwrap().num_vars(), 2);
+/// ```
+pub fn to_opb(problem: &PseudoBoolean) -> String {
+    fn write_terms(out: &mut String, terms: &[(usize, i64)], sign: i64) {
+        for &(var, coef) in terms {
+            out.push_str(&format!("{:+} x{} ", sign * coef, var + 1));
+        }
+    }
+
+    let mut out = format!(
+        "* #variable= {} #constraint= {}\n",
+        problem.num_vars(),
+        problem.num_constraints()
+    );
+    if !problem.objective().is_empty() {
+        out.push_str("min: ");
+        write_terms(&mut out, problem.objective(), 1);
+        out.push_str(";\n");
+    }
+    for constraint in problem.constraints() {
+        let (sign, op) = match constraint.cmp {
+            PseudoBooleanComparison::Ge => (1, ">="),
+            PseudoBooleanComparison::Le => (-1, ">="),
+            PseudoBooleanComparison::Eq => (1, "="),
+        };
+        write_terms(&mut out, &constraint.terms, sign);
+        out.push_str(&format!("{op} {} ;\n", sign * constraint.rhs));
+    }
+    out
+}
+
+/// Parse a [`PseudoBoolean`] instance from the OPB format.
+///
+/// Supports linear objectives (`min:`) and linear constraints with `>=`,
+/// `<=` or `=` over variables `x1, x2, ...`. The variable count is the larger
+/// of the `#variable=` header (if any) and the highest variable index used.
+/// Non-linear products and negated literals (`~x1`) are rejected.
+pub fn from_opb(text: &str) -> Result<PseudoBoolean> {
+    let parse_error =
+        |msg: String| ProblemError::SerializationError(format!("Failed to parse OPB: {msg}"));
+
+    let mut num_vars = 0usize;
+    let mut body = String::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if let Some(comment) = line.strip_prefix('*') {
+            let mut tokens = comment.split_whitespace();
+            while let Some(token) = tokens.next() {
+                if token == "#variable=" {
+                    let count = tokens.next().unwrap_or_default();
+                    num_vars = count
+                        .parse()
+                        .map_err(|_| parse_error(format!("invalid variable count '{count}'")))?;
+                }
+            }
+        } else {
+            body.push_str(line);
+            body.push('\n');
+        }
+    }
+
+    let mut objective = Vec::new();
+    let mut constraints = Vec::new();
+    for statement in body.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+        let mut tokens: Vec<&str> = statement.split_whitespace().collect();
+        if let Some(first) = tokens.first().copied() {
+            if let Some(rest) = first.strip_prefix("min:") {
+                if !objective.is_empty() {
+                    return Err(parse_error("more than one objective".to_string()));
+                }
+                tokens[0] = rest;
+                objective = parse_opb_terms(&tokens, &mut num_vars).map_err(parse_error)?;
+                continue;
+            }
+            if first.starts_with("max:") {
+                return Err(parse_error(
+                    "OPB objectives must be minimized (min:)".to_string(),
+                ));
+            }
+        }
+        let op_at = tokens
+            .iter()
+            .position(|t| matches!(*t, ">=" | "<=" | "="))
+            .ok_or_else(|| parse_error(format!("missing relation in '{statement}'")))?;
+        let rhs = match &tokens[op_at + 1..] {
+            [rhs] => rhs
+                .parse::<i64>()
+                .map_err(|_| parse_error(format!("invalid right-hand side '{rhs}'")))?,
+            _ => {
+                return Err(parse_error(format!(
+                    "expected one right-hand side in '{statement}'"
+                )))
+            }
+        };
+        let terms = parse_opb_terms(&tokens[..op_at], &mut num_vars).map_err(parse_error)?;
+        constraints.push(match tokens[op_at] {
+            ">=" => PseudoBooleanConstraint::ge(terms, rhs),
+            "<=" => PseudoBooleanConstraint::le(terms, rhs),
+            _ => PseudoBooleanConstraint::eq(terms, rhs),
+        });
+    }
+    Ok(PseudoBoolean::new(num_vars, objective, constraints))
+}
+
+/// Parse `coef var coef var ...` into 0-indexed terms, growing `num_vars` to
+/// cover every variable seen.
+fn parse_opb_terms(
+    tokens: &[&str],
+    num_vars: &mut usize,
+) -> std::result::Result<Vec<(usize, i64)>, String> {
+    let tokens: Vec<&str> = tokens.iter().copied().filter(|t| !t.is_empty()).collect();
+    let mut terms = Vec::new();
+    for chunk in tokens.chunks(2) {
+        let [coef, var] = chunk else {
+            return Err(format!(
+                "dangling token '{}' (non-linear terms are not supported)",
+                chunk[0]
+            ));
+        };
+        let coef = coef.parse::<i64>().map_err(|_| {
+            format!("invalid coefficient '{coef}' (non-linear terms are not supported)")
+        })?;
+        if var.starts_with('~') {
+            return Err(format!("negated literal '{var}' is not supported"));
+        }
+        let index = var
+            .strip_prefix('x')
+            .and_then(|k| k.parse::<usize>().ok())
+            .filter(|&k| k >= 1)
+            .ok_or_else(|| format!("invalid variable '{var}'"))?;
+        *num_vars = (*num_vars).max(index);
+        terms.push((index - 1, coef));
+    }
+    Ok(terms)
+}
+
 #[cfg(test)]
 #[path = "unit_tests/io.rs"]
 mod tests;
@@ -0,0 +1,179 @@
+//! Persisted solver results with problem provenance.
+//!
+//! A [`SolverResult`] records what a solver returned for a problem together
+//! with the problem's canonical hash
+//! ([`ProblemSide::canonical_hash`](crate::export::ProblemSide::canonical_hash)),
+//! so a saved result can later be checked against the instance it claims to
+//! solve.
+
+use crate::error::{ProblemError, Result};
+use crate::export::ProblemSide;
+use crate::registry::format_metric;
+use crate::traits::Problem;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+/// The outcome of running a solver on one problem instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolverResult {
+    /// Canonical hash of the solved problem, written as 16 hex digits.
+    #[serde(with = "hex_hash")]
+    pub problem_hash: u64,
+    /// Name of the solver that produced the result (e.g., `"brute-force"`).
+    pub solver: String,
+    /// Objective value in the CLI-facing metric form (e.g., `"Max(3)"`).
+    pub objective: String,
+    /// Configurations attaining `objective`; empty for value-only results.
+    pub configs: Vec<Vec<usize>>,
+    /// Wall-clock time the solver took.
+    pub elapsed: Duration,
+}
+
+impl SolverResult {
+    /// Create a result for the problem described by `problem`.
+    pub fn new(
+        problem: &ProblemSide,
+        solver: impl Into<String>,
+        objective: impl Into<String>,
+        configs: Vec<Vec<usize>>,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            problem_hash: problem.canonical_hash(),
+            solver: solver.into(),
+            objective: objective.into(),
+            configs,
+            elapsed,
+        }
+    }
+
+    /// Create a result for a typed problem, formatting `objective` the way
+    /// the CLI prints evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::io::SolverResult;
+    /// use problemreductions::models::graph::MaximumIndependentSet;
+    /// use problemreductions::topology::SimpleGraph;
+    /// use problemreductions::{BruteForce, Problem};
+    /// use std::time::Instant;
+    ///
+    /// let problem = MaximumIndependentSet::new(SimpleGraph::new(3, vec![(0, 1), (1, 2)]), vec![1i32; 3]);
+    /// let start = Instant::now();
+    /// let witness = BruteForce::new().find_witness(&problem).unwrap();
+    /// let value = problem.evaluate(&witness);
+    /// let result = SolverResult::from_problem(&problem, "brute-force", &value, vec![witness], start.elapsed());
+    ///
+    /// assert_eq!(result.objective, "Max(2)");
+    /// assert!(result.verify(&problem).is_ok());
+    /// ```
+    pub fn from_problem<P>(
+        problem: &P,
+        solver: impl Into<String>,
+        objective: &P::Value,
+        configs: Vec<Vec<usize>>,
+        elapsed: Duration,
+    ) -> Self
+    where
+        P: Problem + Serialize,
+        P::Value: fmt::Display,
+    {
+        Self::new(
+            &ProblemSide::from_problem(problem),
+            solver,
+            format_metric(objective),
+            configs,
+            elapsed,
+        )
+    }
+
+    /// Check that this result was computed for `problem`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProblemError::ResultMismatch`] if the canonical hash of
+    /// `problem` differs from [`Self::problem_hash`].
+    pub fn check_problem(&self, problem: &ProblemSide) -> Result<()> {
+        let expected = problem.canonical_hash();
+        if self.problem_hash != expected {
+            return Err(ProblemError::ResultMismatch(format!(
+                "result is for problem hash {:016x}, but the problem hashes to {:016x}",
+                self.problem_hash, expected
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check that this result was computed for `problem` and that every
+    /// stored configuration still evaluates to [`Self::objective`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProblemError::ResultMismatch`] on a hash mismatch or when a
+    /// configuration evaluates to a different objective.
+    pub fn verify<P>(&self, problem: &P) -> Result<()>
+    where
+        P: Problem + Serialize,
+        P::Value: fmt::Display,
+    {
+        self.check_problem(&ProblemSide::from_problem(problem))?;
+        for config in &self.configs {
+            if config.len() != problem.num_variables() {
+                return Err(ProblemError::ResultMismatch(format!(
+                    "configuration {config:?} has {} variables, expected {}",
+                    config.len(),
+                    problem.num_variables()
+                )));
+            }
+            let value = format_metric(&problem.evaluate(config));
+            if value != self.objective {
+                return Err(ProblemError::ResultMismatch(format!(
+                    "configuration {config:?} evaluates to {value}, but the result records {}",
+                    self.objective
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write a solver result to a JSON file.
+pub fn write_result<P: AsRef<Path>>(result: &SolverResult, path: P) -> Result<()> {
+    super::write_problem(result, path, super::FileFormat::Json)
+}
+
+/// Read a solver result from a JSON file.
+///
+/// Loading does not check provenance; call [`SolverResult::check_problem`]
+/// or [`SolverResult::verify`] against the problem before trusting it.
+pub fn read_result<P: AsRef<Path>>(path: P) -> Result<SolverResult> {
+    super::read_problem(path, super::FileFormat::Json)
+}
+
+/// Serialize a `u64` hash as a fixed-width hex string, which survives JSON
+/// readers that parse every number as a double.
+mod hex_hash {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        hash: &u64,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{hash:016x}"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<u64, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        u64::from_str_radix(&text, 16)
+            .map_err(|_| serde::de::Error::custom(format!("invalid problem hash '{text}'")))
+    }
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/io/result.rs"]
+mod tests;
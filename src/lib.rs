@@ -7,9 +7,10 @@
 //!
 //! | Module | Purpose |
 //! |--------|---------|
-//! | [`models`] | Problem types — [`graph`](models::graph), [`formula`](models::formula), [`set`](models::set), [`algebraic`](models::algebraic), [`misc`](models::misc) |
+//! | [`models`] | Problem types — [`graph`](models::graph), [`formula`](models::formula), [`csp`](models::csp), [`set`](models::set), [`algebraic`](models::algebraic), [`misc`](models::misc) |
 //! | [`rules`] | Reduction rules, [`ReductionGraph`](rules::ReductionGraph) for path search |
-//! | [`solvers`] | [`BruteForce`] and [`ILPSolver`](solvers::ILPSolver) |
+//! | [`solvers`] | [`BruteForce`], [`ILPSolver`](solvers::ILPSolver), the [`heuristics`](solvers::heuristics), and the exact matching solver [`BlossomSolver`](solvers::BlossomSolver) |
+//! | [`statistics`] | Instance statistics — [`ProblemStatistics`](statistics::ProblemStatistics), [`GraphStatistics`](statistics::GraphStatistics) |
 //! | [`topology`] | Graph types — [`SimpleGraph`](topology::SimpleGraph), [`UnitDiskGraph`](topology::UnitDiskGraph), etc. |
 //! | [`traits`] | Core traits — [`Problem`] |
 //! | [`types`] | [`Max`], [`Min`], [`Extremum`], [`ExtremumSense`], [`ProblemSize`], [`WeightElement`] |
@@ -32,6 +33,7 @@ pub mod models;
 pub mod registry;
 pub mod rules;
 pub mod solvers;
+pub mod statistics;
 pub mod topology;
 pub mod traits;
 #[allow(dead_code)]
@@ -44,50 +46,55 @@ pub mod prelude {
     // Problem types
     pub use crate::models::algebraic::{
         AlgebraicEquationsOverGF2, ConsecutiveOnesMatrixAugmentation,
-        MinimumWeightSolutionToLinearEquations, QuadraticAssignment, QuadraticCongruences,
-        SimultaneousIncongruences, SparseMatrixCompression, BMF, QUBO,
+        MinimumWeightSolutionToLinearEquations, PseudoBoolean, QuadraticAssignment,
+        QuadraticCongruences, SimultaneousIncongruences, SparseMatrixCompression, BMF, QUBO,
     };
+    pub use crate::models::csp::{CspConstraint, CSP};
     pub use crate::models::formula::{
-        CNFClause, CircuitSAT, KSatisfiability, Maximum2Satisfiability, NAESatisfiability,
-        NonTautology, OneInThreeSatisfiability, Planar3Satisfiability, QuantifiedBooleanFormulas,
-        Satisfiability,
+        CNFClause, CircuitSAT, KSatisfiability, Maximum2Satisfiability, ModelCounting,
+        NAESatisfiability, NonTautology, OneInThreeSatisfiability, Planar3Satisfiability,
+        QuantifiedBooleanFormulas, Satisfiability, XorClause, XorSatisfiability,
     };
     pub use crate::models::graph::{
         AcyclicPartition, BalancedCompleteBipartiteSubgraph, BicliqueCover,
         BiconnectivityAugmentation, BottleneckTravelingSalesman, BoundedComponentSpanningForest,
-        DegreeConstrainedSpanningTree, DirectedTwoCommodityIntegralFlow, DisjointConnectingPaths,
-        GeneralizedHex, GraphPartitioning, HamiltonianCircuit, HamiltonianPath,
-        HamiltonianPathBetweenTwoVertices, IntegralFlowBundles, IntegralFlowHomologousArcs,
-        IntegralFlowWithMultipliers, IsomorphicSpanningTree, KClique, Kernel, KthBestSpanningTree,
-        LengthBoundedDisjointPaths, LongestPath, MixedChinesePostman, SpinGlass, SteinerTree,
-        StrongConnectivityAugmentation, SubgraphIsomorphism,
+        ClusterEditing, DegreeConstrainedSpanningTree, DirectedTwoCommodityIntegralFlow,
+        DisjointConnectingPaths, GeneralizedHex, GraphPartitioning, HamiltonianCircuit,
+        HamiltonianPath, HamiltonianPathBetweenTwoVertices, IntegralFlowBundles,
+        IntegralFlowHomologousArcs, IntegralFlowWithMultipliers, IsomorphicSpanningTree, KClique,
+        Kernel, KthBestSpanningTree, LengthBoundedDisjointPaths, LongestPath, MixedChinesePostman,
+        PolySpinGlass, SpinGlass, SteinerTree, StrongConnectivityAugmentation, SubgraphIsomorphism,
     };
     pub use crate::models::graph::{
-        KColoring, LongestCircuit, MaxCut, MaximalIS, MaximumClique, MaximumIndependentSet,
-        MaximumLeafSpanningTree, MaximumMatching, MinMaxMulticenter, MinimumCutIntoBoundedSets,
-        MinimumDominatingSet, MinimumDummyActivitiesPert, MinimumFeedbackArcSet,
-        MinimumFeedbackVertexSet, MinimumGeometricConnectedDominatingSet, MinimumGraphBandwidth,
-        MinimumMultiwayCut, MinimumSumMulticenter, MinimumVertexCover, MonochromaticTriangle,
-        MultipleChoiceBranching, MultipleCopyFileAllocation, OptimalLinearArrangement,
-        PartialFeedbackEdgeSet, PartitionIntoCliques, PartitionIntoPathsOfLength2,
-        PartitionIntoTriangles, PathConstrainedNetworkFlow, RootedTreeArrangement, RuralPostman,
-        ShortestWeightConstrainedPath, SteinerTreeInGraphs, TravelingSalesman,
-        UndirectedFlowLowerBounds, UndirectedTwoCommodityIntegralFlow,
+        KColoring, LongestCircuit, MaxBisection, MaxCut, MaximalIS, MaximumAcyclicSubgraph,
+        MaximumBalancedBiclique, MaximumClique, MaximumIndependentSet, MaximumLeafSpanningTree,
+        MaximumMatching, MaximumPlanarSubgraph, MinMaxMulticenter, MinimumCliqueCover,
+        MinimumCutIntoBoundedSets, MinimumDominatingSet, MinimumDummyActivitiesPert,
+        MinimumEdgeDominatingSet, MinimumFeedbackArcSet, MinimumFeedbackVertexSet,
+        MinimumGeometricConnectedDominatingSet, MinimumGraphBandwidth, MinimumMaximalIS,
+        MinimumMultiwayCut, MinimumSteinerForest, MinimumSumMulticenter, MinimumVertexCover,
+        MonochromaticTriangle, MultipleChoiceBranching, MultipleCopyFileAllocation,
+        OptimalLinearArrangement, PartialFeedbackEdgeSet, PartitionIntoCliques,
+        PartitionIntoPathsOfLength2, PartitionIntoTriangles, PathConstrainedNetworkFlow,
+        RootedTreeArrangement, RuralPostman, ShortestWeightConstrainedPath, SteinerTreeInGraphs,
+        TravelingSalesman, UndirectedFlowLowerBounds, UndirectedTwoCommodityIntegralFlow,
     };
     pub use crate::models::misc::{
         AdditionalKey, BinPacking, BoyceCoddNormalFormViolation, CapacityAssignment, CbqRelation,
         ConjunctiveBooleanQuery, ConjunctiveQueryFoldability, ConsistencyOfDatabaseFrequencyTables,
         CosineProductIntegration, EnsembleComputation, ExpectedRetrievalCost, Factoring,
         FlowShopScheduling, GroupingBySwapping, IntegerExpressionMembership, JobShopScheduling,
-        Knapsack, LongestCommonSubsequence, MinimumTardinessSequencing, MultiprocessorScheduling,
-        OpenShopScheduling, PaintShop, Partition, PreemptiveScheduling, ProductionPlanning,
-        QueryArg, RectilinearPictureCompression, ResourceConstrainedScheduling,
-        SchedulingWithIndividualDeadlines, SequencingToMinimizeMaximumCumulativeCost,
-        SequencingToMinimizeTardyTaskWeight, SequencingToMinimizeWeightedCompletionTime,
-        SequencingToMinimizeWeightedTardiness, SequencingWithDeadlinesAndSetUpTimes,
-        SequencingWithReleaseTimesAndDeadlines, SequencingWithinIntervals,
-        ShortestCommonSupersequence, StackerCrane, StaffScheduling, StringToStringCorrection,
-        SubsetProduct, SubsetSum, SumOfSquaresPartition, Term, ThreePartition, TimetableDesign,
+        KCenter, KTrackIntervalScheduling, Knapsack, LongestCommonSubsequence,
+        MinimumMultiprocessorScheduling, MinimumTardinessSequencing, MinimumWeightTriangulation,
+        MultiprocessorScheduling, OpenShopScheduling, PaintShop, Partition, PreemptiveScheduling,
+        ProductionPlanning, QueryArg, RectanglePacking, RectilinearPictureCompression,
+        ResourceConstrainedScheduling, SchedulingWithIndividualDeadlines,
+        SequencingToMinimizeMaximumCumulativeCost, SequencingToMinimizeTardyTaskWeight,
+        SequencingToMinimizeWeightedCompletionTime, SequencingToMinimizeWeightedTardiness,
+        SequencingWithDeadlinesAndSetUpTimes, SequencingWithReleaseTimesAndDeadlines,
+        SequencingWithinIntervals, ShortestCommonSupersequence, ShortestCommonSuperstring,
+        StackerCrane, StaffScheduling, StringToStringCorrection, SubsetProduct, SubsetSum,
+        SumOfSquaresPartition, Term, ThreePartition, TimetableDesign,
     };
     pub use crate::models::set::{
         ComparativeContainment, ConsecutiveSets, ExactCoverBy3Sets, IntegerKnapsack,
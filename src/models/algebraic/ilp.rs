@@ -59,36 +59,91 @@ pub enum Comparison {
     Ge,
     /// Equal (==).
     Eq,
+    /// Ranged (lo <= lhs <= rhs); the lower bound is stored on the
+    /// [`LinearConstraint`] and exposed by [`LinearConstraint::lower_bound`].
+    Range,
 }
 
 impl Comparison {
     /// Check if the comparison holds between lhs and rhs.
+    ///
+    /// For [`Comparison::Range`] only the upper side `lhs <= rhs` is checked;
+    /// use [`LinearConstraint::is_satisfied`] to check both sides.
     pub fn holds(&self, lhs: f64, rhs: f64) -> bool {
         match self {
-            Comparison::Le => lhs <= rhs,
+            Comparison::Le | Comparison::Range => lhs <= rhs,
             Comparison::Ge => lhs >= rhs,
             Comparison::Eq => (lhs - rhs).abs() < 1e-9,
         }
     }
 }
 
-/// A linear constraint: sum of (coefficient * variable) {<=, >=, ==} rhs.
+/// A linear constraint: sum of (coefficient * variable) {<=, >=, ==} rhs,
+/// or the ranged form lo <= sum of (coefficient * variable) <= rhs.
 ///
 /// The constraint is represented sparsely: only non-zero coefficients are stored.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "LinearConstraintDef")]
 pub struct LinearConstraint {
     /// Sparse representation: (var_index, coefficient) pairs.
     pub terms: Vec<(usize, f64)>,
     /// Comparison operator.
     pub cmp: Comparison,
-    /// Right-hand side constant.
+    /// Right-hand side constant (the upper bound of a ranged constraint).
     pub rhs: f64,
+    /// Lower bound of a [`Comparison::Range`] row; `None` for all other rows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lo: Option<f64>,
 }
 
 impl LinearConstraint {
     /// Create a new linear constraint.
     pub(crate) fn new(terms: Vec<(usize, f64)>, cmp: Comparison, rhs: f64) -> Self {
-        Self { terms, cmp, rhs }
+        debug_assert!(cmp != Comparison::Range, "use LinearConstraint::range");
+        Self {
+            terms,
+            cmp,
+            rhs,
+            lo: None,
+        }
+    }
+
+    /// Create a ranged constraint `lo <= lhs <= hi`.
+    ///
+    /// This replaces a `ge`/`le` pair over the same terms with a single row.
+    pub fn range(terms: Vec<(usize, f64)>, lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "range lower bound {lo} exceeds upper bound {hi}");
+        Self {
+            terms,
+            cmp: Comparison::Range,
+            rhs: hi,
+            lo: Some(lo),
+        }
+    }
+
+    /// Copy this constraint's comparison and bounds onto new terms.
+    pub(crate) fn with_terms(&self, terms: Vec<(usize, f64)>) -> Self {
+        Self {
+            terms,
+            ..self.clone()
+        }
+    }
+
+    /// Lower bound on the left-hand side, if any (`Ge`, `Eq` and `Range`).
+    pub fn lower_bound(&self) -> Option<f64> {
+        match self.cmp {
+            Comparison::Le => None,
+            Comparison::Ge | Comparison::Eq => Some(self.rhs),
+            Comparison::Range => self.lo,
+        }
+    }
+
+    /// Upper bound on the left-hand side, if any (`Le`, `Eq` and `Range`).
+    pub fn upper_bound(&self) -> Option<f64> {
+        match self.cmp {
+            Comparison::Ge => None,
+            Comparison::Le | Comparison::Eq | Comparison::Range => Some(self.rhs),
+        }
     }
 
     /// Create a less-than-or-equal constraint.
@@ -117,7 +172,7 @@ impl LinearConstraint {
     /// Check if the constraint is satisfied by given variable values.
     pub fn is_satisfied(&self, values: &[i64]) -> bool {
         let lhs = self.evaluate_lhs(values);
-        self.cmp.holds(lhs, self.rhs)
+        self.cmp.holds(lhs, self.rhs) && self.lo.is_none_or(|lo| lo <= lhs)
     }
 
     /// Get the set of variable indices involved in this constraint.
@@ -126,6 +181,39 @@ impl LinearConstraint {
     }
 }
 
+#[derive(Deserialize)]
+struct LinearConstraintDef {
+    terms: Vec<(usize, f64)>,
+    cmp: Comparison,
+    rhs: f64,
+    #[serde(default)]
+    lo: Option<f64>,
+}
+
+impl TryFrom<LinearConstraintDef> for LinearConstraint {
+    type Error = String;
+
+    fn try_from(value: LinearConstraintDef) -> Result<Self, Self::Error> {
+        let LinearConstraintDef {
+            terms,
+            cmp,
+            rhs,
+            lo,
+        } = value;
+        match (cmp, lo) {
+            (Comparison::Range, Some(lo)) if lo <= rhs => Ok(Self::range(terms, lo, rhs)),
+            (Comparison::Range, Some(lo)) => {
+                Err(format!("range lower bound {lo} exceeds upper bound {rhs}"))
+            }
+            (Comparison::Range, None) => Err("ranged constraint requires `lo`".to_string()),
+            (_, Some(_)) => Err(format!(
+                "`lo` is only allowed on ranged constraints, not {cmp:?}"
+            )),
+            (_, None) => Ok(Self::new(terms, cmp, rhs)),
+        }
+    }
+}
+
 /// Optimization direction for the ILP.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ObjectiveSense {
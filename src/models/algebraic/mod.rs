@@ -4,6 +4,7 @@
 //! - [`AlgebraicEquationsOverGF2`]: Multilinear polynomial equations over GF(2)
 //! - [`QUBO`]: Quadratic Unconstrained Binary Optimization
 //! - [`ILP`]: Integer Linear Programming
+//! - [`PseudoBoolean`]: Pseudo-Boolean Optimization (binary variables, integer linear data, OPB)
 //! - [`ClosestVectorProblem`]: Closest Vector Problem (minimize lattice distance)
 //! - [`BMF`]: Boolean Matrix Factorization
 //! - [`ConsecutiveBlockMinimization`]: Consecutive Block Minimization
@@ -31,6 +32,7 @@ pub(crate) mod minimum_matrix_cover;
 pub(crate) mod minimum_matrix_domination;
 pub(crate) mod minimum_weight_decoding;
 pub(crate) mod minimum_weight_solution_to_linear_equations;
+pub(crate) mod pseudo_boolean;
 pub(crate) mod quadratic_assignment;
 pub(crate) mod quadratic_congruences;
 pub(crate) mod quadratic_diophantine_equations;
@@ -51,6 +53,7 @@ pub use minimum_matrix_cover::MinimumMatrixCover;
 pub use minimum_matrix_domination::MinimumMatrixDomination;
 pub use minimum_weight_decoding::MinimumWeightDecoding;
 pub use minimum_weight_solution_to_linear_equations::MinimumWeightSolutionToLinearEquations;
+pub use pseudo_boolean::{PseudoBoolean, PseudoBooleanComparison, PseudoBooleanConstraint};
 pub use quadratic_assignment::QuadraticAssignment;
 pub use quadratic_congruences::QuadraticCongruences;
 pub use quadratic_diophantine_equations::QuadraticDiophantineEquations;
@@ -64,6 +67,7 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(algebraic_equations_over_gf2::canonical_model_example_specs());
     specs.extend(qubo::canonical_model_example_specs());
     specs.extend(ilp::canonical_model_example_specs());
+    specs.extend(pseudo_boolean::canonical_model_example_specs());
     specs.extend(closest_vector_problem::canonical_model_example_specs());
     specs.extend(bmf::canonical_model_example_specs());
     specs.extend(consecutive_block_minimization::canonical_model_example_specs());
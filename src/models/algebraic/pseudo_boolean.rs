@@ -0,0 +1,296 @@
+//! Pseudo-Boolean Optimization (PBO) problem implementation.
+//!
+//! PBO minimizes a linear objective with integer coefficients over binary
+//! variables subject to linear constraints with integer coefficients. It is
+//! the problem spoken by the OPB file format of the pseudo-Boolean solver
+//! competitions; see [`crate::io::to_opb`] and [`crate::io::from_opb`].
+//!
+//! Semantically this is a binary [`ILP`](super::ILP) restricted to integer data
+//! and minimization, which also makes it a natural bridge between ILP and SAT
+//! encodings.
+
+use crate::models::algebraic::Comparison;
+use crate::registry::{FieldInfo, ProblemSchemaEntry};
+use crate::traits::Problem;
+use crate::types::Min;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "PseudoBoolean",
+        display_name: "Pseudo-Boolean Optimization",
+        aliases: &["PBO"],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Minimize a linear integer objective over binary variables subject to linear integer constraints",
+        fields: &[
+            FieldInfo { name: "num_vars", type_name: "usize", description: "Number of binary variables" },
+            FieldInfo { name: "objective", type_name: "Vec<(usize, i64)>", description: "Sparse objective coefficients (minimized)" },
+            FieldInfo { name: "constraints", type_name: "Vec<PseudoBooleanConstraint>", description: "Linear constraints with integer coefficients" },
+        ],
+    }
+}
+
+/// Comparison operator for pseudo-Boolean constraints.
+///
+/// Unlike the ILP [`Comparison`], there is no ranged form: OPB files only
+/// speak `>=`, `<=` and `=`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PseudoBooleanComparison {
+    /// Less than or equal (<=).
+    Le,
+    /// Greater than or equal (>=).
+    Ge,
+    /// Equal (=).
+    Eq,
+}
+
+impl From<PseudoBooleanComparison> for Comparison {
+    fn from(cmp: PseudoBooleanComparison) -> Self {
+        match cmp {
+            PseudoBooleanComparison::Le => Comparison::Le,
+            PseudoBooleanComparison::Ge => Comparison::Ge,
+            PseudoBooleanComparison::Eq => Comparison::Eq,
+        }
+    }
+}
+
+/// A linear pseudo-Boolean constraint `Σ a_i x_i {>=, <=, =} b` with integer
+/// coefficients, stored sparsely.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PseudoBooleanConstraint {
+    /// Sparse representation: (var_index, coefficient) pairs.
+    pub terms: Vec<(usize, i64)>,
+    /// Comparison operator.
+    pub cmp: PseudoBooleanComparison,
+    /// Right-hand side constant.
+    pub rhs: i64,
+}
+
+impl PseudoBooleanConstraint {
+    /// Create a greater-than-or-equal constraint.
+    pub fn ge(terms: Vec<(usize, i64)>, rhs: i64) -> Self {
+        Self {
+            terms,
+            cmp: PseudoBooleanComparison::Ge,
+            rhs,
+        }
+    }
+
+    /// Create a less-than-or-equal constraint.
+    pub fn le(terms: Vec<(usize, i64)>, rhs: i64) -> Self {
+        Self {
+            terms,
+            cmp: PseudoBooleanComparison::Le,
+            rhs,
+        }
+    }
+
+    /// Create an equality constraint.
+    pub fn eq(terms: Vec<(usize, i64)>, rhs: i64) -> Self {
+        Self {
+            terms,
+            cmp: PseudoBooleanComparison::Eq,
+            rhs,
+        }
+    }
+
+    /// Evaluate the left-hand side for a binary configuration.
+    pub fn evaluate_lhs(&self, config: &[usize]) -> i64 {
+        self.terms
+            .iter()
+            .map(|&(var, coef)| coef * config.get(var).copied().unwrap_or(0) as i64)
+            .sum()
+    }
+
+    /// Largest slack `|lhs - rhs|` a satisfying assignment can leave, or 0 for
+    /// equalities.
+    pub(crate) fn slack_range(&self) -> i64 {
+        let min_lhs: i64 = self.terms.iter().map(|&(_, a)| a.min(0)).sum();
+        let max_lhs: i64 = self.terms.iter().map(|&(_, a)| a.max(0)).sum();
+        match self.cmp {
+            PseudoBooleanComparison::Ge => max_lhs - self.rhs,
+            PseudoBooleanComparison::Le => self.rhs - min_lhs,
+            PseudoBooleanComparison::Eq => 0,
+        }
+    }
+
+    /// Check if the constraint is satisfied by a binary configuration.
+    pub fn is_satisfied(&self, config: &[usize]) -> bool {
+        let lhs = self.evaluate_lhs(config);
+        match self.cmp {
+            PseudoBooleanComparison::Ge => lhs >= self.rhs,
+            PseudoBooleanComparison::Le => lhs <= self.rhs,
+            PseudoBooleanComparison::Eq => lhs == self.rhs,
+        }
+    }
+}
+
+/// Number of bits needed to represent `0..=range` (zero for an empty range).
+pub(crate) fn slack_bits(range: i64) -> usize {
+    if range <= 0 {
+        0
+    } else {
+        (u64::BITS - (range as u64).leading_zeros()) as usize
+    }
+}
+
+/// The Pseudo-Boolean Optimization problem.
+///
+/// Given binary variables `x_0, ..., x_{n-1}`, integer objective coefficients
+/// `c` and linear constraints with integer coefficients, find an assignment
+/// minimizing `Σ c_i x_i` subject to every constraint. With an empty
+/// objective this is the pseudo-Boolean satisfaction problem: every feasible
+/// assignment has value 0.
+///
+/// Maximization is expressed by negating the objective, as in OPB files.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::algebraic::{PseudoBoolean, PseudoBooleanConstraint};
+/// use problemreductions::types::Min;
+/// use problemreductions::{BruteForce, Solver};
+///
+/// // minimize x0 + 2 x1 + 3 x2  s.t.  x0 + x1 + x2 >= 2,  x0 + x1 <= 1
+/// let problem = PseudoBoolean::new(
+///     3,
+///     vec![(0, 1), (1, 2), (2, 3)],
+///     vec![
+///         PseudoBooleanConstraint::ge(vec![(0, 1), (1, 1), (2, 1)], 2),
+///         PseudoBooleanConstraint::le(vec![(0, 1), (1, 1)], 1),
+///     ],
+/// );
+/// assert_eq!(BruteForce::new().solve(&problem), Min(Some(4)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PseudoBoolean {
+    /// Number of binary variables.
+    num_vars: usize,
+    /// Sparse objective coefficients: (var_index, coefficient).
+    objective: Vec<(usize, i64)>,
+    /// Linear constraints.
+    constraints: Vec<PseudoBooleanConstraint>,
+}
+
+impl PseudoBoolean {
+    /// Create a new Pseudo-Boolean Optimization instance.
+    ///
+    /// # Panics
+    /// Panics if a term refers to a variable `>= num_vars`.
+    pub fn new(
+        num_vars: usize,
+        objective: Vec<(usize, i64)>,
+        constraints: Vec<PseudoBooleanConstraint>,
+    ) -> Self {
+        let terms = objective
+            .iter()
+            .chain(constraints.iter().flat_map(|c| &c.terms));
+        for &(var, _) in terms {
+            assert!(
+                var < num_vars,
+                "variable index {var} out of range for {num_vars} variables"
+            );
+        }
+        Self {
+            num_vars,
+            objective,
+            constraints,
+        }
+    }
+
+    /// Get the number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Get the number of constraints.
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Get the sparse objective coefficients.
+    pub fn objective(&self) -> &[(usize, i64)] {
+        &self.objective
+    }
+
+    /// Get the constraints.
+    pub fn constraints(&self) -> &[PseudoBooleanConstraint] {
+        &self.constraints
+    }
+
+    /// Number of binary slack bits needed to turn every inequality into an
+    /// equality, as used by the QUBO penalty encoding.
+    ///
+    /// A `>=` constraint needs a slack ranging over `0..=max_lhs - rhs` and a
+    /// `<=` constraint one over `0..=rhs - min_lhs`, each in binary.
+    pub fn num_slack_bits(&self) -> usize {
+        self.constraints
+            .iter()
+            .map(|c| slack_bits(c.slack_range()))
+            .sum()
+    }
+
+    /// Evaluate the objective for a binary configuration, ignoring feasibility.
+    pub fn evaluate_objective(&self, config: &[usize]) -> i64 {
+        self.objective
+            .iter()
+            .map(|&(var, coef)| coef * config.get(var).copied().unwrap_or(0) as i64)
+            .sum()
+    }
+
+    /// Check if a configuration is binary, has the right length and
+    /// satisfies every constraint.
+    pub fn is_feasible(&self, config: &[usize]) -> bool {
+        config.len() == self.num_vars
+            && config.iter().all(|&x| x <= 1)
+            && self.constraints.iter().all(|c| c.is_satisfied(config))
+    }
+}
+
+impl Problem for PseudoBoolean {
+    const NAME: &'static str = "PseudoBoolean";
+    type Value = Min<i64>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.num_vars]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<i64> {
+        if !self.is_feasible(config) {
+            return Min(None);
+        }
+        Min(Some(self.evaluate_objective(config)))
+    }
+}
+
+crate::declare_variants! {
+    default PseudoBoolean => "2^num_vars",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // Pick at least two of three items but not both of the first two; the
+    // cheapest choice drops the middle item.
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "pseudo_boolean",
+        instance: Box::new(PseudoBoolean::new(
+            3,
+            vec![(0, 1), (1, 2), (2, 3)],
+            vec![
+                PseudoBooleanConstraint::ge(vec![(0, 1), (1, 1), (2, 1)], 2),
+                PseudoBooleanConstraint::le(vec![(0, 1), (1, 1)], 1),
+            ],
+        )),
+        optimal_config: vec![1, 0, 1],
+        optimal_value: serde_json::json!(4),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/algebraic/pseudo_boolean.rs"]
+mod tests;
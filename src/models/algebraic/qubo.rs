@@ -100,6 +100,54 @@ impl<W: Clone + Default> QUBO<W> {
         Self { num_vars, matrix }
     }
 
+    /// Create a QUBO from sparse linear and quadratic term lists.
+    ///
+    /// Repeated terms are summed. A quadratic term `(i, i, w)` is added to
+    /// the diagonal, since `x_i * x_i = x_i` for binary `x_i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any term references a variable index `>= num_vars`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::models::algebraic::QUBO;
+    ///
+    /// // minimize x0 - 2*x1 + 3*x0*x1 over 3 variables
+    /// let qubo = QUBO::from_terms(3, &[(0, 1.0), (1, -2.0)], &[(1, 0, 3.0)]);
+    /// assert_eq!(qubo.get(0, 1), Some(&3.0));
+    /// assert_eq!(qubo.evaluate(&[1, 1, 0]), 2.0);
+    /// ```
+    pub fn from_terms(
+        num_vars: usize,
+        linear: &[(usize, W)],
+        quadratic: &[(usize, usize, W)],
+    ) -> Self
+    where
+        W: num_traits::Zero + std::ops::AddAssign,
+    {
+        let check = |i: usize| {
+            assert!(
+                i < num_vars,
+                "QUBO term references variable {i} >= num_vars ({num_vars})"
+            );
+        };
+        let mut matrix = vec![vec![W::zero(); num_vars]; num_vars];
+        for (i, w) in linear {
+            check(*i);
+            matrix[*i][*i] += w.clone();
+        }
+        for (i, j, w) in quadratic {
+            check(*i);
+            check(*j);
+            let (lo, hi) = if i <= j { (*i, *j) } else { (*j, *i) };
+            matrix[lo][hi] += w.clone();
+        }
+
+        Self { num_vars, matrix }
+    }
+
     /// Get the number of variables.
     pub fn num_vars(&self) -> usize {
         self.num_vars
@@ -114,6 +162,83 @@ impl<W: Clone + Default> QUBO<W> {
     pub fn get(&self, i: usize, j: usize) -> Option<&W> {
         self.matrix.get(i).and_then(|row| row.get(j))
     }
+
+    /// Number of non-zero off-diagonal entries `Q[i][j]` with `i < j`.
+    pub fn num_interactions(&self) -> usize
+    where
+        W: num_traits::Zero,
+    {
+        self.matrix
+            .iter()
+            .enumerate()
+            .map(|(i, row)| row.iter().skip(i + 1).filter(|q| !q.is_zero()).count())
+            .sum()
+    }
+
+    /// Number of non-zero entries `Q[i][j]` with `i <= j`, counting both
+    /// linear (diagonal) and quadratic terms.
+    pub fn nonzero_terms(&self) -> usize
+    where
+        W: num_traits::Zero,
+    {
+        self.matrix
+            .iter()
+            .enumerate()
+            .map(|(i, row)| row.iter().skip(i).filter(|q| !q.is_zero()).count())
+            .sum()
+    }
+
+    /// Fraction of the `n(n+1)/2` upper-triangular entries that are non-zero.
+    ///
+    /// `n` non-zero linear terms without couplings give density `2/(n+1)`;
+    /// a fully coupled QUBO has density `1.0`. An empty QUBO has density `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::models::algebraic::QUBO;
+    ///
+    /// let qubo = QUBO::from_terms(3, &[(0, 1.0), (2, -1.0)], &[(0, 1, 2.0)]);
+    /// assert_eq!(qubo.nonzero_terms(), 3);
+    /// assert_eq!(qubo.density(), 0.5);
+    /// ```
+    pub fn density(&self) -> f64
+    where
+        W: num_traits::Zero,
+    {
+        let n = self.num_vars;
+        if n == 0 {
+            return 0.0;
+        }
+        self.nonzero_terms() as f64 / (n * (n + 1) / 2) as f64
+    }
+
+    /// The non-zero entries as sparse `(linear, quadratic)` term lists.
+    ///
+    /// Linear terms are `(i, Q[i][i])`; quadratic terms are `(i, j, Q[i][j])`
+    /// with `i < j`, both in row-major order. This is the inverse of
+    /// [`QUBO::from_terms`].
+    #[allow(clippy::type_complexity)]
+    pub fn to_sparse_terms(&self) -> (Vec<(usize, W)>, Vec<(usize, usize, W)>)
+    where
+        W: num_traits::Zero,
+    {
+        let mut linear = Vec::new();
+        let mut quadratic = Vec::new();
+        for (i, row) in self.matrix.iter().enumerate() {
+            for (j, q) in row.iter().enumerate().skip(i) {
+                if q.is_zero() {
+                    continue;
+                }
+                if i == j {
+                    linear.push((i, q.clone()));
+                } else {
+                    quadratic.push((i, j, q.clone()));
+                }
+            }
+        }
+        (linear, quadratic)
+    }
 }
 
 impl<W> QUBO<W>
@@ -3,7 +3,10 @@
 //! Given a list of pairs (aᵢ, bᵢ) with bᵢ > 0 and 1 ≤ aᵢ ≤ bᵢ, determine whether
 //! there exists a non-negative integer x such that x ≢ aᵢ (mod bᵢ) for all i.
 
-use crate::registry::{FieldInfo, ProblemSchemaEntry, ProblemSizeFieldEntry};
+use crate::registry::{
+    ComplexityClass, FieldInfo, ProblemComplexityClassEntry, ProblemSchemaEntry,
+    ProblemSizeFieldEntry,
+};
 use crate::traits::Problem;
 use crate::types::Or;
 use serde::de::Error as _;
@@ -34,6 +37,13 @@ inventory::submit! {
     }
 }
 
+inventory::submit! {
+    ProblemComplexityClassEntry {
+        name: "SimultaneousIncongruences",
+        class: ComplexityClass::NpComplete,
+    }
+}
+
 /// Simultaneous Incongruences problem.
 ///
 /// Given a list of pairs (aᵢ, bᵢ) with bᵢ > 0 and 1 ≤ aᵢ ≤ bᵢ, determine whether
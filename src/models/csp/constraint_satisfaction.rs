@@ -0,0 +1,242 @@
+//! Generic Constraint Satisfaction Problem (CSP) implementation.
+//!
+//! A CSP has variables over finite domains and a list of constraints, each
+//! restricting the values a tuple of variables may take together. Graph
+//! coloring, list coloring, and SAT are all CSPs with particular constraint
+//! shapes.
+
+use crate::config::DimsIterator;
+use crate::registry::{FieldInfo, ProblemSchemaEntry};
+use crate::traits::Problem;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "CSP",
+        display_name: "Constraint Satisfaction",
+        aliases: &["ConstraintSatisfaction"],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Find an assignment of finite-domain variables satisfying every constraint",
+        fields: &[
+            FieldInfo { name: "domains", type_name: "Vec<usize>", description: "Domain size of each variable; variable i takes values 0..domains[i]" },
+            FieldInfo { name: "constraints", type_name: "Vec<CspConstraint>", description: "Constraints as (scope, allowed tuples) pairs" },
+        ],
+    }
+}
+
+/// A constraint over a tuple of variables.
+///
+/// The constraint is stored extensionally: `allowed` lists every value tuple
+/// the variables in `scope` may take together, in scope order. Use
+/// [`CspConstraint::from_predicate`] to build the table from a predicate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CspConstraint {
+    /// Variables the constraint ranges over (0-indexed).
+    pub scope: Vec<usize>,
+    /// Allowed value tuples, each of length `scope.len()`.
+    pub allowed: Vec<Vec<usize>>,
+}
+
+impl CspConstraint {
+    /// Create a constraint from its scope and allowed tuples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some allowed tuple does not match the scope length.
+    pub fn new(scope: Vec<usize>, allowed: Vec<Vec<usize>>) -> Self {
+        for tuple in &allowed {
+            assert_eq!(
+                tuple.len(),
+                scope.len(),
+                "allowed tuple {tuple:?} does not match scope {scope:?}"
+            );
+        }
+        Self { scope, allowed }
+    }
+
+    /// Create a constraint allowing exactly the tuples over `scope` on which
+    /// `predicate` holds, with each variable ranging over its domain in
+    /// `domains`.
+    pub fn from_predicate(
+        scope: Vec<usize>,
+        domains: &[usize],
+        predicate: impl Fn(&[usize]) -> bool,
+    ) -> Self {
+        let dims = scope.iter().map(|&v| domains[v]).collect();
+        let allowed = DimsIterator::new(dims)
+            .filter(|tuple| predicate(tuple))
+            .collect();
+        Self::new(scope, allowed)
+    }
+
+    /// Number of variables in the scope.
+    pub fn arity(&self) -> usize {
+        self.scope.len()
+    }
+
+    /// Check whether `config` assigns the scope an allowed tuple.
+    ///
+    /// Variables outside `config` make the constraint unsatisfied.
+    pub fn is_satisfied(&self, config: &[usize]) -> bool {
+        let Some(tuple) = self
+            .scope
+            .iter()
+            .map(|&v| config.get(v).copied())
+            .collect::<Option<Vec<usize>>>()
+        else {
+            return false;
+        };
+        self.allowed.contains(&tuple)
+    }
+}
+
+/// Check whether `config` gives every variable a value in its domain and
+/// satisfies every constraint.
+pub fn is_satisfying_csp(
+    domains: &[usize],
+    constraints: &[CspConstraint],
+    config: &[usize],
+) -> bool {
+    config.len() == domains.len()
+        && config
+            .iter()
+            .zip(domains)
+            .all(|(&value, &size)| value < size)
+        && constraints.iter().all(|c| c.is_satisfied(config))
+}
+
+/// The generic Constraint Satisfaction Problem.
+///
+/// Given variables `x_0, ..., x_{n-1}` with finite domains
+/// `x_i ∈ {0, ..., d_i - 1}` and constraints `(S_j, R_j)` where `R_j` is the
+/// set of allowed value tuples for the variables in scope `S_j`, determine
+/// whether some assignment satisfies every constraint.
+///
+/// # Variables
+///
+/// One variable per CSP variable, with dimension equal to its domain size.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::csp::{CspConstraint, CSP};
+/// use problemreductions::{Problem, Solver, BruteForce};
+///
+/// // Properly 2-color the path 0 - 1 - 2
+/// let domains = vec![2; 3];
+/// let differ = |t: &[usize]| t[0] != t[1];
+/// let constraints = vec![
+///     CspConstraint::from_predicate(vec![0, 1], &domains, differ),
+///     CspConstraint::from_predicate(vec![1, 2], &domains, differ),
+/// ];
+/// let problem = CSP::new(domains, constraints);
+///
+/// let solver = BruteForce::new();
+/// let witness = solver.find_witness(&problem).unwrap();
+/// assert!(problem.is_satisfying(&witness));
+/// assert_eq!(witness[0], witness[2]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CSP {
+    /// Domain size of each variable.
+    domains: Vec<usize>,
+    /// Constraints over tuples of variables.
+    constraints: Vec<CspConstraint>,
+}
+
+impl CSP {
+    /// Create a new CSP.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a constraint mentions a variable outside `0..domains.len()`.
+    pub fn new(domains: Vec<usize>, constraints: Vec<CspConstraint>) -> Self {
+        for constraint in &constraints {
+            for &v in &constraint.scope {
+                assert!(
+                    v < domains.len(),
+                    "constraint variable {v} out of range for {} variables",
+                    domains.len()
+                );
+            }
+        }
+        Self {
+            domains,
+            constraints,
+        }
+    }
+
+    /// Get the domain size of each variable.
+    pub fn domains(&self) -> &[usize] {
+        &self.domains
+    }
+
+    /// Get the constraints.
+    pub fn constraints(&self) -> &[CspConstraint] {
+        &self.constraints
+    }
+
+    /// Get the number of constraints.
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Get the largest domain size (0 when there are no variables).
+    pub fn max_domain_size(&self) -> usize {
+        self.domains.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Check whether an assignment satisfies every constraint.
+    pub fn is_satisfying(&self, config: &[usize]) -> bool {
+        is_satisfying_csp(&self.domains, &self.constraints, config)
+    }
+}
+
+impl Problem for CSP {
+    const NAME: &'static str = "CSP";
+    type Value = crate::types::Or;
+
+    fn dims(&self) -> Vec<usize> {
+        self.domains.clone()
+    }
+
+    fn evaluate(&self, config: &[usize]) -> crate::types::Or {
+        crate::types::Or(self.is_satisfying(config))
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+}
+
+crate::declare_variants! {
+    default CSP => "max_domain_size^num_variables",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // List coloring of the triangle 0-1-2 with a pendant vertex 3 on vertex 2.
+    // Vertices 0 and 1 may use colors {0, 1}, vertex 2 colors {0, 1, 2}, and a
+    // unary constraint pins vertex 3 to color 1. Vertices 0 and 1 use up
+    // colors 0 and 1, forcing vertex 2 to color 2.
+    let domains = vec![2, 2, 3, 3];
+    let differ = |t: &[usize]| t[0] != t[1];
+    let constraints = vec![
+        CspConstraint::from_predicate(vec![0, 1], &domains, differ),
+        CspConstraint::from_predicate(vec![0, 2], &domains, differ),
+        CspConstraint::from_predicate(vec![1, 2], &domains, differ),
+        CspConstraint::from_predicate(vec![2, 3], &domains, differ),
+        CspConstraint::new(vec![3], vec![vec![1]]),
+    ];
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "csp",
+        instance: Box::new(CSP::new(domains, constraints)),
+        optimal_config: vec![0, 1, 2, 1],
+        optimal_value: serde_json::json!(true),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/csp/constraint_satisfaction.rs"]
+mod tests;
@@ -0,0 +1,14 @@
+//! Constraint satisfaction problems.
+//!
+//! Problems whose input is a set of finite-domain variables and constraints
+//! over tuples of them:
+//! - [`CSP`]: Generic constraint satisfaction with extensional (table) constraints
+
+pub(crate) mod constraint_satisfaction;
+
+pub use constraint_satisfaction::{is_satisfying_csp, CspConstraint, CSP};
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    constraint_satisfaction::canonical_model_example_specs()
+}
@@ -1,12 +1,14 @@
 //! Maximum 2-Satisfiability (MAX-2-SAT) problem implementation.
 //!
-//! MAX-2-SAT is an optimization variant of 2-SAT where each clause has exactly
-//! 2 literals, and the goal is to maximize the number of satisfied clauses.
-//! While 2-SAT (decision) is solvable in polynomial time, MAX-2-SAT is NP-hard.
+//! MAX-2-SAT is an optimization variant of 2-SAT where each clause has at most
+//! 2 literals and a weight, and the goal is to maximize the total weight of
+//! satisfied clauses. While 2-SAT (decision) is solvable in polynomial time,
+//! MAX-2-SAT is NP-hard even with unit weights.
 
-use crate::registry::{FieldInfo, ProblemSchemaEntry};
+use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
 use crate::traits::Problem;
-use crate::types::Max;
+use crate::types::{Max, One, WeightElement};
+use num_traits::Zero;
 use serde::{Deserialize, Serialize};
 
 use super::CNFClause;
@@ -16,29 +18,32 @@ inventory::submit! {
         name: "Maximum2Satisfiability",
         display_name: "Maximum 2-Satisfiability",
         aliases: &["MAX2SAT"],
-        dimensions: &[],
+        dimensions: &[VariantDimension::new("weight", "One", &["One", "i32"])],
         module_path: module_path!(),
-        description: "Maximize the number of satisfied 2-literal clauses",
+        description: "Maximize the total weight of satisfied clauses with at most 2 literals",
         fields: &[
             FieldInfo { name: "num_vars", type_name: "usize", description: "Number of Boolean variables" },
-            FieldInfo { name: "clauses", type_name: "Vec<CNFClause>", description: "Collection of 2-literal clauses" },
+            FieldInfo { name: "clauses", type_name: "Vec<CNFClause>", description: "Collection of clauses with at most 2 literals" },
+            FieldInfo { name: "weights", type_name: "Vec<W>", description: "Weight of each clause" },
         ],
     }
 }
 
-/// Maximum 2-Satisfiability problem where each clause has exactly 2 literals.
+/// Maximum 2-Satisfiability problem where each clause has at most 2 literals.
 ///
-/// Given a set of Boolean variables and a collection of clauses, each containing
-/// exactly 2 literals, find a truth assignment that maximizes the number of
-/// simultaneously satisfied clauses.
+/// Given a set of Boolean variables and a collection of weighted clauses, each
+/// containing at most 2 literals, find a truth assignment that maximizes the
+/// total weight of simultaneously satisfied clauses. With `W = One` this is
+/// the classic unweighted MAX-2-SAT counting satisfied clauses.
 ///
 /// # Example
 ///
 /// ```
 /// use problemreductions::models::formula::{Maximum2Satisfiability, CNFClause};
+/// use problemreductions::types::One;
 /// use problemreductions::{Problem, Solver, BruteForce};
 ///
-/// let problem = Maximum2Satisfiability::new(
+/// let problem = Maximum2Satisfiability::<One>::new(
 ///     3,
 ///     vec![
 ///         CNFClause::new(vec![1, 2]),    // x1 OR x2
@@ -51,28 +56,52 @@ inventory::submit! {
 /// let value = solver.solve(&problem);
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Maximum2Satisfiability {
+pub struct Maximum2Satisfiability<W> {
     /// Number of Boolean variables.
     num_vars: usize,
-    /// Clauses in CNF, each with exactly 2 literals.
+    /// Clauses in CNF, each with at most 2 literals.
     clauses: Vec<CNFClause>,
+    /// Weight of each clause.
+    weights: Vec<W>,
 }
 
-impl Maximum2Satisfiability {
-    /// Create a new MAX-2-SAT problem.
+impl<W: Clone + Default> Maximum2Satisfiability<W> {
+    /// Create a new MAX-2-SAT problem with unit clause weights.
     ///
     /// # Panics
-    /// Panics if any clause does not have exactly 2 literals.
-    pub fn new(num_vars: usize, clauses: Vec<CNFClause>) -> Self {
+    /// Panics if any clause has more than 2 literals.
+    pub fn new(num_vars: usize, clauses: Vec<CNFClause>) -> Self
+    where
+        W: From<i32>,
+    {
+        let weights = vec![W::from(1); clauses.len()];
+        Self::with_weights(num_vars, clauses, weights)
+    }
+
+    /// Create a new MAX-2-SAT problem with one weight per clause.
+    ///
+    /// # Panics
+    /// Panics if any clause has more than 2 literals, or if the number of
+    /// weights differs from the number of clauses.
+    pub fn with_weights(num_vars: usize, clauses: Vec<CNFClause>, weights: Vec<W>) -> Self {
         for (i, clause) in clauses.iter().enumerate() {
             assert!(
-                clause.len() == 2,
-                "Clause {} has {} literals, expected 2",
+                clause.len() <= 2,
+                "Clause {} has {} literals, expected at most 2",
                 i,
                 clause.len()
             );
         }
-        Self { num_vars, clauses }
+        assert_eq!(
+            weights.len(),
+            clauses.len(),
+            "weights length must match number of clauses"
+        );
+        Self {
+            num_vars,
+            clauses,
+            weights,
+        }
     }
 
     /// Get the number of variables.
@@ -90,6 +119,11 @@ impl Maximum2Satisfiability {
         &self.clauses
     }
 
+    /// Get the clause weights.
+    pub fn weights(&self) -> &[W] {
+        &self.weights
+    }
+
     /// Count satisfied clauses for an assignment.
     pub fn count_satisfied(&self, assignment: &[bool]) -> usize {
         self.clauses
@@ -99,33 +133,50 @@ impl Maximum2Satisfiability {
     }
 }
 
-impl Problem for Maximum2Satisfiability {
+impl<W: WeightElement> Maximum2Satisfiability<W> {
+    /// Total weight of the clauses satisfied by an assignment.
+    pub fn satisfied_weight(&self, assignment: &[bool]) -> W::Sum {
+        let mut total = W::Sum::zero();
+        for (clause, weight) in self.clauses.iter().zip(&self.weights) {
+            if clause.is_satisfied(assignment) {
+                total += weight.to_sum();
+            }
+        }
+        total
+    }
+}
+
+impl<W> Problem for Maximum2Satisfiability<W>
+where
+    W: WeightElement + crate::variant::VariantParam,
+{
     const NAME: &'static str = "Maximum2Satisfiability";
-    type Value = Max<usize>;
+    type Value = Max<W::Sum>;
 
     fn dims(&self) -> Vec<usize> {
         vec![2; self.num_vars]
     }
 
-    fn evaluate(&self, config: &[usize]) -> Max<usize> {
+    fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
         let assignment = super::config_to_assignment(config);
-        Max(Some(self.count_satisfied(&assignment)))
+        Max(Some(self.satisfied_weight(&assignment)))
     }
 
     fn variant() -> Vec<(&'static str, &'static str)> {
-        crate::variant_params![]
+        crate::variant_params![W]
     }
 }
 
 crate::declare_variants! {
-    default Maximum2Satisfiability => "2^(0.7905 * num_variables)",
+    default Maximum2Satisfiability<One> => "2^(0.7905 * num_variables)",
+    Maximum2Satisfiability<i32> => "2^(0.7905 * num_variables)",
 }
 
 #[cfg(feature = "example-db")]
 pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
     vec![crate::example_db::specs::ModelExampleSpec {
         id: "maximum_2_satisfiability",
-        instance: Box::new(Maximum2Satisfiability::new(
+        instance: Box::new(Maximum2Satisfiability::<One>::new(
             4,
             vec![
                 CNFClause::new(vec![1, 2]),
@@ -4,32 +4,38 @@
 //! - [`Satisfiability`]: Boolean satisfiability (SAT) with CNF clauses
 //! - [`NAESatisfiability`]: Not-All-Equal satisfiability with CNF clauses
 //! - [`KSatisfiability`]: K-SAT where each clause has exactly K literals
-//! - [`Maximum2Satisfiability`]: MAX-2-SAT — maximize satisfied 2-literal clauses
+//! - [`Maximum2Satisfiability`]: MAX-2-SAT — maximize the weight of satisfied clauses of at most 2 literals
+//! - [`ModelCounting`]: Count satisfying assignments of a CNF formula (#SAT)
 //! - [`NonTautology`]: Find a falsifying assignment for a DNF formula
 //! - [`OneInThreeSatisfiability`]: Exactly one literal true per clause (1-in-3 SAT)
 //! - [`Planar3Satisfiability`]: 3-SAT restricted to planar variable-clause incidence graphs
 //! - [`CircuitSAT`]: Boolean circuit satisfiability
 //! - [`QuantifiedBooleanFormulas`]: Quantified Boolean Formulas (QBF) — PSPACE-complete
+//! - [`XorSatisfiability`]: CNF-XOR SAT — CNF clauses plus XOR parity constraints
 
 pub(crate) mod circuit;
 pub(crate) mod ksat;
 pub(crate) mod maximum_2_satisfiability;
+pub(crate) mod model_counting;
 pub(crate) mod nae_satisfiability;
 pub(crate) mod non_tautology;
 pub(crate) mod one_in_three_satisfiability;
 pub(crate) mod planar_3_satisfiability;
 pub(crate) mod qbf;
 pub(crate) mod sat;
+pub(crate) mod xor_satisfiability;
 
 pub use circuit::{Assignment, BooleanExpr, BooleanOp, Circuit, CircuitSAT};
 pub use ksat::KSatisfiability;
 pub use maximum_2_satisfiability::Maximum2Satisfiability;
+pub use model_counting::ModelCounting;
 pub use nae_satisfiability::NAESatisfiability;
 pub use non_tautology::NonTautology;
 pub use one_in_three_satisfiability::OneInThreeSatisfiability;
 pub use planar_3_satisfiability::Planar3Satisfiability;
 pub use qbf::{QuantifiedBooleanFormulas, Quantifier};
 pub use sat::{CNFClause, Satisfiability};
+pub use xor_satisfiability::{XorClause, XorSatisfiability};
 
 /// Convert a binary config (0/1 per variable) to a boolean assignment.
 pub(crate) fn config_to_assignment(config: &[usize]) -> Vec<bool> {
@@ -43,10 +49,12 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(nae_satisfiability::canonical_model_example_specs());
     specs.extend(ksat::canonical_model_example_specs());
     specs.extend(maximum_2_satisfiability::canonical_model_example_specs());
+    specs.extend(model_counting::canonical_model_example_specs());
     specs.extend(circuit::canonical_model_example_specs());
     specs.extend(non_tautology::canonical_model_example_specs());
     specs.extend(one_in_three_satisfiability::canonical_model_example_specs());
     specs.extend(planar_3_satisfiability::canonical_model_example_specs());
     specs.extend(qbf::canonical_model_example_specs());
+    specs.extend(xor_satisfiability::canonical_model_example_specs());
     specs
 }
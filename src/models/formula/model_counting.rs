@@ -0,0 +1,166 @@
+//! Model Counting (#SAT) problem implementation.
+//!
+//! Model counting asks for the number of assignments that satisfy a CNF
+//! formula. It is #P-complete; this model exposes the count as an aggregate
+//! value so small instances can be counted exactly.
+
+use super::sat::{count_cnf_models, CNFClause, Satisfiability};
+use crate::registry::{
+    ComplexityClass, FieldInfo, ProblemComplexityClassEntry, ProblemSchemaEntry,
+    ProblemSizeFieldEntry,
+};
+use crate::traits::Problem;
+use crate::types::Sum;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "ModelCounting",
+        display_name: "Model Counting",
+        aliases: &[],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Count satisfying assignments of a CNF formula",
+        fields: &[
+            FieldInfo { name: "num_vars", type_name: "usize", description: "Number of Boolean variables" },
+            FieldInfo { name: "clauses", type_name: "Vec<CNFClause>", description: "Clauses in conjunctive normal form" },
+        ],
+    }
+}
+
+inventory::submit! {
+    ProblemSizeFieldEntry {
+        name: "ModelCounting",
+        fields: &["num_vars", "num_clauses", "num_literals"],
+    }
+}
+
+inventory::submit! {
+    ProblemComplexityClassEntry {
+        name: "ModelCounting",
+        class: ComplexityClass::NpHard,
+    }
+}
+
+/// Model Counting (#SAT) over a CNF formula.
+///
+/// # Representation
+///
+/// Variable `i` is the truth value of `x_{i+1}`. `evaluate` returns `Sum(1)`
+/// for a satisfying assignment and `Sum(0)` otherwise, so the aggregate over
+/// all configurations is the number of models. [`ModelCounting::count`]
+/// computes the same number with DPLL-style pruning instead of enumerating
+/// every assignment.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::formula::{CNFClause, ModelCounting};
+/// use problemreductions::types::Sum;
+/// use problemreductions::{BruteForce, Solver};
+///
+/// // (x1 OR x2) AND (NOT x1 OR x3): 4 of the 8 assignments satisfy it.
+/// let problem = ModelCounting::new(
+///     3,
+///     vec![CNFClause::new(vec![1, 2]), CNFClause::new(vec![-1, 3])],
+/// );
+/// assert_eq!(problem.count(), Some(4));
+/// assert_eq!(BruteForce::new().solve(&problem), Sum(4));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelCounting {
+    /// Number of variables.
+    num_vars: usize,
+    /// Clauses in CNF.
+    clauses: Vec<CNFClause>,
+}
+
+impl ModelCounting {
+    /// Create a new model counting problem.
+    pub fn new(num_vars: usize, clauses: Vec<CNFClause>) -> Self {
+        Self { num_vars, clauses }
+    }
+
+    /// Get the number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Get the number of clauses.
+    pub fn num_clauses(&self) -> usize {
+        self.clauses.len()
+    }
+
+    /// Get the total number of literal occurrences across all clauses.
+    pub fn num_literals(&self) -> usize {
+        self.clauses.iter().map(|c| c.len()).sum()
+    }
+
+    /// Get the clauses.
+    pub fn clauses(&self) -> &[CNFClause] {
+        &self.clauses
+    }
+
+    /// Count the satisfying assignments.
+    ///
+    /// See [`Satisfiability::count_models`]; `None` for formulas too large
+    /// to count or with a zero literal.
+    pub fn count(&self) -> Option<u64> {
+        count_cnf_models(self.num_vars, &self.clauses)
+    }
+}
+
+impl From<Satisfiability> for ModelCounting {
+    fn from(sat: Satisfiability) -> Self {
+        Self::new(sat.num_vars(), sat.clauses().to_vec())
+    }
+}
+
+impl Problem for ModelCounting {
+    const NAME: &'static str = "ModelCounting";
+    type Value = Sum<u64>;
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.num_vars]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Sum<u64> {
+        let assignment = super::config_to_assignment(config);
+        if self.clauses.iter().all(|c| c.is_satisfied(&assignment)) {
+            Sum(1)
+        } else {
+            Sum(0)
+        }
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+}
+
+crate::declare_variants! {
+    default ModelCounting => "2^num_variables",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // (x1 OR x2) AND (NOT x1 OR x3) AND (NOT x2 OR NOT x3) has exactly 2 models:
+    // (F, T, F) and (T, F, T).
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "model_counting",
+        instance: Box::new(ModelCounting::new(
+            3,
+            vec![
+                CNFClause::new(vec![1, 2]),
+                CNFClause::new(vec![-1, 3]),
+                CNFClause::new(vec![-2, -3]),
+            ],
+        )),
+        optimal_config: vec![0, 1, 0],
+        optimal_value: serde_json::json!(1),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/formula/model_counting.rs"]
+mod tests;
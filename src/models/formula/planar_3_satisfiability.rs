@@ -38,8 +38,8 @@ inventory::submit! {
 ///
 /// **Note:** Planarity of the incidence graph is NOT validated at construction
 /// time. Only the clause width (exactly 3 literals) and variable index range
-/// are validated. This is analogous to how `PlanarGraph` does not explicitly
-/// validate planarity in this codebase.
+/// are validated. Unlike `PlanarGraph`, which runs a planarity test on
+/// construction, the incidence graph is taken on trust.
 ///
 /// # Example
 ///
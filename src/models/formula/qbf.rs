@@ -9,7 +9,9 @@
 //! determine whether F is true.
 
 use crate::models::formula::CNFClause;
-use crate::registry::{FieldInfo, ProblemSchemaEntry};
+use crate::registry::{
+    ComplexityClass, FieldInfo, ProblemComplexityClassEntry, ProblemSchemaEntry,
+};
 use crate::traits::Problem;
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +31,13 @@ inventory::submit! {
     }
 }
 
+inventory::submit! {
+    ProblemComplexityClassEntry {
+        name: "QuantifiedBooleanFormulas",
+        class: ComplexityClass::PspaceComplete,
+    }
+}
+
 /// Quantifier type for QBF variables.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Quantifier {
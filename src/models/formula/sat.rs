@@ -122,6 +122,9 @@ pub struct Satisfiability {
 }
 
 impl Satisfiability {
+    /// Largest number of variables [`count_models`](Self::count_models) accepts.
+    pub const MAX_COUNT_VARS: usize = 30;
+
     /// Create a new SAT problem.
     pub fn new(num_vars: usize, clauses: Vec<CNFClause>) -> Self {
         Self { num_vars, clauses }
@@ -165,6 +168,21 @@ impl Satisfiability {
         self.clauses.iter().all(|c| c.is_satisfied(assignment))
     }
 
+    /// Count the satisfying assignments of the formula (#SAT).
+    ///
+    /// Uses DPLL-style search with unit propagation: a branch is cut as soon
+    /// as a clause is falsified, and once every clause is satisfied the
+    /// remaining free variables contribute a factor of two each without
+    /// being enumerated. Still exponential in the worst case, so intended for
+    /// small instances.
+    ///
+    /// Returns `None` if the formula has more than
+    /// [`MAX_COUNT_VARS`](Self::MAX_COUNT_VARS) variables or contains the
+    /// literal `0`, which names no variable.
+    pub fn count_models(&self) -> Option<u64> {
+        count_cnf_models(self.num_vars, &self.clauses)
+    }
+
     /// Check if a solution (config) is valid.
     ///
     /// For SAT, a valid solution is one that satisfies all clauses.
@@ -188,6 +206,20 @@ impl Problem for Satisfiability {
         })
     }
 
+    fn objective_terms(&self, config: &[usize]) -> Vec<crate::traits::ObjectiveTerm> {
+        let assignment = super::config_to_assignment(config);
+        self.clauses
+            .iter()
+            .enumerate()
+            .map(|(i, clause)| {
+                crate::traits::ObjectiveTerm::new(
+                    format!("clause {i}"),
+                    u8::from(clause.is_satisfied(&assignment)),
+                )
+            })
+            .collect()
+    }
+
     fn variant() -> Vec<(&'static str, &'static str)> {
         crate::variant_params![]
     }
@@ -197,6 +229,107 @@ crate::declare_variants! {
     default Satisfiability => "2^num_variables",
 }
 
+/// Count the assignments of `num_vars` variables that satisfy every clause.
+///
+/// Literals on variables outside `0..num_vars` read as false, matching
+/// [`CNFClause::is_satisfied`]. Returns `None` for formulas over more than
+/// [`Satisfiability::MAX_COUNT_VARS`] variables or with a zero literal.
+pub(crate) fn count_cnf_models(num_vars: usize, clauses: &[CNFClause]) -> Option<u64> {
+    if num_vars > Satisfiability::MAX_COUNT_VARS || clauses.iter().any(|c| c.literals.contains(&0))
+    {
+        return None;
+    }
+    let mut assignment = vec![None; num_vars];
+    Some(count_models_rec(clauses, &mut assignment))
+}
+
+/// Clause status under a partial assignment.
+enum ClauseState {
+    Satisfied,
+    Falsified,
+    Unit(i32),
+    Open(usize),
+}
+
+fn clause_state(clause: &CNFClause, assignment: &[Option<bool>]) -> ClauseState {
+    let mut unassigned = None;
+    let mut num_unassigned = 0;
+    for &lit in &clause.literals {
+        let var = lit.unsigned_abs() as usize - 1;
+        match assignment.get(var).copied().unwrap_or(Some(false)) {
+            Some(value) if value == (lit > 0) => return ClauseState::Satisfied,
+            Some(_) => {}
+            None => {
+                num_unassigned += 1;
+                unassigned = Some(lit);
+            }
+        }
+    }
+    match (num_unassigned, unassigned) {
+        (0, _) => ClauseState::Falsified,
+        (1, Some(lit)) => ClauseState::Unit(lit),
+        (_, Some(lit)) => ClauseState::Open(lit.unsigned_abs() as usize - 1),
+        (_, None) => unreachable!("unassigned literals were counted"),
+    }
+}
+
+fn count_models_rec(clauses: &[CNFClause], assignment: &mut [Option<bool>]) -> u64 {
+    // Unit propagation, recording forced variables so they can be undone.
+    let mut forced = Vec::new();
+    let branch_var = loop {
+        let mut unit = None;
+        let mut open = None;
+        let mut conflict = false;
+        for clause in clauses {
+            match clause_state(clause, assignment) {
+                ClauseState::Satisfied => {}
+                ClauseState::Falsified => {
+                    conflict = true;
+                    break;
+                }
+                ClauseState::Unit(lit) => {
+                    unit = Some(lit);
+                    break;
+                }
+                ClauseState::Open(var) => {
+                    open.get_or_insert(var);
+                }
+            }
+        }
+        if conflict {
+            break None;
+        }
+        match unit {
+            Some(lit) => {
+                let var = lit.unsigned_abs() as usize - 1;
+                assignment[var] = Some(lit > 0);
+                forced.push(var);
+            }
+            None => break Some(open),
+        }
+    };
+
+    let count = match branch_var {
+        // A clause is falsified.
+        None => 0,
+        // Every clause is satisfied: the free variables are unconstrained.
+        Some(None) => 1u64 << assignment.iter().filter(|v| v.is_none()).count(),
+        Some(Some(var)) => {
+            let mut total = 0;
+            for value in [false, true] {
+                assignment[var] = Some(value);
+                total += count_models_rec(clauses, assignment);
+            }
+            assignment[var] = None;
+            total
+        }
+    };
+    for var in forced {
+        assignment[var] = None;
+    }
+    count
+}
+
 /// Check if an assignment satisfies a SAT formula.
 ///
 /// # Arguments
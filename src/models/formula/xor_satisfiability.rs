@@ -0,0 +1,242 @@
+//! CNF-XOR Satisfiability problem implementation.
+//!
+//! CNF-XOR SAT extends ordinary SAT with parity constraints: besides CNF
+//! clauses, the formula contains XOR clauses that require an odd number of
+//! their literals to be true. Such constraints arise naturally in
+//! cryptanalysis and in hashing-based approximate model counting.
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry};
+use crate::traits::Problem;
+use serde::{Deserialize, Serialize};
+
+use super::CNFClause;
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "XorSatisfiability",
+        display_name: "CNF-XOR Satisfiability",
+        aliases: &["XORSAT", "CNFXOR"],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Find an assignment satisfying CNF clauses and XOR parity constraints",
+        fields: &[
+            FieldInfo { name: "num_vars", type_name: "usize", description: "Number of Boolean variables" },
+            FieldInfo { name: "clauses", type_name: "Vec<CNFClause>", description: "Clauses in conjunctive normal form" },
+            FieldInfo { name: "xor_clauses", type_name: "Vec<XorClause>", description: "Parity constraints requiring an odd number of true literals" },
+        ],
+    }
+}
+
+/// A parity (XOR) clause.
+///
+/// An XOR clause `l_1 ⊕ l_2 ⊕ ... ⊕ l_k` is satisfied when an odd number of
+/// its literals are true. Literals use the same signed, 1-indexed encoding
+/// as [`CNFClause`], so a negated literal flips the required parity of its
+/// variable. The empty XOR clause is never satisfied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct XorClause {
+    /// Literals in this clause (signed integers, 1-indexed).
+    pub literals: Vec<i32>,
+}
+
+impl XorClause {
+    /// Create a new XOR clause from literals.
+    pub fn new(literals: Vec<i32>) -> Self {
+        Self { literals }
+    }
+
+    /// Check if the clause is satisfied by an assignment.
+    ///
+    /// # Arguments
+    /// * `assignment` - Boolean assignment, 0-indexed
+    pub fn is_satisfied(&self, assignment: &[bool]) -> bool {
+        self.literals
+            .iter()
+            .filter(|&&lit| {
+                let var = lit.unsigned_abs() as usize - 1;
+                let value = assignment.get(var).copied().unwrap_or(false);
+                value == (lit > 0)
+            })
+            .count()
+            % 2
+            == 1
+    }
+
+    /// Get the variables involved in this clause (0-indexed).
+    pub fn variables(&self) -> Vec<usize> {
+        self.literals
+            .iter()
+            .map(|&lit| lit.unsigned_abs() as usize - 1)
+            .collect()
+    }
+
+    /// Get the number of literals.
+    pub fn len(&self) -> usize {
+        self.literals.len()
+    }
+
+    /// Check if the clause is empty.
+    pub fn is_empty(&self) -> bool {
+        self.literals.is_empty()
+    }
+}
+
+/// CNF-XOR Satisfiability problem.
+///
+/// Given a CNF formula and a list of XOR clauses over the same Boolean
+/// variables, determine whether some assignment satisfies every CNF clause
+/// and every parity constraint. With no CNF clauses this is XOR-SAT, which
+/// is solvable by Gaussian elimination over GF(2); the combination is
+/// NP-complete.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::formula::{CNFClause, XorClause, XorSatisfiability};
+/// use problemreductions::{Problem, Solver, BruteForce};
+///
+/// // x1 ⊕ x2 ⊕ x3 = 1, x1 ⊕ x2 = 0, and (¬x3)
+/// let problem = XorSatisfiability::new(
+///     3,
+///     vec![CNFClause::new(vec![-3])],
+///     vec![XorClause::new(vec![1, 2, 3]), XorClause::new(vec![1, -2])],
+/// );
+///
+/// // x3 = 0 forces x1 ⊕ x2 = 1, contradicting the second parity
+/// let solver = BruteForce::new();
+/// assert!(solver.find_witness(&problem).is_none());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XorSatisfiability {
+    /// Number of variables.
+    num_vars: usize,
+    /// Clauses in CNF.
+    clauses: Vec<CNFClause>,
+    /// Parity constraints.
+    #[serde(default)]
+    xor_clauses: Vec<XorClause>,
+}
+
+impl XorSatisfiability {
+    /// Create a new CNF-XOR SAT problem.
+    pub fn new(num_vars: usize, clauses: Vec<CNFClause>, xor_clauses: Vec<XorClause>) -> Self {
+        Self {
+            num_vars,
+            clauses,
+            xor_clauses,
+        }
+    }
+
+    /// Create a pure XOR-SAT problem with no CNF clauses.
+    pub fn from_xor_clauses(num_vars: usize, xor_clauses: Vec<XorClause>) -> Self {
+        Self::new(num_vars, vec![], xor_clauses)
+    }
+
+    /// Get the number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Get the number of CNF clauses.
+    pub fn num_clauses(&self) -> usize {
+        self.clauses.len()
+    }
+
+    /// Get the total number of literal occurrences across the CNF clauses.
+    pub fn num_literals(&self) -> usize {
+        self.clauses.iter().map(|c| c.len()).sum()
+    }
+
+    /// Get the largest number of literals in a single CNF clause.
+    pub fn max_clause_size(&self) -> usize {
+        self.clauses.iter().map(|c| c.len()).max().unwrap_or(0)
+    }
+
+    /// Get the number of XOR clauses.
+    pub fn num_xor_clauses(&self) -> usize {
+        self.xor_clauses.len()
+    }
+
+    /// Get the total number of literal occurrences across the XOR clauses.
+    pub fn num_xor_literals(&self) -> usize {
+        self.xor_clauses.iter().map(|c| c.len()).sum()
+    }
+
+    /// Get the CNF clauses.
+    pub fn clauses(&self) -> &[CNFClause] {
+        &self.clauses
+    }
+
+    /// Get the XOR clauses.
+    pub fn xor_clauses(&self) -> &[XorClause] {
+        &self.xor_clauses
+    }
+
+    /// Count how many CNF and XOR clauses are satisfied by an assignment.
+    pub fn count_satisfied(&self, assignment: &[bool]) -> usize {
+        self.clauses
+            .iter()
+            .filter(|c| c.is_satisfied(assignment))
+            .count()
+            + self
+                .xor_clauses
+                .iter()
+                .filter(|c| c.is_satisfied(assignment))
+                .count()
+    }
+
+    /// Check if an assignment satisfies every CNF and XOR clause.
+    pub fn is_satisfying(&self, assignment: &[bool]) -> bool {
+        self.clauses.iter().all(|c| c.is_satisfied(assignment))
+            && self.xor_clauses.iter().all(|c| c.is_satisfied(assignment))
+    }
+
+    /// Check if a solution (config) is valid.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        self.evaluate(config).0
+    }
+}
+
+impl Problem for XorSatisfiability {
+    const NAME: &'static str = "XorSatisfiability";
+    type Value = crate::types::Or;
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.num_vars]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> crate::types::Or {
+        crate::types::Or({
+            let assignment = super::config_to_assignment(config);
+            self.is_satisfying(&assignment)
+        })
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+}
+
+crate::declare_variants! {
+    default XorSatisfiability => "2^num_variables",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // Parities x1 ⊕ x2 ⊕ x3 = 1 and x2 ⊕ ¬x4 = 1 (i.e. x2 = x4) together
+    // with the CNF clauses (x1 ∨ x4) and (¬x1 ∨ ¬x3).
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "xor_satisfiability",
+        instance: Box::new(XorSatisfiability::new(
+            4,
+            vec![CNFClause::new(vec![1, 4]), CNFClause::new(vec![-1, -3])],
+            vec![XorClause::new(vec![1, 2, 3]), XorClause::new(vec![2, -4])],
+        )),
+        optimal_config: vec![1, 0, 0, 0],
+        optimal_value: serde_json::json!(true),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/formula/xor_satisfiability.rs"]
+mod tests;
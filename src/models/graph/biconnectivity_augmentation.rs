@@ -175,6 +175,10 @@ where
         vec![2; self.num_potential_edges()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> crate::types::Or {
         crate::types::Or({
             self.augmented_graph(config)
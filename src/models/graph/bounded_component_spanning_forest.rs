@@ -195,6 +195,10 @@ where
         vec![self.max_components; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> crate::types::Or {
         crate::types::Or(self.is_valid_solution(config))
     }
@@ -214,6 +214,10 @@ where
         vec![2; self.edge_list.len()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> crate::types::Or {
         crate::types::Or({
             let n = self.graph.num_vertices();
@@ -0,0 +1,216 @@
+//! Cluster Editing problem implementation.
+//!
+//! Given a graph with edge deletion costs, partition the vertices into
+//! clusters minimizing the cost of the edge edits that turn the graph into a
+//! disjoint union of cliques.
+
+use num_traits::{One as _, Zero};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    registry::{FieldInfo, ProblemSchemaEntry, VariantDimension},
+    topology::{Graph, SimpleGraph},
+    traits::Problem,
+    types::{Min, One, WeightElement},
+};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "ClusterEditing",
+        display_name: "Cluster Editing",
+        aliases: &["CorrelationClustering"],
+        dimensions: &[
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("weight", "i32", &["One", "i32"]),
+        ],
+        module_path: module_path!(),
+        description: "Partition vertices into clusters minimizing edge deletions plus insertions",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "G", description: "The underlying graph G=(V,E)" },
+            FieldInfo { name: "edge_weights", type_name: "Vec<W>", description: "Cost w: E -> R of deleting each edge" },
+        ],
+    }
+}
+
+/// The Cluster Editing problem.
+///
+/// Given a graph G = (V, E) with edge weights w_e, find a partition of V
+/// into clusters minimizing the number of disagreements: the total weight of
+/// edges between different clusters (deleted) plus the number of non-adjacent
+/// vertex pairs inside the same cluster (inserted). Equivalently, this is
+/// correlation clustering on the complete graph where edges of G are "similar"
+/// pairs and non-edges are "dissimilar" pairs of unit cost.
+///
+/// # Representation
+///
+/// Each vertex is assigned a cluster id in `0..n`. Every assignment is a
+/// feasible clustering; cluster ids are interchangeable, so an optimal
+/// partition corresponds to several optimal configurations.
+///
+/// # Type Parameters
+///
+/// * `G` - The graph type (e.g., `SimpleGraph`)
+/// * `W` - The weight type for edge deletions (e.g., `i32`, `One`)
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::ClusterEditing;
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::types::Min;
+/// use problemreductions::{BruteForce, Problem, Solver};
+///
+/// // Triangle 0-1-2 with a pendant edge (2, 3): deleting (2, 3) costs 1.
+/// let graph = SimpleGraph::new(4, vec![(0, 1), (0, 2), (1, 2), (2, 3)]);
+/// let problem = ClusterEditing::new(graph, vec![1i32; 4]);
+///
+/// let solution = BruteForce::new().find_witness(&problem).unwrap();
+/// assert_eq!(problem.evaluate(&solution), Min(Some(1)));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterEditing<G, W> {
+    /// The underlying graph.
+    graph: G,
+    /// Deletion cost for each edge (in edge index order).
+    edge_weights: Vec<W>,
+}
+
+impl<G: Graph, W: Clone + Default> ClusterEditing<G, W> {
+    /// Create a ClusterEditing problem from a graph and edge deletion costs.
+    pub fn new(graph: G, edge_weights: Vec<W>) -> Self {
+        assert_eq!(
+            edge_weights.len(),
+            graph.num_edges(),
+            "edge_weights length must match num_edges"
+        );
+        Self {
+            graph,
+            edge_weights,
+        }
+    }
+
+    /// Create a ClusterEditing problem with unit edge weights.
+    pub fn unit_weights(graph: G) -> Self
+    where
+        W: From<i32>,
+    {
+        let edge_weights = vec![W::from(1); graph.num_edges()];
+        Self::new(graph, edge_weights)
+    }
+
+    /// Get a reference to the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Get a reference to the edge weights.
+    pub fn edge_weights(&self) -> &[W] {
+        &self.edge_weights
+    }
+
+    /// Set new edge weights.
+    pub fn set_weights(&mut self, weights: Vec<W>) {
+        assert_eq!(weights.len(), self.graph.num_edges());
+        self.edge_weights = weights;
+    }
+
+    /// Get the edge weights as a Vec.
+    pub fn weights(&self) -> Vec<W> {
+        self.edge_weights.clone()
+    }
+
+    /// Check if the problem uses a non-unit weight type.
+    pub fn is_weighted(&self) -> bool
+    where
+        W: WeightElement,
+    {
+        !W::IS_UNIT
+    }
+
+    /// Check if a configuration is a valid cluster assignment.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        let n = self.graph.num_vertices();
+        config.len() == n && config.iter().all(|&cluster| cluster < n)
+    }
+}
+
+impl<G: Graph, W: WeightElement> ClusterEditing<G, W> {
+    /// Get the number of vertices in the underlying graph.
+    pub fn num_vertices(&self) -> usize {
+        self.graph.num_vertices()
+    }
+
+    /// Get the number of edges in the underlying graph.
+    pub fn num_edges(&self) -> usize {
+        self.graph.num_edges()
+    }
+}
+
+impl<G, W> Problem for ClusterEditing<G, W>
+where
+    G: Graph + crate::variant::VariantParam,
+    W: WeightElement + crate::variant::VariantParam,
+{
+    const NAME: &'static str = "ClusterEditing";
+    type Value = Min<W::Sum>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![G, W]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        let n = self.graph.num_vertices();
+        vec![n; n]
+    }
+
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
+        if !self.is_valid_solution(config) {
+            return Min(None);
+        }
+
+        // Deleted edges: adjacent pairs split across clusters.
+        let mut total = W::Sum::zero();
+        for ((u, v), w) in self.graph.edges().into_iter().zip(&self.edge_weights) {
+            if config[u] != config[v] {
+                total += w.to_sum();
+            }
+        }
+
+        // Inserted edges: non-adjacent pairs sharing a cluster.
+        let n = config.len();
+        for u in 0..n {
+            for v in (u + 1)..n {
+                if config[u] == config[v] && !self.graph.has_edge(u, v) {
+                    total += W::Sum::one();
+                }
+            }
+        }
+        Min(Some(total))
+    }
+}
+
+crate::declare_variants! {
+    default ClusterEditing<SimpleGraph, i32> => "3^num_vertices",
+    ClusterEditing<SimpleGraph, One> => "3^num_vertices",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "cluster_editing_simplegraph_i32",
+        instance: Box::new(ClusterEditing::new(
+            SimpleGraph::new(5, vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]),
+            vec![3, 3, 3, 1, 1, 2],
+        )),
+        optimal_config: vec![0, 0, 0, 1, 1],
+        optimal_value: serde_json::json!(2),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/cluster_editing.rs"]
+mod tests;
@@ -127,6 +127,10 @@ where
         vec![2; self.num_edges()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> crate::types::Or {
         crate::types::Or(self.is_valid_solution(config))
     }
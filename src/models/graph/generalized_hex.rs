@@ -7,7 +7,9 @@ use std::collections::{HashMap, VecDeque};
 
 use serde::{Deserialize, Serialize};
 
-use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
+use crate::registry::{
+    ComplexityClass, FieldInfo, ProblemComplexityClassEntry, ProblemSchemaEntry, VariantDimension,
+};
 use crate::topology::{Graph, SimpleGraph};
 use crate::traits::Problem;
 use crate::variant::VariantParam;
@@ -30,6 +32,13 @@ inventory::submit! {
     }
 }
 
+inventory::submit! {
+    ProblemComplexityClassEntry {
+        name: "GeneralizedHex",
+        class: ComplexityClass::PspaceComplete,
+    }
+}
+
 /// Generalized Hex on an undirected graph.
 ///
 /// The problem is represented as a zero-variable decision problem: the graph
@@ -249,6 +258,10 @@ where
         vec![]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> crate::types::Or {
         crate::types::Or({
             if !config.is_empty() {
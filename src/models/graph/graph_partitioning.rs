@@ -102,6 +102,10 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<i32> {
         let n = self.graph.num_vertices();
         if config.len() != n {
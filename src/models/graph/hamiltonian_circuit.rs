@@ -103,6 +103,10 @@ where
         vec![n; n]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> crate::types::Or {
         crate::types::Or(is_valid_hamiltonian_circuit(&self.graph, config))
     }
@@ -124,6 +124,10 @@ where
         vec![self.graph.num_vertices(); self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> crate::types::Or {
         crate::types::Or(is_valid_isomorphic_spanning_tree(
             &self.graph,
@@ -97,6 +97,10 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> crate::types::Or {
         crate::types::Or(is_kclique_config(&self.graph, config, self.k))
     }
@@ -155,6 +155,10 @@ where
         vec![self.num_colors; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> crate::types::Or {
         crate::types::Or(self.is_valid_coloring(config))
     }
@@ -132,6 +132,10 @@ where
         vec![2; self.max_paths * self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Max<usize> {
         validate_path_collection(
             &self.graph,
@@ -160,6 +160,10 @@ where
         vec![2; self.graph.num_edges()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
         if !self.is_valid_solution(config) {
             return Max(None);
@@ -0,0 +1,199 @@
+//! MaxBisection problem implementation.
+//!
+//! The Maximum Bisection problem asks for a balanced partition of vertices
+//! into two equal halves maximizing the total weight of crossing edges.
+
+use super::max_cut::cut_size;
+use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
+use crate::topology::{Graph, SimpleGraph};
+use crate::traits::Problem;
+use crate::types::{Max, WeightElement};
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MaxBisection",
+        display_name: "Max Bisection",
+        aliases: &[],
+        dimensions: &[
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("weight", "i32", &["i32"]),
+        ],
+        module_path: module_path!(),
+        description: "Find maximum weight cut among balanced bisections of a graph",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "G", description: "The undirected graph G=(V,E)" },
+            FieldInfo { name: "edge_weights", type_name: "Vec<W>", description: "Edge weights w: E -> R" },
+        ],
+    }
+}
+
+/// The Maximum Bisection problem.
+///
+/// Given a weighted graph G = (V, E) with |V| = n (even) and edge weights w_e,
+/// partition V into two disjoint sets A and B with |A| = |B| = n/2,
+/// maximizing the total weight of edges crossing the partition.
+///
+/// Unlike [`MaxCut`](super::MaxCut), the balance constraint makes every
+/// unbalanced configuration infeasible; for odd n no configuration is feasible.
+///
+/// # Type Parameters
+///
+/// * `G` - The graph type (e.g., `SimpleGraph`)
+/// * `W` - The weight type for edges (e.g., `i32`)
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MaxBisection;
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::types::Max;
+/// use problemreductions::{Problem, Solver, BruteForce};
+///
+/// // K4: every bisection cuts 4 of the 6 edges
+/// let graph = SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+/// let problem = MaxBisection::new(graph, vec![1; 6]);
+///
+/// let solver = BruteForce::new();
+/// assert_eq!(solver.solve(&problem), Max(Some(4)));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaxBisection<G, W> {
+    /// The underlying graph structure.
+    graph: G,
+    /// Weights for each edge (in the same order as graph.edges()).
+    edge_weights: Vec<W>,
+}
+
+impl<G: Graph, W: Clone + Default> MaxBisection<G, W> {
+    /// Create a MaxBisection problem from a graph with specified edge weights.
+    ///
+    /// # Arguments
+    /// * `graph` - The underlying graph
+    /// * `edge_weights` - Weights for each edge (must match graph.num_edges())
+    pub fn new(graph: G, edge_weights: Vec<W>) -> Self {
+        assert_eq!(
+            edge_weights.len(),
+            graph.num_edges(),
+            "edge_weights length must match num_edges"
+        );
+        Self {
+            graph,
+            edge_weights,
+        }
+    }
+
+    /// Create a MaxBisection problem with unit weights.
+    pub fn unweighted(graph: G) -> Self
+    where
+        W: From<i32>,
+    {
+        let edge_weights = vec![W::from(1); graph.num_edges()];
+        Self {
+            graph,
+            edge_weights,
+        }
+    }
+
+    /// Get a reference to the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Get the edges with weights.
+    pub fn edges(&self) -> Vec<(usize, usize, W)> {
+        self.graph
+            .edges()
+            .into_iter()
+            .zip(self.edge_weights.iter())
+            .map(|((u, v), w)| (u, v, w.clone()))
+            .collect()
+    }
+
+    /// Get edge weights only.
+    pub fn edge_weights(&self) -> Vec<W> {
+        self.edge_weights.clone()
+    }
+
+    /// Check whether a configuration is a balanced bisection.
+    pub fn is_balanced(&self, config: &[usize]) -> bool {
+        let n = self.graph.num_vertices();
+        config.len() == n
+            && config.iter().all(|&part| part < 2)
+            && 2 * config.iter().filter(|&&part| part == 1).count() == n
+    }
+}
+
+impl<G: Graph, W: WeightElement> MaxBisection<G, W> {
+    /// Get the number of vertices in the underlying graph.
+    pub fn num_vertices(&self) -> usize {
+        self.graph().num_vertices()
+    }
+
+    /// Get the number of edges in the underlying graph.
+    pub fn num_edges(&self) -> usize {
+        self.graph().num_edges()
+    }
+}
+
+impl<G, W> Problem for MaxBisection<G, W>
+where
+    G: Graph + crate::variant::VariantParam,
+    W: WeightElement + crate::variant::VariantParam,
+{
+    const NAME: &'static str = "MaxBisection";
+    type Value = Max<W::Sum>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![G, W]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.graph.num_vertices()]
+    }
+
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
+        if !self.is_balanced(config) {
+            return Max(None);
+        }
+        let partition: Vec<bool> = config.iter().map(|&c| c != 0).collect();
+        Max(Some(cut_size(&self.graph, &self.edge_weights, &partition)))
+    }
+}
+
+crate::declare_variants! {
+    default MaxBisection<SimpleGraph, i32> => "2^num_vertices",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // Two triangles joined by a perfect matching (the triangular prism):
+    // each triangle keeps at least one edge uncut, so at most 7 of 9 edges are cut.
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "max_bisection_simplegraph_i32",
+        instance: Box::new(MaxBisection::<_, i32>::unweighted(SimpleGraph::new(
+            6,
+            vec![
+                (0, 1),
+                (0, 2),
+                (1, 2),
+                (0, 3),
+                (1, 4),
+                (2, 5),
+                (3, 4),
+                (3, 5),
+                (4, 5),
+            ],
+        ))),
+        optimal_config: vec![0, 1, 0, 1, 0, 1],
+        optimal_value: serde_json::json!(7),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/max_bisection.rs"]
+mod tests;
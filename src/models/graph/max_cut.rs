@@ -4,7 +4,7 @@
 //! that maximizes the total weight of edges crossing the partition.
 
 use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
-use crate::topology::{Graph, SimpleGraph};
+use crate::topology::{Graph, KingsSubgraph, SimpleGraph, TriangularSubgraph, UnitDiskGraph};
 use crate::traits::Problem;
 use crate::types::{Max, One, WeightElement};
 use num_traits::Zero;
@@ -16,7 +16,7 @@ inventory::submit! {
         display_name: "Max Cut",
         aliases: &["GraphPartitioning", "MaximumBipartiteSubgraph"],
         dimensions: &[
-            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph", "KingsSubgraph", "TriangularSubgraph", "UnitDiskGraph"]),
             VariantDimension::new("weight", "i32", &["i32", "One"]),
         ],
         module_path: module_path!(),
@@ -144,6 +144,9 @@ impl<G: Graph, W: Clone + Default> MaxCut<G, W> {
     }
 
     /// Compute the cut size for a given partition configuration.
+    ///
+    /// Negative edge weights are summed with their sign, so cutting a
+    /// negative edge lowers the cut.
     pub fn cut_size(&self, config: &[usize]) -> W::Sum
     where
         W: WeightElement,
@@ -151,6 +154,57 @@ impl<G: Graph, W: Clone + Default> MaxCut<G, W> {
         let partition: Vec<bool> = config.iter().map(|&c| c != 0).collect();
         cut_size(&self.graph, &self.edge_weights, &partition)
     }
+
+    /// Total weight of the frustrated edges of a partition.
+    ///
+    /// Reading the instance as a signed graph, a positive edge wants its
+    /// endpoints on opposite sides and a negative edge wants them together.
+    /// An edge is frustrated when the partition goes against its sign; it
+    /// contributes `|w|`. This equals `positive_weight - cut_size(config)`.
+    pub fn frustration(&self, config: &[usize]) -> W::Sum
+    where
+        W: WeightElement,
+    {
+        self.positive_weight() - self.cut_size(config)
+    }
+
+    /// Frustration index of the instance read as a signed graph.
+    ///
+    /// The minimum of [`frustration`](Self::frustration) over all partitions,
+    /// i.e. the least total weight of edges whose removal leaves a balanced
+    /// signed graph; zero iff the signed graph is balanced. Computed by
+    /// exhaustive search, so only for small graphs.
+    pub fn frustration_index(&self) -> W::Sum
+    where
+        W: WeightElement,
+    {
+        // Complementary partitions have the same cut, so fix the last vertex.
+        let n = self.graph.num_vertices();
+        let mut max_cut = W::Sum::zero();
+        for mut config in crate::config::DimsIterator::new(vec![2; n.saturating_sub(1)]) {
+            config.push(0);
+            let cut = self.cut_size(&config);
+            if cut > max_cut {
+                max_cut = cut;
+            }
+        }
+        self.positive_weight() - max_cut
+    }
+
+    /// Sum of the positive edge weights, an upper bound on any cut.
+    fn positive_weight(&self) -> W::Sum
+    where
+        W: WeightElement,
+    {
+        let mut total = W::Sum::zero();
+        for weight in &self.edge_weights {
+            let weight = weight.to_sum();
+            if weight > W::Sum::zero() {
+                total += weight;
+            }
+        }
+        total
+    }
 }
 
 impl<G: Graph, W: WeightElement> MaxCut<G, W> {
@@ -181,11 +235,54 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
         // All cuts are valid, so always return Valid
         let partition: Vec<bool> = config.iter().map(|&c| c != 0).collect();
         Max(Some(cut_size(&self.graph, &self.edge_weights, &partition)))
     }
+
+    fn objective_terms(&self, config: &[usize]) -> Vec<crate::traits::ObjectiveTerm> {
+        let side = |v: usize| config.get(v).copied().unwrap_or(0) != 0;
+        self.graph
+            .edges()
+            .into_iter()
+            .zip(&self.edge_weights)
+            .map(|((u, v), w)| {
+                let value = if side(u) != side(v) {
+                    w.to_sum()
+                } else {
+                    W::Sum::zero()
+                };
+                crate::traits::ObjectiveTerm::new(format!("edge ({u}, {v})"), value)
+            })
+            .collect()
+    }
+
+    fn bit_evaluator(&self) -> Option<crate::traits::BitEvaluator<'_, Max<W::Sum>>> {
+        if self.graph.num_vertices() > crate::config::BitConfig::MAX_VARIABLES {
+            return None;
+        }
+        let edges: Vec<(usize, usize, W::Sum)> = self
+            .graph
+            .edges()
+            .into_iter()
+            .zip(&self.edge_weights)
+            .map(|((u, v), w)| (u, v, w.to_sum()))
+            .collect();
+        Some(Box::new(move |bits| {
+            let mut total = W::Sum::zero();
+            for (u, v, weight) in &edges {
+                if bits.get(*u) != bits.get(*v) {
+                    total += weight.clone();
+                }
+            }
+            Max(Some(total))
+        }))
+    }
 }
 
 /// Compute the total weight of edges crossing the cut.
@@ -211,6 +308,9 @@ where
 crate::declare_variants! {
     default MaxCut<SimpleGraph, i32> => "2^(2.372 * num_vertices / 3)",
     MaxCut<SimpleGraph, One> => "2^(0.7907 * num_vertices)",
+    MaxCut<KingsSubgraph, i32> => "2^(2.372 * num_vertices / 3)",
+    MaxCut<TriangularSubgraph, i32> => "2^(2.372 * num_vertices / 3)",
+    MaxCut<UnitDiskGraph, i32> => "2^(2.372 * num_vertices / 3)",
 }
 
 #[cfg(feature = "example-db")]
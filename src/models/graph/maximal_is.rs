@@ -163,6 +163,10 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
         if !self.is_maximal(config) {
             return Max(None);
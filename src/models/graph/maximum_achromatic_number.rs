@@ -137,6 +137,10 @@ where
         vec![self.graph.num_vertices(); self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Max<usize> {
         if config.len() != self.graph.num_vertices() {
             return Max(None);
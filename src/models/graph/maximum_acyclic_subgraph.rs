@@ -0,0 +1,177 @@
+//! Maximum Acyclic Subgraph problem implementation.
+//!
+//! The Maximum Acyclic Subgraph problem asks for a maximum-weight subset of
+//! arcs that forms a directed acyclic graph. It is the complement of
+//! [`MinimumFeedbackArcSet`](crate::models::graph::MinimumFeedbackArcSet).
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
+use crate::topology::DirectedGraph;
+use crate::traits::Problem;
+use crate::types::{Max, WeightElement};
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MaximumAcyclicSubgraph",
+        display_name: "Maximum Acyclic Subgraph",
+        aliases: &["MAS"],
+        dimensions: &[
+            VariantDimension::new("weight", "i32", &["i32"]),
+        ],
+        module_path: module_path!(),
+        description: "Find maximum weight acyclic arc subset of a directed graph",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "DirectedGraph", description: "The directed graph G=(V,A)" },
+            FieldInfo { name: "weights", type_name: "Vec<W>", description: "Arc weights w: A -> R" },
+        ],
+    }
+}
+
+/// The Maximum Acyclic Subgraph problem.
+///
+/// Given a directed graph G = (V, A) and weights w_a for each arc,
+/// find a subset A' ⊆ A such that:
+/// - The subgraph (V, A') is a directed acyclic graph (DAG)
+/// - The total weight Σ_{a ∈ A'} w_a is maximized
+///
+/// A' is acyclic iff A \ A' is a feedback arc set, so the optimum equals the
+/// total arc weight minus the minimum feedback arc set weight. On tournaments
+/// this is the ranking problem: find the vertex order agreeing with the most
+/// pairwise comparisons.
+///
+/// # Variables
+///
+/// One binary variable per arc: x_a = 1 means arc a is kept in the subgraph.
+/// The configuration space has dimension m = |A|.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MaximumAcyclicSubgraph;
+/// use problemreductions::topology::DirectedGraph;
+/// use problemreductions::{Problem, Solver, BruteForce};
+///
+/// // Directed cycle: 0->1->2->0
+/// let graph = DirectedGraph::new(3, vec![(0, 1), (1, 2), (2, 0)]);
+/// let problem = MaximumAcyclicSubgraph::new(graph, vec![1i32; 3]);
+///
+/// let solver = BruteForce::new();
+/// let solution = solver.find_witness(&problem).unwrap();
+///
+/// // Any two arcs of the cycle form a path
+/// assert_eq!(solution.iter().sum::<usize>(), 2);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaximumAcyclicSubgraph<W> {
+    /// The directed graph.
+    graph: DirectedGraph,
+    /// Weights for each arc.
+    weights: Vec<W>,
+}
+
+impl<W: Clone + Default> MaximumAcyclicSubgraph<W> {
+    /// Create a Maximum Acyclic Subgraph problem from a directed graph with given weights.
+    pub fn new(graph: DirectedGraph, weights: Vec<W>) -> Self {
+        assert_eq!(
+            weights.len(),
+            graph.num_arcs(),
+            "weights length must match graph num_arcs"
+        );
+        Self { graph, weights }
+    }
+
+    /// Get a reference to the underlying directed graph.
+    pub fn graph(&self) -> &DirectedGraph {
+        &self.graph
+    }
+
+    /// Get a reference to the weights slice.
+    pub fn weights(&self) -> &[W] {
+        &self.weights
+    }
+
+    /// Check if a configuration selects an acyclic set of arcs.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        is_acyclic_selection(&self.graph, config)
+    }
+}
+
+impl<W: WeightElement> MaximumAcyclicSubgraph<W> {
+    /// Check if the problem has non-unit weights.
+    pub fn is_weighted(&self) -> bool {
+        !W::IS_UNIT
+    }
+
+    /// Get the number of vertices in the directed graph.
+    pub fn num_vertices(&self) -> usize {
+        self.graph.num_vertices()
+    }
+
+    /// Get the number of arcs in the directed graph.
+    pub fn num_arcs(&self) -> usize {
+        self.graph.num_arcs()
+    }
+}
+
+impl<W> Problem for MaximumAcyclicSubgraph<W>
+where
+    W: WeightElement + crate::variant::VariantParam,
+{
+    const NAME: &'static str = "MaximumAcyclicSubgraph";
+    type Value = Max<W::Sum>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![W]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.graph.num_arcs()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
+        if !is_acyclic_selection(&self.graph, config) {
+            return Max(None);
+        }
+        let mut total = W::Sum::zero();
+        for (i, &selected) in config.iter().enumerate() {
+            if selected != 0 {
+                total += self.weights[i].to_sum();
+            }
+        }
+        Max(Some(total))
+    }
+}
+
+/// Check if the arcs selected by `config` (1 = kept) form a DAG.
+fn is_acyclic_selection(graph: &DirectedGraph, config: &[usize]) -> bool {
+    if config.len() != graph.num_arcs() {
+        return false;
+    }
+    let kept_arcs: Vec<bool> = config.iter().map(|&x| x != 0).collect();
+    graph.is_acyclic_subgraph(&kept_arcs)
+}
+
+crate::declare_variants! {
+    default MaximumAcyclicSubgraph<i32> => "2^num_vertices",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    use crate::topology::DirectedGraph;
+    // Tournament on 4 vertices with two 3-cycles (0->1->2->0 and 0->1->3->0);
+    // dropping arc 0->1 leaves the order 1, 2, 3, 0 consistent with 5 arcs.
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "maximum_acyclic_subgraph",
+        instance: Box::new(MaximumAcyclicSubgraph::new(
+            DirectedGraph::new(4, vec![(0, 1), (1, 2), (2, 0), (1, 3), (3, 0), (2, 3)]),
+            vec![1i32; 6],
+        )),
+        optimal_config: vec![0, 1, 1, 1, 1, 1],
+        optimal_value: serde_json::json!(5),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/maximum_acyclic_subgraph.rs"]
+mod tests;
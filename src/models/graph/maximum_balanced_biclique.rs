@@ -0,0 +1,187 @@
+//! Maximum Balanced Biclique problem implementation.
+//!
+//! Find the largest k such that a bipartite graph contains K_{k,k}: equal-size
+//! subsets of both sides with every cross pair present.
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry};
+use crate::topology::BipartiteGraph;
+use crate::traits::Problem;
+use crate::types::Max;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MaximumBalancedBiclique",
+        display_name: "Maximum Balanced Biclique",
+        aliases: &[],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Find the largest K_{k,k} subgraph of a bipartite graph",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "BipartiteGraph", description: "The bipartite graph G = (A, B, E)" },
+        ],
+    }
+}
+
+/// The Maximum Balanced Biclique problem.
+///
+/// Given a bipartite graph G = (A, B, E), find A' ⊆ A and B' ⊆ B with
+/// |A'| = |B'| and A' × B' ⊆ E, maximizing |A'|.
+///
+/// This is the optimization form of [`BalancedCompleteBipartiteSubgraph`]
+/// (Garey & Johnson GT24).
+///
+/// # Representation
+///
+/// One binary variable per vertex, left vertices first: `config[l]` for
+/// `l < left_size`, then `config[left_size + r]` for the right side. The
+/// value is the common side size k; unbalanced or incomplete selections are
+/// infeasible. The empty selection is a valid biclique of size 0.
+///
+/// [`BalancedCompleteBipartiteSubgraph`]: crate::models::graph::BalancedCompleteBipartiteSubgraph
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MaximumBalancedBiclique;
+/// use problemreductions::topology::BipartiteGraph;
+/// use problemreductions::{Problem, Solver, BruteForce};
+///
+/// // K_{2,2} on {0,1} x {0,1}, plus a pendant edge (2, 2)
+/// let graph = BipartiteGraph::new(3, 3, vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 2)]);
+/// let problem = MaximumBalancedBiclique::new(graph);
+///
+/// let value = BruteForce::new().solve(&problem);
+/// assert_eq!(value.0, Some(2));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "MaximumBalancedBicliqueRepr")]
+pub struct MaximumBalancedBiclique {
+    graph: BipartiteGraph,
+    #[serde(skip)]
+    edge_lookup: HashSet<(usize, usize)>,
+}
+
+impl MaximumBalancedBiclique {
+    /// Create a new Maximum Balanced Biclique problem.
+    pub fn new(graph: BipartiteGraph) -> Self {
+        let edge_lookup = graph.left_edges().iter().copied().collect();
+        Self { graph, edge_lookup }
+    }
+
+    /// Get a reference to the underlying bipartite graph.
+    pub fn graph(&self) -> &BipartiteGraph {
+        &self.graph
+    }
+
+    /// Number of vertices in the left partition.
+    pub fn left_size(&self) -> usize {
+        self.graph.left_size()
+    }
+
+    /// Number of vertices in the right partition.
+    pub fn right_size(&self) -> usize {
+        self.graph.right_size()
+    }
+
+    /// Total number of vertices.
+    pub fn num_vertices(&self) -> usize {
+        self.left_size() + self.right_size()
+    }
+
+    /// Number of edges.
+    pub fn num_edges(&self) -> usize {
+        self.graph.left_edges().len()
+    }
+
+    /// Check whether `(left, right)` is an edge, in bipartite-local coordinates.
+    pub fn has_edge(&self, left: usize, right: usize) -> bool {
+        self.edge_lookup.contains(&(left, right))
+    }
+
+    /// Check if a configuration selects a balanced complete bipartite subgraph.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        self.evaluate(config).is_valid()
+    }
+}
+
+impl Problem for MaximumBalancedBiclique {
+    const NAME: &'static str = "MaximumBalancedBiclique";
+    type Value = Max<usize>;
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.num_vertices()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Max<usize> {
+        if config.len() != self.num_vertices() || config.iter().any(|&v| v > 1) {
+            return Max(None);
+        }
+        let (left, right) = config.split_at(self.left_size());
+        let selected_left: Vec<usize> = (0..left.len()).filter(|&l| left[l] == 1).collect();
+        let selected_right: Vec<usize> = (0..right.len()).filter(|&r| right[r] == 1).collect();
+
+        if selected_left.len() != selected_right.len() {
+            return Max(None);
+        }
+        let complete = selected_left
+            .iter()
+            .all(|&l| selected_right.iter().all(|&r| self.has_edge(l, r)));
+        if complete {
+            Max(Some(selected_left.len()))
+        } else {
+            Max(None)
+        }
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+}
+
+#[derive(Deserialize)]
+struct MaximumBalancedBicliqueRepr {
+    graph: BipartiteGraph,
+}
+
+impl From<MaximumBalancedBicliqueRepr> for MaximumBalancedBiclique {
+    fn from(repr: MaximumBalancedBicliqueRepr) -> Self {
+        Self::new(repr.graph)
+    }
+}
+
+crate::declare_variants! {
+    default MaximumBalancedBiclique => "1.3803^num_vertices",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "maximum_balanced_biclique",
+        instance: Box::new(MaximumBalancedBiclique::new(BipartiteGraph::new(
+            4,
+            4,
+            vec![
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (1, 0),
+                (1, 1),
+                (1, 2),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (3, 0),
+                (3, 1),
+                (3, 3),
+            ],
+        ))),
+        optimal_config: vec![1, 1, 1, 0, 1, 1, 1, 0],
+        optimal_value: serde_json::json!(3),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/maximum_balanced_biclique.rs"]
+mod tests;
@@ -111,6 +111,120 @@ impl<G: Graph, W: WeightElement> MaximumClique<G, W> {
     pub fn num_edges(&self) -> usize {
         self.graph().num_edges()
     }
+
+    /// All maximum-weight cliques, as sorted vertex lists in lexicographic order.
+    ///
+    /// Candidates are the maximal cliques from [`maximal_cliques`], so with
+    /// negative weights a better non-maximal clique can be missed. The empty
+    /// graph has the single maximum clique `[]`.
+    pub fn all_maximum_cliques(&self) -> Vec<Vec<usize>> {
+        let weight = |clique: &[usize]| {
+            let mut total = W::Sum::zero();
+            for &v in clique {
+                total += self.weights[v].to_sum();
+            }
+            total
+        };
+        let mut best: Vec<Vec<usize>> = Vec::new();
+        let mut best_weight = W::Sum::zero();
+        for clique in maximal_cliques(&self.graph) {
+            let total = weight(&clique);
+            if best.is_empty() || total > best_weight {
+                best = vec![clique];
+                best_weight = total;
+            } else if total == best_weight {
+                best.push(clique);
+            }
+        }
+        best
+    }
+}
+
+/// Enumerate all maximal cliques of `graph` (Bron–Kerbosch with Tomita pivoting).
+///
+/// Each clique is a sorted vertex list; the result is in lexicographic order.
+/// Isolated vertices are maximal cliques of size 1, and a graph without
+/// vertices has the single maximal clique `[]`.
+pub fn maximal_cliques<G: Graph>(graph: &G) -> Vec<Vec<usize>> {
+    let n = graph.num_vertices();
+    let adjacency: Vec<Vec<bool>> = (0..n)
+        .map(|u| {
+            let mut row = vec![false; n];
+            for v in graph.neighbors(u) {
+                if v != u {
+                    row[v] = true;
+                }
+            }
+            row
+        })
+        .collect();
+    let mut cliques = Vec::new();
+    bron_kerbosch(
+        &adjacency,
+        &mut Vec::new(),
+        (0..n).collect(),
+        Vec::new(),
+        &mut cliques,
+    );
+    for clique in &mut cliques {
+        clique.sort_unstable();
+    }
+    cliques.sort();
+    cliques
+}
+
+/// Report every maximal clique extending `current` with vertices of
+/// `candidates`, none of which is extendable by a vertex in `excluded`.
+fn bron_kerbosch(
+    adjacency: &[Vec<bool>],
+    current: &mut Vec<usize>,
+    candidates: Vec<usize>,
+    excluded: Vec<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() {
+            cliques.push(current.clone());
+        }
+        return;
+    }
+    // Tomita pivot: a maximal clique must contain the pivot or one of its
+    // non-neighbours, so only those are branched on.
+    let pivot = candidates
+        .iter()
+        .chain(&excluded)
+        .copied()
+        .max_by_key(|&u| candidates.iter().filter(|&&v| adjacency[u][v]).count())
+        .expect("candidates is non-empty");
+    let branch: Vec<usize> = candidates
+        .iter()
+        .copied()
+        .filter(|&v| !adjacency[pivot][v])
+        .collect();
+
+    let mut candidates = candidates;
+    let mut excluded = excluded;
+    for v in branch {
+        current.push(v);
+        bron_kerbosch(
+            adjacency,
+            current,
+            candidates
+                .iter()
+                .copied()
+                .filter(|&u| adjacency[v][u])
+                .collect(),
+            excluded
+                .iter()
+                .copied()
+                .filter(|&u| adjacency[v][u])
+                .collect(),
+            cliques,
+        );
+        current.pop();
+        candidates.retain(|&u| u != v);
+        excluded.push(v);
+    }
 }
 
 impl<G, W> Problem for MaximumClique<G, W>
@@ -129,6 +243,10 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
         if !is_clique_config(&self.graph, config) {
             return Max(None);
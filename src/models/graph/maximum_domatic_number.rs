@@ -13,7 +13,7 @@ inventory::submit! {
     ProblemSchemaEntry {
         name: "MaximumDomaticNumber",
         display_name: "Maximum Domatic Number",
-        aliases: &[],
+        aliases: &["DomaticNumber", "DomaticPartition"],
         dimensions: &[
             VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
         ],
@@ -146,6 +146,10 @@ where
         vec![n; n]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Max<usize> {
         match self.evaluate_partition(config) {
             Some(k) => Max(Some(k)),
@@ -129,6 +129,10 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
         if !is_independent_set_config(&self.graph, config) {
             return Max(None);
@@ -141,6 +145,47 @@ where
         }
         Max(Some(total))
     }
+
+    fn objective_terms(&self, config: &[usize]) -> Vec<crate::traits::ObjectiveTerm> {
+        self.weights
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let value = if config.get(i) == Some(&1) {
+                    w.to_sum()
+                } else {
+                    W::Sum::zero()
+                };
+                crate::traits::ObjectiveTerm::new(format!("vertex {i}"), value)
+            })
+            .collect()
+    }
+
+    fn bit_evaluator(&self) -> Option<crate::traits::BitEvaluator<'_, Max<W::Sum>>> {
+        if self.graph.num_vertices() > crate::config::BitConfig::MAX_VARIABLES {
+            return None;
+        }
+        // Bit v of conflicts[u] is set iff (u, v) is an edge.
+        let mut conflicts = vec![0u64; self.graph.num_vertices()];
+        for (u, v) in self.graph.edges() {
+            conflicts[u] |= 1 << v;
+            conflicts[v] |= 1 << u;
+        }
+        let weights: Vec<W::Sum> = self.weights.iter().map(WeightElement::to_sum).collect();
+        Some(Box::new(move |bits| {
+            let mut total = W::Sum::zero();
+            let mut rest = bits.0;
+            while rest != 0 {
+                let v = rest.trailing_zeros() as usize;
+                if bits.0 & conflicts[v] != 0 {
+                    return Max(None);
+                }
+                total += weights[v].clone();
+                rest &= rest - 1;
+            }
+            Max(Some(total))
+        }))
+    }
 }
 
 /// Check if a configuration forms a valid independent set.
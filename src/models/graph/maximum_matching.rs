@@ -4,7 +4,7 @@
 //! such that no two edges share a vertex.
 
 use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
-use crate::topology::{Graph, SimpleGraph};
+use crate::topology::{Graph, KingsSubgraph, SimpleGraph, TriangularSubgraph, UnitDiskGraph};
 use crate::traits::Problem;
 use crate::types::{Max, WeightElement};
 use num_traits::Zero;
@@ -17,7 +17,7 @@ inventory::submit! {
         display_name: "Maximum Matching",
         aliases: &["MaxMatching"],
         dimensions: &[
-            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph", "KingsSubgraph", "TriangularSubgraph", "UnitDiskGraph"]),
             VariantDimension::new("weight", "i32", &["i32"]),
         ],
         module_path: module_path!(),
@@ -197,6 +197,10 @@ where
         vec![2; self.graph.num_edges()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
         if !self.is_valid_matching(config) {
             return Max(None);
@@ -215,6 +219,9 @@ where
 
 crate::declare_variants! {
     default MaximumMatching<SimpleGraph, i32> => "num_vertices^3",
+    MaximumMatching<KingsSubgraph, i32> => "num_vertices^3",
+    MaximumMatching<TriangularSubgraph, i32> => "num_vertices^3",
+    MaximumMatching<UnitDiskGraph, i32> => "num_vertices^3",
 }
 
 #[cfg(feature = "example-db")]
@@ -0,0 +1,208 @@
+//! Maximum Planar Subgraph problem implementation.
+//!
+//! The Maximum Planar Subgraph problem asks for a maximum weight set of edges
+//! whose induced subgraph is planar. Its complement is the weighted
+//! skewness of the graph (the fewest edges to delete to reach planarity).
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
+use crate::topology::{Graph, SimpleGraph};
+use crate::traits::Problem;
+use crate::types::{Max, One, WeightElement};
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MaximumPlanarSubgraph",
+        display_name: "Maximum Planar Subgraph",
+        aliases: &["MPS"],
+        dimensions: &[
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("weight", "i32", &["One", "i32"]),
+        ],
+        module_path: module_path!(),
+        description: "Find a maximum weight edge subset forming a planar subgraph",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "G", description: "The underlying graph G=(V,E)" },
+            FieldInfo { name: "edge_weights", type_name: "Vec<W>", description: "Edge weights w: E -> R" },
+        ],
+    }
+}
+
+/// The Maximum Planar Subgraph problem.
+///
+/// Given a graph G = (V, E) with edge weights, find a maximum weight subset
+/// E' ⊆ E such that (V, E') is planar. There is one binary variable per edge
+/// in `graph.edges()` order; every planar edge subset is feasible.
+///
+/// # Type Parameters
+///
+/// * `G` - The graph type (e.g., `SimpleGraph`)
+/// * `W` - The weight type (e.g., `i32`, `One`)
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MaximumPlanarSubgraph;
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::types::Max;
+/// use problemreductions::{BruteForce, Solver};
+///
+/// // K5 becomes planar after deleting any single edge.
+/// let problem = MaximumPlanarSubgraph::<_, i32>::unit_weights(SimpleGraph::complete(5));
+/// assert_eq!(BruteForce::new().solve(&problem), Max(Some(9)));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaximumPlanarSubgraph<G, W> {
+    /// The underlying graph.
+    graph: G,
+    /// Weights for each edge (in edge index order).
+    edge_weights: Vec<W>,
+}
+
+impl<G: Graph, W: Clone + Default> MaximumPlanarSubgraph<G, W> {
+    /// Create a MaximumPlanarSubgraph problem from a graph with given edge weights.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph
+    /// * `edge_weights` - Weight for each edge (in graph.edges() order)
+    pub fn new(graph: G, edge_weights: Vec<W>) -> Self {
+        assert_eq!(
+            edge_weights.len(),
+            graph.num_edges(),
+            "edge_weights length must match num_edges"
+        );
+        Self {
+            graph,
+            edge_weights,
+        }
+    }
+
+    /// Create a MaximumPlanarSubgraph problem with unit weights.
+    pub fn unit_weights(graph: G) -> Self
+    where
+        W: From<i32>,
+    {
+        let edge_weights = vec![W::from(1); graph.num_edges()];
+        Self {
+            graph,
+            edge_weights,
+        }
+    }
+
+    /// Get a reference to the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Get the weights for the problem.
+    pub fn weights(&self) -> &[W] {
+        &self.edge_weights
+    }
+
+    /// Edges selected by a configuration.
+    pub fn selected_edges(&self, config: &[usize]) -> Vec<(usize, usize)> {
+        self.graph
+            .edges()
+            .into_iter()
+            .zip(config)
+            .filter(|&(_, &x)| x == 1)
+            .map(|(e, _)| e)
+            .collect()
+    }
+
+    /// Check if a configuration is binary, has one entry per edge and
+    /// selects a planar subgraph.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        config.len() == self.graph.num_edges()
+            && config.iter().all(|&x| x <= 1)
+            && crate::topology::planarity::is_planar(
+                self.graph.num_vertices(),
+                &self.selected_edges(config),
+            )
+    }
+}
+
+impl<G: Graph, W: WeightElement> MaximumPlanarSubgraph<G, W> {
+    /// Get the number of vertices in the underlying graph.
+    pub fn num_vertices(&self) -> usize {
+        self.graph().num_vertices()
+    }
+
+    /// Get the number of edges in the underlying graph.
+    pub fn num_edges(&self) -> usize {
+        self.graph().num_edges()
+    }
+}
+
+impl<G, W> Problem for MaximumPlanarSubgraph<G, W>
+where
+    G: Graph + crate::variant::VariantParam,
+    W: WeightElement + crate::variant::VariantParam,
+{
+    const NAME: &'static str = "MaximumPlanarSubgraph";
+    type Value = Max<W::Sum>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![G, W]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.graph.num_edges()]
+    }
+
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Max<W::Sum> {
+        if !self.is_valid_solution(config) {
+            return Max(None);
+        }
+        let mut total = W::Sum::zero();
+        for (w, &selected) in self.edge_weights.iter().zip(config) {
+            if selected == 1 {
+                total += w.to_sum();
+            }
+        }
+        Max(Some(total))
+    }
+}
+
+crate::declare_variants! {
+    default MaximumPlanarSubgraph<SimpleGraph, i32> => "2^num_edges",
+    MaximumPlanarSubgraph<SimpleGraph, One> => "2^num_edges",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // K3,3 with one heavy edge: dropping any single light edge restores
+    // planarity, and the cheapest choice is the first light edge.
+    let graph = SimpleGraph::new(
+        6,
+        vec![
+            (0, 3),
+            (0, 4),
+            (0, 5),
+            (1, 3),
+            (1, 4),
+            (1, 5),
+            (2, 3),
+            (2, 4),
+            (2, 5),
+        ],
+    );
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "maximum_planar_subgraph_simplegraph_i32",
+        instance: Box::new(MaximumPlanarSubgraph::new(
+            graph,
+            vec![5, 1, 1, 1, 1, 1, 1, 1, 1],
+        )),
+        optimal_config: vec![1, 0, 1, 1, 1, 1, 1, 1, 1],
+        optimal_value: serde_json::json!(12),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/maximum_planar_subgraph.rs"]
+mod tests;
@@ -238,6 +238,10 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
         if config.len() != self.graph.num_vertices() || config.iter().any(|&selected| selected > 1)
         {
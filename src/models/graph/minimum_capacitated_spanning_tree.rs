@@ -300,6 +300,10 @@ where
         vec![2; self.graph.num_edges()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
         if !is_valid_capacitated_spanning_tree(
             &self.graph,
@@ -0,0 +1,164 @@
+//! Minimum Clique Cover problem implementation.
+//!
+//! The Minimum Clique Cover problem asks for a partition of the vertices of a
+//! graph into as few cliques as possible.
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
+use crate::topology::{Graph, SimpleGraph};
+use crate::traits::Problem;
+use crate::types::Min;
+use crate::variant::VariantParam;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MinimumCliqueCover",
+        display_name: "Minimum Clique Cover",
+        aliases: &[],
+        dimensions: &[
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+        ],
+        module_path: module_path!(),
+        description: "Partition vertices into the minimum number of cliques",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "G", description: "The underlying graph G=(V,E)" },
+        ],
+    }
+}
+
+/// The Minimum Clique Cover problem.
+///
+/// Given a graph G = (V, E), partition V into groups V_1, ..., V_k such that
+/// each V_i induces a clique, minimizing k. The minimum k is the clique cover
+/// number of G, which equals the chromatic number of the complement graph.
+///
+/// Unlike [`PartitionIntoCliques`](super::PartitionIntoCliques), which asks
+/// whether a partition into at most K cliques exists, this is the
+/// optimization version with no bound.
+///
+/// # Representation
+///
+/// Each vertex is assigned a group id in `0..n`. The objective is the number
+/// of distinct group ids used; assignments whose groups are not cliques are
+/// infeasible.
+///
+/// # Type Parameters
+///
+/// * `G` - Graph type (e.g., SimpleGraph)
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MinimumCliqueCover;
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::types::Min;
+/// use problemreductions::{Solver, BruteForce};
+///
+/// // Two triangles joined by the edge 2-3 are covered by two cliques
+/// let graph = SimpleGraph::new(6, vec![(0, 1), (0, 2), (1, 2), (2, 3), (3, 4), (3, 5), (4, 5)]);
+/// let problem = MinimumCliqueCover::new(graph);
+///
+/// let solver = BruteForce::new();
+/// assert_eq!(solver.solve(&problem), Min(Some(2)));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(deserialize = "G: serde::Deserialize<'de>"))]
+pub struct MinimumCliqueCover<G> {
+    /// The underlying graph.
+    graph: G,
+}
+
+impl<G: Graph> MinimumCliqueCover<G> {
+    /// Create a new Minimum Clique Cover instance.
+    pub fn new(graph: G) -> Self {
+        Self { graph }
+    }
+
+    /// Get a reference to the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Get the number of vertices in the underlying graph.
+    pub fn num_vertices(&self) -> usize {
+        self.graph.num_vertices()
+    }
+
+    /// Get the number of edges in the underlying graph.
+    pub fn num_edges(&self) -> usize {
+        self.graph.num_edges()
+    }
+
+    /// Check if a configuration is a valid solution.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        self.is_valid_clique_cover(config)
+    }
+
+    /// Check whether every group of `config` induces a clique.
+    pub fn is_valid_clique_cover(&self, config: &[usize]) -> bool {
+        let n = self.graph.num_vertices();
+        if config.len() != n || config.iter().any(|&c| c >= n) {
+            return false;
+        }
+        (0..n).all(|u| ((u + 1)..n).all(|v| config[u] != config[v] || self.graph.has_edge(u, v)))
+    }
+
+    /// Number of distinct groups used by `config`.
+    pub fn num_cliques_used(config: &[usize]) -> usize {
+        let mut groups = config.to_vec();
+        groups.sort_unstable();
+        groups.dedup();
+        groups.len()
+    }
+}
+
+impl<G> Problem for MinimumCliqueCover<G>
+where
+    G: Graph + VariantParam,
+{
+    const NAME: &'static str = "MinimumCliqueCover";
+    type Value = Min<usize>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![G]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        let n = self.graph.num_vertices();
+        vec![n; n]
+    }
+
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<usize> {
+        if !self.is_valid_clique_cover(config) {
+            return Min(None);
+        }
+        Min(Some(Self::num_cliques_used(config)))
+    }
+}
+
+crate::declare_variants! {
+    default MinimumCliqueCover<SimpleGraph> => "2^num_vertices",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // The 5-cycle has no triangles, so cliques are edges or single vertices
+    // and three of them are needed to cover five vertices.
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "minimum_clique_cover_simplegraph",
+        instance: Box::new(MinimumCliqueCover::new(SimpleGraph::new(
+            5,
+            vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)],
+        ))),
+        optimal_config: vec![0, 0, 1, 1, 2],
+        optimal_value: serde_json::json!(3),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/minimum_clique_cover.rs"]
+mod tests;
@@ -138,6 +138,10 @@ where
         vec![self.graph.num_edges(); self.graph.num_edges()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<usize> {
         if config.len() != self.graph.num_edges() {
             return Min(None);
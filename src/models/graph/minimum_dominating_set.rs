@@ -5,7 +5,7 @@
 
 use crate::models::decision::Decision;
 use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
-use crate::topology::{Graph, SimpleGraph};
+use crate::topology::{Graph, KingsSubgraph, SimpleGraph, TriangularSubgraph, UnitDiskGraph};
 use crate::traits::Problem;
 use crate::types::{Min, One, WeightElement};
 use num_traits::Zero;
@@ -18,7 +18,7 @@ inventory::submit! {
         display_name: "Minimum Dominating Set",
         aliases: &[],
         dimensions: &[
-            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph", "KingsSubgraph", "TriangularSubgraph", "UnitDiskGraph"]),
             VariantDimension::new("weight", "i32", &["i32", "One"]),
         ],
         module_path: module_path!(),
@@ -150,6 +150,10 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
         if !self.is_dominating(config) {
             return Min(None);
@@ -167,6 +171,9 @@ where
 crate::declare_variants! {
     default MinimumDominatingSet<SimpleGraph, i32> => "1.4969^num_vertices",
     MinimumDominatingSet<SimpleGraph, One> => "1.4969^num_vertices",
+    MinimumDominatingSet<KingsSubgraph, i32> => "1.4969^num_vertices",
+    MinimumDominatingSet<TriangularSubgraph, i32> => "1.4969^num_vertices",
+    MinimumDominatingSet<UnitDiskGraph, i32> => "1.4969^num_vertices",
 }
 
 impl<G, W> crate::models::decision::DecisionProblemMeta for MinimumDominatingSet<G, W>
@@ -270,6 +277,16 @@ inventory::submit! {
                 .next()
                 .map(|config| (config, crate::registry::format_metric(&value)))
         },
+        solve_telemetry_fn: |any| {
+            let problem = any
+                .downcast_ref::<Decision<MinimumDominatingSet<SimpleGraph, One>>>()
+                .expect("DecisionMinimumDominatingSet telemetry solve source type mismatch");
+            let (value, telemetry) = crate::solvers::Solver::solve_with_telemetry(
+                &crate::solvers::BruteForce::new(),
+                problem,
+            );
+            (crate::registry::format_metric(&value), telemetry)
+        },
     }
 }
 
@@ -0,0 +1,172 @@
+//! Minimum Edge Dominating Set problem implementation.
+//!
+//! The Edge Dominating Set problem asks for a minimum weight subset of edges
+//! such that every edge is either in the set or shares an endpoint with an
+//! edge in the set.
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
+use crate::topology::{Graph, SimpleGraph};
+use crate::traits::Problem;
+use crate::types::{Min, WeightElement};
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MinimumEdgeDominatingSet",
+        display_name: "Minimum Edge Dominating Set",
+        aliases: &["EDS"],
+        dimensions: &[
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("weight", "i32", &["i32"]),
+        ],
+        module_path: module_path!(),
+        description: "Find minimum weight set of edges touching every edge of a graph",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "G", description: "The underlying graph G=(V,E)" },
+            FieldInfo { name: "edge_weights", type_name: "Vec<W>", description: "Edge weights w: E -> R" },
+        ],
+    }
+}
+
+/// The Minimum Edge Dominating Set problem (GT2).
+///
+/// Given a graph G = (V, E) and weights w_e for each edge, find a subset
+/// D ⊆ E such that:
+/// - Every edge is either in D or shares an endpoint with an edge in D
+/// - The total weight Σ_{e ∈ D} w_e is minimized
+///
+/// Equivalently, D is a dominating set of the line graph L(G).
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MinimumEdgeDominatingSet;
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::{Problem, Solver, BruteForce};
+///
+/// // Path P4: the middle edge touches both outer edges
+/// let graph = SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]);
+/// let problem = MinimumEdgeDominatingSet::new(graph, vec![1; 3]);
+///
+/// let solver = BruteForce::new();
+/// assert_eq!(solver.find_witness(&problem), Some(vec![0, 1, 0]));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinimumEdgeDominatingSet<G, W> {
+    /// The underlying graph.
+    graph: G,
+    /// Weights for each edge (in the same order as graph.edges()).
+    edge_weights: Vec<W>,
+}
+
+impl<G: Graph, W: Clone + Default> MinimumEdgeDominatingSet<G, W> {
+    /// Create an Edge Dominating Set problem from a graph with given edge weights.
+    pub fn new(graph: G, edge_weights: Vec<W>) -> Self {
+        assert_eq!(
+            edge_weights.len(),
+            graph.num_edges(),
+            "edge_weights length must match num_edges"
+        );
+        Self {
+            graph,
+            edge_weights,
+        }
+    }
+
+    /// Get a reference to the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Get a reference to the edge weights slice.
+    pub fn edge_weights(&self) -> &[W] {
+        &self.edge_weights
+    }
+
+    /// Check if a configuration is a valid edge dominating set.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        let edges = self.graph.edges();
+        if config.len() != edges.len() {
+            return false;
+        }
+        let mut touched = vec![false; self.graph.num_vertices()];
+        for (&(u, v), _) in edges.iter().zip(config).filter(|(_, &sel)| sel == 1) {
+            touched[u] = true;
+            touched[v] = true;
+        }
+        edges.iter().all(|&(u, v)| touched[u] || touched[v])
+    }
+}
+
+impl<G: Graph, W: WeightElement> MinimumEdgeDominatingSet<G, W> {
+    /// Get the number of vertices in the underlying graph.
+    pub fn num_vertices(&self) -> usize {
+        self.graph().num_vertices()
+    }
+
+    /// Get the number of edges in the underlying graph.
+    pub fn num_edges(&self) -> usize {
+        self.graph().num_edges()
+    }
+}
+
+impl<G, W> Problem for MinimumEdgeDominatingSet<G, W>
+where
+    G: Graph + crate::variant::VariantParam,
+    W: WeightElement + crate::variant::VariantParam,
+{
+    const NAME: &'static str = "MinimumEdgeDominatingSet";
+    type Value = Min<W::Sum>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![G, W]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.graph.num_edges()]
+    }
+
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
+        if config.iter().any(|&sel| sel > 1) || !self.is_valid_solution(config) {
+            return Min(None);
+        }
+        let mut total = W::Sum::zero();
+        for (w, _) in self
+            .edge_weights
+            .iter()
+            .zip(config)
+            .filter(|(_, &sel)| sel == 1)
+        {
+            total += w.to_sum();
+        }
+        Min(Some(total))
+    }
+}
+
+crate::declare_variants! {
+    default MinimumEdgeDominatingSet<SimpleGraph, i32> => "2^num_edges",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // Cycle C5: each edge touches three edges (itself and two neighbours),
+    // so one edge cannot dominate all five, but two non-adjacent edges can.
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "minimum_edge_dominating_set_simplegraph_i32",
+        instance: Box::new(MinimumEdgeDominatingSet::new(
+            SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]),
+            vec![1i32; 5],
+        )),
+        optimal_config: vec![1, 0, 1, 0, 0],
+        optimal_value: serde_json::json!(2),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/minimum_edge_dominating_set.rs"]
+mod tests;
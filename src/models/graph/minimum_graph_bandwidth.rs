@@ -88,6 +88,11 @@ impl<G: Graph> MinimumGraphBandwidth<G> {
         self.graph.num_edges()
     }
 
+    /// Check if a configuration is a valid permutation.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        self.is_valid_permutation(config)
+    }
+
     /// Check if a configuration forms a valid permutation of {0, ..., n-1}.
     fn is_valid_permutation(&self, config: &[usize]) -> bool {
         let n = self.graph.num_vertices();
@@ -136,6 +141,10 @@ where
         vec![n; n]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<usize> {
         match self.bandwidth(config) {
             Some(bw) => Min(Some(bw)),
@@ -109,6 +109,10 @@ where
         vec![2; n * m]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<usize> {
         let n = self.graph.num_vertices();
         let m = self.graph.num_edges();
@@ -0,0 +1,194 @@
+//! Minimum Maximal Independent Set problem implementation.
+//!
+//! The Minimum Maximal Independent Set problem (also known as Minimum
+//! Independent Dominating Set) asks for a maximal independent set of minimum
+//! total weight.
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
+use crate::topology::{Graph, SimpleGraph};
+use crate::traits::Problem;
+use crate::types::{Min, WeightElement};
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MinimumMaximalIS",
+        display_name: "Minimum Maximal IS",
+        aliases: &[],
+        dimensions: &[
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("weight", "i32", &["i32"]),
+        ],
+        module_path: module_path!(),
+        description: "Find minimum weight maximal independent set (independent dominating set)",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "G", description: "The underlying graph G=(V,E)" },
+            FieldInfo { name: "weights", type_name: "Vec<W>", description: "Vertex weights w: V -> R" },
+        ],
+    }
+}
+
+/// The Minimum Maximal Independent Set problem.
+///
+/// Given a graph G = (V, E) and weights w_v for each vertex, find a subset
+/// S ⊆ V such that:
+/// - S is independent (no two vertices of S are adjacent)
+/// - S is maximal (every vertex outside S has a neighbor in S)
+/// - The total weight Σ_{v ∈ S} w_v is minimized
+///
+/// A maximal independent set is exactly an independent dominating set, so
+/// this is the Minimum Independent Dominating Set problem. It sits between
+/// [`MaximalIS`](super::MaximalIS), which maximizes over the same feasible
+/// sets, and [`MinimumDominatingSet`](super::MinimumDominatingSet), which
+/// drops the independence requirement.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MinimumMaximalIS;
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::types::Min;
+/// use problemreductions::{Solver, BruteForce};
+///
+/// // Star graph: the center alone is the smallest maximal independent set
+/// let graph = SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3)]);
+/// let problem = MinimumMaximalIS::new(graph, vec![1; 4]);
+///
+/// let solver = BruteForce::new();
+/// assert_eq!(solver.solve(&problem), Min(Some(1)));
+/// assert_eq!(solver.find_all_witnesses(&problem), vec![vec![1, 0, 0, 0]]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinimumMaximalIS<G, W> {
+    /// The underlying graph.
+    graph: G,
+    /// Weights for each vertex.
+    weights: Vec<W>,
+}
+
+impl<G: Graph, W: Clone + Default> MinimumMaximalIS<G, W> {
+    /// Create a Minimum Maximal Independent Set problem from a graph with given weights.
+    pub fn new(graph: G, weights: Vec<W>) -> Self {
+        assert_eq!(
+            weights.len(),
+            graph.num_vertices(),
+            "weights length must match graph num_vertices"
+        );
+        Self { graph, weights }
+    }
+
+    /// Get a reference to the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Get a reference to the weights.
+    pub fn weights(&self) -> &[W] {
+        &self.weights
+    }
+
+    /// Check if the problem uses a non-unit weight type.
+    pub fn is_weighted(&self) -> bool
+    where
+        W: WeightElement,
+    {
+        !W::IS_UNIT
+    }
+
+    /// Check if a configuration is a valid solution.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        self.is_minimal_maximal_is(config)
+    }
+
+    /// Check if a configuration is feasible for this problem: an independent
+    /// set that no vertex can be added to.
+    ///
+    /// Minimality is with respect to weight among such sets and is decided by
+    /// the objective, not by this check.
+    pub fn is_minimal_maximal_is(&self, config: &[usize]) -> bool {
+        let selected = |v: usize| config.get(v).copied().unwrap_or(0) == 1;
+
+        if self
+            .graph
+            .edges()
+            .into_iter()
+            .any(|(u, v)| selected(u) && selected(v))
+        {
+            return false;
+        }
+
+        (0..self.graph.num_vertices())
+            .all(|v| selected(v) || self.graph.neighbors(v).into_iter().any(selected))
+    }
+}
+
+impl<G: Graph, W: WeightElement> MinimumMaximalIS<G, W> {
+    /// Get the number of vertices in the underlying graph.
+    pub fn num_vertices(&self) -> usize {
+        self.graph().num_vertices()
+    }
+
+    /// Get the number of edges in the underlying graph.
+    pub fn num_edges(&self) -> usize {
+        self.graph().num_edges()
+    }
+}
+
+impl<G, W> Problem for MinimumMaximalIS<G, W>
+where
+    G: Graph + crate::variant::VariantParam,
+    W: WeightElement + crate::variant::VariantParam,
+{
+    const NAME: &'static str = "MinimumMaximalIS";
+    type Value = Min<W::Sum>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![G, W]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.graph.num_vertices()]
+    }
+
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
+        if !self.is_minimal_maximal_is(config) {
+            return Min(None);
+        }
+        let mut total = W::Sum::zero();
+        for (i, &selected) in config.iter().enumerate() {
+            if selected == 1 {
+                total += self.weights[i].to_sum();
+            }
+        }
+        Min(Some(total))
+    }
+}
+
+crate::declare_variants! {
+    default MinimumMaximalIS<SimpleGraph, i32> => "1.3575^num_vertices",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // Path 0-1-2-3 with weights (2, 1, 1, 2): the maximum IS is {0, 3} (4),
+    // the minimum dominating set is {1, 2} (2, not independent), and the
+    // minimum maximal IS is {0, 2} or {1, 3} (3).
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "minimum_maximal_is_simplegraph_i32",
+        instance: Box::new(MinimumMaximalIS::new(
+            SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+            vec![2i32, 1, 1, 2],
+        )),
+        optimal_config: vec![1, 0, 1, 0],
+        optimal_value: serde_json::json!(3),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/minimum_maximal_is.rs"]
+mod tests;
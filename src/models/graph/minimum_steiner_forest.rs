@@ -0,0 +1,277 @@
+//! Minimum Steiner Forest problem implementation.
+//!
+//! Given a weighted graph and a list of terminal pairs, find a minimum-weight
+//! forest in which every pair is connected.
+
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    registry::{FieldInfo, ProblemSchemaEntry, VariantDimension},
+    topology::{Graph, SimpleGraph},
+    traits::Problem,
+    types::{Min, One, WeightElement},
+};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MinimumSteinerForest",
+        display_name: "Minimum Steiner Forest",
+        aliases: &[],
+        dimensions: &[
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("weight", "i32", &["One", "i32"]),
+        ],
+        module_path: module_path!(),
+        description: "Find minimum weight forest connecting every terminal pair",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "G", description: "The underlying graph G=(V,E)" },
+            FieldInfo { name: "edge_weights", type_name: "Vec<W>", description: "Edge weights w: E -> R" },
+            FieldInfo { name: "terminal_pairs", type_name: "Vec<(usize, usize)>", description: "Demand pairs (s_i, t_i) that must be connected" },
+        ],
+    }
+}
+
+/// The Minimum Steiner Forest problem.
+///
+/// Given a weighted graph G = (V, E) with edge weights w_e and terminal
+/// pairs (s_1, t_1), ..., (s_k, t_k), find a forest F in G such that s_i
+/// and t_i lie in the same tree of F for every i, minimizing the total edge
+/// weight of F. Steiner Tree is the special case where all pairs share a
+/// common terminal.
+///
+/// # Representation
+///
+/// Each edge is assigned a binary variable:
+/// - 0: edge is not in the forest
+/// - 1: edge is in the forest
+///
+/// A valid Steiner forest requires:
+/// - Selected edges are acyclic
+/// - Both endpoints of every terminal pair lie in the same component
+///
+/// # Type Parameters
+///
+/// * `G` - The graph type (e.g., `SimpleGraph`)
+/// * `W` - The weight type for edges (e.g., `i32`, `One`)
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MinimumSteinerForest;
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::{BruteForce, Solver};
+///
+/// // Path 0-1-2-3-4-5 with demands (0, 1) and (4, 5).
+/// let graph = SimpleGraph::path(6);
+/// let problem = MinimumSteinerForest::new(graph, vec![1i32; 5], vec![(0, 1), (4, 5)]);
+///
+/// let solution = BruteForce::new().find_witness(&problem).unwrap();
+/// assert_eq!(solution, vec![1, 0, 0, 0, 1]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinimumSteinerForest<G, W> {
+    /// The underlying graph.
+    graph: G,
+    /// Weights for each edge (in edge index order).
+    edge_weights: Vec<W>,
+    /// Terminal pairs that must be connected.
+    terminal_pairs: Vec<(usize, usize)>,
+}
+
+impl<G: Graph, W: Clone + Default> MinimumSteinerForest<G, W> {
+    /// Create a MinimumSteinerForest problem from a graph, edge weights, and terminal pairs.
+    pub fn new(graph: G, edge_weights: Vec<W>, terminal_pairs: Vec<(usize, usize)>) -> Self {
+        assert_eq!(
+            edge_weights.len(),
+            graph.num_edges(),
+            "edge_weights length must match num_edges"
+        );
+        assert!(
+            !terminal_pairs.is_empty(),
+            "at least 1 terminal pair required"
+        );
+        let n = graph.num_vertices();
+        for &(s, t) in &terminal_pairs {
+            assert!(
+                s < n && t < n,
+                "terminal pair ({s}, {t}) out of range (num_vertices = {n})"
+            );
+            assert_ne!(
+                s, t,
+                "terminal pair ({s}, {t}) must have distinct endpoints"
+            );
+        }
+        Self {
+            graph,
+            edge_weights,
+            terminal_pairs,
+        }
+    }
+
+    /// Create a MinimumSteinerForest problem with unit edge weights.
+    pub fn unit_weights(graph: G, terminal_pairs: Vec<(usize, usize)>) -> Self
+    where
+        W: From<i32>,
+    {
+        let edge_weights = vec![W::from(1); graph.num_edges()];
+        Self::new(graph, edge_weights, terminal_pairs)
+    }
+
+    /// Get a reference to the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Get a reference to the edge weights.
+    pub fn edge_weights(&self) -> &[W] {
+        &self.edge_weights
+    }
+
+    /// Set new edge weights.
+    pub fn set_weights(&mut self, weights: Vec<W>) {
+        assert_eq!(weights.len(), self.graph.num_edges());
+        self.edge_weights = weights;
+    }
+
+    /// Get the edge weights as a Vec.
+    pub fn weights(&self) -> Vec<W> {
+        self.edge_weights.clone()
+    }
+
+    /// Get the terminal pairs.
+    pub fn terminal_pairs(&self) -> &[(usize, usize)] {
+        &self.terminal_pairs
+    }
+
+    /// Check if the problem uses a non-unit weight type.
+    pub fn is_weighted(&self) -> bool
+    where
+        W: WeightElement,
+    {
+        !W::IS_UNIT
+    }
+
+    /// Check if a configuration is a valid Steiner forest.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        is_valid_steiner_forest(&self.graph, &self.terminal_pairs, config)
+    }
+}
+
+impl<G: Graph, W: WeightElement> MinimumSteinerForest<G, W> {
+    /// Get the number of vertices in the underlying graph.
+    pub fn num_vertices(&self) -> usize {
+        self.graph.num_vertices()
+    }
+
+    /// Get the number of edges in the underlying graph.
+    pub fn num_edges(&self) -> usize {
+        self.graph.num_edges()
+    }
+
+    /// Get the number of terminal pairs.
+    pub fn num_terminal_pairs(&self) -> usize {
+        self.terminal_pairs.len()
+    }
+}
+
+/// Find the root of `v` with path halving.
+fn find(parent: &mut [usize], mut v: usize) -> usize {
+    while parent[v] != v {
+        parent[v] = parent[parent[v]];
+        v = parent[v];
+    }
+    v
+}
+
+/// Check if a configuration forms a valid Steiner forest:
+/// 1. Selected edges are acyclic (no edge joins two already-connected vertices)
+/// 2. Every terminal pair ends up in the same union-find component
+fn is_valid_steiner_forest<G: Graph>(
+    graph: &G,
+    terminal_pairs: &[(usize, usize)],
+    config: &[usize],
+) -> bool {
+    let edges = graph.edges();
+    if config.len() != edges.len() {
+        return false;
+    }
+
+    let mut parent: Vec<usize> = (0..graph.num_vertices()).collect();
+    for (idx, &sel) in config.iter().enumerate() {
+        if sel == 1 {
+            let (u, v) = edges[idx];
+            let (ru, rv) = (find(&mut parent, u), find(&mut parent, v));
+            if ru == rv {
+                return false;
+            }
+            parent[ru] = rv;
+        }
+    }
+
+    terminal_pairs
+        .iter()
+        .all(|&(s, t)| find(&mut parent, s) == find(&mut parent, t))
+}
+
+impl<G, W> Problem for MinimumSteinerForest<G, W>
+where
+    G: Graph + crate::variant::VariantParam,
+    W: WeightElement + crate::variant::VariantParam,
+{
+    const NAME: &'static str = "MinimumSteinerForest";
+    type Value = Min<W::Sum>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![G, W]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.graph.num_edges()]
+    }
+
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
+        if !is_valid_steiner_forest(&self.graph, &self.terminal_pairs, config) {
+            return Min(None);
+        }
+        let mut total = W::Sum::zero();
+        for (idx, &selected) in config.iter().enumerate() {
+            if selected == 1 {
+                if let Some(w) = self.edge_weights.get(idx) {
+                    total += w.to_sum();
+                }
+            }
+        }
+        Min(Some(total))
+    }
+}
+
+crate::declare_variants! {
+    default MinimumSteinerForest<SimpleGraph, i32> => "2^num_edges",
+    MinimumSteinerForest<SimpleGraph, One> => "2^num_edges",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "minimum_steiner_forest_simplegraph_i32",
+        instance: Box::new(MinimumSteinerForest::new(
+            SimpleGraph::new(
+                6,
+                vec![(0, 1), (1, 2), (0, 3), (2, 5), (3, 4), (4, 5), (1, 4)],
+            ),
+            vec![1, 1, 4, 4, 1, 1, 3],
+            vec![(0, 2), (3, 5)],
+        )),
+        optimal_config: vec![1, 1, 0, 0, 1, 1, 0],
+        optimal_value: serde_json::json!(4),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/minimum_steiner_forest.rs"]
+mod tests;
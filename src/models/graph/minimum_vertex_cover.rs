@@ -1,11 +1,15 @@
 //! Vertex Covering problem implementation.
 //!
 //! The Vertex Cover problem asks for a minimum weight subset of vertices
-//! such that every edge has at least one endpoint in the subset.
+//! such that every edge has at least one endpoint in the subset. On a
+//! [`HyperGraph`] every hyperedge must contain a selected vertex, which is the
+//! hitting set problem (the dual of set covering).
 
 use crate::models::decision::Decision;
 use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
-use crate::topology::{Graph, SimpleGraph};
+use crate::topology::{
+    Graph, HyperGraph, KingsSubgraph, SimpleGraph, TriangularSubgraph, UnitDiskGraph,
+};
 use crate::traits::Problem;
 use crate::types::{Min, One, WeightElement};
 use num_traits::Zero;
@@ -17,11 +21,11 @@ inventory::submit! {
         display_name: "Minimum Vertex Cover",
         aliases: &["MVC"],
         dimensions: &[
-            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph", "KingsSubgraph", "TriangularSubgraph", "UnitDiskGraph", "HyperGraph"]),
             VariantDimension::new("weight", "i32", &["i32", "One"]),
         ],
         module_path: module_path!(),
-        description: "Find minimum weight vertex cover in a graph",
+        description: "Find minimum weight vertex cover in a graph or hypergraph",
         fields: &[
             FieldInfo { name: "graph", type_name: "G", description: "The underlying graph G=(V,E)" },
             FieldInfo { name: "weights", type_name: "Vec<W>", description: "Vertex weights w: V -> R" },
@@ -36,6 +40,9 @@ inventory::submit! {
 /// - Every edge has at least one endpoint in S (covering constraint)
 /// - The total weight Σ_{v ∈ S} w_v is minimized
 ///
+/// With `G = HyperGraph` the covering constraint asks every hyperedge to
+/// contain a vertex of S (hypergraph vertex cover, a.k.a. hitting set).
+///
 /// # Example
 ///
 /// ```
@@ -62,12 +69,12 @@ pub struct MinimumVertexCover<G, W> {
     weights: Vec<W>,
 }
 
-impl<G: Graph, W: Clone + Default> MinimumVertexCover<G, W> {
+impl<G: VertexCoverTopology, W: Clone + Default> MinimumVertexCover<G, W> {
     /// Create a Vertex Covering problem from a graph with given weights.
     pub fn new(graph: G, weights: Vec<W>) -> Self {
         assert_eq!(
             weights.len(),
-            graph.num_vertices(),
+            graph.cover_num_vertices(),
             "weights length must match graph num_vertices"
         );
         Self { graph, weights }
@@ -93,7 +100,7 @@ impl<G: Graph, W: Clone + Default> MinimumVertexCover<G, W> {
 
     /// Check if a configuration is a valid vertex cover.
     pub fn is_valid_solution(&self, config: &[usize]) -> bool {
-        is_vertex_cover_config(&self.graph, config)
+        self.graph.is_covered_by(config)
     }
 }
 
@@ -125,18 +132,94 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
         if !is_vertex_cover_config(&self.graph, config) {
             return Min(None);
         }
-        let mut total = W::Sum::zero();
-        for (i, &selected) in config.iter().enumerate() {
-            if selected == 1 {
-                total += self.weights[i].to_sum();
-            }
+        Min(Some(selected_weight(&self.weights, config)))
+    }
+
+    fn objective_terms(&self, config: &[usize]) -> Vec<crate::traits::ObjectiveTerm> {
+        vertex_objective_terms(&self.weights, config)
+    }
+}
+
+impl<W: WeightElement> MinimumVertexCover<HyperGraph, W> {
+    /// Get the number of vertices in the underlying hypergraph.
+    pub fn num_vertices(&self) -> usize {
+        self.graph.num_vertices()
+    }
+
+    /// Get the number of hyperedges in the underlying hypergraph.
+    pub fn num_edges(&self) -> usize {
+        self.graph.num_edges()
+    }
+
+    /// Get the size of the largest hyperedge.
+    pub fn rank(&self) -> usize {
+        self.graph.rank()
+    }
+}
+
+impl<W> Problem for MinimumVertexCover<HyperGraph, W>
+where
+    W: WeightElement + crate::variant::VariantParam,
+{
+    const NAME: &'static str = "MinimumVertexCover";
+    type Value = Min<W::Sum>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![HyperGraph, W]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.graph.num_vertices()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
+        if !self.graph.is_covered_by(config) {
+            return Min(None);
         }
-        Min(Some(total))
+        Min(Some(selected_weight(&self.weights, config)))
     }
+
+    fn objective_terms(&self, config: &[usize]) -> Vec<crate::traits::ObjectiveTerm> {
+        vertex_objective_terms(&self.weights, config)
+    }
+}
+
+/// Total weight of the vertices selected by `config`.
+fn selected_weight<W: WeightElement>(weights: &[W], config: &[usize]) -> W::Sum {
+    let mut total = W::Sum::zero();
+    for (i, &selected) in config.iter().enumerate() {
+        if selected == 1 {
+            total += weights[i].to_sum();
+        }
+    }
+    total
+}
+
+/// Per-vertex objective breakdown for `config`.
+fn vertex_objective_terms<W: WeightElement>(
+    weights: &[W],
+    config: &[usize],
+) -> Vec<crate::traits::ObjectiveTerm> {
+    weights
+        .iter()
+        .enumerate()
+        .map(|(i, w)| {
+            let value = if config.get(i) == Some(&1) {
+                w.to_sum()
+            } else {
+                W::Sum::zero()
+            };
+            crate::traits::ObjectiveTerm::new(format!("vertex {i}"), value)
+        })
+        .collect()
 }
 
 /// Check if a configuration forms a valid vertex cover.
@@ -151,9 +234,49 @@ pub(crate) fn is_vertex_cover_config<G: Graph>(graph: &G, config: &[usize]) -> b
     true
 }
 
+/// A topology whose edges a vertex cover must hit.
+///
+/// Implemented for every [`Graph`] (two endpoints per edge) and for
+/// [`HyperGraph`] (any number of vertices per hyperedge).
+pub trait VertexCoverTopology {
+    /// Number of vertices that may be selected.
+    fn cover_num_vertices(&self) -> usize;
+
+    /// Check whether the vertices selected by `config` (1 = selected) hit
+    /// every edge.
+    fn is_covered_by(&self, config: &[usize]) -> bool;
+}
+
+impl<G: Graph> VertexCoverTopology for G {
+    fn cover_num_vertices(&self) -> usize {
+        self.num_vertices()
+    }
+
+    fn is_covered_by(&self, config: &[usize]) -> bool {
+        is_vertex_cover_config(self, config)
+    }
+}
+
+impl VertexCoverTopology for HyperGraph {
+    fn cover_num_vertices(&self) -> usize {
+        self.num_vertices()
+    }
+
+    fn is_covered_by(&self, config: &[usize]) -> bool {
+        self.edges().iter().all(|edge| {
+            edge.iter()
+                .any(|&v| config.get(v).copied().unwrap_or(0) == 1)
+        })
+    }
+}
+
 crate::declare_variants! {
     default MinimumVertexCover<SimpleGraph, i32> => "1.1996^num_vertices",
     MinimumVertexCover<SimpleGraph, One> => "1.1996^num_vertices",
+    MinimumVertexCover<KingsSubgraph, i32> => "2^sqrt(num_vertices)",
+    MinimumVertexCover<TriangularSubgraph, i32> => "2^sqrt(num_vertices)",
+    MinimumVertexCover<UnitDiskGraph, i32> => "2^sqrt(num_vertices)",
+    MinimumVertexCover<HyperGraph, i32> => "2^num_vertices",
 }
 
 impl<G, W> crate::models::decision::DecisionProblemMeta for MinimumVertexCover<G, W>
@@ -285,6 +408,24 @@ pub(crate) fn is_vertex_cover<G: Graph>(graph: &G, selected: &[bool]) -> bool {
     true
 }
 
+/// Check if a set of vertices forms a vertex cover of a hypergraph, i.e.
+/// hits every hyperedge.
+///
+/// # Panics
+/// Panics if `selected.len() != graph.num_vertices()`.
+#[cfg(test)]
+pub(crate) fn is_hypergraph_vertex_cover(graph: &HyperGraph, selected: &[bool]) -> bool {
+    assert_eq!(
+        selected.len(),
+        graph.num_vertices(),
+        "selected length must match num_vertices"
+    );
+    graph
+        .edges()
+        .iter()
+        .all(|edge| edge.iter().any(|&v| selected[v]))
+}
+
 #[cfg(test)]
 #[path = "../../unit_tests/models/graph/minimum_vertex_cover.rs"]
 mod tests;
@@ -8,12 +8,15 @@
 //! - [`MaximumIndependentSet`]: Maximum weight independent set
 //! - [`MaximumLeafSpanningTree`]: Spanning tree maximizing number of leaves
 //! - [`MaximalIS`]: Maximal independent set
+//! - [`MinimumMaximalIS`]: Minimum weight maximal independent set (independent dominating set)
 //! - [`MinimumVertexCover`]: Minimum weight vertex cover
+//! - [`MinimumCliqueCover`]: Partition vertices into the minimum number of cliques
 //! - [`MinimumCoveringByCliques`]: Minimum number of cliques covering all edges
 //! - [`MonochromaticTriangle`]: 2-color edges so that no triangle is monochromatic
 //! - [`MinimumIntersectionGraphBasis`]: Minimum universe size for intersection graph representation
 //! - [`MinimumCapacitatedSpanningTree`]: Minimum weight spanning tree with subtree capacity constraints
 //! - [`MinimumDominatingSet`]: Minimum dominating set
+//! - [`MinimumEdgeDominatingSet`]: Minimum edge dominating set
 //! - [`MinimumMetricDimension`]: Minimum resolving set (metric dimension)
 //! - [`MinimumEdgeCostFlow`]: Minimum edge-cost integral flow
 //! - [`MinimumGeometricConnectedDominatingSet`]: Minimum connected dominating set in a geometric point set
@@ -22,6 +25,7 @@
 //! - [`MaximumAchromaticNumber`]: Maximum number of colors in a complete proper coloring
 //! - [`MaximumDomaticNumber`]: Maximum partition into disjoint dominating sets
 //! - [`MaxCut`]: Maximum cut on weighted graphs
+//! - [`MaxBisection`]: Maximum cut among balanced bisections
 //! - [`MinimumCutIntoBoundedSets`]: Minimum cut into bounded sets (Garey & Johnson ND17)
 //! - [`MinimumDummyActivitiesPert`]: Minimum dummy activities in activity-on-arc PERT networks
 //! - [`HamiltonianCircuit`]: Hamiltonian circuit (decision problem)
@@ -32,9 +36,11 @@
 //! - [`KColoring`]: K-vertex coloring
 //! - [`PartitionIntoTriangles`]: Partition vertices into triangles
 //! - [`MaximumMatching`]: Maximum weight matching
+//! - [`MaximumPlanarSubgraph`]: Maximum weight planar edge subset
 //! - [`MinimumMaximalMatching`]: Minimum-size maximal matching
 //! - [`TravelingSalesman`]: Traveling Salesman (minimum weight Hamiltonian cycle)
 //! - [`SpinGlass`]: Ising model Hamiltonian
+//! - [`PolySpinGlass`]: Ising model Hamiltonian with higher-order terms on a hypergraph
 //! - [`MinimumMultiwayCut`]: Minimum weight multiway cut
 //! - [`HamiltonianPath`]: Hamiltonian path (simple path visiting every vertex)
 //! - [`HamiltonianPathBetweenTwoVertices`]: Hamiltonian path between two specified vertices (decision problem)
@@ -47,6 +53,7 @@
 //! - [`BicliqueCover`]: Biclique cover on bipartite graphs
 //! - [`SteinerTreeInGraphs`]: Minimum weight Steiner tree connecting terminal vertices
 //! - [`BalancedCompleteBipartiteSubgraph`]: Balanced biclique decision problem
+//! - [`MaximumBalancedBiclique`]: Largest balanced biclique in a bipartite graph
 //! - [`BiconnectivityAugmentation`]: Biconnectivity augmentation with weighted potential edges
 //! - [`BoundedComponentSpanningForest`]: Partition vertices into bounded-weight connected components
 //! - [`BottleneckTravelingSalesman`]: Hamiltonian cycle minimizing the maximum selected edge weight
@@ -55,6 +62,7 @@
 //! - [`PartialFeedbackEdgeSet`]: Remove at most K edges to hit every short cycle
 //! - [`RootedTreeArrangement`]: Rooted-tree embedding with bounded total edge stretch
 //! - [`MinimumFeedbackArcSet`]: Minimum feedback arc set on directed graphs
+//! - [`MaximumAcyclicSubgraph`]: Maximum weight acyclic arc subset of a directed graph
 //! - [`MinMaxMulticenter`]: Min-max multicenter (vertex p-center, satisfaction)
 //! - [`MinimumSumMulticenter`]: Min-sum multicenter (p-median)
 //! - [`MultipleChoiceBranching`]: Directed branching with partition constraints
@@ -63,6 +71,8 @@
 //! - [`RuralPostman`]: Rural Postman (circuit covering required edges)
 //! - [`MixedChinesePostman`]: Mixed-graph postman tour with bounded total length
 //! - [`SteinerTree`]: Minimum-weight tree spanning all required terminals
+//! - [`MinimumSteinerForest`]: Minimum-weight forest connecting every terminal pair
+//! - [`ClusterEditing`]: Minimum edge edits turning a graph into disjoint cliques
 //! - [`SubgraphIsomorphism`]: Subgraph isomorphism (decision problem)
 //! - [`DirectedTwoCommodityIntegralFlow`]: Directed two-commodity integral flow (satisfaction)
 //! - [`IntegralFlowBundles`]: Integral flow feasibility with overlapping bundle capacities
@@ -81,6 +91,7 @@ pub(crate) mod biconnectivity_augmentation;
 pub(crate) mod bottleneck_traveling_salesman;
 pub(crate) mod bounded_component_spanning_forest;
 pub(crate) mod bounded_diameter_spanning_tree;
+pub(crate) mod cluster_editing;
 pub(crate) mod degree_constrained_spanning_tree;
 pub(crate) mod directed_hamiltonian_path;
 pub(crate) mod directed_two_commodity_integral_flow;
@@ -101,29 +112,37 @@ pub(crate) mod kth_best_spanning_tree;
 pub(crate) mod length_bounded_disjoint_paths;
 pub(crate) mod longest_circuit;
 pub(crate) mod longest_path;
+pub(crate) mod max_bisection;
 pub(crate) mod max_cut;
 pub(crate) mod maximal_is;
 pub(crate) mod maximum_achromatic_number;
+pub(crate) mod maximum_acyclic_subgraph;
+pub(crate) mod maximum_balanced_biclique;
 pub(crate) mod maximum_clique;
 pub(crate) mod maximum_domatic_number;
 pub(crate) mod maximum_independent_set;
 pub(crate) mod maximum_leaf_spanning_tree;
 pub(crate) mod maximum_matching;
+pub(crate) mod maximum_planar_subgraph;
 pub(crate) mod min_max_multicenter;
 pub(crate) mod minimum_capacitated_spanning_tree;
+pub(crate) mod minimum_clique_cover;
 pub(crate) mod minimum_covering_by_cliques;
 pub(crate) mod minimum_cut_into_bounded_sets;
 pub(crate) mod minimum_dominating_set;
 pub(crate) mod minimum_dummy_activities_pert;
 pub(crate) mod minimum_edge_cost_flow;
+pub(crate) mod minimum_edge_dominating_set;
 pub(crate) mod minimum_feedback_arc_set;
 pub(crate) mod minimum_feedback_vertex_set;
 pub(crate) mod minimum_geometric_connected_dominating_set;
 pub(crate) mod minimum_graph_bandwidth;
 pub(crate) mod minimum_intersection_graph_basis;
+pub(crate) mod minimum_maximal_is;
 pub(crate) mod minimum_maximal_matching;
 pub(crate) mod minimum_metric_dimension;
 pub(crate) mod minimum_multiway_cut;
+pub(crate) mod minimum_steiner_forest;
 pub(crate) mod minimum_sum_multicenter;
 pub(crate) mod minimum_vertex_cover;
 pub(crate) mod mixed_chinese_postman;
@@ -138,6 +157,7 @@ pub(crate) mod partition_into_paths_of_length_2;
 pub(crate) mod partition_into_perfect_matchings;
 pub(crate) mod partition_into_triangles;
 pub(crate) mod path_constrained_network_flow;
+pub(crate) mod poly_spin_glass;
 pub(crate) mod rooted_tree_arrangement;
 pub(crate) mod rural_postman;
 pub(crate) mod shortest_weight_constrained_path;
@@ -156,6 +176,7 @@ pub use biconnectivity_augmentation::BiconnectivityAugmentation;
 pub use bottleneck_traveling_salesman::BottleneckTravelingSalesman;
 pub use bounded_component_spanning_forest::BoundedComponentSpanningForest;
 pub use bounded_diameter_spanning_tree::BoundedDiameterSpanningTree;
+pub use cluster_editing::ClusterEditing;
 pub use degree_constrained_spanning_tree::DegreeConstrainedSpanningTree;
 pub use directed_hamiltonian_path::DirectedHamiltonianPath;
 pub use directed_two_commodity_integral_flow::DirectedTwoCommodityIntegralFlow;
@@ -176,31 +197,39 @@ pub use kth_best_spanning_tree::KthBestSpanningTree;
 pub use length_bounded_disjoint_paths::LengthBoundedDisjointPaths;
 pub use longest_circuit::LongestCircuit;
 pub use longest_path::LongestPath;
+pub use max_bisection::MaxBisection;
 pub use max_cut::MaxCut;
 pub use maximal_is::MaximalIS;
 pub use maximum_achromatic_number::MaximumAchromaticNumber;
-pub use maximum_clique::MaximumClique;
+pub use maximum_acyclic_subgraph::MaximumAcyclicSubgraph;
+pub use maximum_balanced_biclique::MaximumBalancedBiclique;
+pub use maximum_clique::{maximal_cliques, MaximumClique};
 pub use maximum_domatic_number::MaximumDomaticNumber;
 pub use maximum_independent_set::MaximumIndependentSet;
 pub use maximum_leaf_spanning_tree::MaximumLeafSpanningTree;
 pub use maximum_matching::MaximumMatching;
+pub use maximum_planar_subgraph::MaximumPlanarSubgraph;
 pub use min_max_multicenter::MinMaxMulticenter;
 pub use minimum_capacitated_spanning_tree::MinimumCapacitatedSpanningTree;
+pub use minimum_clique_cover::MinimumCliqueCover;
 pub use minimum_covering_by_cliques::MinimumCoveringByCliques;
 pub use minimum_cut_into_bounded_sets::MinimumCutIntoBoundedSets;
 pub use minimum_dominating_set::MinimumDominatingSet;
 pub use minimum_dummy_activities_pert::MinimumDummyActivitiesPert;
 pub use minimum_edge_cost_flow::MinimumEdgeCostFlow;
+pub use minimum_edge_dominating_set::MinimumEdgeDominatingSet;
 pub use minimum_feedback_arc_set::MinimumFeedbackArcSet;
 pub use minimum_feedback_vertex_set::MinimumFeedbackVertexSet;
 pub use minimum_geometric_connected_dominating_set::MinimumGeometricConnectedDominatingSet;
 pub use minimum_graph_bandwidth::MinimumGraphBandwidth;
 pub use minimum_intersection_graph_basis::MinimumIntersectionGraphBasis;
+pub use minimum_maximal_is::MinimumMaximalIS;
 pub use minimum_maximal_matching::MinimumMaximalMatching;
 pub use minimum_metric_dimension::MinimumMetricDimension;
 pub use minimum_multiway_cut::MinimumMultiwayCut;
+pub use minimum_steiner_forest::MinimumSteinerForest;
 pub use minimum_sum_multicenter::MinimumSumMulticenter;
-pub use minimum_vertex_cover::MinimumVertexCover;
+pub use minimum_vertex_cover::{MinimumVertexCover, VertexCoverTopology};
 pub use mixed_chinese_postman::MixedChinesePostman;
 pub use monochromatic_triangle::MonochromaticTriangle;
 pub use multiple_choice_branching::MultipleChoiceBranching;
@@ -213,6 +242,7 @@ pub use partition_into_paths_of_length_2::PartitionIntoPathsOfLength2;
 pub use partition_into_perfect_matchings::PartitionIntoPerfectMatchings;
 pub use partition_into_triangles::PartitionIntoTriangles;
 pub use path_constrained_network_flow::PathConstrainedNetworkFlow;
+pub use poly_spin_glass::PolySpinGlass;
 pub use rooted_tree_arrangement::RootedTreeArrangement;
 pub use rural_postman::RuralPostman;
 pub use shortest_weight_constrained_path::ShortestWeightConstrainedPath;
@@ -236,6 +266,7 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(minimum_vertex_cover::canonical_model_example_specs());
     specs.extend(minimum_vertex_cover::decision_canonical_model_example_specs());
     specs.extend(max_cut::canonical_model_example_specs());
+    specs.extend(max_bisection::canonical_model_example_specs());
     specs.extend(generalized_hex::canonical_model_example_specs());
     specs.extend(hamiltonian_circuit::canonical_model_example_specs());
     specs.extend(hamiltonian_path::canonical_model_example_specs());
@@ -250,20 +281,24 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(length_bounded_disjoint_paths::canonical_model_example_specs());
     specs.extend(longest_circuit::canonical_model_example_specs());
     specs.extend(longest_path::canonical_model_example_specs());
+    specs.extend(minimum_clique_cover::canonical_model_example_specs());
     specs.extend(minimum_covering_by_cliques::canonical_model_example_specs());
     specs.extend(monochromatic_triangle::canonical_model_example_specs());
     specs.extend(minimum_intersection_graph_basis::canonical_model_example_specs());
     specs.extend(minimum_dominating_set::canonical_model_example_specs());
     specs.extend(minimum_dominating_set::decision_canonical_model_example_specs());
+    specs.extend(minimum_edge_dominating_set::canonical_model_example_specs());
     specs.extend(minimum_metric_dimension::canonical_model_example_specs());
     specs.extend(minimum_geometric_connected_dominating_set::canonical_model_example_specs());
     specs.extend(maximum_matching::canonical_model_example_specs());
+    specs.extend(maximum_planar_subgraph::canonical_model_example_specs());
     specs.extend(minimum_maximal_matching::canonical_model_example_specs());
     specs.extend(traveling_salesman::canonical_model_example_specs());
     specs.extend(maximum_achromatic_number::canonical_model_example_specs());
     specs.extend(maximum_domatic_number::canonical_model_example_specs());
     specs.extend(maximum_clique::canonical_model_example_specs());
     specs.extend(maximal_is::canonical_model_example_specs());
+    specs.extend(minimum_maximal_is::canonical_model_example_specs());
     specs.extend(minimum_cut_into_bounded_sets::canonical_model_example_specs());
     specs.extend(minimum_dummy_activities_pert::canonical_model_example_specs());
     specs.extend(multiple_copy_file_allocation::canonical_model_example_specs());
@@ -274,19 +309,23 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(shortest_weight_constrained_path::canonical_model_example_specs());
     specs.extend(multiple_choice_branching::canonical_model_example_specs());
     specs.extend(spin_glass::canonical_model_example_specs());
+    specs.extend(poly_spin_glass::canonical_model_example_specs());
     specs.extend(biclique_cover::canonical_model_example_specs());
     specs.extend(balanced_complete_bipartite_subgraph::canonical_model_example_specs());
+    specs.extend(maximum_balanced_biclique::canonical_model_example_specs());
     specs.extend(biconnectivity_augmentation::canonical_model_example_specs());
     specs.extend(bottleneck_traveling_salesman::canonical_model_example_specs());
     specs.extend(bounded_component_spanning_forest::canonical_model_example_specs());
     specs.extend(partition_into_triangles::canonical_model_example_specs());
     specs.extend(partition_into_cliques::canonical_model_example_specs());
+    specs.extend(cluster_editing::canonical_model_example_specs());
     specs.extend(partition_into_forests::canonical_model_example_specs());
     specs.extend(partition_into_perfect_matchings::canonical_model_example_specs());
     specs.extend(partition_into_paths_of_length_2::canonical_model_example_specs());
     specs.extend(path_constrained_network_flow::canonical_model_example_specs());
     specs.extend(rooted_tree_arrangement::canonical_model_example_specs());
     specs.extend(steiner_tree::canonical_model_example_specs());
+    specs.extend(minimum_steiner_forest::canonical_model_example_specs());
     specs.extend(steiner_tree_in_graphs::canonical_model_example_specs());
     specs.extend(directed_two_commodity_integral_flow::canonical_model_example_specs());
     specs.extend(disjoint_connecting_paths::canonical_model_example_specs());
@@ -299,6 +338,7 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(minimum_edge_cost_flow::canonical_model_example_specs());
     specs.extend(minimum_graph_bandwidth::canonical_model_example_specs());
     specs.extend(minimum_feedback_arc_set::canonical_model_example_specs());
+    specs.extend(maximum_acyclic_subgraph::canonical_model_example_specs());
     specs.extend(optimal_linear_arrangement::canonical_model_example_specs());
     specs.extend(partial_feedback_edge_set::canonical_model_example_specs());
     specs.extend(mixed_chinese_postman::canonical_model_example_specs());
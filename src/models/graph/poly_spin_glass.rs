@@ -0,0 +1,245 @@
+//! Polynomial Spin Glass (higher-order Ising model) problem implementation.
+//!
+//! Generalizes [`SpinGlass`](super::SpinGlass) from pairwise couplings to
+//! couplings over arbitrary spin subsets stored on a [`HyperGraph`].
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
+use crate::topology::HyperGraph;
+use crate::traits::Problem;
+use crate::types::{Min, WeightElement};
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "PolySpinGlass",
+        display_name: "Polynomial Spin Glass",
+        aliases: &[],
+        dimensions: &[
+            VariantDimension::new("weight", "i32", &["i32", "f64"]),
+        ],
+        module_path: module_path!(),
+        description: "Minimize an Ising Hamiltonian with higher-order terms on a hypergraph",
+        fields: &[
+            FieldInfo { name: "graph", type_name: "HyperGraph", description: "The interaction hypergraph" },
+            FieldInfo { name: "couplings", type_name: "Vec<W>", description: "Couplings J_e, one per hyperedge" },
+            FieldInfo { name: "fields", type_name: "Vec<W>", description: "On-site fields h_i" },
+        ],
+    }
+}
+
+/// The Polynomial Spin Glass (higher-order Ising model) problem.
+///
+/// Given n spin variables s_i in {-1, +1}, a hypergraph whose hyperedges e
+/// carry couplings J_e, and on-site fields h_i, minimize the Hamiltonian:
+///
+/// H(s) = sum_e J_e * prod_{i in e} s_i + sum_i h_i * s_i
+///
+/// A [`SpinGlass`](super::SpinGlass) is the special case where every
+/// hyperedge has exactly two spins.
+///
+/// # Representation
+///
+/// Variables are binary (0 or 1), mapped to spins via: s = 2*x - 1
+/// - x = 0 -> s = -1
+/// - x = 1 -> s = +1
+///
+/// # Type Parameters
+///
+/// * `W` - The weight type for couplings (e.g., `i32`, `f64`)
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::PolySpinGlass;
+/// use problemreductions::{BruteForce, Solver};
+///
+/// // A single 3-body term J = 1: the ground states have an odd number of down spins.
+/// let problem = PolySpinGlass::<i32>::without_fields(3, vec![(vec![0, 1, 2], 1)]);
+///
+/// let solutions = BruteForce::new().find_all_witnesses(&problem);
+/// assert_eq!(solutions.len(), 4);
+/// for sol in &solutions {
+///     assert_eq!(sol.iter().filter(|&&x| x == 0).count() % 2, 1);
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolySpinGlass<W> {
+    /// The interaction hypergraph.
+    graph: HyperGraph,
+    /// Coupling terms J_e, one per hyperedge in graph.edges() order.
+    couplings: Vec<W>,
+    /// On-site fields h_i.
+    fields: Vec<W>,
+}
+
+impl<W: Clone> PolySpinGlass<W> {
+    /// Create a new Polynomial Spin Glass problem.
+    ///
+    /// # Arguments
+    /// * `num_spins` - Number of spin variables
+    /// * `interactions` - Coupling terms J_e as (spins, value)
+    /// * `fields` - On-site fields h_i
+    pub fn new(num_spins: usize, interactions: Vec<(Vec<usize>, W)>, fields: Vec<W>) -> Self {
+        let (edges, couplings) = interactions.into_iter().unzip();
+        Self::from_hypergraph(HyperGraph::new(num_spins, edges), couplings, fields)
+    }
+
+    /// Create a Polynomial Spin Glass with no on-site fields.
+    pub fn without_fields(num_spins: usize, interactions: Vec<(Vec<usize>, W)>) -> Self
+    where
+        W: num_traits::Zero,
+    {
+        Self::new(num_spins, interactions, vec![W::zero(); num_spins])
+    }
+
+    /// Create a Polynomial Spin Glass from a hypergraph with specified couplings.
+    ///
+    /// # Arguments
+    /// * `graph` - The interaction hypergraph
+    /// * `couplings` - Coupling terms (must match graph.num_edges())
+    /// * `fields` - On-site fields h_i
+    pub fn from_hypergraph(graph: HyperGraph, couplings: Vec<W>, fields: Vec<W>) -> Self {
+        assert_eq!(
+            couplings.len(),
+            graph.num_edges(),
+            "couplings length must match num_edges"
+        );
+        assert_eq!(
+            fields.len(),
+            graph.num_vertices(),
+            "fields length must match num_vertices"
+        );
+        Self {
+            graph,
+            couplings,
+            fields,
+        }
+    }
+
+    /// Get a reference to the interaction hypergraph.
+    pub fn graph(&self) -> &HyperGraph {
+        &self.graph
+    }
+
+    /// Get the number of spins.
+    pub fn num_spins(&self) -> usize {
+        self.graph.num_vertices()
+    }
+
+    /// Get the number of interaction terms (hyperedges).
+    pub fn num_interactions(&self) -> usize {
+        self.graph.num_edges()
+    }
+
+    /// Get the largest number of spins in a single interaction term.
+    pub fn max_order(&self) -> usize {
+        self.graph.rank()
+    }
+
+    /// Get the interactions as (spins, weight) pairs.
+    pub fn interactions(&self) -> Vec<(Vec<usize>, W)> {
+        self.graph
+            .edges()
+            .iter()
+            .cloned()
+            .zip(self.couplings.iter().cloned())
+            .collect()
+    }
+
+    /// Get the couplings (J_e values).
+    pub fn couplings(&self) -> &[W] {
+        &self.couplings
+    }
+
+    /// Get the on-site fields.
+    pub fn fields(&self) -> &[W] {
+        &self.fields
+    }
+
+    /// Convert binary config (0,1) to spin config (-1,+1).
+    pub fn config_to_spins(config: &[usize]) -> Vec<i32> {
+        config.iter().map(|&x| 2 * x as i32 - 1).collect()
+    }
+}
+
+impl<W> PolySpinGlass<W>
+where
+    W: Clone + num_traits::Zero + std::ops::AddAssign + std::ops::Mul<Output = W> + From<i32>,
+{
+    /// Compute the Hamiltonian energy for a spin configuration.
+    pub fn compute_energy(&self, spins: &[i32]) -> W {
+        let mut energy = W::zero();
+
+        // Interaction terms: sum J_e * prod_{i in e} s_i
+        for (edge, j_val) in self.graph.edges().iter().zip(self.couplings.iter()) {
+            let product: i32 = edge
+                .iter()
+                .map(|&i| spins.get(i).copied().unwrap_or(1))
+                .product();
+            energy += j_val.clone() * W::from(product);
+        }
+
+        // On-site terms: sum h_i * s_i
+        for (i, h_val) in self.fields.iter().enumerate() {
+            let s_i = spins.get(i).copied().unwrap_or(1);
+            energy += h_val.clone() * W::from(s_i);
+        }
+
+        energy
+    }
+}
+
+impl<W> Problem for PolySpinGlass<W>
+where
+    W: WeightElement
+        + crate::variant::VariantParam
+        + PartialOrd
+        + num_traits::Num
+        + num_traits::Zero
+        + num_traits::Bounded
+        + std::ops::AddAssign
+        + std::ops::Mul<Output = W>
+        + From<i32>,
+{
+    const NAME: &'static str = "PolySpinGlass";
+    type Value = Min<W::Sum>;
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.graph.num_vertices()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
+        let spins = Self::config_to_spins(config);
+        Min(Some(self.compute_energy(&spins).to_sum()))
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![W]
+    }
+}
+
+crate::declare_variants! {
+    default PolySpinGlass<i32> => "2^num_spins",
+    PolySpinGlass<f64> => "2^num_spins",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // H = s0 s1 s2 - s2 s3 - s0. The 3-body term wants an odd number of down
+    // spins, the pair term aligns s2 and s3, and the field pulls s0 up:
+    // (+1, -1, +1, +1) and (+1, +1, -1, -1) reach the lower bound -3.
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "poly_spin_glass_i32",
+        instance: Box::new(PolySpinGlass::<i32>::new(
+            4,
+            vec![(vec![0, 1, 2], 1), (vec![2, 3], -1)],
+            vec![-1, 0, 0, 0],
+        )),
+        optimal_config: vec![1, 0, 1, 1],
+        optimal_value: serde_json::json!(-3),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/graph/poly_spin_glass.rs"]
+mod tests;
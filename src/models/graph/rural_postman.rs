@@ -24,7 +24,7 @@ inventory::submit! {
         description: "Find a minimum-cost circuit covering all required edges (Rural Postman Problem)",
         fields: &[
             FieldInfo { name: "graph", type_name: "G", description: "The underlying graph G=(V,E)" },
-            FieldInfo { name: "edge_weights", type_name: "Vec<W>", description: "Edge lengths l(e) for each e in E" },
+            FieldInfo { name: "edge_lengths", type_name: "Vec<W>", description: "Edge lengths l(e) for each e in E" },
             FieldInfo { name: "required_edges", type_name: "Vec<usize>", description: "Edge indices of the required subset E' ⊆ E" },
         ],
     }
@@ -260,6 +260,10 @@ where
         vec![3; self.graph.num_edges()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
         Min(self.is_valid_solution(config))
     }
@@ -7,6 +7,7 @@ use crate::topology::{Graph, SimpleGraph};
 use crate::traits::Problem;
 use crate::types::{Min, WeightElement};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 inventory::submit! {
     ProblemSchemaEntry {
@@ -100,6 +101,50 @@ impl<W: Clone + Default> SpinGlass<SimpleGraph, W> {
         let fields = vec![W::zero(); num_spins];
         Self::new(num_spins, interactions, fields)
     }
+
+    /// Create a Spin Glass from a sparse list of couplings `(i, j, J_ij)`.
+    ///
+    /// The number of spins is `fields.len()`. Couplings on the same pair are
+    /// summed regardless of orientation, and pairs whose total is zero are
+    /// dropped, so the graph contains exactly the interacting pairs. This is
+    /// convenient for inverse-Ising models estimated from pairwise
+    /// correlations, where most pairs do not interact.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coupling references a spin `>= fields.len()` or couples a
+    /// spin to itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::models::graph::SpinGlass;
+    /// use problemreductions::topology::SimpleGraph;
+    ///
+    /// let sg = SpinGlass::<SimpleGraph, f64>::from_correlations(
+    ///     &[(0, 1, 0.5), (2, 1, -1.0), (1, 0, 0.5)],
+    ///     &[0.0, 0.1, 0.0],
+    /// );
+    /// assert_eq!(sg.interactions(), vec![((0, 1), 1.0), ((1, 2), -1.0)]);
+    /// ```
+    pub fn from_correlations(couplings: &[(usize, usize, W)], fields: &[W]) -> Self
+    where
+        W: num_traits::Zero + std::ops::AddAssign,
+    {
+        let num_spins = fields.len();
+        let mut merged: BTreeMap<(usize, usize), W> = BTreeMap::new();
+        for (i, j, w) in couplings {
+            assert!(
+                *i < num_spins && *j < num_spins,
+                "coupling ({i}, {j}) references spin >= num_spins ({num_spins})"
+            );
+            assert!(i != j, "coupling ({i}, {j}) couples a spin to itself");
+            let key = if i < j { (*i, *j) } else { (*j, *i) };
+            *merged.entry(key).or_insert_with(W::zero) += w.clone();
+        }
+        let interactions = merged.into_iter().filter(|(_, w)| !w.is_zero()).collect();
+        Self::new(num_spins, interactions, fields.to_vec())
+    }
 }
 
 impl<G: Graph, W: Clone + Default> SpinGlass<G, W> {
@@ -226,6 +271,10 @@ where
         vec![2; self.graph.num_vertices()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
         let spins = Self::config_to_spins(config);
         Min(Some(self.compute_energy(&spins).to_sum()))
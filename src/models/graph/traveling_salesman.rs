@@ -4,7 +4,7 @@
 //! that visits every vertex exactly once.
 
 use crate::registry::{FieldInfo, ProblemSchemaEntry, VariantDimension};
-use crate::topology::{Graph, SimpleGraph};
+use crate::topology::{DirectedGraph, Graph, SimpleGraph};
 use crate::traits::Problem;
 use crate::types::{Min, WeightElement};
 use num_traits::Zero;
@@ -16,7 +16,7 @@ inventory::submit! {
         display_name: "Traveling Salesman",
         aliases: &["TSP"],
         dimensions: &[
-            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph"]),
+            VariantDimension::new("graph", "SimpleGraph", &["SimpleGraph", "DirectedGraph"]),
             VariantDimension::new("weight", "i32", &["i32"]),
         ],
         module_path: module_path!(),
@@ -45,9 +45,18 @@ inventory::submit! {
 /// - Selected edges form a single connected cycle (no subtours)
 /// - Exactly |V| edges are selected
 ///
+/// # Asymmetric distances
+///
+/// With `G = DirectedGraph` each variable selects an arc instead, so the cost
+/// of travelling i -> j may differ from j -> i. A valid tour selects exactly
+/// one outgoing and one incoming arc per vertex, forming a single directed
+/// cycle; its cost is the sum of the directed legs.
+/// [`from_matrix`](TravelingSalesman::from_matrix) builds this variant from a
+/// full distance matrix.
+///
 /// # Type Parameters
 ///
-/// * `G` - The graph type (e.g., `SimpleGraph`, `KingsSubgraph`)
+/// * `G` - The graph type (e.g., `SimpleGraph`, `KingsSubgraph`, `DirectedGraph`)
 /// * `W` - The weight type for edges (e.g., `i32`, `f64`)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TravelingSalesman<G, W> {
@@ -160,6 +169,10 @@ where
         vec![2; self.graph.num_edges()]
     }
 
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims()).with_graph(&self.graph)
+    }
+
     fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
         if !self.is_valid_hamiltonian_cycle(config) {
             return Min(None);
@@ -176,6 +189,181 @@ where
     }
 }
 
+impl<W: Clone + Default> TravelingSalesman<DirectedGraph, W> {
+    /// Create an asymmetric TravelingSalesman problem from a directed graph
+    /// with one weight per arc (in `graph.arcs()` order).
+    pub fn from_arcs(graph: DirectedGraph, arc_weights: Vec<W>) -> Self {
+        assert_eq!(
+            arc_weights.len(),
+            graph.num_arcs(),
+            "arc_weights length must match num_arcs"
+        );
+        Self {
+            graph,
+            edge_weights: arc_weights,
+        }
+    }
+
+    /// Create an asymmetric TravelingSalesman problem from a distance matrix.
+    ///
+    /// `matrix[i][j]` is the cost of travelling from city `i` to city `j`; it
+    /// need not equal `matrix[j][i]`. The diagonal is ignored. Arcs are laid
+    /// out row by row, so arc `(i, j)` precedes `(i, j + 1)` and `(i + 1, 0)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::models::graph::TravelingSalesman;
+    /// use problemreductions::{BruteForce, Min, Problem, Solver};
+    ///
+    /// // Clockwise 0 -> 1 -> 2 -> 0 costs 3, counter-clockwise costs 30.
+    /// let problem = TravelingSalesman::from_matrix(vec![
+    ///     vec![0, 1, 10],
+    ///     vec![10, 0, 1],
+    ///     vec![1, 10, 0],
+    /// ]);
+    /// assert_eq!(problem.tour_cost(&[0, 1, 2]), Some(3));
+    /// assert_eq!(problem.tour_cost(&[0, 2, 1]), Some(30));
+    /// assert_eq!(BruteForce::new().solve(&problem), Min(Some(3)));
+    /// ```
+    pub fn from_matrix(matrix: Vec<Vec<W>>) -> Self {
+        let n = matrix.len();
+        let mut arcs = Vec::with_capacity(n * n.saturating_sub(1));
+        let mut arc_weights = Vec::with_capacity(n * n.saturating_sub(1));
+        for (i, row) in matrix.into_iter().enumerate() {
+            assert_eq!(row.len(), n, "distance matrix must be square");
+            for (j, w) in row.into_iter().enumerate() {
+                if i != j {
+                    arcs.push((i, j));
+                    arc_weights.push(w);
+                }
+            }
+        }
+        Self::from_arcs(DirectedGraph::new(n, arcs), arc_weights)
+    }
+
+    /// Get a reference to the underlying directed graph.
+    pub fn digraph(&self) -> &DirectedGraph {
+        &self.graph
+    }
+
+    /// Get the arc weights (in `graph.arcs()` order).
+    pub fn arc_weights(&self) -> &[W] {
+        &self.edge_weights
+    }
+
+    /// Check if a configuration selects the arcs of a directed Hamiltonian cycle.
+    pub fn is_valid_tour(&self, config: &[usize]) -> bool {
+        if config.len() != self.graph.num_arcs() || config.iter().any(|&s| s > 1) {
+            return false;
+        }
+        let selected: Vec<bool> = config.iter().map(|&s| s == 1).collect();
+        is_directed_hamiltonian_cycle(&self.graph, &selected)
+    }
+}
+
+impl<W: WeightElement> TravelingSalesman<DirectedGraph, W> {
+    /// Get the number of vertices (cities).
+    pub fn num_vertices(&self) -> usize {
+        self.graph.num_vertices()
+    }
+
+    /// Get the number of arcs.
+    pub fn num_arcs(&self) -> usize {
+        self.graph.num_arcs()
+    }
+
+    /// Cost of visiting the cities in `tour` order and returning to the start.
+    ///
+    /// Sums the directed legs `tour[k] -> tour[k + 1]` and the closing leg.
+    /// Returns `None` if `tour` is not a permutation of the cities or uses a
+    /// missing arc.
+    pub fn tour_cost(&self, tour: &[usize]) -> Option<W::Sum> {
+        let n = self.graph.num_vertices();
+        let mut seen = vec![false; n];
+        if tour.len() != n
+            || tour
+                .iter()
+                .any(|&v| v >= n || std::mem::replace(&mut seen[v], true))
+        {
+            return None;
+        }
+        let arcs = self.graph.arcs();
+        let mut total = W::Sum::zero();
+        for k in 0..n {
+            let leg = (tour[k], tour[(k + 1) % n]);
+            let idx = arcs.iter().position(|&arc| arc == leg)?;
+            total += self.edge_weights[idx].to_sum();
+        }
+        Some(total)
+    }
+}
+
+impl<W> Problem for TravelingSalesman<DirectedGraph, W>
+where
+    W: WeightElement + crate::variant::VariantParam,
+{
+    const NAME: &'static str = "TravelingSalesman";
+    type Value = Min<W::Sum>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![DirectedGraph, W]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.graph.num_arcs()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
+        if !self.is_valid_tour(config) {
+            return Min(None);
+        }
+        let mut total = W::Sum::zero();
+        for (w, _) in self
+            .edge_weights
+            .iter()
+            .zip(config)
+            .filter(|(_, &s)| s == 1)
+        {
+            total += w.to_sum();
+        }
+        Min(Some(total))
+    }
+}
+
+/// Check if a selection of arcs forms a single directed Hamiltonian cycle.
+fn is_directed_hamiltonian_cycle(graph: &DirectedGraph, selected: &[bool]) -> bool {
+    let n = graph.num_vertices();
+    let mut successor = vec![None; n];
+    let mut in_degree = vec![0usize; n];
+    for (&(u, v), _) in graph.arcs().iter().zip(selected).filter(|(_, &s)| s) {
+        if successor[u].replace(v).is_some() {
+            return false;
+        }
+        in_degree[v] += 1;
+    }
+    if n == 0 || in_degree.iter().any(|&d| d != 1) {
+        return false;
+    }
+    // Every vertex has one successor and one predecessor; the cycle through
+    // vertex 0 must cover them all.
+    let mut current = 0;
+    for step in 1..=n {
+        current = match successor[current] {
+            Some(next) => next,
+            None => return false,
+        };
+        if current == 0 {
+            return step == n;
+        }
+    }
+    false
+}
+
 /// Check if a selection of edges forms a valid Hamiltonian cycle.
 ///
 /// # Panics
@@ -261,6 +449,7 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
 
 crate::declare_variants! {
     default TravelingSalesman<SimpleGraph, i32> => "2^num_vertices",
+    TravelingSalesman<DirectedGraph, i32> => "2^num_vertices",
 }
 
 #[cfg(test)]
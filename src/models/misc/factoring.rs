@@ -2,6 +2,13 @@
 //!
 //! The Factoring problem represents integer factorization as a computational problem.
 //! Given a number N, find two factors (a, b) such that a * b = N.
+//!
+//! The optimization objective measures |a * b - N| over factors that are both
+//! at least 2; configurations with a factor 0 or 1 are infeasible, so the
+//! trivial factorization 1 * N is never reported. A prime N therefore has a
+//! nonzero optimum (or none at all if no pair fits the bit budgets), and
+//! [`Factoring::is_valid_solution`] accepts no configuration. An N with more
+//! than two prime factors accepts every split into two nontrivial factors.
 
 use crate::registry::{FieldInfo, ProblemSchemaEntry};
 use crate::traits::Problem;
@@ -20,6 +27,8 @@ inventory::submit! {
             FieldInfo { name: "m", type_name: "usize", description: "Bits for first factor" },
             FieldInfo { name: "n", type_name: "usize", description: "Bits for second factor" },
             FieldInfo { name: "target", type_name: "u64", description: "Number to factor" },
+            FieldInfo { name: "p_bits", type_name: "Option<usize>", description: "Exact bit width of the first factor (highest bit forced to 1)" },
+            FieldInfo { name: "q_bits", type_name: "Option<usize>", description: "Exact bit width of the second factor (highest bit forced to 1)" },
         ],
     }
 }
@@ -29,6 +38,11 @@ inventory::submit! {
 /// Given a number to factor, find two integers that multiply to give
 /// the target number. Variables represent the bits of the two factors.
 ///
+/// Optionally, `p_bits` / `q_bits` require a factor to have exactly that many
+/// significant bits: its bit `p_bits - 1` is 1 and every higher bit is 0.
+/// A width of at least 2 rules out the trivial factor 1, which is how
+/// balanced RSA-style instances (both factors `b` bits) are posed.
+///
 /// # Example
 ///
 /// ```
@@ -48,6 +62,7 @@ inventory::submit! {
 /// }
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "FactoringDef")]
 pub struct Factoring {
     /// Number of bits for the first factor.
     m: usize,
@@ -55,6 +70,12 @@ pub struct Factoring {
     n: usize,
     /// The number to factor.
     target: u64,
+    /// Exact bit width required of the first factor, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    p_bits: Option<usize>,
+    /// Exact bit width required of the second factor, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    q_bits: Option<usize>,
 }
 
 impl Factoring {
@@ -65,7 +86,54 @@ impl Factoring {
     /// * `n` - Number of bits for the second factor
     /// * `target` - The number to factor
     pub fn new(m: usize, n: usize, target: u64) -> Self {
-        Self { m, n, target }
+        Self {
+            m,
+            n,
+            target,
+            p_bits: None,
+            q_bits: None,
+        }
+    }
+
+    /// Create a Factoring problem whose factors have exactly `p_bits` and
+    /// `q_bits` significant bits.
+    ///
+    /// The factors are encoded with exactly that many bits each, so e.g.
+    /// `with_factor_bits(6, 6, 3233)` asks for two 6-bit primes of RSA-3233.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either width is 0.
+    pub fn with_factor_bits(p_bits: usize, q_bits: usize, target: u64) -> Self {
+        Self::try_new(p_bits, q_bits, target, Some(p_bits), Some(q_bits))
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a Factoring problem with optional exact factor widths,
+    /// validating that each width fits its factor's bit budget.
+    pub fn try_new(
+        m: usize,
+        n: usize,
+        target: u64,
+        p_bits: Option<usize>,
+        q_bits: Option<usize>,
+    ) -> Result<Self, String> {
+        for (name, width, budget) in [("p_bits", p_bits, m), ("q_bits", q_bits, n)] {
+            match width {
+                Some(0) => return Err(format!("{name} must be at least 1")),
+                Some(w) if w > budget => {
+                    return Err(format!("{name} = {w} exceeds the {budget} available bits"))
+                }
+                _ => {}
+            }
+        }
+        Ok(Self {
+            m,
+            n,
+            target,
+            p_bits,
+            q_bits,
+        })
     }
 
     /// Get the number of bits for the first factor.
@@ -93,6 +161,38 @@ impl Factoring {
         self.target
     }
 
+    /// Get the exact bit width required of the first factor, if any.
+    pub fn p_bits(&self) -> Option<usize> {
+        self.p_bits
+    }
+
+    /// Get the exact bit width required of the second factor, if any.
+    pub fn q_bits(&self) -> Option<usize> {
+        self.q_bits
+    }
+
+    /// Configuration bits pinned by the width constraints, as
+    /// `(variable index, required value)` pairs.
+    ///
+    /// A width `w` pins the factor's bit `w - 1` to 1 and its higher bits to 0.
+    pub fn fixed_bits(&self) -> Vec<(usize, usize)> {
+        let mut fixed = Vec::new();
+        for (offset, budget, width) in [(0, self.m, self.p_bits), (self.m, self.n, self.q_bits)] {
+            if let Some(w) = width {
+                fixed.push((offset + w - 1, 1));
+                fixed.extend((w..budget).map(|bit| (offset + bit, 0)));
+            }
+        }
+        fixed
+    }
+
+    /// Check whether a configuration respects the factor width constraints.
+    pub fn satisfies_bit_widths(&self, config: &[usize]) -> bool {
+        self.fixed_bits()
+            .into_iter()
+            .all(|(index, value)| config[index] == value)
+    }
+
     /// Read the two factors from a configuration.
     ///
     /// The first `m` bits represent the first factor,
@@ -103,15 +203,57 @@ impl Factoring {
         (a, b)
     }
 
-    /// Check if a configuration is a valid factorization.
+    /// Check if a configuration is a nontrivial factorization.
+    ///
+    /// Unlike [`Factoring::is_valid_factorization`], this rejects `1 * N`, so
+    /// no configuration is valid when the target is prime.
     pub fn is_valid_solution(&self, config: &[usize]) -> bool {
-        self.is_valid_factorization(config)
+        self.is_nontrivial_factorization(config)
     }
 
-    /// Check if the configuration is a valid factorization.
+    /// Check if the configuration is a valid factorization that respects
+    /// the factor width constraints.
+    ///
+    /// Trivial factorizations `1 * N` and `N * 1` are accepted.
     pub fn is_valid_factorization(&self, config: &[usize]) -> bool {
         let (a, b) = self.read_factors(config);
-        a * b == self.target
+        is_factoring(self.target, a, b) && self.satisfies_bit_widths(config)
+    }
+
+    /// Check if the configuration is a valid factorization with both
+    /// factors at least 2.
+    pub fn is_nontrivial_factorization(&self, config: &[usize]) -> bool {
+        let (a, b) = self.read_factors(config);
+        nontrivial_factors(a, b) && self.is_valid_factorization(config)
+    }
+
+    /// Check whether the target is a product of exactly two primes
+    /// (counted with multiplicity), e.g. 15 = 3 * 5 or 9 = 3 * 3.
+    ///
+    /// Composite targets that are not semiprime, such as 30 = 2 * 3 * 5,
+    /// still have nontrivial factorizations, just more than one unordered
+    /// pair of them.
+    pub fn is_semiprime(&self) -> bool {
+        count_prime_factors(self.target) == 2
+    }
+}
+
+#[derive(Deserialize)]
+struct FactoringDef {
+    m: usize,
+    n: usize,
+    target: u64,
+    #[serde(default)]
+    p_bits: Option<usize>,
+    #[serde(default)]
+    q_bits: Option<usize>,
+}
+
+impl TryFrom<FactoringDef> for Factoring {
+    type Error = String;
+
+    fn try_from(value: FactoringDef) -> Result<Self, Self::Error> {
+        Self::try_new(value.m, value.n, value.target, value.p_bits, value.q_bits)
     }
 }
 
@@ -126,8 +268,32 @@ fn int_to_bits(n: u64, num_bits: usize) -> Vec<usize> {
     (0..num_bits).map(|i| ((n >> i) & 1) as usize).collect()
 }
 
+/// Number of prime factors of `n` counted with multiplicity (0 for 0 and 1).
+fn count_prime_factors(mut n: u64) -> usize {
+    if n < 2 {
+        return 0;
+    }
+    let mut count = 0;
+    let mut p = 2u64;
+    while p <= n / p {
+        while n.is_multiple_of(p) {
+            n /= p;
+            count += 1;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        count += 1;
+    }
+    count
+}
+
+/// Whether both factors are at least 2, ruling out `1 * N` (and `0 * x`).
+fn nontrivial_factors(a: u64, b: u64) -> bool {
+    a > 1 && b > 1
+}
+
 /// Check if the given factors correctly factorize the target.
-#[cfg(test)]
 pub(crate) fn is_factoring(target: u64, a: u64, b: u64) -> bool {
     a * b == target
 }
@@ -141,7 +307,15 @@ impl Problem for Factoring {
     }
 
     fn evaluate(&self, config: &[usize]) -> Min<i32> {
+        if !self.satisfies_bit_widths(config) {
+            return Min(None);
+        }
         let (a, b) = self.read_factors(config);
+        // 1 * N is not a factorization; without this a prime N would report
+        // the trivial pair as an exact optimum.
+        if !nontrivial_factors(a, b) {
+            return Min(None);
+        }
         let product = a * b;
         // Distance from target (0 means exact match)
         let distance = if product > self.target {
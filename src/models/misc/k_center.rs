@@ -0,0 +1,261 @@
+//! K-Center problem implementation.
+//!
+//! Given a distance matrix over n sites, the K-Center problem asks for K
+//! centers minimizing the maximum distance from any site to its nearest
+//! center (the covering radius).
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry, ProblemSizeFieldEntry, VariantDimension};
+use crate::traits::Problem;
+use crate::types::Min;
+use crate::variant::{KValue, K1, K2, KN};
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "KCenter",
+        display_name: "K-Center",
+        aliases: &[],
+        dimensions: &[
+            VariantDimension::new("k", "KN", &["KN", "K1", "K2"]),
+        ],
+        module_path: module_path!(),
+        description: "Choose K centers minimizing the maximum distance from any site to its nearest center",
+        fields: &[
+            FieldInfo { name: "distances", type_name: "Vec<Vec<u64>>", description: "Symmetric distance matrix with zero diagonal" },
+            FieldInfo { name: "num_centers", type_name: "usize", description: "Number of centers K" },
+        ],
+    }
+}
+
+inventory::submit! {
+    ProblemSizeFieldEntry {
+        name: "KCenter",
+        fields: &["num_sites", "num_centers"],
+    }
+}
+
+/// The K-Center problem.
+///
+/// Given `n` sites with pairwise distances `d(i, j)` and a number `K` of
+/// centers, find a set `C` of exactly `K` sites minimizing
+/// `max_i min_{c in C} d(i, c)`.
+///
+/// Unlike [`MinMaxMulticenter`](crate::models::graph::MinMaxMulticenter),
+/// which measures shortest-path distances in a weighted graph, the distances
+/// here are given directly, as in metric facility location.
+///
+/// # Representation
+///
+/// Each site has a binary variable: `config[i] = 1` if site `i` is a center.
+/// Configurations with a number of centers other than `K` are infeasible.
+///
+/// # Type Parameters
+///
+/// * `K` - KValue type for the number of centers (e.g., `K2`), or `KN` for a runtime K
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::misc::KCenter;
+/// use problemreductions::types::Min;
+/// use problemreductions::variant::K1;
+/// use problemreductions::{BruteForce, Solver};
+///
+/// // Three sites on a line at positions 0, 1, 2: the middle site covers both ends.
+/// let problem = KCenter::<K1>::new(vec![vec![0, 1, 2], vec![1, 0, 1], vec![2, 1, 0]]);
+/// assert_eq!(BruteForce::new().solve(&problem), Min(Some(1)));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(deserialize = ""), try_from = "KCenterDef<K>")]
+pub struct KCenter<K: KValue> {
+    /// Symmetric distance matrix with zero diagonal.
+    distances: Vec<Vec<u64>>,
+    /// Runtime number of centers. Always set; for compile-time K types it equals K::K.
+    num_centers: usize,
+    #[serde(skip)]
+    _phantom: std::marker::PhantomData<K>,
+}
+
+impl<K: KValue> KCenter<K> {
+    /// Create a K-Center problem with the number of centers given by `K`.
+    ///
+    /// # Panics
+    /// Panics if `K` is `KN` (use [`KCenter::<KN>::with_k`] instead), or if
+    /// the distance matrix is invalid (see [`KCenter::<KN>::with_k`]).
+    pub fn new(distances: Vec<Vec<u64>>) -> Self {
+        Self::build(distances, K::K.expect("KN requires with_k"))
+    }
+
+    fn build(distances: Vec<Vec<u64>>, num_centers: usize) -> Self {
+        Self::try_build(distances, num_centers).unwrap_or_else(|message| panic!("{message}"))
+    }
+
+    fn try_build(distances: Vec<Vec<u64>>, num_centers: usize) -> Result<Self, String> {
+        let n = distances.len();
+        if num_centers == 0 {
+            return Err("num_centers must be positive".to_string());
+        }
+        if num_centers > n {
+            return Err(format!(
+                "num_centers ({num_centers}) must not exceed the number of sites ({n})"
+            ));
+        }
+        for (i, row) in distances.iter().enumerate() {
+            if row.len() != n {
+                return Err(format!(
+                    "Distance matrix must be square: row {i} has {} columns, expected {n}",
+                    row.len()
+                ));
+            }
+            if row[i] != 0 {
+                return Err(format!("Diagonal entry distances[{i}][{i}] must be 0"));
+            }
+            for (j, &d) in row.iter().enumerate().take(i) {
+                if d != distances[j][i] {
+                    return Err(format!(
+                        "Distance matrix must be symmetric: distances[{i}][{j}] = {d} != distances[{j}][{i}] = {}",
+                        distances[j][i]
+                    ));
+                }
+            }
+        }
+        Ok(Self {
+            distances,
+            num_centers,
+            _phantom: std::marker::PhantomData,
+        })
+    }
+
+    /// Returns the distance matrix.
+    pub fn distances(&self) -> &[Vec<u64>] {
+        &self.distances
+    }
+
+    /// Returns the number of sites.
+    pub fn num_sites(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// Returns the number of centers K.
+    pub fn num_centers(&self) -> usize {
+        self.num_centers
+    }
+
+    /// Returns the distinct distance values in increasing order.
+    ///
+    /// The optimal radius is always one of these values.
+    pub fn candidate_radii(&self) -> Vec<u64> {
+        let mut radii: Vec<u64> = self.distances.iter().flatten().copied().collect();
+        radii.sort_unstable();
+        radii.dedup();
+        radii
+    }
+
+    /// Returns the number of distinct distance values.
+    pub fn num_candidate_radii(&self) -> usize {
+        self.candidate_radii().len()
+    }
+
+    /// Covering radius of a center selection, or `None` if the selection is
+    /// not a 0/1 vector with exactly K centers.
+    pub fn radius(&self, config: &[usize]) -> Option<u64> {
+        if config.len() != self.num_sites() || config.iter().any(|&x| x > 1) {
+            return None;
+        }
+        let centers: Vec<usize> = (0..config.len()).filter(|&i| config[i] == 1).collect();
+        if centers.len() != self.num_centers {
+            return None;
+        }
+        self.distances
+            .iter()
+            .map(|row| centers.iter().map(|&c| row[c]).min())
+            .try_fold(0, |radius, nearest| nearest.map(|d| radius.max(d)))
+    }
+}
+
+impl KCenter<KN> {
+    /// Create a K-Center problem with an explicit number of centers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `num_centers` is zero or exceeds the number of sites
+    /// - `distances` is not square
+    /// - `distances` is not symmetric
+    /// - diagonal entries are not zero
+    pub fn with_k(distances: Vec<Vec<u64>>, num_centers: usize) -> Self {
+        Self::build(distances, num_centers)
+    }
+}
+
+impl<K: KValue> Problem for KCenter<K> {
+    const NAME: &'static str = "KCenter";
+    type Value = Min<u64>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![K]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.num_sites()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<u64> {
+        Min(self.radius(config))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = ""))]
+struct KCenterDef<K: KValue> {
+    distances: Vec<Vec<u64>>,
+    #[serde(default = "default_num_centers::<K>")]
+    num_centers: usize,
+    #[serde(skip)]
+    _phantom: std::marker::PhantomData<K>,
+}
+
+fn default_num_centers<K: KValue>() -> usize {
+    K::K.unwrap_or(0)
+}
+
+impl<K: KValue> TryFrom<KCenterDef<K>> for KCenter<K> {
+    type Error = String;
+
+    fn try_from(value: KCenterDef<K>) -> Result<Self, Self::Error> {
+        if let Some(k) = K::K.filter(|&k| k != value.num_centers) {
+            return Err(format!(
+                "num_centers ({}) must equal K ({k}) for this variant",
+                value.num_centers
+            ));
+        }
+        Self::try_build(value.distances, value.num_centers)
+    }
+}
+
+crate::declare_variants! {
+    default KCenter<KN> => "2^num_sites",
+    KCenter<K1> => "num_sites^2",
+    KCenter<K2> => "num_sites^3",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // Six sites on a line at positions 0, 1, 2, 6, 7, 8 with K = 2: one
+    // center in the middle of each group gives radius 1.
+    let positions: [u64; 6] = [0, 1, 2, 6, 7, 8];
+    let distances = positions
+        .iter()
+        .map(|&a| positions.iter().map(|&b| a.abs_diff(b)).collect())
+        .collect();
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "k_center_kn",
+        instance: Box::new(KCenter::<KN>::with_k(distances, 2)),
+        optimal_config: vec![0, 1, 0, 0, 1, 0],
+        optimal_value: serde_json::json!(1),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/misc/k_center.rs"]
+mod tests;
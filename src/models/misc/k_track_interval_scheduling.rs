@@ -0,0 +1,229 @@
+//! K-Track Interval Scheduling problem implementation.
+//!
+//! Weighted interval scheduling on K identical machines (tracks): accept a
+//! maximum-weight subset of fixed intervals such that the intervals accepted
+//! on each track are pairwise disjoint.
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry};
+use crate::traits::Problem;
+use crate::types::Max;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "KTrackIntervalScheduling",
+        display_name: "K-Track Interval Scheduling",
+        aliases: &["IntervalScheduling"],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Accept a maximum-weight set of intervals that fits on K tracks without overlaps",
+        fields: &[
+            FieldInfo { name: "starts", type_name: "Vec<u64>", description: "Start time s(i) of each interval" },
+            FieldInfo { name: "ends", type_name: "Vec<u64>", description: "End time e(i) of each interval (exclusive)" },
+            FieldInfo { name: "weights", type_name: "Vec<u64>", description: "Weight w(i) gained by accepting interval i" },
+            FieldInfo { name: "num_tracks", type_name: "usize", description: "Number of identical tracks K" },
+        ],
+    }
+}
+
+/// The K-Track Interval Scheduling problem.
+///
+/// Given n half-open intervals `[s(i), e(i))` with weights `w(i)` and K
+/// identical tracks, assign each interval to a track or reject it so that no
+/// two intervals on the same track overlap, maximizing the total accepted
+/// weight. For K = 1 and unit weights this is the classical activity selection
+/// problem, solved greedily by earliest finish time; the general weighted
+/// problem is solvable in polynomial time as a min-cost flow
+/// (Arkin & Silverberg, 1987).
+///
+/// # Representation
+///
+/// Each interval has a variable in `{0, ..., K}`: `0` rejects the interval and
+/// `t + 1` places it on track `t`. Two intervals overlap iff
+/// `s(i) < e(j)` and `s(j) < e(i)`, so intervals that merely touch may share a
+/// track.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::misc::KTrackIntervalScheduling;
+/// use problemreductions::types::Max;
+/// use problemreductions::{BruteForce, Problem, Solver};
+///
+/// // [0, 2), [1, 3) and [2, 4): the middle one clashes with both others
+/// let problem = KTrackIntervalScheduling::new(vec![0, 1, 2], vec![2, 3, 4], vec![1, 1, 1], 1);
+/// assert_eq!(BruteForce::new().solve(&problem), Max(Some(2)));
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct KTrackIntervalScheduling {
+    starts: Vec<u64>,
+    ends: Vec<u64>,
+    weights: Vec<u64>,
+    num_tracks: usize,
+}
+
+#[derive(Deserialize)]
+struct KTrackIntervalSchedulingSerde {
+    starts: Vec<u64>,
+    ends: Vec<u64>,
+    weights: Vec<u64>,
+    num_tracks: usize,
+}
+
+impl KTrackIntervalScheduling {
+    fn validate(
+        starts: &[u64],
+        ends: &[u64],
+        weights: &[u64],
+        num_tracks: usize,
+    ) -> Result<(), String> {
+        if starts.len() != ends.len() {
+            return Err("starts length must equal ends length".to_string());
+        }
+        if starts.len() != weights.len() {
+            return Err("starts length must equal weights length".to_string());
+        }
+        if let Some(i) = (0..starts.len()).find(|&i| starts[i] >= ends[i]) {
+            return Err(format!(
+                "interval {i} is empty: start {} must be less than end {}",
+                starts[i], ends[i]
+            ));
+        }
+        if num_tracks == 0 {
+            return Err("num_tracks must be positive".to_string());
+        }
+        Ok(())
+    }
+
+    /// Create a new K-Track Interval Scheduling instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `starts`, `ends`, and `weights` differ in length, if any
+    /// interval has `start >= end`, or if `num_tracks` is zero.
+    pub fn new(starts: Vec<u64>, ends: Vec<u64>, weights: Vec<u64>, num_tracks: usize) -> Self {
+        Self::validate(&starts, &ends, &weights, num_tracks).unwrap_or_else(|err| panic!("{err}"));
+        Self {
+            starts,
+            ends,
+            weights,
+            num_tracks,
+        }
+    }
+
+    /// Returns the number of intervals.
+    pub fn num_intervals(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Returns the number of tracks K.
+    pub fn num_tracks(&self) -> usize {
+        self.num_tracks
+    }
+
+    /// Returns the interval start times.
+    pub fn starts(&self) -> &[u64] {
+        &self.starts
+    }
+
+    /// Returns the (exclusive) interval end times.
+    pub fn ends(&self) -> &[u64] {
+        &self.ends
+    }
+
+    /// Returns the interval weights.
+    pub fn weights(&self) -> &[u64] {
+        &self.weights
+    }
+
+    /// Check whether intervals `i` and `j` share a point in time.
+    pub fn overlaps(&self, i: usize, j: usize) -> bool {
+        self.starts[i] < self.ends[j] && self.starts[j] < self.ends[i]
+    }
+
+    /// Check if a configuration places no two overlapping intervals on one track.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        self.evaluate(config).is_valid()
+    }
+}
+
+impl TryFrom<KTrackIntervalSchedulingSerde> for KTrackIntervalScheduling {
+    type Error = String;
+
+    fn try_from(value: KTrackIntervalSchedulingSerde) -> Result<Self, Self::Error> {
+        Self::validate(&value.starts, &value.ends, &value.weights, value.num_tracks)?;
+        Ok(Self {
+            starts: value.starts,
+            ends: value.ends,
+            weights: value.weights,
+            num_tracks: value.num_tracks,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for KTrackIntervalScheduling {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = KTrackIntervalSchedulingSerde::deserialize(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Problem for KTrackIntervalScheduling {
+    const NAME: &'static str = "KTrackIntervalScheduling";
+    type Value = Max<u64>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![self.num_tracks + 1; self.num_intervals()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Max<u64> {
+        let n = self.num_intervals();
+        if config.len() != n || config.iter().any(|&t| t > self.num_tracks) {
+            return Max(None);
+        }
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if config[i] != 0 && config[i] == config[j] && self.overlaps(i, j) {
+                    return Max(None);
+                }
+            }
+        }
+        Max(Some(
+            (0..n)
+                .filter(|&i| config[i] != 0)
+                .map(|i| self.weights[i])
+                .sum(),
+        ))
+    }
+}
+
+crate::declare_variants! {
+    default KTrackIntervalScheduling => "num_intervals^2 * log(num_intervals)",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // Six intervals on two tracks: everything except [1, 3) and [4, 7) fits.
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "k_track_interval_scheduling",
+        instance: Box::new(KTrackIntervalScheduling::new(
+            vec![0, 1, 2, 4, 5, 7],
+            vec![4, 3, 6, 7, 8, 9],
+            vec![3, 2, 4, 2, 3, 1],
+            2,
+        )),
+        optimal_config: vec![1, 0, 2, 0, 1, 2],
+        optimal_value: serde_json::json!(11),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/misc/k_track_interval_scheduling.rs"]
+mod tests;
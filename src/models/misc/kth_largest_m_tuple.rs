@@ -4,7 +4,10 @@
 //! distinct m-tuples (one element per set) have total size at least B.
 //! The answer is YES iff the count is at least K. Garey & Johnson MP10.
 
-use crate::registry::{FieldInfo, ProblemSchemaEntry, ProblemSizeFieldEntry};
+use crate::registry::{
+    ComplexityClass, FieldInfo, ProblemComplexityClassEntry, ProblemSchemaEntry,
+    ProblemSizeFieldEntry,
+};
 use crate::traits::Problem;
 use crate::types::Sum;
 use serde::de::Error as _;
@@ -33,6 +36,13 @@ inventory::submit! {
     }
 }
 
+inventory::submit! {
+    ProblemComplexityClassEntry {
+        name: "KthLargestMTuple",
+        class: ComplexityClass::NpHard,
+    }
+}
+
 /// The Kth Largest m-Tuple problem.
 ///
 /// Given sets `X_1, ..., X_m` of positive integers, a threshold `K`, and a
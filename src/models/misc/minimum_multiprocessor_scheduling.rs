@@ -0,0 +1,146 @@
+//! Minimum Multiprocessor Scheduling problem implementation.
+//!
+//! The optimization form of [`MultiprocessorScheduling`](super::MultiprocessorScheduling),
+//! written P||Cmax in scheduling notation: assign tasks to identical
+//! processors so that the makespan (the largest processor load) is minimized.
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry};
+use crate::traits::Problem;
+use crate::types::Min;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MinimumMultiprocessorScheduling",
+        display_name: "Minimum Multiprocessor Scheduling",
+        aliases: &["PCmax"],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Assign tasks to identical processors minimizing the makespan",
+        fields: &[
+            FieldInfo { name: "lengths", type_name: "Vec<u64>", description: "Processing time l(t) for each task" },
+            FieldInfo { name: "num_processors", type_name: "usize", description: "Number of identical processors m" },
+        ],
+    }
+}
+
+/// The Minimum Multiprocessor Scheduling problem (P||Cmax).
+///
+/// Given a set T of tasks with processing times and a number m of identical
+/// processors, find an assignment of tasks to processors minimizing the
+/// makespan `max_p Σ_{t assigned to p} l(t)`.
+///
+/// This is a bottleneck objective: only the most loaded processor counts.
+/// The decision version with a deadline is
+/// [`MultiprocessorScheduling`](super::MultiprocessorScheduling).
+///
+/// # Representation
+///
+/// Each task has a variable in `{0, ..., m-1}` representing its processor assignment.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::misc::MinimumMultiprocessorScheduling;
+/// use problemreductions::types::Min;
+/// use problemreductions::{BruteForce, Problem, Solver};
+///
+/// // Tasks [3, 3, 2, 2, 2] on 2 processors: {3, 3} and {2, 2, 2} both take 6
+/// let problem = MinimumMultiprocessorScheduling::new(vec![3, 3, 2, 2, 2], 2);
+/// let solver = BruteForce::new();
+/// assert_eq!(solver.solve(&problem), Min(Some(6)));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinimumMultiprocessorScheduling {
+    /// Processing time for each task.
+    lengths: Vec<u64>,
+    /// Number of identical processors.
+    #[serde(deserialize_with = "super::multiprocessor_scheduling::positive_usize::deserialize")]
+    num_processors: usize,
+}
+
+impl MinimumMultiprocessorScheduling {
+    /// Create a new Minimum Multiprocessor Scheduling instance.
+    ///
+    /// # Panics
+    /// Panics if `num_processors` is zero.
+    pub fn new(lengths: Vec<u64>, num_processors: usize) -> Self {
+        assert!(num_processors > 0, "num_processors must be positive");
+        Self {
+            lengths,
+            num_processors,
+        }
+    }
+
+    /// Returns the processing times for each task.
+    pub fn lengths(&self) -> &[u64] {
+        &self.lengths
+    }
+
+    /// Returns the number of processors.
+    pub fn num_processors(&self) -> usize {
+        self.num_processors
+    }
+
+    /// Returns the number of tasks.
+    pub fn num_tasks(&self) -> usize {
+        self.lengths.len()
+    }
+
+    /// Returns the total processing time of all tasks.
+    pub fn total_length(&self) -> u64 {
+        self.lengths.iter().sum()
+    }
+
+    /// Returns the load of each processor, or `None` if the configuration is
+    /// malformed (wrong length or a processor index out of range).
+    pub fn loads(&self, config: &[usize]) -> Option<Vec<u64>> {
+        let m = self.num_processors;
+        if config.len() != self.num_tasks() || config.iter().any(|&p| p >= m) {
+            return None;
+        }
+        let mut loads = vec![0u64; m];
+        for (&processor, &length) in config.iter().zip(&self.lengths) {
+            loads[processor] += length;
+        }
+        Some(loads)
+    }
+}
+
+impl Problem for MinimumMultiprocessorScheduling {
+    const NAME: &'static str = "MinimumMultiprocessorScheduling";
+    type Value = Min<u64>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![self.num_processors; self.num_tasks()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<u64> {
+        Min(self
+            .loads(config)
+            .map(|loads| loads.into_iter().max().unwrap_or(0)))
+    }
+}
+
+crate::declare_variants! {
+    default MinimumMultiprocessorScheduling => "num_processors^num_tasks",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // Total length 12 on 2 processors: the two 3s against the three 2s is perfect.
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "minimum_multiprocessor_scheduling",
+        instance: Box::new(MinimumMultiprocessorScheduling::new(vec![3, 3, 2, 2, 2], 2)),
+        optimal_config: vec![0, 0, 1, 1, 1],
+        optimal_value: serde_json::json!(6),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/misc/minimum_multiprocessor_scheduling.rs"]
+mod tests;
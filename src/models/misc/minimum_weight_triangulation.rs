@@ -0,0 +1,250 @@
+//! Minimum Weight Triangulation problem implementation.
+//!
+//! Given the vertices of a convex polygon in the plane, choose non-crossing
+//! diagonals that split it into triangles while minimizing the total
+//! Euclidean edge length. Points are stored as `(x, y)` positions, the same
+//! representation [`UnitDiskGraph`](crate::topology::UnitDiskGraph) uses.
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry, ProblemSizeFieldEntry};
+use crate::traits::Problem;
+use crate::types::Min;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::{PI, TAU};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "MinimumWeightTriangulation",
+        display_name: "Minimum Weight Triangulation",
+        aliases: &["MWT"],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Triangulate a convex polygon with non-crossing diagonals of minimum total length",
+        fields: &[
+            FieldInfo { name: "positions", type_name: "Vec<(f64, f64)>", description: "Polygon vertices in cyclic order (strictly convex)" },
+        ],
+    }
+}
+
+inventory::submit! {
+    ProblemSizeFieldEntry {
+        name: "MinimumWeightTriangulation",
+        fields: &["num_points", "num_diagonals"],
+    }
+}
+
+/// The Minimum Weight Triangulation problem on a convex point set.
+///
+/// Given points `p_0, ..., p_{n-1}` forming a strictly convex polygon in
+/// cyclic order (either orientation), select diagonals `p_i p_j` that pairwise
+/// do not cross and triangulate the polygon, minimizing the total length of
+/// all triangulation edges (polygon sides plus selected diagonals).
+///
+/// There is one binary variable per diagonal, ordered lexicographically by
+/// `(i, j)` with `i < j` (see [`diagonals`](Self::diagonals)). A selection is
+/// a triangulation iff it has exactly `n - 3` pairwise non-crossing
+/// diagonals, since every non-crossing set extends to one of that size.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::misc::MinimumWeightTriangulation;
+/// use problemreductions::{BruteForce, Problem, Solver};
+///
+/// // Unit square: both triangulations use one diagonal of length sqrt(2).
+/// let problem = MinimumWeightTriangulation::new(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+/// assert_eq!(problem.num_diagonals(), 2);
+/// let best = BruteForce::new().solve(&problem).unwrap();
+/// assert!((best - (4.0 + 2f64.sqrt())).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "MinimumWeightTriangulationDef")]
+pub struct MinimumWeightTriangulation {
+    /// Polygon vertices in cyclic order.
+    positions: Vec<(f64, f64)>,
+}
+
+impl MinimumWeightTriangulation {
+    /// Create a new instance.
+    ///
+    /// # Panics
+    /// Panics if there are fewer than three points, a coordinate is not
+    /// finite, or the points do not form a strictly convex polygon in order.
+    pub fn new(positions: Vec<(f64, f64)>) -> Self {
+        Self::try_new(positions).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Fallible constructor used by CLI validation and deserialization.
+    pub fn try_new(positions: Vec<(f64, f64)>) -> Result<Self, String> {
+        let n = positions.len();
+        if n < 3 {
+            return Err("a polygon needs at least 3 points".into());
+        }
+        if positions
+            .iter()
+            .any(|&(x, y)| !x.is_finite() || !y.is_finite())
+        {
+            return Err("point coordinates must be finite".into());
+        }
+        // Every turn must go the same way, and the exterior angles must add
+        // up to one full turn (a pentagram turns consistently but twice).
+        let heading = |a: (f64, f64), b: (f64, f64)| (b.1 - a.1).atan2(b.0 - a.0);
+        let mut orientation = 0.0;
+        let mut winding = 0.0;
+        for i in 0..n {
+            let (a, b, c) = (positions[i], positions[(i + 1) % n], positions[(i + 2) % n]);
+            let turn = (b.0 - a.0) * (c.1 - b.1) - (b.1 - a.1) * (c.0 - b.0);
+            if turn == 0.0 || turn * orientation < 0.0 {
+                return Err(format!(
+                    "points must form a strictly convex polygon in cyclic order (fails at point {})",
+                    (i + 1) % n
+                ));
+            }
+            orientation = turn;
+            let mut exterior = heading(b, c) - heading(a, b);
+            if exterior > PI {
+                exterior -= TAU;
+            } else if exterior <= -PI {
+                exterior += TAU;
+            }
+            winding += exterior;
+        }
+        if (winding.abs() - TAU).abs() > 1e-6 {
+            return Err("points must form a simple convex polygon".into());
+        }
+        Ok(Self { positions })
+    }
+
+    /// Get the polygon vertices.
+    pub fn positions(&self) -> &[(f64, f64)] {
+        &self.positions
+    }
+
+    /// Get the number of points.
+    pub fn num_points(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Get the number of diagonals, `n (n - 3) / 2`.
+    pub fn num_diagonals(&self) -> usize {
+        let n = self.num_points();
+        n * (n - 3) / 2
+    }
+
+    /// Diagonals `(i, j)` with `i < j` in variable order.
+    pub fn diagonals(&self) -> Vec<(usize, usize)> {
+        let n = self.num_points();
+        (0..n)
+            .flat_map(|i| ((i + 2)..n).map(move |j| (i, j)))
+            .filter(|&(i, j)| !(i == 0 && j == n - 1))
+            .collect()
+    }
+
+    /// Euclidean distance between points `i` and `j`.
+    pub fn distance(&self, i: usize, j: usize) -> f64 {
+        let (a, b) = (self.positions[i], self.positions[j]);
+        (a.0 - b.0).hypot(a.1 - b.1)
+    }
+
+    /// Total length of the polygon boundary.
+    pub fn perimeter(&self) -> f64 {
+        let n = self.num_points();
+        (0..n).map(|i| self.distance(i, (i + 1) % n)).sum()
+    }
+
+    /// Diagonals selected by a configuration.
+    pub fn selected_diagonals(&self, config: &[usize]) -> Vec<(usize, usize)> {
+        self.diagonals()
+            .into_iter()
+            .zip(config)
+            .filter(|&(_, &x)| x == 1)
+            .map(|(d, _)| d)
+            .collect()
+    }
+
+    /// Check if a configuration selects exactly the diagonals of a
+    /// triangulation: `n - 3` of them, no two crossing.
+    pub fn is_valid_solution(&self, config: &[usize]) -> bool {
+        if config.len() != self.num_diagonals() || config.iter().any(|&x| x > 1) {
+            return false;
+        }
+        let selected = self.selected_diagonals(config);
+        selected.len() == self.num_points() - 3
+            && selected
+                .iter()
+                .enumerate()
+                .all(|(k, &d)| selected[k + 1..].iter().all(|&e| !diagonals_cross(d, e)))
+    }
+}
+
+/// Whether two diagonals of a convex polygon cross in their interiors, i.e.
+/// their endpoints strictly interleave around the boundary.
+fn diagonals_cross((a, b): (usize, usize), (c, d): (usize, usize)) -> bool {
+    (a < c && c < b && b < d) || (c < a && a < d && d < b)
+}
+
+#[derive(Deserialize)]
+struct MinimumWeightTriangulationDef {
+    positions: Vec<(f64, f64)>,
+}
+
+impl TryFrom<MinimumWeightTriangulationDef> for MinimumWeightTriangulation {
+    type Error = String;
+
+    fn try_from(value: MinimumWeightTriangulationDef) -> Result<Self, Self::Error> {
+        Self::try_new(value.positions)
+    }
+}
+
+impl Problem for MinimumWeightTriangulation {
+    const NAME: &'static str = "MinimumWeightTriangulation";
+    type Value = Min<f64>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.num_diagonals()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<f64> {
+        if !self.is_valid_solution(config) {
+            return Min(None);
+        }
+        let diagonals: f64 = self
+            .selected_diagonals(config)
+            .into_iter()
+            .map(|(i, j)| self.distance(i, j))
+            .sum();
+        Min(Some(self.perimeter() + diagonals))
+    }
+}
+
+crate::declare_variants! {
+    default MinimumWeightTriangulation => "num_points^3",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // Convex pentagon with vertex 2 pulled far to the right: the best
+    // triangulation cuts that vertex off with the short diagonal (1, 3).
+    let problem = MinimumWeightTriangulation::new(vec![
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (6.0, 2.0),
+        (2.0, 4.0),
+        (0.0, 3.0),
+    ]);
+    let optimal_config = vec![0, 0, 1, 1, 0];
+    let optimal_value = problem.evaluate(&optimal_config).unwrap();
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "minimum_weight_triangulation",
+        instance: Box::new(problem),
+        optimal_config,
+        optimal_value: serde_json::json!(optimal_value),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/misc/minimum_weight_triangulation.rs"]
+mod tests;
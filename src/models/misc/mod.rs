@@ -18,6 +18,8 @@
 //! - [`FlowShopScheduling`]: Flow Shop Scheduling (meet deadline on m processors)
 //! - [`GroupingBySwapping`]: Group equal symbols into contiguous blocks by adjacent swaps
 //! - [`JobShopScheduling`]: Minimize makespan with per-job processor routes
+//! - [`KCenter`]: Choose K centers minimizing the covering radius of a distance matrix
+//! - [`KTrackIntervalScheduling`]: Accept maximum-weight intervals on K tracks without overlaps
 //! - [`Knapsack`]: 0-1 Knapsack (maximize value subject to weight capacity)
 //! - [`MultiprocessorScheduling`]: Schedule tasks on processors to meet a deadline
 //! - [`Numerical3DimensionalMatching`]: Partition W∪X∪Y into m triples each summing to B
@@ -34,7 +36,9 @@
 //! - [`MinimumInternalMacroDataCompression`]: Minimize self-referencing compression cost
 //! - [`MinimumRegisterSufficiencyForLoops`]: Minimize registers for loop variable allocation (circular arc coloring)
 //! - [`MinimumWeightAndOrGraph`]: Find minimum-weight solution subgraph in a DAG with AND/OR gates
+//! - [`MinimumWeightTriangulation`]: Triangulate a convex polygon with minimum total edge length
 //! - [`MinimumTardinessSequencing`]: Minimize tardy tasks in single-machine scheduling
+//! - [`MinimumMultiprocessorScheduling`]: Schedule tasks on identical processors minimizing the makespan
 //! - [`OptimumCommunicationSpanningTree`]: Find spanning tree minimizing total weighted communication cost
 //! - [`PaintShop`]: Minimize color switches in paint shop scheduling
 //! - [`CosineProductIntegration`]: Balanced sign assignment for integer frequencies
@@ -44,6 +48,7 @@
 //! - [`PrecedenceConstrainedScheduling`]: Schedule unit tasks on processors by deadline
 //! - [`PreemptiveScheduling`]: Preemptive parallel scheduling with precedences (minimize makespan)
 //! - [`ProductionPlanning`]: Meet all period demands within capacity and total-cost bounds
+//! - [`RectanglePacking`]: Place rectangles without overlap in a fixed container
 //! - [`RectilinearPictureCompression`]: Cover 1-entries with bounded rectangles
 //! - [`RegisterSufficiency`]: Evaluate DAG computation with bounded registers
 //! - [`ResourceConstrainedScheduling`]: Schedule unit-length tasks on processors with resource constraints
@@ -57,6 +62,7 @@
 //! - [`SequencingWithReleaseTimesAndDeadlines`]: Single-machine scheduling feasibility
 //! - [`SequencingWithinIntervals`]: Schedule tasks within time windows
 //! - [`ShortestCommonSupersequence`]: Find a common supersequence of bounded length
+//! - [`ShortestCommonSuperstring`]: Find a shortest string containing every input string as a substring
 //! - [`SquareTiling`]: Place colored square tiles on an N x N grid with matching edge colors
 //! - [`TimetableDesign`]: Schedule craftsmen on tasks across work periods
 //! - [`StringToStringCorrection`]: String-to-String Correction (derive target via deletions and swaps)
@@ -128,6 +134,8 @@ mod flow_shop_scheduling;
 mod grouping_by_swapping;
 pub(crate) mod integer_expression_membership;
 mod job_shop_scheduling;
+mod k_center;
+mod k_track_interval_scheduling;
 mod knapsack;
 mod kth_largest_m_tuple;
 mod longest_common_subsequence;
@@ -141,9 +149,11 @@ pub(crate) mod minimum_disjunctive_normal_form;
 mod minimum_external_macro_data_compression;
 mod minimum_fault_detection_test_set;
 mod minimum_internal_macro_data_compression;
+mod minimum_multiprocessor_scheduling;
 mod minimum_register_sufficiency_for_loops;
 mod minimum_tardiness_sequencing;
 mod minimum_weight_and_or_graph;
+mod minimum_weight_triangulation;
 mod multiprocessor_scheduling;
 mod non_liveness_free_petri_net;
 mod numerical_3_dimensional_matching;
@@ -156,6 +166,7 @@ pub(crate) mod partition;
 mod precedence_constrained_scheduling;
 mod preemptive_scheduling;
 mod production_planning;
+mod rectangle_packing;
 mod rectilinear_picture_compression;
 mod register_sufficiency;
 pub(crate) mod resource_constrained_scheduling;
@@ -169,6 +180,7 @@ mod sequencing_with_deadlines_and_set_up_times;
 mod sequencing_with_release_times_and_deadlines;
 mod sequencing_within_intervals;
 pub(crate) mod shortest_common_supersequence;
+pub(crate) mod shortest_common_superstring;
 mod square_tiling;
 mod stacker_crane;
 mod staff_scheduling;
@@ -201,6 +213,8 @@ pub use flow_shop_scheduling::FlowShopScheduling;
 pub use grouping_by_swapping::GroupingBySwapping;
 pub use integer_expression_membership::{IntExpr, IntegerExpressionMembership};
 pub use job_shop_scheduling::JobShopScheduling;
+pub use k_center::KCenter;
+pub use k_track_interval_scheduling::KTrackIntervalScheduling;
 pub use knapsack::Knapsack;
 pub use kth_largest_m_tuple::KthLargestMTuple;
 pub use longest_common_subsequence::LongestCommonSubsequence;
@@ -214,9 +228,11 @@ pub use minimum_disjunctive_normal_form::MinimumDisjunctiveNormalForm;
 pub use minimum_external_macro_data_compression::MinimumExternalMacroDataCompression;
 pub use minimum_fault_detection_test_set::MinimumFaultDetectionTestSet;
 pub use minimum_internal_macro_data_compression::MinimumInternalMacroDataCompression;
+pub use minimum_multiprocessor_scheduling::MinimumMultiprocessorScheduling;
 pub use minimum_register_sufficiency_for_loops::MinimumRegisterSufficiencyForLoops;
 pub use minimum_tardiness_sequencing::MinimumTardinessSequencing;
 pub use minimum_weight_and_or_graph::MinimumWeightAndOrGraph;
+pub use minimum_weight_triangulation::MinimumWeightTriangulation;
 pub use multiprocessor_scheduling::MultiprocessorScheduling;
 pub use non_liveness_free_petri_net::NonLivenessFreePetriNet;
 pub use numerical_3_dimensional_matching::Numerical3DimensionalMatching;
@@ -229,6 +245,7 @@ pub use partition::Partition;
 pub use precedence_constrained_scheduling::PrecedenceConstrainedScheduling;
 pub use preemptive_scheduling::PreemptiveScheduling;
 pub use production_planning::ProductionPlanning;
+pub use rectangle_packing::RectanglePacking;
 pub use rectilinear_picture_compression::RectilinearPictureCompression;
 pub use register_sufficiency::RegisterSufficiency;
 pub use resource_constrained_scheduling::ResourceConstrainedScheduling;
@@ -242,6 +259,7 @@ pub use sequencing_with_deadlines_and_set_up_times::SequencingWithDeadlinesAndSe
 pub use sequencing_with_release_times_and_deadlines::SequencingWithReleaseTimesAndDeadlines;
 pub use sequencing_within_intervals::SequencingWithinIntervals;
 pub use shortest_common_supersequence::ShortestCommonSupersequence;
+pub use shortest_common_superstring::ShortestCommonSuperstring;
 pub use square_tiling::SquareTiling;
 pub use stacker_crane::StackerCrane;
 pub use staff_scheduling::StaffScheduling;
@@ -266,11 +284,13 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(grouping_by_swapping::canonical_model_example_specs());
     specs.extend(longest_common_subsequence::canonical_model_example_specs());
     specs.extend(multiprocessor_scheduling::canonical_model_example_specs());
+    specs.extend(minimum_multiprocessor_scheduling::canonical_model_example_specs());
     specs.extend(open_shop_scheduling::canonical_model_example_specs());
     specs.extend(paintshop::canonical_model_example_specs());
     specs.extend(partition::canonical_model_example_specs());
     specs.extend(production_planning::canonical_model_example_specs());
     specs.extend(rectilinear_picture_compression::canonical_model_example_specs());
+    specs.extend(rectangle_packing::canonical_model_example_specs());
     specs.extend(scheduling_to_minimize_weighted_completion_time::canonical_model_example_specs());
     specs.extend(scheduling_with_individual_deadlines::canonical_model_example_specs());
     specs.extend(sequencing_within_intervals::canonical_model_example_specs());
@@ -278,6 +298,7 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(stacker_crane::canonical_model_example_specs());
     specs.extend(timetable_design::canonical_model_example_specs());
     specs.extend(shortest_common_supersequence::canonical_model_example_specs());
+    specs.extend(shortest_common_superstring::canonical_model_example_specs());
     specs.extend(resource_constrained_scheduling::canonical_model_example_specs());
     specs.extend(partially_ordered_knapsack::canonical_model_example_specs());
     specs.extend(string_to_string_correction::canonical_model_example_specs());
@@ -314,6 +335,8 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(register_sufficiency::canonical_model_example_specs());
     specs.extend(feasible_register_assignment::canonical_model_example_specs());
     specs.extend(kth_largest_m_tuple::canonical_model_example_specs());
+    specs.extend(k_center::canonical_model_example_specs());
+    specs.extend(k_track_interval_scheduling::canonical_model_example_specs());
     specs.extend(preemptive_scheduling::canonical_model_example_specs());
     specs.extend(betweenness::canonical_model_example_specs());
     specs.extend(cyclic_ordering::canonical_model_example_specs());
@@ -321,6 +344,7 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     specs.extend(maximum_likelihood_ranking::canonical_model_example_specs());
     specs.extend(clustering::canonical_model_example_specs());
     specs.extend(minimum_weight_and_or_graph::canonical_model_example_specs());
+    specs.extend(minimum_weight_triangulation::canonical_model_example_specs());
     specs.extend(minimum_fault_detection_test_set::canonical_model_example_specs());
     specs.extend(minimum_axiom_set::canonical_model_example_specs());
     specs.extend(optimum_communication_spanning_tree::canonical_model_example_specs());
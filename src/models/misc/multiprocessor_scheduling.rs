@@ -147,7 +147,7 @@ pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::M
     }]
 }
 
-mod positive_usize {
+pub(super) mod positive_usize {
     use serde::de::Error;
     use serde::{Deserialize, Deserializer};
 
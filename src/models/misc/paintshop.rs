@@ -20,7 +20,10 @@ inventory::submit! {
         module_path: module_path!(),
         description: "Minimize color changes in paint shop sequence",
         fields: &[
-            FieldInfo { name: "sequence", type_name: "Vec<String>", description: "Car labels (each must appear exactly twice)" },
+            FieldInfo { name: "sequence_indices", type_name: "Vec<usize>", description: "Car index at each sequence position (each car appears exactly twice)" },
+            FieldInfo { name: "car_labels", type_name: "Vec<String>", description: "Original car labels, indexed by car" },
+            FieldInfo { name: "is_first", type_name: "Vec<bool>", description: "Whether each position is the first occurrence of its car" },
+            FieldInfo { name: "num_cars", type_name: "usize", description: "Number of distinct cars" },
         ],
     }
 }
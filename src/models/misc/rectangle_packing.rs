@@ -0,0 +1,302 @@
+//! Rectangle Packing problem implementation.
+//!
+//! Given axis-aligned rectangles `(w_i, h_i)` and a `W × H` container,
+//! decide whether every rectangle can be placed at an integer position
+//! inside the container with no two rectangles overlapping. Optionally,
+//! rectangles may be rotated by 90 degrees. This is the decision version of
+//! two-dimensional strip packing with a fixed strip height.
+
+use crate::registry::{FieldInfo, ProblemSchemaEntry, ProblemSizeFieldEntry};
+use crate::traits::Problem;
+use crate::types::Or;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "RectanglePacking",
+        display_name: "Rectangle Packing",
+        aliases: &[],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Place axis-aligned rectangles without overlap inside a fixed container",
+        fields: &[
+            FieldInfo { name: "rectangles", type_name: "Vec<(usize, usize)>", description: "Rectangles as (width, height) pairs" },
+            FieldInfo { name: "container_width", type_name: "usize", description: "Container width W" },
+            FieldInfo { name: "container_height", type_name: "usize", description: "Container height H" },
+            FieldInfo { name: "allow_rotation", type_name: "bool", description: "Whether rectangles may be rotated by 90 degrees" },
+        ],
+    }
+}
+
+inventory::submit! {
+    ProblemSizeFieldEntry {
+        name: "RectanglePacking",
+        fields: &["num_rectangles", "container_width", "container_height"],
+    }
+}
+
+/// Rectangle Packing problem.
+///
+/// Each rectangle `i` is placed with its lower-left corner at integer
+/// coordinates `(x_i, y_i)`. The configuration lists `x_i, y_i` for every
+/// rectangle in order, followed by a rotation bit `r_i` when rotation is
+/// allowed (`r_i = 1` swaps width and height). A configuration is a valid
+/// packing when every rectangle lies inside `[0, W] × [0, H]` and the
+/// interiors of any two rectangles are disjoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct RectanglePacking {
+    rectangles: Vec<(usize, usize)>,
+    container_width: usize,
+    container_height: usize,
+    allow_rotation: bool,
+}
+
+impl RectanglePacking {
+    fn validate_inputs(
+        rectangles: &[(usize, usize)],
+        container_width: usize,
+        container_height: usize,
+        allow_rotation: bool,
+    ) -> Result<(), String> {
+        if rectangles.is_empty() {
+            return Err("RectanglePacking requires at least one rectangle".to_string());
+        }
+        if container_width == 0 || container_height == 0 {
+            return Err("RectanglePacking requires a positive container size".to_string());
+        }
+        for (i, &(w, h)) in rectangles.iter().enumerate() {
+            if w == 0 || h == 0 {
+                return Err(format!(
+                    "Rectangle {i} has zero width or height; all sides must be >= 1"
+                ));
+            }
+            let fits = w <= container_width && h <= container_height;
+            let fits_rotated = allow_rotation && h <= container_width && w <= container_height;
+            if !fits && !fits_rotated {
+                return Err(format!(
+                    "Rectangle {i} ({w}x{h}) does not fit in the {container_width}x{container_height} container"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Try to create a new `RectanglePacking` instance.
+    pub fn try_new(
+        rectangles: Vec<(usize, usize)>,
+        container_width: usize,
+        container_height: usize,
+        allow_rotation: bool,
+    ) -> Result<Self, String> {
+        Self::validate_inputs(
+            &rectangles,
+            container_width,
+            container_height,
+            allow_rotation,
+        )?;
+        Ok(Self {
+            rectangles,
+            container_width,
+            container_height,
+            allow_rotation,
+        })
+    }
+
+    /// Create a new `RectanglePacking` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rectangle has a zero side, the container is empty, or a
+    /// rectangle fits the container in no allowed orientation.
+    pub fn new(
+        rectangles: Vec<(usize, usize)>,
+        container_width: usize,
+        container_height: usize,
+        allow_rotation: bool,
+    ) -> Self {
+        Self::try_new(
+            rectangles,
+            container_width,
+            container_height,
+            allow_rotation,
+        )
+        .unwrap_or_else(|message| panic!("{message}"))
+    }
+
+    /// The rectangles as `(width, height)` pairs.
+    pub fn rectangles(&self) -> &[(usize, usize)] {
+        &self.rectangles
+    }
+
+    /// The container width W.
+    pub fn container_width(&self) -> usize {
+        self.container_width
+    }
+
+    /// The container height H.
+    pub fn container_height(&self) -> usize {
+        self.container_height
+    }
+
+    /// Whether rectangles may be rotated by 90 degrees.
+    pub fn allow_rotation(&self) -> bool {
+        self.allow_rotation
+    }
+
+    /// The number of rectangles.
+    pub fn num_rectangles(&self) -> usize {
+        self.rectangles.len()
+    }
+
+    /// Number of configuration variables per rectangle (2, or 3 with rotation).
+    pub fn vars_per_rectangle(&self) -> usize {
+        if self.allow_rotation {
+            3
+        } else {
+            2
+        }
+    }
+
+    /// Width and height of rectangle `i` in the given orientation.
+    pub fn extent(&self, i: usize, rotated: bool) -> (usize, usize) {
+        let (w, h) = self.rectangles[i];
+        if rotated {
+            (h, w)
+        } else {
+            (w, h)
+        }
+    }
+
+    /// Decode a configuration into `(x, y, width, height)` placements.
+    ///
+    /// Returns `None` if the configuration has the wrong length or uses a
+    /// rotation bit outside `{0, 1}`. Containment and overlap are not checked.
+    pub fn placements(&self, config: &[usize]) -> Option<Vec<(usize, usize, usize, usize)>> {
+        let k = self.vars_per_rectangle();
+        if config.len() != self.num_rectangles() * k {
+            return None;
+        }
+        config
+            .chunks(k)
+            .enumerate()
+            .map(|(i, vars)| {
+                let rotated = match vars.get(2) {
+                    None | Some(0) => false,
+                    Some(1) => true,
+                    Some(_) => return None,
+                };
+                let (w, h) = self.extent(i, rotated);
+                Some((vars[0], vars[1], w, h))
+            })
+            .collect()
+    }
+}
+
+#[derive(Deserialize)]
+struct RectanglePackingData {
+    rectangles: Vec<(usize, usize)>,
+    container_width: usize,
+    container_height: usize,
+    #[serde(default)]
+    allow_rotation: bool,
+}
+
+impl<'de> Deserialize<'de> for RectanglePacking {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = RectanglePackingData::deserialize(deserializer)?;
+        Self::try_new(
+            data.rectangles,
+            data.container_width,
+            data.container_height,
+            data.allow_rotation,
+        )
+        .map_err(D::Error::custom)
+    }
+}
+
+impl Problem for RectanglePacking {
+    const NAME: &'static str = "RectanglePacking";
+    type Value = Or;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        self.rectangles
+            .iter()
+            .flat_map(|&(w, h)| {
+                // With rotation, the narrower side bounds the largest offset.
+                let (min_w, min_h) = if self.allow_rotation {
+                    (w.min(h), w.min(h))
+                } else {
+                    (w, h)
+                };
+                let position = [
+                    self.container_width - min_w + 1,
+                    self.container_height - min_h + 1,
+                ];
+                let rotation = self.allow_rotation.then_some(2);
+                position.into_iter().chain(rotation)
+            })
+            .collect()
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Or {
+        Or({
+            let Some(placements) = self.placements(config) else {
+                return Or(false);
+            };
+
+            // Every rectangle lies inside the container
+            if placements
+                .iter()
+                .any(|&(x, y, w, h)| x + w > self.container_width || y + h > self.container_height)
+            {
+                return Or(false);
+            }
+
+            // Rectangles are pairwise separated along x or y
+            for (i, &(x_i, y_i, w_i, h_i)) in placements.iter().enumerate() {
+                for &(x_j, y_j, w_j, h_j) in &placements[i + 1..] {
+                    let separated = x_i + w_i <= x_j
+                        || x_j + w_j <= x_i
+                        || y_i + h_i <= y_j
+                        || y_j + h_j <= y_i;
+                    if !separated {
+                        return Or(false);
+                    }
+                }
+            }
+            true
+        })
+    }
+}
+
+crate::declare_variants! {
+    default RectanglePacking => "(2 * container_width * container_height)^num_rectangles",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "rectangle_packing",
+        // A 1x2 and two 2x1 rectangles tile a 3x2 container exactly.
+        instance: Box::new(RectanglePacking::new(
+            vec![(1, 2), (2, 1), (2, 1)],
+            3,
+            2,
+            false,
+        )),
+        optimal_config: vec![0, 0, 1, 0, 1, 1],
+        optimal_value: serde_json::json!(true),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/misc/rectangle_packing.rs"]
+mod tests;
@@ -0,0 +1,216 @@
+//! Shortest Common Superstring problem implementation.
+//!
+//! Given a set of strings over an alphabet, find a shortest string that
+//! contains every input string as a contiguous substring. Every shortest
+//! superstring arises from merging the strings in some order with maximal
+//! overlaps, so the configuration is a permutation of the strings. This
+//! problem is NP-hard (Gallant, Maier and Storer, 1980).
+
+use crate::models::permutation::PermutationConstraint;
+use crate::registry::{FieldInfo, ProblemSchemaEntry};
+use crate::traits::Problem;
+use crate::types::Min;
+use serde::{Deserialize, Serialize};
+
+inventory::submit! {
+    ProblemSchemaEntry {
+        name: "ShortestCommonSuperstring",
+        display_name: "Shortest Common Superstring",
+        aliases: &[],
+        dimensions: &[],
+        module_path: module_path!(),
+        description: "Find a shortest string containing every input string as a substring",
+        fields: &[
+            FieldInfo { name: "alphabet_size", type_name: "usize", description: "Size of the alphabet" },
+            FieldInfo { name: "strings", type_name: "Vec<Vec<usize>>", description: "Input strings over the alphabet {0, ..., alphabet_size-1}" },
+        ],
+    }
+}
+
+/// The Shortest Common Superstring problem.
+///
+/// Given an alphabet of size `k` and strings `s_0, ..., s_{n-1}` over
+/// `{0, ..., k-1}`, find a shortest string `w` such that every `s_i` is a
+/// substring of `w`.
+///
+/// # Representation
+///
+/// The configuration is a permutation of `0..n` giving the merge order.
+/// Strings are appended in that order, each overlapping the superstring built
+/// so far as much as possible; a string that already occurs in it is
+/// skipped. The value is the length of the merged string. Configurations that
+/// are not permutations evaluate to `Min(None)`.
+///
+/// The model also implements [`PermutationConstraint`], so
+/// [`BruteForce::solve_permutations`](crate::solvers::BruteForce::solve_permutations)
+/// can enumerate only the `n!` merge orders.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::misc::ShortestCommonSuperstring;
+/// use problemreductions::models::PermutationProblem;
+/// use problemreductions::types::Min;
+/// use problemreductions::{BruteForce, Problem};
+///
+/// // "CATG", "ATGC", "TGCA" with A=0, C=1, G=2, T=3 merge into "CATGCA".
+/// let problem = ShortestCommonSuperstring::new(
+///     4,
+///     vec![vec![1, 0, 3, 2], vec![0, 3, 2, 1], vec![3, 2, 1, 0]],
+/// );
+/// assert_eq!(problem.evaluate(&[0, 1, 2]), Min(Some(6)));
+///
+/// let orders = PermutationProblem::new(problem);
+/// assert_eq!(BruteForce::new().solve_permutations(&orders), Min(Some(6)));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortestCommonSuperstring {
+    alphabet_size: usize,
+    strings: Vec<Vec<usize>>,
+}
+
+impl ShortestCommonSuperstring {
+    /// Create a new ShortestCommonSuperstring instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `strings` is empty or any symbol is outside
+    /// `{0, ..., alphabet_size-1}`.
+    pub fn new(alphabet_size: usize, strings: Vec<Vec<usize>>) -> Self {
+        assert!(!strings.is_empty(), "must have at least one string");
+        assert!(
+            strings
+                .iter()
+                .flatten()
+                .all(|&symbol| symbol < alphabet_size),
+            "all symbols must be less than alphabet_size"
+        );
+        Self {
+            alphabet_size,
+            strings,
+        }
+    }
+
+    /// Returns the alphabet size.
+    pub fn alphabet_size(&self) -> usize {
+        self.alphabet_size
+    }
+
+    /// Returns the input strings.
+    pub fn strings(&self) -> &[Vec<usize>] {
+        &self.strings
+    }
+
+    /// Returns the number of input strings.
+    pub fn num_strings(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns the total length of all input strings.
+    pub fn total_length(&self) -> usize {
+        self.strings.iter().map(Vec::len).sum()
+    }
+
+    /// Length of the longest proper suffix of string `i` that is a prefix of
+    /// string `j`.
+    pub fn overlap(&self, i: usize, j: usize) -> usize {
+        let (a, b) = (&self.strings[i], &self.strings[j]);
+        overlap(a, b)
+    }
+
+    /// Merge the strings in the given order, or `None` if `order` is not a
+    /// permutation of `0..n`.
+    pub fn superstring(&self, order: &[usize]) -> Option<Vec<usize>> {
+        let n = self.num_strings();
+        let mut seen = vec![false; n];
+        if order.len() != n
+            || !order
+                .iter()
+                .all(|&i| i < n && !std::mem::replace(&mut seen[i], true))
+        {
+            return None;
+        }
+
+        let mut merged: Vec<usize> = Vec::with_capacity(self.total_length());
+        for &i in order {
+            let string = &self.strings[i];
+            if contains(&merged, string) {
+                continue;
+            }
+            let shared = overlap(&merged, string);
+            merged.extend_from_slice(&string[shared..]);
+        }
+        Some(merged)
+    }
+}
+
+/// Length of the longest proper suffix of `a` that is a prefix of `b`.
+fn overlap(a: &[usize], b: &[usize]) -> usize {
+    let max = a.len().min(b.len().saturating_sub(1));
+    (1..=max)
+        .rev()
+        .find(|&k| a[a.len() - k..] == b[..k])
+        .unwrap_or(0)
+}
+
+/// Whether `needle` occurs as a contiguous substring of `haystack`.
+fn contains(haystack: &[usize], needle: &[usize]) -> bool {
+    needle.is_empty()
+        || haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+}
+
+impl Problem for ShortestCommonSuperstring {
+    const NAME: &'static str = "ShortestCommonSuperstring";
+    type Value = Min<usize>;
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        crate::variant_params![]
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        let n = self.num_strings();
+        vec![n; n]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Min<usize> {
+        Min(self.superstring(config).map(|merged| merged.len()))
+    }
+}
+
+impl PermutationConstraint for ShortestCommonSuperstring {
+    const NAME: &'static str = "ShortestCommonSuperstring";
+    type Value = Min<usize>;
+
+    fn num_elements(&self) -> usize {
+        self.num_strings()
+    }
+
+    fn cost(&self, perm: &[usize]) -> Min<usize> {
+        Problem::evaluate(self, perm)
+    }
+}
+
+crate::declare_variants! {
+    default ShortestCommonSuperstring => "2^num_strings * num_strings^2",
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_model_example_specs() -> Vec<crate::example_db::specs::ModelExampleSpec> {
+    // "CATG", "ATGC", "TGCA" with A=0, C=1, G=2, T=3. Consecutive strings
+    // overlap in three symbols, giving the superstring "CATGCA" of length 6.
+    vec![crate::example_db::specs::ModelExampleSpec {
+        id: "shortest_common_superstring",
+        instance: Box::new(ShortestCommonSuperstring::new(
+            4,
+            vec![vec![1, 0, 3, 2], vec![0, 3, 2, 1], vec![3, 2, 1, 0]],
+        )),
+        optimal_config: vec![0, 1, 2],
+        optimal_value: serde_json::json!(6),
+    }]
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/models/misc/shortest_common_superstring.rs"]
+mod tests;
@@ -3,10 +3,12 @@
 //! Each sub-module groups related problem types by input structure.
 
 pub mod algebraic;
+pub mod csp;
 pub mod decision;
 pub mod formula;
 pub mod graph;
 pub mod misc;
+pub mod permutation;
 pub mod set;
 
 // Re-export commonly used types
@@ -14,38 +16,40 @@ pub use algebraic::{
     AlgebraicEquationsOverGF2, ClosestVectorProblem, ConsecutiveBlockMinimization,
     ConsecutiveOnesMatrixAugmentation, ConsecutiveOnesSubmatrix, EquilibriumPoint,
     FeasibleBasisExtension, MinimumMatrixCover, MinimumMatrixDomination, MinimumWeightDecoding,
-    MinimumWeightSolutionToLinearEquations, QuadraticAssignment, QuadraticCongruences,
-    QuadraticDiophantineEquations, SimultaneousIncongruences, SparseMatrixCompression, BMF, ILP,
-    QUBO,
+    MinimumWeightSolutionToLinearEquations, PseudoBoolean, QuadraticAssignment,
+    QuadraticCongruences, QuadraticDiophantineEquations, SimultaneousIncongruences,
+    SparseMatrixCompression, BMF, ILP, QUBO,
 };
+pub use csp::{is_satisfying_csp, CspConstraint, CSP};
 pub use decision::Decision;
 pub use formula::{
-    CNFClause, CircuitSAT, KSatisfiability, Maximum2Satisfiability, NAESatisfiability,
-    NonTautology, OneInThreeSatisfiability, Planar3Satisfiability, QuantifiedBooleanFormulas,
-    Quantifier, Satisfiability,
+    CNFClause, CircuitSAT, KSatisfiability, Maximum2Satisfiability, ModelCounting,
+    NAESatisfiability, NonTautology, OneInThreeSatisfiability, Planar3Satisfiability,
+    QuantifiedBooleanFormulas, Quantifier, Satisfiability, XorClause, XorSatisfiability,
 };
 pub use graph::{
     AcyclicPartition, BalancedCompleteBipartiteSubgraph, BicliqueCover, BiconnectivityAugmentation,
     BottleneckTravelingSalesman, BoundedComponentSpanningForest, BoundedDiameterSpanningTree,
-    DegreeConstrainedSpanningTree, DirectedHamiltonianPath, DirectedTwoCommodityIntegralFlow,
-    DisjointConnectingPaths, GeneralizedHex, GraphPartitioning, HamiltonianCircuit,
-    HamiltonianPath, HamiltonianPathBetweenTwoVertices, IntegralFlowBundles,
+    ClusterEditing, DegreeConstrainedSpanningTree, DirectedHamiltonianPath,
+    DirectedTwoCommodityIntegralFlow, DisjointConnectingPaths, GeneralizedHex, GraphPartitioning,
+    HamiltonianCircuit, HamiltonianPath, HamiltonianPathBetweenTwoVertices, IntegralFlowBundles,
     IntegralFlowHomologousArcs, IntegralFlowWithMultipliers, IsomorphicSpanningTree, KClique,
     KColoring, Kernel, KthBestSpanningTree, LengthBoundedDisjointPaths, LongestCircuit,
-    LongestPath, MaxCut, MaximalIS, MaximumAchromaticNumber, MaximumClique, MaximumDomaticNumber,
-    MaximumIndependentSet, MaximumLeafSpanningTree, MaximumMatching, MinMaxMulticenter,
-    MinimumCoveringByCliques, MinimumCutIntoBoundedSets, MinimumDominatingSet,
-    MinimumDummyActivitiesPert, MinimumEdgeCostFlow, MinimumFeedbackArcSet,
-    MinimumFeedbackVertexSet, MinimumGeometricConnectedDominatingSet, MinimumGraphBandwidth,
-    MinimumIntersectionGraphBasis, MinimumMaximalMatching, MinimumMultiwayCut,
-    MinimumSumMulticenter, MinimumVertexCover, MixedChinesePostman, MonochromaticTriangle,
-    MultipleChoiceBranching, MultipleCopyFileAllocation, OptimalLinearArrangement,
-    PartialFeedbackEdgeSet, PartitionIntoCliques, PartitionIntoForests,
-    PartitionIntoPathsOfLength2, PartitionIntoPerfectMatchings, PartitionIntoTriangles,
-    PathConstrainedNetworkFlow, RootedTreeArrangement, RuralPostman, ShortestWeightConstrainedPath,
-    SpinGlass, SteinerTree, SteinerTreeInGraphs, StrongConnectivityAugmentation,
-    SubgraphIsomorphism, TravelingSalesman, UndirectedFlowLowerBounds,
-    UndirectedTwoCommodityIntegralFlow,
+    LongestPath, MaxBisection, MaxCut, MaximalIS, MaximumAchromaticNumber, MaximumAcyclicSubgraph,
+    MaximumBalancedBiclique, MaximumClique, MaximumDomaticNumber, MaximumIndependentSet,
+    MaximumLeafSpanningTree, MaximumMatching, MaximumPlanarSubgraph, MinMaxMulticenter,
+    MinimumCliqueCover, MinimumCoveringByCliques, MinimumCutIntoBoundedSets, MinimumDominatingSet,
+    MinimumDummyActivitiesPert, MinimumEdgeCostFlow, MinimumEdgeDominatingSet,
+    MinimumFeedbackArcSet, MinimumFeedbackVertexSet, MinimumGeometricConnectedDominatingSet,
+    MinimumGraphBandwidth, MinimumIntersectionGraphBasis, MinimumMaximalIS, MinimumMaximalMatching,
+    MinimumMultiwayCut, MinimumSteinerForest, MinimumSumMulticenter, MinimumVertexCover,
+    MixedChinesePostman, MonochromaticTriangle, MultipleChoiceBranching,
+    MultipleCopyFileAllocation, OptimalLinearArrangement, PartialFeedbackEdgeSet,
+    PartitionIntoCliques, PartitionIntoForests, PartitionIntoPathsOfLength2,
+    PartitionIntoPerfectMatchings, PartitionIntoTriangles, PathConstrainedNetworkFlow,
+    PolySpinGlass, RootedTreeArrangement, RuralPostman, ShortestWeightConstrainedPath, SpinGlass,
+    SteinerTree, SteinerTreeInGraphs, StrongConnectivityAugmentation, SubgraphIsomorphism,
+    TravelingSalesman, UndirectedFlowLowerBounds, UndirectedTwoCommodityIntegralFlow,
 };
 pub use misc::PartiallyOrderedKnapsack;
 pub use misc::{
@@ -53,25 +57,28 @@ pub use misc::{
     ConjunctiveBooleanQuery, ConjunctiveQueryFoldability, ConsistencyOfDatabaseFrequencyTables,
     CosineProductIntegration, CyclicOrdering, DynamicStorageAllocation, EnsembleComputation,
     ExpectedRetrievalCost, Factoring, FeasibleRegisterAssignment, FlowShopScheduling,
-    GroupingBySwapping, IntExpr, IntegerExpressionMembership, JobShopScheduling, Knapsack,
-    KthLargestMTuple, LongestCommonSubsequence, MaximumLikelihoodRanking, MinimumAxiomSet,
-    MinimumCodeGenerationOneRegister, MinimumCodeGenerationParallelAssignments,
-    MinimumCodeGenerationUnlimitedRegisters, MinimumDecisionTree, MinimumDisjunctiveNormalForm,
-    MinimumExternalMacroDataCompression, MinimumFaultDetectionTestSet,
-    MinimumInternalMacroDataCompression, MinimumRegisterSufficiencyForLoops,
-    MinimumTardinessSequencing, MinimumWeightAndOrGraph, MultiprocessorScheduling,
-    NonLivenessFreePetriNet, Numerical3DimensionalMatching, NumericalMatchingWithTargetSums,
-    OpenShopScheduling, OptimumCommunicationSpanningTree, PaintShop, Partition,
-    PrecedenceConstrainedScheduling, PreemptiveScheduling, ProductionPlanning, QueryArg,
-    RectilinearPictureCompression, RegisterSufficiency, ResourceConstrainedScheduling,
-    SchedulingToMinimizeWeightedCompletionTime, SchedulingWithIndividualDeadlines,
-    SequencingToMinimizeMaximumCumulativeCost, SequencingToMinimizeTardyTaskWeight,
-    SequencingToMinimizeWeightedCompletionTime, SequencingToMinimizeWeightedTardiness,
-    SequencingWithDeadlinesAndSetUpTimes, SequencingWithReleaseTimesAndDeadlines,
-    SequencingWithinIntervals, ShortestCommonSupersequence, SquareTiling, StackerCrane,
-    StaffScheduling, StringToStringCorrection, SubsetProduct, SubsetSum, SumOfSquaresPartition,
-    Term, ThreePartition, TimetableDesign,
+    GroupingBySwapping, IntExpr, IntegerExpressionMembership, JobShopScheduling, KCenter,
+    KTrackIntervalScheduling, Knapsack, KthLargestMTuple, LongestCommonSubsequence,
+    MaximumLikelihoodRanking, MinimumAxiomSet, MinimumCodeGenerationOneRegister,
+    MinimumCodeGenerationParallelAssignments, MinimumCodeGenerationUnlimitedRegisters,
+    MinimumDecisionTree, MinimumDisjunctiveNormalForm, MinimumExternalMacroDataCompression,
+    MinimumFaultDetectionTestSet, MinimumInternalMacroDataCompression,
+    MinimumMultiprocessorScheduling, MinimumRegisterSufficiencyForLoops,
+    MinimumTardinessSequencing, MinimumWeightAndOrGraph, MinimumWeightTriangulation,
+    MultiprocessorScheduling, NonLivenessFreePetriNet, Numerical3DimensionalMatching,
+    NumericalMatchingWithTargetSums, OpenShopScheduling, OptimumCommunicationSpanningTree,
+    PaintShop, Partition, PrecedenceConstrainedScheduling, PreemptiveScheduling,
+    ProductionPlanning, QueryArg, RectanglePacking, RectilinearPictureCompression,
+    RegisterSufficiency, ResourceConstrainedScheduling, SchedulingToMinimizeWeightedCompletionTime,
+    SchedulingWithIndividualDeadlines, SequencingToMinimizeMaximumCumulativeCost,
+    SequencingToMinimizeTardyTaskWeight, SequencingToMinimizeWeightedCompletionTime,
+    SequencingToMinimizeWeightedTardiness, SequencingWithDeadlinesAndSetUpTimes,
+    SequencingWithReleaseTimesAndDeadlines, SequencingWithinIntervals, ShortestCommonSupersequence,
+    ShortestCommonSuperstring, SquareTiling, StackerCrane, StaffScheduling,
+    StringToStringCorrection, SubsetProduct, SubsetSum, SumOfSquaresPartition, Term,
+    ThreePartition, TimetableDesign,
 };
+pub use permutation::{PermutationConstraint, PermutationProblem};
 pub use set::{
     ComparativeContainment, ConsecutiveSets, ExactCoverBy3Sets, IntegerKnapsack, MaximumSetPacking,
     MinimumCardinalityKey, MinimumHittingSet, MinimumSetCovering, PrimeAttributeName,
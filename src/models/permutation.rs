@@ -0,0 +1,130 @@
+//! Generic template for permutation-encoded problems.
+//!
+//! Ordering problems (arrangements, tours, schedules) share the same
+//! configuration space: a permutation of `0..n`. [`PermutationProblem`]
+//! supplies that encoding once, so a new ordering problem only implements
+//! [`PermutationConstraint`] with its feasibility rule and cost.
+
+use crate::traits::Problem;
+use crate::types::Aggregate;
+use serde::{Deserialize, Serialize};
+
+/// Cost and feasibility logic of a permutation problem.
+pub trait PermutationConstraint: Clone {
+    /// Base name of the resulting problem type.
+    const NAME: &'static str;
+    /// Aggregate value of a permutation (e.g., `Min<i64>`).
+    type Value: Aggregate;
+
+    /// Number of elements being ordered.
+    fn num_elements(&self) -> usize;
+
+    /// Value of a feasible permutation.
+    fn cost(&self, perm: &[usize]) -> Self::Value;
+
+    /// Whether `perm` satisfies the side constraints. All orderings are
+    /// feasible by default.
+    fn is_feasible(&self, _perm: &[usize]) -> bool {
+        true
+    }
+
+    /// Variant attributes of the resulting problem.
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        vec![]
+    }
+}
+
+/// A problem whose configurations are permutations of `0..n`.
+///
+/// The configuration space is `n` variables of cardinality `n`, matching the
+/// encoding of the hand-written ordering models. Configurations that repeat
+/// an element or violate [`PermutationConstraint::is_feasible`] evaluate to the
+/// aggregate identity (e.g., `Min(None)`), so they never contribute to the
+/// result. [`BruteForce::solve_permutations`](crate::solvers::BruteForce::solve_permutations)
+/// enumerates only the `n!` permutations instead of all `n^n` configurations.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::{PermutationConstraint, PermutationProblem};
+/// use problemreductions::types::Min;
+/// use problemreductions::BruteForce;
+///
+/// /// Minimize the total displacement |perm[i] - target[i]|.
+/// #[derive(Clone)]
+/// struct Displacement(Vec<usize>);
+///
+/// impl PermutationConstraint for Displacement {
+///     const NAME: &'static str = "Displacement";
+///     type Value = Min<usize>;
+///
+///     fn num_elements(&self) -> usize {
+///         self.0.len()
+///     }
+///
+///     fn cost(&self, perm: &[usize]) -> Min<usize> {
+///         Min(Some(perm.iter().zip(&self.0).map(|(&p, &t)| p.abs_diff(t)).sum()))
+///     }
+/// }
+///
+/// let problem = PermutationProblem::new(Displacement(vec![2, 0, 1]));
+/// assert_eq!(BruteForce::new().solve_permutations(&problem), Min(Some(0)));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermutationProblem<C> {
+    constraint: C,
+}
+
+impl<C: PermutationConstraint> PermutationProblem<C> {
+    /// Create a permutation problem from its constraint.
+    pub fn new(constraint: C) -> Self {
+        Self { constraint }
+    }
+
+    /// Borrow the underlying constraint.
+    pub fn constraint(&self) -> &C {
+        &self.constraint
+    }
+
+    /// Number of elements being ordered.
+    pub fn num_elements(&self) -> usize {
+        self.constraint.num_elements()
+    }
+
+    /// Check whether `config` is a permutation of `0..n`.
+    pub fn is_permutation(&self, config: &[usize]) -> bool {
+        let n = self.num_elements();
+        if config.len() != n {
+            return false;
+        }
+        let mut seen = vec![false; n];
+        config
+            .iter()
+            .all(|&x| x < n && !std::mem::replace(&mut seen[x], true))
+    }
+}
+
+impl<C: PermutationConstraint> Problem for PermutationProblem<C> {
+    const NAME: &'static str = C::NAME;
+    type Value = C::Value;
+
+    fn dims(&self) -> Vec<usize> {
+        let n = self.num_elements();
+        vec![n; n]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> C::Value {
+        if !self.is_permutation(config) || !self.constraint.is_feasible(config) {
+            return C::Value::identity();
+        }
+        self.constraint.cost(config)
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        C::variant()
+    }
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/models/permutation.rs"]
+mod tests;
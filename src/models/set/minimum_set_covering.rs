@@ -22,6 +22,7 @@ inventory::submit! {
             FieldInfo { name: "universe_size", type_name: "usize", description: "Size of the universe U" },
             FieldInfo { name: "sets", type_name: "Vec<Vec<usize>>", description: "Collection of subsets of U" },
             FieldInfo { name: "weights", type_name: "Vec<W>", description: "Weight for each set" },
+            FieldInfo { name: "coverage_requirements", type_name: "Option<Vec<usize>>", description: "Times each element must be covered (multicover; all 1 if omitted)" },
         ],
     }
 }
@@ -32,6 +33,10 @@ inventory::submit! {
 /// each with a weight, find a minimum weight subcollection of S
 /// that covers all elements in U.
 ///
+/// With [`with_coverage_requirements`](Self::with_coverage_requirements)
+/// this becomes the set *multicover* problem: element `e` must lie in at
+/// least `r_e` selected sets. Requirements of 1 give back classic set cover.
+///
 /// # Example
 ///
 /// ```
@@ -66,6 +71,10 @@ pub struct MinimumSetCovering<W = i32> {
     sets: Vec<Vec<usize>>,
     /// Weights for each set.
     weights: Vec<W>,
+    /// Minimum number of selected sets covering each element; `None` means
+    /// every element must be covered once.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    coverage_requirements: Option<Vec<usize>>,
 }
 
 impl<W: Clone + Default> MinimumSetCovering<W> {
@@ -80,6 +89,7 @@ impl<W: Clone + Default> MinimumSetCovering<W> {
             universe_size,
             sets,
             weights,
+            coverage_requirements: None,
         }
     }
 
@@ -90,7 +100,70 @@ impl<W: Clone + Default> MinimumSetCovering<W> {
             universe_size,
             sets,
             weights,
+            coverage_requirements: None,
+        }
+    }
+
+    /// Require each element `e` to be covered at least `requirements[e]`
+    /// times, turning the instance into a set multicover problem.
+    ///
+    /// # Panics
+    /// Panics if `requirements.len() != universe_size`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::models::set::MinimumSetCovering;
+    /// use problemreductions::types::Min;
+    /// use problemreductions::{BruteForce, Solver};
+    ///
+    /// let sets = vec![vec![0, 1], vec![1, 2], vec![0, 2]];
+    /// let cover = MinimumSetCovering::<i32>::new(3, sets.clone());
+    /// assert_eq!(BruteForce::new().solve(&cover), Min(Some(2)));
+    ///
+    /// // Element 1 must now be covered twice.
+    /// let multicover = MinimumSetCovering::<i32>::new(3, sets).with_coverage_requirements(vec![1, 2, 1]);
+    /// assert_eq!(BruteForce::new().solve(&multicover), Min(Some(2)));
+    /// ```
+    pub fn with_coverage_requirements(mut self, requirements: Vec<usize>) -> Self {
+        assert_eq!(
+            requirements.len(),
+            self.universe_size,
+            "coverage_requirements length must match universe_size"
+        );
+        self.coverage_requirements = Some(requirements);
+        self
+    }
+
+    /// Number of selected sets that must cover `element` (1 unless
+    /// requirements were set).
+    pub fn coverage_requirement(&self, element: usize) -> usize {
+        self.coverage_requirements
+            .as_ref()
+            .map_or(1, |requirements| requirements[element])
+    }
+
+    /// Whether per-element coverage requirements were set.
+    pub fn is_multicover(&self) -> bool {
+        self.coverage_requirements.is_some()
+    }
+
+    /// How many selected sets contain each universe element. A set listing
+    /// an element twice still counts once.
+    pub fn coverage_counts(&self, config: &[usize]) -> Vec<usize> {
+        let mut counts = vec![0; self.universe_size];
+        let mut last_set = vec![usize::MAX; self.universe_size];
+        for (i, &selected) in config.iter().enumerate() {
+            if selected == 1 {
+                for &e in self.sets.get(i).into_iter().flatten() {
+                    if e < self.universe_size && last_set[e] != i {
+                        last_set[e] = i;
+                        counts[e] += 1;
+                    }
+                }
+            }
         }
+        counts
     }
 
     /// Get the universe size.
@@ -118,10 +191,13 @@ impl<W: Clone + Default> MinimumSetCovering<W> {
         &self.weights
     }
 
-    /// Check if a configuration is a valid set cover.
+    /// Check if a configuration is a valid set cover, i.e. every element is
+    /// covered at least as often as it requires.
     pub fn is_valid_solution(&self, config: &[usize]) -> bool {
-        let covered = self.covered_elements(config);
-        covered.len() == self.universe_size && (0..self.universe_size).all(|e| covered.contains(&e))
+        self.coverage_counts(config)
+            .iter()
+            .enumerate()
+            .all(|(e, &count)| count >= self.coverage_requirement(e))
     }
 
     /// Check which elements are covered by selected sets.
@@ -150,10 +226,7 @@ where
     }
 
     fn evaluate(&self, config: &[usize]) -> Min<W::Sum> {
-        let covered = self.covered_elements(config);
-        let is_valid = covered.len() == self.universe_size
-            && (0..self.universe_size).all(|e| covered.contains(&e));
-        if !is_valid {
+        if !self.is_valid_solution(config) {
             return Min(None);
         }
         let mut total = W::Sum::zero();
@@ -177,18 +250,32 @@ crate::declare_variants! {
 /// Check if a selection of sets forms a valid set cover.
 #[cfg(test)]
 pub(crate) fn is_set_cover(universe_size: usize, sets: &[Vec<usize>], selected: &[bool]) -> bool {
+    is_set_multicover(sets, &vec![1; universe_size], selected)
+}
+
+/// Check if a selection of sets covers each element `e` at least
+/// `requirements[e]` times.
+#[cfg(test)]
+pub(crate) fn is_set_multicover(
+    sets: &[Vec<usize>],
+    requirements: &[usize],
+    selected: &[bool],
+) -> bool {
     if selected.len() != sets.len() {
         return false;
     }
 
-    let mut covered = HashSet::new();
-    for (i, &sel) in selected.iter().enumerate() {
-        if sel {
-            covered.extend(sets[i].iter().copied());
+    let mut counts = vec![0; requirements.len()];
+    for (set, _) in sets.iter().zip(selected).filter(|(_, &sel)| sel) {
+        let distinct: HashSet<usize> = set.iter().copied().collect();
+        for e in distinct {
+            if let Some(count) = counts.get_mut(e) {
+                *count += 1;
+            }
         }
     }
 
-    (0..universe_size).all(|e| covered.contains(&e))
+    counts.iter().zip(requirements).all(|(c, r)| c >= r)
 }
 
 #[cfg(feature = "example-db")]
@@ -38,6 +38,10 @@ pub trait DynProblem: Any {
     fn variant_map(&self) -> BTreeMap<String, String>;
     /// Return the number of variables.
     fn num_variables_dyn(&self) -> usize;
+    /// Return instance statistics (`Problem::statistics`).
+    fn statistics_dyn(&self) -> crate::statistics::ProblemStatistics;
+    /// Return the labeled objective breakdown (`Problem::objective_terms`).
+    fn objective_terms_dyn(&self, config: &[usize]) -> Vec<crate::traits::ObjectiveTerm>;
 }
 
 impl<T> DynProblem for T
@@ -76,6 +80,14 @@ where
     fn num_variables_dyn(&self) -> usize {
         self.num_variables()
     }
+
+    fn statistics_dyn(&self) -> crate::statistics::ProblemStatistics {
+        self.statistics()
+    }
+
+    fn objective_terms_dyn(&self, config: &[usize]) -> Vec<crate::traits::ObjectiveTerm> {
+        self.objective_terms(config)
+    }
 }
 
 /// Function pointer type for brute-force value solve dispatch.
@@ -84,13 +96,18 @@ pub type SolveValueFn = fn(&dyn Any) -> String;
 /// Function pointer type for brute-force witness solve dispatch.
 pub type SolveWitnessFn = fn(&dyn Any) -> Option<(Vec<usize>, String)>;
 
+/// Function pointer type for brute-force value solve dispatch with telemetry.
+pub type SolveTelemetryFn = fn(&dyn Any) -> (String, crate::solvers::SolverTelemetry);
+
 /// A loaded problem with type-erased solve capability.
 ///
-/// Wraps a `Box<dyn DynProblem>` with brute-force value and witness function pointers.
+/// Wraps a `Box<dyn DynProblem>` with brute-force value, witness and
+/// telemetry function pointers.
 pub struct LoadedDynProblem {
     inner: Box<dyn DynProblem>,
     solve_value_fn: SolveValueFn,
     solve_witness_fn: SolveWitnessFn,
+    solve_telemetry_fn: SolveTelemetryFn,
 }
 
 impl std::fmt::Debug for LoadedDynProblem {
@@ -107,11 +124,13 @@ impl LoadedDynProblem {
         inner: Box<dyn DynProblem>,
         solve_value_fn: SolveValueFn,
         solve_witness_fn: SolveWitnessFn,
+        solve_telemetry_fn: SolveTelemetryFn,
     ) -> Self {
         Self {
             inner,
             solve_value_fn,
             solve_witness_fn,
+            solve_telemetry_fn,
         }
     }
 
@@ -120,6 +139,12 @@ impl LoadedDynProblem {
         (self.solve_value_fn)(self.inner.as_any())
     }
 
+    /// Solve the problem using brute force and return its aggregate value
+    /// string together with the solver telemetry.
+    pub fn solve_brute_force_telemetry(&self) -> (String, crate::solvers::SolverTelemetry) {
+        (self.solve_telemetry_fn)(self.inner.as_any())
+    }
+
     /// Solve the problem using brute force and return a witness when available.
     pub fn solve_brute_force_witness(&self) -> Option<(Vec<usize>, String)> {
         (self.solve_witness_fn)(self.inner.as_any())
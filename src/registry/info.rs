@@ -3,6 +3,7 @@
 //! This module provides types for describing problem characteristics:
 //!
 //! - [`ComplexityClass`] - Computational complexity (P, NP-complete, etc.)
+//! - [`ProblemCategory`] - Model family a problem belongs to (graph, formula, etc.)
 //! - [`ProblemInfo`] - Rich metadata about a problem type
 //! - [`ProblemMetadata`] - Trait for problems to provide their metadata
 //!
@@ -71,12 +72,114 @@ impl ComplexityClass {
     }
 }
 
+impl ComplexityClass {
+    /// All complexity classes, in the order they are listed to users.
+    pub const ALL: [ComplexityClass; 5] = [
+        ComplexityClass::P,
+        ComplexityClass::NpComplete,
+        ComplexityClass::NpHard,
+        ComplexityClass::PspaceComplete,
+        ComplexityClass::Unknown,
+    ];
+
+    /// Parse a complexity class name, case-insensitively.
+    ///
+    /// Accepts the display names (`"NP-complete"`) as well as `_`/`-`/space
+    /// separated spellings (`"np_complete"`, `"np complete"`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized: String = name
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|class| class.name().replace('-', "").to_lowercase() == normalized)
+    }
+}
+
 impl fmt::Display for ComplexityClass {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.name())
     }
 }
 
+/// The model family a problem belongs to.
+///
+/// Mirrors the submodules of [`crate::models`]: every registered problem lives
+/// in exactly one of them.
+///
+/// # Example
+///
+/// ```rust
+/// use problemreductions::registry::ProblemCategory;
+///
+/// let category = ProblemCategory::from_name("graph").unwrap();
+/// assert_eq!(category, ProblemCategory::Graph);
+/// assert_eq!(category.name(), "graph");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ProblemCategory {
+    /// Graph problems (`models::graph`)
+    Graph,
+    /// Logic and formula problems (`models::formula`)
+    Formula,
+    /// Finite-domain constraint satisfaction problems (`models::csp`)
+    Csp,
+    /// Set system problems (`models::set`)
+    Set,
+    /// Matrix, linear-system, and lattice problems (`models::algebraic`)
+    Algebraic,
+    /// Problems with unique input structures (`models::misc`)
+    Misc,
+}
+
+impl ProblemCategory {
+    /// All categories, in module declaration order.
+    pub const ALL: [ProblemCategory; 6] = [
+        ProblemCategory::Graph,
+        ProblemCategory::Formula,
+        ProblemCategory::Csp,
+        ProblemCategory::Set,
+        ProblemCategory::Algebraic,
+        ProblemCategory::Misc,
+    ];
+
+    /// Get the category name, matching the `models` submodule name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProblemCategory::Graph => "graph",
+            ProblemCategory::Formula => "formula",
+            ProblemCategory::Csp => "csp",
+            ProblemCategory::Set => "set",
+            ProblemCategory::Algebraic => "algebraic",
+            ProblemCategory::Misc => "misc",
+        }
+    }
+
+    /// Parse a category name, case-insensitively.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let lower = name.to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|category| category.name() == lower)
+    }
+
+    /// Classify a problem from its `module_path!()`
+    /// (e.g., `"problemreductions::models::graph::maximum_independent_set"`).
+    pub fn from_module_path(module_path: &str) -> Option<Self> {
+        let mut parts = module_path.split("::");
+        parts.find(|&part| part == "models")?;
+        Self::from_name(parts.next()?)
+    }
+}
+
+impl fmt::Display for ProblemCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 /// Metadata about a problem type.
 ///
 /// Contains static information about a problem definition, including its name,
@@ -7,6 +7,8 @@
 //! - [`ProblemInfo`] - Rich metadata (name, description, complexity, reductions)
 //! - [`ProblemMetadata`] - Trait for problems to provide their own metadata
 //! - [`ComplexityClass`] - Computational complexity classification
+//! - [`ProblemCategory`] - Model family classification
+//! - [`problems_with_complexity`] / [`problems_in_category`] - Filtered problem discovery
 //!
 //! # Example
 //!
@@ -51,13 +53,19 @@ pub mod problem_type;
 mod schema;
 pub mod variant;
 
-pub use dyn_problem::{format_metric, DynProblem, LoadedDynProblem, SolveValueFn, SolveWitnessFn};
-pub use info::{ComplexityClass, FieldInfo, ProblemInfo, ProblemMetadata};
+pub use dyn_problem::{
+    format_metric, DynProblem, LoadedDynProblem, SolveTelemetryFn, SolveValueFn, SolveWitnessFn,
+};
+pub use info::{ComplexityClass, FieldInfo, ProblemCategory, ProblemInfo, ProblemMetadata};
 pub use problem_ref::{parse_catalog_problem_ref, require_graph_variant, ProblemRef};
-pub use problem_type::{find_problem_type, find_problem_type_by_alias, problem_types, ProblemType};
+pub use problem_type::{
+    find_problem_type, find_problem_type_by_alias, problem_types, problems_in_category,
+    problems_with_complexity, ProblemType,
+};
 pub use schema::{
-    collect_schemas, declared_size_fields, FieldInfoJson, ProblemSchemaEntry, ProblemSchemaJson,
-    ProblemSizeFieldEntry, VariantDimension,
+    collect_schemas, declared_size_fields, problem_json_schema, FieldInfoJson,
+    ProblemComplexityClassEntry, ProblemSchemaEntry, ProblemSchemaJson, ProblemSizeFieldEntry,
+    VariantDimension, JSON_SCHEMA_DIALECT,
 };
 pub use variant::{
     find_variant_by_alias, find_variant_entry, validate_variant_aliases, VariantEntry,
@@ -87,6 +95,7 @@ pub fn load_dyn(
         inner,
         entry.solve_value_fn,
         entry.solve_witness_fn,
+        entry.solve_telemetry_fn,
     ))
 }
 
@@ -1,7 +1,9 @@
 //! Problem type catalog: runtime lookup by name, alias, and variant validation.
 
-use super::schema::{ProblemSchemaEntry, VariantDimension};
-use super::FieldInfo;
+use super::schema::{ProblemComplexityClassEntry, ProblemSchemaEntry, VariantDimension};
+use super::variant::VariantEntry;
+use super::{ComplexityClass, FieldInfo, ProblemCategory, ProblemInfo};
+use crate::expr::Expr;
 use std::collections::BTreeMap;
 
 /// A runtime view of a registered problem type from the catalog.
@@ -19,6 +21,8 @@ pub struct ProblemType {
     pub description: &'static str,
     /// Struct fields.
     pub fields: &'static [FieldInfo],
+    /// Module path of the model (e.g., `"problemreductions::models::graph::maximum_independent_set"`).
+    pub module_path: &'static str,
 }
 
 impl ProblemType {
@@ -31,9 +35,47 @@ impl ProblemType {
             dimensions: entry.dimensions,
             description: entry.description,
             fields: entry.fields,
+            module_path: entry.module_path,
         }
     }
 
+    /// The model family this problem belongs to, from its module path.
+    pub fn category(&self) -> Option<ProblemCategory> {
+        ProblemCategory::from_module_path(self.module_path)
+    }
+
+    /// The complexity class of this problem.
+    ///
+    /// Uses an explicit [`ProblemComplexityClassEntry`] when one is registered.
+    /// Otherwise the problem is in P if the best-known complexity of its
+    /// default variant is polynomially bounded, and NP-complete if it is not.
+    /// Problems without a declared default variant are `Unknown`.
+    pub fn complexity_class(&self) -> ComplexityClass {
+        if let Some(entry) = inventory::iter::<ProblemComplexityClassEntry>()
+            .find(|entry| entry.name == self.canonical_name)
+        {
+            return entry.class;
+        }
+        let Some(default) = inventory::iter::<VariantEntry>()
+            .find(|entry| entry.name == self.canonical_name && entry.is_default)
+        else {
+            return ComplexityClass::Unknown;
+        };
+        match Expr::try_parse(default.complexity) {
+            Ok(expr) if is_polynomially_bounded(&expr) => ComplexityClass::P,
+            Ok(_) => ComplexityClass::NpComplete,
+            Err(_) => ComplexityClass::Unknown,
+        }
+    }
+
+    /// Build a [`ProblemInfo`] summary for this problem type.
+    pub fn problem_info(&self) -> ProblemInfo {
+        ProblemInfo::new(self.canonical_name, self.description)
+            .with_aliases(self.aliases)
+            .with_complexity(self.complexity_class())
+            .with_fields(self.fields)
+    }
+
     /// Get the default variant map (each dimension set to its default value).
     pub fn default_variant(&self) -> BTreeMap<String, String> {
         self.dimensions
@@ -75,6 +117,41 @@ pub fn problem_types() -> Vec<ProblemType> {
     types
 }
 
+/// Return all registered problems in the given complexity class, sorted by name.
+///
+/// See [`ProblemType::complexity_class`] for how problems are classified.
+pub fn problems_with_complexity(class: ComplexityClass) -> Vec<ProblemInfo> {
+    problem_types()
+        .into_iter()
+        .filter(|problem| problem.complexity_class() == class)
+        .map(|problem| problem.problem_info())
+        .collect()
+}
+
+/// Return all registered problems in the given category, sorted by name.
+pub fn problems_in_category(category: ProblemCategory) -> Vec<ProblemInfo> {
+    problem_types()
+        .into_iter()
+        .filter(|problem| problem.category() == Some(category))
+        .map(|problem| problem.problem_info())
+        .collect()
+}
+
+/// Check that an expression grows at most polynomially: logarithms, roots,
+/// and constant (possibly fractional) powers of polynomially bounded terms
+/// are allowed; exponentials, factorials, and variable exponents are not.
+fn is_polynomially_bounded(expr: &Expr) -> bool {
+    match expr {
+        Expr::Const(_) | Expr::Var(_) => true,
+        Expr::Add(a, b) | Expr::Mul(a, b) => {
+            is_polynomially_bounded(a) && is_polynomially_bounded(b)
+        }
+        Expr::Pow(base, exp) => is_polynomially_bounded(base) && exp.constant_value().is_some(),
+        Expr::Log(a) | Expr::Sqrt(a) => is_polynomially_bounded(a),
+        Expr::Exp(a) | Expr::Factorial(a) => a.constant_value().is_some(),
+    }
+}
+
 #[cfg(test)]
 #[path = "../unit_tests/registry/problem_type.rs"]
 mod tests;
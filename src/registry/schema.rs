@@ -1,7 +1,9 @@
 //! Problem schema registration via inventory.
 
-use super::FieldInfo;
+use super::{ComplexityClass, FieldInfo, VariantEntry};
 use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
 
 /// A declared variant dimension for a problem type.
 ///
@@ -65,6 +67,20 @@ pub struct ProblemSizeFieldEntry {
 
 inventory::collect!(ProblemSizeFieldEntry);
 
+/// Optional static complexity-class metadata for problem types.
+///
+/// Problems without an entry are classified from the complexity of their
+/// default variant (see [`ProblemType::complexity_class`](super::ProblemType::complexity_class)).
+/// Submit one only when that heuristic is wrong, e.g. for PSPACE-complete problems.
+pub struct ProblemComplexityClassEntry {
+    /// Problem name (e.g., "QuantifiedBooleanFormulas").
+    pub name: &'static str,
+    /// The problem's complexity class.
+    pub class: ComplexityClass,
+}
+
+inventory::collect!(ProblemComplexityClassEntry);
+
 /// JSON-serializable problem schema.
 #[derive(Debug, Clone, Serialize)]
 pub struct ProblemSchemaJson {
@@ -117,6 +133,345 @@ pub fn declared_size_fields(name: &str) -> Vec<&'static str> {
         .collect()
 }
 
+/// JSON Schema dialect emitted by [`problem_json_schema`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generate a JSON Schema for the problem file format of `name`.
+///
+/// A problem file is the `{"type", "variant", "data"}` wrapper written by
+/// `pred create`. The `data` object is described from the registered
+/// [`FieldInfo`] type names; generic parameters (`G`, `W`, ...) are resolved
+/// against the variant. With `variant = None` the schema accepts any
+/// registered variant of the problem.
+///
+/// Returns `None` if `name` is not a registered problem, or if `variant` is
+/// not one of its registered variants.
+pub fn problem_json_schema(
+    name: &str,
+    variant: Option<&BTreeMap<String, String>>,
+) -> Option<Value> {
+    let entry = inventory::iter::<ProblemSchemaEntry>
+        .into_iter()
+        .find(|entry| entry.name == name)?;
+    let mut variants: Vec<BTreeMap<String, String>> = inventory::iter::<VariantEntry>
+        .into_iter()
+        .filter(|v| v.name == name)
+        .map(VariantEntry::variant_map)
+        .collect();
+    variants.sort();
+    variants.dedup();
+    if let Some(variant) = variant {
+        if !variants.contains(variant) {
+            return None;
+        }
+        variants = vec![variant.clone()];
+    }
+
+    let mut branches: Vec<Value> = variants
+        .iter()
+        .map(|variant| problem_file_schema(entry, variant))
+        .collect();
+    let mut schema = if branches.len() == 1 {
+        branches.pop().expect("one branch")
+    } else {
+        json!({ "anyOf": branches })
+    };
+    let object = schema.as_object_mut().expect("schema is an object");
+    object.insert("$schema".into(), json!(JSON_SCHEMA_DIALECT));
+    object.insert("title".into(), json!(entry.display_name));
+    object.insert("description".into(), json!(entry.description));
+    Some(schema)
+}
+
+/// Schema for one variant: the file wrapper around the `data` object.
+fn problem_file_schema(entry: &ProblemSchemaEntry, variant: &BTreeMap<String, String>) -> Value {
+    let mut properties = serde_json::Map::new();
+    let mut required = Vec::new();
+    for field in entry.fields {
+        let mut schema = type_schema(field.type_name, variant);
+        if let Value::Object(object) = &mut schema {
+            object.insert("description".into(), json!(field.description));
+        }
+        properties.insert(field.name.into(), schema);
+        if !field.type_name.trim().starts_with("Option<") {
+            required.push(field.name);
+        }
+    }
+    let mut data = json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    });
+    // `Decision<P>` serializes as `{inner, bound}` while its fields are listed flat.
+    if entry.name.starts_with("Decision") {
+        if let Some(bound) = data["properties"]
+            .as_object_mut()
+            .and_then(|properties| properties.remove("bound"))
+        {
+            data["required"] = json!(required
+                .iter()
+                .filter(|name| **name != "bound")
+                .collect::<Vec<_>>());
+            data = json!({
+                "type": "object",
+                "properties": { "inner": data, "bound": bound },
+                "required": ["inner", "bound"],
+            });
+        }
+    }
+    let variant_properties: serde_json::Map<String, Value> = variant
+        .iter()
+        .map(|(key, value)| (key.clone(), json!({ "const": value })))
+        .collect();
+    json!({
+        "type": "object",
+        "properties": {
+            "type": { "const": entry.name },
+            "variant": {
+                "type": "object",
+                "properties": variant_properties,
+                "additionalProperties": false,
+            },
+            "data": data,
+        },
+        "required": ["type", "data"],
+    })
+}
+
+/// Named types with a struct-like serde form: `(type, &[(field, field type)])`.
+const OBJECT_TYPES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "SimpleGraph",
+        &[("num_vertices", "usize"), ("edges", "Vec<(usize, usize)>")],
+    ),
+    (
+        "DirectedGraph",
+        &[("num_vertices", "usize"), ("arcs", "Vec<(usize, usize)>")],
+    ),
+    (
+        "MixedGraph",
+        &[
+            ("num_vertices", "usize"),
+            ("arcs", "Vec<(usize, usize)>"),
+            ("edges", "Vec<(usize, usize)>"),
+        ],
+    ),
+    (
+        "HyperGraph",
+        &[("num_vertices", "usize"), ("edges", "Vec<Vec<usize>>")],
+    ),
+    (
+        "BipartiteGraph",
+        &[
+            ("left_size", "usize"),
+            ("right_size", "usize"),
+            ("edges", "Vec<(usize, usize)>"),
+        ],
+    ),
+    (
+        "UnitDiskGraph",
+        &[
+            ("positions", "Vec<(f64, f64)>"),
+            ("radius", "f64"),
+            ("edges", "Vec<(usize, usize)>"),
+        ],
+    ),
+    ("KingsSubgraph", &[("positions", "Vec<(i32, i32)>")]),
+    ("TriangularSubgraph", &[("positions", "Vec<(i32, i32)>")]),
+    ("PlanarGraph", &[("inner", "SimpleGraph")]),
+    ("CNFClause", &[("literals", "Vec<i32>")]),
+    ("XorClause", &[("literals", "Vec<i32>")]),
+    (
+        "LinearConstraint",
+        &[
+            ("terms", "Vec<(usize, f64)>"),
+            ("cmp", "Comparison"),
+            ("rhs", "f64"),
+            ("lo", "Option<f64>"),
+        ],
+    ),
+    (
+        "PseudoBooleanConstraint",
+        &[
+            ("terms", "Vec<(usize, i64)>"),
+            ("cmp", "PseudoBooleanComparison"),
+            ("rhs", "i64"),
+        ],
+    ),
+    (
+        "VarBounds",
+        &[("lower", "Option<i64>"), ("upper", "Option<i64>")],
+    ),
+    (
+        "Relation",
+        &[("arity", "usize"), ("tuples", "Vec<Vec<usize>>")],
+    ),
+    (
+        "FrequencyTable",
+        &[
+            ("attribute_a", "usize"),
+            ("attribute_b", "usize"),
+            ("counts", "Vec<Vec<usize>>"),
+        ],
+    ),
+    (
+        "KnownValue",
+        &[
+            ("object", "usize"),
+            ("attribute", "usize"),
+            ("value", "usize"),
+        ],
+    ),
+];
+
+/// Named unit-variant enums, serialized as their variant name.
+const ENUM_TYPES: &[(&str, &[&str])] = &[
+    ("Comparison", &["Le", "Ge", "Eq", "Range"]),
+    ("PseudoBooleanComparison", &["Le", "Ge", "Eq"]),
+    ("ObjectiveSense", &["Maximize", "Minimize"]),
+    ("Quantifier", &["Exists", "ForAll"]),
+];
+
+/// Translate a Rust type name from a [`FieldInfo`] into a JSON Schema.
+///
+/// Unknown named types map to the empty schema, which accepts any value.
+fn type_schema(type_name: &str, variant: &BTreeMap<String, String>) -> Value {
+    let ty = type_name.trim();
+    if let Some(inner) = generic_argument(ty, "Vec") {
+        return json!({ "type": "array", "items": type_schema(inner, variant) });
+    }
+    if let Some(inner) = generic_argument(ty, "Option") {
+        return json!({ "anyOf": [type_schema(inner, variant), { "type": "null" }] });
+    }
+    if let Some(inner) = ty.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        let items: Vec<Value> = split_top_level(inner, ',')
+            .into_iter()
+            .map(|item| type_schema(item, variant))
+            .collect();
+        return json!({
+            "type": "array",
+            "prefixItems": items,
+            "minItems": items.len(),
+            "maxItems": items.len(),
+        });
+    }
+    if let Some(inner) = ty.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        if let [item, len] = split_top_level(inner, ';')[..] {
+            if let Ok(len) = len.parse::<usize>() {
+                return json!({
+                    "type": "array",
+                    "items": type_schema(item, variant),
+                    "minItems": len,
+                    "maxItems": len,
+                });
+            }
+        }
+    }
+    match ty {
+        "usize" | "u8" | "u16" | "u32" | "u64" => json!({ "type": "integer", "minimum": 0 }),
+        "isize" | "i8" | "i16" | "i32" | "i64" => json!({ "type": "integer" }),
+        "f32" | "f64" => json!({ "type": "number" }),
+        "bool" => json!({ "type": "boolean" }),
+        "String" => json!({ "type": "string" }),
+        "BigUint" => json!({
+            "anyOf": [
+                { "type": "string", "pattern": "^[0-9]+$" },
+                { "type": "integer", "minimum": 0 },
+            ]
+        }),
+        "One" => json!({ "enum": [1, null] }),
+        "QueryArg" => {
+            let tagged = |tag: &str| {
+                json!({
+                    "type": "object",
+                    "properties": { tag: { "type": "integer", "minimum": 0 } },
+                    "required": [tag],
+                    "additionalProperties": false,
+                })
+            };
+            json!({ "oneOf": [tagged("Variable"), tagged("Constant")] })
+        }
+        "Term" => json!({
+            "type": "object",
+            "properties": {
+                "type": { "enum": ["Constant", "Distinguished", "Undistinguished"] },
+                "index": { "type": "integer", "minimum": 0 },
+            },
+            "required": ["type", "index"],
+        }),
+        "G" => match variant.get("graph") {
+            Some(graph) => type_schema(graph, variant),
+            None => json!({}),
+        },
+        _ if is_weight_parameter(ty) => match variant.get("weight") {
+            Some(weight) if weight.as_str() == "One" && ty.ends_with("::Sum") => {
+                json!({ "type": "integer" })
+            }
+            Some(weight) => type_schema(weight, variant),
+            None => json!({}),
+        },
+        _ => {
+            if let Some((_, fields)) = OBJECT_TYPES.iter().find(|(name, _)| *name == ty) {
+                object_schema(fields, variant)
+            } else if let Some((_, values)) = ENUM_TYPES.iter().find(|(name, _)| *name == ty) {
+                json!({ "enum": values })
+            } else {
+                json!({})
+            }
+        }
+    }
+}
+
+fn object_schema(fields: &[(&str, &str)], variant: &BTreeMap<String, String>) -> Value {
+    let properties: serde_json::Map<String, Value> = fields
+        .iter()
+        .map(|(name, ty)| (name.to_string(), type_schema(ty, variant)))
+        .collect();
+    let required: Vec<&str> = fields
+        .iter()
+        .filter(|(_, ty)| !ty.starts_with("Option<"))
+        .map(|(name, _)| *name)
+        .collect();
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+/// Generic weight-like parameters such as `W`, `T` or `W::Sum`.
+fn is_weight_parameter(ty: &str) -> bool {
+    let base = ty.split("::").next().unwrap_or(ty);
+    base.len() == 1 && base.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/// `generic_argument("Vec<Vec<usize>>", "Vec") == Some("Vec<usize>")`.
+fn generic_argument<'a>(ty: &'a str, wrapper: &str) -> Option<&'a str> {
+    ty.strip_prefix(wrapper)?
+        .trim_start()
+        .strip_prefix('<')?
+        .strip_suffix('>')
+}
+
+/// Split `s` at `separator` characters that are not nested in `<>`, `()` or `[]`.
+fn split_top_level(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            _ if c == separator && depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    let last = s[start..].trim();
+    if !last.is_empty() {
+        parts.push(last);
+    }
+    parts
+}
+
 #[cfg(test)]
 #[path = "../unit_tests/registry/schema.rs"]
 mod tests;
@@ -3,7 +3,7 @@
 use std::any::Any;
 use std::collections::BTreeMap;
 
-use crate::registry::dyn_problem::{DynProblem, SolveValueFn, SolveWitnessFn};
+use crate::registry::dyn_problem::{DynProblem, SolveTelemetryFn, SolveValueFn, SolveWitnessFn};
 
 /// A registered problem variant entry.
 ///
@@ -36,6 +36,8 @@ pub struct VariantEntry {
     pub solve_value_fn: SolveValueFn,
     /// Solve witness: downcast `&dyn Any` and brute-force recover a witness when available.
     pub solve_witness_fn: SolveWitnessFn,
+    /// Solve telemetry: downcast `&dyn Any`, brute-force solve and report the search effort.
+    pub solve_telemetry_fn: SolveTelemetryFn,
 }
 
 impl VariantEntry {
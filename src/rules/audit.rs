@@ -0,0 +1,247 @@
+//! Empirical validation of declared reduction overheads.
+//!
+//! Each registered reduction declares its target size as expressions over
+//! the source size (see [`ReductionOverhead`]). An audit runs the reduction
+//! on concrete instances, measures the target size through the registered
+//! getters, and compares every measured field against the declared
+//! expression evaluated on the measured source size. A field is flagged when
+//! the measurement exceeds the declared bound by more than a constant factor
+//! on any instance.
+
+use crate::rules::graph::ReductionGraph;
+use crate::rules::registry::{reductions_from, ReductionEntry, ReductionOverhead};
+use crate::rules::traits::ReduceTo;
+use crate::traits::Problem;
+use crate::types::ProblemSize;
+use serde::Serialize;
+use std::any::Any;
+use std::fmt;
+
+/// Default factor by which a measurement may exceed its declared bound.
+pub const DEFAULT_AUDIT_TOLERANCE: f64 = 2.0;
+
+/// Sizes observed for one audited instance.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditSample {
+    /// Measured source size.
+    pub source_size: ProblemSize,
+    /// Declared target size, evaluated on `source_size`.
+    pub declared: Vec<(String, f64)>,
+    /// Measured target size.
+    pub measured: ProblemSize,
+}
+
+/// Audit outcome for one declared output field.
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldAudit {
+    /// Output field name (e.g. `"num_vars"`).
+    pub field: String,
+    /// Declared expression, as written in the overhead.
+    pub expression: String,
+    /// Least-squares factor `c` fitting `measured ≈ c · declared`.
+    pub fitted_factor: f64,
+    /// Largest `measured / declared` ratio over all samples.
+    pub max_ratio: f64,
+    /// Whether `max_ratio` exceeds the audit tolerance.
+    pub exceeded: bool,
+}
+
+/// Result of auditing one reduction against a set of instances.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditReport {
+    pub source_name: String,
+    pub target_name: String,
+    /// Factor by which a measurement may exceed its declared bound.
+    pub tolerance: f64,
+    pub samples: Vec<AuditSample>,
+    /// One entry per declared field that could be measured on the target.
+    pub fields: Vec<FieldAudit>,
+    /// Declared fields the target problem does not expose as a size getter.
+    pub unmeasured: Vec<String>,
+}
+
+impl AuditReport {
+    /// Fields whose measured growth exceeds the declared bound.
+    pub fn violations(&self) -> impl Iterator<Item = &FieldAudit> {
+        self.fields.iter().filter(|field| field.exceeded)
+    }
+
+    /// Whether every measured field stays within the declared bound.
+    pub fn is_ok(&self) -> bool {
+        self.violations().next().is_none()
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} -> {} ({} instances, tolerance {}x)",
+            self.source_name,
+            self.target_name,
+            self.samples.len(),
+            self.tolerance
+        )?;
+        for field in &self.fields {
+            writeln!(
+                f,
+                "  {:<6} {} = {}  (max ratio {:.3}, fitted factor {:.3})",
+                if field.exceeded { "FAIL" } else { "ok" },
+                field.field,
+                field.expression,
+                field.max_ratio,
+                field.fitted_factor
+            )?;
+        }
+        for field in &self.unmeasured {
+            writeln!(f, "  skip   {field} (no size getter on target)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Find the registered reduction entry between two exact variants.
+pub fn find_reduction_entry(
+    source_name: &str,
+    source_variant: &[(&str, &str)],
+    target_name: &str,
+    target_variant: &[(&str, &str)],
+) -> Option<&'static ReductionEntry> {
+    let source_variant = ReductionGraph::variant_to_map(source_variant);
+    let target_variant = ReductionGraph::variant_to_map(target_variant);
+    reductions_from(source_name).iter().copied().find(|entry| {
+        entry.target_name == target_name
+            && ReductionGraph::variant_to_map(&entry.source_variant()) == source_variant
+            && ReductionGraph::variant_to_map(&entry.target_variant()) == target_variant
+    })
+}
+
+/// Audit the registered `S -> T` reduction on the given instances with the
+/// default tolerance.
+///
+/// # Panics
+///
+/// Panics if no reduction from `S` to `T` is registered.
+pub fn audit_reduction<S, T>(instances: &[S]) -> AuditReport
+where
+    S: ReduceTo<T> + 'static,
+    T: Problem + 'static,
+{
+    let entry = find_reduction_entry(S::NAME, &S::variant(), T::NAME, &T::variant())
+        .unwrap_or_else(|| panic!("no registered reduction {} -> {}", S::NAME, T::NAME));
+    let instances: Vec<&dyn Any> = instances.iter().map(|s| s as &dyn Any).collect();
+    audit_entry(entry, &instances, DEFAULT_AUDIT_TOLERANCE)
+}
+
+/// Audit a reduction entry on type-erased source instances.
+///
+/// Each instance must have the entry's source type. Source and target sizes
+/// are measured through the getters registered for the respective variants.
+///
+/// # Panics
+///
+/// Panics if the entry has no witness reduction executor.
+pub fn audit_entry(entry: &ReductionEntry, instances: &[&dyn Any], tolerance: f64) -> AuditReport {
+    let reduce = entry.reduce_fn.unwrap_or_else(|| {
+        panic!(
+            "{} -> {} has no reduce_fn",
+            entry.source_name, entry.target_name
+        )
+    });
+    let target_variant = ReductionGraph::variant_to_map(&entry.target_variant());
+    let overhead = entry.overhead();
+
+    let samples = instances
+        .iter()
+        .map(|&instance| {
+            let source_size = (entry.source_size_fn)(instance);
+            let result = reduce(instance);
+            let measured = ReductionGraph::compute_source_size_for_variant(
+                entry.target_name,
+                &target_variant,
+                result.target_problem_any(),
+            );
+            AuditSample {
+                declared: declared_sizes(&overhead, &source_size),
+                source_size,
+                measured,
+            }
+        })
+        .collect();
+
+    build_report(
+        entry.source_name,
+        entry.target_name,
+        &overhead,
+        samples,
+        tolerance,
+    )
+}
+
+fn declared_sizes(overhead: &ReductionOverhead, source_size: &ProblemSize) -> Vec<(String, f64)> {
+    overhead
+        .output_size
+        .iter()
+        .map(|(name, expr)| (name.to_string(), expr.eval(source_size)))
+        .collect()
+}
+
+fn build_report(
+    source_name: &str,
+    target_name: &str,
+    overhead: &ReductionOverhead,
+    samples: Vec<AuditSample>,
+    tolerance: f64,
+) -> AuditReport {
+    let mut fields = Vec::new();
+    let mut unmeasured = Vec::new();
+
+    for (index, (name, expr)) in overhead.output_size.iter().enumerate() {
+        let pairs: Option<Vec<(f64, f64)>> = samples
+            .iter()
+            .map(|sample| {
+                let measured = sample.measured.get(name)? as f64;
+                Some((sample.declared[index].1, measured))
+            })
+            .collect();
+        let Some(pairs) = pairs.filter(|pairs| !pairs.is_empty()) else {
+            unmeasured.push(name.to_string());
+            continue;
+        };
+
+        // A declared bound below one still allows a size of one (e.g. a
+        // constant-size gadget), so ratios are taken against max(declared, 1).
+        let max_ratio = pairs
+            .iter()
+            .map(|&(declared, measured)| measured / declared.max(1.0))
+            .fold(0.0, f64::max);
+        let (cross, square) =
+            pairs
+                .iter()
+                .fold((0.0, 0.0), |(cross, square), &(declared, measured)| {
+                    (cross + declared * measured, square + declared * declared)
+                });
+        let fitted_factor = if square > 0.0 { cross / square } else { 0.0 };
+
+        fields.push(FieldAudit {
+            field: name.to_string(),
+            expression: expr.to_string(),
+            fitted_factor,
+            max_ratio,
+            exceeded: max_ratio > tolerance,
+        });
+    }
+
+    AuditReport {
+        source_name: source_name.to_string(),
+        target_name: target_name.to_string(),
+        tolerance,
+        samples,
+        fields,
+        unmeasured,
+    }
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/audit.rs"]
+mod tests;
@@ -0,0 +1,130 @@
+//! Reduction from ClusterEditing to ILP (Integer Linear Programming).
+//!
+//! Uses the clique partitioning formulation of Grötschel and Wakabayashi:
+//! - Variables: binary `x_uv` for every vertex pair `u < v` (1 = same cluster)
+//! - Constraints: transitivity `x_uv + x_vw - x_uw <= 1` for every triple,
+//!   once per choice of the middle vertex, so "same cluster" is an
+//!   equivalence relation
+//! - Objective: minimize `Σ_{uv ∉ E} x_uv - Σ_{uv ∈ E} w_uv · x_uv`, which
+//!   differs from the editing cost `Σ_{uv ∈ E} w_uv (1 - x_uv) + Σ_{uv ∉ E} x_uv`
+//!   by the constant `Σ_e w_e`
+
+use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::graph::ClusterEditing;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+
+/// Result of reducing ClusterEditing to ILP.
+///
+/// Variable layout: `x_uv` for each pair `u < v` in lexicographic order.
+#[derive(Debug, Clone)]
+pub struct ReductionClusterEditingToILP {
+    target: ILP<bool>,
+    num_vertices: usize,
+}
+
+/// Index of the pair variable `x_uv` (`u < v`) among the `n(n-1)/2` pairs.
+fn pair_index(n: usize, u: usize, v: usize) -> usize {
+    debug_assert!(u < v && v < n);
+    u * (2 * n - u - 1) / 2 + (v - u - 1)
+}
+
+impl ReductionResult for ReductionClusterEditingToILP {
+    type Source = ClusterEditing<SimpleGraph, i32>;
+    type Target = ILP<bool>;
+
+    fn target_problem(&self) -> &ILP<bool> {
+        &self.target
+    }
+
+    /// Label each vertex with the smallest vertex in its cluster.
+    ///
+    /// Transitivity makes `x` an equivalence relation, so the smallest
+    /// co-clustered vertex identifies the cluster.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let n = self.num_vertices;
+        (0..n)
+            .map(|v| {
+                (0..v)
+                    .find(|&u| target_solution[pair_index(n, u, v)] == 1)
+                    .unwrap_or(v)
+            })
+            .collect()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vars = "num_vertices * (num_vertices - 1) / 2",
+        num_constraints = "num_vertices * (num_vertices - 1) * (num_vertices - 2) / 2",
+    }
+)]
+impl ReduceTo<ILP<bool>> for ClusterEditing<SimpleGraph, i32> {
+    type Result = ReductionClusterEditingToILP;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.num_vertices();
+        let num_vars = n * n.saturating_sub(1) / 2;
+
+        let mut constraints = Vec::new();
+        for u in 0..n {
+            for v in (u + 1)..n {
+                for w in (v + 1)..n {
+                    let (uv, uw, vw) = (
+                        pair_index(n, u, v),
+                        pair_index(n, u, w),
+                        pair_index(n, v, w),
+                    );
+                    for (left, right, closing) in [(uv, vw, uw), (uv, uw, vw), (uw, vw, uv)] {
+                        constraints.push(LinearConstraint::le(
+                            vec![(left, 1.0), (right, 1.0), (closing, -1.0)],
+                            1.0,
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut coefficients = vec![0.0; num_vars];
+        for u in 0..n {
+            for v in (u + 1)..n {
+                coefficients[pair_index(n, u, v)] = 1.0;
+            }
+        }
+        for ((u, v), &weight) in self.graph().edges().into_iter().zip(self.edge_weights()) {
+            let (u, v) = if u < v { (u, v) } else { (v, u) };
+            coefficients[pair_index(n, u, v)] = -(weight as f64);
+        }
+        let objective: Vec<(usize, f64)> = coefficients
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, coef)| coef != 0.0)
+            .collect();
+
+        let target = ILP::new(num_vars, constraints, objective, ObjectiveSense::Minimize);
+
+        ReductionClusterEditingToILP {
+            target,
+            num_vertices: n,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "clusterediting_to_ilp",
+        build: || {
+            let source = ClusterEditing::new(
+                SimpleGraph::new(5, vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]),
+                vec![3, 3, 3, 1, 1, 2],
+            );
+            crate::example_db::specs::rule_example_via_ilp::<_, bool>(source)
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/clusterediting_ilp.rs"]
+mod tests;
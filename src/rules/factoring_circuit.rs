@@ -6,6 +6,9 @@
 //!
 //! The multiplier circuit uses an array multiplier structure with
 //! carry propagation, building up partial products row by row.
+//! Factor width constraints (`p_bits`/`q_bits`) become constant assignments
+//! on the pinned input bits, and two more assignments force both factors to
+//! be at least 2 (some bit above the lowest is set), excluding 1 × N.
 
 use crate::models::formula::{Assignment, BooleanExpr, Circuit, CircuitSAT};
 use crate::models::misc::Factoring;
@@ -176,8 +179,8 @@ fn build_multiplier_cell(
 }
 
 #[reduction(overhead = {
-    num_variables = "6 * num_bits_first * num_bits_second + num_bits_first + num_bits_second",
-    num_assignments = "6 * num_bits_first * num_bits_second + num_bits_first + num_bits_second",
+    num_variables = "6 * num_bits_first * num_bits_second + num_bits_first + num_bits_second + 2",
+    num_assignments = "6 * num_bits_first * num_bits_second + num_bits_first + num_bits_second + 4",
 })]
 impl ReduceTo<CircuitSAT> for Factoring {
     type Result = ReductionFactoringToCircuit;
@@ -254,6 +257,32 @@ impl ReduceTo<CircuitSAT> for Factoring {
             ));
         }
 
+        // Pin the factor bits fixed by the width constraints
+        for (index, value) in self.fixed_bits() {
+            let name = if index < n1 {
+                &p_vars[index]
+            } else {
+                &q_vars[index - n1]
+            };
+            assignments.push(Assignment::new(
+                vec![name.clone()],
+                BooleanExpr::constant(value == 1),
+            ));
+        }
+
+        // Require p, q >= 2: the OR of each factor's bits above bit 0 is true
+        for (name, vars) in [("p_nontrivial", &p_vars), ("q_nontrivial", &q_vars)] {
+            let high_bits = vars[1..].iter().map(|v| BooleanExpr::var(v)).collect();
+            assignments.push(Assignment::new(
+                vec![name.to_string()],
+                BooleanExpr::or(high_bits),
+            ));
+            assignments.push(Assignment::new(
+                vec![name.to_string()],
+                BooleanExpr::constant(true),
+            ));
+        }
+
         // Build the circuit
         let circuit = Circuit::new(assignments);
         let circuit_sat = CircuitSAT::new(circuit);
@@ -281,7 +310,7 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
                     target_config: vec![
                         1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 1, 0, 0,
                         1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 1, 1, 1,
-                        1, 1, 1, 1, 1, 1, 0, 0, 0, 0,
+                        1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0,
                     ],
                 },
             )
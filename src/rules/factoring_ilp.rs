@@ -18,6 +18,9 @@
 //! 3. No overflow: c_{m+n-1} = 0
 //! 4. Binary bounds: p_i ≤ 1, q_j ≤ 1
 //! 5. Carry bounds: 0 ≤ c_k ≤ min(m, n)
+//! 6. Factor widths: when `p_bits`/`q_bits` are set, the factor's top bit is
+//!    fixed to 1 and any higher bits to 0
+//! 7. Nontrivial factors: Σ_{i≥1} p_i ≥ 1 and Σ_{j≥1} q_j ≥ 1, i.e. p, q ≥ 2
 
 use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
 use crate::models::misc::Factoring;
@@ -212,6 +215,21 @@ impl ReduceTo<ILP<i32>> for Factoring {
             constraints.push(LinearConstraint::le(vec![(cv, 1.0)], carry_upper));
         }
 
+        // Constraint 6: Factor width pins (config indices coincide with p/q variables)
+        for (index, value) in self.fixed_bits() {
+            constraints.push(LinearConstraint::eq(vec![(index, 1.0)], value as f64));
+        }
+
+        // Constraint 7: Both factors at least 2, so 1 × N is excluded
+        constraints.push(LinearConstraint::ge(
+            (1..m).map(|i| (p_var(i), 1.0)).collect(),
+            1.0,
+        ));
+        constraints.push(LinearConstraint::ge(
+            (1..n).map(|j| (q_var(j), 1.0)).collect(),
+            1.0,
+        ));
+
         // Objective: feasibility problem (minimize 0)
         let objective: Vec<(usize, f64)> = vec![];
 
@@ -14,7 +14,8 @@
 
 use crate::rules::cost::PathCostFn;
 use crate::rules::registry::{
-    AggregateReduceFn, EdgeCapabilities, ReduceFn, ReductionEntry, ReductionOverhead,
+    reductions_from, reductions_to, AggregateReduceFn, EdgeCapabilities, ReduceFn, ReductionEntry,
+    ReductionOverhead,
 };
 use crate::rules::traits::{DynAggregateReductionResult, DynReductionResult};
 use crate::types::ProblemSize;
@@ -165,6 +166,70 @@ impl ReductionPath {
     }
 }
 
+impl ReductionPath {
+    /// Rebuild a path from a [`Recipe`](crate::export::recipe::Recipe).
+    ///
+    /// The recipe must have a supported version, its steps must chain from
+    /// `source` to `target`, and every step must be an edge of `graph`
+    /// between the recorded variants.
+    pub fn from_recipe(
+        graph: &ReductionGraph,
+        recipe: &crate::export::recipe::Recipe,
+    ) -> crate::error::Result<Self> {
+        use crate::export::recipe::RECIPE_VERSION;
+        let invalid = |msg: String| crate::error::ProblemError::InvalidProblem(msg);
+
+        if recipe.version != RECIPE_VERSION {
+            return Err(invalid(format!(
+                "unsupported recipe version {} (expected {RECIPE_VERSION})",
+                recipe.version
+            )));
+        }
+        let (Some(first), Some(last)) = (recipe.steps.first(), recipe.steps.last()) else {
+            return Err(invalid("recipe has no reduction steps".to_string()));
+        };
+        if first.from != recipe.source || last.to != recipe.target {
+            return Err(invalid(
+                "recipe steps do not run from its source to its target".to_string(),
+            ));
+        }
+
+        let mut steps = vec![ReductionStep {
+            name: first.from.name.clone(),
+            variant: first.from.variant.clone(),
+        }];
+        for (i, step) in recipe.steps.iter().enumerate() {
+            if i > 0 && step.from != recipe.steps[i - 1].to {
+                return Err(invalid(format!(
+                    "recipe step {} starts at {} but step {i} ends at {}",
+                    i + 1,
+                    step.from.name,
+                    recipe.steps[i - 1].to.name
+                )));
+            }
+            let edge = graph
+                .lookup_node(&step.from.name, &step.from.variant)
+                .zip(graph.lookup_node(&step.to.name, &step.to.variant))
+                .and_then(|(src, dst)| graph.graph.find_edge(src, dst));
+            if edge.is_none() {
+                return Err(invalid(format!(
+                    "recipe step {} ({} {:?} -> {} {:?}) is not a registered reduction",
+                    i + 1,
+                    step.from.name,
+                    step.from.variant,
+                    step.to.name,
+                    step.to.variant
+                )));
+            }
+            steps.push(ReductionStep {
+                name: step.to.name.clone(),
+                variant: step.to.variant.clone(),
+            });
+        }
+        Ok(Self { steps })
+    }
+}
+
 impl std::fmt::Display for ReductionPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut prev_name = "";
@@ -271,6 +336,83 @@ pub struct NeighborTree {
     pub children: Vec<NeighborTree>,
 }
 
+/// A variant node belonging to a [`ReductionCluster`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ClusterMember {
+    /// Problem name.
+    pub name: &'static str,
+    /// Variant attributes.
+    pub variant: BTreeMap<String, String>,
+}
+
+/// A strongly connected component of the reduction graph: every member
+/// reduces to every other member, so they are computationally equivalent.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReductionCluster {
+    /// Members sorted by name and variant.
+    pub members: Vec<ClusterMember>,
+}
+
+impl ReductionCluster {
+    /// Whether the cluster holds more than one variant node.
+    pub fn is_nontrivial(&self) -> bool {
+        self.members.len() > 1
+    }
+
+    /// Whether the cluster contains any variant of the named problem.
+    pub fn contains_name(&self, name: &str) -> bool {
+        self.members.iter().any(|m| m.name == name)
+    }
+}
+
+/// The condensation of the reduction graph, returned by
+/// [`ReductionGraph::condensation`].
+///
+/// Each strongly connected component is contracted to a single cluster; the
+/// clusters form a DAG.
+#[derive(Debug, Clone, Serialize)]
+pub struct CondensedGraph {
+    /// Clusters in topological order: every edge goes from a lower to a
+    /// higher index, so upstream (harder-to-reach-from) clusters come first.
+    pub clusters: Vec<ReductionCluster>,
+    /// Deduplicated `(source, target)` cluster indices, sorted.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl CondensedGraph {
+    /// Index of the cluster containing the given variant node.
+    pub fn cluster_of(&self, name: &str, variant: &BTreeMap<String, String>) -> Option<usize> {
+        self.clusters.iter().position(|cluster| {
+            cluster
+                .members
+                .iter()
+                .any(|m| m.name == name && m.variant == *variant)
+        })
+    }
+
+    /// Whether cluster `to` is reachable from cluster `from` along DAG edges.
+    /// A cluster always reaches itself.
+    pub fn reaches(&self, from: usize, to: usize) -> bool {
+        let mut visited = vec![false; self.clusters.len()];
+        let mut stack = vec![from];
+        while let Some(cluster) = stack.pop() {
+            if cluster == to {
+                return true;
+            }
+            if std::mem::replace(&mut visited[cluster], true) {
+                continue;
+            }
+            stack.extend(
+                self.edges
+                    .iter()
+                    .filter(|&&(src, _)| src == cluster)
+                    .map(|&(_, dst)| dst),
+            );
+        }
+        false
+    }
+}
+
 /// Runtime graph of all registered reductions.
 ///
 /// Uses variant-level nodes: each node is a unique `(problem_name, variant)` pair.
@@ -636,7 +778,8 @@ impl ReductionGraph {
     }
 
     /// Like [`find_all_paths_mode`](Self::find_all_paths_mode) but stops
-    /// enumeration after collecting `limit` paths.
+    /// enumeration after collecting `limit` paths. Only paths that support
+    /// `mode` count towards the limit.
     pub fn find_paths_up_to_mode(
         &self,
         source: &str,
@@ -684,12 +827,12 @@ impl ReductionGraph {
             _,
             std::hash::RandomState,
         >(&self.graph, src, dst, 0, max_intermediate_nodes)
+        .filter(|p| self.node_path_supports_mode(p, mode))
         .take(limit)
         .collect();
 
         paths
             .iter()
-            .filter(|p| self.node_path_supports_mode(p, mode))
             .map(|p| self.node_path_to_reduction_path(p))
             .collect()
     }
@@ -919,16 +1062,14 @@ impl ReductionGraph {
             crate::registry::declared_size_fields(name)
                 .into_iter()
                 .collect();
-        for entry in inventory::iter::<ReductionEntry> {
-            if entry.source_name == name {
-                // Source's size fields are the input variables of the overhead.
-                fields.extend(entry.overhead().input_variable_names());
-            }
-            if entry.target_name == name {
-                // Target's size fields are the output field names.
-                let overhead = entry.overhead();
-                fields.extend(overhead.output_size.iter().map(|(name, _)| *name));
-            }
+        for entry in reductions_from(name) {
+            // Source's size fields are the input variables of the overhead.
+            fields.extend(entry.overhead().input_variable_names());
+        }
+        for entry in reductions_to(name) {
+            // Target's size fields are the output field names.
+            let overhead = entry.overhead();
+            fields.extend(overhead.output_size.iter().map(|(name, _)| *name));
         }
         let mut result: Vec<&'static str> = fields.into_iter().collect();
         result.sort_unstable();
@@ -966,16 +1107,14 @@ impl ReductionGraph {
         let mut merged: Vec<(String, usize)> = Vec::new();
         let mut seen: HashSet<String> = HashSet::new();
 
-        for entry in inventory::iter::<ReductionEntry> {
-            if entry.source_name == name {
-                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                    (entry.source_size_fn)(instance)
-                }));
-                if let Ok(size) = result {
-                    for (k, v) in size.components {
-                        if seen.insert(k.clone()) {
-                            merged.push((k, v));
-                        }
+        for entry in reductions_from(name) {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                (entry.source_size_fn)(instance)
+            }));
+            if let Ok(size) = result {
+                for (k, v) in size.components {
+                    if seen.insert(k.clone()) {
+                        merged.push((k, v));
                     }
                 }
             }
@@ -983,6 +1122,32 @@ impl ReductionGraph {
         ProblemSize { components: merged }
     }
 
+    /// Compute the source problem's size, consulting only reductions whose
+    /// source variant matches `variant`.
+    ///
+    /// Unlike [`Self::compute_source_size`], this never hands the instance to a
+    /// getter compiled for another variant, so no downcast can fail.
+    pub fn compute_source_size_for_variant(
+        name: &str,
+        variant: &BTreeMap<String, String>,
+        instance: &dyn Any,
+    ) -> ProblemSize {
+        let mut merged: Vec<(String, usize)> = Vec::new();
+        let mut seen: HashSet<String> = HashSet::new();
+
+        for entry in reductions_from(name) {
+            if Self::variant_to_map(&entry.source_variant()) != *variant {
+                continue;
+            }
+            for (k, v) in (entry.source_size_fn)(instance).components {
+                if seen.insert(k.clone()) {
+                    merged.push((k, v));
+                }
+            }
+        }
+        ProblemSize { components: merged }
+    }
+
     /// Get all incoming reductions to a problem (across all its variants).
     pub fn incoming_reductions(&self, name: &str) -> Vec<ReductionEdgeInfo> {
         let Some(indices) = self.name_to_nodes.get(name) else {
@@ -1150,6 +1315,88 @@ impl ReductionGraph {
             })
             .unwrap_or_default()
     }
+
+    /// Contract strongly connected components into clusters of mutually
+    /// reducible variant nodes (e.g. MaximumIndependentSet and
+    /// MinimumVertexCover) and return the DAG between them.
+    ///
+    /// All registered edges count, regardless of witness/aggregate/Turing
+    /// capability. Clusters are ordered topologically, breaking ties by their
+    /// smallest member so the output is deterministic.
+    pub fn condensation(&self) -> CondensedGraph {
+        let sccs = petgraph::algo::tarjan_scc(&self.graph);
+        let mut component = vec![0; self.graph.node_count()];
+        let mut clusters: Vec<ReductionCluster> = sccs
+            .iter()
+            .enumerate()
+            .map(|(c, scc)| {
+                let mut members: Vec<ClusterMember> = scc
+                    .iter()
+                    .map(|&idx| {
+                        component[idx.index()] = c;
+                        let node = &self.nodes[self.graph[idx]];
+                        ClusterMember {
+                            name: node.name,
+                            variant: node.variant.clone(),
+                        }
+                    })
+                    .collect();
+                members.sort();
+                ReductionCluster { members }
+            })
+            .collect();
+
+        let mut dag: HashSet<(usize, usize)> = HashSet::new();
+        for edge in self.graph.edge_references() {
+            let (src, dst) = (
+                component[edge.source().index()],
+                component[edge.target().index()],
+            );
+            if src != dst {
+                dag.insert((src, dst));
+            }
+        }
+
+        // Kahn's algorithm, always emitting the ready cluster with the
+        // smallest first member.
+        let mut in_degree = vec![0usize; clusters.len()];
+        for &(_, dst) in &dag {
+            in_degree[dst] += 1;
+        }
+        let mut ready: BinaryHeap<Reverse<(&ClusterMember, usize)>> = (0..clusters.len())
+            .filter(|&c| in_degree[c] == 0)
+            .map(|c| Reverse((&clusters[c].members[0], c)))
+            .collect();
+        let mut order = Vec::with_capacity(clusters.len());
+        while let Some(Reverse((_, c))) = ready.pop() {
+            order.push(c);
+            for &(src, dst) in &dag {
+                if src == c {
+                    in_degree[dst] -= 1;
+                    if in_degree[dst] == 0 {
+                        ready.push(Reverse((&clusters[dst].members[0], dst)));
+                    }
+                }
+            }
+        }
+
+        let mut position = vec![0; clusters.len()];
+        for (pos, &c) in order.iter().enumerate() {
+            position[c] = pos;
+        }
+        let mut edges: Vec<(usize, usize)> = dag
+            .into_iter()
+            .map(|(src, dst)| (position[src], position[dst]))
+            .collect();
+        edges.sort_unstable();
+
+        let mut slots: Vec<Option<ReductionCluster>> = clusters.drain(..).map(Some).collect();
+        let clusters = order
+            .iter()
+            .map(|&c| slots[c].take().expect("each cluster is emitted once"))
+            .collect();
+        CondensedGraph { clusters, edges }
+    }
 }
 
 impl Default for ReductionGraph {
@@ -1270,8 +1517,8 @@ impl ReductionGraph {
         src_variant: &BTreeMap<String, String>,
         dst_variant: &BTreeMap<String, String>,
     ) -> String {
-        for entry in inventory::iter::<ReductionEntry> {
-            if entry.source_name == src_name && entry.target_name == dst_name {
+        for entry in reductions_from(src_name) {
+            if entry.target_name == dst_name {
                 let entry_src = Self::variant_to_map(&entry.source_variant());
                 let entry_dst = Self::variant_to_map(&entry.target_variant());
                 if &entry_src == src_variant && &entry_dst == dst_variant {
@@ -1341,8 +1588,8 @@ impl ReductionGraph {
         target_name: &str,
         target_variant: &BTreeMap<String, String>,
     ) -> Option<MatchedEntry> {
-        for entry in inventory::iter::<ReductionEntry> {
-            if entry.source_name != source_name || entry.target_name != target_name {
+        for entry in reductions_from(source_name) {
+            if entry.target_name != target_name {
                 continue;
             }
 
@@ -1569,6 +1816,10 @@ mod tests;
 #[path = "../unit_tests/rules/reduction_path_parity.rs"]
 mod reduction_path_parity_tests;
 
+#[cfg(test)]
+#[path = "../unit_tests/rules/golden.rs"]
+mod golden_tests;
+
 #[cfg(all(test, feature = "ilp-solver"))]
 #[path = "../unit_tests/rules/maximumindependentset_ilp.rs"]
 mod maximumindependentset_ilp_path_tests;
@@ -71,3 +71,23 @@ pub(crate) fn complement_edges(graph: &SimpleGraph) -> Vec<(usize, usize)> {
     }
     edges
 }
+
+/// Build the line graph L(G): one vertex per edge of `graph` (in `edges()`
+/// order), with two vertices adjacent iff the edges share an endpoint.
+pub(crate) fn line_graph<G: Graph>(graph: &G) -> SimpleGraph {
+    let edges = graph.edges();
+    let mut incident = vec![Vec::new(); graph.num_vertices()];
+    for (i, &(u, v)) in edges.iter().enumerate() {
+        incident[u].push(i);
+        incident[v].push(i);
+    }
+    let mut line_edges = Vec::new();
+    for (i, &(u, v)) in edges.iter().enumerate() {
+        for &j in incident[u].iter().chain(&incident[v]) {
+            if j > i {
+                line_edges.push((i, j));
+            }
+        }
+    }
+    SimpleGraph::new(edges.len(), line_edges)
+}
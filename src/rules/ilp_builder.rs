@@ -0,0 +1,212 @@
+//! Fluent builder for the ILPs emitted by `*_ilp` reductions.
+//!
+//! [`IlpBuilder`] allocates variables in named blocks, validates every
+//! constraint term against the allocated range (panicking with the
+//! constraint's name), and fixes variables with a single equality row so
+//! all reductions encode fixed values the same way.
+
+use crate::models::algebraic::{LinearConstraint, ObjectiveSense, VariableDomain, ILP};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// A contiguous block of ILP variables returned by [`IlpBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VarBlock {
+    start: usize,
+    len: usize,
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+impl VarBlock {
+    /// ILP index of the `i`-th variable in the block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is outside the block.
+    pub fn at(&self, i: usize) -> usize {
+        assert!(
+            i < self.len,
+            "variable {i} out of range for a block of {}",
+            self.len
+        );
+        self.start + i
+    }
+
+    /// Number of variables in the block.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the block is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// ILP indices of all variables in the block.
+    pub fn indices(&self) -> std::ops::Range<usize> {
+        self.start..self.start + self.len
+    }
+}
+
+/// Incrementally assembles an [`ILP`].
+///
+/// ```text
+/// let mut b = IlpBuilder::<bool>::new();
+/// let x = b.binary_vars(n);
+/// for e in 0..m {
+///     b.add_le(format_args!("element[{e}]"), terms(e), 1.0);
+/// }
+/// b.maximize(weights);
+/// let ilp = b.build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct IlpBuilder<V: VariableDomain = bool> {
+    num_vars: usize,
+    constraints: Vec<LinearConstraint>,
+    objective: Vec<(usize, f64)>,
+    sense: ObjectiveSense,
+    _marker: PhantomData<V>,
+}
+
+impl<V: VariableDomain> Default for IlpBuilder<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+impl<V: VariableDomain> IlpBuilder<V> {
+    /// Create a builder with no variables, no constraints, and an empty
+    /// minimization objective.
+    pub fn new() -> Self {
+        Self {
+            num_vars: 0,
+            constraints: Vec::new(),
+            objective: Vec::new(),
+            sense: ObjectiveSense::Minimize,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Allocate `n` fresh variables.
+    fn alloc(&mut self, n: usize) -> VarBlock {
+        let block = VarBlock {
+            start: self.num_vars,
+            len: n,
+        };
+        self.num_vars += n;
+        block
+    }
+
+    /// Number of variables allocated so far.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Number of constraints added so far.
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    fn check_terms(&self, what: impl Display, terms: &[(usize, f64)]) {
+        for &(var, coef) in terms {
+            assert!(
+                var < self.num_vars,
+                "{what}: variable {var} out of range ({} variables allocated)",
+                self.num_vars
+            );
+            assert!(
+                coef.is_finite(),
+                "{what}: coefficient of variable {var} is {coef}"
+            );
+        }
+    }
+
+    /// Add a constraint, checking its terms against the allocated variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics, naming the constraint, if a term refers to an unallocated
+    /// variable or has a non-finite coefficient.
+    pub fn add(&mut self, name: impl Display, constraint: LinearConstraint) -> &mut Self {
+        self.check_terms(format_args!("constraint {name}"), &constraint.terms);
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Add `Σ terms ≤ rhs`.
+    pub fn add_le(&mut self, name: impl Display, terms: Vec<(usize, f64)>, rhs: f64) -> &mut Self {
+        self.add(name, LinearConstraint::le(terms, rhs))
+    }
+
+    /// Add `Σ terms ≥ rhs`.
+    pub fn add_ge(&mut self, name: impl Display, terms: Vec<(usize, f64)>, rhs: f64) -> &mut Self {
+        self.add(name, LinearConstraint::ge(terms, rhs))
+    }
+
+    /// Add `Σ terms = rhs`.
+    pub fn add_eq(&mut self, name: impl Display, terms: Vec<(usize, f64)>, rhs: f64) -> &mut Self {
+        self.add(name, LinearConstraint::eq(terms, rhs))
+    }
+
+    /// Fix `var` to `value` with the single-term equality row `var = value`.
+    pub fn fix(&mut self, var: usize, value: f64) -> &mut Self {
+        self.add_eq(format_args!("fix[{var}]"), vec![(var, 1.0)], value)
+    }
+
+    /// Set the objective to maximize `Σ terms`.
+    pub fn maximize(&mut self, terms: Vec<(usize, f64)>) -> &mut Self {
+        self.objective(ObjectiveSense::Maximize, terms)
+    }
+
+    /// Set the objective to minimize `Σ terms`.
+    pub fn minimize(&mut self, terms: Vec<(usize, f64)>) -> &mut Self {
+        self.objective(ObjectiveSense::Minimize, terms)
+    }
+
+    /// Set the objective sense and coefficients.
+    pub fn objective(&mut self, sense: ObjectiveSense, terms: Vec<(usize, f64)>) -> &mut Self {
+        self.check_terms("objective", &terms);
+        self.sense = sense;
+        self.objective = terms;
+        self
+    }
+
+    /// Finish building the ILP.
+    pub fn build(self) -> ILP<V> {
+        ILP::new(self.num_vars, self.constraints, self.objective, self.sense)
+    }
+}
+
+impl IlpBuilder<bool> {
+    /// Allocate `n` binary variables.
+    pub fn binary_vars(&mut self, n: usize) -> VarBlock {
+        self.alloc(n)
+    }
+}
+
+#[cfg_attr(not(test), allow(dead_code))]
+impl IlpBuilder<i32> {
+    /// Allocate `n` non-negative integer variables.
+    pub fn int_vars(&mut self, n: usize) -> VarBlock {
+        self.alloc(n)
+    }
+}
+
+/// Objective terms `(i, w_i)` for a weight slice indexed from variable 0.
+pub fn weighted_terms<W: Copy + Into<f64>>(weights: &[W]) -> Vec<(usize, f64)> {
+    weights
+        .iter()
+        .enumerate()
+        .map(|(i, &w)| (i, w.into()))
+        .collect()
+}
+
+/// Unit-coefficient terms over the given variables.
+pub fn unit_terms(vars: impl IntoIterator<Item = usize>) -> Vec<(usize, f64)> {
+    vars.into_iter().map(|v| (v, 1.0)).collect()
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/ilp_builder.rs"]
+mod tests;
@@ -4,7 +4,7 @@
 //! to infer per-variable upper bounds, then encodes each integer variable into
 //! ceil(log2(U+1)) binary variables using truncated binary encoding (Karimi & Rosenberg 2017).
 
-use crate::models::algebraic::{Comparison, LinearConstraint, ILP};
+use crate::models::algebraic::{LinearConstraint, ILP};
 use crate::reduction;
 use crate::rules::traits::{ReduceTo, ReductionResult};
 
@@ -67,13 +67,15 @@ fn fbbt(num_vars: usize, constraints: &[LinearConstraint]) -> Result<Vec<i64>, F
                 }
             }
 
-            let rhs = c.rhs as i64;
+            // Upper bound from Le/Eq/Range, lower bound from Ge/Eq/Range
+            let le_rhs = c.upper_bound().map(|b| b as i64);
+            let ge_rhs = c.lower_bound().map(|b| b as i64);
 
             // Infeasibility checks
-            if matches!(c.cmp, Comparison::Le | Comparison::Eq) && act_min_finite && act_min > rhs {
+            if le_rhs.is_some_and(|rhs| act_min_finite && act_min > rhs) {
                 return Err(FbbtError::Infeasible);
             }
-            if matches!(c.cmp, Comparison::Ge | Comparison::Eq) && act_max_finite && act_max < rhs {
+            if ge_rhs.is_some_and(|rhs| act_max_finite && act_max < rhs) {
                 return Err(FbbtError::Infeasible);
             }
 
@@ -84,8 +86,8 @@ fn fbbt(num_vars: usize, constraints: &[LinearConstraint]) -> Result<Vec<i64>, F
                     continue;
                 }
 
-                // From Le or Eq: upper bound tightening for positive coef, lower bound for negative
-                if matches!(c.cmp, Comparison::Le | Comparison::Eq) {
+                // From Le, Eq or Range: upper bound tightening for positive coef, lower bound for negative
+                if let Some(rhs) = le_rhs {
                     // Compute residual min = act_min - this variable's min contribution
                     let my_min = if coef_i > 0 {
                         coef_i.saturating_mul(lower[var])
@@ -125,8 +127,8 @@ fn fbbt(num_vars: usize, constraints: &[LinearConstraint]) -> Result<Vec<i64>, F
                     }
                 }
 
-                // From Ge or Eq: lower bound tightening for positive coef, upper for negative
-                if matches!(c.cmp, Comparison::Ge | Comparison::Eq) {
+                // From Ge, Eq or Range: lower bound tightening for positive coef, upper for negative
+                if let Some(rhs) = ge_rhs {
                     let my_max = if coef_i > 0 {
                         if upper[var] >= INF {
                             continue;
@@ -331,7 +333,7 @@ impl ReduceTo<ILP<bool>> for ILP<i32> {
                         new_terms.push((enc.start + j, coef * w as f64));
                     }
                 }
-                LinearConstraint::new(new_terms, c.cmp, c.rhs)
+                c.with_terms(new_terms)
             })
             .collect();
 
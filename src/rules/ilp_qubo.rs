@@ -1,13 +1,26 @@
 //! Reduction from binary ILP to QUBO.
 //!
-//! Binary ILP: optimize c^T x s.t. Ax {<=,>=,=} b, x ∈ {0,1}^n.
+//! Binary ILP: optimize c^T x s.t. Ax {<=,>=,=} b (or lo <= Ax <= b), x ∈ {0,1}^n.
 //!
 //! Formulation (following qubogen):
 //! 1. Normalize constraints to Ax = b by adding slack variables
 //! 2. QUBO = -diag(c + 2·P·b·A) + P·A^T·A
 //!
 //! For Minimize sense, c is negated (convert to maximization).
-//! Slack variables: ceil(log2(slack_range)) bits per inequality constraint.
+//! Slack variables: ceil(log2(slack_range / δ)) bits per inequality constraint,
+//! stepping by the grid resolution δ of the constraint data; ranged
+//! constraints cap the last bit so the slack cannot exceed hi - lo.
+//!
+//! The penalty P defaults to (Σ|c_j| + 1) / δ², which exceeds the objective
+//! range divided by the smallest possible violation δ², so the QUBO optimum
+//! is feasible whenever the ILP is. Use [`ReductionILPToQUBO::with_penalty`]
+//! to override it.
+//!
+//! δ is the coarsest of `1, 0.1, ..., 1e-6` on which all constraint data lies
+//! exactly. Data off every such grid (e.g. `1/3`) has no safe penalty: the
+//! reduction then falls back to unit slack steps, capped so the slack never
+//! exceeds its range, and P = Σ|c_j| + 1. The QUBO optimum may be infeasible
+//! in that case, which [`ReductionILPToQUBO::guarantees_feasibility`] reports.
 
 use crate::models::algebraic::{Comparison, ObjectiveSense, ILP, QUBO};
 use crate::reduction;
@@ -18,6 +31,49 @@ use crate::rules::traits::{ReduceTo, ReductionResult};
 pub struct ReductionILPToQUBO {
     target: QUBO<f64>,
     num_original_vars: usize,
+    penalty: f64,
+    guarantees_feasibility: bool,
+}
+
+impl ReductionILPToQUBO {
+    /// Reduce with an explicit penalty weight instead of [`Self::auto_penalty`].
+    ///
+    /// Penalties at or below the automatic value may let an infeasible
+    /// assignment become the QUBO optimum; see [`Self::guarantees_feasibility`].
+    pub fn with_penalty(ilp: &ILP<bool>, penalty: f64) -> Self {
+        let encoding = SlackEncoding::new(ilp);
+        encoding.into_reduction(penalty)
+    }
+
+    /// Smallest penalty weight guaranteed to keep the QUBO optimum feasible.
+    ///
+    /// Any two assignments differ in objective by at most `Σ|c_j|`. All
+    /// constraint data lies on a grid of resolution `δ` (detected as the
+    /// coarsest of `1, 0.1, ..., 1e-6`), and slack bits step by `δ`, so a
+    /// violated constraint contributes at least `δ²` to the penalty sum.
+    /// Hence `P = (Σ|c_j| + 1) / δ²` makes every infeasible assignment worse
+    /// than every feasible one.
+    ///
+    /// If the constraint data lies on none of these grids, this is the
+    /// fallback `Σ|c_j| + 1`, which carries no such guarantee.
+    pub fn auto_penalty(ilp: &ILP<bool>) -> f64 {
+        SlackEncoding::new(ilp).auto_penalty()
+    }
+
+    /// Penalty weight used to build the QUBO.
+    pub fn penalty(&self) -> f64 {
+        self.penalty
+    }
+
+    /// Whether every QUBO optimum is feasible whenever the ILP is.
+    ///
+    /// This holds when the constraint data lies on a grid of resolution `δ`
+    /// and `P · δ² > Σ|c_j|`, so always for [`Self::auto_penalty`] on such
+    /// data. When it is `false`, check the extracted solution with
+    /// [`ILP::is_feasible`] or reduce with a larger explicit penalty.
+    pub fn guarantees_feasibility(&self) -> bool {
+        self.guarantees_feasibility
+    }
 }
 
 impl ReductionResult for ReductionILPToQUBO {
@@ -41,91 +97,150 @@ impl ReduceTo<QUBO<f64>> for ILP<bool> {
     type Result = ReductionILPToQUBO;
 
     fn reduce_to(&self) -> Self::Result {
-        let n = self.num_vars;
+        let encoding = SlackEncoding::new(self);
+        let penalty = encoding.auto_penalty();
+        encoding.into_reduction(penalty)
+    }
+}
+
+/// Equality form `A'x' = b` of a binary ILP with slack columns appended,
+/// together with the maximization cost vector.
+struct SlackEncoding {
+    num_original_vars: usize,
+    /// Extended constraint matrix, one dense row of length `nq` per constraint.
+    a_ext: Vec<Vec<f64>>,
+    b_vec: Vec<f64>,
+    /// Cost vector of length `nq` (negated for minimization, zero on slack).
+    c_vec: Vec<f64>,
+    /// Grid resolution of the constraint data, or `None` if it lies on no grid.
+    resolution: Option<f64>,
+}
+
+impl SlackEncoding {
+    fn new(ilp: &ILP<bool>) -> Self {
+        let n = ilp.num_vars;
 
         // All variables are binary by type — no runtime check needed.
 
         // Build dense constraint matrix A and rhs vector b
-        // Also compute slack sizes for inequality constraints
-        let num_constraints = self.constraints.len();
+        let num_constraints = ilp.constraints.len();
         let mut a_dense = vec![vec![0.0; n]; num_constraints];
         let mut b_vec = vec![0.0; num_constraints];
-        let mut slack_sizes = vec![0usize; num_constraints];
-
-        for (k, constraint) in self.constraints.iter().enumerate() {
+        for (k, constraint) in ilp.constraints.iter().enumerate() {
             for &(var, coef) in &constraint.terms {
                 a_dense[k][var] += coef;
             }
             b_vec[k] = constraint.rhs;
+        }
+        let resolution = grid_resolution(
+            a_dense.iter().flatten().copied().chain(
+                ilp.constraints
+                    .iter()
+                    .flat_map(|c| std::iter::once(c.rhs).chain(c.lower_bound())),
+            ),
+        );
 
-            // Compute slack variable count: ceil(log2(slack_range + 1)) bits
-            // to represent integer values 0..slack_range with binary encoding.
+        // Off-grid data falls back to unit slack steps. Rounding could then
+        // let a ranged slack exceed hi - lo, so the step count is floored.
+        let step = resolution.unwrap_or(1.0);
+        let steps = |range: f64| match resolution {
+            Some(_) => (range / step).round(),
+            None => (range / step).floor(),
+        };
+
+        // Compute signed slack column coefficients for inequality constraints
+        let mut slack_coefs: Vec<Vec<f64>> = vec![vec![]; num_constraints];
+        for (k, constraint) in ilp.constraints.iter().enumerate() {
+            // Slack steps by `step`, with ceil(log2(steps + 1)) bits
+            // to represent 0..=steps with binary encoding.
             // For binary variables, min_lhs = Σ min(0, a_i), max_lhs = Σ max(0, a_i).
-            match constraint.cmp {
-                Comparison::Eq => {} // no slack needed
-                Comparison::Le => {
-                    // Ax <= b → Ax + s = b, s ∈ {0, ..., b - min_lhs}
-                    let min_lhs: f64 = a_dense[k].iter().map(|&c| c.min(0.0)).sum();
-                    let slack_range = constraint.rhs - min_lhs;
-                    if slack_range > 0.0 {
-                        slack_sizes[k] = (slack_range + 1.0).log2().ceil() as usize;
-                    }
-                }
-                Comparison::Ge => {
-                    // Ax >= b → Ax - s = b, s ∈ {0, ..., max_lhs - b}
-                    let max_lhs: f64 = a_dense[k].iter().map(|&c| c.max(0.0)).sum();
-                    let slack_range = max_lhs - constraint.rhs;
-                    if slack_range > 0.0 {
-                        slack_sizes[k] = (slack_range + 1.0).log2().ceil() as usize;
-                    }
+            let min_lhs: f64 = a_dense[k].iter().map(|&c| c.min(0.0)).sum();
+            let max_lhs: f64 = a_dense[k].iter().map(|&c| c.max(0.0)).sum();
+            slack_coefs[k] = match constraint.cmp {
+                Comparison::Eq => vec![], // no slack needed
+                // Ax <= b → Ax + s = b, s ∈ {0, ..., b - min_lhs}
+                Comparison::Le => power_of_two_slack(steps(constraint.rhs - min_lhs), step),
+                // Ax >= b → Ax - s = b, s ∈ {0, ..., max_lhs - b}
+                Comparison::Ge => power_of_two_slack(steps(max_lhs - constraint.rhs), -step),
+                // lo <= Ax <= hi → Ax - s = lo, s ∈ {0, ..., min(hi, max_lhs) - lo}.
+                // The slack must not exceed hi - lo, so its last bit is capped.
+                Comparison::Range => {
+                    let lo = constraint
+                        .lower_bound()
+                        .expect("ranged rows have a lower bound");
+                    b_vec[k] = lo;
+                    capped_slack(steps(constraint.rhs.min(max_lhs) - lo))
+                        .into_iter()
+                        .map(|w| -w * step)
+                        .collect()
                 }
-            }
+            };
         }
 
-        let total_slack: usize = slack_sizes.iter().sum();
+        let total_slack: usize = slack_coefs.iter().map(Vec::len).sum();
         let nq = n + total_slack;
 
         // Extend A with slack columns
         let mut a_ext = vec![vec![0.0; nq]; num_constraints];
         for k in 0..num_constraints {
-            for j in 0..n {
-                a_ext[k][j] = a_dense[k][j];
-            }
+            a_ext[k][..n].copy_from_slice(&a_dense[k]);
         }
 
         // Add slack variable columns
         let mut slack_col = n;
-        for (k, &ns) in slack_sizes.iter().enumerate() {
-            if ns > 0 {
-                let sign = match self.constraints[k].cmp {
-                    Comparison::Le => 1.0,  // Ax + s = b
-                    Comparison::Ge => -1.0, // Ax - s = b
-                    Comparison::Eq => 0.0,
-                };
-                for s in 0..ns {
-                    a_ext[k][slack_col + s] = sign * 2.0_f64.powi(s as i32);
-                }
-                slack_col += ns;
+        for (k, coefs) in slack_coefs.iter().enumerate() {
+            for (s, &coef) in coefs.iter().enumerate() {
+                a_ext[k][slack_col + s] = coef;
             }
+            slack_col += coefs.len();
         }
 
         // Build dense cost vector (nq elements)
         let mut c_vec = vec![0.0; nq];
-        for &(var, coef) in &self.objective {
+        for &(var, coef) in &ilp.objective {
             c_vec[var] = coef;
         }
 
         // For Minimize sense, negate the cost (formula assumes maximization)
-        if self.sense == ObjectiveSense::Minimize {
+        if ilp.sense == ObjectiveSense::Minimize {
             for c in c_vec.iter_mut() {
                 *c = -*c;
             }
         }
 
-        // Penalty: must be large enough to enforce constraints
-        let penalty = 1.0
-            + c_vec.iter().map(|c| c.abs()).sum::<f64>()
-            + b_vec.iter().map(|b| b.abs()).sum::<f64>();
+        Self {
+            num_original_vars: n,
+            a_ext,
+            b_vec,
+            c_vec,
+            resolution,
+        }
+    }
+
+    /// Largest change `Σ|c_j|` of the objective between two assignments.
+    fn objective_range(&self) -> f64 {
+        self.c_vec.iter().map(|c| c.abs()).sum()
+    }
+
+    /// `(Σ|c_j| + 1) / δ²`, with `δ = 1` off-grid; see
+    /// [`ReductionILPToQUBO::auto_penalty`].
+    fn auto_penalty(&self) -> f64 {
+        let resolution = self.resolution.unwrap_or(1.0);
+        (self.objective_range() + 1.0) / (resolution * resolution)
+    }
+
+    fn into_reduction(self, penalty: f64) -> ReductionILPToQUBO {
+        let nq = self.c_vec.len();
+        let guarantees_feasibility = self
+            .resolution
+            .is_some_and(|resolution| penalty * resolution * resolution > self.objective_range());
+        let Self {
+            num_original_vars,
+            a_ext,
+            b_vec,
+            c_vec,
+            ..
+        } = self;
 
         // QUBO = -diag(c + 2·P·b·A) + P·A^T·A
         let mut matrix = vec![vec![0.0; nq]; nq];
@@ -162,11 +277,45 @@ impl ReduceTo<QUBO<f64>> for ILP<bool> {
 
         ReductionILPToQUBO {
             target: QUBO::from_matrix(matrix),
-            num_original_vars: n,
+            num_original_vars,
+            penalty,
+            guarantees_feasibility,
         }
     }
 }
 
+/// Coarsest grid `10^-d` (`d ≤ 6`) containing every value, or `None` if the
+/// values lie on no such grid.
+fn grid_resolution(values: impl Iterator<Item = f64> + Clone) -> Option<f64> {
+    (0..=6).map(|d| 10f64.powi(-d)).find(|&step| {
+        values.clone().all(|v| {
+            let scaled = v / step;
+            (scaled - scaled.round()).abs() <= 1e-9 * scaled.abs().max(1.0)
+        })
+    })
+}
+
+/// Slack coefficients `sign · 2^s` covering `0..=steps`.
+fn power_of_two_slack(steps: f64, sign: f64) -> Vec<f64> {
+    if steps <= 0.0 {
+        return vec![];
+    }
+    let bits = (steps + 1.0).log2().ceil() as usize;
+    (0..bits).map(|s| sign * 2.0_f64.powi(s as i32)).collect()
+}
+
+/// Slack weights `1, 2, ..., 2^(K-2), steps - (2^(K-1) - 1)` whose subset
+/// sums are exactly `0..=steps`.
+fn capped_slack(steps: f64) -> Vec<f64> {
+    if steps <= 0.0 {
+        return vec![];
+    }
+    let bits = (steps + 1.0).log2().ceil() as usize;
+    let mut weights: Vec<f64> = (0..bits - 1).map(|s| 2.0_f64.powi(s as i32)).collect();
+    weights.push(steps - (2.0_f64.powi(bits as i32 - 1) - 1.0));
+    weights
+}
+
 #[cfg(feature = "example-db")]
 pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
     use crate::export::SolutionPair;
@@ -0,0 +1,19 @@
+//! Variant cast reductions for KCenter.
+
+use crate::impl_variant_reduction;
+use crate::models::misc::KCenter;
+use crate::variant::{K1, K2, KN};
+
+impl_variant_reduction!(
+    KCenter,
+    <K1> => <KN>,
+    fields: [num_sites, num_centers],
+    |src| KCenter::with_k(src.distances().to_vec(), src.num_centers())
+);
+
+impl_variant_reduction!(
+    KCenter,
+    <K2> => <KN>,
+    fields: [num_sites, num_centers],
+    |src| KCenter::with_k(src.distances().to_vec(), src.num_centers())
+);
@@ -0,0 +1,124 @@
+//! Reduction from KCenter to ILP (Integer Linear Programming).
+//!
+//! The bottleneck objective is encoded with threshold variables over the
+//! sorted distinct distances `0 = r_0 < r_1 < ... < r_{T-1}`:
+//!
+//! Variable layout (all binary):
+//! - `x_j` for each site j (1 if site j is a center), indices `0..n`
+//! - `u_t` for each threshold `t = 1..T-1` (1 if the radius is at least `r_t`),
+//!   index `n + t - 1`
+//!
+//! Constraints:
+//! - Cardinality: Σ_j x_j = K
+//! - Threshold: ∀i, ∀t ≥ 1: Σ_{j : d(i,j) ≤ r_{t-1}} x_j + u_t ≥ 1
+//!   (if no center lies within `r_{t-1}` of site i, the radius exceeds `r_{t-1}`)
+//!
+//! Objective: minimize Σ_t (r_t - r_{t-1}) · u_t, which equals the covering
+//! radius at the optimum because the forced thresholds form a prefix.
+//!
+//! Extraction: first n variables (x_j).
+
+use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::misc::KCenter;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::variant::KN;
+
+/// Result of reducing KCenter to ILP.
+#[derive(Debug, Clone)]
+pub struct ReductionKCenterToILP {
+    target: ILP<bool>,
+    num_sites: usize,
+}
+
+impl ReductionResult for ReductionKCenterToILP {
+    type Source = KCenter<KN>;
+    type Target = ILP<bool>;
+
+    fn target_problem(&self) -> &ILP<bool> {
+        &self.target
+    }
+
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution[..self.num_sites].to_vec()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vars = "num_sites + num_candidate_radii - 1",
+        num_constraints = "1 + num_sites * (num_candidate_radii - 1)",
+    }
+)]
+impl ReduceTo<ILP<bool>> for KCenter<KN> {
+    type Result = ReductionKCenterToILP;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.num_sites();
+        let radii = self.candidate_radii();
+        let distances = self.distances();
+        let u_var = |t: usize| n + t - 1;
+
+        let num_vars = n + radii.len() - 1;
+        let mut constraints = Vec::with_capacity(1 + n * (radii.len() - 1));
+
+        // Cardinality constraint: Σ_j x_j = K
+        let center_terms: Vec<(usize, f64)> = (0..n).map(|j| (j, 1.0)).collect();
+        constraints.push(LinearConstraint::eq(
+            center_terms,
+            self.num_centers() as f64,
+        ));
+
+        // Threshold constraints: ∀i, t: Σ_{d(i,j) ≤ r_{t-1}} x_j + u_t ≥ 1
+        for row in distances {
+            for t in 1..radii.len() {
+                let mut terms: Vec<(usize, f64)> = (0..n)
+                    .filter(|&j| row[j] <= radii[t - 1])
+                    .map(|j| (j, 1.0))
+                    .collect();
+                terms.push((u_var(t), 1.0));
+                constraints.push(LinearConstraint::ge(terms, 1.0));
+            }
+        }
+
+        // Objective: minimize Σ_t (r_t - r_{t-1}) u_t
+        let objective: Vec<(usize, f64)> = (1..radii.len())
+            .map(|t| (u_var(t), (radii[t] - radii[t - 1]) as f64))
+            .collect();
+
+        let target = ILP::new(num_vars, constraints, objective, ObjectiveSense::Minimize);
+        ReductionKCenterToILP {
+            target,
+            num_sites: n,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "kcenter_to_ilp",
+        build: || {
+            // Four sites on a line at positions 0, 1, 2, 3 with K = 2.
+            // Radii 0 < 1 < 2 < 3; centers {1, 2} give radius 1, so only u_1 is set.
+            let positions: [u64; 4] = [0, 1, 2, 3];
+            let distances = positions
+                .iter()
+                .map(|&a| positions.iter().map(|&b| a.abs_diff(b)).collect())
+                .collect();
+            crate::example_db::specs::rule_example_with_witness::<_, ILP<bool>>(
+                KCenter::<KN>::with_k(distances, 2),
+                SolutionPair {
+                    source_config: vec![0, 1, 1, 0],
+                    target_config: vec![0, 1, 1, 0, 1, 0, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/kcenter_ilp.rs"]
+mod tests;
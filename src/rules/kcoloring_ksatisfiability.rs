@@ -0,0 +1,118 @@
+//! Reduction from 3-Coloring to 3-SAT with exactly three literals per clause.
+//!
+//! Variable `x_{v,c}` says vertex `v` gets color `c`. Each vertex gets the
+//! clause `(x_{v,0} ∨ x_{v,1} ∨ x_{v,2})` (at least one color); the
+//! at-most-one-color pairs `(¬x_{v,c} ∨ ¬x_{v,d})` and the edge conflicts
+//! `(¬x_{u,c} ∨ ¬x_{v,c})` have only two literals. Each such pair `(a ∨ b)`
+//! is padded with one shared auxiliary variable `y` into
+//! `(a ∨ b ∨ y) ∧ (a ∨ b ∨ ¬y)`, which holds for either value of `y` iff
+//! `a ∨ b` does. Satisfying assignments are then exactly the proper
+//! 3-colorings, one-hot encoded, with `y` free.
+
+use crate::models::formula::{CNFClause, KSatisfiability};
+use crate::models::graph::KColoring;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+use crate::variant::K3;
+
+/// Number of colors handled by this reduction.
+const NUM_COLORS: usize = 3;
+
+/// Result of reducing 3-Coloring to 3-SAT.
+///
+/// Variable `3 * v + c + 1` (1-indexed) is `x_{v,c}`; the last variable is
+/// the padding variable `y`.
+#[derive(Debug, Clone)]
+pub struct ReductionKColoringToKSAT {
+    target: KSatisfiability<K3>,
+    num_vertices: usize,
+}
+
+impl ReductionResult for ReductionKColoringToKSAT {
+    type Source = KColoring<K3, SimpleGraph>;
+    type Target = KSatisfiability<K3>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Each vertex takes its true color variable; 0 if none is set.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution[..NUM_COLORS * self.num_vertices]
+            .chunks(NUM_COLORS)
+            .map(|colors| colors.iter().position(|&x| x == 1).unwrap_or(0))
+            .collect()
+    }
+}
+
+/// Push the 2-clause `(a ∨ b)` as two 3-clauses over the padding variable.
+fn push_padded(clauses: &mut Vec<CNFClause>, a: i32, b: i32, padding: i32) {
+    clauses.push(CNFClause::new(vec![a, b, padding]));
+    clauses.push(CNFClause::new(vec![a, b, -padding]));
+}
+
+#[reduction(
+    overhead = {
+        num_vars = "3 * num_vertices + 1",
+        num_clauses = "7 * num_vertices + 6 * num_edges",
+    }
+)]
+impl ReduceTo<KSatisfiability<K3>> for KColoring<K3, SimpleGraph> {
+    type Result = ReductionKColoringToKSAT;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.graph().num_vertices();
+        let color = |v: usize, c: usize| (NUM_COLORS * v + c + 1) as i32;
+        let padding = (NUM_COLORS * n + 1) as i32;
+
+        let mut clauses = Vec::new();
+        for v in 0..n {
+            clauses.push(CNFClause::new(
+                (0..NUM_COLORS).map(|c| color(v, c)).collect(),
+            ));
+            for c in 0..NUM_COLORS {
+                for d in (c + 1)..NUM_COLORS {
+                    push_padded(&mut clauses, -color(v, c), -color(v, d), padding);
+                }
+            }
+        }
+        for (u, v) in self.graph().edges() {
+            for c in 0..NUM_COLORS {
+                push_padded(&mut clauses, -color(u, c), -color(v, c), padding);
+            }
+        }
+
+        ReductionKColoringToKSAT {
+            target: KSatisfiability::new(NUM_COLORS * n + 1, clauses),
+            num_vertices: n,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "kcoloring_to_ksatisfiability",
+        build: || {
+            // 5-cycle: odd, so it needs all three colors
+            let source = KColoring::<K3, _>::new(SimpleGraph::new(
+                5,
+                vec![(0, 1), (1, 2), (2, 3), (3, 4), (0, 4)],
+            ));
+            crate::example_db::specs::rule_example_with_witness::<_, KSatisfiability<K3>>(
+                source,
+                SolutionPair {
+                    source_config: vec![0, 1, 0, 1, 2],
+                    target_config: vec![1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/kcoloring_ksatisfiability.rs"]
+mod tests;
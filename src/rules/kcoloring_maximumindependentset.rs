@@ -0,0 +1,109 @@
+//! Reduction from KColoring to MaximumIndependentSet via vertex-color pairs.
+//!
+//! The target graph has one vertex `(v, c)` for every source vertex `v` and
+//! color `c`. The `K` pairs of each source vertex form a clique, so an
+//! independent set picks at most one color per vertex, and `(u, c)` is joined
+//! to `(v, c)` for every source edge `{u, v}`, so adjacent vertices never pick
+//! the same color. G is K-colorable iff the maximum independent set has size
+//! `num_vertices`.
+
+use crate::models::graph::{KColoring, MaximumIndependentSet};
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+use crate::types::One;
+use crate::variant::KN;
+
+/// Result of reducing KColoring to MaximumIndependentSet.
+///
+/// Target vertex `v * num_colors + c` stands for coloring source vertex `v`
+/// with color `c`.
+#[derive(Debug, Clone)]
+pub struct ReductionKColoringToIS {
+    target: MaximumIndependentSet<SimpleGraph, One>,
+    num_colors: usize,
+}
+
+impl ReductionResult for ReductionKColoringToIS {
+    type Source = KColoring<KN, SimpleGraph>;
+    type Target = MaximumIndependentSet<SimpleGraph, One>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Read each vertex's color from its selected pair. Vertices without a
+    /// selected pair, which only occur in non-maximum sets, get color 0.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution
+            .chunks(self.num_colors)
+            .map(|pairs| pairs.iter().position(|&x| x == 1).unwrap_or(0))
+            .collect()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "num_vertices * num_colors",
+        num_edges = "num_vertices * num_colors * (num_colors - 1) / 2 + num_edges * num_colors",
+    }
+)]
+impl ReduceTo<MaximumIndependentSet<SimpleGraph, One>> for KColoring<KN, SimpleGraph> {
+    type Result = ReductionKColoringToIS;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.graph().num_vertices();
+        let k = self.num_colors();
+        let pair = |v: usize, c: usize| v * k + c;
+
+        let mut edges = Vec::new();
+        // Pick one color per vertex.
+        for v in 0..n {
+            for c in 0..k {
+                for d in (c + 1)..k {
+                    edges.push((pair(v, c), pair(v, d)));
+                }
+            }
+        }
+        // Adjacent vertices may not share a color.
+        for (u, v) in self.graph().edges() {
+            for c in 0..k {
+                edges.push((pair(u, c), pair(v, c)));
+            }
+        }
+
+        let target = MaximumIndependentSet::new(SimpleGraph::new(n * k, edges), vec![One; n * k]);
+        ReductionKColoringToIS {
+            target,
+            num_colors: k,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "kcoloring_to_maximumindependentset",
+        build: || {
+            // A triangle is 3-colorable; the target has 3 x 3 vertex-color pairs.
+            let source =
+                KColoring::<KN, _>::with_k(SimpleGraph::new(3, vec![(0, 1), (1, 2), (0, 2)]), 3);
+            crate::example_db::specs::rule_example_with_witness::<
+                _,
+                MaximumIndependentSet<SimpleGraph, One>,
+            >(
+                source,
+                SolutionPair {
+                    source_config: vec![0, 1, 2],
+                    target_config: vec![1, 0, 0, 0, 1, 0, 0, 0, 1],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/kcoloring_maximumindependentset.rs"]
+mod tests;
@@ -0,0 +1,180 @@
+//! Reductions between KColoring and MinimumCliqueCover via complement graphs.
+//!
+//! Color classes of G are independent sets, i.e. cliques of the complement
+//! graph, so the clique cover number of the complement equals the chromatic
+//! number of G. G is K-colorable iff the minimum clique cover of the
+//! complement uses at most K cliques.
+//!
+//! In the other direction, a clique cover of G is a coloring of its
+//! complement. A single coloring query only preserves feasibility, so
+//! MinimumCliqueCover → KColoring is registered as a Turing edge: the clique
+//! cover number is the least K for which the complement is K-colorable.
+
+use crate::expr::Expr;
+use crate::models::graph::{KColoring, MinimumCliqueCover};
+use crate::reduction;
+use crate::rules::graph_helpers::complement_edges;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+use crate::traits::Problem;
+use crate::variant::KN;
+use std::collections::HashMap;
+
+/// Result of reducing KColoring to MinimumCliqueCover.
+#[derive(Debug, Clone)]
+pub struct ReductionKColoringToMinimumCliqueCover {
+    target: MinimumCliqueCover<SimpleGraph>,
+}
+
+impl ReductionResult for ReductionKColoringToMinimumCliqueCover {
+    type Source = KColoring<KN, SimpleGraph>;
+    type Target = MinimumCliqueCover<SimpleGraph>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Relabel clique groups as colors `0, 1, ...` in order of first
+    /// appearance, so a cover with at most K cliques yields colors below K.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let mut colors = HashMap::new();
+        target_solution
+            .iter()
+            .map(|&group| {
+                let next = colors.len();
+                *colors.entry(group).or_insert(next)
+            })
+            .collect()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "num_vertices",
+        num_edges = "num_vertices * (num_vertices - 1) / 2 - num_edges",
+    }
+)]
+impl ReduceTo<MinimumCliqueCover<SimpleGraph>> for KColoring<KN, SimpleGraph> {
+    type Result = ReductionKColoringToMinimumCliqueCover;
+
+    fn reduce_to(&self) -> Self::Result {
+        let target = MinimumCliqueCover::new(SimpleGraph::new(
+            self.graph().num_vertices(),
+            complement_edges(self.graph()),
+        ));
+        ReductionKColoringToMinimumCliqueCover { target }
+    }
+}
+
+/// Result of reducing MinimumCliqueCover to KColoring of the complement graph.
+#[derive(Debug, Clone)]
+pub struct ReductionMinimumCliqueCoverToKColoring {
+    target: KColoring<KN, SimpleGraph>,
+}
+
+impl ReductionMinimumCliqueCoverToKColoring {
+    /// Ask whether `source` can be covered by at most `num_cliques` cliques.
+    ///
+    /// This is one query of the Turing reduction; the minimum is the least
+    /// `num_cliques` whose target is satisfiable.
+    pub fn with_num_cliques(source: &MinimumCliqueCover<SimpleGraph>, num_cliques: usize) -> Self {
+        let target = KColoring::<KN, _>::with_k(
+            SimpleGraph::new(source.num_vertices(), complement_edges(source.graph())),
+            num_cliques,
+        );
+        Self { target }
+    }
+}
+
+impl ReductionResult for ReductionMinimumCliqueCoverToKColoring {
+    type Source = MinimumCliqueCover<SimpleGraph>;
+    type Target = KColoring<KN, SimpleGraph>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Each color class is a clique of the source, so colors are group ids.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+/// Uses `K = num_vertices`, which every instance satisfies, so the extracted
+/// cover is valid but not necessarily minimum; see
+/// [`ReductionMinimumCliqueCoverToKColoring::with_num_cliques`].
+impl ReduceTo<KColoring<KN, SimpleGraph>> for MinimumCliqueCover<SimpleGraph> {
+    type Result = ReductionMinimumCliqueCoverToKColoring;
+
+    fn reduce_to(&self) -> Self::Result {
+        ReductionMinimumCliqueCoverToKColoring::with_num_cliques(self, self.num_vertices())
+    }
+}
+
+// MinimumCliqueCover → KColoring (Turing: search over K)
+inventory::submit! {
+    crate::rules::ReductionEntry {
+        source_name: "MinimumCliqueCover",
+        target_name: "KColoring",
+        source_variant_fn: <MinimumCliqueCover<SimpleGraph> as Problem>::variant,
+        target_variant_fn: <KColoring<KN, SimpleGraph> as Problem>::variant,
+        overhead_fn: || {
+            let n = Expr::Var("num_vertices");
+            let pairs = n.clone() * (n.clone() - Expr::Const(1.0)) / Expr::Const(2.0);
+            crate::rules::ReductionOverhead::new(vec![
+                ("num_vertices", n),
+                ("num_edges", pairs - Expr::Var("num_edges")),
+            ])
+        },
+        module_path: module_path!(),
+        reduce_fn: None,
+        reduce_aggregate_fn: None,
+        capabilities: crate::rules::EdgeCapabilities::turing(),
+        overhead_eval_fn: |any| {
+            let source = any
+                .downcast_ref::<MinimumCliqueCover<SimpleGraph>>()
+                .expect("MinimumCliqueCover turing overhead source type mismatch");
+            let n = source.num_vertices();
+            crate::types::ProblemSize::new(vec![
+                ("num_vertices", n),
+                ("num_edges", n * n.saturating_sub(1) / 2 - source.num_edges()),
+            ])
+        },
+        source_size_fn: |any| {
+            let source = any
+                .downcast_ref::<MinimumCliqueCover<SimpleGraph>>()
+                .expect("MinimumCliqueCover turing size source type mismatch");
+            crate::types::ProblemSize::new(vec![
+                ("num_vertices", source.num_vertices()),
+                ("num_edges", source.num_edges()),
+            ])
+        },
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "kcoloring_to_minimumcliquecover",
+        build: || {
+            // The 5-cycle is 3-chromatic, and its complement is again a 5-cycle.
+            let source = KColoring::<KN, _>::with_k(
+                SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]),
+                3,
+            );
+            crate::example_db::specs::rule_example_with_witness::<_, MinimumCliqueCover<SimpleGraph>>(
+                source,
+                SolutionPair {
+                    source_config: vec![0, 1, 0, 1, 2],
+                    target_config: vec![0, 1, 0, 1, 2],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/kcoloring_minimumcliquecover.rs"]
+mod tests;
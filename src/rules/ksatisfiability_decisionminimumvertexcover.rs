@@ -47,7 +47,7 @@ impl ReduceTo<Decision<MinimumVertexCover<SimpleGraph, i32>>> for KSatisfiabilit
         let base_reduction = <KSatisfiability<K3> as ReduceTo<
             MinimumVertexCover<SimpleGraph, i32>,
         >>::reduce_to(self);
-        let bound = i32::try_from(self.num_vars() + 2 * self.num_clauses())
+        let bound = i32::try_from(base_reduction.cover_size_threshold())
             .expect("decision minimum vertex cover bound must fit in i32");
         let target = Decision::new(base_reduction.target_problem().clone(), bound);
 
@@ -6,8 +6,13 @@
 //! literal l_k in clause c_j, add a communication edge from the triangle vertex
 //! j_k to the literal vertex l_k.
 //!
-//! The resulting graph has a vertex cover of size n + 2m if and only if the
-//! 3-SAT formula is satisfiable (n = num_vars, m = num_clauses).
+//! Cover-size threshold: every vertex cover contains at least one endpoint of
+//! each of the n truth-setting edges and at least two vertices of each of the m
+//! triangles, so every cover has size at least n + 2m. A cover of exactly that
+//! size exists if and only if the 3-SAT formula is satisfiable
+//! (n = num_vars, m = num_clauses); for unsatisfiable formulas the minimum
+//! vertex cover is strictly larger. See
+//! [`Reduction3SATToMVC::cover_size_threshold`].
 //!
 //! Reference: Garey & Johnson, "Computers and Intractability", 1979, Theorem 3.3
 
@@ -23,6 +28,18 @@ use crate::variant::K3;
 pub struct Reduction3SATToMVC {
     target: MinimumVertexCover<SimpleGraph, i32>,
     source_num_vars: usize,
+    source_num_clauses: usize,
+}
+
+impl Reduction3SATToMVC {
+    /// The cover size `n + 2m` that is reached exactly when the source formula
+    /// is satisfiable.
+    ///
+    /// No vertex cover of the target graph is smaller than this; the minimum
+    /// cover exceeds it iff the formula is unsatisfiable.
+    pub fn cover_size_threshold(&self) -> usize {
+        self.source_num_vars + 2 * self.source_num_clauses
+    }
 }
 
 impl ReductionResult for Reduction3SATToMVC {
@@ -37,9 +54,10 @@ impl ReductionResult for Reduction3SATToMVC {
     ///
     /// Vertex layout: indices 0..2n are literal vertices (even = positive,
     /// odd = negated). For variable i, vertex 2*i is u_i and vertex 2*i+1
-    /// is not-u_i. Each truth-setting edge forces exactly one of these two
-    /// into any minimum vertex cover. If u_i is in the cover, set x_i = 1;
-    /// if not-u_i is in the cover, set x_i = 0.
+    /// is not-u_i. In a cover of threshold size each truth-setting edge has
+    /// exactly one endpoint in the cover, and the literal vertex *excluded*
+    /// from the cover is the false literal: if u_i is excluded, set x_i = 0;
+    /// otherwise (not-u_i excluded) set x_i = 1.
     fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
         (0..self.source_num_vars)
             .map(|i| {
@@ -104,6 +122,7 @@ impl ReduceTo<MinimumVertexCover<SimpleGraph, i32>> for KSatisfiability<K3> {
         Reduction3SATToMVC {
             target,
             source_num_vars: n,
+            source_num_clauses: m,
         }
     }
 }
@@ -0,0 +1,114 @@
+//! Reduction from KTrackIntervalScheduling to ILP.
+//!
+//! Binary variables x_{i,t} (interval i on track t) at index i * K + t.
+//! Assignment: Σ_t x_{i,t} ≤ 1 for each interval i (rejection leaves it 0).
+//! Track capacity: for each track t and each interval i, the intervals active
+//! at time s(i) form a clique, so Σ_{j : s(j) ≤ s(i) < e(j)} x_{j,t} ≤ 1. Any
+//! two overlapping intervals are both active at the later start time, so these
+//! n rows per track forbid every conflict.
+//! Objective: maximize Σ_{i,t} w(i) · x_{i,t}.
+
+use crate::models::algebraic::ILP;
+use crate::models::misc::KTrackIntervalScheduling;
+use crate::reduction;
+use crate::rules::ilp_builder::{unit_terms, IlpBuilder};
+use crate::rules::traits::{ReduceTo, ReductionResult};
+
+/// Result of reducing KTrackIntervalScheduling to ILP.
+#[derive(Debug, Clone)]
+pub struct ReductionKTISToILP {
+    target: ILP<bool>,
+    num_intervals: usize,
+    num_tracks: usize,
+}
+
+impl ReductionResult for ReductionKTISToILP {
+    type Source = KTrackIntervalScheduling;
+    type Target = ILP<bool>;
+
+    fn target_problem(&self) -> &ILP<bool> {
+        &self.target
+    }
+
+    /// Interval i goes to track t + 1 if x_{i,t} = 1, and is rejected (0) otherwise.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let k = self.num_tracks;
+        (0..self.num_intervals)
+            .map(|i| {
+                (0..k)
+                    .find(|&t| target_solution[i * k + t] == 1)
+                    .map_or(0, |t| t + 1)
+            })
+            .collect()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vars = "num_intervals * num_tracks",
+        num_constraints = "num_intervals + num_intervals * num_tracks",
+    }
+)]
+impl ReduceTo<ILP<bool>> for KTrackIntervalScheduling {
+    type Result = ReductionKTISToILP;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.num_intervals();
+        let k = self.num_tracks();
+        let mut ilp = IlpBuilder::new();
+        let x = ilp.binary_vars(n * k);
+
+        // Σ_t x_{i,t} <= 1
+        for i in 0..n {
+            ilp.add_le(
+                format_args!("assign[{i}]"),
+                unit_terms((0..k).map(|t| x.at(i * k + t))),
+                1.0,
+            );
+        }
+
+        // Σ_{j active at s(i)} x_{j,t} <= 1
+        for t in 0..k {
+            for i in 0..n {
+                let time = self.starts()[i];
+                let active = (0..n)
+                    .filter(|&j| self.starts()[j] <= time && time < self.ends()[j])
+                    .map(|j| x.at(j * k + t));
+                ilp.add_le(format_args!("track[{t},{i}]"), unit_terms(active), 1.0);
+            }
+        }
+
+        ilp.maximize(
+            (0..n)
+                .flat_map(|i| (0..k).map(move |t| (i, t)))
+                .map(|(i, t)| (x.at(i * k + t), self.weights()[i] as f64))
+                .collect(),
+        );
+
+        ReductionKTISToILP {
+            target: ilp.build(),
+            num_intervals: n,
+            num_tracks: k,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "ktrackintervalscheduling_to_ilp",
+        build: || {
+            let source = KTrackIntervalScheduling::new(
+                vec![0, 1, 2, 4, 5, 7],
+                vec![4, 3, 6, 7, 8, 9],
+                vec![3, 2, 4, 2, 3, 1],
+                2,
+            );
+            crate::example_db::specs::rule_example_via_ilp::<_, bool>(source)
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/ktrackintervalscheduling_ilp.rs"]
+mod tests;
@@ -0,0 +1,88 @@
+//! Reduction from MaxBisection to QUBO.
+//!
+//! Uses the penalty-method QUBO
+//! H = -sum_(u,v in E) w_uv (x_u + x_v - 2 x_u x_v) + P (sum_i x_i - n/2)^2
+//! with P = sum_e |w_e| + 1. Any imbalanced partition pays at least P, which
+//! exceeds the largest possible difference in cut weight, so the minimizers
+//! of H are exactly the maximum bisections.
+
+use crate::models::algebraic::QUBO;
+use crate::models::graph::MaxBisection;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::SimpleGraph;
+
+/// Result of reducing MaxBisection to QUBO.
+#[derive(Debug, Clone)]
+pub struct ReductionMaxBisectionToQUBO {
+    target: QUBO<f64>,
+}
+
+impl ReductionResult for ReductionMaxBisectionToQUBO {
+    type Source = MaxBisection<SimpleGraph, i32>;
+    type Target = QUBO<f64>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+#[reduction(overhead = { num_vars = "num_vertices" })]
+impl ReduceTo<QUBO<f64>> for MaxBisection<SimpleGraph, i32> {
+    type Result = ReductionMaxBisectionToQUBO;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.num_vertices();
+        let edges = self.edges();
+        let penalty = edges.iter().map(|&(_, _, w)| (w as f64).abs()).sum::<f64>() + 1.0;
+        let mut matrix = vec![vec![0.0f64; n]; n];
+
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = penalty * (1.0 - n as f64);
+            for value in row.iter_mut().skip(i + 1) {
+                *value = 2.0 * penalty;
+            }
+        }
+
+        for (u, v, w) in edges {
+            let w = w as f64;
+            let (lo, hi) = if u < v { (u, v) } else { (v, u) };
+            matrix[u][u] -= w;
+            matrix[v][v] -= w;
+            matrix[lo][hi] += 2.0 * w;
+        }
+
+        ReductionMaxBisectionToQUBO {
+            target: QUBO::from_matrix(matrix),
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "maxbisection_to_qubo",
+        build: || {
+            crate::example_db::specs::rule_example_with_witness::<_, QUBO<f64>>(
+                MaxBisection::new(
+                    SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]),
+                    vec![5, 5, 5, 1, 1, 1],
+                ),
+                SolutionPair {
+                    source_config: vec![0, 0, 1, 1],
+                    target_config: vec![0, 0, 1, 1],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/maxbisection_qubo.rs"]
+mod tests;
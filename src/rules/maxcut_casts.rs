@@ -0,0 +1,27 @@
+//! Variant cast reductions for MaxCut.
+//!
+//! These explicit casts relax the graph topology along the subtype chain
+//! `KingsSubgraph`/`TriangularSubgraph` -> `UnitDiskGraph` -> `SimpleGraph`
+//! using the variant hierarchy's `CastToParent` trait.
+//! Casting a graph preserves its vertex indices and edge order, so the edge
+//! weights carry over unchanged.
+
+use crate::impl_graph_casts;
+use crate::models::graph::MaxCut;
+use crate::topology::{KingsSubgraph, SimpleGraph, TriangularSubgraph, UnitDiskGraph};
+
+impl_graph_casts!(
+    MaxCut,
+    weights: [i32],
+    graphs: [
+        KingsSubgraph => UnitDiskGraph,
+        TriangularSubgraph => UnitDiskGraph,
+        UnitDiskGraph => SimpleGraph,
+    ],
+    fields: [num_vertices, num_edges],
+    |src, graph| MaxCut::new(graph, src.edge_weights())
+);
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/maxcut_casts.rs"]
+mod tests;
@@ -0,0 +1,141 @@
+//! Reduction from MaximalIS to Satisfiability (SAT).
+//!
+//! A maximal independent set is exactly an independent dominating set, so it
+//! can be described by two families of CNF clauses over one Boolean variable
+//! x_v per vertex:
+//!
+//! 1. Independence: for every edge (u, v), the clause (NOT x_u OR NOT x_v).
+//! 2. Maximality: for every vertex u, the clause (x_u OR x_w1 OR ... OR x_wd),
+//!    where w1, ..., wd are the neighbors of u. Either u is selected or one
+//!    of its neighbors is, so u cannot be added to the set.
+//!
+//! Satisfying assignments correspond one-to-one to maximal independent sets.
+//! Vertex weights are not encoded, so a single query only preserves
+//! feasibility, and the edge is registered as a Turing edge: the maximum
+//! weight is found by enumerating maximal independent sets, blocking each
+//! one found with a clause (see [`ReductionMaximalISToSAT::excluding`]).
+
+use crate::expr::Expr;
+use crate::models::formula::{CNFClause, Satisfiability};
+use crate::models::graph::MaximalIS;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+use crate::traits::Problem;
+
+/// Result of reducing MaximalIS to Satisfiability.
+#[derive(Debug, Clone)]
+pub struct ReductionMaximalISToSAT {
+    target: Satisfiability,
+}
+
+impl ReductionResult for ReductionMaximalISToSAT {
+    type Source = MaximalIS<SimpleGraph, i32>;
+    type Target = Satisfiability;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Variable x_v is the selection bit of vertex v, so extraction is the identity.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+impl ReductionMaximalISToSAT {
+    /// Ask for a maximal independent set of `source` other than those in `found`.
+    ///
+    /// This is one query of the Turing reduction. Distinct maximal independent
+    /// sets are incomparable, so any other maximal set selects a vertex outside
+    /// each found set `S`; the blocking clause for `S` is `OR_{v not in S} x_v`.
+    /// Querying until the target is unsatisfiable enumerates every maximal
+    /// independent set, and the heaviest one is the optimum.
+    pub fn excluding(source: &MaximalIS<SimpleGraph, i32>, found: &[Vec<usize>]) -> Self {
+        let graph = source.graph();
+        let n = graph.num_vertices();
+        let lit = |v: usize| (v + 1) as i32;
+
+        // Independence clauses: (NOT x_u OR NOT x_v) for every edge.
+        let mut clauses: Vec<CNFClause> = graph
+            .edges()
+            .into_iter()
+            .map(|(u, v)| CNFClause::new(vec![-lit(u), -lit(v)]))
+            .collect();
+
+        // Maximality clauses: (x_u OR x_w for w in N(u)) for every vertex.
+        for u in 0..n {
+            let mut literals = vec![lit(u)];
+            literals.extend(graph.neighbors(u).into_iter().map(lit));
+            clauses.push(CNFClause::new(literals));
+        }
+
+        // Blocking clauses: (x_v for v not in S) for every found set S.
+        for config in found {
+            clauses.push(CNFClause::new(
+                (0..n).filter(|&v| config[v] == 0).map(lit).collect(),
+            ));
+        }
+
+        Self {
+            target: Satisfiability::new(n, clauses),
+        }
+    }
+}
+
+/// Answers a single query with no excluded sets, so the extracted set is
+/// maximal but not necessarily of maximum weight; see
+/// [`ReductionMaximalISToSAT::excluding`].
+impl ReduceTo<Satisfiability> for MaximalIS<SimpleGraph, i32> {
+    type Result = ReductionMaximalISToSAT;
+
+    fn reduce_to(&self) -> Self::Result {
+        ReductionMaximalISToSAT::excluding(self, &[])
+    }
+}
+
+// MaximalIS → SAT (Turing: enumerate maximal sets with blocking clauses)
+inventory::submit! {
+    crate::rules::ReductionEntry {
+        source_name: "MaximalIS",
+        target_name: "Satisfiability",
+        source_variant_fn: <MaximalIS<SimpleGraph, i32> as Problem>::variant,
+        target_variant_fn: <Satisfiability as Problem>::variant,
+        overhead_fn: || {
+            let n = Expr::Var("num_vertices");
+            let m = Expr::Var("num_edges");
+            crate::rules::ReductionOverhead::new(vec![
+                ("num_vars", n.clone()),
+                ("num_clauses", n.clone() + m.clone()),
+                ("num_literals", n + Expr::Const(4.0) * m),
+            ])
+        },
+        module_path: module_path!(),
+        reduce_fn: None,
+        reduce_aggregate_fn: None,
+        capabilities: crate::rules::EdgeCapabilities::turing(),
+        overhead_eval_fn: |any| {
+            let source = any
+                .downcast_ref::<MaximalIS<SimpleGraph, i32>>()
+                .expect("MaximalIS turing overhead source type mismatch");
+            let (n, m) = (source.num_vertices(), source.num_edges());
+            crate::types::ProblemSize::new(vec![
+                ("num_vars", n),
+                ("num_clauses", n + m),
+                ("num_literals", n + 4 * m),
+            ])
+        },
+        source_size_fn: |any| {
+            let source = any
+                .downcast_ref::<MaximalIS<SimpleGraph, i32>>()
+                .expect("MaximalIS turing size source type mismatch");
+            crate::types::ProblemSize::new(vec![
+                ("num_vertices", source.num_vertices()),
+                ("num_edges", source.num_edges()),
+            ])
+        },
+    }
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/maximalis_satisfiability.rs"]
+mod tests;
@@ -0,0 +1,20 @@
+//! Variant cast reductions for Maximum2Satisfiability.
+
+use crate::impl_variant_reduction;
+use crate::models::formula::Maximum2Satisfiability;
+use crate::types::One;
+use crate::variant::CastToParent;
+
+impl_variant_reduction!(
+    Maximum2Satisfiability,
+    <One> => <i32>,
+    fields: [num_vars, num_clauses],
+    |src| Maximum2Satisfiability::with_weights(
+        src.num_vars(),
+        src.clauses().to_vec(),
+        src.weights().iter().map(|w| w.cast_to_parent()).collect())
+);
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/maximum2satisfiability_casts.rs"]
+mod tests;
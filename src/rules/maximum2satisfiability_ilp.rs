@@ -11,6 +11,7 @@ use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
 use crate::models::formula::Maximum2Satisfiability;
 use crate::reduction;
 use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::types::One;
 
 /// Result of reducing Maximum2Satisfiability to ILP.
 #[derive(Debug, Clone)]
@@ -20,7 +21,7 @@ pub struct ReductionMaximum2SatisfiabilityToILP {
 }
 
 impl ReductionResult for ReductionMaximum2SatisfiabilityToILP {
-    type Source = Maximum2Satisfiability;
+    type Source = Maximum2Satisfiability<One>;
     type Target = ILP<bool>;
 
     fn target_problem(&self) -> &ILP<bool> {
@@ -38,7 +39,7 @@ impl ReductionResult for ReductionMaximum2SatisfiabilityToILP {
         num_constraints = "num_clauses",
     }
 )]
-impl ReduceTo<ILP<bool>> for Maximum2Satisfiability {
+impl ReduceTo<ILP<bool>> for Maximum2Satisfiability<One> {
     type Result = ReductionMaximum2SatisfiabilityToILP;
 
     fn reduce_to(&self) -> Self::Result {
@@ -105,7 +106,7 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
     vec![crate::example_db::specs::RuleExampleSpec {
         id: "maximum2satisfiability_to_ilp",
         build: || {
-            let source = Maximum2Satisfiability::new(
+            let source = Maximum2Satisfiability::<One>::new(
                 4,
                 vec![
                     CNFClause::new(vec![1, 2]),
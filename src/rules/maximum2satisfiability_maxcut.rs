@@ -7,26 +7,32 @@
 //! For each 2-literal clause `(l_1 \/ l_2)`, we add the doubled affine form of
 //! its satisfaction indicator:
 //! `2 * sat(C) = K_C + w(s,a) cut(s,a) + w(s,b) cut(s,b) + w(a,b) cut(a,b)`.
-//! Summing over clauses yields
-//! `2 * satisfied(phi, x) = C_0 + cut_value(G_phi, partition)`, so every
+//! A unit clause `(l)` only needs the `(s,a)` term, and an empty clause is the
+//! constant 0. Scaling each form by the clause weight and summing yields
+//! `2 * satisfied_weight(phi, x) = C_0 + cut_value(G_phi, partition)`, so every
 //! optimal cut extracts to an optimal MAX-2-SAT assignment.
 
-use crate::models::formula::Maximum2Satisfiability;
+use crate::models::formula::{CNFClause, Maximum2Satisfiability};
 use crate::models::graph::MaxCut;
 use crate::reduction;
 use crate::rules::traits::{ReduceTo, ReductionResult};
 use crate::topology::SimpleGraph;
+use crate::types::{One, WeightElement};
+use crate::variant::VariantParam;
 use std::collections::BTreeMap;
 
 /// Result of reducing Maximum2Satisfiability to MaxCut.
 #[derive(Debug, Clone)]
-pub struct ReductionMaximum2SatisfiabilityToMaxCut {
+pub struct ReductionMaximum2SatisfiabilityToMaxCut<W> {
     target: MaxCut<SimpleGraph, i32>,
     source_num_vars: usize,
+    _phantom: std::marker::PhantomData<W>,
 }
 
-impl ReductionResult for ReductionMaximum2SatisfiabilityToMaxCut {
-    type Source = Maximum2Satisfiability;
+impl<W: WeightElement + VariantParam> ReductionResult
+    for ReductionMaximum2SatisfiabilityToMaxCut<W>
+{
+    type Source = Maximum2Satisfiability<W>;
     type Target = MaxCut<SimpleGraph, i32>;
 
     fn target_problem(&self) -> &Self::Target {
@@ -54,44 +60,88 @@ fn literal_polarity(lit: i32) -> i32 {
     }
 }
 
+/// Build the MaxCut instance for clauses with integer weights.
+fn reduce_weighted<W>(
+    num_vars: usize,
+    clauses: &[CNFClause],
+    weights: impl Iterator<Item = i32>,
+) -> ReductionMaximum2SatisfiabilityToMaxCut<W> {
+    let mut accumulated = BTreeMap::new();
+
+    for (clause, weight) in clauses.iter().zip(weights) {
+        match clause.literals[..] {
+            [] => {}
+            [lit] => {
+                // 2 * sat = (1 + sigma) - 2 * sigma * cut(s, a)
+                let var = lit.unsigned_abs() as usize;
+                add_edge_weight(
+                    &mut accumulated,
+                    0,
+                    var,
+                    -2 * literal_polarity(lit) * weight,
+                );
+            }
+            [lit_a, lit_b] => {
+                let var_a = lit_a.unsigned_abs() as usize;
+                let var_b = lit_b.unsigned_abs() as usize;
+                let sigma_a = literal_polarity(lit_a);
+                let sigma_b = literal_polarity(lit_b);
+
+                add_edge_weight(&mut accumulated, 0, var_a, -sigma_a * weight);
+                add_edge_weight(&mut accumulated, 0, var_b, -sigma_b * weight);
+                if var_a != var_b {
+                    add_edge_weight(&mut accumulated, var_a, var_b, sigma_a * sigma_b * weight);
+                }
+            }
+            _ => unreachable!("Maximum2Satisfiability clauses have at most 2 literals"),
+        }
+    }
+
+    let (edges, weights): (Vec<_>, Vec<_>) = accumulated
+        .into_iter()
+        .filter(|(_, weight)| *weight != 0)
+        .unzip();
+
+    ReductionMaximum2SatisfiabilityToMaxCut {
+        target: MaxCut::new(SimpleGraph::new(num_vars + 1, edges), weights),
+        source_num_vars: num_vars,
+        _phantom: std::marker::PhantomData,
+    }
+}
+
 #[reduction(
     overhead = {
         num_vertices = "num_vars + 1",
         num_edges = "num_vars + num_clauses",
     }
 )]
-impl ReduceTo<MaxCut<SimpleGraph, i32>> for Maximum2Satisfiability {
-    type Result = ReductionMaximum2SatisfiabilityToMaxCut;
+impl ReduceTo<MaxCut<SimpleGraph, i32>> for Maximum2Satisfiability<One> {
+    type Result = ReductionMaximum2SatisfiabilityToMaxCut<One>;
 
     fn reduce_to(&self) -> Self::Result {
-        let mut accumulated = BTreeMap::new();
-
-        for clause in self.clauses() {
-            let literals = &clause.literals;
-            let (lit_a, lit_b) = (literals[0], literals[1]);
-            let var_a = lit_a.unsigned_abs() as usize;
-            let var_b = lit_b.unsigned_abs() as usize;
-            let sigma_a = literal_polarity(lit_a);
-            let sigma_b = literal_polarity(lit_b);
-
-            add_edge_weight(&mut accumulated, 0, var_a, -sigma_a);
-            add_edge_weight(&mut accumulated, 0, var_b, -sigma_b);
-            if var_a != var_b {
-                add_edge_weight(&mut accumulated, var_a, var_b, sigma_a * sigma_b);
-            }
-        }
-
-        let (edges, weights): (Vec<_>, Vec<_>) = accumulated
-            .into_iter()
-            .filter(|(_, weight)| *weight != 0)
-            .unzip();
+        reduce_weighted(
+            self.num_vars(),
+            self.clauses(),
+            std::iter::repeat_n(1, self.num_clauses()),
+        )
+    }
+}
 
-        let target = MaxCut::new(SimpleGraph::new(self.num_vars() + 1, edges), weights);
+#[reduction(
+    overhead = {
+        num_vertices = "num_vars + 1",
+        num_edges = "num_vars + num_clauses",
+    }
+)]
+impl ReduceTo<MaxCut<SimpleGraph, i32>> for Maximum2Satisfiability<i32> {
+    type Result = ReductionMaximum2SatisfiabilityToMaxCut<i32>;
 
-        ReductionMaximum2SatisfiabilityToMaxCut {
-            target,
-            source_num_vars: self.num_vars(),
-        }
+    fn reduce_to(&self) -> Self::Result {
+        reduce_weighted(
+            self.num_vars(),
+            self.clauses(),
+            self.weights().iter().copied(),
+        )
     }
 }
 
@@ -100,31 +150,57 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
     use crate::export::SolutionPair;
     use crate::models::formula::CNFClause;
 
-    vec![crate::example_db::specs::RuleExampleSpec {
-        id: "maximum2satisfiability_to_maxcut",
-        build: || {
-            let source = Maximum2Satisfiability::new(
-                3,
-                vec![
-                    CNFClause::new(vec![1, 2]),
-                    CNFClause::new(vec![-1, 3]),
-                    CNFClause::new(vec![2, -3]),
-                    CNFClause::new(vec![-1, -2]),
-                    CNFClause::new(vec![1, 3]),
-                ],
-            );
-            crate::example_db::specs::rule_example_with_witness::<_, MaxCut<SimpleGraph, i32>>(
-                source,
-                SolutionPair {
-                    // x1=F, x2=T, x3=T satisfies all five clauses.
-                    source_config: vec![0, 1, 1],
-                    // Vertex 0 is the reference vertex s. Variables are true
-                    // exactly when they share s's side of the cut.
-                    target_config: vec![0, 1, 0, 0],
-                },
-            )
+    vec![
+        crate::example_db::specs::RuleExampleSpec {
+            id: "maximum2satisfiability_to_maxcut",
+            build: || {
+                let source = Maximum2Satisfiability::<One>::new(
+                    3,
+                    vec![
+                        CNFClause::new(vec![1, 2]),
+                        CNFClause::new(vec![-1, 3]),
+                        CNFClause::new(vec![2, -3]),
+                        CNFClause::new(vec![-1, -2]),
+                        CNFClause::new(vec![1, 3]),
+                    ],
+                );
+                crate::example_db::specs::rule_example_with_witness::<_, MaxCut<SimpleGraph, i32>>(
+                    source,
+                    SolutionPair {
+                        // x1=F, x2=T, x3=T satisfies all five clauses.
+                        source_config: vec![0, 1, 1],
+                        // Vertex 0 is the reference vertex s. Variables are true
+                        // exactly when they share s's side of the cut.
+                        target_config: vec![0, 1, 0, 0],
+                    },
+                )
+            },
+        },
+        crate::example_db::specs::RuleExampleSpec {
+            id: "maximum2satisfiability_i32_to_maxcut",
+            build: || {
+                // (x1 \/ x2) w=3, (~x1) w=2, (~x2 \/ x3) w=1, (~x3) w=4
+                let source = Maximum2Satisfiability::with_weights(
+                    3,
+                    vec![
+                        CNFClause::new(vec![1, 2]),
+                        CNFClause::new(vec![-1]),
+                        CNFClause::new(vec![-2, 3]),
+                        CNFClause::new(vec![-3]),
+                    ],
+                    vec![3, 2, 1, 4],
+                );
+                crate::example_db::specs::rule_example_with_witness::<_, MaxCut<SimpleGraph, i32>>(
+                    source,
+                    SolutionPair {
+                        // x1=F, x2=T, x3=F: weight 3 + 2 + 4 = 9 of 10.
+                        source_config: vec![0, 1, 0],
+                        target_config: vec![0, 1, 0, 1],
+                    },
+                )
+            },
         },
-    }]
+    ]
 }
 
 #[cfg(test)]
@@ -0,0 +1,128 @@
+//! Reductions between MaximumAcyclicSubgraph and MinimumFeedbackArcSet problems.
+//!
+//! These problems are complements: an arc set A' is acyclic iff A \ A' is a
+//! feedback arc set.
+
+use crate::models::graph::{MaximumAcyclicSubgraph, MinimumFeedbackArcSet};
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::types::WeightElement;
+
+/// Result of reducing MaximumAcyclicSubgraph to MinimumFeedbackArcSet.
+#[derive(Debug, Clone)]
+pub struct ReductionMASToFAS<W> {
+    target: MinimumFeedbackArcSet<W>,
+}
+
+impl<W> ReductionResult for ReductionMASToFAS<W>
+where
+    W: WeightElement + crate::variant::VariantParam,
+{
+    type Source = MaximumAcyclicSubgraph<W>;
+    type Target = MinimumFeedbackArcSet<W>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Solution extraction: complement the configuration.
+    /// If an arc is removed (1), it is NOT kept in the acyclic subgraph (0).
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.iter().map(|&x| 1 - x).collect()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "num_vertices",
+        num_arcs = "num_arcs",
+    }
+)]
+impl ReduceTo<MinimumFeedbackArcSet<i32>> for MaximumAcyclicSubgraph<i32> {
+    type Result = ReductionMASToFAS<i32>;
+
+    fn reduce_to(&self) -> Self::Result {
+        let target = MinimumFeedbackArcSet::new(self.graph().clone(), self.weights().to_vec());
+        ReductionMASToFAS { target }
+    }
+}
+
+/// Result of reducing MinimumFeedbackArcSet to MaximumAcyclicSubgraph.
+#[derive(Debug, Clone)]
+pub struct ReductionFASToMAS<W> {
+    target: MaximumAcyclicSubgraph<W>,
+}
+
+impl<W> ReductionResult for ReductionFASToMAS<W>
+where
+    W: WeightElement + crate::variant::VariantParam,
+{
+    type Source = MinimumFeedbackArcSet<W>;
+    type Target = MaximumAcyclicSubgraph<W>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Solution extraction: complement the configuration.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.iter().map(|&x| 1 - x).collect()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "num_vertices",
+        num_arcs = "num_arcs",
+    }
+)]
+impl ReduceTo<MaximumAcyclicSubgraph<i32>> for MinimumFeedbackArcSet<i32> {
+    type Result = ReductionFASToMAS<i32>;
+
+    fn reduce_to(&self) -> Self::Result {
+        let target = MaximumAcyclicSubgraph::new(self.graph().clone(), self.weights().to_vec());
+        ReductionFASToMAS { target }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+    use crate::topology::DirectedGraph;
+
+    // Tournament on 4 vertices whose three cycles all use arc 0->1.
+    fn tournament() -> DirectedGraph {
+        DirectedGraph::new(4, vec![(0, 1), (1, 2), (2, 0), (1, 3), (3, 0), (2, 3)])
+    }
+
+    vec![
+        crate::example_db::specs::RuleExampleSpec {
+            id: "maximumacyclicsubgraph_to_minimumfeedbackarcset",
+            build: || {
+                crate::example_db::specs::rule_example_with_witness::<_, MinimumFeedbackArcSet<i32>>(
+                    MaximumAcyclicSubgraph::new(tournament(), vec![1i32; 6]),
+                    SolutionPair {
+                        source_config: vec![0, 1, 1, 1, 1, 1],
+                        target_config: vec![1, 0, 0, 0, 0, 0],
+                    },
+                )
+            },
+        },
+        crate::example_db::specs::RuleExampleSpec {
+            id: "minimumfeedbackarcset_to_maximumacyclicsubgraph",
+            build: || {
+                crate::example_db::specs::rule_example_with_witness::<_, MaximumAcyclicSubgraph<i32>>(
+                    MinimumFeedbackArcSet::new(tournament(), vec![1i32; 6]),
+                    SolutionPair {
+                        source_config: vec![1, 0, 0, 0, 0, 0],
+                        target_config: vec![0, 1, 1, 1, 1, 1],
+                    },
+                )
+            },
+        },
+    ]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/maximumacyclicsubgraph_minimumfeedbackarcset.rs"]
+mod tests;
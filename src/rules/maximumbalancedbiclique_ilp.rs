@@ -0,0 +1,95 @@
+//! Reduction from MaximumBalancedBiclique to ILP.
+//!
+//! Binary variables x_l for left vertices, y_r for right vertices.
+//! Balance: Σ x_l - Σ y_r = 0.
+//! Non-edge forbidding: x_l + y_r ≤ 1 for every non-edge (l, r), so a
+//! selected left vertex forces every selected right vertex to be a neighbor.
+//! Objective: maximize Σ x_l.
+
+use crate::models::algebraic::ILP;
+use crate::models::graph::MaximumBalancedBiclique;
+use crate::reduction;
+use crate::rules::ilp_builder::{unit_terms, IlpBuilder};
+use crate::rules::traits::{ReduceTo, ReductionResult};
+
+/// Result of reducing MaximumBalancedBiclique to ILP.
+#[derive(Debug, Clone)]
+pub struct ReductionMBBToILP {
+    target: ILP<bool>,
+}
+
+impl ReductionResult for ReductionMBBToILP {
+    type Source = MaximumBalancedBiclique;
+    type Target = ILP<bool>;
+
+    fn target_problem(&self) -> &ILP<bool> {
+        &self.target
+    }
+
+    /// Variables are laid out exactly like the source configuration.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vars = "num_vertices",
+        num_constraints = "left_size * right_size - num_edges + 1",
+    }
+)]
+impl ReduceTo<ILP<bool>> for MaximumBalancedBiclique {
+    type Result = ReductionMBBToILP;
+
+    fn reduce_to(&self) -> Self::Result {
+        let mut ilp = IlpBuilder::new();
+        let x = ilp.binary_vars(self.left_size());
+        let y = ilp.binary_vars(self.right_size());
+
+        // Σ x_l - Σ y_r = 0
+        let balance = unit_terms(x.indices())
+            .into_iter()
+            .chain(y.indices().map(|v| (v, -1.0)))
+            .collect();
+        ilp.add_eq("balance", balance, 0.0);
+
+        // x_l + y_r <= 1 for (l, r) not in E
+        for l in 0..x.len() {
+            for r in 0..y.len() {
+                if !self.has_edge(l, r) {
+                    ilp.add_le(
+                        format_args!("non_edge[{l},{r}]"),
+                        unit_terms([x.at(l), y.at(r)]),
+                        1.0,
+                    );
+                }
+            }
+        }
+
+        ilp.maximize(unit_terms(x.indices()));
+
+        ReductionMBBToILP {
+            target: ilp.build(),
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::topology::BipartiteGraph;
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "maximumbalancedbiclique_to_ilp",
+        build: || {
+            let source = MaximumBalancedBiclique::new(BipartiteGraph::new(
+                3,
+                3,
+                vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 1), (2, 2)],
+            ));
+            crate::example_db::specs::rule_example_via_ilp::<_, bool>(source)
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/maximumbalancedbiclique_ilp.rs"]
+mod tests;
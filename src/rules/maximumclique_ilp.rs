@@ -6,9 +6,10 @@
 //!   at most one can be in the clique
 //! - Objective: Maximize the sum of weights of selected vertices
 
-use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::algebraic::ILP;
 use crate::models::graph::MaximumClique;
 use crate::reduction;
+use crate::rules::ilp_builder::{unit_terms, weighted_terms, IlpBuilder};
 use crate::rules::traits::{ReduceTo, ReductionResult};
 use crate::topology::{Graph, SimpleGraph};
 
@@ -50,31 +51,27 @@ impl ReduceTo<ILP<bool>> for MaximumClique<SimpleGraph, i32> {
     type Result = ReductionCliqueToILP;
 
     fn reduce_to(&self) -> Self::Result {
-        let num_vars = self.graph().num_vertices();
+        let num_vertices = self.graph().num_vertices();
+        let mut ilp = IlpBuilder::new();
+        ilp.binary_vars(num_vertices);
 
         // Constraints: x_u + x_v <= 1 for each NON-EDGE (u, v)
         // This ensures at most one vertex of each non-edge is selected (i.e., if both
         // are selected, they must be adjacent, forming a clique)
-        let mut constraints: Vec<LinearConstraint> = Vec::new();
-        for u in 0..num_vars {
-            for v in (u + 1)..num_vars {
+        for u in 0..num_vertices {
+            for v in (u + 1)..num_vertices {
                 if !self.graph().has_edge(u, v) {
-                    constraints.push(LinearConstraint::le(vec![(u, 1.0), (v, 1.0)], 1.0));
+                    ilp.add_le(format_args!("non_edge[{u},{v}]"), unit_terms([u, v]), 1.0);
                 }
             }
         }
 
         // Objective: maximize sum of w_i * x_i (weighted sum of selected vertices)
-        let objective: Vec<(usize, f64)> = self
-            .weights()
-            .iter()
-            .enumerate()
-            .map(|(i, &w)| (i, w as f64))
-            .collect();
+        ilp.maximize(weighted_terms(self.weights()));
 
-        let target = ILP::new(num_vars, constraints, objective, ObjectiveSense::Maximize);
-
-        ReductionCliqueToILP { target }
+        ReductionCliqueToILP {
+            target: ilp.build(),
+        }
     }
 }
 
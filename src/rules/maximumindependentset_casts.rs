@@ -1,53 +1,33 @@
 //! Variant cast reductions for MaximumIndependentSet.
 //!
-//! These explicit casts convert MIS between graph subtypes using
-//! the variant hierarchy's `CastToParent` trait.
+//! These explicit casts convert MIS between graph subtypes and weight types
+//! using the variant hierarchy's `CastToParent` trait.
 
-use crate::impl_variant_reduction;
 use crate::models::graph::MaximumIndependentSet;
 use crate::topology::{KingsSubgraph, SimpleGraph, TriangularSubgraph, UnitDiskGraph};
 use crate::types::One;
 use crate::variant::CastToParent;
+use crate::{impl_graph_casts, impl_variant_reduction};
 
-impl_variant_reduction!(
-    MaximumIndependentSet,
-    <KingsSubgraph, i32> => <UnitDiskGraph, i32>,
-    fields: [num_vertices, num_edges],
-    |src| MaximumIndependentSet::new(
-        src.graph().cast_to_parent(), src.weights().to_vec())
-);
-
-impl_variant_reduction!(
+// Graph-hierarchy casts (same weight One); there is no TriangularSubgraph/One variant
+impl_graph_casts!(
     MaximumIndependentSet,
-    <TriangularSubgraph, i32> => <UnitDiskGraph, i32>,
+    weights: [One],
+    graphs: [KingsSubgraph => UnitDiskGraph, UnitDiskGraph => SimpleGraph],
     fields: [num_vertices, num_edges],
-    |src| MaximumIndependentSet::new(
-        src.graph().cast_to_parent(), src.weights().to_vec())
+    |src, graph| MaximumIndependentSet::new(graph, src.weights().to_vec())
 );
 
-impl_variant_reduction!(
+impl_graph_casts!(
     MaximumIndependentSet,
-    <UnitDiskGraph, i32> => <SimpleGraph, i32>,
+    weights: [i32],
+    graphs: [
+        KingsSubgraph => UnitDiskGraph,
+        TriangularSubgraph => UnitDiskGraph,
+        UnitDiskGraph => SimpleGraph,
+    ],
     fields: [num_vertices, num_edges],
-    |src| MaximumIndependentSet::new(
-        src.graph().cast_to_parent(), src.weights().to_vec())
-);
-
-// Graph-hierarchy casts (same weight One)
-impl_variant_reduction!(
-    MaximumIndependentSet,
-    <KingsSubgraph, One> => <UnitDiskGraph, One>,
-    fields: [num_vertices, num_edges],
-    |src| MaximumIndependentSet::new(
-        src.graph().cast_to_parent(), src.weights().to_vec())
-);
-
-impl_variant_reduction!(
-    MaximumIndependentSet,
-    <UnitDiskGraph, One> => <SimpleGraph, One>,
-    fields: [num_vertices, num_edges],
-    |src| MaximumIndependentSet::new(
-        src.graph().cast_to_parent(), src.weights().to_vec())
+    |src, graph| MaximumIndependentSet::new(graph, src.weights().to_vec())
 );
 
 // Weight-hierarchy casts (One → i32)
@@ -74,3 +54,7 @@ impl_variant_reduction!(
     |src| MaximumIndependentSet::new(
         src.graph().clone(), src.weights().iter().map(|w| w.cast_to_parent()).collect())
 );
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/maximumindependentset_casts.rs"]
+mod tests;
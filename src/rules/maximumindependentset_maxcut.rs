@@ -0,0 +1,110 @@
+//! Reduction from MaximumIndependentSet to MaxCut.
+//!
+//! The reduction keeps every source vertex and adds one apex vertex `a`
+//! adjacent to all of them. A vertex is selected exactly when it lies on the
+//! opposite side of the cut from `a`. With `x_i` that indicator, a source
+//! edge of weight `d` contributes `d (x_i + x_j - 2 x_i x_j)` and an apex edge
+//! of weight `c_i` contributes `c_i x_i`, so choosing `c_i = w_i - deg(i) d`
+//! makes the cut value
+//!
+//! `sum_i w_i x_i - 2d * #{(i, j) in E : x_i = x_j = 1}`.
+//!
+//! With `d = max(max_i w_i, 1)` the penalty `2d` exceeds every vertex
+//! weight, so dropping an endpoint of a violated edge always increases the
+//! cut. Every maximum cut therefore selects an independent set, and its cut
+//! value equals that set's weight.
+
+use crate::models::graph::{MaxCut, MaximumIndependentSet};
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+
+/// Result of reducing MaximumIndependentSet to MaxCut.
+#[derive(Debug, Clone)]
+pub struct ReductionISToMaxCut {
+    target: MaxCut<SimpleGraph, i32>,
+    /// Index of the apex vertex; source vertices keep their indices.
+    apex: usize,
+}
+
+impl ReductionResult for ReductionISToMaxCut {
+    type Source = MaximumIndependentSet<SimpleGraph, i32>;
+    type Target = MaxCut<SimpleGraph, i32>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// A vertex is in the independent set iff it is cut away from the apex.
+    /// Comparing against the apex's side makes both labelings of the same
+    /// partition extract to the same set.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let apex_side = target_solution[self.apex];
+        target_solution[..self.apex]
+            .iter()
+            .map(|&side| usize::from(side != apex_side))
+            .collect()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "num_vertices + 1",
+        num_edges = "num_vertices + num_edges",
+    }
+)]
+impl ReduceTo<MaxCut<SimpleGraph, i32>> for MaximumIndependentSet<SimpleGraph, i32> {
+    type Result = ReductionISToMaxCut;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.graph().num_vertices();
+        let source_edges = self.graph().edges();
+        let penalty = self.weights().iter().copied().max().unwrap_or(0).max(1);
+
+        let mut degree = vec![0i32; n];
+        for &(u, v) in &source_edges {
+            degree[u] += 1;
+            degree[v] += 1;
+        }
+
+        let mut edges = source_edges;
+        let mut weights = vec![penalty; edges.len()];
+        // Apex edges cancel the penalty weight each vertex collects from its
+        // source edges, leaving exactly w_i when the vertex is cut alone.
+        for (v, &w) in self.weights().iter().enumerate() {
+            edges.push((v, n));
+            weights.push(w - degree[v] * penalty);
+        }
+
+        ReductionISToMaxCut {
+            target: MaxCut::new(SimpleGraph::new(n + 1, edges), weights),
+            apex: n,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "maximumindependentset_to_maxcut",
+        build: || {
+            // Weighted 5-cycle whose unique maximum independent set is {0, 2}.
+            let source = MaximumIndependentSet::new(SimpleGraph::cycle(5), vec![3, 1, 2, 1, 2]);
+            crate::example_db::specs::rule_example_with_witness::<_, MaxCut<SimpleGraph, i32>>(
+                source,
+                SolutionPair {
+                    source_config: vec![1, 0, 1, 0, 0],
+                    // Vertex 5 is the apex; the selected vertices sit on the
+                    // other side of the cut.
+                    target_config: vec![1, 0, 1, 0, 0, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/maximumindependentset_maxcut.rs"]
+mod tests;
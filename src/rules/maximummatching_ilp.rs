@@ -6,9 +6,10 @@
 //!   (at most one incident edge can be selected)
 //! - Objective: Maximize the sum of weights of selected edges
 
-use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::algebraic::ILP;
 use crate::models::graph::MaximumMatching;
 use crate::reduction;
+use crate::rules::ilp_builder::{unit_terms, weighted_terms, IlpBuilder};
 use crate::rules::traits::{ReduceTo, ReductionResult};
 use crate::topology::{Graph, SimpleGraph};
 
@@ -50,31 +51,28 @@ impl ReduceTo<ILP<bool>> for MaximumMatching<SimpleGraph, i32> {
     type Result = ReductionMatchingToILP;
 
     fn reduce_to(&self) -> Self::Result {
-        let num_vars = self.graph().num_edges(); // Number of edges
+        let mut ilp = IlpBuilder::new();
+        ilp.binary_vars(self.graph().num_edges());
 
         // Constraints: For each vertex v, sum of incident edge variables <= 1
         // This ensures at most one incident edge is selected per vertex
         let v2e = self.vertex_to_edges();
-        let constraints: Vec<LinearConstraint> = (0..self.graph().num_vertices())
-            .filter_map(|vertex| v2e.get(&vertex))
-            .filter(|edges| !edges.is_empty())
-            .map(|edges| {
-                let terms: Vec<(usize, f64)> = edges.iter().map(|&e| (e, 1.0)).collect();
-                LinearConstraint::le(terms, 1.0)
-            })
-            .collect();
+        for vertex in 0..self.graph().num_vertices() {
+            if let Some(edges) = v2e.get(&vertex).filter(|edges| !edges.is_empty()) {
+                ilp.add_le(
+                    format_args!("vertex[{vertex}]"),
+                    unit_terms(edges.iter().copied()),
+                    1.0,
+                );
+            }
+        }
 
         // Objective: maximize sum of w_e * x_e (weighted sum of selected edges)
-        let weights = self.weights();
-        let objective: Vec<(usize, f64)> = weights
-            .iter()
-            .enumerate()
-            .map(|(i, &w)| (i, w as f64))
-            .collect();
+        ilp.maximize(weighted_terms(&self.weights()));
 
-        let target = ILP::new(num_vars, constraints, objective, ObjectiveSense::Maximize);
-
-        ReductionMatchingToILP { target }
+        ReductionMatchingToILP {
+            target: ilp.build(),
+        }
     }
 }
 
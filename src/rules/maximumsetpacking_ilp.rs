@@ -2,12 +2,14 @@
 //!
 //! The Set Packing problem can be formulated as a binary ILP:
 //! - Variables: One binary variable per set (0 = not selected, 1 = selected)
-//! - Constraints: For each element e, Σ_{i : e ∈ S_i} x_i ≤ 1
+//! - Constraints: For each element e, Σ_{i : e ∈ S_i} x_i ≤ 1 (elements in at
+//!   most one set give a trivially satisfied row and are skipped)
 //! - Objective: Maximize the sum of weights of selected sets
 
-use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::algebraic::ILP;
 use crate::models::set::MaximumSetPacking;
 use crate::reduction;
+use crate::rules::ilp_builder::{unit_terms, weighted_terms, IlpBuilder};
 use crate::rules::traits::{ReduceTo, ReductionResult};
 
 /// Result of reducing MaximumSetPacking to ILP.
@@ -44,7 +46,8 @@ impl ReduceTo<ILP<bool>> for MaximumSetPacking<i32> {
     type Result = ReductionSPToILP;
 
     fn reduce_to(&self) -> Self::Result {
-        let num_vars = self.num_sets();
+        let mut ilp = IlpBuilder::new();
+        ilp.binary_vars(self.num_sets());
 
         // Build element-to-sets mapping, then create one constraint per element
         let universe = self.universe_size();
@@ -54,26 +57,17 @@ impl ReduceTo<ILP<bool>> for MaximumSetPacking<i32> {
                 elem_to_sets[e].push(i);
             }
         }
+        for (e, sets) in elem_to_sets.into_iter().enumerate() {
+            if sets.len() > 1 {
+                ilp.add_le(format_args!("element[{e}]"), unit_terms(sets), 1.0);
+            }
+        }
 
-        let constraints: Vec<LinearConstraint> = elem_to_sets
-            .into_iter()
-            .filter(|sets| sets.len() > 1)
-            .map(|sets| {
-                let terms: Vec<(usize, f64)> = sets.into_iter().map(|i| (i, 1.0)).collect();
-                LinearConstraint::le(terms, 1.0)
-            })
-            .collect();
-
-        let objective: Vec<(usize, f64)> = self
-            .weights_ref()
-            .iter()
-            .enumerate()
-            .map(|(i, &w)| (i, w as f64))
-            .collect();
-
-        let target = ILP::new(num_vars, constraints, objective, ObjectiveSense::Maximize);
+        ilp.maximize(weighted_terms(self.weights_ref()));
 
-        ReductionSPToILP { target }
+        ReductionSPToILP {
+            target: ilp.build(),
+        }
     }
 }
 
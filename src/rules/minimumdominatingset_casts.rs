@@ -0,0 +1,25 @@
+//! Variant cast reductions for MinimumDominatingSet.
+//!
+//! These explicit casts relax the graph topology along the subtype chain
+//! `KingsSubgraph`/`TriangularSubgraph` -> `UnitDiskGraph` -> `SimpleGraph`
+//! using the variant hierarchy's `CastToParent` trait.
+
+use crate::impl_graph_casts;
+use crate::models::graph::MinimumDominatingSet;
+use crate::topology::{KingsSubgraph, SimpleGraph, TriangularSubgraph, UnitDiskGraph};
+
+impl_graph_casts!(
+    MinimumDominatingSet,
+    weights: [i32],
+    graphs: [
+        KingsSubgraph => UnitDiskGraph,
+        TriangularSubgraph => UnitDiskGraph,
+        UnitDiskGraph => SimpleGraph,
+    ],
+    fields: [num_vertices, num_edges],
+    |src, graph| MinimumDominatingSet::new(graph, src.weights().to_vec())
+);
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/minimumdominatingset_casts.rs"]
+mod tests;
@@ -6,9 +6,10 @@
 //!   (v or at least one of its neighbors must be selected)
 //! - Objective: Minimize the sum of weights of selected vertices
 
-use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::algebraic::ILP;
 use crate::models::graph::MinimumDominatingSet;
 use crate::reduction;
+use crate::rules::ilp_builder::{unit_terms, weighted_terms, IlpBuilder};
 use crate::rules::traits::{ReduceTo, ReductionResult};
 use crate::topology::{Graph, SimpleGraph};
 
@@ -51,32 +52,27 @@ impl ReduceTo<ILP<bool>> for MinimumDominatingSet<SimpleGraph, i32> {
     type Result = ReductionDSToILP;
 
     fn reduce_to(&self) -> Self::Result {
-        let num_vars = self.graph().num_vertices();
+        let num_vertices = self.graph().num_vertices();
+        let mut ilp = IlpBuilder::new();
+        ilp.binary_vars(num_vertices);
 
         // Constraints: For each vertex v, x_v + sum_{u in N(v)} x_u >= 1
         // This ensures that v is dominated (either selected or has a selected neighbor)
-        let constraints: Vec<LinearConstraint> = (0..num_vars)
-            .map(|v| {
-                // Build terms: x_v with coefficient 1, plus each neighbor with coefficient 1
-                let mut terms: Vec<(usize, f64)> = vec![(v, 1.0)];
-                for neighbor in self.neighbors(v) {
-                    terms.push((neighbor, 1.0));
-                }
-                LinearConstraint::ge(terms, 1.0)
-            })
-            .collect();
+        for v in 0..num_vertices {
+            let closed_neighborhood = std::iter::once(v).chain(self.neighbors(v));
+            ilp.add_ge(
+                format_args!("dominate[{v}]"),
+                unit_terms(closed_neighborhood),
+                1.0,
+            );
+        }
 
         // Objective: minimize sum of w_i * x_i (weighted sum of selected vertices)
-        let objective: Vec<(usize, f64)> = self
-            .weights()
-            .iter()
-            .enumerate()
-            .map(|(i, &w)| (i, w as f64))
-            .collect();
+        ilp.minimize(weighted_terms(self.weights()));
 
-        let target = ILP::new(num_vars, constraints, objective, ObjectiveSense::Minimize);
-
-        ReductionDSToILP { target }
+        ReductionDSToILP {
+            target: ilp.build(),
+        }
     }
 }
 
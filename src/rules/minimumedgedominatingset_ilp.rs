@@ -0,0 +1,103 @@
+//! Reduction from MinimumEdgeDominatingSet to ILP (Integer Linear Programming).
+//!
+//! The Edge Dominating Set problem can be formulated as a binary ILP:
+//! - Variables: One binary variable per edge (0 = not selected, 1 = selected)
+//! - Constraints: For each edge e = {u, v}: sum_{f incident to u or v} x_f >= 1
+//!   (e or at least one edge sharing an endpoint with it must be selected)
+//! - Objective: Minimize the sum of weights of selected edges
+
+use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::graph::MinimumEdgeDominatingSet;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+
+/// Result of reducing MinimumEdgeDominatingSet to ILP.
+///
+/// Each edge corresponds to a binary variable, and each edge contributes one
+/// covering constraint over the edges incident to either of its endpoints.
+#[derive(Debug, Clone)]
+pub struct ReductionEDSToILP {
+    target: ILP<bool>,
+}
+
+impl ReductionResult for ReductionEDSToILP {
+    type Source = MinimumEdgeDominatingSet<SimpleGraph, i32>;
+    type Target = ILP<bool>;
+
+    fn target_problem(&self) -> &ILP<bool> {
+        &self.target
+    }
+
+    /// Variables are the source edges, so the configuration is copied unchanged.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vars = "num_edges",
+        num_constraints = "num_edges",
+    }
+)]
+impl ReduceTo<ILP<bool>> for MinimumEdgeDominatingSet<SimpleGraph, i32> {
+    type Result = ReductionEDSToILP;
+
+    fn reduce_to(&self) -> Self::Result {
+        let edges = self.graph().edges();
+        let mut incident = vec![Vec::new(); self.graph().num_vertices()];
+        for (i, &(u, v)) in edges.iter().enumerate() {
+            incident[u].push(i);
+            incident[v].push(i);
+        }
+
+        // For each edge {u, v}: every edge at u or v (including itself) counts once.
+        let constraints: Vec<LinearConstraint> = edges
+            .iter()
+            .enumerate()
+            .map(|(i, &(u, v))| {
+                let terms: Vec<(usize, f64)> = incident[u]
+                    .iter()
+                    .chain(incident[v].iter().filter(|&&j| j != i))
+                    .map(|&j| (j, 1.0))
+                    .collect();
+                LinearConstraint::ge(terms, 1.0)
+            })
+            .collect();
+
+        let objective: Vec<(usize, f64)> = self
+            .edge_weights()
+            .iter()
+            .enumerate()
+            .map(|(i, &w)| (i, w as f64))
+            .collect();
+
+        let target = ILP::new(
+            edges.len(),
+            constraints,
+            objective,
+            ObjectiveSense::Minimize,
+        );
+
+        ReductionEDSToILP { target }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "minimumedgedominatingset_to_ilp",
+        build: || {
+            let source = MinimumEdgeDominatingSet::new(
+                SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]),
+                vec![1i32; 5],
+            );
+            crate::example_db::specs::rule_example_via_ilp::<_, bool>(source)
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/minimumedgedominatingset_ilp.rs"]
+mod tests;
@@ -0,0 +1,82 @@
+//! Reduction from MinimumEdgeDominatingSet to MinimumDominatingSet.
+//!
+//! An edge set dominates G exactly when the corresponding vertex set
+//! dominates the line graph L(G), so the reduction builds L(G) and carries
+//! each edge weight over to its line-graph vertex. Solutions map back
+//! unchanged.
+
+use crate::models::graph::{MinimumDominatingSet, MinimumEdgeDominatingSet};
+use crate::reduction;
+use crate::rules::graph_helpers::line_graph;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::SimpleGraph;
+
+/// Result of reducing MinimumEdgeDominatingSet to MinimumDominatingSet.
+///
+/// Target vertex `i` is the `i`-th source edge.
+#[derive(Debug, Clone)]
+pub struct ReductionEDSToDS {
+    target: MinimumDominatingSet<SimpleGraph, i32>,
+}
+
+impl ReductionResult for ReductionEDSToDS {
+    type Source = MinimumEdgeDominatingSet<SimpleGraph, i32>;
+    type Target = MinimumDominatingSet<SimpleGraph, i32>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "num_edges",
+        num_edges = "num_edges * (num_edges - 1) / 2",
+    }
+)]
+impl ReduceTo<MinimumDominatingSet<SimpleGraph, i32>>
+    for MinimumEdgeDominatingSet<SimpleGraph, i32>
+{
+    type Result = ReductionEDSToDS;
+
+    fn reduce_to(&self) -> Self::Result {
+        ReductionEDSToDS {
+            target: MinimumDominatingSet::new(
+                line_graph(self.graph()),
+                self.edge_weights().to_vec(),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "minimumedgedominatingset_to_minimumdominatingset",
+        build: || {
+            crate::example_db::specs::rule_example_with_witness::<
+                _,
+                MinimumDominatingSet<SimpleGraph, i32>,
+            >(
+                MinimumEdgeDominatingSet::new(
+                    SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]),
+                    vec![1i32; 5],
+                ),
+                SolutionPair {
+                    source_config: vec![1, 0, 1, 0, 0],
+                    target_config: vec![1, 0, 1, 0, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/minimumedgedominatingset_minimumdominatingset.rs"]
+mod tests;
@@ -0,0 +1,118 @@
+//! Reduction from MinimumMultiprocessorScheduling to ILP<i32>.
+//!
+//! Assignment formulation with an integer makespan variable:
+//! - Variables: binary x_{j,p} (task j on processor p) at index j * m + p,
+//!   plus the makespan C at index n * m.
+//! - Constraints:
+//!   1. Assignment: Σ_p x_{j,p} = 1 for each task j
+//!   2. Load: Σ_j len_j · x_{j,p} - C ≤ 0 for each processor p
+//!   3. Binary bounds: x_{j,p} ≤ 1 (ILP<i32> variables are non-negative integers)
+//! - Objective: Minimize C.
+//! - Extraction: the processor p with x_{j,p} = 1 for each task j.
+
+use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::misc::MinimumMultiprocessorScheduling;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+
+/// Result of reducing MinimumMultiprocessorScheduling to ILP<i32>.
+///
+/// Variable layout:
+/// - x_{j,p} at index j * num_processors + p  (num_tasks * num_processors vars)
+/// - C at index num_tasks * num_processors  (1 integer var)
+#[derive(Debug, Clone)]
+pub struct ReductionMinMPSToILP {
+    target: ILP<i32>,
+    num_tasks: usize,
+    num_processors: usize,
+}
+
+impl ReductionResult for ReductionMinMPSToILP {
+    type Source = MinimumMultiprocessorScheduling;
+    type Target = ILP<i32>;
+
+    fn target_problem(&self) -> &ILP<i32> {
+        &self.target
+    }
+
+    /// Extract solution: for each task j, find the processor p where x_{j,p} = 1.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let m = self.num_processors;
+        (0..self.num_tasks)
+            .map(|j| {
+                (0..m)
+                    .find(|&p| target_solution[j * m + p] == 1)
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vars = "num_tasks * num_processors + 1",
+        num_constraints = "num_tasks + num_processors + num_tasks * num_processors",
+    }
+)]
+impl ReduceTo<ILP<i32>> for MinimumMultiprocessorScheduling {
+    type Result = ReductionMinMPSToILP;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.num_tasks();
+        let m = self.num_processors();
+        let makespan = n * m;
+        let num_vars = makespan + 1;
+
+        let mut constraints = Vec::with_capacity(n + m + n * m);
+
+        // Assignment constraints: for each task j, Σ_p x_{j,p} = 1
+        for j in 0..n {
+            let terms: Vec<(usize, f64)> = (0..m).map(|p| (j * m + p, 1.0)).collect();
+            constraints.push(LinearConstraint::eq(terms, 1.0));
+        }
+
+        // Load constraints: for each processor p, Σ_j len_j * x_{j,p} - C ≤ 0
+        for p in 0..m {
+            let mut terms: Vec<(usize, f64)> = self
+                .lengths()
+                .iter()
+                .enumerate()
+                .map(|(j, &len)| (j * m + p, len as f64))
+                .collect();
+            terms.push((makespan, -1.0));
+            constraints.push(LinearConstraint::le(terms, 0.0));
+        }
+
+        // Binary bounds: x_{j,p} ≤ 1
+        for var in 0..makespan {
+            constraints.push(LinearConstraint::le(vec![(var, 1.0)], 1.0));
+        }
+
+        ReductionMinMPSToILP {
+            target: ILP::new(
+                num_vars,
+                constraints,
+                vec![(makespan, 1.0)],
+                ObjectiveSense::Minimize,
+            ),
+            num_tasks: n,
+            num_processors: m,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "minimummultiprocessorscheduling_to_ilp",
+        build: || {
+            // 4 tasks [3, 3, 2, 2] on 2 processors: optimal makespan 5
+            let source = MinimumMultiprocessorScheduling::new(vec![3, 3, 2, 2], 2);
+            crate::example_db::specs::rule_example_via_ilp::<_, i32>(source)
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/minimummultiprocessorscheduling_ilp.rs"]
+mod tests;
@@ -2,12 +2,14 @@
 //!
 //! The Set Covering problem can be formulated as a binary ILP:
 //! - Variables: One binary variable per set (0 = not selected, 1 = selected)
-//! - Constraints: For each element e: sum_{j: e in set_j} x_j >= 1 (element must be covered)
+//! - Constraints: For each element e: sum_{j: e in set_j} x_j >= r_e (element must be covered
+//!   r_e times; r_e = 1 unless coverage requirements are set)
 //! - Objective: Minimize the sum of weights of selected sets
 
-use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::algebraic::ILP;
 use crate::models::set::MinimumSetCovering;
 use crate::reduction;
+use crate::rules::ilp_builder::{unit_terms, weighted_terms, IlpBuilder};
 use crate::rules::traits::{ReduceTo, ReductionResult};
 
 /// Result of reducing MinimumSetCovering to ILP.
@@ -48,36 +50,31 @@ impl ReduceTo<ILP<bool>> for MinimumSetCovering<i32> {
     type Result = ReductionSCToILP;
 
     fn reduce_to(&self) -> Self::Result {
-        let num_vars = self.num_sets();
+        let mut ilp = IlpBuilder::new();
+        ilp.binary_vars(self.num_sets());
 
-        // Constraints: For each element e, sum_{j: e in set_j} x_j >= 1
-        // This ensures each element is covered by at least one selected set
-        let constraints: Vec<LinearConstraint> = (0..self.universe_size())
-            .map(|element| {
-                // Find all sets containing this element
-                let terms: Vec<(usize, f64)> = self
-                    .sets()
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, set)| set.contains(&element))
-                    .map(|(j, _)| (j, 1.0))
-                    .collect();
-
-                LinearConstraint::ge(terms, 1.0)
-            })
-            .collect();
+        // Constraints: For each element e, sum_{j: e in set_j} x_j >= r_e
+        // This ensures each element is covered by enough selected sets
+        for element in 0..self.universe_size() {
+            let covering_sets = self
+                .sets()
+                .iter()
+                .enumerate()
+                .filter(|(_, set)| set.contains(&element))
+                .map(|(j, _)| j);
+            ilp.add_ge(
+                format_args!("cover[{element}]"),
+                unit_terms(covering_sets),
+                self.coverage_requirement(element) as f64,
+            );
+        }
 
         // Objective: minimize sum of w_i * x_i (weighted sum of selected sets)
-        let objective: Vec<(usize, f64)> = self
-            .weights_ref()
-            .iter()
-            .enumerate()
-            .map(|(i, &w)| (i, w as f64))
-            .collect();
-
-        let target = ILP::new(num_vars, constraints, objective, ObjectiveSense::Minimize);
+        ilp.minimize(weighted_terms(self.weights_ref()));
 
-        ReductionSCToILP { target }
+        ReductionSCToILP {
+            target: ilp.build(),
+        }
     }
 }
 
@@ -0,0 +1,116 @@
+//! Reduction from MinimumSetCovering to MinimumVertexCover on hypergraphs.
+//!
+//! Set covering is hypergraph vertex cover with the roles of vertices and
+//! edges exchanged: each set becomes a vertex and each element becomes the
+//! hyperedge of the sets containing it. A subcollection covers the universe
+//! iff the corresponding vertices hit every hyperedge.
+
+use crate::models::graph::MinimumVertexCover;
+use crate::models::set::MinimumSetCovering;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::HyperGraph;
+use crate::types::WeightElement;
+
+/// Result of reducing MinimumSetCovering to hypergraph MinimumVertexCover.
+#[derive(Debug, Clone)]
+pub struct ReductionSCToHVC<W> {
+    target: MinimumVertexCover<HyperGraph, W>,
+}
+
+impl<W> ReductionResult for ReductionSCToHVC<W>
+where
+    W: WeightElement + crate::variant::VariantParam,
+{
+    type Source = MinimumSetCovering<W>;
+    type Target = MinimumVertexCover<HyperGraph, W>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Solution extraction: set i in SC corresponds to vertex i in VC.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "num_sets",
+        num_edges = "universe_size",
+    }
+)]
+impl ReduceTo<MinimumVertexCover<HyperGraph, i32>> for MinimumSetCovering<i32> {
+    type Result = ReductionSCToHVC<i32>;
+
+    /// # Panics
+    ///
+    /// Panics on set multicover instances (some coverage requirement above
+    /// 1), which a vertex cover cannot express. Elements with requirement 0
+    /// impose no constraint and get no hyperedge.
+    fn reduce_to(&self) -> Self::Result {
+        let mut edges: Vec<Vec<usize>> = Vec::with_capacity(self.universe_size());
+        for element in 0..self.universe_size() {
+            let requirement = self.coverage_requirement(element);
+            assert!(
+                requirement <= 1,
+                "element {element} must be covered {requirement} times; \
+                 set multicover does not reduce to vertex cover"
+            );
+            if requirement == 0 {
+                continue;
+            }
+            let containing_sets = self
+                .sets()
+                .iter()
+                .enumerate()
+                .filter(|(_, set)| set.contains(&element))
+                .map(|(j, _)| j)
+                .collect();
+            edges.push(containing_sets);
+        }
+
+        let graph = HyperGraph::new(self.num_sets(), edges);
+        let target = MinimumVertexCover::new(graph, self.weights_ref().to_vec());
+
+        ReductionSCToHVC { target }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "minimumsetcovering_to_minimumvertexcover_hypergraph",
+        build: || {
+            // Universe {0..5}; the two disjoint triples {0,1,2} and {3,4,5}
+            // are the unique minimum cover.
+            let source = MinimumSetCovering::<i32>::new(
+                6,
+                vec![
+                    vec![0, 1, 2],
+                    vec![3, 4, 5],
+                    vec![0, 3],
+                    vec![1, 4],
+                    vec![2, 5],
+                ],
+            );
+            crate::example_db::specs::rule_example_with_witness::<
+                _,
+                MinimumVertexCover<HyperGraph, i32>,
+            >(
+                source,
+                SolutionPair {
+                    source_config: vec![1, 1, 0, 0, 0],
+                    target_config: vec![1, 1, 0, 0, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/minimumsetcovering_minimumvertexcover.rs"]
+mod tests;
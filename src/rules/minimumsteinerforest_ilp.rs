@@ -0,0 +1,148 @@
+//! Reduction from MinimumSteinerForest to ILP (Integer Linear Programming).
+//!
+//! Uses one unit-flow commodity per terminal pair:
+//! - Variables: edge selectors `y_e` plus directed flow variables `f^k_(u,v)`
+//!   for each terminal pair `k`
+//! - Constraints: flow conservation sending one unit from `s_k` to `t_k`, and
+//!   capacity linking `f^k_(u,v) <= y_e`, so every s_k-t_k cut is crossed by a
+//!   selected edge
+//! - Objective: minimize the total weight of selected edges
+//!
+//! With strictly positive weights an optimal selection is acyclic, hence a
+//! Steiner forest.
+
+use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::graph::MinimumSteinerForest;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+
+/// Result of reducing MinimumSteinerForest to ILP.
+///
+/// Variable layout (all binary):
+/// - `y_e` for each undirected source edge `e` (indices `0..m`)
+/// - `f^k_(u,v)` and `f^k_(v,u)` for each terminal pair `k` and each source edge
+///   `(u, v)` (indices `m..m + 2mk`)
+#[derive(Debug, Clone)]
+pub struct ReductionSteinerForestToILP {
+    target: ILP<bool>,
+    num_edges: usize,
+}
+
+impl ReductionResult for ReductionSteinerForestToILP {
+    type Source = MinimumSteinerForest<SimpleGraph, i32>;
+    type Target = ILP<bool>;
+
+    fn target_problem(&self) -> &ILP<bool> {
+        &self.target
+    }
+
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution[..self.num_edges].to_vec()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vars = "num_edges + 2 * num_edges * num_terminal_pairs",
+        num_constraints = "num_vertices * num_terminal_pairs + 2 * num_edges * num_terminal_pairs",
+    }
+)]
+impl ReduceTo<ILP<bool>> for MinimumSteinerForest<SimpleGraph, i32> {
+    type Result = ReductionSteinerForestToILP;
+
+    fn reduce_to(&self) -> Self::Result {
+        assert!(
+            self.edge_weights().iter().all(|&weight| weight > 0),
+            "MinimumSteinerForest -> ILP requires strictly positive edge weights (zero-weight edges should be contracted beforehand)"
+        );
+
+        let n = self.num_vertices();
+        let m = self.num_edges();
+        let pairs = self.terminal_pairs();
+        let edges = self.graph().edges();
+        let num_vars = m + 2 * m * pairs.len();
+        let num_constraints = n * pairs.len() + 2 * m * pairs.len();
+        let mut constraints = Vec::with_capacity(num_constraints);
+
+        let edge_var = |edge_idx: usize| edge_idx;
+        let flow_var = |pair_idx: usize, edge_idx: usize, dir: usize| -> usize {
+            m + pair_idx * 2 * m + 2 * edge_idx + dir
+        };
+
+        for (pair_idx, &(source, sink)) in pairs.iter().enumerate() {
+            for vertex in 0..n {
+                let mut terms = Vec::new();
+                for (edge_idx, &(u, v)) in edges.iter().enumerate() {
+                    if v == vertex {
+                        terms.push((flow_var(pair_idx, edge_idx, 0), 1.0));
+                        terms.push((flow_var(pair_idx, edge_idx, 1), -1.0));
+                    }
+                    if u == vertex {
+                        terms.push((flow_var(pair_idx, edge_idx, 0), -1.0));
+                        terms.push((flow_var(pair_idx, edge_idx, 1), 1.0));
+                    }
+                }
+
+                let rhs = if vertex == source {
+                    -1.0
+                } else if vertex == sink {
+                    1.0
+                } else {
+                    0.0
+                };
+                constraints.push(LinearConstraint::eq(terms, rhs));
+            }
+        }
+
+        for pair_idx in 0..pairs.len() {
+            for edge_idx in 0..m {
+                let selector = edge_var(edge_idx);
+                constraints.push(LinearConstraint::le(
+                    vec![(flow_var(pair_idx, edge_idx, 0), 1.0), (selector, -1.0)],
+                    0.0,
+                ));
+                constraints.push(LinearConstraint::le(
+                    vec![(flow_var(pair_idx, edge_idx, 1), 1.0), (selector, -1.0)],
+                    0.0,
+                ));
+            }
+        }
+
+        let objective: Vec<(usize, f64)> = self
+            .edge_weights()
+            .iter()
+            .enumerate()
+            .map(|(edge_idx, &weight)| (edge_var(edge_idx), weight as f64))
+            .collect();
+
+        let target = ILP::new(num_vars, constraints, objective, ObjectiveSense::Minimize);
+
+        ReductionSteinerForestToILP {
+            target,
+            num_edges: m,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "minimumsteinerforest_to_ilp",
+        build: || {
+            let source = MinimumSteinerForest::new(
+                SimpleGraph::new(
+                    6,
+                    vec![(0, 1), (1, 2), (0, 3), (2, 5), (3, 4), (4, 5), (1, 4)],
+                ),
+                vec![1, 1, 4, 4, 1, 1, 3],
+                vec![(0, 2), (3, 5)],
+            );
+            crate::example_db::specs::rule_example_via_ilp::<_, bool>(source)
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/minimumsteinerforest_ilp.rs"]
+mod tests;
@@ -0,0 +1,122 @@
+//! König reduction from unit-weight MinimumVertexCover to MaximumMatching on
+//! bipartite graphs.
+//!
+//! By König's theorem, the minimum vertex cover of a bipartite graph has the
+//! same size as a maximum matching, and a minimum cover can be read off a
+//! maximum matching with alternating paths. The identity does not hold on
+//! graphs with odd cycles (on `K3`, `mvc = 2` but `mm = 1`), so this is a
+//! helper with a fallible constructor rather than a registered reduction edge.
+
+use crate::error::{ProblemError, Result};
+use crate::models::graph::{MaximumMatching, MinimumVertexCover};
+use crate::rules::traits::ReductionResult;
+use crate::topology::{Graph, SimpleGraph};
+use crate::types::One;
+use std::collections::VecDeque;
+
+/// Result of reducing bipartite MinimumVertexCover to MaximumMatching.
+#[derive(Debug, Clone)]
+pub struct ReductionVCToMatching {
+    target: MaximumMatching<SimpleGraph, i32>,
+    /// Bipartition side of each vertex; `true` marks the right part.
+    is_right: Vec<bool>,
+}
+
+impl ReductionVCToMatching {
+    /// Reduce a unit-weight vertex cover instance to maximum matching.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProblemError::InvalidProblem`] if the graph is not bipartite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::models::graph::MinimumVertexCover;
+    /// use problemreductions::rules::{ReductionResult, ReductionVCToMatching};
+    /// use problemreductions::topology::SimpleGraph;
+    /// use problemreductions::types::One;
+    /// use problemreductions::BruteForce;
+    ///
+    /// // K2,3 with parts {0, 1} and {2, 3, 4}
+    /// let graph = SimpleGraph::new(5, vec![(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4)]);
+    /// let source = MinimumVertexCover::new(graph, vec![One; 5]);
+    /// let reduction = ReductionVCToMatching::try_new(&source).unwrap();
+    ///
+    /// let matching = BruteForce::new().find_witness(reduction.target_problem()).unwrap();
+    /// let cover = reduction.extract_solution(&matching);
+    /// assert_eq!(cover, vec![1, 1, 0, 0, 0]);
+    /// ```
+    pub fn try_new(source: &MinimumVertexCover<SimpleGraph, One>) -> Result<Self> {
+        let graph = source.graph();
+        let is_right = graph.two_coloring().map_err(|(u, v)| {
+            ProblemError::InvalidProblem(format!(
+                "König reduction requires a bipartite graph: edge ({u}, {v}) closes an odd cycle"
+            ))
+        })?;
+        Ok(Self {
+            target: MaximumMatching::unit_weights(graph.clone()),
+            is_right,
+        })
+    }
+}
+
+impl ReductionResult for ReductionVCToMatching {
+    type Source = MinimumVertexCover<SimpleGraph, One>;
+    type Target = MaximumMatching<SimpleGraph, i32>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Recover a vertex cover from a matching by König's construction.
+    ///
+    /// Let Z be the vertices reachable from unmatched left vertices along
+    /// alternating paths (non-matching edges left to right, matching edges
+    /// right to left). Then (L \ Z) ∪ (R ∩ Z) covers every edge, and has
+    /// exactly one endpoint per matching edge when the matching is maximum.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let graph = self.target.graph();
+        let n = graph.num_vertices();
+        let mut mate: Vec<Option<usize>> = vec![None; n];
+        for ((u, v), _) in graph
+            .edges()
+            .into_iter()
+            .zip(target_solution)
+            .filter(|(_, &selected)| selected == 1)
+        {
+            mate[u] = Some(v);
+            mate[v] = Some(u);
+        }
+
+        let mut reached = vec![false; n];
+        let mut queue: VecDeque<usize> = (0..n)
+            .filter(|&v| !self.is_right[v] && mate[v].is_none())
+            .collect();
+        for &v in &queue {
+            reached[v] = true;
+        }
+        while let Some(u) = queue.pop_front() {
+            for v in graph.neighbors(u) {
+                // Left vertices leave by non-matching edges; right vertices by their matching edge.
+                let alternating = if self.is_right[u] {
+                    mate[u] == Some(v)
+                } else {
+                    mate[u] != Some(v)
+                };
+                if alternating && !reached[v] {
+                    reached[v] = true;
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        (0..n)
+            .map(|v| usize::from(reached[v] == self.is_right[v]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/minimumvertexcover_maximummatching.rs"]
+mod tests;
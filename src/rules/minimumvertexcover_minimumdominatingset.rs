@@ -0,0 +1,128 @@
+//! Reduction from MinimumVertexCover to MinimumDominatingSet.
+//!
+//! Every edge `{u, v}` gets a new vertex `w_uv` adjacent to both `u` and `v`;
+//! the original edge is kept, so each edge becomes a triangle. Dominating
+//! `w_uv` requires `u`, `v` or `w_uv` itself, and the latter can always be
+//! traded for an endpoint, so dominating sets of the new graph correspond to
+//! vertex covers of the old one.
+//!
+//! Isolated vertices need no cover but can only dominate themselves, so they
+//! belong to every dominating set: the target optimum equals the source
+//! optimum plus the total weight of the isolated vertices. Weights are
+//! assumed positive.
+
+use crate::models::graph::{MinimumDominatingSet, MinimumVertexCover};
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+
+/// Result of reducing MinimumVertexCover to MinimumDominatingSet.
+///
+/// Target vertices `0..n` are the source vertices; vertex `n + i` is the
+/// subdivision vertex of the `i`-th source edge.
+#[derive(Debug, Clone)]
+pub struct ReductionVCToDS {
+    target: MinimumDominatingSet<SimpleGraph, i32>,
+    /// Source edges, indexed like the subdivision vertices.
+    edges: Vec<(usize, usize)>,
+    /// Source vertex weights, used to pick the lighter endpoint.
+    weights: Vec<i32>,
+}
+
+impl ReductionResult for ReductionVCToDS {
+    type Source = MinimumVertexCover<SimpleGraph, i32>;
+    type Target = MinimumDominatingSet<SimpleGraph, i32>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Keep the selected source vertices, replace each selected subdivision
+    /// vertex whose edge is still uncovered by its lighter endpoint, and drop
+    /// isolated vertices, which the cover does not need.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let n = self.weights.len();
+        let mut cover = target_solution[..n].to_vec();
+        let mut has_edge = vec![false; n];
+        for (i, &(u, v)) in self.edges.iter().enumerate() {
+            has_edge[u] = true;
+            has_edge[v] = true;
+            if target_solution[n + i] == 1 && cover[u] == 0 && cover[v] == 0 {
+                let lighter = if self.weights[v] < self.weights[u] {
+                    v
+                } else {
+                    u
+                };
+                cover[lighter] = 1;
+            }
+        }
+        for (selected, _) in cover.iter_mut().zip(&has_edge).filter(|(_, &h)| !h) {
+            *selected = 0;
+        }
+        cover
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "num_vertices + num_edges",
+        num_edges = "3 * num_edges",
+    }
+)]
+impl ReduceTo<MinimumDominatingSet<SimpleGraph, i32>> for MinimumVertexCover<SimpleGraph, i32> {
+    type Result = ReductionVCToDS;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.graph().num_vertices();
+        let edges = self.graph().edges();
+        let weights = self.weights().to_vec();
+
+        let mut target_edges = edges.clone();
+        let mut target_weights = weights.clone();
+        for (i, &(u, v)) in edges.iter().enumerate() {
+            target_edges.push((u, n + i));
+            target_edges.push((v, n + i));
+            // Never cheaper than the lighter endpoint, so trading the
+            // subdivision vertex for it cannot increase the weight.
+            target_weights.push(weights[u].max(weights[v]));
+        }
+
+        let target = MinimumDominatingSet::new(
+            SimpleGraph::new(n + edges.len(), target_edges),
+            target_weights,
+        );
+        ReductionVCToDS {
+            target,
+            edges,
+            weights,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "minimumvertexcover_to_minimumdominatingset",
+        build: || {
+            // Bull graph: triangle 0-1-2 with pendants 3 (at 1) and 4 (at 2).
+            let (n, edges) = crate::topology::small_graphs::bull();
+            let source = MinimumVertexCover::new(SimpleGraph::new(n, edges), vec![1i32; n]);
+            crate::example_db::specs::rule_example_with_witness::<
+                _,
+                MinimumDominatingSet<SimpleGraph, i32>,
+            >(
+                source,
+                SolutionPair {
+                    source_config: vec![0, 1, 1, 0, 0],
+                    target_config: vec![0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/minimumvertexcover_minimumdominatingset.rs"]
+mod tests;
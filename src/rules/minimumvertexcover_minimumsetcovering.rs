@@ -2,12 +2,14 @@
 //!
 //! Each vertex becomes a set containing the edges it covers.
 //! The universe is the set of all edges (labeled 0 to num_edges-1).
+//! The same construction applies verbatim to hypergraphs, where the
+//! universe is the set of hyperedges.
 
 use crate::models::graph::MinimumVertexCover;
 use crate::models::set::MinimumSetCovering;
 use crate::reduction;
 use crate::rules::traits::{ReduceTo, ReductionResult};
-use crate::topology::{Graph, SimpleGraph};
+use crate::topology::{Graph, HyperGraph, SimpleGraph};
 use crate::types::WeightElement;
 
 /// Result of reducing MinimumVertexCover to MinimumSetCovering.
@@ -67,24 +69,103 @@ impl ReduceTo<MinimumSetCovering<i32>> for MinimumVertexCover<SimpleGraph, i32>
     }
 }
 
+/// Result of reducing hypergraph MinimumVertexCover to MinimumSetCovering.
+#[derive(Debug, Clone)]
+pub struct ReductionHVCToSC<W> {
+    target: MinimumSetCovering<W>,
+}
+
+impl<W> ReductionResult for ReductionHVCToSC<W>
+where
+    W: WeightElement + crate::variant::VariantParam,
+{
+    type Source = MinimumVertexCover<HyperGraph, W>;
+    type Target = MinimumSetCovering<W>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Solution extraction: vertex i in VC corresponds to set i in SC.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_sets = "num_vertices",
+        universe_size = "num_edges",
+    }
+)]
+impl ReduceTo<MinimumSetCovering<i32>> for MinimumVertexCover<HyperGraph, i32> {
+    type Result = ReductionHVCToSC<i32>;
+
+    fn reduce_to(&self) -> Self::Result {
+        // Vertex v becomes the set of hyperedges containing it.
+        let mut sets: Vec<Vec<usize>> = vec![Vec::new(); self.num_vertices()];
+        for (edge_idx, edge) in self.graph().edges().iter().enumerate() {
+            for &v in edge {
+                if sets[v].last() != Some(&edge_idx) {
+                    sets[v].push(edge_idx);
+                }
+            }
+        }
+
+        let target =
+            MinimumSetCovering::with_weights(self.num_edges(), sets, self.weights().to_vec());
+
+        ReductionHVCToSC { target }
+    }
+}
+
 #[cfg(feature = "example-db")]
 pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
     use crate::export::SolutionPair;
 
-    vec![crate::example_db::specs::RuleExampleSpec {
-        id: "minimumvertexcover_to_minimumsetcovering",
-        build: || {
-            let (n, edges) = crate::topology::small_graphs::petersen();
-            let source = MinimumVertexCover::new(SimpleGraph::new(n, edges), vec![1i32; 10]);
-            crate::example_db::specs::rule_example_with_witness::<_, MinimumSetCovering<i32>>(
-                source,
-                SolutionPair {
-                    source_config: vec![0, 1, 1, 0, 1, 1, 0, 0, 1, 1],
-                    target_config: vec![0, 1, 1, 0, 1, 1, 0, 0, 1, 1],
-                },
-            )
+    vec![
+        crate::example_db::specs::RuleExampleSpec {
+            id: "minimumvertexcover_to_minimumsetcovering",
+            build: || {
+                let (n, edges) = crate::topology::small_graphs::petersen();
+                let source = MinimumVertexCover::new(SimpleGraph::new(n, edges), vec![1i32; 10]);
+                crate::example_db::specs::rule_example_with_witness::<_, MinimumSetCovering<i32>>(
+                    source,
+                    SolutionPair {
+                        source_config: vec![0, 1, 1, 0, 1, 1, 0, 0, 1, 1],
+                        target_config: vec![0, 1, 1, 0, 1, 1, 0, 0, 1, 1],
+                    },
+                )
+            },
+        },
+        crate::example_db::specs::RuleExampleSpec {
+            id: "minimumvertexcover_hypergraph_to_minimumsetcovering",
+            build: || {
+                // Fano plane: any two of its 7 lines meet, so each line is a
+                // minimum cover of size 3.
+                let graph = HyperGraph::new(
+                    7,
+                    vec![
+                        vec![0, 1, 2],
+                        vec![0, 3, 4],
+                        vec![0, 5, 6],
+                        vec![1, 3, 5],
+                        vec![1, 4, 6],
+                        vec![2, 3, 6],
+                        vec![2, 4, 5],
+                    ],
+                );
+                let source = MinimumVertexCover::new(graph, vec![1i32; 7]);
+                crate::example_db::specs::rule_example_with_witness::<_, MinimumSetCovering<i32>>(
+                    source,
+                    SolutionPair {
+                        source_config: vec![1, 1, 1, 0, 0, 0, 0],
+                        target_config: vec![1, 1, 1, 0, 0, 0, 0],
+                    },
+                )
+            },
         },
-    }]
+    ]
 }
 
 #[cfg(test)]
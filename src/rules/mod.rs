@@ -1,6 +1,7 @@
 //! Reduction rules between NP-hard problems.
 
 pub mod analysis;
+pub mod audit;
 pub mod cost;
 pub mod registry;
 pub use cost::{
@@ -43,11 +44,15 @@ pub(crate) mod hamiltonianpathbetweentwovertices_longestpath;
 pub(crate) mod ilp_i32_ilp_bool;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod integerknapsack_ilp;
+mod kcenter_casts;
 pub(crate) mod kclique_balancedcompletebipartitesubgraph;
 pub(crate) mod kclique_conjunctivebooleanquery;
 pub(crate) mod kclique_subgraphisomorphism;
 mod kcoloring_casts;
 pub(crate) mod kcoloring_clustering;
+pub(crate) mod kcoloring_ksatisfiability;
+pub(crate) mod kcoloring_maximumindependentset;
+pub(crate) mod kcoloring_minimumcliquecover;
 pub(crate) mod kcoloring_partitionintocliques;
 pub(crate) mod kcoloring_twodimensionalconsecutivesets;
 mod knapsack_qubo;
@@ -71,24 +76,37 @@ pub(crate) mod ksatisfiability_simultaneousincongruences;
 pub(crate) mod ksatisfiability_subsetsum;
 pub(crate) mod ksatisfiability_timetabledesign;
 pub(crate) mod longestcommonsubsequence_maximumindependentset;
+pub(crate) mod maxbisection_qubo;
+mod maxcut_casts;
 pub(crate) mod maxcut_minimumcutintoboundedsets;
+pub(crate) mod maximalis_satisfiability;
+mod maximum2satisfiability_casts;
 pub(crate) mod maximum2satisfiability_maxcut;
+pub(crate) mod maximumacyclicsubgraph_minimumfeedbackarcset;
 pub(crate) mod maximumclique_maximumindependentset;
 mod maximumindependentset_casts;
 mod maximumindependentset_gridgraph;
 pub(crate) mod maximumindependentset_integralflowbundles;
+pub(crate) mod maximumindependentset_maxcut;
 pub(crate) mod maximumindependentset_maximumclique;
 pub(crate) mod maximumindependentset_maximumsetpacking;
 mod maximumindependentset_triangular;
+mod maximummatching_casts;
 pub(crate) mod maximummatching_maximumsetpacking;
 mod maximumsetpacking_casts;
 pub(crate) mod maximumsetpacking_qubo;
+mod minimumdominatingset_casts;
+pub(crate) mod minimumedgedominatingset_minimumdominatingset;
 pub(crate) mod minimumfeedbackarcset_maximumlikelihoodranking;
 pub(crate) mod minimumfeedbackvertexset_minimumcodegenerationunlimitedregisters;
 pub(crate) mod minimummultiwaycut_qubo;
+pub(crate) mod minimumsetcovering_minimumvertexcover;
+mod minimumvertexcover_casts;
 pub(crate) mod minimumvertexcover_ensemblecomputation;
 pub(crate) mod minimumvertexcover_longestcommonsubsequence;
 pub(crate) mod minimumvertexcover_maximumindependentset;
+pub(crate) mod minimumvertexcover_maximummatching;
+pub(crate) mod minimumvertexcover_minimumdominatingset;
 pub(crate) mod minimumvertexcover_minimumfeedbackarcset;
 pub(crate) mod minimumvertexcover_minimumfeedbackvertexset;
 pub(crate) mod minimumvertexcover_minimumhittingset;
@@ -99,9 +117,11 @@ pub(crate) mod naesatisfiability_maxcut;
 pub(crate) mod naesatisfiability_partitionintoperfectmatchings;
 pub(crate) mod naesatisfiability_setsplitting;
 pub(crate) mod paintshop_qubo;
+pub(crate) mod paintshop_spinglass;
 pub(crate) mod partition_binpacking;
 pub(crate) mod partition_cosineproductintegration;
 pub(crate) mod partition_knapsack;
+pub(crate) mod partition_minimummultiprocessorscheduling;
 pub(crate) mod partition_multiprocessorscheduling;
 pub(crate) mod partition_openshopscheduling;
 pub(crate) mod partition_productionplanning;
@@ -109,12 +129,16 @@ pub(crate) mod partition_sequencingtominimizetardytaskweight;
 pub(crate) mod partition_subsetsum;
 pub(crate) mod partitionintocliques_minimumcoveringbycliques;
 pub(crate) mod partitionintopathsoflength2_boundedcomponentspanningforest;
+pub(crate) mod partitionintotriangles_exactcoverby3sets;
+pub(crate) mod pseudoboolean_qubo;
 pub(crate) mod rootedtreearrangement_rootedtreestorageassignment;
 pub(crate) mod sat_circuitsat;
 pub(crate) mod sat_coloring;
 pub(crate) mod sat_ksat;
+pub(crate) mod sat_maximumclique;
 pub(crate) mod sat_maximumindependentset;
 pub(crate) mod sat_minimumdominatingset;
+pub(crate) mod sat_minimumvertexcover;
 pub(crate) mod satisfiability_integralflowhomologousarcs;
 pub(crate) mod satisfiability_maximum2satisfiability;
 pub(crate) mod satisfiability_naesatisfiability;
@@ -134,6 +158,8 @@ pub(crate) mod threepartition_resourceconstrainedscheduling;
 pub(crate) mod threepartition_sequencingwithreleasetimesanddeadlines;
 mod traits;
 pub(crate) mod travelingsalesman_qubo;
+pub(crate) mod xorsatisfiability_polyspinglass;
+pub(crate) mod xorsatisfiability_satisfiability;
 
 pub mod unitdiskmapping;
 
@@ -156,6 +182,8 @@ pub(crate) mod capacityassignment_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod circuit_ilp;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod clusterediting_ilp;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod clustering_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod coloring_ilp;
@@ -190,6 +218,8 @@ pub(crate) mod hamiltonianpath_ilp;
 #[cfg(feature = "ilp-solver")]
 mod ilp_bool_ilp_i32;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod ilp_builder;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod ilp_helpers;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod ilp_qubo;
@@ -202,10 +232,14 @@ pub(crate) mod integralflowwithmultipliers_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod isomorphicspanningtree_ilp;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod kcenter_ilp;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod kclique_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod knapsack_ilp;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod ktrackintervalscheduling_ilp;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod lengthboundeddisjointpaths_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod longestcircuit_ilp;
@@ -218,6 +252,8 @@ pub(crate) mod maximalis_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod maximum2satisfiability_ilp;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod maximumbalancedbiclique_ilp;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod maximumclique_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod maximumdomaticnumber_ilp;
@@ -240,6 +276,8 @@ pub(crate) mod minimumdominatingset_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod minimumedgecostflow_ilp;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod minimumedgedominatingset_ilp;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod minimumexternalmacrodatacompression_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod minimumfaultdetectiontestset_ilp;
@@ -260,10 +298,14 @@ pub(crate) mod minimummaximalmatching_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod minimummetricdimension_ilp;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod minimummultiprocessorscheduling_ilp;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod minimummultiwaycut_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod minimumsetcovering_ilp;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod minimumsteinerforest_ilp;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod minimumsummulticenter_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod minimumtardinesssequencing_ilp;
@@ -304,10 +346,14 @@ pub(crate) mod precedenceconstrainedscheduling_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod preemptivescheduling_ilp;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod pseudoboolean_ilp;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod quadraticassignment_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod qubo_ilp;
 #[cfg(feature = "ilp-solver")]
+pub(crate) mod rectanglepacking_ilp;
+#[cfg(feature = "ilp-solver")]
 pub(crate) mod rectilinearpicturecompression_ilp;
 #[cfg(feature = "ilp-solver")]
 pub(crate) mod registersufficiency_ilp;
@@ -369,9 +415,11 @@ pub(crate) mod undirectedflowlowerbounds_ilp;
 pub(crate) mod undirectedtwocommodityintegralflow_ilp;
 
 pub use graph::{
-    AggregateReductionChain, NeighborInfo, NeighborTree, ReductionChain, ReductionEdgeInfo,
-    ReductionGraph, ReductionMode, ReductionPath, ReductionStep, TraversalFlow,
+    AggregateReductionChain, ClusterMember, CondensedGraph, NeighborInfo, NeighborTree,
+    ReductionChain, ReductionCluster, ReductionEdgeInfo, ReductionGraph, ReductionMode,
+    ReductionPath, ReductionStep, TraversalFlow,
 };
+pub use minimumvertexcover_maximummatching::ReductionVCToMatching;
 pub use traits::{
     AggregateReductionResult, ReduceTo, ReduceToAggregate, ReductionAutoCast, ReductionResult,
 };
@@ -415,6 +463,9 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
     specs.extend(kclique_conjunctivebooleanquery::canonical_rule_example_specs());
     specs.extend(kclique_subgraphisomorphism::canonical_rule_example_specs());
     specs.extend(kcoloring_clustering::canonical_rule_example_specs());
+    specs.extend(kcoloring_ksatisfiability::canonical_rule_example_specs());
+    specs.extend(kcoloring_maximumindependentset::canonical_rule_example_specs());
+    specs.extend(kcoloring_minimumcliquecover::canonical_rule_example_specs());
     specs.extend(kcoloring_partitionintocliques::canonical_rule_example_specs());
     specs.extend(kcoloring_twodimensionalconsecutivesets::canonical_rule_example_specs());
     specs.extend(knapsack_qubo::canonical_rule_example_specs());
@@ -440,12 +491,15 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
     specs.extend(maximum2satisfiability_maxcut::canonical_rule_example_specs());
     specs.extend(maximumclique_maximumindependentset::canonical_rule_example_specs());
     specs.extend(maximumindependentset_integralflowbundles::canonical_rule_example_specs());
+    specs.extend(maximumindependentset_maxcut::canonical_rule_example_specs());
     specs.extend(maximumindependentset_maximumclique::canonical_rule_example_specs());
     specs.extend(maximumindependentset_maximumsetpacking::canonical_rule_example_specs());
     specs.extend(maximummatching_maximumsetpacking::canonical_rule_example_specs());
     specs.extend(maximumsetpacking_qubo::canonical_rule_example_specs());
+    specs.extend(minimumedgedominatingset_minimumdominatingset::canonical_rule_example_specs());
     specs.extend(minimummultiwaycut_qubo::canonical_rule_example_specs());
     specs.extend(paintshop_qubo::canonical_rule_example_specs());
+    specs.extend(paintshop_spinglass::canonical_rule_example_specs());
     specs.extend(partition_cosineproductintegration::canonical_rule_example_specs());
     specs.extend(partition_knapsack::canonical_rule_example_specs());
     specs.extend(partition_openshopscheduling::canonical_rule_example_specs());
@@ -454,14 +508,18 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
     specs.extend(
         partitionintopathsoflength2_boundedcomponentspanningforest::canonical_rule_example_specs(),
     );
+    specs.extend(partition_minimummultiprocessorscheduling::canonical_rule_example_specs());
     specs.extend(partition_multiprocessorscheduling::canonical_rule_example_specs());
     specs.extend(partitionintocliques_minimumcoveringbycliques::canonical_rule_example_specs());
+    specs.extend(partitionintotriangles_exactcoverby3sets::canonical_rule_example_specs());
     specs.extend(partition_subsetsum::canonical_rule_example_specs());
+    specs.extend(pseudoboolean_qubo::canonical_rule_example_specs());
     specs.extend(rootedtreearrangement_rootedtreestorageassignment::canonical_rule_example_specs());
     specs.extend(naesatisfiability_maxcut::canonical_rule_example_specs());
     specs.extend(naesatisfiability_partitionintoperfectmatchings::canonical_rule_example_specs());
     specs.extend(satisfiability_maximum2satisfiability::canonical_rule_example_specs());
     specs.extend(exactcoverby3sets_maximumsetpacking::canonical_rule_example_specs());
+    specs.extend(maxbisection_qubo::canonical_rule_example_specs());
     specs.extend(maxcut_minimumcutintoboundedsets::canonical_rule_example_specs());
     specs.extend(partition_binpacking::canonical_rule_example_specs());
     specs.extend(threedimensionalmatching_threepartition::canonical_rule_example_specs());
@@ -471,16 +529,19 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
     );
     specs.extend(minimumvertexcover_ensemblecomputation::canonical_rule_example_specs());
     specs.extend(minimumfeedbackarcset_maximumlikelihoodranking::canonical_rule_example_specs());
+    specs.extend(maximumacyclicsubgraph_minimumfeedbackarcset::canonical_rule_example_specs());
     specs.extend(
         minimumfeedbackvertexset_minimumcodegenerationunlimitedregisters::canonical_rule_example_specs(),
     );
     specs.extend(minimumvertexcover_longestcommonsubsequence::canonical_rule_example_specs());
     specs.extend(minimumvertexcover_maximumindependentset::canonical_rule_example_specs());
     specs.extend(minimumvertexcover_minimummaximalmatching::canonical_rule_example_specs());
+    specs.extend(minimumvertexcover_minimumdominatingset::canonical_rule_example_specs());
     specs.extend(minimumvertexcover_minimumfeedbackarcset::canonical_rule_example_specs());
     specs.extend(minimumvertexcover_minimumfeedbackvertexset::canonical_rule_example_specs());
     specs.extend(minimumvertexcover_minimumhittingset::canonical_rule_example_specs());
     specs.extend(minimumvertexcover_minimumsetcovering::canonical_rule_example_specs());
+    specs.extend(minimumsetcovering_minimumvertexcover::canonical_rule_example_specs());
     specs.extend(minimumvertexcover_minimumweightandorgraph::canonical_rule_example_specs());
     specs.extend(naesatisfiability_setsplitting::canonical_rule_example_specs());
     specs.extend(setsplitting_betweenness::canonical_rule_example_specs());
@@ -489,8 +550,10 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
     specs.extend(sat_circuitsat::canonical_rule_example_specs());
     specs.extend(sat_coloring::canonical_rule_example_specs());
     specs.extend(sat_ksat::canonical_rule_example_specs());
+    specs.extend(sat_maximumclique::canonical_rule_example_specs());
     specs.extend(sat_maximumindependentset::canonical_rule_example_specs());
     specs.extend(sat_minimumdominatingset::canonical_rule_example_specs());
+    specs.extend(sat_minimumvertexcover::canonical_rule_example_specs());
     specs.extend(satisfiability_nontautology::canonical_rule_example_specs());
     specs.extend(spinglass_maxcut::canonical_rule_example_specs());
     specs.extend(spinglass_qubo::canonical_rule_example_specs());
@@ -499,6 +562,8 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
     specs.extend(subsetsum_integerexpressionmembership::canonical_rule_example_specs());
     specs.extend(subsetsum_partition::canonical_rule_example_specs());
     specs.extend(travelingsalesman_qubo::canonical_rule_example_specs());
+    specs.extend(xorsatisfiability_polyspinglass::canonical_rule_example_specs());
+    specs.extend(xorsatisfiability_satisfiability::canonical_rule_example_specs());
     specs.extend(
         crate::models::graph::minimum_vertex_cover::decision_canonical_rule_example_specs(),
     );
@@ -516,6 +581,7 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
         specs.extend(boundedcomponentspanningforest_ilp::canonical_rule_example_specs());
         specs.extend(capacityassignment_ilp::canonical_rule_example_specs());
         specs.extend(circuit_ilp::canonical_rule_example_specs());
+        specs.extend(clusterediting_ilp::canonical_rule_example_specs());
         specs.extend(clustering_ilp::canonical_rule_example_specs());
         specs.extend(coloring_ilp::canonical_rule_example_specs());
         specs.extend(consecutiveblockminimization_ilp::canonical_rule_example_specs());
@@ -537,8 +603,10 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
         specs.extend(integralflowhomologousarcs_ilp::canonical_rule_example_specs());
         specs.extend(integralflowwithmultipliers_ilp::canonical_rule_example_specs());
         specs.extend(isomorphicspanningtree_ilp::canonical_rule_example_specs());
+        specs.extend(kcenter_ilp::canonical_rule_example_specs());
         specs.extend(kclique_ilp::canonical_rule_example_specs());
         specs.extend(knapsack_ilp::canonical_rule_example_specs());
+        specs.extend(ktrackintervalscheduling_ilp::canonical_rule_example_specs());
         specs.extend(maximumlikelihoodranking_ilp::canonical_rule_example_specs());
         specs.extend(lengthboundeddisjointpaths_ilp::canonical_rule_example_specs());
         specs.extend(longestcircuit_ilp::canonical_rule_example_specs());
@@ -546,6 +614,7 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
         specs.extend(longestpath_ilp::canonical_rule_example_specs());
         specs.extend(maximalis_ilp::canonical_rule_example_specs());
         specs.extend(maximum2satisfiability_ilp::canonical_rule_example_specs());
+        specs.extend(maximumbalancedbiclique_ilp::canonical_rule_example_specs());
         specs.extend(maximumclique_ilp::canonical_rule_example_specs());
         specs.extend(maximumdomaticnumber_ilp::canonical_rule_example_specs());
         specs.extend(maximumleafspanningtree_ilp::canonical_rule_example_specs());
@@ -553,7 +622,9 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
         specs.extend(maximumsetpacking_ilp::canonical_rule_example_specs());
         specs.extend(minimumcutintoboundedsets_ilp::canonical_rule_example_specs());
         specs.extend(minimumdominatingset_ilp::canonical_rule_example_specs());
+        specs.extend(minimumedgedominatingset_ilp::canonical_rule_example_specs());
         specs.extend(minimummetricdimension_ilp::canonical_rule_example_specs());
+        specs.extend(minimummultiprocessorscheduling_ilp::canonical_rule_example_specs());
         specs.extend(minimummatrixcover_ilp::canonical_rule_example_specs());
         specs.extend(minimummaximalmatching_ilp::canonical_rule_example_specs());
         specs.extend(minimumcapacitatedspanningtree_ilp::canonical_rule_example_specs());
@@ -568,6 +639,7 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
         specs.extend(minimumhittingset_ilp::canonical_rule_example_specs());
         specs.extend(minimummultiwaycut_ilp::canonical_rule_example_specs());
         specs.extend(minimumsetcovering_ilp::canonical_rule_example_specs());
+        specs.extend(minimumsteinerforest_ilp::canonical_rule_example_specs());
         specs.extend(minimumweightdecoding_ilp::canonical_rule_example_specs());
         specs.extend(minimumtardinesssequencing_ilp::canonical_rule_example_specs());
         specs.extend(minimumsummulticenter_ilp::canonical_rule_example_specs());
@@ -588,8 +660,10 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
         specs.extend(pathconstrainednetworkflow_ilp::canonical_rule_example_specs());
         specs.extend(precedenceconstrainedscheduling_ilp::canonical_rule_example_specs());
         specs.extend(preemptivescheduling_ilp::canonical_rule_example_specs());
+        specs.extend(pseudoboolean_ilp::canonical_rule_example_specs());
         specs.extend(quadraticassignment_ilp::canonical_rule_example_specs());
         specs.extend(qubo_ilp::canonical_rule_example_specs());
+        specs.extend(rectanglepacking_ilp::canonical_rule_example_specs());
         specs.extend(rectilinearpicturecompression_ilp::canonical_rule_example_specs());
         specs.extend(registersufficiency_ilp::canonical_rule_example_specs());
         specs.extend(resourceconstrainedscheduling_ilp::canonical_rule_example_specs());
@@ -673,3 +747,54 @@ macro_rules! impl_variant_reduction {
         }
     };
 }
+
+/// Generates graph-subtype casts for every listed weight type.
+///
+/// Each `Sub => Parent` pair must follow the graph hierarchy: the source
+/// graph is converted with `CastToParent::cast_to_parent`, so a pair that is
+/// not a registered subtype edge fails to compile. The cast is emitted once
+/// per weight, giving the cross product of `weights` and `graphs` as
+/// [`impl_variant_reduction!`] invocations.
+///
+/// # Example
+///
+/// ```text
+/// impl_graph_casts!(
+///     MaximumIndependentSet,
+///     weights: [i32, One],
+///     graphs: [KingsSubgraph => UnitDiskGraph, UnitDiskGraph => SimpleGraph],
+///     fields: [num_vertices, num_edges],
+///     |src, graph| MaximumIndependentSet::new(graph, src.weights().to_vec())
+/// );
+/// ```
+#[macro_export]
+macro_rules! impl_graph_casts {
+    ($problem:ident,
+     weights: [$($weight:ty),+ $(,)?],
+     graphs: $graphs:tt,
+     fields: $fields:tt,
+     |$src:ident, $graph:ident| $body:expr) => {
+        $(
+            $crate::impl_graph_casts!(
+                @weight $problem, $weight, $graphs, $fields, |$src, $graph| $body
+            );
+        )+
+    };
+    (@weight $problem:ident, $weight:ty,
+     [$($sub:ty => $parent:ty),+ $(,)?],
+     $fields:tt,
+     |$src:ident, $graph:ident| $body:expr) => {
+        $(
+            $crate::impl_variant_reduction!(
+                $problem,
+                <$sub, $weight> => <$parent, $weight>,
+                fields: $fields,
+                |$src| {
+                    let $graph: $parent =
+                        $crate::variant::CastToParent::cast_to_parent($src.graph());
+                    $body
+                }
+            );
+        )+
+    };
+}
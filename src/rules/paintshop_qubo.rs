@@ -5,6 +5,13 @@
 //! Adjacent pairs in the sequence contribute to the Q matrix based on their
 //! parity (first/second occurrence).
 //!
+//! The constraint that each car is painted each color exactly once needs no
+//! penalty term. An occurrence-level encoding with variables y1, y2 per car
+//! would add P (y1 + y2 - 1)^2; one variable per car substitutes y2 = 1 - y1,
+//! which makes that penalty identically zero. What remains is exact:
+//! `switches = x^T Q x + c`, where c counts adjacent pairs of different parity
+//! plus adjacent occurrences of the same car.
+//!
 //! Reference: Streif et al., 2021, Physical Review A 104, 012403.
 
 use crate::models::algebraic::QUBO;
@@ -0,0 +1,110 @@
+//! Reduction from PaintShop to SpinGlass.
+//!
+//! One spin per car, s_i = 2 x_i - 1, where x_i is the color of car i's first
+//! occurrence; the second occurrence always gets the opposite color, so the
+//! "both colors exactly once" rule is built into the encoding and needs no
+//! penalty. For an adjacent pair of distinct cars a, b, a switch happens iff
+//! s_a s_b = -1 (same parity) or s_a s_b = +1 (different parity), so
+//! `2 * switches = K + sum J_ab s_a s_b` with J_ab = -1 per same-parity
+//! adjacency and +1 per different-parity adjacency. K counts the adjacent
+//! pairs of distinct cars plus twice the adjacent same-car pairs, which
+//! always switch.
+//!
+//! Reference: Streif et al., 2021, Physical Review A 104, 012403.
+
+use crate::models::graph::SpinGlass;
+use crate::models::misc::PaintShop;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::SimpleGraph;
+use std::collections::BTreeMap;
+
+/// Result of reducing PaintShop to SpinGlass.
+#[derive(Debug, Clone)]
+pub struct ReductionPaintShopToSpinGlass {
+    target: SpinGlass<SimpleGraph, i32>,
+    offset: i32,
+}
+
+impl ReductionPaintShopToSpinGlass {
+    /// The constant K with `2 * switches = K + energy`.
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+}
+
+impl ReductionResult for ReductionPaintShopToSpinGlass {
+    type Source = PaintShop;
+    type Target = SpinGlass<SimpleGraph, i32>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Spin i up (config 1) means car i's first occurrence gets color 1.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+#[reduction(overhead = {
+    num_spins = "num_cars",
+    num_interactions = "num_sequence",
+})]
+impl ReduceTo<SpinGlass<SimpleGraph, i32>> for PaintShop {
+    type Result = ReductionPaintShopToSpinGlass;
+
+    fn reduce_to(&self) -> Self::Result {
+        let seq = self.sequence_indices();
+        let is_first = self.is_first();
+
+        let mut couplings: BTreeMap<(usize, usize), i32> = BTreeMap::new();
+        let mut offset = 0;
+        for pos in 0..seq.len().saturating_sub(1) {
+            let (a, b) = (seq[pos], seq[pos + 1]);
+            if a == b {
+                // Two occurrences of one car always differ in color
+                offset += 2;
+                continue;
+            }
+            offset += 1;
+            let sign = if is_first[pos] == is_first[pos + 1] {
+                -1
+            } else {
+                1
+            };
+            *couplings.entry((a.min(b), a.max(b))).or_insert(0) += sign;
+        }
+        // Opposite adjacencies of the same pair cancel out
+        let interactions = couplings.into_iter().filter(|&(_, j)| j != 0).collect();
+
+        ReductionPaintShopToSpinGlass {
+            target: SpinGlass::without_fields(self.num_cars(), interactions),
+            offset,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "paintshop_to_spinglass",
+        build: || {
+            // Sequence [A, B, C, A, D, B, D, C]: minimum 2 switches
+            let source = PaintShop::new(vec!["A", "B", "C", "A", "D", "B", "D", "C"]);
+            crate::example_db::specs::rule_example_with_witness::<_, SpinGlass<SimpleGraph, i32>>(
+                source,
+                SolutionPair {
+                    source_config: vec![1, 0, 0, 0],
+                    target_config: vec![1, 0, 0, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/paintshop_spinglass.rs"]
+mod tests;
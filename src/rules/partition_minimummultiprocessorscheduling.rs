@@ -0,0 +1,70 @@
+//! Reduction from Partition to MinimumMultiprocessorScheduling.
+//!
+//! Given a Partition instance with sizes A = {a_1, ..., a_n}, construct a
+//! two-processor scheduling instance with one task of length a_i per element.
+//! Every schedule has makespan at least total_sum / 2, with equality exactly
+//! when the two processors carry equal loads, so a valid partition exists iff
+//! the minimum makespan is total_sum / 2.
+//!
+//! Solution extraction is the identity: the processor of each task is the
+//! subset of the corresponding element.
+
+use crate::models::misc::{MinimumMultiprocessorScheduling, Partition};
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+
+/// Result of reducing Partition to MinimumMultiprocessorScheduling.
+#[derive(Debug, Clone)]
+pub struct ReductionPartitionToMinMPS {
+    target: MinimumMultiprocessorScheduling,
+}
+
+impl ReductionResult for ReductionPartitionToMinMPS {
+    type Source = Partition;
+    type Target = MinimumMultiprocessorScheduling;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Solution extraction: identity mapping.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+#[reduction(overhead = {
+    num_tasks = "num_elements",
+})]
+impl ReduceTo<MinimumMultiprocessorScheduling> for Partition {
+    type Result = ReductionPartitionToMinMPS;
+
+    fn reduce_to(&self) -> Self::Result {
+        ReductionPartitionToMinMPS {
+            target: MinimumMultiprocessorScheduling::new(self.sizes().to_vec(), 2),
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "partition_to_minimummultiprocessorscheduling",
+        build: || {
+            // sizes [3, 3, 2, 2, 2], sum=12: {3, 3} against {2, 2, 2} has makespan 6
+            crate::example_db::specs::rule_example_with_witness::<_, MinimumMultiprocessorScheduling>(
+                Partition::new(vec![3, 3, 2, 2, 2]),
+                SolutionPair {
+                    source_config: vec![0, 0, 1, 1, 1],
+                    target_config: vec![0, 0, 1, 1, 1],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/partition_minimummultiprocessorscheduling.rs"]
+mod tests;
@@ -0,0 +1,101 @@
+//! Reduction from PartitionIntoTriangles to ExactCoverBy3Sets.
+//!
+//! Every triangle {u, v, w} of G becomes a 3-element subset of the universe
+//! V. A partition of V into triangles is exactly a choice of pairwise disjoint
+//! triangles covering every vertex, i.e. an exact cover.
+//! Extraction numbers the selected triangles in index order and assigns each
+//! vertex the number of the triangle covering it.
+
+use crate::models::graph::PartitionIntoTriangles;
+use crate::models::set::ExactCoverBy3Sets;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+
+/// Result of reducing PartitionIntoTriangles to ExactCoverBy3Sets.
+#[derive(Debug, Clone)]
+pub struct ReductionPITToX3C {
+    target: ExactCoverBy3Sets,
+}
+
+impl ReductionResult for ReductionPITToX3C {
+    type Source = PartitionIntoTriangles<SimpleGraph>;
+    type Target = ExactCoverBy3Sets;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Vertices outside every selected triangle are left in group 0.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let mut groups = vec![0; self.target.universe_size()];
+        let selected = self
+            .target
+            .subsets()
+            .iter()
+            .zip(target_solution)
+            .filter(|&(_, &chosen)| chosen == 1);
+        for (group, (triangle, _)) in selected.enumerate() {
+            for &v in triangle {
+                groups[v] = group;
+            }
+        }
+        groups
+    }
+}
+
+#[reduction(
+    overhead = {
+        universe_size = "num_vertices",
+        num_subsets = "num_vertices * num_edges",
+    }
+)]
+impl ReduceTo<ExactCoverBy3Sets> for PartitionIntoTriangles<SimpleGraph> {
+    type Result = ReductionPITToX3C;
+
+    fn reduce_to(&self) -> Self::Result {
+        let graph = self.graph();
+        // Each triangle u < v < w is found once, from its lowest edge (u, v).
+        let mut triangles = Vec::new();
+        for (u, v) in graph.edges() {
+            let (u, v) = (u.min(v), u.max(v));
+            for w in v + 1..graph.num_vertices() {
+                if graph.has_edge(u, w) && graph.has_edge(v, w) {
+                    triangles.push([u, v, w]);
+                }
+            }
+        }
+        triangles.sort_unstable();
+
+        ReductionPITToX3C {
+            target: ExactCoverBy3Sets::new(self.num_vertices(), triangles),
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "partitionintotriangles_to_exactcoverby3sets",
+        build: || {
+            // Two triangles joined by the edge (0, 3); triangles [0,1,2], [3,4,5]
+            let source = PartitionIntoTriangles::new(SimpleGraph::new(
+                6,
+                vec![(0, 1), (0, 2), (1, 2), (3, 4), (3, 5), (4, 5), (0, 3)],
+            ));
+            crate::example_db::specs::rule_example_with_witness::<_, ExactCoverBy3Sets>(
+                source,
+                SolutionPair {
+                    source_config: vec![0, 0, 0, 1, 1, 1],
+                    target_config: vec![1, 1],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/partitionintotriangles_exactcoverby3sets.rs"]
+mod tests;
@@ -0,0 +1,85 @@
+//! Reduction from PseudoBoolean to ILP<bool>.
+//!
+//! A pseudo-Boolean instance already is a binary ILP: every constraint and the
+//! (minimized) objective carry over term by term with the integer coefficients
+//! widened to `f64`. Solutions map back unchanged.
+
+use crate::models::algebraic::{LinearConstraint, ObjectiveSense, PseudoBoolean, ILP};
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+
+/// Result of reducing PseudoBoolean to ILP<bool>.
+#[derive(Debug, Clone)]
+pub struct ReductionPBToILP {
+    target: ILP<bool>,
+}
+
+impl ReductionResult for ReductionPBToILP {
+    type Source = PseudoBoolean;
+    type Target = ILP<bool>;
+
+    fn target_problem(&self) -> &ILP<bool> {
+        &self.target
+    }
+
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution.to_vec()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vars = "num_vars",
+        num_constraints = "num_constraints",
+    }
+)]
+impl ReduceTo<ILP<bool>> for PseudoBoolean {
+    type Result = ReductionPBToILP;
+
+    fn reduce_to(&self) -> Self::Result {
+        let widen = |terms: &[(usize, i64)]| -> Vec<(usize, f64)> {
+            terms
+                .iter()
+                .map(|&(var, coef)| (var, coef as f64))
+                .collect()
+        };
+        let constraints = self
+            .constraints()
+            .iter()
+            .map(|c| LinearConstraint::new(widen(&c.terms), c.cmp.into(), c.rhs as f64))
+            .collect();
+
+        ReductionPBToILP {
+            target: ILP::new(
+                self.num_vars(),
+                constraints,
+                widen(self.objective()),
+                ObjectiveSense::Minimize,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::models::algebraic::PseudoBooleanConstraint;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "pseudoboolean_to_ilp",
+        build: || {
+            let source = PseudoBoolean::new(
+                3,
+                vec![(0, 1), (1, 2), (2, 3)],
+                vec![
+                    PseudoBooleanConstraint::ge(vec![(0, 1), (1, 1), (2, 1)], 2),
+                    PseudoBooleanConstraint::le(vec![(0, 1), (1, 1)], 1),
+                ],
+            );
+            crate::example_db::specs::rule_example_via_ilp::<_, bool>(source)
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/pseudoboolean_ilp.rs"]
+mod tests;
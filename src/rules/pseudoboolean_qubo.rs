@@ -0,0 +1,119 @@
+//! Reduction from PseudoBoolean to QUBO.
+//!
+//! Every constraint is brought to the equality form `Σ a_k z_k = b`:
+//! - `Σ a x >= b` becomes `Σ a x - Σ 2^j s_j = b` with `s ∈ 0..=max_lhs - b`,
+//! - `Σ a x <= b` becomes `Σ a x + Σ 2^j s_j = b` with `s ∈ 0..=b - min_lhs`,
+//!
+//! using binary slack bits `s_j` appended after the original variables. The
+//! QUBO minimizes `Σ c_i x_i + P · Σ_constraints (Σ a_k z_k - b)²`. All data is
+//! integral, so a violated constraint costs at least `P`, and
+//! `P = Σ|c_i| + 1` makes every infeasible assignment worse than every
+//! feasible one.
+
+use crate::models::algebraic::{PseudoBoolean, PseudoBooleanComparison, QUBO};
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+
+/// Result of reducing PseudoBoolean to QUBO.
+#[derive(Debug, Clone)]
+pub struct ReductionPBToQUBO {
+    target: QUBO<f64>,
+    num_original_vars: usize,
+}
+
+impl ReductionResult for ReductionPBToQUBO {
+    type Source = PseudoBoolean;
+    type Target = QUBO<f64>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Extract only the original variables (discard slack).
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution[..self.num_original_vars].to_vec()
+    }
+}
+
+#[reduction(overhead = { num_vars = "num_vars + num_slack_bits" })]
+impl ReduceTo<QUBO<f64>> for PseudoBoolean {
+    type Result = ReductionPBToQUBO;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.num_vars();
+        let total = n + self.num_slack_bits();
+        let penalty = (self.objective().iter().map(|&(_, c)| c.abs()).sum::<i64>() + 1) as f64;
+
+        let mut matrix = vec![vec![0.0f64; total]; total];
+        for &(var, coef) in self.objective() {
+            matrix[var][var] += coef as f64;
+        }
+
+        let mut slack_col = n;
+        for constraint in self.constraints() {
+            // Dense row of the equality form over original and slack columns.
+            let mut row = vec![0i64; total];
+            for &(var, coef) in &constraint.terms {
+                row[var] += coef;
+            }
+            let sign = match constraint.cmp {
+                PseudoBooleanComparison::Ge => -1,
+                _ => 1,
+            };
+            let bits =
+                crate::models::algebraic::pseudo_boolean::slack_bits(constraint.slack_range());
+            for j in 0..bits {
+                row[slack_col + j] = sign << j;
+            }
+            slack_col += bits;
+
+            // P · (Σ a_k z_k - b)², dropping the constant P · b² and using z² = z.
+            let b = constraint.rhs as f64;
+            let used: Vec<usize> = (0..total).filter(|&k| row[k] != 0).collect();
+            for (idx, &i) in used.iter().enumerate() {
+                let a_i = row[i] as f64;
+                matrix[i][i] += penalty * (a_i * a_i - 2.0 * b * a_i);
+                for &j in &used[idx + 1..] {
+                    matrix[i][j] += 2.0 * penalty * a_i * row[j] as f64;
+                }
+            }
+        }
+
+        ReductionPBToQUBO {
+            target: QUBO::from_matrix(matrix),
+            num_original_vars: n,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+    use crate::models::algebraic::PseudoBooleanConstraint;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "pseudoboolean_to_qubo",
+        build: || {
+            // x0 + x1 + x2 >= 2 has slack range 1 (one bit); x0 + x1 <= 1 too.
+            let source = PseudoBoolean::new(
+                3,
+                vec![(0, 1), (1, 2), (2, 3)],
+                vec![
+                    PseudoBooleanConstraint::ge(vec![(0, 1), (1, 1), (2, 1)], 2),
+                    PseudoBooleanConstraint::le(vec![(0, 1), (1, 1)], 1),
+                ],
+            );
+            crate::example_db::specs::rule_example_with_witness::<_, QUBO<f64>>(
+                source,
+                SolutionPair {
+                    source_config: vec![1, 0, 1],
+                    target_config: vec![1, 0, 1, 0, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/pseudoboolean_qubo.rs"]
+mod tests;
@@ -40,8 +40,8 @@ impl ReductionResult for ReductionQUBOToILP {
 
 #[reduction(
     overhead = {
-        num_vars = "num_vars^2",
-        num_constraints = "num_vars^2",
+        num_vars = "num_vars + num_interactions",
+        num_constraints = "3 * num_interactions",
     }
 )]
 impl ReduceTo<ILP<bool>> for QUBO<f64> {
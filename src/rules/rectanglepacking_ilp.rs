@@ -0,0 +1,179 @@
+//! Reduction from RectanglePacking to ILP<i32>.
+//!
+//! Variable layout, for n rectangles:
+//! - `x_i, y_i, r_i` at indices `3i, 3i + 1, 3i + 2`: integer lower-left
+//!   corner and rotation bit (fixed to 0 when rotation is not allowed)
+//! - `s_{p,k}` for each pair p = (i, j), i < j, and k ∈ {left, right, below,
+//!   above}: index `3n + 4p + k`, 1 if the pair is separated that way
+//!
+//! With `w'_i = w_i + (h_i - w_i) r_i` and `h'_i = h_i + (w_i - h_i) r_i`:
+//! - Containment: x_i + w'_i ≤ W and y_i + h'_i ≤ H
+//! - Big-M separation, e.g. left: x_i + w'_i ≤ x_j + W (1 - s_{p,left}),
+//!   and symmetrically for right, below (M = H) and above
+//! - Disjunction: Σ_k s_{p,k} ≥ 1
+//!
+//! Containment makes W (resp. H) a valid big-M: a relaxed separation
+//! constraint holds for every contained placement.
+
+use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::misc::RectanglePacking;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+
+/// Result of reducing RectanglePacking to ILP<i32>.
+#[derive(Debug, Clone)]
+pub struct ReductionRectanglePackingToILP {
+    target: ILP<i32>,
+    num_rectangles: usize,
+    allow_rotation: bool,
+}
+
+impl ReductionResult for ReductionRectanglePackingToILP {
+    type Source = RectanglePacking;
+    type Target = ILP<i32>;
+
+    fn target_problem(&self) -> &ILP<i32> {
+        &self.target
+    }
+
+    /// Extract solution: positions, plus rotation bits when rotation is allowed.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let vars_per_rectangle = if self.allow_rotation { 3 } else { 2 };
+        (0..self.num_rectangles)
+            .flat_map(|i| target_solution[3 * i..3 * i + vars_per_rectangle].to_vec())
+            .collect()
+    }
+}
+
+#[reduction(overhead = {
+    num_vars = "3 * num_rectangles + 2 * num_rectangles * (num_rectangles - 1)",
+    num_constraints = "3 * num_rectangles + 9 * num_rectangles * (num_rectangles - 1) / 2",
+})]
+impl ReduceTo<ILP<i32>> for RectanglePacking {
+    type Result = ReductionRectanglePackingToILP;
+
+    fn reduce_to(&self) -> Self::Result {
+        let n = self.num_rectangles();
+        let big_w = self.container_width() as f64;
+        let big_h = self.container_height() as f64;
+        let x_var = |i: usize| 3 * i;
+        let y_var = |i: usize| 3 * i + 1;
+        let r_var = |i: usize| 3 * i + 2;
+
+        let pairs: Vec<(usize, usize)> = (0..n)
+            .flat_map(|i| ((i + 1)..n).map(move |j| (i, j)))
+            .collect();
+        let s_var = |p: usize, k: usize| 3 * n + 4 * p + k;
+        let num_vars = 3 * n + 4 * pairs.len();
+
+        let sides: Vec<(f64, f64)> = self
+            .rectangles()
+            .iter()
+            .map(|&(w, h)| (w as f64, h as f64))
+            .collect();
+
+        let mut constraints = Vec::with_capacity(3 * n + 9 * pairs.len());
+
+        for (i, &(w, h)) in sides.iter().enumerate() {
+            // Rotation bit: r_i ≤ 1, or r_i ≤ 0 when rotation is not allowed
+            let max_rotation = if self.allow_rotation() { 1.0 } else { 0.0 };
+            constraints.push(LinearConstraint::le(vec![(r_var(i), 1.0)], max_rotation));
+            // x_i + w_i + (h_i - w_i) r_i ≤ W
+            constraints.push(LinearConstraint::le(
+                vec![(x_var(i), 1.0), (r_var(i), h - w)],
+                big_w - w,
+            ));
+            // y_i + h_i + (w_i - h_i) r_i ≤ H
+            constraints.push(LinearConstraint::le(
+                vec![(y_var(i), 1.0), (r_var(i), w - h)],
+                big_h - h,
+            ));
+        }
+
+        for (p, &(i, j)) in pairs.iter().enumerate() {
+            let (w_i, h_i) = sides[i];
+            let (w_j, h_j) = sides[j];
+
+            // Selector bits: s_{p,k} ≤ 1
+            for k in 0..4 {
+                constraints.push(LinearConstraint::le(vec![(s_var(p, k), 1.0)], 1.0));
+            }
+
+            // i left of j: x_i - x_j + (h_i - w_i) r_i + W s ≤ W - w_i
+            constraints.push(LinearConstraint::le(
+                vec![
+                    (x_var(i), 1.0),
+                    (x_var(j), -1.0),
+                    (r_var(i), h_i - w_i),
+                    (s_var(p, 0), big_w),
+                ],
+                big_w - w_i,
+            ));
+            // j left of i: x_j - x_i + (h_j - w_j) r_j + W s ≤ W - w_j
+            constraints.push(LinearConstraint::le(
+                vec![
+                    (x_var(j), 1.0),
+                    (x_var(i), -1.0),
+                    (r_var(j), h_j - w_j),
+                    (s_var(p, 1), big_w),
+                ],
+                big_w - w_j,
+            ));
+            // i below j: y_i - y_j + (w_i - h_i) r_i + H s ≤ H - h_i
+            constraints.push(LinearConstraint::le(
+                vec![
+                    (y_var(i), 1.0),
+                    (y_var(j), -1.0),
+                    (r_var(i), w_i - h_i),
+                    (s_var(p, 2), big_h),
+                ],
+                big_h - h_i,
+            ));
+            // j below i: y_j - y_i + (w_j - h_j) r_j + H s ≤ H - h_j
+            constraints.push(LinearConstraint::le(
+                vec![
+                    (y_var(j), 1.0),
+                    (y_var(i), -1.0),
+                    (r_var(j), w_j - h_j),
+                    (s_var(p, 3), big_h),
+                ],
+                big_h - h_j,
+            ));
+
+            // At least one separation holds
+            constraints.push(LinearConstraint::ge(
+                (0..4).map(|k| (s_var(p, k), 1.0)).collect(),
+                1.0,
+            ));
+        }
+
+        ReductionRectanglePackingToILP {
+            target: ILP::new(num_vars, constraints, vec![], ObjectiveSense::Minimize),
+            num_rectangles: n,
+            allow_rotation: self.allow_rotation(),
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "rectanglepacking_to_ilp",
+        build: || {
+            // Two 1x2 dominoes side by side in a 2x2 box: domino 0 is left of domino 1.
+            crate::example_db::specs::rule_example_with_witness::<_, ILP<i32>>(
+                RectanglePacking::new(vec![(1, 2), (1, 2)], 2, 2, false),
+                SolutionPair {
+                    source_config: vec![0, 0, 1, 0],
+                    target_config: vec![0, 0, 0, 1, 0, 0, 1, 0, 0, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/rectanglepacking_ilp.rs"]
+mod tests;
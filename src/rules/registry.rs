@@ -4,7 +4,8 @@ use crate::expr::Expr;
 use crate::rules::traits::{DynAggregateReductionResult, DynReductionResult};
 use crate::types::ProblemSize;
 use std::any::Any;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
 
 /// Overhead specification for a reduction.
 #[derive(Clone, Debug, Default, serde::Serialize)]
@@ -53,6 +54,12 @@ impl ReductionOverhead {
     ///
     /// Returns a new overhead whose expressions map from self's input variables
     /// directly to `next`'s output variables.
+    ///
+    /// Variables are matched by name: a `next` input such as `num_vertices` or
+    /// `num_vars` is replaced by self's output field of the same name, so both
+    /// steps must use the problem's size getter names (`num_vertices`,
+    /// `num_edges`, `num_vars`, `num_clauses`, ...). Inputs that self does not
+    /// produce are left as free variables.
     pub fn compose(&self, next: &ReductionOverhead) -> ReductionOverhead {
         use std::collections::HashMap;
 
@@ -83,6 +90,23 @@ impl ReductionOverhead {
     }
 }
 
+/// Parallel resource sum: same-named output fields are added, and fields
+/// present on only one side are kept unchanged.
+impl std::ops::Add for ReductionOverhead {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut output_size = self.output_size;
+        for (name, expr) in other.output_size {
+            match output_size.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, existing)) => *existing = existing.clone() + expr,
+                None => output_size.push((name, expr)),
+            }
+        }
+        Self { output_size }
+    }
+}
+
 /// Witness/config reduction executor stored in the inventory.
 pub type ReduceFn = fn(&dyn Any) -> Box<dyn DynReductionResult>;
 
@@ -241,6 +265,48 @@ pub fn reduction_entries() -> Vec<&'static ReductionEntry> {
     inventory::iter::<ReductionEntry>().collect()
 }
 
+/// Registered reduction entries grouped by source and target problem name.
+struct ReductionIndex {
+    by_source: HashMap<&'static str, Vec<&'static ReductionEntry>>,
+    by_target: HashMap<&'static str, Vec<&'static ReductionEntry>>,
+}
+
+/// Build the name index on first use; `inventory` is fixed at link time, so
+/// the index never needs to be invalidated.
+fn reduction_index() -> &'static ReductionIndex {
+    static INDEX: OnceLock<ReductionIndex> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut by_source: HashMap<&'static str, Vec<&'static ReductionEntry>> = HashMap::new();
+        let mut by_target: HashMap<&'static str, Vec<&'static ReductionEntry>> = HashMap::new();
+        for entry in inventory::iter::<ReductionEntry> {
+            by_source.entry(entry.source_name).or_default().push(entry);
+            by_target.entry(entry.target_name).or_default().push(entry);
+        }
+        ReductionIndex {
+            by_source,
+            by_target,
+        }
+    })
+}
+
+/// Return the registered reductions whose source problem is `source_name`,
+/// in registration order.
+pub fn reductions_from(source_name: &str) -> &'static [&'static ReductionEntry] {
+    reduction_index()
+        .by_source
+        .get(source_name)
+        .map_or(&[], Vec::as_slice)
+}
+
+/// Return the registered reductions whose target problem is `target_name`,
+/// in registration order.
+pub fn reductions_to(target_name: &str) -> &'static [&'static ReductionEntry] {
+    reduction_index()
+        .by_target
+        .get(target_name)
+        .map_or(&[], Vec::as_slice)
+}
+
 #[cfg(test)]
 #[path = "../unit_tests/rules/registry.rs"]
 mod tests;
@@ -0,0 +1,144 @@
+//! Reduction from Satisfiability (SAT) to MaximumClique.
+//!
+//! Classical Karp (1972) reduction. The reduction creates one vertex for each
+//! literal occurrence in each clause. Two vertices are adjacent iff they lie in
+//! different clauses and their literals are not complementary (x and NOT x).
+//!
+//! A clique can use at most one vertex per clause, so the clique number is at
+//! most `num_clauses`, with equality iff the formula is satisfiable: the
+//! literals of such a clique are mutually consistent and hit every clause.
+
+use crate::models::formula::Satisfiability;
+use crate::models::graph::MaximumClique;
+use crate::reduction;
+use crate::rules::sat_maximumindependentset::BoolVar;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::SimpleGraph;
+
+/// Result of reducing Satisfiability to MaximumClique.
+#[derive(Debug, Clone)]
+pub struct ReductionSATToClique {
+    /// The target MaximumClique problem.
+    target: MaximumClique<SimpleGraph, i32>,
+    /// Mapping from vertex index to the literal it represents.
+    literals: Vec<BoolVar>,
+    /// The number of variables in the source SAT problem.
+    num_source_variables: usize,
+    /// The number of clauses in the source SAT problem.
+    num_clauses: usize,
+}
+
+impl ReductionResult for ReductionSATToClique {
+    type Source = Satisfiability;
+    type Target = MaximumClique<SimpleGraph, i32>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Extract a SAT solution from a MaximumClique solution.
+    ///
+    /// Each selected vertex sets its variable so that the literal is true.
+    /// Clique vertices never contradict each other; variables not covered by
+    /// the clique default to false.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        let mut assignment = vec![0usize; self.num_source_variables];
+        for (vertex_idx, &selected) in target_solution.iter().enumerate() {
+            if selected == 1 {
+                let literal = &self.literals[vertex_idx];
+                assignment[literal.name] = if literal.neg { 0 } else { 1 };
+            }
+        }
+        assignment
+    }
+}
+
+impl ReductionSATToClique {
+    /// Get the number of clauses in the source SAT problem.
+    ///
+    /// The source is satisfiable iff the target's clique number equals this.
+    pub fn num_clauses(&self) -> usize {
+        self.num_clauses
+    }
+
+    /// Get a reference to the literals mapping.
+    pub fn literals(&self) -> &[BoolVar] {
+        &self.literals
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "num_literals",
+        num_edges = "num_literals^2",
+    }
+)]
+impl ReduceTo<MaximumClique<SimpleGraph, i32>> for Satisfiability {
+    type Result = ReductionSATToClique;
+
+    fn reduce_to(&self) -> Self::Result {
+        let mut literals: Vec<BoolVar> = Vec::new();
+        let mut clause_of: Vec<usize> = Vec::new();
+        for (clause_idx, clause) in self.clauses().iter().enumerate() {
+            for &lit in &clause.literals {
+                literals.push(BoolVar::from_literal(lit));
+                clause_of.push(clause_idx);
+            }
+        }
+
+        let num_vertices = literals.len();
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for i in 0..num_vertices {
+            for j in (i + 1)..num_vertices {
+                if clause_of[i] != clause_of[j] && !literals[i].is_complement(&literals[j]) {
+                    edges.push((i, j));
+                }
+            }
+        }
+
+        let target = MaximumClique::new(
+            SimpleGraph::new(num_vertices, edges),
+            vec![1i32; num_vertices],
+        );
+
+        ReductionSATToClique {
+            target,
+            literals,
+            num_source_variables: self.num_vars(),
+            num_clauses: self.num_clauses(),
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+    use crate::models::formula::CNFClause;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "satisfiability_to_maximumclique",
+        build: || {
+            // (x1 OR x2) AND (NOT x1 OR x3) AND (NOT x2 OR NOT x3)
+            // Vertices: 0:x1 1:x2 | 2:~x1 3:x3 | 4:~x2 5:~x3
+            let source = Satisfiability::new(
+                3,
+                vec![
+                    CNFClause::new(vec![1, 2]),
+                    CNFClause::new(vec![-1, 3]),
+                    CNFClause::new(vec![-2, -3]),
+                ],
+            );
+            crate::example_db::specs::rule_example_with_witness::<_, MaximumClique<SimpleGraph, i32>>(
+                source,
+                SolutionPair {
+                    source_config: vec![1, 0, 1],
+                    target_config: vec![1, 0, 0, 1, 1, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/sat_maximumclique.rs"]
+mod tests;
@@ -0,0 +1,200 @@
+//! Reduction from Satisfiability (SAT) to MinimumVertexCover.
+//!
+//! Applies the Garey & Johnson 3-SAT construction after splitting every clause
+//! with k > 3 literals into the k - 2 clauses
+//! (l_1 v l_2 v y_1), (~y_1 v l_3 v y_2), ..., (~y_{k-3} v l_{k-1} v l_k)
+//! over k - 3 fresh variables. For each variable x_i that occurs in the
+//! formula, add two literal vertices {u_i, not-u_i} joined by a truth-setting
+//! edge; variables that occur in no clause get no vertices. For each clause
+//! C_j with k_j <= 3 literals, add a satisfaction-testing clique on k_j
+//! vertices, and join each clique vertex to the literal vertex it stands for.
+//!
+//! Every vertex cover contains an endpoint of each truth-setting edge and all
+//! but one vertex of each clause clique, so every cover has size at least
+//! n' + Σ_j (k_j - 1), where n' counts the variables with vertices. A cover of
+//! exactly that size exists if and only if the formula is satisfiable; see
+//! [`ReductionSATToVC::cover_size_threshold`]. An empty clause adds no
+//! vertices but lowers the threshold by one, so it correctly makes the
+//! threshold unreachable.
+//!
+//! Per literal occurrence the graph gains at most 7 vertices and 8 edges, so
+//! its size is linear in the total clause length.
+//!
+//! Reference: Garey & Johnson, "Computers and Intractability", 1979, Theorem 3.3
+
+use crate::models::formula::Satisfiability;
+use crate::models::graph::MinimumVertexCover;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::SimpleGraph;
+
+/// Result of reducing Satisfiability to MinimumVertexCover.
+#[derive(Debug, Clone)]
+pub struct ReductionSATToVC {
+    target: MinimumVertexCover<SimpleGraph, i32>,
+    /// Gadget index of each source variable, or `None` if it occurs in no clause.
+    var_gadgets: Vec<Option<usize>>,
+    cover_size_threshold: usize,
+}
+
+impl ReductionSATToVC {
+    /// The cover size `n' + Σ_j (|C_j| - 1)` that is reached exactly when the
+    /// source formula is satisfiable, where `n'` counts the occurring and
+    /// auxiliary variables and `C_j` ranges over the split clauses.
+    ///
+    /// No vertex cover of the target graph is smaller than this; the minimum
+    /// cover exceeds it iff the formula is unsatisfiable. The value saturates at
+    /// zero when empty clauses outnumber `n' + Σ_j |C_j|`, in which case it no
+    /// longer certifies unsatisfiability.
+    pub fn cover_size_threshold(&self) -> usize {
+        self.cover_size_threshold
+    }
+}
+
+impl ReductionResult for ReductionSATToVC {
+    type Source = Satisfiability;
+    type Target = MinimumVertexCover<SimpleGraph, i32>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    /// Extract a SAT assignment from a vertex cover solution.
+    ///
+    /// Vertices 2*g and 2*g+1 are u_g and not-u_g for gadget g. In a cover of
+    /// threshold size exactly one of them is covered, and the uncovered one is
+    /// the false literal: if u_g is uncovered, set the variable to 0; otherwise
+    /// set it to 1. Variables without a gadget occur in no clause and are 0.
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        self.var_gadgets
+            .iter()
+            .map(|gadget| gadget.map_or(0, |g| usize::from(target_solution[2 * g] == 1)))
+            .collect()
+    }
+}
+
+#[reduction(
+    overhead = {
+        num_vertices = "7 * num_literals",
+        num_edges = "8 * num_literals",
+    }
+)]
+impl ReduceTo<MinimumVertexCover<SimpleGraph, i32>> for Satisfiability {
+    type Result = ReductionSATToVC;
+
+    fn reduce_to(&self) -> Self::Result {
+        // Number the occurring variables in order of first occurrence.
+        let mut var_gadgets: Vec<Option<usize>> = vec![None; self.num_vars()];
+        let mut num_gadgets = 0;
+        for clause in self.clauses() {
+            for &lit in &clause.literals {
+                let gadget = &mut var_gadgets[lit.unsigned_abs() as usize - 1];
+                if gadget.is_none() {
+                    *gadget = Some(num_gadgets);
+                    num_gadgets += 1;
+                }
+            }
+        }
+
+        // Split clauses to at most 3 literals, as (gadget, positive) pairs.
+        let mut clauses: Vec<Vec<(usize, bool)>> = Vec::with_capacity(self.num_literals());
+        for clause in self.clauses() {
+            let lits: Vec<(usize, bool)> = clause
+                .literals
+                .iter()
+                .map(|&lit| {
+                    let gadget = var_gadgets[lit.unsigned_abs() as usize - 1];
+                    (gadget.expect("occurring variable has a gadget"), lit > 0)
+                })
+                .collect();
+            let k = lits.len();
+            if k <= 3 {
+                clauses.push(lits);
+                continue;
+            }
+            let mut carry = (num_gadgets, true);
+            clauses.push(vec![lits[0], lits[1], carry]);
+            for &lit in &lits[2..k - 2] {
+                num_gadgets += 1;
+                let next = (num_gadgets, true);
+                clauses.push(vec![(carry.0, false), lit, next]);
+                carry = next;
+            }
+            num_gadgets += 1;
+            clauses.push(vec![(carry.0, false), lits[k - 2], lits[k - 1]]);
+        }
+
+        let num_literals: usize = clauses.iter().map(Vec::len).sum();
+        let total_vertices = 2 * num_gadgets + num_literals;
+        let mut edges: Vec<(usize, usize)> = Vec::with_capacity(num_gadgets + 2 * num_literals);
+
+        // Truth-setting edges (u_g, not-u_g)
+        for g in 0..num_gadgets {
+            edges.push((2 * g, 2 * g + 1));
+        }
+
+        // Clause cliques and communication edges
+        let mut base = 2 * num_gadgets;
+        for clause in &clauses {
+            let k = clause.len();
+            for a in 0..k {
+                for b in (a + 1)..k {
+                    edges.push((base + a, base + b));
+                }
+            }
+            for (a, &(gadget, positive)) in clause.iter().enumerate() {
+                let literal_vertex = if positive { 2 * gadget } else { 2 * gadget + 1 };
+                edges.push((base + a, literal_vertex));
+            }
+            base += k;
+        }
+
+        let graph = SimpleGraph::new(total_vertices, edges);
+        let target = MinimumVertexCover::new(graph, vec![1i32; total_vertices]);
+
+        ReductionSATToVC {
+            target,
+            var_gadgets,
+            cover_size_threshold: (num_gadgets + num_literals).saturating_sub(clauses.len()),
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+    use crate::models::formula::CNFClause;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "satisfiability_to_minimumvertexcover",
+        build: || {
+            let source = Satisfiability::new(
+                3,
+                vec![
+                    CNFClause::new(vec![1, 2]),
+                    CNFClause::new(vec![-1, 2, 3]),
+                    CNFClause::new(vec![-2, -3]),
+                ],
+            );
+            crate::example_db::specs::rule_example_with_witness::<
+                _,
+                MinimumVertexCover<SimpleGraph, i32>,
+            >(
+                source,
+                SolutionPair {
+                    // x1=1, x2=0, x3=1
+                    source_config: vec![1, 0, 1],
+                    // Literal vertices: u1(0), ~u1(1), u2(2), ~u2(3), u3(4), ~u3(5)
+                    // Clause cliques: {6, 7}, {8, 9, 10}, {11, 12}
+                    // Cover the true literals u1, ~u2, u3 and every clique
+                    // vertex except one whose literal is true: 3 + 1 + 2 + 1 = 7
+                    target_config: vec![1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/sat_minimumvertexcover.rs"]
+mod tests;
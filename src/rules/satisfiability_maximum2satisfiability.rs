@@ -3,17 +3,18 @@
 use crate::models::formula::{CNFClause, Maximum2Satisfiability, Satisfiability};
 use crate::reduction;
 use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::types::One;
 
 /// Result of reducing SAT to MAX-2-SAT.
 #[derive(Debug, Clone)]
 pub struct ReductionSatisfiabilityToMaximum2Satisfiability {
-    target: Maximum2Satisfiability,
+    target: Maximum2Satisfiability<One>,
     source_num_vars: usize,
 }
 
 impl ReductionResult for ReductionSatisfiabilityToMaximum2Satisfiability {
     type Source = Satisfiability;
-    type Target = Maximum2Satisfiability;
+    type Target = Maximum2Satisfiability<One>;
 
     fn target_problem(&self) -> &Self::Target {
         &self.target
@@ -96,7 +97,7 @@ fn add_gjs_gadget(clause: &CNFClause, w: i32, target_clauses: &mut Vec<CNFClause
         num_clauses = "10 * (num_literals + 3 * num_clauses)",
     }
 )]
-impl ReduceTo<Maximum2Satisfiability> for Satisfiability {
+impl ReduceTo<Maximum2Satisfiability<One>> for Satisfiability {
     type Result = ReductionSatisfiabilityToMaximum2Satisfiability;
 
     fn reduce_to(&self) -> Self::Result {
@@ -134,7 +135,7 @@ pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::Ru
                 3,
                 vec![CNFClause::new(vec![1, -2, 3]), CNFClause::new(vec![-1, 2])],
             );
-            crate::example_db::specs::rule_example_with_witness::<_, Maximum2Satisfiability>(
+            crate::example_db::specs::rule_example_with_witness::<_, Maximum2Satisfiability<One>>(
                 source,
                 SolutionPair {
                     source_config: vec![1, 1, 1],
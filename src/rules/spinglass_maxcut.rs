@@ -1,7 +1,15 @@
 //! Reductions between SpinGlass and MaxCut problems.
 //!
-//! MaxCut -> SpinGlass: Direct mapping, edge weights become J couplings.
-//! SpinGlass -> MaxCut: Requires ancilla vertex for onsite terms.
+//! MaxCut -> SpinGlass: Direct mapping, edge weights become J couplings and
+//! no fields are introduced.
+//! SpinGlass -> MaxCut: Couplings become edges. Nonzero fields h_i are
+//! absorbed by an ancilla spin fixed at s_a = +1, turning h_i s_i into a
+//! coupling h_i s_i s_a; without fields no ancilla is added.
+//!
+//! With spin s = 2x - 1 an edge is cut iff s_i s_j = -1, so
+//! `cut = (sum of weights - H) / 2` and maximum cuts are ground states.
+//! The identity holds for couplings of either sign: a negative edge is
+//! ferromagnetic, and an optimal cut leaves it uncut unless frustrated.
 
 use crate::models::graph::MaxCut;
 use crate::models::graph::SpinGlass;
@@ -54,23 +62,8 @@ impl ReduceTo<SpinGlass<SimpleGraph, i32>> for MaxCut<SimpleGraph, i32> {
         let n = self.graph().num_vertices();
         let edges_with_weights = self.edges();
 
-        // MaxCut: maximize sum of w_ij for edges (i,j) where s_i != s_j
-        // SpinGlass: minimize sum of J_ij * s_i * s_j
-        //
-        // For MaxCut, we want to maximize cut, which means:
-        // - When s_i != s_j (opposite spins), edge contributes to cut
-        // - s_i * s_j = -1 when opposite, +1 when same
-        //
-        // To convert: maximize sum(w_ij * [s_i != s_j])
-        //           = maximize sum(w_ij * (1 - s_i*s_j)/2)
-        //           = constant - minimize sum(w_ij * s_i*s_j / 2)
-        //
-        // So J_ij = -w_ij / 2 would work, but since we need to relate
-        // the problems directly, we use J_ij = w_ij and negate.
-        // Actually, for a proper reduction, we set J_ij = w_ij.
-        // MaxCut wants to maximize edges cut, SpinGlass minimizes energy.
-        // When J > 0 (antiferromagnetic), opposite spins lower energy.
-        // So maximizing cut = minimizing Ising energy with J = w.
+        // cut = sum_ij w_ij (1 - s_i s_j) / 2, so maximizing the cut
+        // minimizes H = sum_ij w_ij s_i s_j: J_ij = w_ij, no fields.
         let interactions: Vec<((usize, usize), i32)> = edges_with_weights
             .into_iter()
             .map(|(u, v, w)| ((u, v), w))
@@ -112,27 +105,26 @@ where
         &self.target
     }
 
+    /// Without an ancilla, H is invariant under flipping every spin, so the
+    /// cut is read off directly. With one, fix the gauge s_a = +1 (config 1):
+    /// a cut and its complement have the same value, so flip every spin when
+    /// the ancilla landed on the 0 side, then drop it.
     fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
-        match self.ancilla {
-            None => target_solution.to_vec(),
-            Some(anc) => {
-                // If ancilla is 1, flip all bits; then remove ancilla
-                let mut sol = target_solution.to_vec();
-                if sol[anc] == 1 {
-                    for x in sol.iter_mut() {
-                        *x = 1 - *x;
-                    }
-                }
-                sol.remove(anc);
-                sol
-            }
-        }
+        let Some(anc) = self.ancilla else {
+            return target_solution.to_vec();
+        };
+        let flip = usize::from(target_solution[anc] != 1);
+        target_solution[..anc]
+            .iter()
+            .chain(&target_solution[anc + 1..])
+            .map(|&x| x ^ flip)
+            .collect()
     }
 }
 
 #[reduction(
     overhead = {
-        num_vertices = "num_spins",
+        num_vertices = "num_spins + 1",
         num_edges = "num_interactions + num_spins",
     }
 )]
@@ -144,7 +136,7 @@ impl ReduceTo<MaxCut<SimpleGraph, i32>> for SpinGlass<SimpleGraph, i32> {
         let interactions = self.interactions();
         let fields = self.fields();
 
-        // Check if we need an ancilla vertex for onsite terms
+        // Fast path: without fields the couplings alone are a MaxCut instance
         let need_ancilla = fields.iter().any(|h| !h.is_zero());
         let total_vertices = if need_ancilla { n + 1 } else { n };
         let ancilla_idx = if need_ancilla { Some(n) } else { None };
@@ -159,8 +151,8 @@ impl ReduceTo<MaxCut<SimpleGraph, i32>> for SpinGlass<SimpleGraph, i32> {
         }
 
         // Add onsite terms as edges to ancilla
-        // h_i * s_i can be modeled as an edge to ancilla with weight h_i
-        // When s_i and s_ancilla are opposite, the edge is cut
+        // With the ancilla fixed at s_a = +1, h_i * s_i = h_i * s_i * s_a is
+        // an ordinary coupling, i.e. an edge to the ancilla with weight h_i
         if need_ancilla {
             for (i, h) in fields.iter().enumerate() {
                 if !h.is_zero() {
@@ -210,6 +210,170 @@ where
     assert_eq!(source.evaluate(&extracted), bf_value);
 }
 
+/// Maps a source instance and its optimum to the optimum the target must reach.
+type OptimumRelation<'a, R> = dyn Fn(
+        &<R as ReductionResult>::Source,
+        <<R as ReductionResult>::Source as Problem>::Value,
+    ) -> <<R as ReductionResult>::Target as Problem>::Value
+    + 'a;
+
+/// Property check: a reduction preserves the optimum on random source instances.
+///
+/// Draws `cases` instances from `generate` with an RNG seeded by `seed`, reduces
+/// each with `reduce`, and solves both sides with [`BruteForce`]. The target
+/// optimum must equal `expected_target(source, source_optimum)` — the value
+/// relation documented by the reduction — and the source configuration
+/// extracted from a target witness must attain the source optimum.
+pub(crate) fn assert_reduction_preserves_optimum<R>(
+    generate: &dyn Fn(&mut rand::rngs::SmallRng) -> R::Source,
+    reduce: &dyn Fn(&R::Source) -> R,
+    expected_target: &OptimumRelation<'_, R>,
+    seed: u64,
+    cases: usize,
+) where
+    R: ReductionResult,
+    R::Source: Problem + std::fmt::Debug + 'static,
+    R::Target: Problem + 'static,
+    <R::Source as Problem>::Value: Aggregate + std::fmt::Debug + PartialEq,
+    <R::Target as Problem>::Value: Aggregate + std::fmt::Debug + PartialEq,
+{
+    use crate::solvers::Solver;
+    use rand::SeedableRng;
+
+    let solver = BruteForce::new();
+    let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+    for case in 0..cases {
+        let source = generate(&mut rng);
+        let reduction = reduce(&source);
+        let target = reduction.target_problem();
+
+        let source_value = solver.solve(&source);
+        let target_value = solver.solve(target);
+        assert_eq!(
+            target_value,
+            expected_target(&source, source_value.clone()),
+            "seed {seed}, case {case}: target optimum does not match source optimum for {source:?}"
+        );
+
+        let witness = solver
+            .find_witness(target)
+            .unwrap_or_else(|| panic!("seed {seed}, case {case}: target has no witness"));
+        assert_eq!(
+            source.evaluate(&reduction.extract_solution(&witness)),
+            source_value,
+            "seed {seed}, case {case}: extracted solution is not optimal for {source:?}"
+        );
+    }
+}
+
+/// Golden-file check: reduce `source` to `target_name` and compare the result
+/// with the canonical JSON stored at `golden_path` (relative to the crate root).
+///
+/// The reduction follows the cheapest witness path (fewest steps, then
+/// smallest overhead) to the default variant of `target_name`. The golden file records the source instance, the path, and
+/// the serialized target. Object keys are sorted and floats are rounded to 12
+/// decimal places, so only genuine changes in the reduction output show up in
+/// the diff. Run with `UPDATE_GOLDEN=1` to rewrite the file after an
+/// intentional change.
+pub(crate) fn assert_reduction_matches_golden<S>(source: &S, target_name: &str, golden_path: &str)
+where
+    S: Problem + serde::Serialize + 'static,
+{
+    use crate::rules::{MinimizeStepsThenOverhead, ReductionGraph};
+
+    let graph = ReductionGraph::new();
+    let source_variant = ReductionGraph::variant_to_map(&S::variant());
+    let target_variant = graph
+        .default_variant_for(target_name)
+        .unwrap_or_else(|| panic!("unknown target problem {target_name}"));
+    let path = graph
+        .find_cheapest_path(
+            S::NAME,
+            &source_variant,
+            target_name,
+            &target_variant,
+            &ReductionGraph::compute_source_size_for_variant(S::NAME, &source_variant, source),
+            &MinimizeStepsThenOverhead,
+        )
+        .unwrap_or_else(|| panic!("no reduction path from {} to {target_name}", S::NAME));
+    let chain = graph
+        .reduce_along_path(&path, source as &dyn std::any::Any)
+        .unwrap_or_else(|| panic!("failed to execute reduction path {path}"));
+    let target_step = path.steps.last().expect("path has a target step");
+    let target = crate::registry::serialize_any(
+        &target_step.name,
+        &target_step.variant,
+        chain.target_problem_any(),
+    )
+    .unwrap_or_else(|| panic!("failed to serialize {target_step}"));
+
+    let actual = canonical_json(serde_json::json!({
+        "source": {
+            "problem": S::NAME,
+            "variant": source_variant,
+            "instance": serde_json::to_value(source).expect("source serializes"),
+        },
+        "path": path.steps.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        "target": {
+            "problem": target_step.name,
+            "variant": target_step.variant,
+            "instance": target,
+        },
+    }));
+
+    let file = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(golden_path);
+    if std::env::var_os("UPDATE_GOLDEN").is_some_and(|v| v == "1") {
+        if let Some(dir) = file.parent() {
+            std::fs::create_dir_all(dir).expect("create golden directory");
+        }
+        let text = serde_json::to_string_pretty(&actual).expect("golden JSON serializes");
+        std::fs::write(&file, text + "\n").expect("write golden file");
+        return;
+    }
+
+    let expected: serde_json::Value = std::fs::read_to_string(&file)
+        .map_err(|err| err.to_string())
+        .and_then(|text| serde_json::from_str(&text).map_err(|err| err.to_string()))
+        .unwrap_or_else(|err| {
+            panic!(
+                "cannot load golden file {}: {err}; run with UPDATE_GOLDEN=1 to create it",
+                file.display()
+            )
+        });
+    assert!(
+        canonical_json(expected) == actual,
+        "reduction {} -> {target_name} no longer matches {}; \
+         if the change is intended, rerun with UPDATE_GOLDEN=1\nactual:\n{}",
+        S::NAME,
+        file.display(),
+        serde_json::to_string_pretty(&actual).expect("golden JSON serializes"),
+    );
+}
+
+/// Sort object keys and round floats so equal reductions compare equal
+/// regardless of map ordering or last-bit floating-point noise.
+fn canonical_json(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            let sorted: std::collections::BTreeMap<String, Value> = map
+                .into_iter()
+                .map(|(key, value)| (key, canonical_json(value)))
+                .collect();
+            Value::Object(sorted.into_iter().collect())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(canonical_json).collect()),
+        Value::Number(number) if number.is_f64() => {
+            let x = number.as_f64().expect("f64 number");
+            // `+ 0.0` turns -0.0 into 0.0.
+            let rounded = (x * 1e12).round() / 1e12 + 0.0;
+            serde_json::Number::from_f64(rounded).map_or(Value::Null, Value::Number)
+        }
+        other => other,
+    }
+}
+
 pub(crate) fn solve_optimization_problem<P>(problem: &P) -> Option<Vec<usize>>
 where
     P: Problem + 'static,
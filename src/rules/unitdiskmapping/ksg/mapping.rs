@@ -214,6 +214,9 @@ impl MappingResult<KsgTapeEntry> {
     /// 2. Unapply gadgets in reverse order (modifying config matrix)
     /// 3. Extract vertex configs from copyline locations
     ///
+    /// When `grid_config` is a maximum independent set of the grid graph, the
+    /// result is a maximum independent set of the source graph.
+    ///
     /// # Arguments
     /// * `grid_config` - Configuration on the grid graph (0 = not selected, 1 = selected)
     ///
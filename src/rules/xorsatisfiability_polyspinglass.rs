@@ -0,0 +1,169 @@
+//! Reduction from CNF-XOR Satisfiability to PolySpinGlass.
+//!
+//! With spins s_i = 2x_i - 1, a literal l on variable i has spin value
+//! σ_l = ±s_i (negated literals flip the sign). Every clause becomes a
+//! penalty polynomial that is M on violating assignments and 0 otherwise,
+//! where M = 2^max(1, max CNF clause size) keeps all couplings integral:
+//!
+//! - An XOR clause over k literals is satisfied iff
+//!   (-1)^(k-1) Π σ_l = +1, so its penalty is M/2 (1 - (-1)^(k-1) Π σ_l),
+//!   a single multi-spin term.
+//! - A CNF clause is violated iff every literal is false, so its penalty is
+//!   M Π (1 - σ_l)/2, expanded into one term per literal subset.
+//!
+//! Monomials are reduced with s_i^2 = 1 and merged, single-spin monomials
+//! become on-site fields, and constant terms are dropped. The source is
+//! satisfiable iff the ground-state energy equals minus the dropped
+//! constant, and the ground states are then exactly the satisfying
+//! assignments.
+
+use crate::models::formula::XorSatisfiability;
+use crate::models::graph::PolySpinGlass;
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use std::collections::BTreeMap;
+
+/// Result of reducing CNF-XOR SAT to PolySpinGlass.
+#[derive(Debug, Clone)]
+pub struct ReductionXorSATToPolySpinGlass {
+    target: PolySpinGlass<i32>,
+    /// Constant energy offset dropped from the penalty Hamiltonian.
+    offset: i64,
+}
+
+impl ReductionXorSATToPolySpinGlass {
+    /// Energy of the target Hamiltonian on a satisfying assignment.
+    ///
+    /// The source is satisfiable iff the target ground-state energy equals
+    /// this value; every violated clause raises the energy by at least the
+    /// penalty scale above it.
+    pub fn satisfying_energy(&self) -> i64 {
+        -self.offset
+    }
+}
+
+impl ReductionResult for ReductionXorSATToPolySpinGlass {
+    type Source = XorSatisfiability;
+    type Target = PolySpinGlass<i32>;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        // Spins and variables share the same binary encoding.
+        target_solution.to_vec()
+    }
+}
+
+/// Spin polynomial keyed by the sorted set of spins in each monomial.
+type SpinPolynomial = BTreeMap<Vec<usize>, i64>;
+
+/// Add `coef * Π_{l in literals} σ_l` to the polynomial, reducing repeated
+/// spins with s_i^2 = 1.
+fn add_literal_monomial(poly: &mut SpinPolynomial, literals: &[i32], mut coef: i64) {
+    let mut spins = Vec::with_capacity(literals.len());
+    for &lit in literals {
+        if lit < 0 {
+            coef = -coef;
+        }
+        let spin = lit.unsigned_abs() as usize - 1;
+        match spins.iter().position(|&s| s == spin) {
+            Some(pos) => {
+                spins.swap_remove(pos);
+            }
+            None => spins.push(spin),
+        }
+    }
+    spins.sort_unstable();
+    *poly.entry(spins).or_insert(0) += coef;
+}
+
+#[reduction(overhead = {
+    num_spins = "num_vars",
+    num_interactions = "num_xor_clauses + num_clauses * 2^max_clause_size",
+})]
+impl ReduceTo<PolySpinGlass<i32>> for XorSatisfiability {
+    type Result = ReductionXorSATToPolySpinGlass;
+
+    fn reduce_to(&self) -> Self::Result {
+        let max_size = self.max_clause_size().max(1);
+        assert!(
+            max_size <= 30,
+            "CNF clauses with more than 30 literals overflow i32 couplings"
+        );
+        let scale = 1i64 << max_size;
+        let mut poly = SpinPolynomial::new();
+
+        for xor in self.xor_clauses() {
+            let sign = if xor.len() % 2 == 1 { 1 } else { -1 };
+            add_literal_monomial(&mut poly, &[], scale / 2);
+            add_literal_monomial(&mut poly, &xor.literals, -sign * scale / 2);
+        }
+
+        for clause in self.clauses() {
+            let k = clause.len();
+            let unit = scale >> k;
+            for mask in 0..1usize << k {
+                let subset: Vec<i32> = (0..k)
+                    .filter(|&i| mask >> i & 1 == 1)
+                    .map(|i| clause.literals[i])
+                    .collect();
+                let sign = if subset.len().is_multiple_of(2) {
+                    1
+                } else {
+                    -1
+                };
+                add_literal_monomial(&mut poly, &subset, sign * unit);
+            }
+        }
+
+        let to_i32 = |coef: i64| i32::try_from(coef).expect("spin coupling overflows i32");
+        let offset = poly.remove(&Vec::new()).unwrap_or(0);
+        let mut fields = vec![0i32; self.num_vars()];
+        let mut interactions = Vec::new();
+        for (spins, coef) in poly {
+            if coef == 0 {
+                continue;
+            }
+            if let [spin] = spins[..] {
+                fields[spin] = to_i32(coef);
+            } else {
+                interactions.push((spins, to_i32(coef)));
+            }
+        }
+
+        let target = PolySpinGlass::new(self.num_vars(), interactions, fields);
+        ReductionXorSATToPolySpinGlass { target, offset }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+    use crate::models::formula::XorClause;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "xorsatisfiability_to_polyspinglass",
+        build: || {
+            // x1 ⊕ x2 ⊕ x3 = 1 and x2 ⊕ ¬x3 = 1 (i.e. x2 = x3) become the
+            // terms -s1 s2 s3 and -s2 s3 (M = 2); the satisfying assignments
+            // have x1 = 1.
+            let source = XorSatisfiability::from_xor_clauses(
+                3,
+                vec![XorClause::new(vec![1, 2, 3]), XorClause::new(vec![2, -3])],
+            );
+            crate::example_db::specs::rule_example_with_witness::<_, PolySpinGlass<i32>>(
+                source,
+                SolutionPair {
+                    source_config: vec![1, 0, 0],
+                    target_config: vec![1, 0, 0],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/xorsatisfiability_polyspinglass.rs"]
+mod tests;
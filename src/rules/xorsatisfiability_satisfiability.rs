@@ -0,0 +1,130 @@
+//! Reduction from CNF-XOR Satisfiability to Satisfiability.
+//!
+//! CNF clauses are copied unchanged. Each XOR clause is expanded into CNF by
+//! forbidding every even-parity assignment of its literals, which needs
+//! 2^(k-1) clauses for k literals. To keep the expansion linear, long XORs
+//! are chained through auxiliary variables `a = l_1 ⊕ l_2` so that every
+//! expanded piece has at most three literals.
+
+use crate::models::formula::{CNFClause, Satisfiability, XorSatisfiability};
+use crate::reduction;
+use crate::rules::traits::{ReduceTo, ReductionResult};
+
+/// Result of reducing CNF-XOR SAT to SAT.
+#[derive(Debug, Clone)]
+pub struct ReductionXorSATToSAT {
+    /// Number of original variables in the source problem.
+    source_num_vars: usize,
+    /// The target SAT problem.
+    target: Satisfiability,
+}
+
+impl ReductionResult for ReductionXorSATToSAT {
+    type Source = XorSatisfiability;
+    type Target = Satisfiability;
+
+    fn target_problem(&self) -> &Self::Target {
+        &self.target
+    }
+
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        // Only return the original variables, discarding the chain auxiliaries
+        target_solution[..self.source_num_vars].to_vec()
+    }
+}
+
+/// Append the CNF clauses forcing `l_1 ⊕ ... ⊕ l_k = 1` for at most three
+/// literals: one clause excluding each even-parity assignment.
+fn expand_short_xor(literals: &[i32], result_clauses: &mut Vec<CNFClause>) {
+    let k = literals.len();
+    for mask in 0..1usize << k {
+        if mask.count_ones() % 2 == 1 {
+            continue;
+        }
+        // The clause is falsified exactly when literal i has value bit i of `mask`
+        let clause = literals
+            .iter()
+            .enumerate()
+            .map(|(i, &lit)| if mask >> i & 1 == 1 { -lit } else { lit })
+            .collect();
+        result_clauses.push(CNFClause::new(clause));
+    }
+}
+
+/// Add an XOR clause to the CNF formula, chaining through ancilla variables
+/// while it has more than three literals.
+///
+/// `l_1 ⊕ l_2 ⊕ rest = 1` becomes `l_1 ⊕ l_2 ⊕ ¬a = 1` (i.e. `a = l_1 ⊕ l_2`)
+/// and `a ⊕ rest = 1`.
+///
+/// # Returns
+/// Updated next_var after any ancilla variables are created
+fn add_xor_to_cnf(literals: &[i32], result_clauses: &mut Vec<CNFClause>, mut next_var: i32) -> i32 {
+    let mut remaining = literals.to_vec();
+    while remaining.len() > 3 {
+        let ancilla = next_var;
+        next_var += 1;
+        expand_short_xor(&[remaining[0], remaining[1], -ancilla], result_clauses);
+        remaining.splice(0..2, [ancilla]);
+    }
+    expand_short_xor(&remaining, result_clauses);
+    next_var
+}
+
+#[reduction(overhead = {
+    num_vars = "num_vars + num_xor_literals",
+    num_clauses = "num_clauses + num_xor_clauses + 4 * num_xor_literals",
+    num_literals = "num_literals + 12 * num_xor_literals",
+})]
+impl ReduceTo<Satisfiability> for XorSatisfiability {
+    type Result = ReductionXorSATToSAT;
+
+    fn reduce_to(&self) -> Self::Result {
+        let source_num_vars = self.num_vars();
+        let mut result_clauses = self.clauses().to_vec();
+        let mut next_var = (source_num_vars + 1) as i32; // 1-indexed
+
+        for xor in self.xor_clauses() {
+            next_var = add_xor_to_cnf(&xor.literals, &mut result_clauses, next_var);
+        }
+
+        let target = Satisfiability::new((next_var - 1) as usize, result_clauses);
+        ReductionXorSATToSAT {
+            source_num_vars,
+            target,
+        }
+    }
+}
+
+#[cfg(feature = "example-db")]
+pub(crate) fn canonical_rule_example_specs() -> Vec<crate::example_db::specs::RuleExampleSpec> {
+    use crate::export::SolutionPair;
+    use crate::models::formula::XorClause;
+
+    vec![crate::example_db::specs::RuleExampleSpec {
+        id: "xorsatisfiability_to_satisfiability",
+        build: || {
+            // The 4-literal parity x1 ⊕ x2 ⊕ x3 ⊕ x4 = 1 is chained through
+            // a = x1 ⊕ x2, giving two 3-literal pieces of four clauses each.
+            let source = XorSatisfiability::new(
+                4,
+                vec![CNFClause::new(vec![-1, -2])],
+                vec![
+                    XorClause::new(vec![1, 2, 3, 4]),
+                    XorClause::new(vec![2, -3]),
+                ],
+            );
+            crate::example_db::specs::rule_example_with_witness::<_, Satisfiability>(
+                source,
+                SolutionPair {
+                    source_config: vec![1, 0, 0, 0],
+                    target_config: vec![1, 0, 0, 0, 1],
+                },
+            )
+        },
+    }]
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/rules/xorsatisfiability_satisfiability.rs"]
+mod tests;
@@ -0,0 +1,676 @@
+//! Exact maximum weight matching on general graphs via Edmonds' blossom algorithm.
+
+use crate::models::graph::MaximumMatching;
+use crate::topology::Graph;
+use crate::types::{Max, NumericSize, WeightElement};
+use num_traits::Zero;
+use std::collections::HashMap;
+
+/// Sentinel for "no vertex / endpoint / edge / blossom".
+const NONE: usize = usize::MAX;
+
+/// Exact solver for [`MaximumMatching`] on general (non-bipartite) graphs.
+///
+/// Implements the primal-dual blossom algorithm of Edmonds with Galil's
+/// `O(n^3)` bookkeeping: odd cycles are shrunk into blossoms while dual
+/// variables certify optimality. Unlike
+/// [`BruteForce`](crate::solvers::BruteForce), which enumerates all `2^m`
+/// edge subsets, it handles graphs with thousands of edges.
+///
+/// Edges with non-positive weight never increase the matching weight and are
+/// ignored; among parallel edges only the heaviest is considered. Integer
+/// weights are handled with integer arithmetic only, so results are exact.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MaximumMatching;
+/// use problemreductions::solvers::BlossomSolver;
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::types::Max;
+///
+/// // A 5-cycle: any maximum matching covers 4 of the 5 vertices.
+/// let graph = SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// let problem = MaximumMatching::<_, i32>::unit_weights(graph);
+/// assert_eq!(BlossomSolver::new().solve(&problem), Max(Some(2)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BlossomSolver;
+
+impl BlossomSolver {
+    /// Create a new `BlossomSolver`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find a maximum weight matching, as a 0/1 selection per edge.
+    pub fn find_witness<G, W>(&self, problem: &MaximumMatching<G, W>) -> Vec<usize>
+    where
+        G: Graph,
+        W: WeightElement,
+        W::Sum: Copy,
+    {
+        let all_edges = problem.edges();
+
+        // Keep the heaviest positive-weight edge per vertex pair.
+        let mut best: HashMap<(usize, usize), usize> = HashMap::new();
+        for (idx, (u, v, w)) in all_edges.iter().enumerate() {
+            if u == v || w.to_sum() <= W::Sum::zero() {
+                continue;
+            }
+            let key = (*u.min(v), *u.max(v));
+            match best.get(&key) {
+                Some(&prev) if all_edges[prev].2.to_sum() >= w.to_sum() => {}
+                _ => {
+                    best.insert(key, idx);
+                }
+            }
+        }
+        let mut kept: Vec<usize> = best.into_values().collect();
+        kept.sort_unstable();
+
+        let edges = kept
+            .iter()
+            .map(|&idx| {
+                let (u, v, w) = &all_edges[idx];
+                (*u, *v, w.to_sum())
+            })
+            .collect();
+        let matched = Blossom::new(problem.graph().num_vertices(), edges).solve();
+
+        let mut config = vec![0; all_edges.len()];
+        for k in matched {
+            config[kept[k]] = 1;
+        }
+        config
+    }
+
+    /// Weight of a maximum matching, in the same form as `MaximumMatching::evaluate`.
+    pub fn solve<G, W>(&self, problem: &MaximumMatching<G, W>) -> Max<W::Sum>
+    where
+        G: Graph,
+        W: WeightElement,
+        W::Sum: Copy,
+    {
+        let config = self.find_witness(problem);
+        let mut total = W::Sum::zero();
+        for ((_, _, w), selected) in problem.edges().into_iter().zip(config) {
+            if selected == 1 {
+                total += w.to_sum();
+            }
+        }
+        Max(Some(total))
+    }
+}
+
+/// State of the blossom algorithm.
+///
+/// Vertices are `0..n` and non-trivial blossoms `n..2n`. Edge `k` has the
+/// endpoints `2k` and `2k + 1`; `p ^ 1` is the opposite endpoint of `p`.
+/// Labels are 0 (free), 1 (S, outer), 2 (T, inner); 5 marks an S-blossom
+/// visited while scanning for a common base.
+struct Blossom<S> {
+    n: usize,
+    edges: Vec<(usize, usize, S)>,
+    /// Vertex at each endpoint.
+    endpoint: Vec<usize>,
+    /// Remote endpoints of the edges incident to each vertex.
+    neighbor_endpoints: Vec<Vec<usize>>,
+    /// Remote endpoint of the matched edge of each vertex.
+    mate: Vec<usize>,
+    label: Vec<u8>,
+    /// Endpoint through which each vertex or top-level blossom got its label.
+    label_end: Vec<usize>,
+    /// Top-level blossom containing each vertex.
+    in_blossom: Vec<usize>,
+    blossom_parent: Vec<usize>,
+    /// Sub-blossoms of each blossom, in cycle order starting at the base.
+    blossom_children: Vec<Vec<usize>>,
+    blossom_base: Vec<usize>,
+    /// Endpoints connecting consecutive sub-blossoms.
+    blossom_endpoints: Vec<Vec<usize>>,
+    /// Least-slack edge to an S-vertex (for free vertices and S-blossoms).
+    best_edge: Vec<usize>,
+    /// Least-slack edges from a blossom to each neighboring S-blossom.
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    unused_blossoms: Vec<usize>,
+    dual: Vec<S>,
+    /// Edges known to have zero slack.
+    allow_edge: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+/// Index a cyclic list with a possibly negative offset.
+fn cyclic(len: usize, j: isize) -> usize {
+    j.rem_euclid(len as isize) as usize
+}
+
+impl<S: NumericSize + Copy> Blossom<S> {
+    fn new(n: usize, edges: Vec<(usize, usize, S)>) -> Self {
+        let max_weight =
+            edges
+                .iter()
+                .map(|&(_, _, w)| w)
+                .fold(S::zero(), |acc, w| if w > acc { w } else { acc });
+        let endpoint = edges.iter().flat_map(|&(u, v, _)| [u, v]).collect();
+        let mut neighbor_endpoints = vec![Vec::new(); n];
+        for (k, &(u, v, _)) in edges.iter().enumerate() {
+            neighbor_endpoints[u].push(2 * k + 1);
+            neighbor_endpoints[v].push(2 * k);
+        }
+        let mut dual = vec![max_weight; n];
+        dual.resize(2 * n, S::zero());
+        let num_edges = edges.len();
+        Self {
+            n,
+            edges,
+            endpoint,
+            neighbor_endpoints,
+            mate: vec![NONE; n],
+            label: vec![0; 2 * n],
+            label_end: vec![NONE; 2 * n],
+            in_blossom: (0..n).collect(),
+            blossom_parent: vec![NONE; 2 * n],
+            blossom_children: vec![Vec::new(); 2 * n],
+            blossom_base: (0..n).chain(std::iter::repeat_n(NONE, n)).collect(),
+            blossom_endpoints: vec![Vec::new(); 2 * n],
+            best_edge: vec![NONE; 2 * n],
+            blossom_best_edges: vec![None; 2 * n],
+            unused_blossoms: (n..2 * n).collect(),
+            dual,
+            allow_edge: vec![false; num_edges],
+            queue: Vec::new(),
+        }
+    }
+
+    /// Twice the reduced cost of edge `k`; zero for tight edges.
+    fn slack(&self, k: usize) -> S {
+        let (u, v, w) = self.edges[k];
+        self.dual[u] + self.dual[v] - (w + w)
+    }
+
+    /// All vertices contained in blossom `b`.
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![b];
+        while let Some(t) = stack.pop() {
+            if t < self.n {
+                leaves.push(t);
+            } else {
+                stack.extend(self.blossom_children[t].iter().rev());
+            }
+        }
+        leaves
+    }
+
+    /// Label the top-level blossom of `w` with `t` via endpoint `p`.
+    fn assign_label(&mut self, w: usize, t: u8, p: usize) {
+        let b = self.in_blossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.label_end[w] = p;
+        self.label_end[b] = p;
+        self.best_edge[w] = NONE;
+        self.best_edge[b] = NONE;
+        if t == 1 {
+            let leaves = self.leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            // The mate of a T-blossom's base becomes an S-vertex.
+            let base = self.blossom_base[b];
+            let mate = self.mate[base];
+            self.assign_label(self.endpoint[mate], 1, mate ^ 1);
+        }
+    }
+
+    /// Trace back from S-vertices `v` and `w` to find a new blossom's base,
+    /// or `NONE` if they lie on an augmenting path.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = Vec::new();
+        let mut base = NONE;
+        while v != NONE || w != NONE {
+            let mut b = self.in_blossom[v];
+            if self.label[b] & 4 != 0 {
+                base = self.blossom_base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = 5;
+            if self.label_end[b] == NONE {
+                // Reached the root of the alternating tree.
+                v = NONE;
+            } else {
+                v = self.endpoint[self.label_end[b]];
+                b = self.in_blossom[v];
+                v = self.endpoint[self.label_end[b]];
+            }
+            if w != NONE {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = 1;
+        }
+        base
+    }
+
+    /// Shrink the odd cycle closed by edge `k` into a new S-blossom.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (mut v, mut w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+        let b = self
+            .unused_blossoms
+            .pop()
+            .expect("at most n blossoms exist at once");
+        self.blossom_base[b] = base;
+        self.blossom_parent[b] = NONE;
+        self.blossom_parent[bb] = b;
+
+        let mut path = Vec::new();
+        let mut endps = Vec::new();
+        while bv != bb {
+            self.blossom_parent[bv] = b;
+            path.push(bv);
+            endps.push(self.label_end[bv]);
+            v = self.endpoint[self.label_end[bv]];
+            bv = self.in_blossom[v];
+        }
+        path.push(bb);
+        path.reverse();
+        endps.reverse();
+        endps.push(2 * k);
+        while bw != bb {
+            self.blossom_parent[bw] = b;
+            path.push(bw);
+            endps.push(self.label_end[bw] ^ 1);
+            w = self.endpoint[self.label_end[bw]];
+            bw = self.in_blossom[w];
+        }
+
+        self.label[b] = 1;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = S::zero();
+        self.blossom_children[b] = path.clone();
+        self.blossom_endpoints[b] = endps;
+        for v in self.leaves(b) {
+            if self.label[self.in_blossom[v]] == 2 {
+                // Former T-vertices become S-vertices and must be scanned.
+                self.queue.push(v);
+            }
+            self.in_blossom[v] = b;
+        }
+
+        // Compute the least-slack edges to neighboring S-blossoms.
+        let mut best_edge_to = vec![NONE; 2 * self.n];
+        for &bv in &path {
+            let candidates: Vec<usize> = match self.blossom_best_edges[bv].take() {
+                Some(list) => list,
+                None => self
+                    .leaves(bv)
+                    .into_iter()
+                    .flat_map(|v| self.neighbor_endpoints[v].iter().map(|&p| p / 2))
+                    .collect(),
+            };
+            for k in candidates {
+                // The endpoint outside the new blossom, if any.
+                let (i, j, _) = self.edges[k];
+                let j = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[j];
+                if bj != b
+                    && self.label[bj] == 1
+                    && (best_edge_to[bj] == NONE || self.slack(k) < self.slack(best_edge_to[bj]))
+                {
+                    best_edge_to[bj] = k;
+                }
+            }
+            self.best_edge[bv] = NONE;
+        }
+        let best_edges: Vec<usize> = best_edge_to.into_iter().filter(|&k| k != NONE).collect();
+        self.best_edge[b] = NONE;
+        for &k in &best_edges {
+            if self.best_edge[b] == NONE || self.slack(k) < self.slack(self.best_edge[b]) {
+                self.best_edge[b] = k;
+            }
+        }
+        self.blossom_best_edges[b] = Some(best_edges);
+    }
+
+    /// Dissolve blossom `b`, relabeling its sub-blossoms if it was a
+    /// T-blossom in the middle of a stage.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        for s in self.blossom_children[b].clone() {
+            self.blossom_parent[s] = NONE;
+            if s < self.n {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.dual[s] == S::zero() {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for v in self.leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+
+        if !end_stage && self.label[b] == 2 {
+            // Relabel the even-length path from the entry child to the base.
+            let children = self.blossom_children[b].clone();
+            let endps = self.blossom_endpoints[b].clone();
+            let len = children.len();
+            let entry_child = self.in_blossom[self.endpoint[self.label_end[b] ^ 1]];
+            let mut j = children
+                .iter()
+                .position(|&c| c == entry_child)
+                .expect("entry child belongs to the blossom") as isize;
+            let (jstep, endptrick): (isize, usize) = if j & 1 == 1 {
+                j -= len as isize;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            let mut p = self.label_end[b];
+            while j != 0 {
+                self.label[self.endpoint[p ^ 1]] = 0;
+                let q = endps[cyclic(len, j - endptrick as isize)];
+                self.label[self.endpoint[q ^ endptrick ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, p);
+                self.allow_edge[q / 2] = true;
+                j += jstep;
+                p = endps[cyclic(len, j - endptrick as isize)] ^ endptrick;
+                self.allow_edge[p / 2] = true;
+                j += jstep;
+            }
+            let bv = children[cyclic(len, j)];
+            self.label[self.endpoint[p ^ 1]] = 2;
+            self.label[bv] = 2;
+            self.label_end[self.endpoint[p ^ 1]] = p;
+            self.label_end[bv] = p;
+            self.best_edge[bv] = NONE;
+            j += jstep;
+
+            // Sub-blossoms off the path may have been reached from outside.
+            while children[cyclic(len, j)] != entry_child {
+                let bv = children[cyclic(len, j)];
+                if self.label[bv] == 1 {
+                    j += jstep;
+                    continue;
+                }
+                let labeled = self.leaves(bv).into_iter().find(|&v| self.label[v] != 0);
+                if let Some(v) = labeled {
+                    self.label[v] = 0;
+                    let base_mate = self.mate[self.blossom_base[bv]];
+                    self.label[self.endpoint[base_mate]] = 0;
+                    self.assign_label(v, 2, self.label_end[v]);
+                }
+                j += jstep;
+            }
+        }
+
+        self.label[b] = 0;
+        self.label_end[b] = NONE;
+        self.blossom_children[b].clear();
+        self.blossom_endpoints[b].clear();
+        self.blossom_base[b] = NONE;
+        self.blossom_best_edges[b] = None;
+        self.best_edge[b] = NONE;
+        self.unused_blossoms.push(b);
+    }
+
+    /// Swap matched and unmatched edges along the even path from vertex `v`
+    /// to the base of blossom `b`, making `v` the new base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.blossom_parent[t] != b {
+            t = self.blossom_parent[t];
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+
+        let len = self.blossom_children[b].len();
+        let i = self.blossom_children[b]
+            .iter()
+            .position(|&c| c == t)
+            .expect("sub-blossom belongs to the blossom");
+        let mut j = i as isize;
+        let (jstep, endptrick): (isize, usize) = if i & 1 == 1 {
+            j -= len as isize;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+        while j != 0 {
+            j += jstep;
+            let t = self.blossom_children[b][cyclic(len, j)];
+            let p = self.blossom_endpoints[b][cyclic(len, j - endptrick as isize)] ^ endptrick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += jstep;
+            let t = self.blossom_children[b][cyclic(len, j)];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+
+        self.blossom_children[b].rotate_left(i);
+        self.blossom_endpoints[b].rotate_left(i);
+        self.blossom_base[b] = self.blossom_base[self.blossom_children[b][0]];
+    }
+
+    /// Augment the matching along the path through edge `k` between two
+    /// S-vertices in different trees.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.label_end[bs] == NONE {
+                    // Reached a tree root.
+                    break;
+                }
+                let t = self.endpoint[self.label_end[bs]];
+                let bt = self.in_blossom[t];
+                s = self.endpoint[self.label_end[bt]];
+                let j = self.endpoint[self.label_end[bt] ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.label_end[bt];
+                p = self.label_end[bt] ^ 1;
+            }
+        }
+    }
+
+    /// Run all stages and return the indices of the matched edges.
+    fn solve(mut self) -> Vec<usize> {
+        let n = self.n;
+        let two = S::one() + S::one();
+
+        for _ in 0..n {
+            // Each stage either augments the matching or proves it optimal.
+            self.label.fill(0);
+            self.best_edge.fill(NONE);
+            for best in &mut self.blossom_best_edges[n..] {
+                *best = None;
+            }
+            self.allow_edge.fill(false);
+            self.queue.clear();
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.in_blossom[v]] == 0 {
+                    self.assign_label(v, 1, NONE);
+                }
+            }
+
+            let mut augmented = false;
+            loop {
+                while let Some(v) = self.queue.pop() {
+                    for p in self.neighbor_endpoints[v].clone() {
+                        let k = p / 2;
+                        let w = self.endpoint[p];
+                        if self.in_blossom[v] == self.in_blossom[w] {
+                            continue;
+                        }
+                        let mut kslack = S::zero();
+                        if !self.allow_edge[k] {
+                            kslack = self.slack(k);
+                            if kslack <= S::zero() {
+                                self.allow_edge[k] = true;
+                            }
+                        }
+                        if self.allow_edge[k] {
+                            let bw = self.in_blossom[w];
+                            if self.label[bw] == 0 {
+                                // w is free: grow the tree.
+                                self.assign_label(w, 2, p ^ 1);
+                            } else if self.label[bw] == 1 {
+                                let base = self.scan_blossom(v, w);
+                                if base != NONE {
+                                    self.add_blossom(base, k);
+                                } else {
+                                    self.augment_matching(k);
+                                    augmented = true;
+                                    break;
+                                }
+                            } else if self.label[w] == 0 {
+                                // w is inside a T-blossom but not yet labeled.
+                                self.label[w] = 2;
+                                self.label_end[w] = p ^ 1;
+                            }
+                        } else if self.label[self.in_blossom[w]] == 1 {
+                            let b = self.in_blossom[v];
+                            if self.best_edge[b] == NONE || kslack < self.slack(self.best_edge[b]) {
+                                self.best_edge[b] = k;
+                            }
+                        } else if self.label[w] == 0
+                            && (self.best_edge[w] == NONE || kslack < self.slack(self.best_edge[w]))
+                        {
+                            self.best_edge[w] = k;
+                        }
+                    }
+                    if augmented {
+                        break;
+                    }
+                }
+                if augmented {
+                    break;
+                }
+
+                // No tight edge left: pick the smallest dual adjustment.
+                // 1: a vertex dual reaches zero; 2: an edge from an S-vertex
+                // to a free vertex becomes tight; 3: an edge between
+                // S-blossoms becomes tight; 4: a T-blossom dual reaches zero.
+                let mut delta_type = 1;
+                let mut delta = self.dual[..n].iter().copied().fold(self.dual[0], |acc, d| {
+                    if d < acc {
+                        d
+                    } else {
+                        acc
+                    }
+                });
+                let mut delta_edge = NONE;
+                let mut delta_blossom = NONE;
+                for v in 0..n {
+                    if self.label[self.in_blossom[v]] == 0 && self.best_edge[v] != NONE {
+                        let d = self.slack(self.best_edge[v]);
+                        if d < delta {
+                            delta = d;
+                            delta_type = 2;
+                            delta_edge = self.best_edge[v];
+                        }
+                    }
+                }
+                for b in 0..2 * n {
+                    if self.blossom_parent[b] == NONE
+                        && self.label[b] == 1
+                        && self.best_edge[b] != NONE
+                    {
+                        let d = self.slack(self.best_edge[b]) / two;
+                        if d < delta {
+                            delta = d;
+                            delta_type = 3;
+                            delta_edge = self.best_edge[b];
+                        }
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossom_base[b] != NONE
+                        && self.blossom_parent[b] == NONE
+                        && self.label[b] == 2
+                        && self.dual[b] < delta
+                    {
+                        delta = self.dual[b];
+                        delta_type = 4;
+                        delta_blossom = b;
+                    }
+                }
+
+                for v in 0..n {
+                    match self.label[self.in_blossom[v]] {
+                        1 => self.dual[v] = self.dual[v] - delta,
+                        2 => self.dual[v] += delta,
+                        _ => {}
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossom_base[b] != NONE && self.blossom_parent[b] == NONE {
+                        match self.label[b] {
+                            1 => self.dual[b] += delta,
+                            2 => self.dual[b] = self.dual[b] - delta,
+                            _ => {}
+                        }
+                    }
+                }
+
+                match delta_type {
+                    1 => break,
+                    2 => {
+                        self.allow_edge[delta_edge] = true;
+                        let (mut i, mut j, _) = self.edges[delta_edge];
+                        if self.label[self.in_blossom[i]] == 0 {
+                            std::mem::swap(&mut i, &mut j);
+                        }
+                        self.queue.push(i);
+                    }
+                    3 => {
+                        self.allow_edge[delta_edge] = true;
+                        let (i, _, _) = self.edges[delta_edge];
+                        self.queue.push(i);
+                    }
+                    _ => self.expand_blossom(delta_blossom, false),
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+
+            // Expand S-blossoms whose dual dropped to zero.
+            for b in n..2 * n {
+                if self.blossom_parent[b] == NONE
+                    && self.blossom_base[b] != NONE
+                    && self.label[b] == 1
+                    && self.dual[b] == S::zero()
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+
+        (0..n)
+            .filter(|&v| self.mate[v] != NONE && v == self.edges[self.mate[v] / 2].0)
+            .map(|v| self.mate[v] / 2)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/solvers/blossom.rs"]
+mod tests;
@@ -1,8 +1,9 @@
 //! Brute force solver that enumerates all configurations.
 
-use crate::config::DimsIterator;
+use crate::config::{fold_bit_configs, fold_configs, fold_permutations, BitConfig};
+use crate::models::permutation::{PermutationConstraint, PermutationProblem};
 use crate::solvers::Solver;
-use crate::traits::Problem;
+use crate::traits::{BitEvaluator, Problem};
 use crate::types::Aggregate;
 
 /// A brute force solver that enumerates all possible configurations.
@@ -34,40 +35,58 @@ impl BruteForce {
         P: Problem,
         P::Value: Aggregate,
     {
-        let total = self.solve(problem);
-
         if !P::Value::supports_witnesses() {
             return vec![];
         }
 
-        DimsIterator::new(problem.dims())
-            .filter(|config| {
-                let value = problem.evaluate(config);
-                P::Value::contributes_to_witnesses(&value, &total)
-            })
-            .collect()
+        solve_with_witnesses(problem).1
     }
 
-    /// Solve a problem and collect all witness configurations in one passable API.
+    /// Solve a problem and collect all witness configurations in a single pass.
+    ///
+    /// Only configurations tied with the running aggregate are retained, so
+    /// memory is bounded by the number of optima rather than the search space.
     pub fn solve_with_witnesses<P>(&self, problem: &P) -> (P::Value, Vec<Vec<usize>>)
     where
         P: Problem,
         P::Value: Aggregate,
     {
-        let total = self.solve(problem);
-
         if !P::Value::supports_witnesses() {
-            return (total, vec![]);
+            return (self.solve(problem), vec![]);
         }
 
-        let witnesses = DimsIterator::new(problem.dims())
-            .filter(|config| {
-                let value = problem.evaluate(config);
-                P::Value::contributes_to_witnesses(&value, &total)
-            })
-            .collect();
+        solve_with_witnesses(problem)
+    }
+
+    /// Solve a permutation problem by enumerating only the `n!` permutations
+    /// rather than all `n^n` configurations.
+    pub fn solve_permutations<C>(&self, problem: &PermutationProblem<C>) -> C::Value
+    where
+        C: PermutationConstraint,
+    {
+        fold_permutations(problem.num_elements(), C::Value::identity(), |acc, perm| {
+            C::Value::combine(acc, problem.evaluate(perm))
+        })
+    }
 
-        (total, witnesses)
+    /// Find all witness permutations of a permutation problem.
+    pub fn find_all_permutation_witnesses<C>(
+        &self,
+        problem: &PermutationProblem<C>,
+    ) -> Vec<Vec<usize>>
+    where
+        C: PermutationConstraint,
+    {
+        if !C::Value::supports_witnesses() {
+            return vec![];
+        }
+        let total = self.solve_permutations(problem);
+        fold_permutations(problem.num_elements(), Vec::new(), |mut witnesses, perm| {
+            if C::Value::contributes_to_witnesses(&problem.evaluate(perm), &total) {
+                witnesses.push(perm.to_vec());
+            }
+            witnesses
+        })
     }
 }
 
@@ -77,10 +96,117 @@ impl Solver for BruteForce {
         P: Problem,
         P::Value: Aggregate,
     {
-        DimsIterator::new(problem.dims())
-            .map(|config| problem.evaluate(&config))
-            .fold(P::Value::identity(), P::Value::combine)
+        match bit_evaluator(problem) {
+            Some(evaluate) => solve_bits(problem.num_variables(), &evaluate),
+            None => solve_configs(problem),
+        }
+    }
+}
+
+/// Return the problem's bitmask evaluator when the bitset fast path applies:
+/// at most 64 variables, all of them binary.
+fn bit_evaluator<P: Problem>(problem: &P) -> Option<BitEvaluator<'_, P::Value>> {
+    let dims = problem.dims();
+    if dims.len() > BitConfig::MAX_VARIABLES || dims.iter().any(|&d| d != 2) {
+        return None;
     }
+    problem.bit_evaluator()
+}
+
+/// Generic path: fold the aggregate over every configuration slice.
+fn solve_configs<P>(problem: &P) -> P::Value
+where
+    P: Problem,
+    P::Value: Aggregate,
+{
+    fold_configs(&problem.dims(), P::Value::identity(), |acc, config| {
+        P::Value::combine(acc, problem.evaluate(config))
+    })
+}
+
+/// Bitset path: fold the aggregate over every packed binary configuration.
+fn solve_bits<V: Aggregate>(num_variables: usize, evaluate: &BitEvaluator<'_, V>) -> V {
+    fold_bit_configs(num_variables, V::identity(), |acc, bits| {
+        V::combine(acc, evaluate(bits))
+    })
+}
+
+/// Running state of a single-pass witness search.
+///
+/// Retained witnesses all share one value. When the running total moves past
+/// that value they are dropped together, so at any time only configurations
+/// tied with the current total are kept.
+struct WitnessFold<V> {
+    total: V,
+    witnesses: Vec<Vec<usize>>,
+    witness_value: Option<V>,
+}
+
+impl<V: Aggregate> WitnessFold<V> {
+    fn new() -> Self {
+        Self {
+            total: V::identity(),
+            witnesses: Vec::new(),
+            witness_value: None,
+        }
+    }
+
+    /// Fold in one configuration; `config` is only materialized if it is kept.
+    fn push(mut self, value: V, config: impl FnOnce() -> Vec<usize>) -> Self {
+        self.total = V::combine(self.total, value.clone());
+        let stale = self
+            .witness_value
+            .as_ref()
+            .is_some_and(|kept| !V::contributes_to_witnesses(kept, &self.total));
+        if stale {
+            self.witnesses.clear();
+            self.witness_value = None;
+        }
+        if V::contributes_to_witnesses(&value, &self.total) {
+            self.witnesses.push(config());
+            self.witness_value.get_or_insert(value);
+        }
+        self
+    }
+
+    fn finish(self) -> (V, Vec<Vec<usize>>) {
+        (self.total, self.witnesses)
+    }
+}
+
+/// Compute the aggregate and every witness configuration in one pass.
+fn solve_with_witnesses<P>(problem: &P) -> (P::Value, Vec<Vec<usize>>)
+where
+    P: Problem,
+    P::Value: Aggregate,
+{
+    match bit_evaluator(problem) {
+        Some(evaluate) => fold_bit_witnesses(problem.num_variables(), &evaluate),
+        None => fold_config_witnesses(problem),
+    }
+}
+
+/// Generic path of [`solve_with_witnesses`].
+fn fold_config_witnesses<P>(problem: &P) -> (P::Value, Vec<Vec<usize>>)
+where
+    P: Problem,
+    P::Value: Aggregate,
+{
+    fold_configs(&problem.dims(), WitnessFold::new(), |fold, config| {
+        fold.push(problem.evaluate(config), || config.to_vec())
+    })
+    .finish()
+}
+
+/// Bitset path of [`solve_with_witnesses`]; only witnesses are unpacked.
+fn fold_bit_witnesses<V: Aggregate>(
+    num_variables: usize,
+    evaluate: &BitEvaluator<'_, V>,
+) -> (V, Vec<Vec<usize>>) {
+    fold_bit_configs(num_variables, WitnessFold::new(), |fold, bits| {
+        fold.push(evaluate(bits), || bits.to_config(num_variables))
+    })
+    .finish()
 }
 
 #[cfg(test)]
@@ -0,0 +1,127 @@
+//! Diversity of the optimal solutions of an optimization problem.
+//!
+//! When an instance has many optima, their spread says whether they are
+//! essentially one solution with small perturbations or genuinely different
+//! alternatives. [`optima_summary`] enumerates all optima with a
+//! [`BruteForce`] solver and reports pairwise Hamming distance statistics
+//! together with a small, mutually distant set of representatives.
+
+use crate::solvers::BruteForce;
+use crate::traits::Problem;
+use crate::types::OptimizationValue;
+use serde::Serialize;
+
+/// Maximum number of representative optima kept by [`optima_summary`].
+pub const NUM_REPRESENTATIVES: usize = 5;
+
+/// Summary of the set of optimal configurations of an instance.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OptimaSummary {
+    /// Number of optimal configurations.
+    pub num_optima: usize,
+    /// Minimum pairwise Hamming distance (0 with fewer than two optima).
+    pub min_distance: usize,
+    /// Mean pairwise Hamming distance (0 with fewer than two optima).
+    pub mean_distance: f64,
+    /// Maximum pairwise Hamming distance (0 with fewer than two optima).
+    pub max_distance: usize,
+    /// Up to [`NUM_REPRESENTATIVES`] optima chosen by farthest-point
+    /// selection, starting from the first optimum in enumeration order.
+    pub representatives: Vec<Vec<usize>>,
+}
+
+impl OptimaSummary {
+    /// Whether the optimum is unique (or there is none), i.e. no diversity.
+    pub fn is_unique(&self) -> bool {
+        self.num_optima <= 1
+    }
+}
+
+/// Number of positions at which two configurations differ.
+pub fn hamming_distance(a: &[usize], b: &[usize]) -> usize {
+    a.iter().zip(b).filter(|(x, y)| x != y).count()
+}
+
+/// Enumerate all optima of `problem` and summarize how diverse they are.
+///
+/// Pairwise statistics take `O(k^2 n)` time for `k` optima over `n`
+/// variables, on top of the exponential enumeration itself.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MaximumClique;
+/// use problemreductions::solvers::diversity::optima_summary;
+/// use problemreductions::solvers::BruteForce;
+/// use problemreductions::topology::SimpleGraph;
+///
+/// // Every single vertex of an edgeless graph is a maximum clique.
+/// let problem = MaximumClique::new(SimpleGraph::new(3, vec![]), vec![1i32; 3]);
+/// let summary = optima_summary(&problem, &BruteForce::new());
+/// assert_eq!(summary.num_optima, 3);
+/// assert_eq!(summary.min_distance, 2);
+/// assert_eq!(summary.max_distance, 2);
+/// ```
+pub fn optima_summary<P>(problem: &P, solver: &BruteForce) -> OptimaSummary
+where
+    P: Problem,
+    P::Value: OptimizationValue,
+{
+    let optima = solver.find_all_witnesses(problem);
+
+    let mut min_distance = usize::MAX;
+    let mut max_distance = 0;
+    let mut total = 0;
+    let mut num_pairs = 0;
+    for (i, a) in optima.iter().enumerate() {
+        for b in &optima[i + 1..] {
+            let d = hamming_distance(a, b);
+            min_distance = min_distance.min(d);
+            max_distance = max_distance.max(d);
+            total += d;
+            num_pairs += 1;
+        }
+    }
+    let mean_distance = if num_pairs == 0 {
+        0.0
+    } else {
+        total as f64 / num_pairs as f64
+    };
+
+    OptimaSummary {
+        num_optima: optima.len(),
+        min_distance: if num_pairs == 0 { 0 } else { min_distance },
+        mean_distance,
+        max_distance,
+        representatives: farthest_points(&optima, NUM_REPRESENTATIVES),
+    }
+}
+
+/// Greedily pick up to `k` configurations, each maximizing its distance to
+/// those already picked. Ties go to the earliest configuration.
+fn farthest_points(configs: &[Vec<usize>], k: usize) -> Vec<Vec<usize>> {
+    let Some(first) = configs.first() else {
+        return vec![];
+    };
+    let mut picked = vec![0];
+    let mut nearest: Vec<usize> = configs.iter().map(|c| hamming_distance(c, first)).collect();
+    while picked.len() < k.min(configs.len()) {
+        let (next, &d) = nearest
+            .iter()
+            .enumerate()
+            .max_by(|(i, a), (j, b)| a.cmp(b).then(j.cmp(i)))
+            .unwrap();
+        if d == 0 {
+            break;
+        }
+        picked.push(next);
+        for (i, c) in configs.iter().enumerate() {
+            nearest[i] = nearest[i].min(hamming_distance(c, &configs[next]));
+        }
+    }
+    picked.into_iter().map(|i| configs[i].clone()).collect()
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/solvers/diversity.rs"]
+mod tests;
@@ -0,0 +1,108 @@
+//! Greedy construction refined by 1-flip local search.
+
+use super::local_search::one_flip;
+use super::{improves, is_feasible, GreedySolver, LocalSearch};
+use crate::solvers::Solver;
+use crate::traits::Problem;
+use crate::types::Aggregate;
+
+/// [`GreedySolver`] followed by 1-flip local search, with random restarts.
+///
+/// The first run starts from the greedy configuration; every further run
+/// starts from a random configuration drawn as in [`LocalSearch`]. Each run
+/// then changes single variables while that strictly improves the value, and
+/// the best configuration over all runs is returned, so the result is never
+/// worse than the greedy one.
+///
+/// On [`MaxCut`](crate::models::graph::MaxCut) this is the classical greedy +
+/// 1-move heuristic: vertices move across the cut while that increases the
+/// cut weight. The result is a local optimum, **not** necessarily a global
+/// one. Runs are deterministic for a fixed seed.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MaxCut;
+/// use problemreductions::solvers::{GreedyLocalSearch, Solver};
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::types::Max;
+///
+/// // A 4-cycle is bipartite, so every edge can be cut.
+/// let graph = SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let problem = MaxCut::<_, i32>::unweighted(graph);
+/// let solver = GreedyLocalSearch::new(42, 4);
+/// assert_eq!(solver.solve(&problem), Max(Some(4)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GreedyLocalSearch {
+    /// Seed for the random starting configurations.
+    pub seed: u64,
+    /// Number of runs, the greedy one included (at least one is made).
+    pub restarts: usize,
+}
+
+impl Default for GreedyLocalSearch {
+    fn default() -> Self {
+        Self::new(0, 10)
+    }
+}
+
+impl GreedyLocalSearch {
+    /// Create a solver with the given seed and restart count.
+    pub fn new(seed: u64, restarts: usize) -> Self {
+        Self { seed, restarts }
+    }
+
+    /// Find the best 1-flip local optimum over all runs, or `None` if none is
+    /// feasible or the value type does not admit witnesses.
+    pub fn find_witness<P>(&self, problem: &P) -> Option<Vec<usize>>
+    where
+        P: Problem,
+        P::Value: Aggregate,
+    {
+        let dims = problem.dims();
+        if !P::Value::supports_witnesses() || dims.contains(&0) {
+            return None;
+        }
+
+        let random = LocalSearch::new(0, self.restarts, self.seed);
+        let greedy = GreedySolver::new()
+            .find_witness(problem)
+            .unwrap_or_else(|| random.initial_config(problem, 0));
+        let starts = std::iter::once(greedy).chain(
+            (1..self.restarts.max(1)).map(|restart| random.initial_config(problem, restart)),
+        );
+
+        let mut best: Option<(Vec<usize>, P::Value)> = None;
+        for mut config in starts {
+            let mut value = problem.evaluate(&config);
+            while one_flip(problem, &dims, &mut config, &mut value) {}
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_value)| improves(best_value, &value))
+            {
+                best = Some((config, value));
+            }
+        }
+
+        best.filter(|(_, value)| is_feasible(value))
+            .map(|(config, _)| config)
+    }
+}
+
+impl Solver for GreedyLocalSearch {
+    /// Value of the best local optimum found (not necessarily optimal).
+    fn solve<P>(&self, problem: &P) -> P::Value
+    where
+        P: Problem,
+        P::Value: Aggregate,
+    {
+        self.find_witness(problem)
+            .map(|config| problem.evaluate(&config))
+            .unwrap_or_else(P::Value::identity)
+    }
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/solvers/heuristics/greedy_local_search.rs"]
+mod tests;
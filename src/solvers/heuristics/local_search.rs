@@ -0,0 +1,178 @@
+//! Randomized 1-flip / 2-flip local search.
+
+use super::{improves, is_feasible};
+use crate::solvers::Solver;
+use crate::traits::Problem;
+use crate::types::Aggregate;
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
+
+/// A randomized local search over the configuration space.
+///
+/// Each restart draws a uniformly random configuration and applies
+/// improving moves until none is left or `max_iters` moves were made.
+/// A move changes one variable (1-flip); when no 1-flip improves, pairs of
+/// variables are changed together (2-flip), which escapes local optima such
+/// as a single frustrated edge in a spin glass. The best configuration over
+/// all restarts is returned.
+///
+/// The result is a local optimum, **not** necessarily a global one. The
+/// search suits unconstrained problems such as QUBO, SpinGlass and MaxCut;
+/// on constrained problems a random start may be infeasible with no
+/// improving move in reach. Runs are deterministic for a fixed seed.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::graph::MaxCut;
+/// use problemreductions::solvers::{LocalSearch, Solver};
+/// use problemreductions::topology::SimpleGraph;
+/// use problemreductions::types::Max;
+///
+/// // A 4-cycle is bipartite, so every edge can be cut.
+/// let graph = SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let problem = MaxCut::<_, i32>::unweighted(graph);
+/// assert_eq!(LocalSearch::new(100, 3, 7).solve(&problem), Max(Some(4)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalSearch {
+    /// Maximum number of improving moves per restart.
+    pub max_iters: usize,
+    /// Number of independent random starts (at least one is made).
+    pub restarts: usize,
+    /// Seed for the random starting configurations.
+    pub seed: u64,
+}
+
+impl Default for LocalSearch {
+    fn default() -> Self {
+        Self::new(1000, 10, 0)
+    }
+}
+
+impl LocalSearch {
+    /// Create a local search with the given move limit, restart count and seed.
+    pub fn new(max_iters: usize, restarts: usize, seed: u64) -> Self {
+        Self {
+            max_iters,
+            restarts,
+            seed,
+        }
+    }
+
+    /// The random starting configuration of the given restart.
+    pub fn initial_config<P: Problem>(&self, problem: &P, restart: usize) -> Vec<usize> {
+        let mut rng = SmallRng::seed_from_u64(self.seed.wrapping_add(restart as u64));
+        problem
+            .dims()
+            .into_iter()
+            .map(|d| rng.random_range(0..d.max(1)))
+            .collect()
+    }
+
+    /// Find the best local optimum over all restarts, or `None` if none is
+    /// feasible or the value type does not admit witnesses.
+    pub fn find_witness<P>(&self, problem: &P) -> Option<Vec<usize>>
+    where
+        P: Problem,
+        P::Value: Aggregate,
+    {
+        let dims = problem.dims();
+        if !P::Value::supports_witnesses() || dims.contains(&0) {
+            return None;
+        }
+
+        let mut best: Option<(Vec<usize>, P::Value)> = None;
+        for restart in 0..self.restarts.max(1) {
+            let mut config = self.initial_config(problem, restart);
+            let mut value = problem.evaluate(&config);
+            for _ in 0..self.max_iters {
+                let improved = one_flip(problem, &dims, &mut config, &mut value)
+                    || two_flip(problem, &dims, &mut config, &mut value);
+                if !improved {
+                    break;
+                }
+            }
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_value)| improves(best_value, &value))
+            {
+                best = Some((config, value));
+            }
+        }
+
+        best.filter(|(_, value)| is_feasible(value))
+            .map(|(config, _)| config)
+    }
+}
+
+impl Solver for LocalSearch {
+    /// Value of the best local optimum found (not necessarily optimal).
+    fn solve<P>(&self, problem: &P) -> P::Value
+    where
+        P: Problem,
+        P::Value: Aggregate,
+    {
+        self.find_witness(problem)
+            .map(|config| problem.evaluate(&config))
+            .unwrap_or_else(P::Value::identity)
+    }
+}
+
+/// Apply the first improving single-variable change, if any.
+pub(super) fn one_flip<P>(
+    problem: &P,
+    dims: &[usize],
+    config: &mut [usize],
+    value: &mut P::Value,
+) -> bool
+where
+    P: Problem,
+    P::Value: Aggregate,
+{
+    for (i, &d) in dims.iter().enumerate() {
+        let current = config[i];
+        for v in (0..d).filter(|&v| v != current) {
+            config[i] = v;
+            let next = problem.evaluate(config);
+            if improves(value, &next) {
+                *value = next;
+                return true;
+            }
+        }
+        config[i] = current;
+    }
+    false
+}
+
+/// Apply the first improving change of two variables at once, if any.
+fn two_flip<P>(problem: &P, dims: &[usize], config: &mut [usize], value: &mut P::Value) -> bool
+where
+    P: Problem,
+    P::Value: Aggregate,
+{
+    for i in 0..dims.len() {
+        let current_i = config[i];
+        for j in (i + 1)..dims.len() {
+            let current_j = config[j];
+            for v in (0..dims[i]).filter(|&v| v != current_i) {
+                for w in (0..dims[j]).filter(|&w| w != current_j) {
+                    config[i] = v;
+                    config[j] = w;
+                    let next = problem.evaluate(config);
+                    if improves(value, &next) {
+                        *value = next;
+                        return true;
+                    }
+                }
+            }
+            config[i] = current_i;
+            config[j] = current_j;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/solvers/heuristics/local_search.rs"]
+mod tests;
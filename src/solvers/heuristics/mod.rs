@@ -0,0 +1,31 @@
+//! Heuristic solvers for instances beyond the reach of exact search.
+//!
+//! [`GreedySolver`], [`LocalSearch`] and [`GreedyLocalSearch`] work on any
+//! [`Problem`] whose value admits witnesses (`Max`, `Min`, `Or`, `Extremum`)
+//! and return the best configuration they find. That configuration is generally **not** optimal;
+//! use [`SolutionQuality`] to compare its value against a bound.
+//!
+//! [`Problem`]: crate::traits::Problem
+
+mod greedy;
+mod greedy_local_search;
+mod local_search;
+mod quality;
+
+pub use greedy::GreedySolver;
+pub use greedy_local_search::GreedyLocalSearch;
+pub use local_search::LocalSearch;
+pub use quality::SolutionQuality;
+
+use crate::types::Aggregate;
+
+/// Whether `candidate` is strictly better than `current`.
+fn improves<V: Aggregate>(current: &V, candidate: &V) -> bool {
+    let best = current.clone().combine(candidate.clone());
+    V::contributes_to_witnesses(candidate, &best) && !V::contributes_to_witnesses(current, &best)
+}
+
+/// Whether a configuration value is feasible, i.e. could be a witness.
+fn is_feasible<V: Aggregate>(value: &V) -> bool {
+    V::contributes_to_witnesses(value, value)
+}
@@ -0,0 +1,139 @@
+//! Quality reports for heuristic solutions.
+
+use crate::types::ExtremumSense;
+use serde::Serialize;
+use std::fmt;
+
+/// How close a heuristic objective value is to a bound on the optimum.
+///
+/// The bound is an upper bound when maximizing and a lower bound when
+/// minimizing, so the optimum lies between `value` and `bound`. It may come
+/// from the LP relaxation of an ILP formulation (with the `ilp-solver`
+/// feature) or from a trivial bound such as the total weight.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::solvers::SolutionQuality;
+/// use problemreductions::types::ExtremumSense;
+///
+/// // An independent set of weight 6 when all weights sum to 8.
+/// let quality = SolutionQuality::with_trivial_bound(
+///     6.0,
+///     ExtremumSense::Maximize,
+///     [2.0, 2.0, 3.0, 1.0],
+/// );
+/// assert_eq!(quality.bound, 8.0);
+/// assert_eq!(quality.gap(), 2.0);
+/// assert_eq!(quality.relative_gap(), 0.25);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SolutionQuality {
+    /// Objective value of the heuristic solution.
+    pub value: f64,
+    /// Bound on the optimal objective value.
+    pub bound: f64,
+    /// Optimization direction of the objective.
+    pub sense: ExtremumSense,
+}
+
+impl SolutionQuality {
+    /// Compare `value` against a known bound.
+    pub fn new(value: f64, bound: f64, sense: ExtremumSense) -> Self {
+        Self {
+            value,
+            bound,
+            sense,
+        }
+    }
+
+    /// Compare `value` against the trivial bound for objectives that sum a
+    /// subset of `weights`: the sum of the positive weights when maximizing,
+    /// or of the negative weights when minimizing.
+    pub fn with_trivial_bound(
+        value: f64,
+        sense: ExtremumSense,
+        weights: impl IntoIterator<Item = f64>,
+    ) -> Self {
+        let bound = weights
+            .into_iter()
+            .filter(|&w| match sense {
+                ExtremumSense::Maximize => w > 0.0,
+                ExtremumSense::Minimize => w < 0.0,
+            })
+            .sum();
+        Self::new(value, bound, sense)
+    }
+
+    /// Compare `value` against the LP relaxation of the problem's ILP formulation.
+    ///
+    /// The relaxation bounds the source optimum when the reduction preserves
+    /// the objective value, as the direct `<Problem> -> ILP` formulations do.
+    /// Returns `None` if the relaxation cannot be solved.
+    #[cfg(feature = "ilp-solver")]
+    pub fn against_lp_relaxation<P>(problem: &P, value: f64) -> Option<Self>
+    where
+        P: crate::rules::ReduceTo<crate::models::algebraic::ILP<bool>>,
+    {
+        use crate::models::algebraic::ObjectiveSense;
+        use crate::rules::ReductionResult;
+
+        let reduction = problem.reduce_to();
+        let ilp = reduction.target_problem();
+        let bound = crate::solvers::ILPSolver::new().solve_relaxation(ilp)?;
+        let sense = match ilp.sense {
+            ObjectiveSense::Maximize => ExtremumSense::Maximize,
+            ObjectiveSense::Minimize => ExtremumSense::Minimize,
+        };
+        Some(Self::new(value, bound, sense))
+    }
+
+    /// Distance from `value` to `bound` in the improving direction.
+    ///
+    /// Non-negative whenever the bound is valid.
+    pub fn gap(&self) -> f64 {
+        match self.sense {
+            ExtremumSense::Maximize => self.bound - self.value,
+            ExtremumSense::Minimize => self.value - self.bound,
+        }
+    }
+
+    /// The gap relative to the magnitude of the bound.
+    ///
+    /// Returns 0 when the gap is zero and infinity when only the bound is zero.
+    pub fn relative_gap(&self) -> f64 {
+        let gap = self.gap();
+        if gap == 0.0 {
+            0.0
+        } else if self.bound == 0.0 {
+            f64::INFINITY
+        } else {
+            gap / self.bound.abs()
+        }
+    }
+
+    /// Whether `value` meets the bound, which proves it optimal.
+    pub fn is_optimal(&self) -> bool {
+        self.gap() <= 1e-9 * self.bound.abs().max(1.0)
+    }
+}
+
+impl fmt::Display for SolutionQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.sense {
+            ExtremumSense::Maximize => "upper",
+            ExtremumSense::Minimize => "lower",
+        };
+        write!(
+            f,
+            "value {} vs {kind} bound {} (gap {:.2}%)",
+            self.value,
+            self.bound,
+            100.0 * self.relative_gap()
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "../../unit_tests/solvers/heuristics/quality.rs"]
+mod tests;
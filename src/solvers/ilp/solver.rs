@@ -9,6 +9,8 @@ use good_lp::default_solver;
 use good_lp::highs;
 #[cfg(feature = "ilp-highs")]
 use good_lp::solvers::highs::HighsParallelType;
+#[cfg(feature = "ilp-highs")]
+use good_lp::WithInitialSolution;
 use good_lp::{variable, ProblemVariables, Solution, SolverModel, Variable};
 
 /// An ILP solver using the HiGHS backend.
@@ -86,19 +88,137 @@ impl ILPSolver {
     /// The returned solution is a configuration vector where each element
     /// is the variable value (config index = value).
     pub fn solve<V: VariableDomain>(&self, problem: &ILP<V>) -> Option<Vec<usize>> {
-        let n = problem.num_vars;
-        if n == 0 {
+        if problem.num_vars == 0 {
             return problem.is_feasible(&[]).then_some(vec![]);
         }
 
-        // Derive tighter per-variable upper bounds from single-variable ≤ constraints.
+        // Extract solution: config index = value (no lower bound offset)
+        let values = self.optimize(problem, true, None)?;
+        Some(
+            values
+                .into_iter()
+                .map(|val| val.round().max(0.0) as usize)
+                .collect(),
+        )
+    }
+
+    /// Solve an ILP problem from a known integer solution.
+    ///
+    /// `initial` uses the same encoding as the vector returned by [`solve`](Self::solve)
+    /// and is passed to HiGHS as a MIP start, so its objective bounds the search
+    /// from the first node. This pays off when solving a sequence of related ILPs
+    /// where the previous optimum is still feasible. A start that is out of the
+    /// variable domain or violates a constraint is ignored and the ILP is solved
+    /// from scratch; backends other than HiGHS ignore the start as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `initial` does not have one entry per ILP variable.
+    pub fn solve_with_start<V: VariableDomain>(
+        &self,
+        problem: &ILP<V>,
+        initial: &[usize],
+    ) -> Option<Vec<usize>> {
+        assert_eq!(
+            initial.len(),
+            problem.num_vars,
+            "warm start has {} values but the ILP has {} variables",
+            initial.len(),
+            problem.num_vars
+        );
+        if problem.num_vars == 0 {
+            return self.solve(problem);
+        }
+
+        let values: Vec<i64> = initial.iter().map(|&c| c as i64).collect();
+        let usable = initial.iter().all(|&c| c < V::DIMS_PER_VAR) && problem.is_feasible(&values);
+        let values = self.optimize(problem, true, usable.then_some(initial))?;
+        Some(
+            values
+                .into_iter()
+                .map(|val| val.round().max(0.0) as usize)
+                .collect(),
+        )
+    }
+
+    /// Solve the LP relaxation of an ILP problem and return its optimal objective value.
+    ///
+    /// Integrality is dropped but variable bounds are kept, so the result is an
+    /// upper bound on the ILP optimum when maximizing and a lower bound when
+    /// minimizing. Returns `None` if the relaxation is infeasible or the solver fails.
+    pub fn solve_relaxation<V: VariableDomain>(&self, problem: &ILP<V>) -> Option<f64> {
+        if problem.num_vars == 0 {
+            return problem.is_feasible(&[]).then_some(0.0);
+        }
+        let values = self.optimize(problem, false, None)?;
+        Some(
+            problem
+                .objective
+                .iter()
+                .map(|&(var_idx, coef)| coef * values[var_idx])
+                .sum(),
+        )
+    }
+
+    /// Find an irreducible infeasible subset (IIS) of the constraints of an infeasible ILP.
+    ///
+    /// Returns the indices into `problem.constraints` of a set of constraints
+    /// that is infeasible together with the variable domains, while dropping
+    /// any one of them makes the rest feasible. Returns `None` if the ILP is
+    /// feasible. This points at the rows that clash when a reduction's
+    /// encoding is wrong.
+    ///
+    /// Uses deletion filtering: each constraint is tentatively removed and
+    /// kept out if the remainder stays infeasible, so this solves one
+    /// feasibility ILP per constraint. With a time limit, a timed-out solve
+    /// counts as infeasible and the subset may not be minimal.
+    pub fn irreducible_infeasible_subset<V: VariableDomain>(
+        &self,
+        problem: &ILP<V>,
+    ) -> Option<Vec<usize>> {
+        let feasible = |indices: &[usize]| {
+            let constraints = indices
+                .iter()
+                .map(|&i| problem.constraints[i].clone())
+                .collect();
+            let subproblem = ILP::<V>::new(problem.num_vars, constraints, vec![], problem.sense);
+            self.solve(&subproblem).is_some()
+        };
+
+        let mut subset: Vec<usize> = (0..problem.constraints.len()).collect();
+        if feasible(&subset) {
+            return None;
+        }
+        let mut position = 0;
+        while position < subset.len() {
+            let candidate = subset.remove(position);
+            if feasible(&subset) {
+                subset.insert(position, candidate);
+                position += 1;
+            }
+        }
+        Some(subset)
+    }
+
+    /// Build and solve the model, returning the value of every ILP variable.
+    ///
+    /// `start`, if given, must be a feasible configuration; it becomes the MIP start.
+    fn optimize<V: VariableDomain>(
+        &self,
+        problem: &ILP<V>,
+        integer: bool,
+        start: Option<&[usize]>,
+    ) -> Option<Vec<f64>> {
+        let n = problem.num_vars;
+
+        // Derive tighter per-variable upper bounds from single-variable ≤ and ranged constraints.
         // This avoids giving HiGHS the full domain (e.g. 2^31 for i32), which can
         // cause severe performance degradation even when constraints already bound
         // the variable to a small range.
         let default_ub = (V::DIMS_PER_VAR - 1) as f64;
         let mut upper_bounds = vec![default_ub; n];
         for constraint in &problem.constraints {
-            if constraint.cmp == crate::models::algebraic::Comparison::Le
+            if matches!(constraint.cmp, Comparison::Le | Comparison::Range)
                 && constraint.terms.len() == 1
             {
                 let (var_idx, coef) = constraint.terms[0];
@@ -111,17 +231,38 @@ impl ILPSolver {
             }
         }
 
-        // Create integer variables with tightened bounds
+        // Create integer (or, for the relaxation, continuous) variables with tightened bounds
         let mut vars_builder = ProblemVariables::new();
         let vars: Vec<Variable> = (0..n)
             .map(|i| {
-                let mut v = variable().integer();
+                let mut v = if integer {
+                    variable().integer()
+                } else {
+                    variable()
+                };
                 v = v.min(0.0);
                 v = v.max(upper_bounds[i]);
                 vars_builder.add(v)
             })
             .collect();
 
+        // good_lp only emits one-sided and equality rows, so a ranged constraint
+        // lo <= a·x <= hi becomes the single row a·x - s = lo with a continuous
+        // slack column s ∈ [0, hi - lo] that HiGHS bounds natively.
+        let range_slacks: Vec<Option<(Variable, f64)>> = problem
+            .constraints
+            .iter()
+            .map(
+                |constraint| match (constraint.cmp, constraint.lower_bound()) {
+                    (Comparison::Range, Some(lo)) => Some((
+                        vars_builder.add(variable().min(0.0).max(constraint.rhs - lo)),
+                        lo,
+                    )),
+                    _ => None,
+                },
+            )
+            .collect();
+
         // Build objective expression
         let objective: good_lp::Expression = problem
             .objective
@@ -153,8 +294,31 @@ impl ILPSolver {
         #[cfg(not(feature = "ilp-highs"))]
         let mut model = unsolved.using(default_solver);
 
+        // The MIP start also fixes each range slack to its row activity minus lo.
+        #[cfg(feature = "ilp-highs")]
+        if let Some(start) = start {
+            let mut initial: Vec<(Variable, f64)> = vars
+                .iter()
+                .zip(start)
+                .map(|(&var, &value)| (var, value as f64))
+                .collect();
+            for (constraint, slack) in problem.constraints.iter().zip(&range_slacks) {
+                if let Some((slack, lo)) = slack {
+                    let activity: f64 = constraint
+                        .terms
+                        .iter()
+                        .map(|&(var_idx, coef)| coef * start[var_idx] as f64)
+                        .sum();
+                    initial.push((*slack, activity - lo));
+                }
+            }
+            model = model.with_initial_solution(initial);
+        }
+        #[cfg(not(feature = "ilp-highs"))]
+        let _ = start;
+
         // Add constraints
-        for constraint in &problem.constraints {
+        for (constraint, slack) in problem.constraints.iter().zip(&range_slacks) {
             // Build left-hand side expression
             let lhs: good_lp::Expression = constraint
                 .terms
@@ -163,10 +327,11 @@ impl ILPSolver {
                 .sum();
 
             // Create the constraint based on comparison type
-            let good_lp_constraint = match constraint.cmp {
-                Comparison::Le => lhs.leq(constraint.rhs),
-                Comparison::Ge => lhs.geq(constraint.rhs),
-                Comparison::Eq => lhs.eq(constraint.rhs),
+            let good_lp_constraint = match (constraint.cmp, slack) {
+                (_, Some((slack, lo))) => (lhs - *slack).eq(*lo),
+                (Comparison::Le | Comparison::Range, _) => lhs.leq(constraint.rhs),
+                (Comparison::Ge, _) => lhs.geq(constraint.rhs),
+                (Comparison::Eq, _) => lhs.eq(constraint.rhs),
             };
 
             model = model.with(good_lp_constraint);
@@ -174,17 +339,7 @@ impl ILPSolver {
 
         // Solve
         let solution = model.solve().ok()?;
-
-        // Extract solution: config index = value (no lower bound offset)
-        let result: Vec<usize> = vars
-            .iter()
-            .map(|v| {
-                let val = solution.value(*v);
-                val.round().max(0.0) as usize
-            })
-            .collect();
-
-        Some(result)
+        Some(vars.iter().map(|v| solution.value(*v)).collect())
     }
 
     /// Solve any problem that reduces to `ILP<bool>`.
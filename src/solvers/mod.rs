@@ -1,19 +1,31 @@
 //! Solvers for computational problems.
 
+mod blossom;
 mod brute_force;
 pub mod customized;
 pub mod decision_search;
+pub mod diversity;
+pub mod heuristics;
+mod sat_dispatcher;
+mod telemetry;
+mod two_sat;
 
 #[cfg(feature = "ilp-solver")]
 pub mod ilp;
 
+pub use blossom::BlossomSolver;
 pub use brute_force::BruteForce;
 pub use customized::CustomizedSolver;
+pub use heuristics::{GreedyLocalSearch, GreedySolver, LocalSearch, SolutionQuality};
+pub use sat_dispatcher::{SatDispatcher, SatStrategy};
+pub use telemetry::SolverTelemetry;
+pub use two_sat::TwoSatSolver;
 
 #[cfg(feature = "ilp-solver")]
 pub use ilp::ILPSolver;
 
 use crate::traits::Problem;
+use std::time::Instant;
 
 /// Trait for problem solvers.
 pub trait Solver {
@@ -22,4 +34,27 @@ pub trait Solver {
     where
         P: Problem,
         P::Value: crate::types::Aggregate;
+
+    /// Solve a problem and report how many configurations were evaluated and
+    /// how long the solve took.
+    ///
+    /// The default runs [`Solver::solve`] on a view of the problem that counts
+    /// evaluations, and reports no pruned branches.
+    fn solve_with_telemetry<P>(&self, problem: &P) -> (P::Value, SolverTelemetry)
+    where
+        P: Problem,
+        P::Value: crate::types::Aggregate,
+    {
+        let counted = telemetry::CountingProblem::new(problem);
+        let start = Instant::now();
+        let value = self.solve(&counted);
+        (
+            value,
+            SolverTelemetry {
+                evaluations: counted.evaluations(),
+                pruned: 0,
+                wall_time: start.elapsed(),
+            },
+        )
+    }
 }
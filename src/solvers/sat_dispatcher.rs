@@ -0,0 +1,147 @@
+//! Strategy selection for satisfiability witnesses.
+
+use super::{BruteForce, TwoSatSolver};
+use crate::models::formula::{CNFClause, Satisfiability};
+
+/// Backend chosen by [`SatDispatcher`] for a CNF formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SatStrategy {
+    /// Every clause has at most two literals: implication-graph SCC.
+    TwoSat,
+    /// Unit propagation alone either finds a conflict or satisfies every clause.
+    UnitPropagation,
+    /// General CNF: exhaustive enumeration.
+    BruteForce,
+}
+
+/// Picks the cheapest exact backend for a [`Satisfiability`] instance.
+///
+/// 2-CNF formulas go to [`TwoSatSolver`]. Otherwise the formula is unit
+/// propagated; if that decides it (a conflict, or every clause satisfied
+/// with the remaining variables free), the propagated assignment is the
+/// answer. Only formulas that survive propagation undecided fall back to
+/// [`BruteForce`].
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::formula::{CNFClause, Satisfiability};
+/// use problemreductions::solvers::{SatDispatcher, SatStrategy};
+/// use problemreductions::Problem;
+///
+/// // (x1) ∧ (¬x1 ∨ x2 ∨ x3) ∧ (¬x2): propagation forces x3
+/// let problem = Satisfiability::new(
+///     3,
+///     vec![
+///         CNFClause::new(vec![1]),
+///         CNFClause::new(vec![-1, 2, 3]),
+///         CNFClause::new(vec![-2]),
+///     ],
+/// );
+/// let dispatcher = SatDispatcher::new();
+/// assert_eq!(dispatcher.strategy(&problem), SatStrategy::UnitPropagation);
+/// let witness = dispatcher.find_witness(&problem).unwrap();
+/// assert_eq!(witness, vec![1, 0, 1]);
+/// assert!(problem.evaluate(&witness).0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SatDispatcher;
+
+/// Outcome of unit propagation to a fixpoint.
+enum Propagation {
+    Conflict,
+    /// Every clause is satisfied; unassigned variables are free.
+    Satisfied(Vec<Option<bool>>),
+    Undecided,
+}
+
+impl SatDispatcher {
+    /// Create a new `SatDispatcher`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Report which backend [`find_witness`](Self::find_witness) would use.
+    pub fn strategy(&self, problem: &Satisfiability) -> SatStrategy {
+        if TwoSatSolver::is_two_cnf(problem.clauses()) {
+            SatStrategy::TwoSat
+        } else if matches!(
+            unit_propagate(problem.num_vars(), problem.clauses()),
+            Propagation::Undecided
+        ) {
+            SatStrategy::BruteForce
+        } else {
+            SatStrategy::UnitPropagation
+        }
+    }
+
+    /// Find a satisfying assignment, or `None` if the formula is unsatisfiable.
+    pub fn find_witness(&self, problem: &Satisfiability) -> Option<Vec<usize>> {
+        if TwoSatSolver::is_two_cnf(problem.clauses()) {
+            return TwoSatSolver::new().find_satisfiability_witness(problem);
+        }
+        match unit_propagate(problem.num_vars(), problem.clauses()) {
+            Propagation::Conflict => None,
+            Propagation::Satisfied(assignment) => Some(
+                assignment
+                    .into_iter()
+                    .map(|value| usize::from(value == Some(true)))
+                    .collect(),
+            ),
+            Propagation::Undecided => BruteForce::new().find_witness(problem),
+        }
+    }
+}
+
+/// Repeatedly assign the last open literal of clauses whose other literals are false.
+///
+/// As in [`CNFClause::is_satisfied`], a variable outside `1..=num_vars` is
+/// fixed to false.
+fn unit_propagate(num_vars: usize, clauses: &[CNFClause]) -> Propagation {
+    let mut assignment: Vec<Option<bool>> = vec![None; num_vars];
+    let var_index = |lit: i32| (lit.unsigned_abs() as usize).checked_sub(1);
+    let value = |assignment: &[Option<bool>], lit: i32| match var_index(lit) {
+        Some(var) if var < num_vars => assignment[var].map(|v| v == (lit > 0)),
+        _ => Some(lit < 0),
+    };
+
+    loop {
+        let mut changed = false;
+        let mut all_satisfied = true;
+        for clause in clauses {
+            if clause
+                .literals
+                .iter()
+                .any(|&lit| value(&assignment, lit) == Some(true))
+            {
+                continue;
+            }
+            all_satisfied = false;
+            let mut open = clause
+                .literals
+                .iter()
+                .filter(|&&lit| value(&assignment, lit).is_none());
+            match (open.next(), open.next()) {
+                (None, _) => return Propagation::Conflict,
+                (Some(&lit), None) => {
+                    // Only in-range variables are ever open.
+                    if let Some(var) = var_index(lit) {
+                        assignment[var] = Some(lit > 0);
+                    }
+                    changed = true;
+                }
+                _ => {}
+            }
+        }
+        if all_satisfied {
+            return Propagation::Satisfied(assignment);
+        }
+        if !changed {
+            return Propagation::Undecided;
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/solvers/sat_dispatcher.rs"]
+mod tests;
@@ -0,0 +1,89 @@
+//! Search-effort counters reported alongside a solve.
+
+use crate::config::BitConfig;
+use crate::traits::{BitEvaluator, ObjectiveTerm, Problem};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// How much work a solver did on one instance.
+///
+/// Returned by [`Solver::solve_with_telemetry`](crate::solvers::Solver::solve_with_telemetry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SolverTelemetry {
+    /// Number of configurations passed to `Problem::evaluate` (or its bitmask
+    /// fast path).
+    pub evaluations: u64,
+    /// Number of search branches discarded without being evaluated. Always
+    /// zero for solvers that do not prune.
+    pub pruned: u64,
+    /// Wall-clock time of the solve.
+    pub wall_time: Duration,
+}
+
+/// A view of a problem that counts every evaluation made through it.
+///
+/// Clones share the counter, so solvers that copy the problem internally are
+/// still fully counted.
+#[derive(Debug, Clone)]
+pub(crate) struct CountingProblem<'a, P> {
+    inner: &'a P,
+    evaluations: Rc<Cell<u64>>,
+}
+
+impl<'a, P> CountingProblem<'a, P> {
+    pub(crate) fn new(inner: &'a P) -> Self {
+        Self {
+            inner,
+            evaluations: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Evaluations made so far through this view or any of its clones.
+    pub(crate) fn evaluations(&self) -> u64 {
+        self.evaluations.get()
+    }
+}
+
+impl<P: Problem> Problem for CountingProblem<'_, P> {
+    const NAME: &'static str = P::NAME;
+    type Value = P::Value;
+
+    fn dims(&self) -> Vec<usize> {
+        self.inner.dims()
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Self::Value {
+        self.evaluations.set(self.evaluations.get() + 1);
+        self.inner.evaluate(config)
+    }
+
+    fn num_variables(&self) -> usize {
+        self.inner.num_variables()
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        P::variant()
+    }
+
+    fn bit_evaluator(&self) -> Option<BitEvaluator<'_, Self::Value>> {
+        let evaluate = self.inner.bit_evaluator()?;
+        let evaluations = Rc::clone(&self.evaluations);
+        Some(Box::new(move |bits: BitConfig| {
+            evaluations.set(evaluations.get() + 1);
+            evaluate(bits)
+        }))
+    }
+
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        self.inner.statistics()
+    }
+
+    fn objective_terms(&self, config: &[usize]) -> Vec<ObjectiveTerm> {
+        self.inner.objective_terms(config)
+    }
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/solvers/telemetry.rs"]
+mod tests;
@@ -0,0 +1,182 @@
+//! Polynomial-time 2-SAT via the implication graph and strongly connected components.
+
+use crate::models::formula::{CNFClause, KSatisfiability, Satisfiability};
+use crate::variant::K2;
+
+/// Sentinel for "not yet visited" in Tarjan's algorithm.
+const UNVISITED: usize = usize::MAX;
+
+/// Exact solver for 2-CNF formulas.
+///
+/// Builds the implication graph with nodes `x` and `¬x` for every variable
+/// and edges `¬a → b`, `¬b → a` for every clause `(a ∨ b)`. The formula is
+/// unsatisfiable iff some `x` and `¬x` share a strongly connected component
+/// (Aspvall, Plass & Tarjan, 1979); otherwise setting each variable to the
+/// literal whose component comes later in topological order satisfies it.
+/// Runs in `O(n + m)` time, against the `2^n` configurations
+/// [`BruteForce`](crate::solvers::BruteForce) enumerates.
+///
+/// Unit clauses `(a)` are handled as `(a ∨ a)`; an empty clause makes the
+/// formula unsatisfiable. As in [`CNFClause::is_satisfied`], a literal over a
+/// variable outside `1..=num_vars` reads that variable as false.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::models::formula::{CNFClause, KSatisfiability};
+/// use problemreductions::solvers::TwoSatSolver;
+/// use problemreductions::variant::K2;
+/// use problemreductions::Problem;
+///
+/// // (x1 ∨ x2) ∧ (¬x1 ∨ x2) ∧ (¬x2 ∨ x3) forces x2 and x3
+/// let problem = KSatisfiability::<K2>::new(
+///     3,
+///     vec![
+///         CNFClause::new(vec![1, 2]),
+///         CNFClause::new(vec![-1, 2]),
+///         CNFClause::new(vec![-2, 3]),
+///     ],
+/// );
+/// let witness = TwoSatSolver::new().find_witness(&problem).unwrap();
+/// assert!(problem.evaluate(&witness).0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TwoSatSolver;
+
+impl TwoSatSolver {
+    /// Create a new `TwoSatSolver`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check whether every clause has at most two literals.
+    pub fn is_two_cnf(clauses: &[CNFClause]) -> bool {
+        clauses.iter().all(|clause| clause.len() <= 2)
+    }
+
+    /// Find a satisfying assignment of a 2-SAT instance, or `None` if unsatisfiable.
+    pub fn find_witness(&self, problem: &KSatisfiability<K2>) -> Option<Vec<usize>> {
+        self.solve_clauses(problem.num_vars(), problem.clauses())
+    }
+
+    /// Find a satisfying assignment of a SAT instance in 2-CNF.
+    ///
+    /// Returns `None` if the formula is unsatisfiable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some clause has more than two literals; check
+    /// [`is_two_cnf`](Self::is_two_cnf) first.
+    pub fn find_satisfiability_witness(&self, problem: &Satisfiability) -> Option<Vec<usize>> {
+        assert!(
+            Self::is_two_cnf(problem.clauses()),
+            "TwoSatSolver requires clauses with at most two literals"
+        );
+        self.solve_clauses(problem.num_vars(), problem.clauses())
+    }
+
+    /// Solve a 2-CNF formula over `num_vars` variables, returning 0/1 per variable.
+    fn solve_clauses(&self, num_vars: usize, clauses: &[CNFClause]) -> Option<Vec<usize>> {
+        // Node 2v is the literal x_v, node 2v + 1 is ¬x_v; `node ^ 1` negates.
+        // A literal over an out-of-range variable is the constant `lit < 0`.
+        let node = |lit: i32| match (lit.unsigned_abs() as usize).checked_sub(1) {
+            Some(var) if var < num_vars => Ok(2 * var + usize::from(lit < 0)),
+            _ => Err(lit < 0),
+        };
+        let mut implications = vec![Vec::new(); 2 * num_vars];
+        for clause in clauses {
+            let mut nodes = Vec::with_capacity(clause.literals.len());
+            let mut satisfied = false;
+            for &lit in &clause.literals {
+                match node(lit) {
+                    Ok(n) => nodes.push(n),
+                    Err(constant) => satisfied |= constant,
+                }
+            }
+            if satisfied {
+                continue;
+            }
+            let (a, b) = match nodes[..] {
+                [] => return None,
+                [a] => (a, a),
+                [a, b] => (a, b),
+                _ => panic!("TwoSatSolver requires clauses with at most two literals"),
+            };
+            implications[a ^ 1].push(b);
+            implications[b ^ 1].push(a);
+        }
+
+        let component = strongly_connected_components(&implications);
+        (0..num_vars)
+            .map(|v| {
+                let (pos, neg) = (component[2 * v], component[2 * v + 1]);
+                // Tarjan numbers components in reverse topological order, so the
+                // literal with the smaller id comes later and is made true.
+                (pos != neg).then_some(usize::from(pos < neg))
+            })
+            .collect()
+    }
+}
+
+/// Tarjan's algorithm without recursion, so long implication chains cannot
+/// overflow the stack. Returns the component id of every node; ids are
+/// assigned in reverse topological order of the condensation.
+fn strongly_connected_components(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut index = vec![UNVISITED; n];
+    let mut lowlink = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut component = vec![UNVISITED; n];
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+    let mut next_component = 0;
+    // (node, position of the next successor to scan)
+    let mut call_stack: Vec<(usize, usize)> = Vec::new();
+
+    for root in 0..n {
+        if index[root] != UNVISITED {
+            continue;
+        }
+        call_stack.push((root, 0));
+        while let Some(&(v, next)) = call_stack.last() {
+            if next == 0 {
+                index[v] = next_index;
+                lowlink[v] = next_index;
+                next_index += 1;
+                stack.push(v);
+                on_stack[v] = true;
+            }
+            if let Some(&w) = adjacency[v].get(next) {
+                if let Some(top) = call_stack.last_mut() {
+                    top.1 += 1;
+                }
+                if index[w] == UNVISITED {
+                    call_stack.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(index[w]);
+                }
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some(&(parent, _)) = call_stack.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[v]);
+            }
+            if lowlink[v] == index[v] {
+                while let Some(w) = stack.pop() {
+                    on_stack[w] = false;
+                    component[w] = next_component;
+                    if w == v {
+                        break;
+                    }
+                }
+                next_component += 1;
+            }
+        }
+    }
+    component
+}
+
+#[cfg(test)]
+#[path = "../unit_tests/solvers/two_sat.rs"]
+mod tests;
@@ -0,0 +1,128 @@
+//! Instance statistics for choosing reduction paths and solvers.
+//!
+//! [`ProblemStatistics`] summarizes the configuration space of any
+//! [`Problem`](crate::traits::Problem); graph problems additionally attach
+//! [`GraphStatistics`] for their underlying graph.
+
+use crate::rules::unitdiskmapping::pathdecomposition::{pathwidth, PathDecompositionMethod};
+use crate::topology::Graph;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Structural statistics of an undirected graph.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GraphStatistics {
+    /// Number of vertices.
+    pub num_vertices: usize,
+    /// Number of edges.
+    pub num_edges: usize,
+    /// Edge density `2m / (n (n - 1))`, or 0 for graphs with fewer than two vertices.
+    pub density: f64,
+    /// Minimum vertex degree (0 for the empty graph).
+    pub min_degree: usize,
+    /// Maximum vertex degree.
+    pub max_degree: usize,
+    /// Average vertex degree `2m / n`.
+    pub avg_degree: f64,
+    /// Number of connected components.
+    pub num_components: usize,
+    /// Pathwidth upper bound from the greedy path decomposition.
+    ///
+    /// Pathwidth also bounds treewidth from above.
+    pub pathwidth_upper_bound: usize,
+}
+
+impl GraphStatistics {
+    /// Compute statistics for a graph.
+    pub fn from_graph<G: Graph>(graph: &G) -> Self {
+        let n = graph.num_vertices();
+        let edges = graph.edges();
+        let m = edges.len();
+
+        let mut degrees = vec![0usize; n];
+        let mut parent: Vec<usize> = (0..n).collect();
+        let mut num_components = n;
+        for &(u, v) in &edges {
+            degrees[u] += 1;
+            degrees[v] += 1;
+            let (ru, rv) = (find(&mut parent, u), find(&mut parent, v));
+            if ru != rv {
+                parent[ru] = rv;
+                num_components -= 1;
+            }
+        }
+
+        let density = if n < 2 {
+            0.0
+        } else {
+            2.0 * m as f64 / (n * (n - 1)) as f64
+        };
+        let avg_degree = if n == 0 {
+            0.0
+        } else {
+            2.0 * m as f64 / n as f64
+        };
+
+        Self {
+            num_vertices: n,
+            num_edges: m,
+            density,
+            min_degree: degrees.iter().copied().min().unwrap_or(0),
+            max_degree: degrees.iter().copied().max().unwrap_or(0),
+            avg_degree,
+            num_components,
+            pathwidth_upper_bound: pathwidth(n, &edges, PathDecompositionMethod::greedy()).vsep(),
+        }
+    }
+}
+
+/// Union-find root lookup with path halving.
+fn find(parent: &mut [usize], mut v: usize) -> usize {
+    while parent[v] != v {
+        parent[v] = parent[parent[v]];
+        v = parent[v];
+    }
+    v
+}
+
+/// Statistics of a problem instance.
+///
+/// Returned by [`Problem::statistics`](crate::traits::Problem::statistics).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProblemStatistics {
+    /// Number of configuration variables.
+    pub num_variables: usize,
+    /// Histogram of variable domain sizes: domain size -> number of variables.
+    pub domain_sizes: BTreeMap<usize, usize>,
+    /// Base-2 logarithm of the configuration space size.
+    pub log2_search_space: f64,
+    /// Statistics of the underlying graph, for graph problems.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graph: Option<GraphStatistics>,
+}
+
+impl ProblemStatistics {
+    /// Summarize a configuration space given its dimensions.
+    pub fn from_dims(dims: &[usize]) -> Self {
+        let mut domain_sizes = BTreeMap::new();
+        for &d in dims {
+            *domain_sizes.entry(d).or_insert(0) += 1;
+        }
+        Self {
+            num_variables: dims.len(),
+            domain_sizes,
+            log2_search_space: dims.iter().map(|&d| (d as f64).log2()).sum(),
+            graph: None,
+        }
+    }
+
+    /// Attach statistics of the underlying graph.
+    pub fn with_graph<G: Graph>(mut self, graph: &G) -> Self {
+        self.graph = Some(GraphStatistics::from_graph(graph));
+        self
+    }
+}
+
+#[cfg(test)]
+#[path = "unit_tests/statistics.rs"]
+mod tests;
@@ -2,6 +2,7 @@
 
 use super::graph::{Graph, SimpleGraph};
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 
 /// Bipartite graph with explicit left/right partitions.
 ///
@@ -73,6 +74,16 @@ impl BipartiteGraph {
         self.right_size
     }
 
+    /// Returns the left vertices in the unified vertex space.
+    pub fn left_vertices(&self) -> Range<usize> {
+        0..self.left_size
+    }
+
+    /// Returns the right vertices in the unified vertex space.
+    pub fn right_vertices(&self) -> Range<usize> {
+        self.left_size..self.left_size + self.right_size
+    }
+
     /// Returns the edges in bipartite-local coordinates.
     pub fn left_edges(&self) -> &[(usize, usize)] {
         &self.edges
@@ -6,11 +6,14 @@
 //! Supported graph types:
 //! - [`SimpleGraph`]: Standard unweighted graph (wrapper around petgraph)
 //! - [`UnitDiskGraph`]: Vertices with 2D positions, edges based on distance
-//! - [`HyperGraph`]: Edges can connect any number of vertices (via adapter)
+//! - [`HyperGraph`](super::HyperGraph): Edges can connect any number of vertices (separate type)
 
+use super::BipartiteGraph;
+use crate::error::ProblemError;
 use petgraph::graph::{NodeIndex, UnGraph};
 use petgraph::visit::EdgeRef;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
 /// Trait for graph types, following Julia's Graphs.jl AbstractGraph pattern.
 ///
@@ -82,6 +85,49 @@ pub trait Graph: Clone + Send + Sync + 'static {
     }
 }
 
+/// Object-safe view of an undirected graph.
+///
+/// [`Graph`] has associated constants and generic methods, so it cannot be
+/// used as a trait object. `GraphView` exposes just the structure needed to
+/// handle graphs whose type is only known at runtime, such as when exporting
+/// the target of a reduction. Geometric graphs also report vertex coordinates.
+pub trait GraphView {
+    /// Name of the graph type (same as [`Graph::NAME`]).
+    fn graph_type(&self) -> &'static str;
+
+    /// Number of vertices.
+    fn vertex_count(&self) -> usize;
+
+    /// All edges as `(u, v)` pairs with `u < v`.
+    fn edge_list(&self) -> Vec<(usize, usize)>;
+
+    /// 2D coordinates of every vertex, if the graph is embedded in the plane.
+    fn coordinates(&self) -> Option<Vec<(f64, f64)>> {
+        None
+    }
+}
+
+/// Implement [`GraphView`] for graph types without coordinates.
+macro_rules! impl_graph_view {
+    ($($ty:ty),* $(,)?) => {$(
+        impl GraphView for $ty {
+            fn graph_type(&self) -> &'static str {
+                <$ty as Graph>::NAME
+            }
+
+            fn vertex_count(&self) -> usize {
+                Graph::num_vertices(self)
+            }
+
+            fn edge_list(&self) -> Vec<(usize, usize)> {
+                Graph::edges(self)
+            }
+        }
+    )*};
+}
+
+impl_graph_view!(SimpleGraph, super::PlanarGraph, super::BipartiteGraph);
+
 /// Trait for casting a graph to a supertype in the graph hierarchy.
 ///
 /// When `A: GraphCast<B>`, graph `A` can be losslessly converted to graph `B`
@@ -103,7 +149,8 @@ impl<G: Graph> GraphCast<SimpleGraph> for G {
 /// A simple unweighted undirected graph.
 ///
 /// This is the default graph type for most problems. It wraps petgraph's
-/// `UnGraph` and implements the `Graph` trait.
+/// `UnGraph` and implements the `Graph` trait. Neighbor lists are cached at
+/// construction and kept in sync by [`add_edge`](SimpleGraph::add_edge).
 ///
 /// # Example
 ///
@@ -120,6 +167,8 @@ impl<G: Graph> GraphCast<SimpleGraph> for G {
 #[derive(Debug, Clone)]
 pub struct SimpleGraph {
     inner: UnGraph<(), ()>,
+    /// Cached neighbor lists, in the order petgraph reports them.
+    adjacency: Vec<Vec<usize>>,
 }
 
 impl SimpleGraph {
@@ -148,7 +197,44 @@ impl SimpleGraph {
             );
             inner.add_edge(NodeIndex::new(u), NodeIndex::new(v), ());
         }
-        Self { inner }
+        let adjacency = (0..num_vertices)
+            .map(|v| Self::petgraph_neighbors(&inner, v))
+            .collect();
+        Self { inner, adjacency }
+    }
+
+    fn petgraph_neighbors(inner: &UnGraph<(), ()>, v: usize) -> Vec<usize> {
+        inner
+            .neighbors(NodeIndex::new(v))
+            .map(|n| n.index())
+            .collect()
+    }
+
+    /// Adds the edge `(u, v)` and updates the cached neighbor lists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` or `v` is >= num_vertices.
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        let num_vertices = self.num_vertices();
+        assert!(
+            u < num_vertices && v < num_vertices,
+            "edge ({}, {}) references vertex >= num_vertices ({})",
+            u,
+            v,
+            num_vertices
+        );
+        self.inner
+            .add_edge(NodeIndex::new(u), NodeIndex::new(v), ());
+        self.adjacency[u] = Self::petgraph_neighbors(&self.inner, u);
+        self.adjacency[v] = Self::petgraph_neighbors(&self.inner, v);
+    }
+
+    /// Returns the cached neighbors of `v` without allocating.
+    ///
+    /// Same order as [`Graph::neighbors`]; empty if `v` is out of range.
+    pub fn neighbor_slice(&self, v: usize) -> &[usize] {
+        self.adjacency.get(v).map_or(&[], Vec::as_slice)
     }
 
     /// Creates an empty graph with the given number of vertices.
@@ -214,6 +300,109 @@ impl SimpleGraph {
 
         Self::new(num_vertices, edges)
     }
+
+    /// Checks whether the graph is planar, i.e. can be drawn in the plane
+    /// without edge crossings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::topology::SimpleGraph;
+    ///
+    /// assert!(SimpleGraph::complete(4).is_planar());
+    /// assert!(!SimpleGraph::complete(5).is_planar());
+    /// ```
+    pub fn is_planar(&self) -> bool {
+        super::planarity::is_planar(self.num_vertices(), &self.edges())
+    }
+
+    /// Checks whether the graph is bipartite, i.e. has no odd cycle.
+    pub fn is_bipartite(&self) -> bool {
+        self.two_coloring().is_ok()
+    }
+
+    /// Converts the graph into a [`BipartiteGraph`].
+    ///
+    /// Each connected component is 2-colored by BFS from its smallest vertex,
+    /// which goes to the left part. Left and right vertices keep their relative
+    /// order, so the `i`-th left vertex is the `i`-th smallest vertex colored
+    /// left.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProblemError::InvalidProblem`] naming an edge that closes an
+    /// odd cycle (including self-loops).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::topology::{Graph, SimpleGraph};
+    ///
+    /// let square = SimpleGraph::cycle(4).try_into_bipartite().unwrap();
+    /// assert_eq!((square.left_size(), square.right_size()), (2, 2));
+    /// assert_eq!(square.num_edges(), 4);
+    ///
+    /// assert!(SimpleGraph::cycle(5).try_into_bipartite().is_err());
+    /// ```
+    pub fn try_into_bipartite(&self) -> Result<BipartiteGraph, ProblemError> {
+        let is_right = self.two_coloring().map_err(|(u, v)| {
+            ProblemError::InvalidProblem(format!(
+                "graph is not bipartite: edge ({u}, {v}) closes an odd cycle"
+            ))
+        })?;
+
+        // Position of each vertex within its part.
+        let mut local = vec![0; is_right.len()];
+        let (mut left_size, mut right_size) = (0, 0);
+        for (v, &right) in is_right.iter().enumerate() {
+            let size = if right {
+                &mut right_size
+            } else {
+                &mut left_size
+            };
+            local[v] = *size;
+            *size += 1;
+        }
+
+        let edges = self
+            .edges()
+            .into_iter()
+            .map(|(u, v)| {
+                let (l, r) = if is_right[u] { (v, u) } else { (u, v) };
+                (local[l], local[r])
+            })
+            .collect();
+        Ok(BipartiteGraph::new(left_size, right_size, edges))
+    }
+
+    /// BFS 2-coloring; `true` marks the right part. On failure, returns an
+    /// edge whose endpoints receive the same color.
+    pub(crate) fn two_coloring(&self) -> Result<Vec<bool>, (usize, usize)> {
+        let n = self.num_vertices();
+        let mut color: Vec<Option<bool>> = vec![None; n];
+        let mut queue = VecDeque::new();
+        for start in 0..n {
+            if color[start].is_some() {
+                continue;
+            }
+            color[start] = Some(false);
+            queue.push_back(start);
+            while let Some(u) = queue.pop_front() {
+                let side = color[u].expect("queued vertices are colored");
+                for &v in self.neighbor_slice(u) {
+                    match color[v] {
+                        None => {
+                            color[v] = Some(!side);
+                            queue.push_back(v);
+                        }
+                        Some(c) if c == side => return Err((u.min(v), u.max(v))),
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        Ok(color.into_iter().map(|c| c.unwrap_or(false)).collect())
+    }
 }
 
 impl Graph for SimpleGraph {
@@ -241,10 +430,11 @@ impl Graph for SimpleGraph {
     }
 
     fn neighbors(&self, v: usize) -> Vec<usize> {
-        self.inner
-            .neighbors(NodeIndex::new(v))
-            .map(|n| n.index())
-            .collect()
+        self.neighbor_slice(v).to_vec()
+    }
+
+    fn degree(&self, v: usize) -> usize {
+        self.neighbor_slice(v).len()
     }
 }
 
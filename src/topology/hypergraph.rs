@@ -0,0 +1,127 @@
+//! Hypergraph implementation.
+//!
+//! This module provides [`HyperGraph`], a topology whose edges may connect any
+//! number of vertices. It is intended for models with higher-order
+//! interactions, such as spin glasses with multi-body couplings.
+
+use serde::{Deserialize, Serialize};
+
+/// A hypergraph whose hyperedges are arbitrary vertex sets.
+///
+/// Hyperedges preserve the input order, both across the edge list and within
+/// each edge, so higher-level models can attach per-edge data by index.
+///
+/// # Example
+///
+/// ```
+/// use problemreductions::topology::HyperGraph;
+///
+/// let graph = HyperGraph::new(4, vec![vec![0, 1, 2], vec![2, 3]]);
+/// assert_eq!(graph.num_vertices(), 4);
+/// assert_eq!(graph.num_edges(), 2);
+/// assert_eq!(graph.rank(), 3);
+/// assert_eq!(graph.degree(2), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HyperGraph {
+    num_vertices: usize,
+    edges: Vec<Vec<usize>>,
+}
+
+impl HyperGraph {
+    /// Create a new hypergraph.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any hyperedge references a vertex outside `0..num_vertices`.
+    pub fn new(num_vertices: usize, edges: Vec<Vec<usize>>) -> Self {
+        for edge in &edges {
+            for &v in edge {
+                assert!(
+                    v < num_vertices,
+                    "hyperedge {:?} references vertex >= num_vertices ({})",
+                    edge,
+                    num_vertices
+                );
+            }
+        }
+        Self {
+            num_vertices,
+            edges,
+        }
+    }
+
+    /// Create a hypergraph with no hyperedges.
+    pub fn empty(num_vertices: usize) -> Self {
+        Self::new(num_vertices, vec![])
+    }
+
+    /// Return the number of vertices.
+    pub fn num_vertices(&self) -> usize {
+        self.num_vertices
+    }
+
+    /// Return the number of hyperedges.
+    pub fn num_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Return the hyperedges.
+    pub fn edges(&self) -> &[Vec<usize>] {
+        &self.edges
+    }
+
+    /// Return the size of the largest hyperedge (0 if there are none).
+    pub fn rank(&self) -> usize {
+        self.edges.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Return the number of hyperedges containing vertex `v`.
+    pub fn degree(&self, v: usize) -> usize {
+        self.edges.iter().filter(|edge| edge.contains(&v)).count()
+    }
+
+    /// Return the vertex-by-hyperedge incidence matrix.
+    ///
+    /// Entry `[v][e]` is true iff vertex `v` belongs to hyperedge `e`, so the
+    /// matrix has `num_vertices` rows and `num_edges` columns.
+    pub fn incidence_matrix(&self) -> Vec<Vec<bool>> {
+        let mut matrix = vec![vec![false; self.edges.len()]; self.num_vertices];
+        for (e, edge) in self.edges.iter().enumerate() {
+            for &v in edge {
+                matrix[v][e] = true;
+            }
+        }
+        matrix
+    }
+
+    /// Return the dual hypergraph, with the roles of vertices and hyperedges swapped.
+    ///
+    /// Vertex `e` of the dual is hyperedge `e` of `self`, and hyperedge `v` of
+    /// the dual lists, in increasing order, the hyperedges containing vertex
+    /// `v`. Taking the dual twice gives back `self` with every hyperedge
+    /// sorted and deduplicated.
+    pub fn dual(&self) -> HyperGraph {
+        let mut edges = vec![Vec::new(); self.num_vertices];
+        for (e, edge) in self.edges.iter().enumerate() {
+            for &v in edge {
+                if edges[v].last() != Some(&e) {
+                    edges[v].push(e);
+                }
+            }
+        }
+        Self::new(self.edges.len(), edges)
+    }
+
+    /// Return true if the hypergraph has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.num_vertices == 0
+    }
+}
+
+use crate::impl_variant_param;
+impl_variant_param!(HyperGraph, "graph");
+
+#[cfg(test)]
+#[path = "../unit_tests/topology/hypergraph.rs"]
+mod tests;
@@ -3,7 +3,7 @@
 //! This is a public graph type produced by the KSG unit disk mapping reduction.
 //! It stores only integer grid positions; edges are computed on-the-fly from geometry.
 
-use super::graph::Graph;
+use super::graph::{Graph, GraphView};
 use super::unit_disk_graph::UnitDiskGraph;
 use serde::{Deserialize, Serialize};
 
@@ -13,8 +13,10 @@ use serde::{Deserialize, Serialize};
 /// (king's move connectivity: adjacent horizontally, vertically, or diagonally).
 /// This is a subtype of [`UnitDiskGraph`] in the variant hierarchy.
 ///
-/// Edges are computed on-the-fly: two positions are connected if their
-/// Euclidean distance is strictly less than 1.5.
+/// Edges are computed on-the-fly: two positions are connected if they differ
+/// by at most one in each coordinate (Euclidean distance 1 or √2, strictly
+/// less than 1.5). Coincident positions are not adjacent, so an interior cell
+/// of a full grid has exactly 8 neighbors.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KingsSubgraph {
     /// Integer grid positions (row, col) for each vertex.
@@ -30,6 +32,31 @@ impl KingsSubgraph {
         Self { positions }
     }
 
+    /// Create a KingsSubgraph from the occupied cells of a square grid.
+    ///
+    /// Repeated cells are dropped; the remaining cells keep their first
+    /// occurrence order, so vertex `i` is the `i`-th distinct cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::topology::{Graph, KingsSubgraph};
+    ///
+    /// // Full 3x3 block: the center touches every other cell.
+    /// let cells = (0..3).flat_map(|r| (0..3).map(move |c| (r, c)));
+    /// let graph = KingsSubgraph::from_occupied(cells);
+    /// assert_eq!(graph.neighbors(4).len(), 8);
+    /// assert_eq!(graph.num_edges(), 20);
+    /// ```
+    pub fn from_occupied(cells: impl IntoIterator<Item = (i32, i32)>) -> Self {
+        let mut seen = std::collections::HashSet::new();
+        let positions = cells
+            .into_iter()
+            .filter(|&cell| seen.insert(cell))
+            .collect();
+        Self { positions }
+    }
+
     /// Get the positions of all vertices.
     pub fn positions(&self) -> &[(i32, i32)] {
         &self.positions
@@ -40,11 +67,14 @@ impl KingsSubgraph {
         self.positions.len()
     }
 
-    /// Compute Euclidean distance between two integer positions.
-    fn distance(p1: (i32, i32), p2: (i32, i32)) -> f64 {
-        let dx = (p1.0 - p2.0) as f64;
-        let dy = (p1.1 - p2.1) as f64;
-        (dx * dx + dy * dy).sqrt()
+    /// Whether two grid cells are one king's move apart.
+    ///
+    /// Equivalent to `0 < distance < KINGS_RADIUS` on integer positions, but
+    /// computed exactly.
+    fn is_kings_move(p1: (i32, i32), p2: (i32, i32)) -> bool {
+        let dr = (p1.0 - p2.0).abs();
+        let dc = (p1.1 - p2.1).abs();
+        dr.max(dc) == 1
     }
 }
 
@@ -60,7 +90,7 @@ impl Graph for KingsSubgraph {
         let mut count = 0;
         for i in 0..n {
             for j in (i + 1)..n {
-                if Self::distance(self.positions[i], self.positions[j]) < KINGS_RADIUS {
+                if Self::is_kings_move(self.positions[i], self.positions[j]) {
                     count += 1;
                 }
             }
@@ -73,7 +103,7 @@ impl Graph for KingsSubgraph {
         let mut edges = Vec::new();
         for i in 0..n {
             for j in (i + 1)..n {
-                if Self::distance(self.positions[i], self.positions[j]) < KINGS_RADIUS {
+                if Self::is_kings_move(self.positions[i], self.positions[j]) {
                     edges.push((i, j));
                 }
             }
@@ -85,7 +115,7 @@ impl Graph for KingsSubgraph {
         if u >= self.positions.len() || v >= self.positions.len() || u == v {
             return false;
         }
-        Self::distance(self.positions[u], self.positions[v]) < KINGS_RADIUS
+        Self::is_kings_move(self.positions[u], self.positions[v])
     }
 
     fn neighbors(&self, v: usize) -> Vec<usize> {
@@ -93,13 +123,34 @@ impl Graph for KingsSubgraph {
             return Vec::new();
         }
         (0..self.positions.len())
-            .filter(|&u| {
-                u != v && Self::distance(self.positions[v], self.positions[u]) < KINGS_RADIUS
-            })
+            .filter(|&u| Self::is_kings_move(self.positions[v], self.positions[u]))
             .collect()
     }
 }
 
+impl GraphView for KingsSubgraph {
+    fn graph_type(&self) -> &'static str {
+        <Self as Graph>::NAME
+    }
+
+    fn vertex_count(&self) -> usize {
+        Graph::num_vertices(self)
+    }
+
+    fn edge_list(&self) -> Vec<(usize, usize)> {
+        Graph::edges(self)
+    }
+
+    fn coordinates(&self) -> Option<Vec<(f64, f64)>> {
+        Some(
+            self.positions
+                .iter()
+                .map(|&(r, c)| (r as f64, c as f64))
+                .collect(),
+        )
+    }
+}
+
 impl crate::variant::VariantParam for KingsSubgraph {
     const CATEGORY: &'static str = "graph";
     const VALUE: &'static str = "KingsSubgraph";
@@ -116,3 +167,7 @@ impl crate::variant::CastToParent for KingsSubgraph {
         UnitDiskGraph::new(positions, KINGS_RADIUS)
     }
 }
+
+#[cfg(test)]
+#[path = "../unit_tests/topology/kings_subgraph.rs"]
+mod tests;
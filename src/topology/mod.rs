@@ -5,6 +5,7 @@
 //! - [`BipartiteGraph`]: Bipartite graph
 //! - [`DirectedGraph`]: Directed graph (digraph)
 //! - [`MixedGraph`]: Mixed graph with directed arcs and undirected edges
+//! - [`HyperGraph`]: Hyperedges connecting any number of vertices
 //! - [`UnitDiskGraph`]: Vertices with 2D positions, edges based on distance
 //! - [`KingsSubgraph`]: 8-connected grid graph (King's graph)
 //! - [`TriangularSubgraph`]: Triangular lattice subgraph
@@ -13,16 +14,19 @@
 mod bipartite_graph;
 mod directed_graph;
 mod graph;
+mod hypergraph;
 mod kings_subgraph;
 mod mixed_graph;
 mod planar_graph;
+pub(crate) mod planarity;
 pub mod small_graphs;
 mod triangular_subgraph;
 mod unit_disk_graph;
 
 pub use bipartite_graph::BipartiteGraph;
 pub use directed_graph::DirectedGraph;
-pub use graph::{Graph, GraphCast, SimpleGraph};
+pub use graph::{Graph, GraphCast, GraphView, SimpleGraph};
+pub use hypergraph::HyperGraph;
 pub use kings_subgraph::KingsSubgraph;
 pub use mixed_graph::MixedGraph;
 pub use planar_graph::PlanarGraph;
@@ -1,12 +1,15 @@
 //! Planar graph — validated wrapper around SimpleGraph.
 
 use super::graph::{Graph, SimpleGraph};
+use crate::error::ProblemError;
 use serde::{Deserialize, Serialize};
 
 /// Planar graph — validated wrapper around SimpleGraph.
 ///
-/// Construction validates the necessary planarity condition: |E| <= 3|V| - 6 for |V| >= 3.
-/// This is a necessary but not sufficient condition.
+/// [`PlanarGraph::new`] rejects non-planar edge sets: the Euler bound
+/// |E| <= 3|V| - 6 is checked first, then an exact planarity test.
+/// Deserialization validates the same way. Use
+/// [`PlanarGraph::new_unchecked`] for graphs already known to be planar.
 ///
 /// # Example
 ///
@@ -15,11 +18,16 @@ use serde::{Deserialize, Serialize};
 ///
 /// // K4 is planar: 4 vertices, 6 edges, 6 <= 3*4 - 6 = 6
 /// let edges = vec![(0,1),(0,2),(0,3),(1,2),(1,3),(2,3)];
-/// let g = PlanarGraph::new(4, edges);
+/// let g = PlanarGraph::new(4, edges).unwrap();
 /// assert_eq!(g.num_vertices(), 4);
 /// assert_eq!(g.num_edges(), 6);
+///
+/// // K3,3 meets the Euler bound but is not planar.
+/// let k33 = (0..3).flat_map(|u| (3..6).map(move |v| (u, v))).collect();
+/// assert!(PlanarGraph::new(6, k33).is_err());
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "PlanarGraphDef")]
 pub struct PlanarGraph {
     inner: SimpleGraph,
 }
@@ -27,27 +35,85 @@ pub struct PlanarGraph {
 impl PlanarGraph {
     /// Create a new planar graph.
     ///
+    /// # Errors
+    ///
+    /// Returns [`ProblemError::InvalidProblem`] if the graph exceeds the
+    /// Euler bound |E| <= 3|V| - 6 or is otherwise not planar.
+    ///
     /// # Panics
-    /// Panics if the graph violates the necessary planarity condition |E| <= 3|V| - 6.
-    pub fn new(num_vertices: usize, edges: Vec<(usize, usize)>) -> Self {
-        let inner = SimpleGraph::new(num_vertices, edges);
+    /// Panics if an edge references a vertex `>= num_vertices`.
+    pub fn new(num_vertices: usize, edges: Vec<(usize, usize)>) -> Result<Self, ProblemError> {
+        Self::from_simple_graph(SimpleGraph::new(num_vertices, edges))
+    }
+
+    /// Create a planar graph without checking planarity.
+    ///
+    /// For trusted callers such as reductions whose output is planar by
+    /// construction. Passing a non-planar graph breaks the guarantee that
+    /// [`embedding`](Self::embedding) returns `Some`.
+    pub fn new_unchecked(num_vertices: usize, edges: Vec<(usize, usize)>) -> Self {
+        Self {
+            inner: SimpleGraph::new(num_vertices, edges),
+        }
+    }
+
+    fn from_simple_graph(inner: SimpleGraph) -> Result<Self, ProblemError> {
+        let num_vertices = inner.num_vertices();
         if num_vertices >= 3 {
             let max_edges = 3 * num_vertices - 6;
-            assert!(
-                inner.num_edges() <= max_edges,
-                "graph has {} edges but a planar graph on {} vertices can have at most {} edges",
-                inner.num_edges(),
-                num_vertices,
-                max_edges
-            );
+            if inner.num_edges() > max_edges {
+                return Err(ProblemError::InvalidProblem(format!(
+                    "graph has {} edges but a planar graph on {} vertices can have at most {} edges",
+                    inner.num_edges(),
+                    num_vertices,
+                    max_edges
+                )));
+            }
         }
-        Self { inner }
+        if !inner.is_planar() {
+            return Err(ProblemError::InvalidProblem(
+                "graph is not planar".to_string(),
+            ));
+        }
+        Ok(Self { inner })
     }
 
     /// Get a reference to the underlying SimpleGraph.
     pub fn inner(&self) -> &SimpleGraph {
         &self.inner
     }
+
+    /// A combinatorial embedding as a rotation system: entry `v` lists the
+    /// neighbors of `v` in cyclic order around `v`.
+    ///
+    /// Always `Some` for validated graphs; `None` only if a graph built with
+    /// [`new_unchecked`](Self::new_unchecked) is not actually planar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use problemreductions::topology::PlanarGraph;
+    ///
+    /// let triangle = PlanarGraph::new(3, vec![(0, 1), (1, 2), (0, 2)]).unwrap();
+    /// let rotation = triangle.embedding().unwrap();
+    /// assert_eq!(rotation[0].len(), 2);
+    /// ```
+    pub fn embedding(&self) -> Option<Vec<Vec<usize>>> {
+        super::planarity::planar_embedding(self.inner.num_vertices(), &self.inner.edges())
+    }
+}
+
+#[derive(Deserialize)]
+struct PlanarGraphDef {
+    inner: SimpleGraph,
+}
+
+impl TryFrom<PlanarGraphDef> for PlanarGraph {
+    type Error = ProblemError;
+
+    fn try_from(value: PlanarGraphDef) -> Result<Self, Self::Error> {
+        Self::from_simple_graph(value.inner)
+    }
 }
 
 impl Graph for PlanarGraph {
@@ -3,7 +3,7 @@
 //! This is a public graph type produced by the triangular unit disk mapping reduction.
 //! It stores only integer grid positions; edges are computed on-the-fly from geometry.
 
-use super::graph::Graph;
+use super::graph::{Graph, GraphView};
 use super::unit_disk_graph::UnitDiskGraph;
 use serde::{Deserialize, Serialize};
 
@@ -17,7 +17,10 @@ use serde::{Deserialize, Serialize};
 /// - `y = col * sqrt(3)/2`
 ///
 /// Edges are computed on-the-fly: two positions are connected if their
-/// physical Euclidean distance is strictly less than 1.1.
+/// physical Euclidean distance is strictly less than 1.1. Lattice neighbors
+/// are exactly at distance 1, so an interior cell has 6 neighbors: `(row ± 1, col)`
+/// in its own column and two cells in each adjacent column (`row` and `row + 1`
+/// when `col` is even, `row - 1` and `row` when `col` is odd).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TriangularSubgraph {
     /// Integer grid positions (row, col) for each vertex.
@@ -126,6 +129,29 @@ impl Graph for TriangularSubgraph {
     }
 }
 
+impl GraphView for TriangularSubgraph {
+    fn graph_type(&self) -> &'static str {
+        <Self as Graph>::NAME
+    }
+
+    fn vertex_count(&self) -> usize {
+        Graph::num_vertices(self)
+    }
+
+    fn edge_list(&self) -> Vec<(usize, usize)> {
+        Graph::edges(self)
+    }
+
+    fn coordinates(&self) -> Option<Vec<(f64, f64)>> {
+        Some(
+            self.positions
+                .iter()
+                .map(|&(r, c)| Self::physical_position(r, c))
+                .collect(),
+        )
+    }
+}
+
 impl crate::variant::VariantParam for TriangularSubgraph {
     const CATEGORY: &'static str = "graph";
     const VALUE: &'static str = "TriangularSubgraph";
@@ -142,3 +168,7 @@ impl crate::variant::CastToParent for TriangularSubgraph {
         UnitDiskGraph::new(positions, TRIANGULAR_RADIUS)
     }
 }
+
+#[cfg(test)]
+#[path = "../unit_tests/topology/triangular_subgraph.rs"]
+mod tests;
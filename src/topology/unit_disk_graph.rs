@@ -3,7 +3,7 @@
 //! A unit disk graph (UDG) is a graph where vertices have positions in 2D space,
 //! and two vertices are connected if their distance is at most a threshold (radius).
 
-use super::graph::Graph;
+use super::graph::{Graph, GraphView};
 use serde::{Deserialize, Serialize};
 
 /// A unit disk graph with vertices at 2D positions.
@@ -228,6 +228,24 @@ impl Graph for UnitDiskGraph {
     }
 }
 
+impl GraphView for UnitDiskGraph {
+    fn graph_type(&self) -> &'static str {
+        <Self as Graph>::NAME
+    }
+
+    fn vertex_count(&self) -> usize {
+        Graph::num_vertices(self)
+    }
+
+    fn edge_list(&self) -> Vec<(usize, usize)> {
+        Graph::edges(self)
+    }
+
+    fn coordinates(&self) -> Option<Vec<(f64, f64)>> {
+        Some(self.positions.clone())
+    }
+}
+
 use super::graph::SimpleGraph;
 use crate::impl_variant_param;
 impl_variant_param!(UnitDiskGraph, "graph", parent: SimpleGraph,
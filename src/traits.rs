@@ -23,6 +23,36 @@ pub trait Problem: Clone {
     /// Returns pairs like `[("graph", "SimpleGraph"), ("weight", "i32")]`.
     fn variant() -> Vec<(&'static str, &'static str)>;
 
+    /// Optional fast evaluator for binary configurations packed as bitmasks.
+    ///
+    /// Problems with at most 64 binary variables may return a closure that
+    /// computes the same value as [`evaluate`](Self::evaluate) directly on a
+    /// [`BitConfig`](crate::config::BitConfig). [`BruteForce`](crate::BruteForce)
+    /// uses it to skip per-configuration slice handling. The default returns
+    /// `None`, which keeps the generic path.
+    fn bit_evaluator(&self) -> Option<BitEvaluator<'_, Self::Value>> {
+        None
+    }
+
+    /// Summary statistics of this instance.
+    ///
+    /// The default describes the configuration space only; graph problems
+    /// override it to attach statistics of their underlying graph.
+    fn statistics(&self) -> crate::statistics::ProblemStatistics {
+        crate::statistics::ProblemStatistics::from_dims(&self.dims())
+    }
+
+    /// Labeled contributions to the objective of a configuration.
+    ///
+    /// Problems with an additive objective return one term per edge, vertex,
+    /// or clause, so that the terms sum to the objective value of a feasible
+    /// configuration. Clause-based problems report 1 for a satisfied clause and
+    /// 0 otherwise. The default returns no terms, meaning no breakdown is
+    /// available.
+    fn objective_terms(&self, _config: &[usize]) -> Vec<ObjectiveTerm> {
+        Vec::new()
+    }
+
     /// Look up this problem's catalog entry.
     ///
     /// Returns the full [`ProblemType`] metadata from the catalog registry.
@@ -33,6 +63,30 @@ pub trait Problem: Clone {
     }
 }
 
+/// Closure evaluating a problem on a packed binary configuration.
+///
+/// Returned by [`Problem::bit_evaluator`].
+pub type BitEvaluator<'a, V> = Box<dyn Fn(crate::config::BitConfig) -> V + 'a>;
+
+/// One labeled contribution returned by [`Problem::objective_terms`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ObjectiveTerm {
+    /// What the term belongs to, e.g. `edge (0, 1)` or `clause 2`.
+    pub label: String,
+    /// The contribution of this term to the objective.
+    pub value: f64,
+}
+
+impl ObjectiveTerm {
+    /// Create a term from a label and a numeric contribution.
+    pub fn new(label: impl Into<String>, value: impl num_traits::ToPrimitive) -> Self {
+        Self {
+            label: label.into(),
+            value: value.to_f64().unwrap_or(f64::NAN),
+        }
+    }
+}
+
 /// Marker trait for explicitly declared problem variants.
 ///
 /// Implemented automatically by [`declare_variants!`] for each concrete type.
@@ -2,6 +2,7 @@
 
 use serde::de::{self, DeserializeOwned, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
 use std::fmt;
 
 /// Bound for objective value types (i32, f64, etc.)
@@ -11,7 +12,7 @@ pub trait NumericSize:
     + PartialOrd
     + num_traits::Num
     + num_traits::Zero
-    + num_traits::Bounded
+    + num_traits::ToPrimitive
     + std::ops::AddAssign
     + 'static
 {
@@ -23,7 +24,7 @@ impl<T> NumericSize for T where
         + PartialOrd
         + num_traits::Num
         + num_traits::Zero
-        + num_traits::Bounded
+        + num_traits::ToPrimitive
         + std::ops::AddAssign
         + 'static
 {
@@ -32,7 +33,8 @@ impl<T> NumericSize for T where
 /// Maps a weight element to its sum/metric type.
 ///
 /// This decouples the per-element weight type from the accumulation type.
-/// For concrete weights (`i32`, `f64`), `Sum` is the same type.
+/// For concrete weights (`i32`, `f64`, and `BigInt` with the `bigint-weights`
+/// feature), `Sum` is the same type.
 /// For the unit weight `One`, `Sum = i32`.
 pub trait WeightElement: Clone + Default + 'static {
     /// The numeric type used for sums and comparisons.
@@ -59,6 +61,17 @@ impl WeightElement for f64 {
     }
 }
 
+/// Arbitrary-precision integer weights, for instances whose coefficients or
+/// objective sums would overflow `i32` and must stay exact.
+#[cfg(feature = "bigint-weights")]
+impl WeightElement for num_bigint::BigInt {
+    type Sum = num_bigint::BigInt;
+    const IS_UNIT: bool = false;
+    fn to_sum(&self) -> num_bigint::BigInt {
+        self.clone()
+    }
+}
+
 /// The constant 1. Unit weight for unweighted problems.
 ///
 /// When used as the weight type parameter `W`, indicates that all weights
@@ -185,6 +198,8 @@ pub trait Aggregate: Clone + fmt::Debug + Serialize + DeserializeOwned {
 }
 
 /// Maximum aggregate over feasible values.
+///
+/// Like [`Min`], `V` may be a bottleneck value or a lexicographic vector.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Max<V>(pub Option<V>);
 
@@ -194,16 +209,14 @@ impl<V: fmt::Debug + PartialOrd + Clone + Serialize + DeserializeOwned> Aggregat
     }
 
     fn combine(self, other: Self) -> Self {
-        use std::cmp::Ordering;
-
         match (self.0, other.0) {
             (None, rhs) => Max(rhs),
             (lhs, None) => Max(lhs),
             (Some(lhs), Some(rhs)) => {
-                let ord = lhs.partial_cmp(&rhs).expect("cannot compare values (NaN?)");
-                match ord {
-                    Ordering::Less => Max(Some(rhs)),
-                    Ordering::Equal | Ordering::Greater => Max(Some(lhs)),
+                if ExtremumSense::Maximize.is_better(&rhs, &lhs) {
+                    Max(Some(rhs))
+                } else {
+                    Max(Some(lhs))
                 }
             }
         }
@@ -242,6 +255,12 @@ impl<V> Max<V> {
 }
 
 /// Minimum aggregate over feasible values.
+///
+/// `V` only needs a partial order, so the same aggregate covers additive
+/// objectives, bottleneck objectives (each configuration evaluates to its
+/// largest cost, as in [`KCenter`](crate::models::misc::KCenter)), and
+/// lexicographic objectives (`V = Vec<W>` or a tuple, compared element by
+/// element).
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Min<V>(pub Option<V>);
 
@@ -251,16 +270,14 @@ impl<V: fmt::Debug + PartialOrd + Clone + Serialize + DeserializeOwned> Aggregat
     }
 
     fn combine(self, other: Self) -> Self {
-        use std::cmp::Ordering;
-
         match (self.0, other.0) {
             (None, rhs) => Min(rhs),
             (lhs, None) => Min(lhs),
             (Some(lhs), Some(rhs)) => {
-                let ord = lhs.partial_cmp(&rhs).expect("cannot compare values (NaN?)");
-                match ord {
-                    Ordering::Greater => Min(Some(rhs)),
-                    Ordering::Equal | Ordering::Less => Min(Some(lhs)),
+                if ExtremumSense::Minimize.is_better(&rhs, &lhs) {
+                    Min(Some(rhs))
+                } else {
+                    Min(Some(lhs))
                 }
             }
         }
@@ -433,6 +450,40 @@ pub enum ExtremumSense {
     Minimize,
 }
 
+impl ExtremumSense {
+    /// Compare two objective values by quality under this sense:
+    /// `Ordering::Greater` means `a` is better than `b`.
+    ///
+    /// # Panics
+    /// Panics if the values are incomparable (e.g. NaN).
+    pub fn compare<V: PartialOrd>(self, a: &V, b: &V) -> Ordering {
+        let ord = a.partial_cmp(b).expect("cannot compare values (NaN?)");
+        match self {
+            ExtremumSense::Maximize => ord,
+            ExtremumSense::Minimize => ord.reverse(),
+        }
+    }
+
+    /// Whether `a` is strictly better than `b`; ties are not improvements.
+    pub fn is_better<V: PartialOrd>(self, a: &V, b: &V) -> bool {
+        self.compare(a, b) == Ordering::Greater
+    }
+
+    /// Whether `a` is at least as good as `b`.
+    pub fn is_at_least_as_good<V: PartialOrd>(self, a: &V, b: &V) -> bool {
+        self.compare(a, b) != Ordering::Less
+    }
+
+    /// The best of `values`, keeping the first one on ties, or `None` if
+    /// the iterator is empty.
+    pub fn best_of<V: PartialOrd>(self, values: impl IntoIterator<Item = V>) -> Option<V> {
+        values.into_iter().fold(None, |best, value| match best {
+            Some(best) if !self.is_better(&value, &best) => Some(best),
+            _ => Some(value),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Extremum<V> {
     pub sense: ExtremumSense,
@@ -467,14 +518,34 @@ impl<V> Extremum<V> {
     }
 }
 
+impl<V: PartialOrd> Extremum<V> {
+    /// Whether `self` is strictly better than `other` under their shared
+    /// sense. A feasible value beats an infeasible one; two infeasible values
+    /// tie.
+    ///
+    /// # Panics
+    /// Panics if both values are feasible but have different senses.
+    pub fn is_better_than(&self, other: &Self) -> bool {
+        match (&self.value, &other.value) {
+            (Some(lhs), Some(rhs)) => {
+                assert_eq!(
+                    self.sense, other.sense,
+                    "cannot compare Extremum values with different senses"
+                );
+                self.sense.is_better(lhs, rhs)
+            }
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
 impl<V: fmt::Debug + PartialOrd + Clone + Serialize + DeserializeOwned> Aggregate for Extremum<V> {
     fn identity() -> Self {
         Self::maximize(None)
     }
 
     fn combine(self, other: Self) -> Self {
-        use std::cmp::Ordering;
-
         match (self.value, other.value) {
             (None, rhs) => Self {
                 sense: other.sense,
@@ -489,12 +560,7 @@ impl<V: fmt::Debug + PartialOrd + Clone + Serialize + DeserializeOwned> Aggregat
                     self.sense, other.sense,
                     "cannot combine Extremum values with different senses"
                 );
-                let ord = lhs.partial_cmp(&rhs).expect("cannot compare values (NaN?)");
-                let keep_self = match self.sense {
-                    ExtremumSense::Maximize => matches!(ord, Ordering::Equal | Ordering::Greater),
-                    ExtremumSense::Minimize => matches!(ord, Ordering::Equal | Ordering::Less),
-                };
-                if keep_self {
+                if !self.sense.is_better(&rhs, &lhs) {
                     Self {
                         sense: self.sense,
                         value: Some(lhs),
@@ -578,10 +644,14 @@ impl fmt::Display for ProblemSize {
 }
 
 use crate::impl_variant_param;
+#[cfg(feature = "bigint-weights")]
+use num_bigint::BigInt;
 
 impl_variant_param!(f64, "weight");
 impl_variant_param!(i32, "weight", parent: f64, cast: |w| *w as f64);
 impl_variant_param!(One, "weight", parent: i32, cast: |_| 1i32);
+#[cfg(feature = "bigint-weights")]
+impl_variant_param!(BigInt, "weight");
 
 #[cfg(test)]
 #[path = "unit_tests/types.rs"]
@@ -590,3 +660,7 @@ mod tests;
 #[cfg(test)]
 #[path = "unit_tests/types_optimization_value.rs"]
 mod optimization_value_tests;
+
+#[cfg(all(test, feature = "bigint-weights"))]
+#[path = "unit_tests/types_bigint.rs"]
+mod bigint_tests;
@@ -108,3 +108,95 @@ fn test_dims_iterator_exact_size() {
     iter.next();
     assert_eq!(iter.len(), 3);
 }
+
+#[test]
+fn test_increment_in_place_carries() {
+    let dims = [2, 3];
+    let mut config = vec![0, 2];
+    assert!(DimsIterator::increment_in_place(&mut config, &dims));
+    assert_eq!(config, vec![1, 0]);
+    assert!(DimsIterator::increment_in_place(&mut config, &dims));
+    assert_eq!(config, vec![1, 1]);
+
+    // Wrapping past the last configuration returns false and resets to zeros.
+    let mut config = vec![1, 2];
+    assert!(!DimsIterator::increment_in_place(&mut config, &dims));
+    assert_eq!(config, vec![0, 0]);
+}
+
+#[test]
+fn test_fold_configs_matches_dims_iterator() {
+    for dims in [vec![4; 4], vec![2, 3, 1, 2], vec![3]] {
+        let expected: Vec<_> = DimsIterator::new(dims.clone()).collect();
+        let visited = fold_configs(&dims, Vec::new(), |mut acc, config| {
+            acc.push(config.to_vec());
+            acc
+        });
+        assert_eq!(visited, expected);
+    }
+}
+
+#[test]
+fn test_fold_configs_degenerate_dims() {
+    // Empty dims visit the single empty config; a zero dimension visits none.
+    assert_eq!(fold_configs(&[], 0, |count, _| count + 1), 1);
+    assert_eq!(fold_configs(&[2, 0, 3], 0, |count, _| count + 1), 0);
+}
+
+#[test]
+fn test_permutation_iterator_lexicographic_order() {
+    let perms: Vec<_> = PermutationIterator::new(3).collect();
+    assert_eq!(
+        perms,
+        vec![
+            vec![0, 1, 2],
+            vec![0, 2, 1],
+            vec![1, 0, 2],
+            vec![1, 2, 0],
+            vec![2, 0, 1],
+            vec![2, 1, 0],
+        ]
+    );
+    let empty: Vec<Vec<usize>> = PermutationIterator::new(0).collect();
+    assert_eq!(empty, vec![Vec::<usize>::new()]);
+}
+
+#[test]
+fn test_permutation_iterator_counts_and_fold() {
+    let iter = PermutationIterator::new(5);
+    assert_eq!(iter.size_hint(), (120, Some(120)));
+    let perms: Vec<_> = iter.collect();
+    assert_eq!(perms.len(), 120);
+    let distinct: std::collections::HashSet<_> = perms.iter().cloned().collect();
+    assert_eq!(distinct.len(), 120);
+
+    let visited = fold_permutations(5, Vec::new(), |mut acc, perm| {
+        acc.push(perm.to_vec());
+        acc
+    });
+    assert_eq!(visited, perms);
+}
+
+#[test]
+fn test_fold_bit_configs_matches_fold_configs_order() {
+    for n in 0..=4 {
+        let expected = fold_configs(&vec![2; n], Vec::new(), |mut acc, config| {
+            acc.push(config.to_vec());
+            acc
+        });
+        let packed = fold_bit_configs(n, Vec::new(), |mut acc, bits| {
+            acc.push(bits.to_config(n));
+            acc
+        });
+        assert_eq!(packed, expected);
+    }
+}
+
+#[test]
+fn test_bit_config_round_trip() {
+    let config = vec![1, 0, 0, 1, 1];
+    let bits = BitConfig::from_config(&config);
+    assert_eq!(bits, BitConfig(0b11001));
+    assert!(bits.get(0) && !bits.get(1) && bits.get(4));
+    assert_eq!(bits.to_config(5), config);
+}
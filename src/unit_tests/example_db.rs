@@ -1,5 +1,6 @@
 use crate::example_db::{
-    build_example_db, build_model_db, build_rule_db, find_model_example, find_rule_example,
+    build_example_db, build_model_db, build_rule_db, find_model_example, find_problem_example,
+    find_rule_example,
 };
 use crate::export::ProblemRef;
 use crate::registry::load_dyn;
@@ -62,6 +63,51 @@ fn test_find_model_example_exact_cover_by_3_sets() {
     );
 }
 
+#[test]
+fn test_find_problem_example_falls_back_to_another_variant() {
+    // MaximumClique/SimpleGraph/One has no example of its own.
+    let problem = ProblemRef {
+        name: "MaximumClique".to_string(),
+        variant: BTreeMap::from([
+            ("graph".to_string(), "SimpleGraph".to_string()),
+            ("weight".to_string(), "One".to_string()),
+        ]),
+    };
+    assert!(find_model_example(&problem).is_err());
+    let example = find_problem_example(&problem).expect("MaximumClique has an example");
+    assert_eq!(example.problem, "MaximumClique");
+    assert_ne!(example.variant, problem.variant);
+
+    let unknown = ProblemRef {
+        name: "NoSuchProblem".to_string(),
+        variant: BTreeMap::new(),
+    };
+    assert!(find_problem_example(&unknown).is_err());
+}
+
+#[test]
+fn test_every_registered_problem_has_a_loadable_example() {
+    let graph = ReductionGraph::new();
+    for name in graph.problem_types() {
+        let variant = graph
+            .default_variant_for(name)
+            .unwrap_or_else(|| graph.variants_for(name).remove(0));
+        let problem = ProblemRef {
+            name: name.to_string(),
+            variant,
+        };
+        let example = find_problem_example(&problem)
+            .unwrap_or_else(|e| panic!("no example to show for {name}: {e}"));
+        let loaded = load_dyn(&example.problem, &example.variant, example.instance.clone())
+            .unwrap_or_else(|e| panic!("example for {name} does not load: {e}"));
+        assert_eq!(
+            loaded.dims_dyn().len(),
+            example.optimal_config.len(),
+            "example config for {name} has the wrong length"
+        );
+    }
+}
+
 #[test]
 fn test_find_model_example_staff_scheduling() {
     let problem = ProblemRef {
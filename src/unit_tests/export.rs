@@ -305,3 +305,95 @@ fn lookup_overhead_rejects_target_variant_mismatch() {
     let result = lookup_overhead("MaximumIndependentSet", &source, "QUBO", &wrong_target);
     assert!(result.is_none(), "Should reject wrong target variant");
 }
+
+// ---- ProblemSide canonical form ----
+
+#[test]
+fn canonicalize_triangle_edge_permutations() {
+    use crate::models::graph::MaximumIndependentSet;
+    use crate::topology::SimpleGraph;
+
+    let a = ProblemSide::from_problem(&MaximumIndependentSet::new(
+        SimpleGraph::new(3, vec![(0, 1), (1, 2), (0, 2)]),
+        vec![1, 1, 1],
+    ));
+    let b = ProblemSide::from_problem(&MaximumIndependentSet::new(
+        SimpleGraph::new(3, vec![(2, 1), (2, 0), (1, 0)]),
+        vec![1, 1, 1],
+    ));
+    assert_ne!(a.instance, b.instance);
+
+    let canonical = a.canonicalize();
+    assert_eq!(canonical, b.canonicalize());
+    assert_eq!(
+        canonical.instance["graph"]["edges"],
+        serde_json::json!([[0, 1], [0, 2], [1, 2]])
+    );
+    assert_eq!(a.canonical_bytes(), b.canonical_bytes());
+    assert_eq!(a.canonical_hash(), b.canonical_hash());
+
+    // A different graph on the same vertices hashes differently.
+    let path = ProblemSide::from_problem(&MaximumIndependentSet::new(
+        SimpleGraph::new(3, vec![(0, 1), (1, 2)]),
+        vec![1, 1, 1],
+    ));
+    assert_ne!(a.canonical_hash(), path.canonical_hash());
+}
+
+#[test]
+fn canonicalize_removes_duplicate_unweighted_edges() {
+    let side = ProblemSide {
+        problem: "MaximumIndependentSet".to_string(),
+        variant: variant_to_map(vec![("graph", "SimpleGraph"), ("weight", "One")]),
+        instance: serde_json::json!({
+            "graph": {"num_vertices": 3, "edges": [[1, 2], [0, 1], [2, 1]]},
+            "weights": [1, 1, 1],
+        }),
+    };
+    assert_eq!(
+        side.canonicalize().instance["graph"]["edges"],
+        serde_json::json!([[0, 1], [1, 2]])
+    );
+}
+
+#[test]
+fn canonicalize_permutes_edge_aligned_data() {
+    use crate::models::graph::{MaxCut, RuralPostman};
+    use crate::topology::SimpleGraph;
+
+    let a = ProblemSide::from_problem(&MaxCut::new(
+        SimpleGraph::new(3, vec![(1, 2), (0, 1), (0, 2)]),
+        vec![5, 7, 9],
+    ));
+    let b = ProblemSide::from_problem(&MaxCut::new(
+        SimpleGraph::new(3, vec![(2, 0), (1, 0), (2, 1)]),
+        vec![9, 7, 5],
+    ));
+    let canonical = a.canonicalize();
+    assert_eq!(
+        canonical.instance["edge_weights"],
+        serde_json::json!([7, 9, 5])
+    );
+    assert_eq!(canonical, b.canonicalize());
+    assert_eq!(a.canonical_hash(), b.canonical_hash());
+
+    // Swapping the weights changes the instance and its hash.
+    let c = ProblemSide::from_problem(&MaxCut::new(
+        SimpleGraph::new(3, vec![(1, 2), (0, 1), (0, 2)]),
+        vec![7, 5, 9],
+    ));
+    assert_ne!(a.canonical_hash(), c.canonical_hash());
+
+    // Edge indices are renumbered to the canonical order.
+    let postman = ProblemSide::from_problem(&RuralPostman::new(
+        SimpleGraph::new(3, vec![(1, 2), (0, 1), (0, 2)]),
+        vec![1, 2, 3],
+        vec![0],
+    ));
+    let canonical = postman.canonicalize();
+    assert_eq!(
+        canonical.instance["edge_lengths"],
+        serde_json::json!([2, 3, 1])
+    );
+    assert_eq!(canonical.instance["required_edges"], serde_json::json!([2]));
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+fn sample_qubo() -> QUBO<f64> {
+    QUBO::from_matrix(vec![
+        vec![-1.0, 2.0, 0.0],
+        vec![0.0, -1.5, 0.5],
+        vec![0.0, 0.0, 3.0],
+    ])
+}
+
+fn all_configs(n: usize) -> Vec<Vec<usize>> {
+    (0..1usize << n)
+        .map(|mask| (0..n).map(|i| (mask >> i) & 1).collect())
+        .collect()
+}
+
+#[test]
+fn test_bqpjson_terms() {
+    let doc = BqpJson::from_qubo(&sample_qubo());
+    assert_eq!(doc.variable_domain, "boolean");
+    assert_eq!(doc.variable_ids, vec![0, 1, 2]);
+    assert_eq!(doc.offset, 0.0);
+    assert_eq!(doc.scale, 1.0);
+    assert_eq!(
+        doc.linear_terms,
+        vec![
+            LinearTerm { id: 0, coeff: -1.0 },
+            LinearTerm { id: 1, coeff: -1.5 },
+            LinearTerm { id: 2, coeff: 3.0 },
+        ]
+    );
+    // The zero coupler (0, 2) is omitted.
+    assert_eq!(
+        doc.quadratic_terms,
+        vec![
+            QuadraticTerm {
+                id_tail: 0,
+                id_head: 1,
+                coeff: 2.0
+            },
+            QuadraticTerm {
+                id_tail: 1,
+                id_head: 2,
+                coeff: 0.5
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_bqpjson_round_trip_energies() {
+    let qubo = sample_qubo();
+    let json = to_bqpjson(&qubo);
+    let (restored, offset) = from_bqpjson(&json).unwrap();
+
+    assert_eq!(restored.num_vars(), 3);
+    assert_eq!(offset, 0.0);
+    for config in all_configs(3) {
+        assert_eq!(restored.evaluate(&config), qubo.evaluate(&config));
+    }
+}
+
+#[test]
+fn test_bqpjson_import_scale_offset_and_ids() {
+    let json = r#"{
+        "version": "1.0.0",
+        "id": 7,
+        "metadata": {},
+        "variable_ids": [10, 3],
+        "variable_domain": "boolean",
+        "scale": 2.0,
+        "offset": 1.0,
+        "linear_terms": [{"id": 3, "coeff": 1.0}, {"id": 10, "coeff": -2.0}],
+        "quadratic_terms": [{"id_tail": 10, "id_head": 3, "coeff": 0.5}]
+    }"#;
+    let (qubo, offset) = from_bqpjson(json).unwrap();
+
+    // Ids are mapped in ascending order: 3 -> 0, 10 -> 1.
+    assert_eq!(qubo.matrix(), &[vec![2.0, 1.0], vec![0.0, -4.0]]);
+    assert_eq!(offset, 2.0);
+    // E(x3 = 1, x10 = 1) = 2 * (1 - 2 + 0.5 + 1) = 1
+    assert_eq!(qubo.evaluate(&[1, 1]) + offset, 1.0);
+}
+
+#[test]
+fn test_bqpjson_rejects_spin_domain() {
+    let mut doc = BqpJson::from_qubo(&sample_qubo());
+    doc.variable_domain = "spin".to_string();
+    assert!(matches!(
+        doc.to_qubo(),
+        Err(ProblemError::InvalidProblem(_))
+    ));
+}
+
+#[test]
+fn test_bqpjson_rejects_unknown_id() {
+    let mut doc = BqpJson::from_qubo(&sample_qubo());
+    doc.linear_terms.push(LinearTerm { id: 42, coeff: 1.0 });
+    assert!(doc.to_qubo().is_err());
+}
+
+#[test]
+fn test_bqpjson_invalid_json() {
+    assert!(matches!(
+        from_bqpjson("not json"),
+        Err(ProblemError::SerializationError(_))
+    ));
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::models::graph::MaximumIndependentSet;
+use crate::rules::{ReduceTo, ReductionResult};
+use crate::topology::Graph;
+use crate::types::One;
+
+fn triangle_to_grid() -> KingsSubgraph {
+    let source = MaximumIndependentSet::new(
+        SimpleGraph::new(3, vec![(0, 1), (1, 2), (0, 2)]),
+        vec![One; 3],
+    );
+    let reduction = ReduceTo::<MaximumIndependentSet<KingsSubgraph, One>>::reduce_to(&source);
+    reduction.target_problem().graph().clone()
+}
+
+#[test]
+fn test_pajek_triangle_grid_golden() {
+    let grid = triangle_to_grid();
+    assert_eq!(
+        to_pajek(&grid, None),
+        include_str!("../../../tests/data/triangle_ksg.net")
+    );
+}
+
+#[test]
+fn test_pajek_vertex_weights() {
+    let graph = SimpleGraph::new(3, vec![(0, 1), (1, 2)]);
+    let pajek = to_pajek(&graph, Some(&[1.0, 2.5, 3.0]));
+    assert_eq!(
+        pajek,
+        "*Vertices 3\n1 \"0\" weight 1\n2 \"1\" weight 2.5\n3 \"2\" weight 3\n*Edges\n1 2\n2 3\n"
+    );
+}
+
+#[test]
+fn test_pajek_coordinates() {
+    let graph = UnitDiskGraph::new(vec![(0.0, 0.0), (0.5, 1.0)], 1.5);
+    let pajek = to_pajek(&graph, Some(&[2.0, 1.0]));
+    assert_eq!(
+        pajek,
+        "*Vertices 2\n1 \"0\" 0 0 weight 2\n2 \"1\" 0.5 1 weight 1\n*Edges\n1 2\n"
+    );
+}
+
+#[test]
+fn test_csv_edgelist_columns() {
+    let grid = triangle_to_grid();
+    let csv = to_csv_edgelist(&grid, None);
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("source,target"));
+    let rows: Vec<(usize, usize)> = lines
+        .map(|line| {
+            let cols: Vec<&str> = line.split(',').collect();
+            assert_eq!(cols.len(), 2);
+            (cols[0].parse().unwrap(), cols[1].parse().unwrap())
+        })
+        .collect();
+    assert_eq!(rows, grid.edges());
+}
+
+#[test]
+fn test_csv_edgelist_weights() {
+    let graph = SimpleGraph::new(3, vec![(0, 1), (1, 2)]);
+    let csv = to_csv_edgelist(&graph, Some(&[3.0, -1.5]));
+    assert_eq!(csv, "source,target,weight\n0,1,3\n1,2,-1.5\n");
+}
+
+#[test]
+fn test_graph_from_json() {
+    let grid = triangle_to_grid();
+    let value = serde_json::to_value(&grid).unwrap();
+    let view = graph_from_json("KingsSubgraph", &value).unwrap();
+    assert_eq!(view.graph_type(), "KingsSubgraph");
+    assert_eq!(view.vertex_count(), grid.num_vertices());
+    assert_eq!(view.edge_list(), grid.edges());
+    assert_eq!(to_pajek(view.as_ref(), None), to_pajek(&grid, None));
+
+    assert!(graph_from_json("HyperGraph", &value).is_err());
+    assert!(graph_from_json("SimpleGraph", &serde_json::json!({})).is_err());
+}
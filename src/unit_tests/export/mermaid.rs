@@ -0,0 +1,63 @@
+use super::*;
+use std::collections::BTreeSet;
+
+#[test]
+fn test_mermaid_has_node_per_problem() {
+    let graph = ReductionGraph::new();
+    let diagram = mermaid(&graph);
+    assert!(diagram.starts_with("graph LR\n"));
+    let lines: BTreeSet<&str> = diagram.lines().collect();
+    for name in graph.problem_types() {
+        assert!(
+            lines.contains(format!("    {name}").as_str()),
+            "missing node {name}"
+        );
+    }
+}
+
+#[test]
+fn test_mermaid_has_arrow_per_reduction() {
+    let graph = ReductionGraph::new();
+    let diagram = mermaid(&graph);
+    let arrows: Vec<&str> = diagram.lines().filter(|l| l.contains("-->")).collect();
+
+    let mut pairs = BTreeSet::new();
+    for name in graph.problem_types() {
+        for edge in graph.outgoing_reductions(name) {
+            if edge.source_name == edge.target_name {
+                continue;
+            }
+            pairs.insert((edge.source_name, edge.target_name));
+            let prefix = format!("    {} -->|", edge.source_name);
+            let suffix = format!("| {}", edge.target_name);
+            assert!(
+                arrows
+                    .iter()
+                    .any(|l| l.starts_with(&prefix) && l.ends_with(&suffix)),
+                "missing arrow {} -> {}",
+                edge.source_name,
+                edge.target_name
+            );
+        }
+    }
+    assert_eq!(arrows.len(), pairs.len());
+}
+
+#[test]
+fn test_mermaid_labels_overhead_degree() {
+    let diagram = mermaid(&ReductionGraph::new());
+    // num_vertices, num_edges
+    assert!(diagram.contains("    MaximumIndependentSet -->|1| MinimumVertexCover\n"));
+    // num_subsets = num_vertices * num_edges
+    assert!(diagram.contains("    PartitionIntoTriangles -->|2| ExactCoverBy3Sets\n"));
+}
+
+#[test]
+fn test_mermaid_skips_variant_casts() {
+    let diagram = mermaid(&ReductionGraph::new());
+    for line in diagram.lines().filter(|l| l.contains("-->")) {
+        let (source, rest) = line.trim().split_once(" -->|").unwrap();
+        let (_, target) = rest.split_once("| ").unwrap();
+        assert_ne!(source, target, "unexpected self-loop: {line}");
+    }
+}
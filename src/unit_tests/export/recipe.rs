@@ -0,0 +1,100 @@
+use super::*;
+use crate::models::graph::MaximumIndependentSet;
+use crate::models::set::MinimumSetCovering;
+use crate::solvers::BruteForce;
+use crate::topology::SimpleGraph;
+use std::collections::BTreeMap;
+
+fn step(name: &str, variant: &[(&str, &str)]) -> ReductionStep {
+    ReductionStep {
+        name: name.to_string(),
+        variant: ReductionGraph::variant_to_map(variant),
+    }
+}
+
+/// MaximumIndependentSet -> MinimumVertexCover -> MinimumSetCovering.
+fn two_hop_path() -> ReductionPath {
+    let graph_i32 = [("graph", "SimpleGraph"), ("weight", "i32")];
+    ReductionPath {
+        steps: vec![
+            step("MaximumIndependentSet", &graph_i32),
+            step("MinimumVertexCover", &graph_i32),
+            step("MinimumSetCovering", &[("weight", "i32")]),
+        ],
+    }
+}
+
+fn refs(path: &ReductionPath) -> Vec<(String, BTreeMap<String, String>)> {
+    path.steps
+        .iter()
+        .map(|s| (s.name.clone(), s.variant.clone()))
+        .collect()
+}
+
+#[test]
+fn test_recipe_two_hop_round_trip_reduces_identically() {
+    let graph = ReductionGraph::new();
+    let path = two_hop_path();
+
+    let json = to_recipe(&graph, &path);
+    let recipe: Recipe = serde_json::from_str(&json).unwrap();
+    assert_eq!(recipe.version, RECIPE_VERSION);
+    assert_eq!(recipe.source.name, "MaximumIndependentSet");
+    assert_eq!(recipe.target.name, "MinimumSetCovering");
+    assert_eq!(recipe.steps.len(), 2);
+    assert_eq!(recipe.steps[0].to, recipe.steps[1].from);
+    assert_eq!(recipe.steps[0].to.name, "MinimumVertexCover");
+    assert!(recipe.steps.iter().all(|s| !s.overhead.is_empty()));
+
+    let reloaded = from_recipe(&graph, &json).unwrap();
+    assert_eq!(refs(&reloaded), refs(&path));
+
+    let source = MaximumIndependentSet::new(SimpleGraph::cycle(5), vec![1i32; 5]);
+    let original = graph.reduce_along_path(&path, &source).unwrap();
+    let replayed = graph.reduce_along_path(&reloaded, &source).unwrap();
+    let original_target = original.target_problem::<MinimumSetCovering<i32>>();
+    let replayed_target = replayed.target_problem::<MinimumSetCovering<i32>>();
+    assert_eq!(
+        serde_json::to_value(original_target).unwrap(),
+        serde_json::to_value(replayed_target).unwrap()
+    );
+
+    let target_solution = BruteForce::new().find_witness(replayed_target).unwrap();
+    assert_eq!(
+        original.extract_solution(&target_solution),
+        replayed.extract_solution(&target_solution)
+    );
+}
+
+#[test]
+fn test_recipe_rejects_unregistered_step() {
+    let graph = ReductionGraph::new();
+    let mut recipe = Recipe::from_path(&graph, &two_hop_path());
+    recipe.steps[1]
+        .to
+        .variant
+        .insert("weight".to_string(), "Unknown".to_string());
+    recipe.target = recipe.steps[1].to.clone();
+
+    let err = ReductionPath::from_recipe(&graph, &recipe).unwrap_err();
+    assert!(err.to_string().contains("step 2"), "{err}");
+}
+
+#[test]
+fn test_recipe_rejects_broken_chain_and_version() {
+    let graph = ReductionGraph::new();
+    let recipe = Recipe::from_path(&graph, &two_hop_path());
+
+    let mut reversed = recipe.clone();
+    reversed.steps.swap(0, 1);
+    assert!(ReductionPath::from_recipe(&graph, &reversed).is_err());
+
+    let mut future = recipe;
+    future.version = RECIPE_VERSION + 1;
+    assert!(ReductionPath::from_recipe(&graph, &future).is_err());
+
+    assert!(matches!(
+        from_recipe(&graph, "{\"version\": 1}"),
+        Err(ProblemError::SerializationError(_))
+    ));
+}
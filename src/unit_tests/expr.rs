@@ -135,6 +135,24 @@ fn test_expr_is_polynomial() {
     assert!(!Expr::Sqrt(Box::new(Expr::Var("n"))).is_polynomial());
 }
 
+#[test]
+fn test_expr_polynomial_degree() {
+    assert_eq!(Expr::parse("3").polynomial_degree(), Some(0));
+    assert_eq!(Expr::parse("num_vertices + 1").polynomial_degree(), Some(1));
+    assert_eq!(
+        Expr::parse("num_vertices * num_edges + num_vertices").polynomial_degree(),
+        Some(2)
+    );
+    assert_eq!(
+        Expr::parse("num_vertices^2 / 2").polynomial_degree(),
+        Some(2)
+    );
+    assert_eq!(Expr::parse("(n + m)^3").polynomial_degree(), Some(3));
+    assert_eq!(Expr::parse("2^n").polynomial_degree(), None);
+    assert_eq!(Expr::parse("n * log(n)").polynomial_degree(), None);
+    assert_eq!(Expr::parse("n^(1/2)").polynomial_degree(), None);
+}
+
 #[test]
 fn test_expr_is_valid_complexity_notation_simple() {
     assert!(Expr::Var("n").is_valid_complexity_notation());
@@ -96,3 +96,82 @@ fn test_invalid_json() {
     let result: Result<MaximumIndependentSet<SimpleGraph, i32>> = from_json("not valid json");
     assert!(result.is_err());
 }
+
+#[test]
+fn test_opb_round_trip() {
+    use crate::models::algebraic::{PseudoBoolean, PseudoBooleanConstraint};
+
+    let problem = PseudoBoolean::new(
+        4,
+        vec![(0, 2), (3, -1)],
+        vec![
+            PseudoBooleanConstraint::ge(vec![(0, 1), (1, 1), (2, 1)], 2),
+            PseudoBooleanConstraint::le(vec![(1, 3), (2, -2)], 1),
+            PseudoBooleanConstraint::eq(vec![(0, 1), (3, 1)], 1),
+        ],
+    );
+    let opb = to_opb(&problem);
+    assert_eq!(
+        opb,
+        "* #variable= 4 #constraint= 3\n\
+         min: +2 x1 -1 x4 ;\n\
+         +1 x1 +1 x2 +1 x3 >= 2 ;\n\
+         -3 x2 +2 x3 >= -1 ;\n\
+         +1 x1 +1 x4 = 1 ;\n"
+    );
+
+    // `<=` is written as a negated `>=`, which describes the same instance.
+    let restored = from_opb(&opb).unwrap();
+    assert_eq!(restored.num_vars(), 4);
+    assert_eq!(restored.objective(), problem.objective());
+    for config in 0..16usize {
+        let config: Vec<usize> = (0..4).map(|i| (config >> i) & 1).collect();
+        assert_eq!(
+            crate::traits::Problem::evaluate(&restored, &config),
+            crate::traits::Problem::evaluate(&problem, &config)
+        );
+    }
+    assert_eq!(to_opb(&restored), opb);
+}
+
+#[test]
+fn test_from_opb_parses_competition_style_input() {
+    use crate::models::algebraic::PseudoBooleanComparison;
+
+    let opb = "* #variable= 5 #constraint= 2\n\
+               * a comment line\n\
+               min: 3 x1 -x2_ignored ;\n";
+    assert!(from_opb(opb).is_err());
+
+    let opb = "* #variable= 5 #constraint= 2\n\
+               min:\n  +1 x1\n  +2 x2 ;\n\
+               +1 x1 +1 x3 <= 1 ;\n\
+               2 x2 -1 x3 = 0;\n";
+    let problem = from_opb(opb).unwrap();
+    // The header declares more variables than the body uses.
+    assert_eq!(problem.num_vars(), 5);
+    assert_eq!(problem.objective(), &[(0, 1), (1, 2)]);
+    assert_eq!(problem.constraints()[0].cmp, PseudoBooleanComparison::Le);
+    assert_eq!(problem.constraints()[1].terms, vec![(1, 2), (2, -1)]);
+    assert_eq!(problem.constraints()[1].rhs, 0);
+
+    // Without a header the variable count comes from the largest index.
+    let problem = from_opb("+1 x3 >= 1 ;").unwrap();
+    assert_eq!(problem.num_vars(), 3);
+    assert!(problem.objective().is_empty());
+}
+
+#[test]
+fn test_from_opb_rejects_unsupported_syntax() {
+    for (opb, message) in [
+        ("+1 x1 x2 >= 1 ;", "non-linear"),
+        ("+1 ~x1 >= 1 ;", "negated literal"),
+        ("max: +1 x1 ;", "min:"),
+        ("+1 x1 +1 x2 ;", "missing relation"),
+        ("+1 x0 >= 1 ;", "invalid variable"),
+        ("+1 x1 >= one ;", "right-hand side"),
+    ] {
+        let err = from_opb(opb).unwrap_err().to_string();
+        assert!(err.contains(message), "{opb}: {err}");
+    }
+}
@@ -0,0 +1,163 @@
+use super::*;
+use crate::models::graph::MaximumIndependentSet;
+use crate::solvers::BruteForce;
+use crate::topology::SimpleGraph;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn path_problem() -> MaximumIndependentSet<SimpleGraph, i32> {
+    MaximumIndependentSet::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        vec![1i32; 4],
+    )
+}
+
+fn solved(problem: &MaximumIndependentSet<SimpleGraph, i32>) -> SolverResult {
+    let configs = BruteForce::new().find_all_witnesses(problem);
+    let value = problem.evaluate(&configs[0]);
+    SolverResult::from_problem(
+        problem,
+        "brute-force",
+        &value,
+        configs,
+        Duration::from_millis(12),
+    )
+}
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    let ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("{name}_{ts}.json"))
+}
+
+#[test]
+fn test_solver_result_records_canonical_problem_hash() {
+    let problem = path_problem();
+    let result = solved(&problem);
+
+    assert_eq!(
+        result.problem_hash,
+        ProblemSide::from_problem(&problem).canonical_hash()
+    );
+    assert_eq!(result.solver, "brute-force");
+    assert_eq!(result.objective, "Max(2)");
+    assert_eq!(result.configs.len(), 3);
+    assert!(result.verify(&problem).is_ok());
+}
+
+#[test]
+fn test_solver_result_hash_ignores_edge_order() {
+    let problem = path_problem();
+    let reordered = MaximumIndependentSet::new(
+        SimpleGraph::new(4, vec![(3, 2), (1, 0), (2, 1)]),
+        vec![1i32; 4],
+    );
+    assert!(solved(&problem).verify(&reordered).is_ok());
+}
+
+#[test]
+fn test_solver_result_file_roundtrip_rehashes_to_original_problem() {
+    let problem = path_problem();
+    let result = solved(&problem);
+    let path = temp_path("test_solver_result");
+
+    write_result(&result, &path).unwrap();
+    let loaded = read_result(&path).unwrap();
+    fs::remove_file(&path).ok();
+
+    assert_eq!(loaded, result);
+    assert_eq!(
+        loaded.problem_hash,
+        ProblemSide::from_problem(&problem).canonical_hash()
+    );
+    assert!(loaded
+        .check_problem(&ProblemSide::from_problem(&problem))
+        .is_ok());
+}
+
+#[test]
+fn test_solver_result_json_shape() {
+    let result = SolverResult {
+        problem_hash: 0xab,
+        solver: "ilp".to_string(),
+        objective: "Min(1)".to_string(),
+        configs: vec![vec![1, 0]],
+        elapsed: Duration::new(1, 500),
+    };
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(json["problem_hash"], "00000000000000ab");
+    assert_eq!(json["configs"], serde_json::json!([[1, 0]]));
+    assert_eq!(
+        json["elapsed"],
+        serde_json::json!({"secs": 1, "nanos": 500})
+    );
+    let back: SolverResult = serde_json::from_value(json).unwrap();
+    assert_eq!(back, result);
+}
+
+#[test]
+fn test_tampered_problem_hash_is_detected() {
+    let problem = path_problem();
+    let path = temp_path("test_solver_result_tampered");
+    write_result(&solved(&problem), &path).unwrap();
+
+    let mut json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    json["problem_hash"] = serde_json::json!("0123456789abcdef");
+    fs::write(&path, json.to_string()).unwrap();
+    let tampered = read_result(&path).unwrap();
+    fs::remove_file(&path).ok();
+
+    let err = tampered.verify(&problem).unwrap_err();
+    assert!(matches!(err, ProblemError::ResultMismatch(_)));
+    assert!(err.to_string().contains("0123456789abcdef"));
+}
+
+#[test]
+fn test_result_for_other_problem_is_detected() {
+    let result = solved(&path_problem());
+    let other = MaximumIndependentSet::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        vec![1, 1, 1, 5],
+    );
+    assert!(matches!(
+        result.check_problem(&ProblemSide::from_problem(&other)),
+        Err(ProblemError::ResultMismatch(_))
+    ));
+}
+
+#[test]
+fn test_tampered_config_or_objective_is_detected() {
+    let problem = path_problem();
+
+    let mut result = solved(&problem);
+    result.configs.push(vec![1, 1, 0, 0]);
+    let err = result.verify(&problem).unwrap_err();
+    assert!(err.to_string().contains("Max(None)"), "{err}");
+
+    let mut result = solved(&problem);
+    result.objective = "Max(3)".to_string();
+    assert!(result.verify(&problem).is_err());
+
+    let mut result = solved(&problem);
+    result.configs = vec![vec![1, 0]];
+    assert!(result.verify(&problem).is_err());
+}
+
+#[test]
+fn test_read_result_rejects_malformed_hash() {
+    let path = temp_path("test_solver_result_bad_hash");
+    fs::write(
+        &path,
+        r#"{"problem_hash":"xyz","solver":"ilp","objective":"Min(0)","configs":[],"elapsed":{"secs":0,"nanos":0}}"#,
+    )
+    .unwrap();
+    let err = read_result(&path).unwrap_err();
+    fs::remove_file(&path).ok();
+    assert!(
+        err.to_string().contains("invalid problem hash 'xyz'"),
+        "{err}"
+    );
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::solvers::BruteForce;
+use crate::solvers::{BruteForce, Solver};
 use crate::traits::Problem;
 use crate::types::Extremum;
 
@@ -33,6 +33,29 @@ fn test_comparison_eq() {
     assert!(cmp.holds(10.0, 10.0 + 1e-10));
 }
 
+#[test]
+fn test_comparison_range() {
+    // The operator alone only sees the upper bound.
+    let cmp = Comparison::Range;
+    assert!(cmp.holds(1.0, 5.0));
+    assert!(cmp.holds(5.0, 5.0));
+    assert!(!cmp.holds(6.0, 5.0));
+}
+
+#[test]
+fn test_comparison_is_hashable() {
+    let ops: std::collections::HashSet<Comparison> = [
+        Comparison::Le,
+        Comparison::Ge,
+        Comparison::Eq,
+        Comparison::Range,
+        Comparison::Le,
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(ops.len(), 4);
+}
+
 // ============================================================
 // LinearConstraint tests
 // ============================================================
@@ -319,6 +342,103 @@ fn test_ilp_equality_constraint() {
     assert_eq!(solutions[0], vec![0, 1]);
 }
 
+#[test]
+fn test_linear_constraint_range() {
+    // 1 <= x0 + 2*x1 <= 2
+    let c = LinearConstraint::range(vec![(0, 1.0), (1, 2.0)], 1.0, 2.0);
+    assert_eq!(c.cmp, Comparison::Range);
+    assert_eq!(c.lower_bound(), Some(1.0));
+    assert_eq!(c.upper_bound(), Some(2.0));
+    assert!(!c.is_satisfied(&[0, 0])); // 0 < 1
+    assert!(c.is_satisfied(&[1, 0])); // 1
+    assert!(c.is_satisfied(&[0, 1])); // 2
+    assert!(!c.is_satisfied(&[1, 1])); // 3 > 2
+
+    assert_eq!(
+        LinearConstraint::le(vec![(0, 1.0)], 3.0).lower_bound(),
+        None
+    );
+    assert_eq!(
+        LinearConstraint::ge(vec![(0, 1.0)], 3.0).upper_bound(),
+        None
+    );
+    assert_eq!(
+        LinearConstraint::eq(vec![(0, 1.0)], 3.0).lower_bound(),
+        Some(3.0)
+    );
+}
+
+#[test]
+fn test_linear_constraint_range_serialization() {
+    let ranged = LinearConstraint::range(vec![(0, 1.0)], 1.0, 4.0);
+    let json = serde_json::to_value(&ranged).unwrap();
+    assert_eq!(json["cmp"], "Range");
+    assert_eq!(json["lo"], 1.0);
+    assert_eq!(json["rhs"], 4.0);
+    let restored: LinearConstraint = serde_json::from_value(json).unwrap();
+    assert_eq!(restored, ranged);
+
+    // Non-ranged constraints keep their existing JSON shape.
+    let le = LinearConstraint::le(vec![(0, 1.0)], 4.0);
+    let json = serde_json::to_value(&le).unwrap();
+    assert_eq!(json["cmp"], "Le");
+    assert!(json.get("lo").is_none());
+    assert_eq!(
+        serde_json::from_value::<LinearConstraint>(json).unwrap(),
+        le
+    );
+}
+
+#[test]
+fn test_linear_constraint_rejects_inconsistent_lower_bound() {
+    for json in [
+        serde_json::json!({ "terms": [], "cmp": "Range", "rhs": 1.0 }),
+        serde_json::json!({ "terms": [], "cmp": "Range", "rhs": 1.0, "lo": 2.0 }),
+        serde_json::json!({ "terms": [], "cmp": "Le", "rhs": 1.0, "lo": 0.0 }),
+    ] {
+        assert!(serde_json::from_value::<LinearConstraint>(json).is_err());
+    }
+}
+
+#[test]
+#[should_panic(expected = "exceeds upper bound")]
+fn test_linear_constraint_range_empty() {
+    LinearConstraint::range(vec![(0, 1.0)], 3.0, 2.0);
+}
+
+#[test]
+fn test_ilp_range_matches_two_inequalities() {
+    // Maximize 3*x0 + 2*x1 + 4*x2 - x3 subject to 1 <= x0 + x1 + x2 + x3 <= 2
+    let terms = vec![(0, 1.0), (1, 1.0), (2, 1.0), (3, 1.0)];
+    let objective = vec![(0, 3.0), (1, 2.0), (2, 4.0), (3, -1.0)];
+    let ranged = ILP::<bool>::new(
+        4,
+        vec![LinearConstraint::range(terms.clone(), 1.0, 2.0)],
+        objective.clone(),
+        ObjectiveSense::Maximize,
+    );
+    let split = ILP::<bool>::new(
+        4,
+        vec![
+            LinearConstraint::ge(terms.clone(), 1.0),
+            LinearConstraint::le(terms, 2.0),
+        ],
+        objective,
+        ObjectiveSense::Maximize,
+    );
+
+    let solver = BruteForce::new();
+    for config in crate::config::DimsIterator::new(ranged.dims()) {
+        assert_eq!(ranged.evaluate(&config), split.evaluate(&config));
+    }
+    assert_eq!(solver.solve(&ranged), solver.solve(&split));
+    assert_eq!(
+        solver.find_all_witnesses(&ranged),
+        solver.find_all_witnesses(&split)
+    );
+    assert_eq!(ranged.num_constraints(), 1);
+}
+
 #[test]
 fn test_ilp_multiple_constraints() {
     // Maximize x0 + x1 + x2 subject to:
@@ -0,0 +1,107 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+
+fn example() -> PseudoBoolean {
+    PseudoBoolean::new(
+        3,
+        vec![(0, 1), (1, 2), (2, 3)],
+        vec![
+            PseudoBooleanConstraint::ge(vec![(0, 1), (1, 1), (2, 1)], 2),
+            PseudoBooleanConstraint::le(vec![(0, 1), (1, 1)], 1),
+        ],
+    )
+}
+
+#[test]
+fn test_pseudo_boolean_basic() {
+    let problem = example();
+    assert_eq!(problem.num_vars(), 3);
+    assert_eq!(problem.num_constraints(), 2);
+    assert_eq!(problem.dims(), vec![2; 3]);
+    assert_eq!(<PseudoBoolean as Problem>::NAME, "PseudoBoolean");
+    assert_eq!(<PseudoBoolean as Problem>::variant(), vec![]);
+}
+
+#[test]
+fn test_pseudo_boolean_evaluate() {
+    let problem = example();
+    assert_eq!(problem.evaluate(&[1, 0, 1]), Min(Some(4)));
+    assert_eq!(problem.evaluate(&[0, 1, 1]), Min(Some(5)));
+    // Violates x0 + x1 <= 1
+    assert_eq!(problem.evaluate(&[1, 1, 0]), Min(None));
+    // Violates x0 + x1 + x2 >= 2
+    assert_eq!(problem.evaluate(&[0, 0, 1]), Min(None));
+    // Malformed configurations
+    assert_eq!(problem.evaluate(&[1, 0]), Min(None));
+    assert_eq!(problem.evaluate(&[2, 0, 1]), Min(None));
+}
+
+#[test]
+fn test_pseudo_boolean_brute_force() {
+    let problem = example();
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Min(Some(4)));
+    assert_eq!(solver.find_all_witnesses(&problem), vec![vec![1, 0, 1]]);
+}
+
+#[test]
+fn test_pseudo_boolean_satisfaction_and_equality() {
+    // No objective: exactly two of three variables, x0 forced off.
+    let problem = PseudoBoolean::new(
+        3,
+        vec![],
+        vec![
+            PseudoBooleanConstraint::eq(vec![(0, 1), (1, 1), (2, 1)], 2),
+            PseudoBooleanConstraint::ge(vec![(0, -1)], 0),
+        ],
+    );
+    assert_eq!(problem.evaluate(&[0, 1, 1]), Min(Some(0)));
+    assert_eq!(problem.evaluate(&[1, 1, 0]), Min(None));
+    assert_eq!(
+        BruteForce::new().find_all_witnesses(&problem),
+        vec![vec![0, 1, 1]]
+    );
+}
+
+#[test]
+fn test_pseudo_boolean_num_slack_bits() {
+    // >= 2 over three unit terms: slack 0..=1 (1 bit); <= 1 over two: 0..=1 (1 bit).
+    assert_eq!(example().num_slack_bits(), 2);
+    let problem = PseudoBoolean::new(
+        3,
+        vec![],
+        vec![
+            // 3x0 + 2x1 - x2 <= 4: slack 0..=5 needs 3 bits
+            PseudoBooleanConstraint::le(vec![(0, 3), (1, 2), (2, -1)], 4),
+            // Equalities need no slack
+            PseudoBooleanConstraint::eq(vec![(0, 1)], 1),
+            // Unsatisfiable >= has an empty slack range
+            PseudoBooleanConstraint::ge(vec![(0, 1)], 2),
+        ],
+    );
+    assert_eq!(problem.num_slack_bits(), 3);
+}
+
+#[test]
+fn test_pseudo_boolean_serialization() {
+    let problem = example();
+    let json = serde_json::to_value(&problem).unwrap();
+    let restored: PseudoBoolean = serde_json::from_value(json).unwrap();
+    assert_eq!(restored, problem);
+}
+
+#[test]
+fn test_pseudo_boolean_deserialize_rejects_ranged_constraint() {
+    let json = serde_json::json!({
+        "num_vars": 2,
+        "objective": [],
+        "constraints": [{ "terms": [[0, 1], [1, 1]], "cmp": "Range", "rhs": 1 }],
+    });
+    assert!(serde_json::from_value::<PseudoBoolean>(json).is_err());
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_pseudo_boolean_rejects_unknown_variable() {
+    PseudoBoolean::new(2, vec![(2, 1)], vec![]);
+}
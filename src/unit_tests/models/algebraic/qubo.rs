@@ -53,6 +53,17 @@ fn test_qubo_new_reverse_indices() {
     assert_eq!(problem.get(0, 1), Some(&3.0)); // Should be stored at (0, 1)
 }
 
+#[test]
+fn test_num_interactions() {
+    let problem = QUBO::from_matrix(vec![
+        vec![1.0, 2.0, 0.0],
+        vec![0.0, 4.0, -5.0],
+        vec![7.0, 0.0, 6.0],
+    ]);
+    // Only the strict upper triangle counts; diagonal and lower entries are ignored.
+    assert_eq!(problem.num_interactions(), 2);
+}
+
 #[test]
 fn test_get_out_of_bounds() {
     let problem = QUBO::from_matrix(vec![vec![1.0, 2.0], vec![0.0, 3.0]]);
@@ -118,3 +129,109 @@ fn test_qubo_paper_example() {
     let best = solver.find_witness(&problem).unwrap();
     assert_eq!(Problem::evaluate(&problem, &best), Min(Some(-2.0)));
 }
+
+#[test]
+fn test_qubo_from_terms_sparse_structure() {
+    let linear: Vec<(usize, f64)> = (0..100).step_by(5).map(|i| (i, -1.0)).collect();
+    let mut quadratic: Vec<(usize, usize, f64)> =
+        (0..99).map(|i| (i, i + 1, 0.5 * (i % 4) as f64)).collect();
+    quadratic.extend([(90, 10, 2.0), (10, 90, 1.0), (7, 7, 3.0)]);
+    let qubo = QUBO::from_terms(100, &linear, &quadratic);
+
+    assert_eq!(qubo.num_vars(), 100);
+    // Chain couplings with i % 4 == 0 are zero, leaving 74 of 99, plus (10, 90).
+    assert_eq!(qubo.num_interactions(), 75);
+    assert_eq!(qubo.get(10, 90), Some(&3.0));
+    assert_eq!(qubo.get(90, 10), Some(&0.0));
+    // (7, 7) is folded into the diagonal.
+    assert_eq!(qubo.get(7, 7), Some(&3.0));
+    assert_eq!(qubo.get(5, 5), Some(&-1.0));
+    assert_eq!(qubo.get(6, 6), Some(&0.0));
+
+    let configs: Vec<Vec<usize>> = vec![
+        vec![0; 100],
+        vec![1; 100],
+        (0..100).map(|i| i % 2).collect(),
+        (0..100)
+            .map(|i| usize::from(i % 5 == 0 || i == 7))
+            .collect(),
+    ];
+    for config in configs {
+        let expected: f64 = linear
+            .iter()
+            .map(|&(i, w)| w * config[i] as f64)
+            .sum::<f64>()
+            + quadratic
+                .iter()
+                .map(|&(i, j, w)| w * (config[i] * config[j]) as f64)
+                .sum::<f64>();
+        assert_eq!(qubo.evaluate(&config), expected);
+    }
+}
+
+#[test]
+#[should_panic(expected = "references variable 4")]
+fn test_qubo_from_terms_rejects_out_of_range() {
+    QUBO::from_terms(4, &[(0, 1.0)], &[(1, 4, 2.0)]);
+}
+
+#[test]
+fn test_qubo_density_diagonal_only() {
+    // Linear terms only: 4 of the 10 upper-triangular entries.
+    let qubo = QUBO::new(vec![1.0, -2.0, 3.0, -4.0], vec![]);
+    assert_eq!(qubo.num_interactions(), 0);
+    assert_eq!(qubo.nonzero_terms(), 4);
+    assert!((qubo.density() - 0.4).abs() < 1e-12);
+
+    let (linear, quadratic) = qubo.to_sparse_terms();
+    assert_eq!(linear, vec![(0, 1.0), (1, -2.0), (2, 3.0), (3, -4.0)]);
+    assert!(quadratic.is_empty());
+}
+
+#[test]
+fn test_qubo_density_fully_dense() {
+    let n = 5;
+    let matrix: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| if j >= i { (i + j + 1) as f64 } else { 0.0 })
+                .collect()
+        })
+        .collect();
+    let qubo = QUBO::from_matrix(matrix);
+    assert_eq!(qubo.nonzero_terms(), 15);
+    assert_eq!(qubo.num_interactions(), 10);
+    assert_eq!(qubo.density(), 1.0);
+
+    let (linear, quadratic) = qubo.to_sparse_terms();
+    assert_eq!(linear.len(), 5);
+    assert_eq!(quadratic.len(), 10);
+    assert_eq!(quadratic[0], (0, 1, 2.0));
+}
+
+#[test]
+fn test_qubo_sparse_terms_round_trip() {
+    // Lower-triangular entries are ignored, as in evaluation.
+    let qubo = QUBO::from_matrix(vec![
+        vec![1.0, 0.0, -3.0],
+        vec![5.0, 0.0, 2.0],
+        vec![0.0, 0.0, 0.0],
+    ]);
+    assert_eq!(qubo.nonzero_terms(), 3);
+    assert!((qubo.density() - 0.5).abs() < 1e-12);
+
+    let (linear, quadratic) = qubo.to_sparse_terms();
+    assert_eq!(linear, vec![(0, 1.0)]);
+    assert_eq!(quadratic, vec![(0, 2, -3.0), (1, 2, 2.0)]);
+    let rebuilt = QUBO::from_terms(3, &linear, &quadratic);
+    for config in [[0, 0, 0], [1, 0, 1], [1, 1, 1], [0, 1, 1]] {
+        assert_eq!(rebuilt.evaluate(&config), qubo.evaluate(&config));
+    }
+}
+
+#[test]
+fn test_qubo_density_empty() {
+    let qubo = QUBO::<f64>::from_matrix(vec![]);
+    assert_eq!(qubo.nonzero_terms(), 0);
+    assert_eq!(qubo.density(), 0.0);
+}
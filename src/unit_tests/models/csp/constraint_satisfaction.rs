@@ -0,0 +1,127 @@
+use super::*;
+use crate::models::graph::KColoring;
+use crate::solvers::BruteForce;
+use crate::topology::{Graph, SimpleGraph};
+use crate::types::Or;
+use crate::variant::KN;
+
+/// Encode proper `k`-coloring of `graph` as a CSP with one not-equal
+/// constraint per edge.
+fn coloring_csp(graph: &SimpleGraph, k: usize) -> CSP {
+    let domains = vec![k; graph.num_vertices()];
+    let constraints = graph
+        .edges()
+        .into_iter()
+        .map(|(u, v)| CspConstraint::from_predicate(vec![u, v], &domains, |t| t[0] != t[1]))
+        .collect();
+    CSP::new(domains, constraints)
+}
+
+#[test]
+fn test_csp_creation() {
+    let problem = CSP::new(
+        vec![2, 3, 4],
+        vec![CspConstraint::new(vec![0, 2], vec![vec![0, 3], vec![1, 0]])],
+    );
+    assert_eq!(problem.dims(), vec![2, 3, 4]);
+    assert_eq!(problem.num_variables(), 3);
+    assert_eq!(problem.num_constraints(), 1);
+    assert_eq!(problem.max_domain_size(), 4);
+    assert_eq!(problem.constraints()[0].arity(), 2);
+}
+
+#[test]
+fn test_csp_from_predicate_enumerates_allowed_tuples() {
+    let domains = vec![2, 3];
+    let constraint = CspConstraint::from_predicate(vec![1, 0], &domains, |t| t[0] > t[1]);
+    assert_eq!(constraint.allowed, vec![vec![1, 0], vec![2, 0], vec![2, 1]]);
+    assert!(constraint.is_satisfied(&[0, 2]));
+    assert!(!constraint.is_satisfied(&[1, 1]));
+    // Scope variables missing from the configuration
+    assert!(!constraint.is_satisfied(&[0]));
+}
+
+#[test]
+fn test_csp_evaluation() {
+    let domains = vec![3, 3];
+    let problem = CSP::new(
+        domains.clone(),
+        vec![CspConstraint::from_predicate(vec![0, 1], &domains, |t| {
+            t[0] + t[1] == 3
+        })],
+    );
+    assert_eq!(problem.evaluate(&[1, 2]), Or(true));
+    assert_eq!(problem.evaluate(&[2, 2]), Or(false));
+    // Out-of-domain value and wrong length
+    assert!(!is_satisfying_csp(&[3, 3], problem.constraints(), &[3, 0]));
+    assert_eq!(problem.evaluate(&[1]), Or(false));
+    // No constraints: every in-domain assignment satisfies
+    assert!(CSP::new(vec![2], vec![]).is_satisfying(&[1]));
+}
+
+#[test]
+fn test_csp_graph_coloring_matches_kcoloring() {
+    // C5 is 3-colorable but not 2-colorable
+    let cycle = SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    let solver = BruteForce::new();
+
+    let three = coloring_csp(&cycle, 3);
+    let witness = solver.find_witness(&three).unwrap();
+    assert!(three.is_satisfying(&witness));
+    assert!(crate::models::graph::kcoloring::is_valid_coloring(
+        &cycle, &witness, 3
+    ));
+    assert_eq!(
+        solver.find_all_witnesses(&three).len(),
+        solver
+            .find_all_witnesses(&KColoring::<KN, _>::with_k(cycle.clone(), 3))
+            .len()
+    );
+
+    assert!(solver.find_witness(&coloring_csp(&cycle, 2)).is_none());
+}
+
+#[test]
+fn test_csp_list_coloring() {
+    // Path 0 - 1 - 2 where vertex 0 may only use color 2 and vertex 2 only
+    // colors {0, 2}: vertex 1 avoids color 2 and the color of vertex 2.
+    let domains = vec![3; 3];
+    let differ = |t: &[usize]| t[0] != t[1];
+    let problem = CSP::new(
+        domains.clone(),
+        vec![
+            CspConstraint::from_predicate(vec![0, 1], &domains, differ),
+            CspConstraint::from_predicate(vec![1, 2], &domains, differ),
+            CspConstraint::new(vec![0], vec![vec![2]]),
+            CspConstraint::new(vec![2], vec![vec![0], vec![2]]),
+        ],
+    );
+    let mut witnesses = BruteForce::new().find_all_witnesses(&problem);
+    witnesses.sort();
+    assert_eq!(witnesses, vec![vec![2, 0, 2], vec![2, 1, 0], vec![2, 1, 2]]);
+}
+
+#[test]
+fn test_csp_serialization() {
+    let problem = CSP::new(
+        vec![2, 2],
+        vec![CspConstraint::new(vec![0, 1], vec![vec![0, 1]])],
+    );
+    let json = serde_json::to_string(&problem).unwrap();
+    let restored: CSP = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.domains(), &[2, 2]);
+    assert_eq!(restored.constraints(), problem.constraints());
+    assert_eq!(restored.evaluate(&[0, 1]), Or(true));
+}
+
+#[test]
+#[should_panic(expected = "does not match scope")]
+fn test_csp_constraint_tuple_length_mismatch() {
+    let _ = CspConstraint::new(vec![0, 1], vec![vec![0]]);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_csp_constraint_variable_out_of_range() {
+    let _ = CSP::new(vec![2], vec![CspConstraint::new(vec![1], vec![vec![0]])]);
+}
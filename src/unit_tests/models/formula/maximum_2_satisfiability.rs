@@ -2,10 +2,10 @@ use super::*;
 use crate::models::formula::CNFClause;
 use crate::solvers::{BruteForce, Solver};
 use crate::traits::Problem;
-use crate::types::Max;
+use crate::types::{Max, One};
 
-fn issue_instance() -> Maximum2Satisfiability {
-    Maximum2Satisfiability::new(
+fn issue_instance() -> Maximum2Satisfiability<One> {
+    Maximum2Satisfiability::<One>::new(
         4,
         vec![
             CNFClause::new(vec![1, 2]),
@@ -28,9 +28,9 @@ fn test_maximum_2_satisfiability_creation() {
 }
 
 #[test]
-#[should_panic(expected = "Clause 0 has 3 literals, expected 2")]
+#[should_panic(expected = "Clause 0 has 3 literals, expected at most 2")]
 fn test_maximum_2_satisfiability_wrong_clause_size() {
-    let _ = Maximum2Satisfiability::new(3, vec![CNFClause::new(vec![1, 2, 3])]);
+    let _ = Maximum2Satisfiability::<One>::new(3, vec![CNFClause::new(vec![1, 2, 3])]);
 }
 
 #[test]
@@ -77,7 +77,7 @@ fn test_maximum_2_satisfiability_witness() {
 fn test_maximum_2_satisfiability_serialization() {
     let problem = issue_instance();
     let json = serde_json::to_string(&problem).unwrap();
-    let restored: Maximum2Satisfiability = serde_json::from_str(&json).unwrap();
+    let restored: Maximum2Satisfiability<One> = serde_json::from_str(&json).unwrap();
     assert_eq!(restored.num_vars(), 4);
     assert_eq!(restored.num_clauses(), 7);
     assert_eq!(restored.evaluate(&[1, 1, 0, 1]), Max(Some(6)));
@@ -89,3 +89,44 @@ fn test_maximum_2_satisfiability_count_satisfied() {
     let assignment = vec![true, true, false, true];
     assert_eq!(problem.count_satisfied(&assignment), 6);
 }
+
+fn weighted_instance() -> Maximum2Satisfiability<i32> {
+    // (x1 ∨ x2) w3, (¬x1) w2, (¬x2 ∨ x3) w1, (¬x3) w4
+    Maximum2Satisfiability::with_weights(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![-1]),
+            CNFClause::new(vec![-2, 3]),
+            CNFClause::new(vec![-3]),
+        ],
+        vec![3, 2, 1, 4],
+    )
+}
+
+#[test]
+fn test_maximum_2_satisfiability_weighted_evaluate() {
+    let problem = weighted_instance();
+    assert_eq!(problem.weights(), &[3, 2, 1, 4]);
+    // x1=F, x2=T, x3=F: clauses 0, 1, 3 satisfied → 3 + 2 + 4
+    assert_eq!(problem.evaluate(&[0, 1, 0]), Max(Some(9)));
+    assert_eq!(problem.satisfied_weight(&[false, true, false]), 9);
+    assert_eq!(problem.count_satisfied(&[false, true, false]), 3);
+    // All false: clauses 1, 2, 3 satisfied → 2 + 1 + 4
+    assert_eq!(problem.evaluate(&[0, 0, 0]), Max(Some(7)));
+}
+
+#[test]
+fn test_maximum_2_satisfiability_weighted_solver() {
+    let problem = weighted_instance();
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Max(Some(9)));
+    let witness = solver.find_witness(&problem).unwrap();
+    assert_eq!(witness, vec![0, 1, 0]);
+}
+
+#[test]
+#[should_panic(expected = "weights length must match number of clauses")]
+fn test_maximum_2_satisfiability_weights_length_mismatch() {
+    let _ = Maximum2Satisfiability::with_weights(2, vec![CNFClause::new(vec![1, 2])], vec![1, 2]);
+}
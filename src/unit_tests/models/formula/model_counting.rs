@@ -0,0 +1,78 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+
+#[test]
+fn test_model_counting_creation() {
+    let problem = ModelCounting::new(
+        3,
+        vec![CNFClause::new(vec![1, 2]), CNFClause::new(vec![-1, 3])],
+    );
+    assert_eq!(problem.num_vars(), 3);
+    assert_eq!(problem.num_clauses(), 2);
+    assert_eq!(problem.num_literals(), 4);
+    assert_eq!(problem.dims(), vec![2, 2, 2]);
+    assert_eq!(<ModelCounting as Problem>::NAME, "ModelCounting");
+}
+
+#[test]
+fn test_model_counting_evaluate() {
+    let problem = ModelCounting::new(
+        2,
+        vec![CNFClause::new(vec![1, 2]), CNFClause::new(vec![-1, -2])],
+    );
+    assert_eq!(problem.evaluate(&[1, 0]), Sum(1));
+    assert_eq!(problem.evaluate(&[0, 1]), Sum(1));
+    assert_eq!(problem.evaluate(&[0, 0]), Sum(0));
+    assert_eq!(problem.evaluate(&[1, 1]), Sum(0));
+}
+
+#[test]
+fn test_model_counting_solver_matches_count() {
+    let problem = ModelCounting::new(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![-1, 3]),
+            CNFClause::new(vec![-2, -3]),
+        ],
+    );
+    assert_eq!(BruteForce::new().solve(&problem), Sum(2));
+    assert_eq!(problem.count(), Some(2));
+}
+
+#[test]
+fn test_model_counting_independent_clauses_multiply() {
+    // (x1 OR x2) AND (x3 OR x4) AND (x5 OR x6 OR x7): 3 * 3 * 7 models,
+    // doubled by the unconstrained x8.
+    let problem = ModelCounting::new(
+        8,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![3, 4]),
+            CNFClause::new(vec![5, 6, 7]),
+        ],
+    );
+    assert_eq!(problem.count(), Some(3 * 3 * 7 * 2));
+    assert_eq!(BruteForce::new().solve(&problem), Sum(126));
+}
+
+#[test]
+fn test_model_counting_from_satisfiability() {
+    let sat = Satisfiability::new(2, vec![CNFClause::new(vec![1]), CNFClause::new(vec![-1])]);
+    let problem = ModelCounting::from(sat);
+    assert_eq!(problem.num_vars(), 2);
+    assert_eq!(problem.count(), Some(0));
+}
+
+#[test]
+fn test_model_counting_serialization_round_trip() {
+    let problem = ModelCounting::new(2, vec![CNFClause::new(vec![1, -2])]);
+    let json = serde_json::to_value(&problem).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"num_vars": 2, "clauses": [{"literals": [1, -2]}]})
+    );
+    let restored: ModelCounting = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.count(), Some(3));
+}
@@ -226,3 +226,98 @@ fn test_sat_paper_example() {
     let solution = solver.find_witness(&problem);
     assert!(solution.is_some());
 }
+
+#[test]
+fn test_count_models_known_counts() {
+    // No clauses: every assignment is a model.
+    assert_eq!(Satisfiability::new(5, vec![]).count_models(), Some(32));
+    // An empty clause is unsatisfiable.
+    assert_eq!(
+        Satisfiability::new(3, vec![CNFClause::new(vec![])]).count_models(),
+        Some(0)
+    );
+    // Unit propagation fixes the chain x1 -> x2 -> x3.
+    let chain = Satisfiability::new(
+        4,
+        vec![
+            CNFClause::new(vec![1]),
+            CNFClause::new(vec![-1, 2]),
+            CNFClause::new(vec![-2, 3]),
+        ],
+    );
+    assert_eq!(chain.count_models(), Some(2));
+    // Independent clauses multiply: (x1 OR x2) AND (NOT x3 OR x4).
+    let independent = Satisfiability::new(
+        4,
+        vec![CNFClause::new(vec![1, 2]), CNFClause::new(vec![-3, 4])],
+    );
+    assert_eq!(independent.count_models(), Some(9));
+}
+
+#[test]
+fn test_count_models_out_of_range_literal_reads_false() {
+    // x3 does not exist, so the clause reduces to x1.
+    let problem = Satisfiability::new(2, vec![CNFClause::new(vec![1, 3])]);
+    assert_eq!(problem.count_models(), Some(2));
+    assert_eq!(BruteForce::new().find_all_witnesses(&problem).len(), 2);
+}
+
+#[test]
+fn test_count_models_rejects_unsupported_formulas() {
+    let limit = Satisfiability::MAX_COUNT_VARS;
+    assert_eq!(
+        Satisfiability::new(limit, vec![]).count_models(),
+        Some(1 << limit)
+    );
+    assert_eq!(Satisfiability::new(limit + 1, vec![]).count_models(), None);
+    assert_eq!(Satisfiability::new(64, vec![]).count_models(), None);
+    // Literal 0 names no variable.
+    let zero = Satisfiability::new(2, vec![CNFClause::new(vec![1, 0])]);
+    assert_eq!(zero.count_models(), None);
+}
+
+#[test]
+fn test_count_models_matches_brute_force_on_random_formulas() {
+    use rand::rngs::SmallRng;
+    use rand::{RngExt, SeedableRng};
+
+    let mut rng = SmallRng::seed_from_u64(7);
+    for _ in 0..40 {
+        let num_vars = rng.random_range(1..=8);
+        let clauses = (0..rng.random_range(0..=10))
+            .map(|_| {
+                let literals = (0..rng.random_range(1..=3))
+                    .map(|_| {
+                        let var = rng.random_range(1..=num_vars as i32);
+                        if rng.random_bool(0.5) {
+                            var
+                        } else {
+                            -var
+                        }
+                    })
+                    .collect();
+                CNFClause::new(literals)
+            })
+            .collect();
+        let problem = Satisfiability::new(num_vars, clauses);
+        let expected = BruteForce::new().find_all_witnesses(&problem).len() as u64;
+        assert_eq!(problem.count_models(), Some(expected), "{problem:?}");
+    }
+}
+
+#[test]
+fn test_sat_objective_terms_report_clauses() {
+    let problem = Satisfiability::new(
+        2,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![-1]),
+            CNFClause::new(vec![-2]),
+        ],
+    );
+    let terms = problem.objective_terms(&[1, 0]);
+    let labels: Vec<&str> = terms.iter().map(|t| t.label.as_str()).collect();
+    assert_eq!(labels, vec!["clause 0", "clause 1", "clause 2"]);
+    let values: Vec<f64> = terms.iter().map(|t| t.value).collect();
+    assert_eq!(values, vec![1.0, 0.0, 1.0]);
+}
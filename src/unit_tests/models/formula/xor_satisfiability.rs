@@ -0,0 +1,115 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+
+#[test]
+fn test_xor_clause_parity() {
+    let clause = XorClause::new(vec![1, -2, 3]);
+    assert_eq!(clause.len(), 3);
+    assert_eq!(clause.variables(), vec![0, 1, 2]);
+
+    // Literal values (x1, ¬x2, x3)
+    assert!(clause.is_satisfied(&[true, true, false])); // (1, 0, 0)
+    assert!(!clause.is_satisfied(&[true, false, false])); // (1, 1, 0)
+    assert!(clause.is_satisfied(&[true, false, true])); // (1, 1, 1)
+    assert!(clause.is_satisfied(&[false, false, false])); // (0, 1, 0)
+
+    // The empty XOR has even parity and is never satisfied
+    assert!(XorClause::new(vec![]).is_empty());
+    assert!(!XorClause::new(vec![]).is_satisfied(&[true]));
+
+    // A repeated variable cancels out
+    let repeated = XorClause::new(vec![1, 1, 2]);
+    assert!(repeated.is_satisfied(&[true, true]));
+    assert!(!repeated.is_satisfied(&[true, false]));
+}
+
+#[test]
+fn test_xor_satisfiability_creation() {
+    let problem = XorSatisfiability::new(
+        4,
+        vec![CNFClause::new(vec![1, 4]), CNFClause::new(vec![-1, -3])],
+        vec![XorClause::new(vec![1, 2, 3]), XorClause::new(vec![2, -4])],
+    );
+    assert_eq!(problem.num_vars(), 4);
+    assert_eq!(problem.num_clauses(), 2);
+    assert_eq!(problem.num_literals(), 4);
+    assert_eq!(problem.max_clause_size(), 2);
+    assert_eq!(problem.num_xor_clauses(), 2);
+    assert_eq!(problem.num_xor_literals(), 5);
+    assert_eq!(problem.dims(), vec![2; 4]);
+
+    assert!(problem.evaluate(&[1, 0, 0, 0]));
+    assert_eq!(problem.count_satisfied(&[true, false, false, false]), 4);
+    // x1 = x3 = 1 violates (¬x1 ∨ ¬x3); x1 ⊕ x2 ⊕ x3 = 0 violates the parity
+    assert!(!problem.evaluate(&[1, 0, 1, 0]));
+    assert_eq!(problem.count_satisfied(&[true, false, true, false]), 2);
+}
+
+#[test]
+fn test_xor_satisfiability_linear_system() {
+    // x1 ⊕ x2 = 1, x2 ⊕ x3 = 1, x1 ⊕ x3 = 0 has exactly the solutions
+    // (1, 0, 1) and (0, 1, 0).
+    let problem = XorSatisfiability::from_xor_clauses(
+        3,
+        vec![
+            XorClause::new(vec![1, 2]),
+            XorClause::new(vec![2, 3]),
+            XorClause::new(vec![1, -3]),
+        ],
+    );
+    let mut witnesses = BruteForce::new().find_all_witnesses(&problem);
+    witnesses.sort();
+    assert_eq!(witnesses, vec![vec![0, 1, 0], vec![1, 0, 1]]);
+}
+
+#[test]
+fn test_xor_satisfiability_inconsistent_parities() {
+    // Summing the three parities gives 0 = 1 over GF(2).
+    let problem = XorSatisfiability::from_xor_clauses(
+        3,
+        vec![
+            XorClause::new(vec![1, 2]),
+            XorClause::new(vec![2, 3]),
+            XorClause::new(vec![1, 3]),
+        ],
+    );
+    assert_eq!(BruteForce::new().solve(&problem), crate::types::Or(false));
+}
+
+#[test]
+fn test_xor_satisfiability_cnf_restricts_parity_solutions() {
+    // x1 ⊕ x2 ⊕ x3 = 1 has four solutions; (¬x1) and (¬x2) leave (0, 0, 1).
+    let problem = XorSatisfiability::new(
+        3,
+        vec![CNFClause::new(vec![-1]), CNFClause::new(vec![-2])],
+        vec![XorClause::new(vec![1, 2, 3])],
+    );
+    assert_eq!(
+        BruteForce::new().find_all_witnesses(&problem),
+        vec![vec![0, 0, 1]]
+    );
+}
+
+#[test]
+fn test_xor_satisfiability_serialization() {
+    let problem = XorSatisfiability::new(
+        2,
+        vec![CNFClause::new(vec![1, 2])],
+        vec![XorClause::new(vec![1, -2])],
+    );
+    let json = serde_json::to_value(&problem).unwrap();
+    assert_eq!(
+        json["xor_clauses"][0]["literals"],
+        serde_json::json!([1, -2])
+    );
+    let restored: XorSatisfiability = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.xor_clauses(), problem.xor_clauses());
+    assert!(restored.evaluate(&[1, 1]));
+    assert!(!restored.evaluate(&[1, 0]));
+
+    // Plain SAT instances deserialize with no parity constraints
+    let plain: XorSatisfiability =
+        serde_json::from_str(r#"{"num_vars":1,"clauses":[{"literals":[1]}]}"#).unwrap();
+    assert_eq!(plain.num_xor_clauses(), 0);
+}
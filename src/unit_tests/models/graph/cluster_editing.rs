@@ -0,0 +1,91 @@
+use super::*;
+use crate::{solvers::BruteForce, topology::SimpleGraph, traits::Problem};
+
+/// Triangle {0, 1, 2} of expensive edges loosely attached to the pair {3, 4}.
+/// Edges in order: (0,1)=3, (0,2)=3, (1,2)=3, (1,3)=1, (2,3)=1, (3,4)=2
+fn example_instance() -> ClusterEditing<SimpleGraph, i32> {
+    let graph = SimpleGraph::new(5, vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    ClusterEditing::new(graph, vec![3, 3, 3, 1, 1, 2])
+}
+
+#[test]
+fn test_cluster_editing_creation() {
+    let problem = example_instance();
+    assert_eq!(problem.num_vertices(), 5);
+    assert_eq!(problem.num_edges(), 6);
+    assert_eq!(problem.dims(), vec![5; 5]);
+    assert_eq!(problem.edge_weights(), &[3, 3, 3, 1, 1, 2]);
+}
+
+#[test]
+fn test_cluster_editing_evaluate() {
+    let problem = example_instance();
+    // {0,1,2} | {3,4}: delete (1,3) and (2,3).
+    assert_eq!(problem.evaluate(&[0, 0, 0, 1, 1]), Min(Some(2)));
+    // Cluster ids are interchangeable.
+    assert_eq!(problem.evaluate(&[4, 4, 4, 2, 2]), Min(Some(2)));
+    // {0,1,2,3} | {4}: insert (0,3), delete (3,4).
+    assert_eq!(problem.evaluate(&[0, 0, 0, 0, 1]), Min(Some(3)));
+    // One big cluster: insert the 4 missing pairs.
+    assert_eq!(problem.evaluate(&[0; 5]), Min(Some(4)));
+    // All singletons: delete every edge.
+    assert_eq!(problem.evaluate(&[0, 1, 2, 3, 4]), Min(Some(13)));
+}
+
+#[test]
+fn test_cluster_editing_evaluate_invalid() {
+    let problem = example_instance();
+    assert_eq!(problem.evaluate(&[0, 0, 0, 5, 1]), Min(None));
+    assert!(!problem.is_valid_solution(&[0, 0, 0]));
+}
+
+#[test]
+fn test_cluster_editing_brute_force() {
+    let problem = example_instance();
+    let solver = BruteForce::new();
+    let solutions = solver.find_all_witnesses(&problem);
+    // The unique optimal partition {0,1,2} | {3,4} under all 5 * 4 labelings.
+    assert_eq!(solutions.len(), 20);
+    for solution in &solutions {
+        assert_eq!(problem.evaluate(solution), Min(Some(2)));
+        assert_eq!(solution[0], solution[1]);
+        assert_eq!(solution[1], solution[2]);
+        assert_eq!(solution[3], solution[4]);
+        assert_ne!(solution[0], solution[3]);
+    }
+}
+
+#[test]
+fn test_cluster_editing_cluster_graph_costs_nothing() {
+    // Already a disjoint union of cliques.
+    let graph = SimpleGraph::new(5, vec![(0, 1), (2, 3), (2, 4), (3, 4)]);
+    let problem = ClusterEditing::<_, i32>::unit_weights(graph);
+    let solution = BruteForce::new().find_witness(&problem).unwrap();
+    assert_eq!(problem.evaluate(&solution), Min(Some(0)));
+}
+
+#[test]
+fn test_cluster_editing_unweighted_path() {
+    // Path 0-1-2: either delete one edge or insert (0, 2).
+    use crate::types::One;
+    let problem: ClusterEditing<SimpleGraph, One> =
+        ClusterEditing::unit_weights(SimpleGraph::path(3));
+    assert!(!problem.is_weighted());
+    let solution = BruteForce::new().find_witness(&problem).unwrap();
+    assert_eq!(problem.evaluate(&solution), Min(Some(1)));
+}
+
+#[test]
+fn test_cluster_editing_serialization() {
+    let problem = example_instance();
+    let json = serde_json::to_value(&problem).unwrap();
+    let deserialized: ClusterEditing<SimpleGraph, i32> = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized.num_edges(), 6);
+    assert_eq!(deserialized.evaluate(&[0, 0, 0, 1, 1]), Min(Some(2)));
+}
+
+#[test]
+#[should_panic(expected = "edge_weights length must match num_edges")]
+fn test_cluster_editing_rejects_wrong_weight_count() {
+    let _ = ClusterEditing::new(SimpleGraph::path(3), vec![1i32]);
+}
@@ -0,0 +1,74 @@
+use super::*;
+use crate::models::graph::MaxCut;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+
+fn cycle(n: usize) -> SimpleGraph {
+    SimpleGraph::new(n, (0..n).map(|i| (i, (i + 1) % n)).collect())
+}
+
+fn k4() -> SimpleGraph {
+    SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)])
+}
+
+#[test]
+fn test_max_bisection_basic() {
+    let problem = MaxBisection::<_, i32>::unweighted(cycle(4));
+    assert_eq!(problem.dims(), vec![2; 4]);
+    assert_eq!(problem.num_vertices(), 4);
+    assert_eq!(problem.num_edges(), 4);
+
+    assert_eq!(problem.evaluate(&[0, 1, 0, 1]), Max(Some(4)));
+    assert_eq!(problem.evaluate(&[0, 0, 1, 1]), Max(Some(2)));
+    // Unbalanced and malformed configurations are infeasible.
+    assert_eq!(problem.evaluate(&[0, 1, 1, 1]), Max(None));
+    assert_eq!(problem.evaluate(&[0, 1, 0]), Max(None));
+    assert_eq!(problem.evaluate(&[0, 2, 0, 1]), Max(None));
+}
+
+#[test]
+fn test_max_bisection_even_cycles_cut_everything() {
+    // Even cycles are bipartite with equal sides, so the best bisection is the full cut.
+    for n in [4, 6] {
+        let problem = MaxBisection::<_, i32>::unweighted(cycle(n));
+        assert_eq!(BruteForce::new().solve(&problem), Max(Some(n as i32)));
+    }
+}
+
+#[test]
+fn test_max_bisection_k4_balance_binds() {
+    // Heavy edges at vertex 0 make {0} vs {1,2,3} the maximum cut (15),
+    // but a bisection must put a second vertex next to 0 (5+5+1+1 = 12).
+    let weights = vec![5, 5, 5, 1, 1, 1];
+    let bisection = MaxBisection::new(k4(), weights.clone());
+    let max_cut = MaxCut::new(k4(), weights);
+
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&max_cut), Max(Some(15)));
+    assert_eq!(solver.solve(&bisection), Max(Some(12)));
+    for witness in solver.find_all_witnesses(&bisection) {
+        assert!(bisection.is_balanced(&witness));
+    }
+
+    let unweighted = MaxBisection::<_, i32>::unweighted(k4());
+    assert_eq!(solver.solve(&unweighted), Max(Some(4)));
+}
+
+#[test]
+fn test_max_bisection_odd_vertices_infeasible() {
+    let problem = MaxBisection::<_, i32>::unweighted(cycle(5));
+    assert_eq!(BruteForce::new().solve(&problem), Max(None));
+    assert!(BruteForce::new().find_all_witnesses(&problem).is_empty());
+}
+
+#[test]
+fn test_max_bisection_serialization() {
+    let problem = MaxBisection::new(cycle(4), vec![1, 2, 3, 4]);
+    let json = serde_json::to_string(&problem).unwrap();
+    let restored: MaxBisection<SimpleGraph, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.edge_weights(), vec![1, 2, 3, 4]);
+    assert_eq!(
+        restored.evaluate(&[0, 1, 0, 1]),
+        problem.evaluate(&[0, 1, 0, 1])
+    );
+}
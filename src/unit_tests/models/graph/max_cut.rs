@@ -154,3 +154,80 @@ fn test_maxcut_paper_example() {
     let best = solver.find_witness(&problem).unwrap();
     assert_eq!(problem.evaluate(&best).unwrap(), 5);
 }
+
+#[test]
+fn test_maxcut_objective_terms_sum_to_cut() {
+    use crate::traits::Problem;
+
+    let problem = MaxCut::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3), (0, 3), (0, 2)]),
+        vec![1, 2, 3, 4, 5],
+    );
+    let config = [1, 0, 0, 1];
+    let terms = problem.objective_terms(&config);
+    let values: Vec<f64> = terms.iter().map(|t| t.value).collect();
+    assert_eq!(values, vec![1.0, 0.0, 3.0, 0.0, 5.0]);
+    assert_eq!(terms[4].label, "edge (0, 2)");
+    assert_eq!(problem.evaluate(&config), Max(Some(9)));
+    assert_eq!(values.iter().sum::<f64>(), 9.0);
+}
+
+#[test]
+fn test_maxcut_negative_weights_stay_uncut() {
+    use crate::traits::Problem;
+
+    // Path 0 - 1 - 2 with a repulsive edge (0, 1) and an attractive edge (1, 2)
+    let problem = MaxCut::new(SimpleGraph::new(3, vec![(0, 1), (1, 2)]), vec![3, -2]);
+    assert_eq!(problem.cut_size(&[0, 1, 0]), 1);
+    assert_eq!(problem.cut_size(&[0, 1, 1]), 3);
+    assert_eq!(problem.cut_size(&[0, 0, 1]), -2);
+
+    let witness = BruteForce::new().find_witness(&problem).unwrap();
+    assert_eq!(problem.evaluate(&witness), Max(Some(3)));
+    assert_eq!(witness[1], witness[2]);
+}
+
+#[test]
+fn test_maxcut_all_negative_optimum_is_empty_cut() {
+    use crate::solvers::Solver;
+
+    let problem = MaxCut::new(
+        SimpleGraph::new(3, vec![(0, 1), (1, 2), (0, 2)]),
+        vec![-1, -4, -2],
+    );
+    assert_eq!(BruteForce::new().solve(&problem), Max(Some(0)));
+    assert_eq!(problem.frustration_index(), 0);
+}
+
+#[test]
+fn test_maxcut_frustration() {
+    // Triangle with two repulsive edges and one attractive edge: balanced
+    // by putting 0 alone; the all-repulsive triangle frustrates one edge.
+    let graph = SimpleGraph::new(3, vec![(0, 1), (1, 2), (0, 2)]);
+    let balanced = MaxCut::new(graph.clone(), vec![2, -3, 1]);
+    assert_eq!(balanced.frustration(&[1, 0, 0]), 0);
+    assert_eq!(balanced.frustration(&[0, 0, 0]), 3);
+    assert_eq!(balanced.frustration(&[0, 1, 0]), 4);
+    assert_eq!(balanced.frustration_index(), 0);
+
+    let frustrated = MaxCut::new(graph, vec![1, 1, 1]);
+    assert_eq!(frustrated.frustration_index(), 1);
+}
+
+#[test]
+fn test_maxcut_frustration_index_counts_min_flipped_edges() {
+    // Signed 4-cycle with an odd number of attractive edges cannot be balanced
+    let problem = MaxCut::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3), (0, 3)]),
+        vec![1, 1, 1, -1],
+    );
+    assert_eq!(problem.frustration_index(), 1);
+    let index = (0..16)
+        .map(|mask| {
+            let config: Vec<usize> = (0..4).map(|v| (mask >> v) & 1).collect();
+            problem.frustration(&config)
+        })
+        .min()
+        .unwrap();
+    assert_eq!(index, 1);
+}
@@ -0,0 +1,104 @@
+use super::*;
+use crate::models::graph::MinimumFeedbackArcSet;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::DirectedGraph;
+use crate::traits::Problem;
+
+/// Tournament on `n` vertices: arc i -> j for i < j, reversed when
+/// `reversed(i, j)` holds.
+fn tournament(n: usize, reversed: impl Fn(usize, usize) -> bool) -> DirectedGraph {
+    let mut arcs = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            arcs.push(if reversed(i, j) { (j, i) } else { (i, j) });
+        }
+    }
+    DirectedGraph::new(n, arcs)
+}
+
+#[test]
+fn test_maximum_acyclic_subgraph_creation() {
+    let graph = DirectedGraph::new(4, vec![(0, 1), (1, 2), (2, 0), (1, 3), (3, 0), (2, 3)]);
+    let problem = MaximumAcyclicSubgraph::new(graph, vec![1i32; 6]);
+    assert_eq!(problem.num_vertices(), 4);
+    assert_eq!(problem.num_arcs(), 6);
+    assert_eq!(problem.dims(), vec![2; 6]);
+    assert!(problem.is_weighted());
+}
+
+#[test]
+fn test_maximum_acyclic_subgraph_evaluation() {
+    let graph = DirectedGraph::new(3, vec![(0, 1), (1, 2), (2, 0)]);
+    let problem = MaximumAcyclicSubgraph::new(graph, vec![1i32, 2, 3]);
+
+    // Keeping the whole cycle is infeasible
+    assert_eq!(problem.evaluate(&[1, 1, 1]), Max(None));
+    assert!(!problem.is_valid_solution(&[1, 1, 1]));
+    // Dropping arc 0->1 keeps weight 2 + 3
+    assert_eq!(problem.evaluate(&[0, 1, 1]), Max(Some(5)));
+    assert!(problem.is_valid_solution(&[0, 1, 1]));
+    // The empty arc set is acyclic
+    assert_eq!(problem.evaluate(&[0, 0, 0]), Max(Some(0)));
+    // Wrong configuration length
+    assert_eq!(problem.evaluate(&[1, 1]), Max(None));
+}
+
+#[test]
+fn test_maximum_acyclic_subgraph_transitive_tournament_keeps_everything() {
+    let problem = MaximumAcyclicSubgraph::new(tournament(5, |_, _| false), vec![1i32; 10]);
+    let solution = BruteForce::new().find_witness(&problem).unwrap();
+    assert_eq!(solution, vec![1; 10]);
+    assert_eq!(problem.evaluate(&solution), Max(Some(10)));
+}
+
+#[test]
+fn test_maximum_acyclic_subgraph_cyclic_tournament() {
+    // Regular tournament on 5 vertices: i beats i+1 and i+2 (mod 5).
+    // Every vertex order disagrees with at least 3 of its 10 arcs.
+    let graph = tournament(5, |i, j| (j - i) > 2);
+    assert_eq!(graph.num_arcs(), 10);
+    let problem = MaximumAcyclicSubgraph::new(graph, vec![1i32; 10]);
+
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Max(Some(7)));
+    for witness in solver.find_all_witnesses(&problem) {
+        assert!(problem.is_valid_solution(&witness));
+        assert_eq!(witness.iter().sum::<usize>(), 7);
+    }
+}
+
+#[test]
+fn test_maximum_acyclic_subgraph_complements_feedback_arc_set() {
+    // Weighted tournaments: optimum + minimum FAS weight = total arc weight.
+    // Pairs (i, j) with i < j of 4 vertices, in tournament arc order.
+    let pair_index = |i: usize, j: usize| [0, 0, 1, 2, 0, 0, 3, 4, 0, 0, 0, 5][i * 4 + j];
+    for mask in 0..(1usize << 6) {
+        let graph = tournament(4, |i, j| (mask >> pair_index(i, j)) & 1 == 1);
+        let weights: Vec<i32> = (0..6).map(|a| 1 + ((a * 7 + mask) % 4) as i32).collect();
+        let total: i32 = weights.iter().sum();
+
+        let mas = MaximumAcyclicSubgraph::new(graph.clone(), weights.clone());
+        let fas = MinimumFeedbackArcSet::new(graph, weights);
+        let solver = BruteForce::new();
+        let kept = solver.solve(&mas).unwrap();
+        let removed = solver.solve(&fas).unwrap();
+        assert_eq!(kept + removed, total, "tournament mask {mask}");
+    }
+}
+
+#[test]
+fn test_maximum_acyclic_subgraph_serialization() {
+    let graph = DirectedGraph::new(3, vec![(0, 1), (1, 2), (2, 0)]);
+    let problem = MaximumAcyclicSubgraph::new(graph, vec![1i32, 2, 3]);
+    let json = serde_json::to_string(&problem).unwrap();
+    let restored: MaximumAcyclicSubgraph<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.weights(), &[1, 2, 3]);
+    assert_eq!(restored.evaluate(&[0, 1, 1]), Max(Some(5)));
+}
+
+#[test]
+#[should_panic(expected = "weights length must match graph num_arcs")]
+fn test_maximum_acyclic_subgraph_weights_length_mismatch() {
+    let graph = DirectedGraph::new(2, vec![(0, 1)]);
+    let _ = MaximumAcyclicSubgraph::new(graph, vec![1i32, 2]);
+}
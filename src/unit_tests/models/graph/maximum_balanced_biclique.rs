@@ -0,0 +1,134 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::BipartiteGraph;
+use crate::traits::Problem;
+
+fn issue_graph() -> BipartiteGraph {
+    // K_{3,3} on {0,1,2} x {0,1,2}; left vertex 3 misses right vertex 2
+    BipartiteGraph::new(
+        4,
+        4,
+        vec![
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 0),
+            (1, 1),
+            (1, 2),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+            (3, 0),
+            (3, 1),
+            (3, 3),
+        ],
+    )
+}
+
+#[test]
+fn test_maximum_balanced_biclique_creation() {
+    let problem = MaximumBalancedBiclique::new(issue_graph());
+
+    assert_eq!(problem.left_size(), 4);
+    assert_eq!(problem.right_size(), 4);
+    assert_eq!(problem.num_vertices(), 8);
+    assert_eq!(problem.num_edges(), 12);
+    assert_eq!(problem.dims(), vec![2; 8]);
+    assert!(problem.has_edge(3, 3));
+    assert!(!problem.has_edge(3, 2));
+}
+
+#[test]
+fn test_maximum_balanced_biclique_evaluation() {
+    let problem = MaximumBalancedBiclique::new(issue_graph());
+
+    assert_eq!(problem.evaluate(&[1, 1, 1, 0, 1, 1, 1, 0]), Max(Some(3)));
+    assert_eq!(problem.evaluate(&[0, 0, 0, 1, 0, 0, 0, 1]), Max(Some(1)));
+    assert_eq!(problem.evaluate(&[0; 8]), Max(Some(0)));
+}
+
+#[test]
+fn test_maximum_balanced_biclique_rejects_unbalanced_selection() {
+    let problem = MaximumBalancedBiclique::new(issue_graph());
+
+    // K_{3,2} is complete but not balanced
+    assert_eq!(problem.evaluate(&[1, 1, 1, 0, 1, 1, 0, 0]), Max(None));
+    assert!(!problem.is_valid_solution(&[1, 0, 0, 0, 0, 0, 0, 0]));
+}
+
+#[test]
+fn test_maximum_balanced_biclique_rejects_incomplete_selection() {
+    let problem = MaximumBalancedBiclique::new(issue_graph());
+
+    // (3, 2) is missing
+    assert_eq!(problem.evaluate(&[1, 1, 0, 1, 1, 1, 1, 0]), Max(None));
+}
+
+#[test]
+fn test_maximum_balanced_biclique_rejects_malformed_config() {
+    let problem = MaximumBalancedBiclique::new(issue_graph());
+
+    assert_eq!(problem.evaluate(&[1, 1, 1, 0, 1, 1, 1]), Max(None));
+    assert_eq!(problem.evaluate(&[2, 0, 0, 0, 1, 0, 0, 0]), Max(None));
+}
+
+#[test]
+fn test_maximum_balanced_biclique_brute_force() {
+    let problem = MaximumBalancedBiclique::new(issue_graph());
+    let solver = BruteForce::new();
+
+    assert_eq!(solver.solve(&problem), Max(Some(3)));
+    let witnesses = solver.find_all_witnesses(&problem);
+    assert_eq!(witnesses, vec![vec![1, 1, 1, 0, 1, 1, 1, 0]]);
+}
+
+#[test]
+fn test_maximum_balanced_biclique_optimum_is_not_the_largest_biclique() {
+    // A star K_{1,3} has 4 vertices but its largest balanced biclique is K_{1,1}
+    let problem =
+        MaximumBalancedBiclique::new(BipartiteGraph::new(1, 3, vec![(0, 0), (0, 1), (0, 2)]));
+    assert_eq!(BruteForce::new().solve(&problem), Max(Some(1)));
+}
+
+#[test]
+fn test_maximum_balanced_biclique_matching_and_empty_graph() {
+    // A perfect matching only contains K_{1,1}
+    let matching =
+        MaximumBalancedBiclique::new(BipartiteGraph::new(3, 3, vec![(0, 0), (1, 1), (2, 2)]));
+    assert_eq!(BruteForce::new().solve(&matching), Max(Some(1)));
+
+    let empty = MaximumBalancedBiclique::new(BipartiteGraph::new(2, 2, vec![]));
+    assert_eq!(BruteForce::new().solve(&empty), Max(Some(0)));
+}
+
+#[test]
+fn test_maximum_balanced_biclique_serialization_round_trip() {
+    let problem = MaximumBalancedBiclique::new(issue_graph());
+    let json = serde_json::to_value(&problem).unwrap();
+    assert!(json.get("edge_lookup").is_none());
+
+    let restored: MaximumBalancedBiclique = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.num_edges(), 12);
+    assert_eq!(
+        restored.evaluate(&[1, 1, 1, 0, 1, 1, 1, 0]),
+        problem.evaluate(&[1, 1, 1, 0, 1, 1, 1, 0])
+    );
+}
+
+#[test]
+fn test_maximum_balanced_biclique_matches_decision_version() {
+    use crate::models::graph::BalancedCompleteBipartiteSubgraph;
+
+    let graph = issue_graph();
+    let optimum = BruteForce::new()
+        .solve(&MaximumBalancedBiclique::new(graph.clone()))
+        .0
+        .unwrap();
+    for k in 1..=4 {
+        let decision = BalancedCompleteBipartiteSubgraph::new(graph.clone(), k);
+        assert_eq!(
+            BruteForce::new().find_witness(&decision).is_some(),
+            k <= optimum
+        );
+    }
+}
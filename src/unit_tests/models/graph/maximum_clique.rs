@@ -319,3 +319,85 @@ fn test_clique_paper_example() {
     let best = solver.find_witness(&problem).unwrap();
     assert_eq!(problem.evaluate(&best).unwrap(), 3);
 }
+
+#[test]
+fn test_maximal_cliques_complete_graph() {
+    let graph = SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    assert_eq!(maximal_cliques(&graph), vec![vec![0, 1, 2, 3]]);
+}
+
+#[test]
+fn test_maximal_cliques_five_cycle() {
+    let graph = SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    assert_eq!(
+        maximal_cliques(&graph),
+        vec![vec![0, 1], vec![0, 4], vec![1, 2], vec![2, 3], vec![3, 4]]
+    );
+}
+
+#[test]
+fn test_maximal_cliques_isolated_and_empty() {
+    let graph = SimpleGraph::new(3, vec![(0, 1)]);
+    assert_eq!(maximal_cliques(&graph), vec![vec![0, 1], vec![2]]);
+    assert_eq!(
+        maximal_cliques(&SimpleGraph::new(0, vec![])),
+        vec![Vec::<usize>::new()]
+    );
+}
+
+#[test]
+fn test_all_maximum_cliques_multiple() {
+    // Two triangles {0,1,2} and {2,3,4} sharing vertex 2, plus a pendant edge 4-5.
+    let graph = SimpleGraph::new(
+        6,
+        vec![(0, 1), (0, 2), (1, 2), (2, 3), (2, 4), (3, 4), (4, 5)],
+    );
+    assert_eq!(
+        maximal_cliques(&graph),
+        vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5]]
+    );
+    let problem = MaximumClique::new(graph.clone(), vec![One; 6]);
+    assert_eq!(
+        problem.all_maximum_cliques(),
+        vec![vec![0, 1, 2], vec![2, 3, 4]]
+    );
+
+    // Weights break the tie.
+    let problem = MaximumClique::new(graph, vec![1, 1, 1, 1, 2, 1]);
+    assert_eq!(problem.all_maximum_cliques(), vec![vec![2, 3, 4]]);
+}
+
+#[test]
+fn test_all_maximum_cliques_match_brute_force() {
+    use crate::traits::Problem;
+
+    let graph = SimpleGraph::new(
+        7,
+        vec![
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (4, 6),
+            (5, 6),
+            (2, 5),
+        ],
+    );
+    let problem = MaximumClique::new(graph, vec![One; 7]);
+    let mut expected: Vec<Vec<usize>> = BruteForce::new()
+        .find_all_witnesses(&problem)
+        .into_iter()
+        .map(|config| (0..config.len()).filter(|&v| config[v] == 1).collect())
+        .collect();
+    expected.sort();
+    let cliques = problem.all_maximum_cliques();
+    assert_eq!(cliques, expected);
+    let config: Vec<usize> = (0..7)
+        .map(|v| usize::from(cliques[0].contains(&v)))
+        .collect();
+    assert_eq!(problem.evaluate(&config), Max(Some(4)));
+}
@@ -1,5 +1,6 @@
 use super::*;
-use crate::solvers::BruteForce;
+use crate::models::graph::MinimumDominatingSet;
+use crate::solvers::{BruteForce, Solver};
 use crate::topology::SimpleGraph;
 use crate::traits::Problem;
 use crate::types::Max;
@@ -109,3 +110,55 @@ fn test_maximum_domatic_number_single_vertex() {
     let config = vec![0];
     assert_eq!(problem.evaluate(&config), Max(Some(1)));
 }
+
+#[test]
+fn test_maximum_domatic_number_complete_graphs() {
+    // In K_n every single vertex dominates, so the domatic number is n
+    for n in 1..=5 {
+        let edges = (0..n)
+            .flat_map(|u| ((u + 1)..n).map(move |v| (u, v)))
+            .collect();
+        let problem = MaximumDomaticNumber::new(SimpleGraph::new(n, edges));
+        assert_eq!(BruteForce::new().solve(&problem), Max(Some(n)));
+    }
+}
+
+#[test]
+fn test_maximum_domatic_number_stars() {
+    // K_{1,m}: every dominating set contains the center or all leaves, so at
+    // most two classes fit: {center} and the leaves
+    for leaves in 1..=4 {
+        let edges = (1..=leaves).map(|v| (0, v)).collect();
+        let problem = MaximumDomaticNumber::new(SimpleGraph::new(leaves + 1, edges));
+        assert_eq!(BruteForce::new().solve(&problem), Max(Some(2)));
+
+        let split: Vec<usize> = (0..=leaves).map(|v| usize::from(v == 0)).collect();
+        assert_eq!(problem.evaluate(&split), Max(Some(2)));
+    }
+}
+
+#[test]
+fn test_maximum_domatic_number_bounded_by_domination_number() {
+    // Every class of a domatic partition is a dominating set, so
+    // domatic number * domination number <= n
+    let graphs = [
+        SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4)]),
+        SimpleGraph::new(5, vec![(0, 1), (0, 2), (0, 3), (0, 4)]),
+        SimpleGraph::new(
+            6,
+            vec![(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3)],
+        ),
+    ];
+    for graph in graphs {
+        let n = graph.num_vertices();
+        let domatic = BruteForce::new()
+            .solve(&MaximumDomaticNumber::new(graph.clone()))
+            .0
+            .unwrap();
+        let domination = BruteForce::new()
+            .solve(&MinimumDominatingSet::new(graph, vec![1i32; n]))
+            .0
+            .unwrap();
+        assert!(domatic * domination as usize <= n);
+    }
+}
@@ -211,3 +211,16 @@ fn test_mis_paper_example() {
     let best = solver.find_witness(&problem).unwrap();
     assert_eq!(problem.evaluate(&best).unwrap(), 4);
 }
+
+#[test]
+fn test_independent_set_objective_terms() {
+    let problem = MaximumIndependentSet::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        vec![3, 1, 4, 2],
+    );
+    let terms = problem.objective_terms(&[1, 0, 0, 1]);
+    assert_eq!(terms.len(), 4);
+    assert_eq!(terms[3].label, "vertex 3");
+    assert_eq!(terms.iter().map(|t| t.value).sum::<f64>(), 5.0);
+    assert_eq!(problem.evaluate(&[1, 0, 0, 1]), Max(Some(5)));
+}
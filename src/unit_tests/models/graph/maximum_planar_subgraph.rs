@@ -0,0 +1,95 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::SimpleGraph;
+use crate::traits::Problem;
+use crate::types::{Max, One};
+
+fn k33() -> SimpleGraph {
+    SimpleGraph::new(
+        6,
+        (0..3).flat_map(|u| (3..6).map(move |v| (u, v))).collect(),
+    )
+}
+
+#[test]
+fn test_maximum_planar_subgraph_creation() {
+    let problem = MaximumPlanarSubgraph::new(SimpleGraph::cycle(4), vec![1, 2, 3, 4]);
+    assert_eq!(problem.num_vertices(), 4);
+    assert_eq!(problem.num_edges(), 4);
+    assert_eq!(problem.num_variables(), 4);
+    assert_eq!(problem.weights(), &[1, 2, 3, 4]);
+    assert_eq!(
+        <MaximumPlanarSubgraph<SimpleGraph, i32> as Problem>::NAME,
+        "MaximumPlanarSubgraph"
+    );
+}
+
+#[test]
+#[should_panic(expected = "edge_weights length must match num_edges")]
+fn test_maximum_planar_subgraph_rejects_wrong_weight_count() {
+    MaximumPlanarSubgraph::new(SimpleGraph::cycle(4), vec![1, 2]);
+}
+
+#[test]
+fn test_maximum_planar_subgraph_evaluate() {
+    let problem = MaximumPlanarSubgraph::<_, i32>::unit_weights(SimpleGraph::complete(5));
+    assert_eq!(problem.evaluate(&[1; 10]), Max(None));
+    let mut config = vec![1; 10];
+    config[3] = 0;
+    assert_eq!(problem.evaluate(&config), Max(Some(9)));
+    assert_eq!(problem.evaluate(&[0; 10]), Max(Some(0)));
+    // Wrong length or non-binary entries are infeasible.
+    assert_eq!(problem.evaluate(&[1; 9]), Max(None));
+    assert_eq!(problem.evaluate(&[2; 10]), Max(None));
+}
+
+#[test]
+fn test_maximum_planar_subgraph_k5_drops_one_edge() {
+    let problem = MaximumPlanarSubgraph::<_, i32>::unit_weights(SimpleGraph::complete(5));
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Max(Some(9)));
+    // Every edge of K5 is symmetric, so each single deletion is optimal.
+    let witnesses = solver.find_all_witnesses(&problem);
+    assert_eq!(witnesses.len(), 10);
+    for w in &witnesses {
+        assert_eq!(w.iter().filter(|&&x| x == 0).count(), 1);
+    }
+}
+
+#[test]
+fn test_maximum_planar_subgraph_k33_drops_one_edge() {
+    let problem = MaximumPlanarSubgraph::<_, One>::unit_weights(k33());
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Max(Some(8)));
+    assert_eq!(solver.find_all_witnesses(&problem).len(), 9);
+}
+
+#[test]
+fn test_maximum_planar_subgraph_planar_input_keeps_everything() {
+    let graph = SimpleGraph::complete(4);
+    let problem = MaximumPlanarSubgraph::new(graph, vec![3, 1, 4, 1, 5, 9]);
+    let witness = BruteForce::new().find_witness(&problem).unwrap();
+    assert_eq!(witness, vec![1; 6]);
+    assert_eq!(problem.evaluate(&witness), Max(Some(23)));
+}
+
+#[test]
+fn test_maximum_planar_subgraph_weights_pick_cheapest_deletion() {
+    let mut weights = vec![10; 9];
+    weights[4] = 1;
+    let problem = MaximumPlanarSubgraph::new(k33(), weights);
+    let witness = BruteForce::new().find_witness(&problem).unwrap();
+    let mut expected = vec![1; 9];
+    expected[4] = 0;
+    assert_eq!(witness, expected);
+    assert_eq!(problem.selected_edges(&witness).len(), 8);
+}
+
+#[test]
+fn test_maximum_planar_subgraph_serialization() {
+    let problem = MaximumPlanarSubgraph::new(SimpleGraph::cycle(3), vec![1, 2, 3]);
+    let json = serde_json::to_value(&problem).unwrap();
+    let restored: MaximumPlanarSubgraph<SimpleGraph, i32> = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.weights(), problem.weights());
+    assert_eq!(restored.graph().edges(), problem.graph().edges());
+}
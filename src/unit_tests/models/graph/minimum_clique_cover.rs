@@ -0,0 +1,69 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::SimpleGraph;
+use crate::traits::Problem;
+
+fn cycle(n: usize) -> SimpleGraph {
+    SimpleGraph::new(n, (0..n).map(|i| (i, (i + 1) % n)).collect())
+}
+
+#[test]
+fn test_minimum_clique_cover_creation() {
+    let problem = MinimumCliqueCover::new(cycle(5));
+    assert_eq!(problem.num_vertices(), 5);
+    assert_eq!(problem.num_edges(), 5);
+    assert_eq!(problem.dims(), vec![5; 5]);
+}
+
+#[test]
+fn test_minimum_clique_cover_evaluate() {
+    let problem = MinimumCliqueCover::new(cycle(5));
+
+    // {0,1}, {2,3}, {4}
+    assert!(problem.is_valid_clique_cover(&[0, 0, 1, 1, 2]));
+    assert_eq!(problem.evaluate(&[0, 0, 1, 1, 2]), Min(Some(3)));
+    // Group ids need not be contiguous
+    assert_eq!(problem.evaluate(&[4, 4, 0, 0, 2]), Min(Some(3)));
+    // {0, 2} is not an edge
+    assert!(!problem.is_valid_solution(&[0, 1, 0, 2, 3]));
+    assert_eq!(problem.evaluate(&[0, 1, 0, 2, 3]), Min(None));
+    // Out-of-range group id and wrong length
+    assert!(!problem.is_valid_clique_cover(&[0, 0, 1, 1, 5]));
+    assert!(!problem.is_valid_clique_cover(&[0, 0, 1, 1]));
+}
+
+#[test]
+fn test_minimum_clique_cover_c5_needs_three_cliques() {
+    let problem = MinimumCliqueCover::new(cycle(5));
+    let solver = BruteForce::new();
+
+    assert_eq!(solver.solve(&problem), Min(Some(3)));
+    let witness = solver.find_witness(&problem).unwrap();
+    assert_eq!(
+        MinimumCliqueCover::<SimpleGraph>::num_cliques_used(&witness),
+        3
+    );
+}
+
+#[test]
+fn test_minimum_clique_cover_complete_and_empty_graphs() {
+    let solver = BruteForce::new();
+
+    let complete = MinimumCliqueCover::new(SimpleGraph::new(
+        4,
+        vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)],
+    ));
+    assert_eq!(solver.solve(&complete), Min(Some(1)));
+
+    let empty = MinimumCliqueCover::new(SimpleGraph::new(3, vec![]));
+    assert_eq!(solver.solve(&empty), Min(Some(3)));
+}
+
+#[test]
+fn test_minimum_clique_cover_serialization() {
+    let problem = MinimumCliqueCover::new(cycle(4));
+    let json = serde_json::to_string(&problem).unwrap();
+    let restored: MinimumCliqueCover<SimpleGraph> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.num_edges(), 4);
+    assert_eq!(restored.evaluate(&[0, 0, 1, 1]), Min(Some(2)));
+}
@@ -0,0 +1,73 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+
+fn path4() -> SimpleGraph {
+    SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)])
+}
+
+fn cycle5() -> SimpleGraph {
+    SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)])
+}
+
+#[test]
+fn test_edge_dominating_set_basic() {
+    let problem = MinimumEdgeDominatingSet::new(path4(), vec![1i32; 3]);
+    assert_eq!(problem.dims(), vec![2; 3]);
+    assert_eq!(problem.num_vertices(), 4);
+    assert_eq!(problem.num_edges(), 3);
+
+    assert_eq!(problem.evaluate(&[0, 1, 0]), Min(Some(1)));
+    assert_eq!(problem.evaluate(&[1, 0, 1]), Min(Some(2)));
+    // Edge (2,3) is not touched by (0,1).
+    assert_eq!(problem.evaluate(&[1, 0, 0]), Min(None));
+    assert_eq!(problem.evaluate(&[0, 0, 0]), Min(None));
+    assert_eq!(problem.evaluate(&[0, 1]), Min(None));
+    assert_eq!(problem.evaluate(&[0, 2, 0]), Min(None));
+}
+
+#[test]
+fn test_edge_dominating_set_p4() {
+    let problem = MinimumEdgeDominatingSet::new(path4(), vec![1i32; 3]);
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Min(Some(1)));
+    assert_eq!(solver.find_all_witnesses(&problem), vec![vec![0, 1, 0]]);
+}
+
+#[test]
+fn test_edge_dominating_set_c5() {
+    let problem = MinimumEdgeDominatingSet::new(cycle5(), vec![1i32; 5]);
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Min(Some(2)));
+    for witness in solver.find_all_witnesses(&problem) {
+        assert!(problem.is_valid_solution(&witness));
+        assert_eq!(witness.iter().sum::<usize>(), 2);
+    }
+}
+
+#[test]
+fn test_edge_dominating_set_weighted() {
+    // A heavy middle edge makes the two outer edges cheaper.
+    let problem = MinimumEdgeDominatingSet::new(path4(), vec![1, 5, 1]);
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Min(Some(2)));
+    assert_eq!(solver.find_witness(&problem), Some(vec![1, 0, 1]));
+}
+
+#[test]
+fn test_edge_dominating_set_edgeless() {
+    let problem = MinimumEdgeDominatingSet::<_, i32>::new(SimpleGraph::new(3, vec![]), vec![]);
+    assert_eq!(problem.evaluate(&[]), Min(Some(0)));
+}
+
+#[test]
+fn test_edge_dominating_set_serialization() {
+    let problem = MinimumEdgeDominatingSet::new(cycle5(), vec![1, 2, 3, 4, 5]);
+    let json = serde_json::to_string(&problem).unwrap();
+    let restored: MinimumEdgeDominatingSet<SimpleGraph, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.edge_weights(), &[1, 2, 3, 4, 5]);
+    assert_eq!(
+        restored.evaluate(&[1, 0, 1, 0, 0]),
+        problem.evaluate(&[1, 0, 1, 0, 0])
+    );
+}
@@ -162,3 +162,27 @@ fn test_minimumgraphbandwidth_permutation_matters() {
     // Edges: (0,1): |1-0|=1, (0,2): |1-2|=1, (0,3): |1-3|=2 → max = 2
     assert_eq!(problem.evaluate(&[1, 0, 2, 3]), Min(Some(2)));
 }
+
+#[test]
+fn test_minimumgraphbandwidth_star_k14() {
+    // K1,4: the center has four neighbors, so two of them sit at distance 2.
+    let graph = SimpleGraph::new(5, vec![(0, 1), (0, 2), (0, 3), (0, 4)]);
+    let problem = MinimumGraphBandwidth::new(graph);
+
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Min(Some(2)));
+    for witness in solver.find_all_witnesses(&problem) {
+        assert!(problem.is_valid_solution(&witness));
+        // Optimal arrangements put the center in the middle.
+        assert_eq!(witness[0], 2);
+    }
+}
+
+#[test]
+fn test_minimumgraphbandwidth_is_valid_solution() {
+    let problem = path_example();
+    assert!(problem.is_valid_solution(&[0, 1, 2, 3]));
+    assert!(problem.is_valid_solution(&[3, 1, 0, 2]));
+    assert!(!problem.is_valid_solution(&[0, 1, 1, 3]));
+    assert!(!problem.is_valid_solution(&[0, 1, 2]));
+}
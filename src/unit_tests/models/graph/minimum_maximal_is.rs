@@ -0,0 +1,105 @@
+use super::*;
+use crate::models::graph::{MaximumIndependentSet, MinimumDominatingSet};
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::SimpleGraph;
+use crate::traits::Problem;
+
+#[test]
+fn test_minimum_maximal_is_creation() {
+    let problem = MinimumMaximalIS::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        vec![1i32; 4],
+    );
+    assert_eq!(problem.num_vertices(), 4);
+    assert_eq!(problem.num_edges(), 3);
+    assert_eq!(problem.dims(), vec![2; 4]);
+    assert_eq!(problem.weights(), &[1, 1, 1, 1]);
+    assert!(problem.is_weighted());
+}
+
+#[test]
+fn test_minimum_maximal_is_feasibility() {
+    let problem = MinimumMaximalIS::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        vec![1i32; 4],
+    );
+
+    assert!(problem.is_minimal_maximal_is(&[1, 0, 1, 0]));
+    assert!(problem.is_minimal_maximal_is(&[1, 0, 0, 1]));
+    // Not independent
+    assert!(!problem.is_minimal_maximal_is(&[1, 1, 0, 0]));
+    // Not maximal: vertex 3 can be added
+    assert!(!problem.is_minimal_maximal_is(&[1, 0, 0, 0]));
+    assert!(!problem.is_minimal_maximal_is(&[0, 0, 0, 0]));
+
+    assert_eq!(problem.evaluate(&[1, 0, 1, 0]), Min(Some(2)));
+    assert_eq!(problem.evaluate(&[1, 1, 0, 0]), Min(None));
+    assert!(problem.is_valid_solution(&[0, 1, 0, 1]));
+}
+
+#[test]
+fn test_minimum_maximal_is_path_differs_from_mis_and_mds() {
+    // Path 0-1-2-3 with weights (2, 1, 1, 2).
+    let graph = SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]);
+    let weights = vec![2i32, 1, 1, 2];
+    let solver = BruteForce::new();
+
+    let problem = MinimumMaximalIS::new(graph.clone(), weights.clone());
+    assert_eq!(solver.solve(&problem), Min(Some(3)));
+    let mut witnesses = solver.find_all_witnesses(&problem);
+    witnesses.sort();
+    assert_eq!(witnesses, vec![vec![0, 1, 0, 1], vec![1, 0, 1, 0]]);
+
+    // The maximum weight IS takes both heavy endpoints.
+    let mis = MaximumIndependentSet::new(graph.clone(), weights.clone());
+    assert_eq!(solver.solve(&mis).unwrap(), 4);
+    assert_eq!(solver.find_all_witnesses(&mis), vec![vec![1, 0, 0, 1]]);
+
+    // The minimum dominating set {1, 2} is cheaper but not independent.
+    let mds = MinimumDominatingSet::new(graph, weights);
+    assert_eq!(solver.solve(&mds).unwrap(), 2);
+    assert_eq!(solver.find_all_witnesses(&mds), vec![vec![0, 1, 1, 0]]);
+}
+
+#[test]
+fn test_minimum_maximal_is_star() {
+    // Star with center 0 and leaves 1..=4: the only maximal independent sets
+    // are {0} and the set of all leaves.
+    let graph = SimpleGraph::new(5, vec![(0, 1), (0, 2), (0, 3), (0, 4)]);
+    let solver = BruteForce::new();
+
+    let problem = MinimumMaximalIS::new(graph.clone(), vec![1i32; 5]);
+    assert_eq!(solver.solve(&problem), Min(Some(1)));
+    assert_eq!(
+        solver.find_all_witnesses(&problem),
+        vec![vec![1, 0, 0, 0, 0]]
+    );
+
+    let mis = MaximumIndependentSet::new(graph.clone(), vec![1i32; 5]);
+    assert_eq!(solver.solve(&mis).unwrap(), 4);
+
+    // A heavy center makes the leaves the cheaper maximal independent set.
+    let heavy = MinimumMaximalIS::new(graph.clone(), vec![5, 1, 1, 1, 1]);
+    assert_eq!(solver.solve(&heavy), Min(Some(4)));
+    assert_eq!(solver.find_all_witnesses(&heavy), vec![vec![0, 1, 1, 1, 1]]);
+
+    // Every dominating set of a star contains the center or all leaves, so
+    // here the two minimization problems agree in value.
+    let mds = MinimumDominatingSet::new(graph, vec![5, 1, 1, 1, 1]);
+    assert_eq!(solver.solve(&mds).unwrap(), 4);
+}
+
+#[test]
+fn test_minimum_maximal_is_serialization() {
+    let problem = MinimumMaximalIS::new(SimpleGraph::new(3, vec![(0, 1), (1, 2)]), vec![1, 2, 3]);
+    let json = serde_json::to_string(&problem).unwrap();
+    let restored: MinimumMaximalIS<SimpleGraph, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.weights(), problem.weights());
+    assert_eq!(restored.evaluate(&[0, 1, 0]), Min(Some(2)));
+}
+
+#[test]
+#[should_panic(expected = "weights length must match")]
+fn test_minimum_maximal_is_wrong_weights_len() {
+    MinimumMaximalIS::new(SimpleGraph::new(3, vec![(0, 1)]), vec![1i32; 2]);
+}
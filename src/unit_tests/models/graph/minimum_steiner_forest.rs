@@ -0,0 +1,111 @@
+use super::*;
+use crate::{solvers::BruteForce, topology::SimpleGraph, traits::Problem};
+
+/// Two routes between the pairs: the cheap subpaths 0-1-2 and 3-4-5, or the
+/// expensive rungs (0,3), (2,5) and the cross edge (1,4).
+/// Edges in order: (0,1)=1, (1,2)=1, (0,3)=4, (2,5)=4, (3,4)=1, (4,5)=1, (1,4)=3
+fn example_instance() -> MinimumSteinerForest<SimpleGraph, i32> {
+    let graph = SimpleGraph::new(
+        6,
+        vec![(0, 1), (1, 2), (0, 3), (2, 5), (3, 4), (4, 5), (1, 4)],
+    );
+    MinimumSteinerForest::new(graph, vec![1, 1, 4, 4, 1, 1, 3], vec![(0, 2), (3, 5)])
+}
+
+#[test]
+fn test_minimum_steiner_forest_creation() {
+    let problem = example_instance();
+    assert_eq!(problem.num_vertices(), 6);
+    assert_eq!(problem.num_edges(), 7);
+    assert_eq!(problem.num_terminal_pairs(), 2);
+    assert_eq!(problem.terminal_pairs(), &[(0, 2), (3, 5)]);
+    assert_eq!(problem.dims(), vec![2; 7]);
+}
+
+#[test]
+fn test_minimum_steiner_forest_evaluate() {
+    let problem = example_instance();
+    // Two disjoint subpaths 0-1-2 and 3-4-5.
+    assert_eq!(problem.evaluate(&[1, 1, 0, 0, 1, 1, 0]), Min(Some(4)));
+    // A single tree through the cross edge (1, 4) also connects both pairs.
+    assert_eq!(problem.evaluate(&[1, 1, 0, 0, 1, 1, 1]), Min(Some(7)));
+    // Adding (0, 3) closes the cycle 0-1-4-3-0.
+    assert_eq!(problem.evaluate(&[1, 1, 1, 0, 1, 1, 1]), Min(None));
+}
+
+#[test]
+fn test_minimum_steiner_forest_evaluate_invalid() {
+    let problem = example_instance();
+    // Pair (3, 5) left disconnected.
+    assert_eq!(problem.evaluate(&[1, 1, 0, 0, 1, 0, 0]), Min(None));
+    // Empty selection.
+    assert_eq!(problem.evaluate(&[0; 7]), Min(None));
+    // Wrong config length.
+    assert!(!problem.is_valid_solution(&[1, 1, 0]));
+}
+
+#[test]
+fn test_minimum_steiner_forest_disjoint_subpaths_on_path() {
+    // Path 0-1-2-3-4-5 with demands (0, 2) and (3, 5): the optimal forest is
+    // the two disjoint subpaths, leaving the middle edge (2, 3) unused.
+    let problem =
+        MinimumSteinerForest::<_, i32>::unit_weights(SimpleGraph::path(6), vec![(0, 2), (3, 5)]);
+    let solutions = BruteForce::new().find_all_witnesses(&problem);
+    assert_eq!(solutions, vec![vec![1, 1, 0, 1, 1]]);
+    assert_eq!(problem.evaluate(&solutions[0]), Min(Some(4)));
+}
+
+#[test]
+fn test_minimum_steiner_forest_brute_force() {
+    let problem = example_instance();
+    let solutions = BruteForce::new().find_all_witnesses(&problem);
+    assert_eq!(solutions, vec![vec![1, 1, 0, 0, 1, 1, 0]]);
+}
+
+#[test]
+fn test_minimum_steiner_forest_is_weighted() {
+    let problem = example_instance();
+    assert!(problem.is_weighted());
+
+    use crate::types::One;
+    let unweighted: MinimumSteinerForest<SimpleGraph, One> =
+        MinimumSteinerForest::unit_weights(SimpleGraph::path(3), vec![(0, 2)]);
+    assert!(!unweighted.is_weighted());
+}
+
+#[test]
+fn test_minimum_steiner_forest_set_weights() {
+    let mut problem = example_instance();
+    assert_eq!(problem.weights(), vec![1, 1, 4, 4, 1, 1, 3]);
+    problem.set_weights(vec![1; 7]);
+    assert_eq!(problem.edge_weights(), &[1; 7]);
+    assert_eq!(problem.evaluate(&[1, 1, 0, 0, 1, 1, 0]), Min(Some(4)));
+}
+
+#[test]
+fn test_minimum_steiner_forest_serialization() {
+    let problem = example_instance();
+    let json = serde_json::to_value(&problem).unwrap();
+    let deserialized: MinimumSteinerForest<SimpleGraph, i32> =
+        serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized.num_edges(), 7);
+    assert_eq!(deserialized.terminal_pairs(), &[(0, 2), (3, 5)]);
+}
+
+#[test]
+#[should_panic(expected = "at least 1 terminal pair required")]
+fn test_minimum_steiner_forest_rejects_no_pairs() {
+    let _ = MinimumSteinerForest::new(SimpleGraph::path(3), vec![1, 1], vec![]);
+}
+
+#[test]
+#[should_panic(expected = "must have distinct endpoints")]
+fn test_minimum_steiner_forest_rejects_degenerate_pair() {
+    let _ = MinimumSteinerForest::new(SimpleGraph::path(3), vec![1, 1], vec![(1, 1)]);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_minimum_steiner_forest_rejects_out_of_range_pair() {
+    let _ = MinimumSteinerForest::new(SimpleGraph::path(3), vec![1, 1], vec![(0, 5)]);
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::solvers::BruteForce;
+use crate::solvers::{BruteForce, Solver};
 use crate::topology::SimpleGraph;
 use crate::traits::Problem;
 include!("../../jl_helpers.rs");
@@ -169,3 +169,107 @@ fn test_mvc_paper_example() {
     let best = solver.find_witness(&problem).unwrap();
     assert_eq!(problem.evaluate(&best).unwrap(), 3);
 }
+
+#[test]
+fn test_vertex_cover_objective_terms() {
+    let problem = MinimumVertexCover::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        vec![3, 1, 4, 2],
+    );
+    let terms = problem.objective_terms(&[0, 1, 0, 1]);
+    let values: Vec<f64> = terms.iter().map(|t| t.value).collect();
+    assert_eq!(values, vec![0.0, 1.0, 0.0, 2.0]);
+    assert_eq!(problem.evaluate(&[0, 1, 0, 1]), Min(Some(3)));
+}
+
+/// The Fano plane: 7 points, 7 lines of 3 points, any two lines meet.
+fn fano_plane() -> HyperGraph {
+    HyperGraph::new(
+        7,
+        vec![
+            vec![0, 1, 2],
+            vec![0, 3, 4],
+            vec![0, 5, 6],
+            vec![1, 3, 5],
+            vec![1, 4, 6],
+            vec![2, 3, 6],
+            vec![2, 4, 5],
+        ],
+    )
+}
+
+#[test]
+fn test_hypergraph_vertex_cover_fano_plane() {
+    let problem = MinimumVertexCover::new(fano_plane(), vec![1i32; 7]);
+    assert_eq!(problem.num_vertices(), 7);
+    assert_eq!(problem.num_edges(), 7);
+    assert_eq!(problem.rank(), 3);
+    assert_eq!(problem.dims(), vec![2; 7]);
+
+    // Every line meets every other line, so each line is a cover; two points
+    // cover at most 5 lines, and three non-collinear points only 6.
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Min(Some(3)));
+    let mut witnesses = solver.find_all_witnesses(&problem);
+    witnesses.sort();
+    let mut lines: Vec<Vec<usize>> = fano_plane()
+        .edges()
+        .iter()
+        .map(|line| (0..7).map(|v| usize::from(line.contains(&v))).collect())
+        .collect();
+    lines.sort();
+    assert_eq!(witnesses, lines);
+}
+
+#[test]
+fn test_hypergraph_vertex_cover_evaluation() {
+    let problem = MinimumVertexCover::new(fano_plane(), vec![1, 2, 3, 4, 5, 6, 7]);
+    // Line {0, 1, 2}
+    assert_eq!(problem.evaluate(&[1, 1, 1, 0, 0, 0, 0]), Min(Some(6)));
+    assert!(problem.is_valid_solution(&[1, 1, 1, 0, 0, 0, 0]));
+    // Non-collinear {0, 1, 3} misses line {2, 4, 5}
+    assert_eq!(problem.evaluate(&[1, 1, 0, 1, 0, 0, 0]), Min(None));
+    assert!(!problem.is_valid_solution(&[1, 1, 0, 1, 0, 0, 0]));
+    assert_eq!(BruteForce::new().solve(&problem), Min(Some(6)));
+
+    let terms = problem.objective_terms(&[0, 0, 1, 1, 0, 0, 0]);
+    let values: Vec<f64> = terms.iter().map(|t| t.value).collect();
+    assert_eq!(values, vec![0.0, 0.0, 3.0, 4.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_is_hypergraph_vertex_cover_function() {
+    let graph = fano_plane();
+    assert!(is_hypergraph_vertex_cover(
+        &graph,
+        &[true, true, true, false, false, false, false]
+    ));
+    assert!(!is_hypergraph_vertex_cover(
+        &graph,
+        &[true, true, false, true, false, false, false]
+    ));
+    // Rank-2 hypergraphs behave like graphs
+    let path = HyperGraph::new(3, vec![vec![0, 1], vec![1, 2]]);
+    assert!(is_hypergraph_vertex_cover(&path, &[false, true, false]));
+    assert!(!is_hypergraph_vertex_cover(&path, &[true, false, false]));
+}
+
+#[test]
+fn test_hypergraph_vertex_cover_variant_and_serialization() {
+    assert_eq!(
+        MinimumVertexCover::<HyperGraph, i32>::variant(),
+        vec![("graph", "HyperGraph"), ("weight", "i32")]
+    );
+    let problem = MinimumVertexCover::new(fano_plane(), vec![1i32; 7]);
+    let json = serde_json::to_value(&problem).unwrap();
+    assert_eq!(json["graph"]["edges"][6], serde_json::json!([2, 4, 5]));
+    let restored: MinimumVertexCover<HyperGraph, i32> = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.graph(), problem.graph());
+    assert_eq!(restored.weights(), problem.weights());
+}
+
+#[test]
+fn test_hypergraph_vertex_cover_empty_edge_is_infeasible() {
+    let problem = MinimumVertexCover::new(HyperGraph::new(2, vec![vec![]]), vec![1i32; 2]);
+    assert_eq!(BruteForce::new().solve(&problem), Min(None));
+}
@@ -0,0 +1,102 @@
+use super::*;
+use crate::models::graph::SpinGlass;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::SimpleGraph;
+use crate::traits::Problem;
+use crate::types::Min;
+
+#[test]
+fn test_poly_spin_glass_creation() {
+    let problem = PolySpinGlass::<i32>::new(
+        4,
+        vec![(vec![0, 1, 2], 1), (vec![2, 3], -1)],
+        vec![1, 0, 0, 0],
+    );
+    assert_eq!(problem.num_spins(), 4);
+    assert_eq!(problem.num_interactions(), 2);
+    assert_eq!(problem.max_order(), 3);
+    assert_eq!(problem.couplings(), &[1, -1]);
+    assert_eq!(problem.fields(), &[1, 0, 0, 0]);
+    assert_eq!(
+        problem.interactions(),
+        vec![(vec![0, 1, 2], 1), (vec![2, 3], -1)]
+    );
+    assert_eq!(problem.dims(), vec![2; 4]);
+    assert_eq!(
+        <PolySpinGlass<i32> as Problem>::variant(),
+        vec![("weight", "i32")]
+    );
+}
+
+#[test]
+fn test_poly_spin_glass_three_body_energy() {
+    let problem = PolySpinGlass::<i32>::without_fields(3, vec![(vec![0, 1, 2], 2)]);
+    // All up: product +1.
+    assert_eq!(problem.evaluate(&[1, 1, 1]), Min(Some(2)));
+    // One spin down: product -1.
+    assert_eq!(problem.evaluate(&[0, 1, 1]), Min(Some(-2)));
+    // Two spins down: product +1.
+    assert_eq!(problem.evaluate(&[0, 0, 1]), Min(Some(2)));
+    // All down: product -1.
+    assert_eq!(problem.evaluate(&[0, 0, 0]), Min(Some(-2)));
+}
+
+#[test]
+fn test_poly_spin_glass_ground_state_flips_with_coupling_sign() {
+    // A weak field pulls every spin up. With J < 0 the 3-body term agrees and
+    // all spins up is the unique ground state; with J > 0 it demands an odd
+    // number of down spins, so the ground states flip exactly one spin.
+    let solver = BruteForce::new();
+
+    let ferro = PolySpinGlass::<i32>::new(3, vec![(vec![0, 1, 2], -5)], vec![-1, -1, -1]);
+    assert_eq!(solver.find_all_witnesses(&ferro), vec![vec![1, 1, 1]]);
+    assert_eq!(solver.solve(&ferro), Min(Some(-8)));
+
+    let anti = PolySpinGlass::<i32>::new(3, vec![(vec![0, 1, 2], 5)], vec![-1, -1, -1]);
+    let mut ground = solver.find_all_witnesses(&anti);
+    ground.sort();
+    assert_eq!(ground, vec![vec![0, 1, 1], vec![1, 0, 1], vec![1, 1, 0]]);
+    assert_eq!(solver.solve(&anti), Min(Some(-6)));
+    assert!(!ground.contains(&vec![1, 1, 1]));
+}
+
+#[test]
+fn test_poly_spin_glass_matches_pairwise_spin_glass() {
+    let interactions = vec![((0, 1), 1), ((1, 2), -2), ((0, 2), 3)];
+    let fields = vec![1, 0, -1];
+    let pairwise = SpinGlass::<SimpleGraph, i32>::new(3, interactions.clone(), fields.clone());
+    let poly = PolySpinGlass::<i32>::new(
+        3,
+        interactions
+            .into_iter()
+            .map(|((i, j), w)| (vec![i, j], w))
+            .collect(),
+        fields,
+    );
+    for config in crate::config::DimsIterator::new(vec![2; 3]) {
+        assert_eq!(poly.evaluate(&config), pairwise.evaluate(&config));
+    }
+}
+
+#[test]
+fn test_poly_spin_glass_f64() {
+    let problem = PolySpinGlass::<f64>::without_fields(2, vec![(vec![0, 1], 1.5), (vec![], 0.5)]);
+    // The empty term is a constant offset.
+    assert_eq!(problem.evaluate(&[0, 1]), Min(Some(-1.0)));
+    assert_eq!(BruteForce::new().solve(&problem), Min(Some(-1.0)));
+}
+
+#[test]
+#[should_panic(expected = "couplings length must match num_edges")]
+fn test_poly_spin_glass_rejects_coupling_mismatch() {
+    PolySpinGlass::<i32>::from_hypergraph(HyperGraph::new(2, vec![vec![0, 1]]), vec![], vec![0, 0]);
+}
+
+#[test]
+fn test_poly_spin_glass_serialization() {
+    let problem = PolySpinGlass::<i32>::new(3, vec![(vec![0, 1, 2], -1)], vec![0, 1, 0]);
+    let json = serde_json::to_value(&problem).unwrap();
+    let restored: PolySpinGlass<i32> = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.interactions(), problem.interactions());
+    assert_eq!(restored.fields(), problem.fields());
+}
@@ -169,3 +169,64 @@ fn test_spinglass_paper_example() {
     assert!(!all_best.is_empty());
     assert_eq!(problem.evaluate(&all_best[0]).unwrap(), -3);
 }
+
+/// Sparse 100-spin model: a ring of couplings plus a few long-range ones.
+fn sparse_terms() -> Vec<(usize, usize, i32)> {
+    let mut terms: Vec<(usize, usize, i32)> = (0..100)
+        .map(|i| (i, (i + 1) % 100, if i % 2 == 0 { 1 } else { -1 }))
+        .collect();
+    terms.extend([(3, 70, 2), (70, 3, 1), (10, 55, -4), (20, 80, 0)]);
+    terms
+}
+
+#[test]
+fn test_spin_glass_from_correlations_sparse_structure() {
+    let fields: Vec<i32> = (0..100).map(|i| if i % 10 == 0 { 1 } else { 0 }).collect();
+    let problem = SpinGlass::<SimpleGraph, i32>::from_correlations(&sparse_terms(), &fields);
+
+    assert_eq!(problem.num_spins(), 100);
+    // 100 ring edges + (3, 70) merged + (10, 55); the zero (20, 80) is dropped.
+    assert_eq!(problem.num_interactions(), 102);
+    let interactions: std::collections::HashMap<(usize, usize), i32> =
+        problem.interactions().into_iter().collect();
+    assert_eq!(interactions[&(3, 70)], 3);
+    assert_eq!(interactions[&(10, 55)], -4);
+    assert_eq!(interactions[&(0, 99)], -1);
+    assert!(!interactions.contains_key(&(20, 80)));
+    assert_eq!(problem.fields(), fields.as_slice());
+}
+
+#[test]
+fn test_spin_glass_from_correlations_energies() {
+    let terms = sparse_terms();
+    let fields: Vec<i32> = (0..100).map(|i| i % 3 - 1).collect();
+    let problem = SpinGlass::<SimpleGraph, i32>::from_correlations(&terms, &fields);
+
+    let configs: Vec<Vec<usize>> = vec![
+        vec![0; 100],
+        vec![1; 100],
+        (0..100).map(|i| i % 2).collect(),
+        (0..100).map(|i| usize::from(i % 7 < 3)).collect(),
+    ];
+    for config in configs {
+        let spins = SpinGlass::<SimpleGraph, i32>::config_to_spins(&config);
+        let expected: i32 = terms
+            .iter()
+            .map(|&(i, j, w)| w * spins[i] * spins[j])
+            .sum::<i32>()
+            + fields.iter().zip(&spins).map(|(h, s)| h * s).sum::<i32>();
+        assert_eq!(problem.compute_energy(&spins), expected);
+    }
+}
+
+#[test]
+#[should_panic(expected = "references spin")]
+fn test_spin_glass_from_correlations_rejects_out_of_range() {
+    SpinGlass::<SimpleGraph, f64>::from_correlations(&[(0, 3, 1.0)], &[0.0; 3]);
+}
+
+#[test]
+#[should_panic(expected = "couples a spin to itself")]
+fn test_spin_glass_from_correlations_rejects_self_coupling() {
+    SpinGlass::<SimpleGraph, f64>::from_correlations(&[(1, 1, 1.0)], &[0.0; 3]);
+}
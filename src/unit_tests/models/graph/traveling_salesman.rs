@@ -1,6 +1,6 @@
 use super::*;
-use crate::solvers::BruteForce;
-use crate::topology::SimpleGraph;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::{DirectedGraph, SimpleGraph};
 use crate::traits::Problem;
 use crate::types::Min;
 
@@ -255,3 +255,73 @@ fn test_tsp_paper_example() {
     let best = solver.find_witness(&problem).unwrap();
     assert_eq!(problem.evaluate(&best), Min(Some(6)));
 }
+
+fn asymmetric_3city() -> TravelingSalesman<DirectedGraph, i32> {
+    // 0 -> 1 -> 2 -> 0 costs 1 + 2 + 3 = 6; 0 -> 2 -> 1 -> 0 costs 10 + 20 + 30 = 60
+    TravelingSalesman::from_matrix(vec![vec![0, 1, 10], vec![30, 0, 2], vec![3, 20, 0]])
+}
+
+#[test]
+fn test_from_matrix_arc_layout() {
+    let problem = asymmetric_3city();
+    assert_eq!(problem.num_vertices(), 3);
+    assert_eq!(problem.num_arcs(), 6);
+    assert_eq!(problem.dims(), vec![2; 6]);
+    assert_eq!(
+        problem.digraph().arcs(),
+        vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)]
+    );
+    assert_eq!(problem.arc_weights(), &[1, 10, 30, 2, 3, 20]);
+}
+
+#[test]
+fn test_asymmetric_tour_cost_depends_on_direction() {
+    let problem = asymmetric_3city();
+    assert_eq!(problem.tour_cost(&[0, 1, 2]), Some(6));
+    assert_eq!(problem.tour_cost(&[0, 2, 1]), Some(60));
+    // Rotations of the same directed tour cost the same
+    assert_eq!(problem.tour_cost(&[1, 2, 0]), Some(6));
+    // Not a permutation
+    assert_eq!(problem.tour_cost(&[0, 1, 1]), None);
+    assert_eq!(problem.tour_cost(&[0, 1]), None);
+}
+
+#[test]
+fn test_asymmetric_evaluate() {
+    let problem = asymmetric_3city();
+    // Arcs: 0=(0,1), 1=(0,2), 2=(1,0), 3=(1,2), 4=(2,0), 5=(2,1)
+    assert_eq!(problem.evaluate(&[1, 0, 0, 1, 1, 0]), Min(Some(6)));
+    assert_eq!(problem.evaluate(&[0, 1, 1, 0, 0, 1]), Min(Some(60)));
+    // Both directions at once: out-degree 2 everywhere
+    assert_eq!(problem.evaluate(&[1, 1, 1, 1, 1, 1]), Min(None));
+    // 2-cycle 0 <-> 1 leaves city 2 unvisited
+    assert_eq!(problem.evaluate(&[1, 0, 1, 0, 0, 0]), Min(None));
+}
+
+#[test]
+fn test_asymmetric_brute_force_picks_cheaper_direction() {
+    let problem = asymmetric_3city();
+    let solver = BruteForce::new();
+    let witnesses = solver.find_all_witnesses(&problem);
+    assert_eq!(witnesses, vec![vec![1, 0, 0, 1, 1, 0]]);
+    assert_eq!(solver.solve(&problem), Min(Some(6)));
+}
+
+#[test]
+fn test_asymmetric_subtours_rejected() {
+    // Two directed 2-cycles (0 <-> 1, 2 <-> 3) have in/out degree 1 but are not a tour
+    let problem = TravelingSalesman::from_matrix(vec![vec![1i32; 4]; 4]);
+    let arcs = problem.digraph().arcs();
+    let config: Vec<usize> = arcs
+        .iter()
+        .map(|&(u, v)| usize::from(u / 2 == v / 2))
+        .collect();
+    assert!(!problem.is_valid_tour(&config));
+    assert_eq!(problem.evaluate(&config), Min(None));
+}
+
+#[test]
+fn test_asymmetric_variant() {
+    let variant = TravelingSalesman::<DirectedGraph, i32>::variant();
+    assert!(variant.contains(&("graph", "DirectedGraph")));
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::solvers::BruteForce;
+use crate::solvers::{BruteForce, Solver};
 use crate::traits::Problem;
 include!("../../jl_helpers.rs");
 
@@ -69,7 +69,11 @@ fn test_jl_parity_evaluation() {
             let config = jl_parse_config(&eval["config"]);
             let result = problem.evaluate(&config);
             let jl_valid = eval["is_valid"].as_bool().unwrap();
-            if jl_valid {
+            // Unlike the Julia reference, a factor below 2 is infeasible.
+            let (a, b) = problem.read_factors(&config);
+            if a < 2 || b < 2 {
+                assert_eq!(result, Min(None));
+            } else if jl_valid {
                 assert_eq!(
                     result.unwrap(),
                     0,
@@ -84,7 +88,10 @@ fn test_jl_parity_evaluation() {
             }
         }
         let best = BruteForce::new().find_all_witnesses(&problem);
-        let jl_best = jl_parse_configs_set(&instance["best_solutions"]);
+        let jl_best: HashSet<Vec<usize>> = jl_parse_configs_set(&instance["best_solutions"])
+            .into_iter()
+            .filter(|config| problem.is_nontrivial_factorization(config))
+            .collect();
         let rust_best: HashSet<Vec<usize>> = best.into_iter().collect();
         assert_eq!(rust_best, jl_best, "Factoring best solutions mismatch");
     }
@@ -120,3 +127,128 @@ fn test_factoring_paper_example() {
     assert_eq!(b, 5);
     assert!(problem.is_valid_solution(&config));
 }
+
+#[test]
+fn test_factoring_balanced_bits_3233() {
+    // RSA-3233 = 53 × 61, both exactly 6 bits.
+    let problem = Factoring::with_factor_bits(6, 6, 3233);
+    assert_eq!(problem.p_bits(), Some(6));
+    assert_eq!(problem.q_bits(), Some(6));
+    assert_eq!(problem.fixed_bits(), vec![(5, 1), (11, 1)]);
+
+    let solutions = BruteForce::new().find_all_witnesses(&problem);
+    let factors: HashSet<(u64, u64)> = solutions
+        .iter()
+        .map(|config| problem.read_factors(config))
+        .collect();
+    assert_eq!(factors, HashSet::from([(53, 61), (61, 53)]));
+    for solution in &solutions {
+        assert!(problem.is_valid_factorization(solution));
+    }
+}
+
+#[test]
+fn test_factoring_bit_widths_reject_trivial_factor() {
+    // With a 12-bit budget for q, 1 × 3233 is representable.
+    let mut config = int_to_bits(1, 6);
+    config.extend(int_to_bits(3233, 12));
+
+    let unconstrained = Factoring::new(6, 12, 3233);
+    assert!(unconstrained.is_valid_factorization(&config));
+
+    let constrained = Factoring::try_new(6, 12, 3233, Some(6), Some(6)).unwrap();
+    assert!(!constrained.satisfies_bit_widths(&config));
+    assert!(!constrained.is_valid_factorization(&config));
+    assert_eq!(constrained.evaluate(&config), Min(None));
+
+    let mut balanced = int_to_bits(53, 6);
+    balanced.extend(int_to_bits(61, 12));
+    assert!(constrained.is_valid_factorization(&balanced));
+    assert_eq!(constrained.evaluate(&balanced), Min(Some(0)));
+}
+
+#[test]
+fn test_factoring_bit_width_validation_and_serde() {
+    assert!(Factoring::try_new(3, 3, 15, Some(4), None).is_err());
+    assert!(Factoring::try_new(3, 3, 15, None, Some(0)).is_err());
+
+    // Unconstrained instances keep their original JSON shape.
+    let json = serde_json::to_value(Factoring::new(2, 3, 15)).unwrap();
+    assert_eq!(json, serde_json::json!({"m": 2, "n": 3, "target": 15}));
+    let restored: Factoring = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.p_bits(), None);
+
+    let json = serde_json::to_value(Factoring::with_factor_bits(6, 6, 3233)).unwrap();
+    let restored: Factoring = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.q_bits(), Some(6));
+    assert!(serde_json::from_value::<Factoring>(serde_json::json!({
+        "m": 2, "n": 2, "target": 6, "p_bits": 3
+    }))
+    .is_err());
+}
+
+#[test]
+fn test_factoring_prime_target_has_no_valid_solution() {
+    // 13 is prime: only 1 × 13 and 13 × 1 reach distance 0.
+    let problem = Factoring::new(4, 4, 13);
+    assert!(!problem.is_semiprime());
+
+    let mut trivial = int_to_bits(1, 4);
+    trivial.extend(int_to_bits(13, 4));
+    assert!(problem.is_valid_factorization(&trivial));
+    assert_eq!(problem.evaluate(&trivial), Min(None));
+    assert!(!problem.is_valid_solution(&trivial));
+
+    let witnesses = BruteForce::new().find_all_witnesses(&problem);
+    assert!(!witnesses.is_empty());
+    assert!(witnesses.iter().all(|w| !problem.is_valid_solution(w)));
+}
+
+#[test]
+fn test_factoring_prime_target_solver_reports_no_exact_factorization() {
+    // The closest nontrivial products to 13 are 2 × 6 = 12 and 2 × 7 = 14.
+    let problem = Factoring::new(4, 4, 13);
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Min(Some(1)));
+    for witness in solver.find_all_witnesses(&problem) {
+        let (a, b) = problem.read_factors(&witness);
+        assert!(a > 1 && b > 1, "trivial witness {a} x {b}");
+    }
+
+    // With 1-bit factors no nontrivial pair exists at all.
+    assert_eq!(solver.solve(&Factoring::new(1, 2, 3)), Min(None));
+}
+
+#[test]
+fn test_factoring_semiprime_target() {
+    let problem = Factoring::new(3, 3, 15);
+    assert!(problem.is_semiprime());
+    let valid: HashSet<(u64, u64)> = BruteForce::new()
+        .find_all_witnesses(&problem)
+        .iter()
+        .filter(|w| problem.is_valid_solution(w))
+        .map(|w| problem.read_factors(w))
+        .collect();
+    assert_eq!(valid, HashSet::from([(3, 5), (5, 3)]));
+
+    // Squares of primes are semiprime too.
+    assert!(Factoring::new(2, 2, 9).is_semiprime());
+}
+
+#[test]
+fn test_factoring_target_with_three_prime_factors() {
+    // 30 = 2 × 3 × 5 is not semiprime, but every split into two factors
+    // of at least 2 is a valid solution: 2 × 15, 3 × 10, 5 × 6 and swaps.
+    let problem = Factoring::new(4, 4, 30);
+    assert!(!problem.is_semiprime());
+    let valid: HashSet<(u64, u64)> = BruteForce::new()
+        .find_all_witnesses(&problem)
+        .iter()
+        .filter(|w| problem.is_valid_solution(w))
+        .map(|w| problem.read_factors(w))
+        .collect();
+    assert_eq!(
+        valid,
+        HashSet::from([(2, 15), (15, 2), (3, 10), (10, 3), (5, 6), (6, 5)])
+    );
+}
@@ -0,0 +1,112 @@
+use crate::models::misc::KCenter;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+use crate::variant::{K1, K2, KN};
+
+/// Distance matrix of a path graph 0 - 1 - ... - (n-1) with unit edge lengths.
+fn path_distances(n: usize) -> Vec<Vec<u64>> {
+    (0..n)
+        .map(|i| (0..n).map(|j| i.abs_diff(j) as u64).collect())
+        .collect()
+}
+
+#[test]
+fn test_kcenter_creation() {
+    let problem = KCenter::<K2>::new(path_distances(5));
+    assert_eq!(problem.num_sites(), 5);
+    assert_eq!(problem.num_centers(), 2);
+    assert_eq!(problem.dims(), vec![2; 5]);
+    assert_eq!(problem.candidate_radii(), vec![0, 1, 2, 3, 4]);
+    assert_eq!(problem.num_candidate_radii(), 5);
+    assert_eq!(<KCenter<K2> as Problem>::variant(), vec![("k", "K2")]);
+}
+
+#[test]
+fn test_kcenter_evaluate() {
+    let problem = KCenter::<KN>::with_k(path_distances(5), 2);
+    // Centers {1, 3}: every vertex is within distance 1.
+    assert_eq!(problem.evaluate(&[0, 1, 0, 1, 0]), Min(Some(1)));
+    // Centers {0, 1}: vertex 4 is at distance 3.
+    assert_eq!(problem.evaluate(&[1, 1, 0, 0, 0]), Min(Some(3)));
+    // Wrong number of centers is infeasible.
+    assert_eq!(problem.evaluate(&[0, 1, 0, 0, 0]), Min(None));
+    assert_eq!(problem.evaluate(&[1, 1, 1, 0, 0]), Min(None));
+    assert_eq!(problem.evaluate(&[0, 2, 0, 1, 0]), Min(None));
+}
+
+#[test]
+fn test_kcenter_path_k1_brute_force() {
+    // On a 5-vertex path the single best center is the middle vertex, radius 2.
+    let problem = KCenter::<K1>::new(path_distances(5));
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Min(Some(2)));
+    assert_eq!(
+        solver.find_all_witnesses(&problem),
+        vec![vec![0, 0, 1, 0, 0]]
+    );
+}
+
+#[test]
+fn test_kcenter_path_k2_brute_force() {
+    // On a 6-vertex path, two centers reach radius 1 only at {1, 4}.
+    let problem = KCenter::<K2>::new(path_distances(6));
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Min(Some(1)));
+    assert_eq!(
+        solver.find_all_witnesses(&problem),
+        vec![vec![0, 1, 0, 0, 1, 0]]
+    );
+}
+
+#[test]
+fn test_kcenter_all_sites_are_centers() {
+    let problem = KCenter::<KN>::with_k(path_distances(3), 3);
+    assert_eq!(BruteForce::new().solve(&problem), Min(Some(0)));
+}
+
+#[test]
+#[should_panic(expected = "KN requires with_k")]
+fn test_kcenter_kn_requires_with_k() {
+    KCenter::<KN>::new(path_distances(3));
+}
+
+#[test]
+#[should_panic(expected = "must be symmetric")]
+fn test_kcenter_rejects_asymmetric_distances() {
+    KCenter::<K1>::new(vec![vec![0, 1], vec![2, 0]]);
+}
+
+#[test]
+#[should_panic(expected = "must not exceed the number of sites")]
+fn test_kcenter_rejects_too_many_centers() {
+    KCenter::<KN>::with_k(path_distances(2), 3);
+}
+
+#[test]
+fn test_kcenter_serialization() {
+    let problem = KCenter::<KN>::with_k(path_distances(4), 2);
+    let json = serde_json::to_value(&problem).unwrap();
+    let restored: KCenter<KN> = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.distances(), problem.distances());
+    assert_eq!(restored.num_centers(), 2);
+}
+
+#[test]
+fn test_kcenter_deserialize_rejects_invalid_instances() {
+    let empty = serde_json::json!({ "distances": [], "num_centers": 0 });
+    let err = serde_json::from_value::<KCenter<KN>>(empty).unwrap_err();
+    assert!(err.to_string().contains("num_centers must be positive"));
+
+    let asymmetric = serde_json::json!({ "distances": [[0, 1], [2, 0]], "num_centers": 1 });
+    let err = serde_json::from_value::<KCenter<KN>>(asymmetric).unwrap_err();
+    assert!(err.to_string().contains("must be symmetric"));
+
+    let wrong_k = serde_json::json!({ "distances": [[0, 1], [1, 0]], "num_centers": 2 });
+    let err = serde_json::from_value::<KCenter<K1>>(wrong_k).unwrap_err();
+    assert!(err.to_string().contains("must equal K (1)"));
+
+    let default_k = serde_json::json!({ "distances": [[0, 1], [1, 0]] });
+    let problem: KCenter<K1> = serde_json::from_value(default_k).unwrap();
+    assert_eq!(problem.num_centers(), 1);
+}
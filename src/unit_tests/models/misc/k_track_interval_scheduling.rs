@@ -0,0 +1,143 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+
+fn example() -> KTrackIntervalScheduling {
+    KTrackIntervalScheduling::new(
+        vec![0, 1, 2, 4, 5, 7],
+        vec![4, 3, 6, 7, 8, 9],
+        vec![3, 2, 4, 2, 3, 1],
+        2,
+    )
+}
+
+/// Activity selection: repeatedly accept the compatible interval that ends first.
+fn greedy_by_finish_time(starts: &[u64], ends: &[u64]) -> u64 {
+    let mut order: Vec<usize> = (0..starts.len()).collect();
+    order.sort_by_key(|&i| ends[i]);
+    let mut free_from = 0;
+    let mut accepted = 0;
+    for i in order {
+        if starts[i] >= free_from {
+            free_from = ends[i];
+            accepted += 1;
+        }
+    }
+    accepted
+}
+
+#[test]
+fn test_k_track_interval_scheduling_basic() {
+    let problem = example();
+    assert_eq!(problem.num_intervals(), 6);
+    assert_eq!(problem.num_tracks(), 2);
+    assert_eq!(problem.starts(), &[0, 1, 2, 4, 5, 7]);
+    assert_eq!(problem.ends(), &[4, 3, 6, 7, 8, 9]);
+    assert_eq!(problem.weights(), &[3, 2, 4, 2, 3, 1]);
+    assert_eq!(problem.dims(), vec![3; 6]);
+    assert_eq!(
+        <KTrackIntervalScheduling as Problem>::NAME,
+        "KTrackIntervalScheduling"
+    );
+    assert_eq!(<KTrackIntervalScheduling as Problem>::variant(), vec![]);
+}
+
+#[test]
+fn test_k_track_interval_scheduling_overlaps_are_half_open() {
+    let problem = example();
+    assert!(problem.overlaps(0, 1));
+    assert!(problem.overlaps(2, 3));
+    // [0, 4) and [4, 7) only touch
+    assert!(!problem.overlaps(0, 3));
+    assert!(!problem.overlaps(3, 0));
+}
+
+#[test]
+fn test_k_track_interval_scheduling_evaluate() {
+    let problem = example();
+    assert_eq!(problem.evaluate(&[1, 0, 2, 0, 1, 2]), Max(Some(11)));
+    assert_eq!(problem.evaluate(&[0; 6]), Max(Some(0)));
+    // Touching intervals may share a track
+    assert_eq!(problem.evaluate(&[1, 0, 0, 1, 0, 0]), Max(Some(5)));
+    // [0, 4) and [1, 3) clash on track 1, but not across tracks
+    assert_eq!(problem.evaluate(&[1, 1, 0, 0, 0, 0]), Max(None));
+    assert_eq!(problem.evaluate(&[1, 2, 0, 0, 0, 0]), Max(Some(5)));
+    assert!(!problem.is_valid_solution(&[1, 1, 0, 0, 0, 0]));
+}
+
+#[test]
+fn test_k_track_interval_scheduling_invalid_config() {
+    let problem = example();
+    assert_eq!(problem.evaluate(&[1, 0, 2, 0, 1]), Max(None));
+    assert_eq!(problem.evaluate(&[3, 0, 0, 0, 0, 0]), Max(None));
+}
+
+#[test]
+fn test_k_track_interval_scheduling_brute_force() {
+    let problem = example();
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Max(Some(11)));
+    // Three optimal track assignments, each counted once per track swap
+    assert_eq!(solver.find_all_witnesses(&problem).len(), 6);
+}
+
+#[test]
+fn test_k_track_interval_scheduling_single_track_matches_greedy() {
+    let instances: [(Vec<u64>, Vec<u64>); 3] = [
+        (vec![0, 1, 2, 4, 5, 7], vec![4, 3, 6, 7, 8, 9]),
+        (vec![1, 3, 0, 5, 3, 5, 6, 8], vec![4, 5, 6, 7, 9, 9, 10, 11]),
+        (vec![0, 0, 0, 2, 2], vec![2, 3, 5, 4, 5]),
+    ];
+    for (starts, ends) in instances {
+        let greedy = greedy_by_finish_time(&starts, &ends);
+        let weights = vec![1; starts.len()];
+        let problem = KTrackIntervalScheduling::new(starts, ends, weights, 1);
+        assert_eq!(BruteForce::new().solve(&problem), Max(Some(greedy)));
+    }
+}
+
+#[test]
+fn test_k_track_interval_scheduling_second_track_beats_greedy() {
+    let starts = vec![1, 3, 0, 5, 3, 5, 6, 8];
+    let ends = vec![4, 5, 6, 7, 9, 9, 10, 11];
+    let greedy = greedy_by_finish_time(&starts, &ends);
+    assert_eq!(greedy, 3);
+
+    let two_tracks = KTrackIntervalScheduling::new(starts, ends, vec![1; 8], 2);
+    assert_eq!(BruteForce::new().solve(&two_tracks), Max(Some(5)));
+}
+
+#[test]
+fn test_k_track_interval_scheduling_weights_beat_cardinality() {
+    // One long heavy interval outweighs two short light ones on a single track
+    let problem = KTrackIntervalScheduling::new(vec![0, 0, 2], vec![4, 2, 4], vec![5, 2, 2], 1);
+    assert_eq!(BruteForce::new().solve(&problem), Max(Some(5)));
+}
+
+#[test]
+fn test_k_track_interval_scheduling_serialization_round_trip() {
+    let problem = example();
+    let json = serde_json::to_value(&problem).unwrap();
+    let restored: KTrackIntervalScheduling = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.starts(), problem.starts());
+    assert_eq!(restored.num_tracks(), 2);
+}
+
+#[test]
+fn test_k_track_interval_scheduling_deserialization_rejects_invalid() {
+    let empty_interval = serde_json::json!({
+        "starts": [3], "ends": [3], "weights": [1], "num_tracks": 1
+    });
+    assert!(serde_json::from_value::<KTrackIntervalScheduling>(empty_interval).is_err());
+
+    let no_tracks = serde_json::json!({
+        "starts": [0], "ends": [1], "weights": [1], "num_tracks": 0
+    });
+    assert!(serde_json::from_value::<KTrackIntervalScheduling>(no_tracks).is_err());
+}
+
+#[test]
+#[should_panic(expected = "starts length must equal weights length")]
+fn test_k_track_interval_scheduling_rejects_mismatched_lengths() {
+    KTrackIntervalScheduling::new(vec![0, 1], vec![2, 3], vec![1], 1);
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+
+#[test]
+fn test_minimum_multiprocessor_scheduling_basic() {
+    let problem = MinimumMultiprocessorScheduling::new(vec![3, 3, 2, 2, 2], 2);
+    assert_eq!(problem.num_tasks(), 5);
+    assert_eq!(problem.num_processors(), 2);
+    assert_eq!(problem.lengths(), &[3, 3, 2, 2, 2]);
+    assert_eq!(problem.total_length(), 12);
+    assert_eq!(problem.dims(), vec![2; 5]);
+    assert_eq!(
+        <MinimumMultiprocessorScheduling as Problem>::NAME,
+        "MinimumMultiprocessorScheduling"
+    );
+    assert_eq!(
+        <MinimumMultiprocessorScheduling as Problem>::variant(),
+        vec![]
+    );
+}
+
+#[test]
+fn test_minimum_multiprocessor_scheduling_evaluate_makespan() {
+    let problem = MinimumMultiprocessorScheduling::new(vec![3, 3, 2, 2, 2], 2);
+    assert_eq!(problem.loads(&[0, 0, 1, 1, 1]), Some(vec![6, 6]));
+    assert_eq!(problem.evaluate(&[0, 0, 1, 1, 1]), Min(Some(6)));
+    // Greedy longest-first: {3, 2, 2} and {3, 2}
+    assert_eq!(problem.evaluate(&[0, 1, 0, 1, 0]), Min(Some(7)));
+    assert_eq!(problem.evaluate(&[0, 0, 0, 0, 0]), Min(Some(12)));
+}
+
+#[test]
+fn test_minimum_multiprocessor_scheduling_invalid_config() {
+    let problem = MinimumMultiprocessorScheduling::new(vec![4, 5, 3], 2);
+    assert_eq!(problem.evaluate(&[0, 1]), Min(None));
+    assert_eq!(problem.evaluate(&[0, 2, 0]), Min(None));
+    assert_eq!(problem.loads(&[0, 1, 0, 1]), None);
+}
+
+#[test]
+fn test_minimum_multiprocessor_scheduling_brute_force() {
+    let solver = BruteForce::new();
+    let problem = MinimumMultiprocessorScheduling::new(vec![3, 3, 2, 2, 2], 2);
+    assert_eq!(solver.solve(&problem), Min(Some(6)));
+    let witness = solver.find_witness(&problem).unwrap();
+    assert_eq!(problem.evaluate(&witness), Min(Some(6)));
+
+    // Three processors: the two 3s alone and {2, 2, 2} leave a makespan of 6,
+    // but {3, 2}, {3, 2}, {2} gives 5.
+    let problem = MinimumMultiprocessorScheduling::new(vec![3, 3, 2, 2, 2], 3);
+    assert_eq!(solver.solve(&problem), Min(Some(5)));
+}
+
+#[test]
+fn test_minimum_multiprocessor_scheduling_empty_instance() {
+    let problem = MinimumMultiprocessorScheduling::new(vec![], 2);
+    assert_eq!(problem.evaluate(&[]), Min(Some(0)));
+}
+
+#[test]
+fn test_minimum_multiprocessor_scheduling_serialization() {
+    let problem = MinimumMultiprocessorScheduling::new(vec![4, 5, 3, 2, 6], 2);
+    let json = serde_json::to_value(&problem).unwrap();
+    let restored: MinimumMultiprocessorScheduling = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.lengths(), problem.lengths());
+    assert_eq!(restored.num_processors(), problem.num_processors());
+
+    let err = serde_json::from_value::<MinimumMultiprocessorScheduling>(serde_json::json!({
+        "lengths": [1, 2],
+        "num_processors": 0
+    }))
+    .unwrap_err();
+    assert!(
+        err.to_string().contains("expected positive integer, got 0"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+#[should_panic(expected = "num_processors must be positive")]
+fn test_minimum_multiprocessor_scheduling_zero_processors() {
+    MinimumMultiprocessorScheduling::new(vec![1, 2], 0);
+}
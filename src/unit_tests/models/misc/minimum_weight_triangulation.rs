@@ -0,0 +1,205 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+
+fn skewed_pentagon() -> MinimumWeightTriangulation {
+    // Vertex 2 sits far to the right of an otherwise compact pentagon.
+    MinimumWeightTriangulation::new(vec![
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (6.0, 2.0),
+        (2.0, 4.0),
+        (0.0, 3.0),
+    ])
+}
+
+/// Classic O(n^3) interval DP for convex polygons, as an independent oracle.
+fn dp_optimum(problem: &MinimumWeightTriangulation) -> f64 {
+    let n = problem.num_points();
+    let mut best = vec![vec![0.0; n]; n];
+    for len in 2..n {
+        for i in 0..n - len {
+            let j = i + len;
+            let chord = if len == n - 1 || len == 1 {
+                0.0
+            } else {
+                problem.distance(i, j)
+            };
+            best[i][j] = (i + 1..j)
+                .map(|k| best[i][k] + best[k][j])
+                .fold(f64::INFINITY, f64::min)
+                + chord;
+        }
+    }
+    best[0][n - 1] + problem.perimeter()
+}
+
+#[test]
+fn test_minimum_weight_triangulation_basic() {
+    let problem = skewed_pentagon();
+    assert_eq!(problem.num_points(), 5);
+    assert_eq!(problem.num_diagonals(), 5);
+    assert_eq!(
+        problem.diagonals(),
+        vec![(0, 2), (0, 3), (1, 3), (1, 4), (2, 4)]
+    );
+    assert_eq!(problem.dims(), vec![2; 5]);
+    assert_eq!(
+        <MinimumWeightTriangulation as Problem>::NAME,
+        "MinimumWeightTriangulation"
+    );
+}
+
+#[test]
+fn test_minimum_weight_triangulation_skewed_pentagon_optimum() {
+    let problem = skewed_pentagon();
+    // Cutting off the far vertex with (1, 3) (length 4) leaves the
+    // quadrilateral 0-1-3-4, whose shorter diagonal is (1, 4) = sqrt(13)
+    // rather than (0, 3) = sqrt(20).
+    let expected = problem.perimeter() + 4.0 + 13f64.sqrt();
+    let solver = BruteForce::new();
+    let witnesses = solver.find_all_witnesses(&problem);
+    assert_eq!(witnesses, vec![vec![0, 0, 1, 1, 0]]);
+    let value = problem.evaluate(&witnesses[0]).unwrap();
+    assert!((value - expected).abs() < 1e-9);
+    assert!((value - dp_optimum(&problem)).abs() < 1e-9);
+
+    // Every fan touching the far vertex is worse.
+    for fan in [[1, 1, 0, 0, 0], [1, 0, 0, 0, 1], [0, 0, 0, 1, 1]] {
+        assert!(problem.evaluate(&fan).unwrap() > value + 1.0);
+    }
+}
+
+#[test]
+fn test_minimum_weight_triangulation_validity() {
+    let problem = skewed_pentagon();
+    // (0, 2) and (1, 3) cross.
+    assert!(!problem.is_valid_solution(&[1, 0, 1, 0, 0]));
+    // One diagonal is not maximal.
+    assert!(!problem.is_valid_solution(&[0, 0, 1, 0, 0]));
+    // Three diagonals always contain a crossing in a pentagon.
+    assert!(!problem.is_valid_solution(&[1, 1, 1, 0, 0]));
+    assert!(!problem.is_valid_solution(&[0, 0, 1, 1]));
+    assert!(!problem.is_valid_solution(&[0, 0, 2, 1, 0]));
+    assert_eq!(problem.evaluate(&[1, 0, 1, 0, 0]), Min(None));
+    assert!(problem.is_valid_solution(&[1, 1, 0, 0, 0]));
+}
+
+#[test]
+fn test_minimum_weight_triangulation_counts_catalan() {
+    // A convex n-gon has Catalan(n - 2) triangulations.
+    for (n, catalan) in [(3, 1), (4, 2), (5, 5), (6, 14), (7, 42)] {
+        let points = (0..n)
+            .map(|k| {
+                let angle = std::f64::consts::TAU * k as f64 / n as f64;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+        let problem = MinimumWeightTriangulation::new(points);
+        let valid = crate::config::DimsIterator::new(problem.dims())
+            .filter(|config| problem.is_valid_solution(config))
+            .count();
+        assert_eq!(valid, catalan, "n = {n}");
+    }
+}
+
+#[test]
+fn test_minimum_weight_triangulation_matches_dp_on_random_polygons() {
+    let mut state = 3u64;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as f64 / (1u64 << 31) as f64
+    };
+    let solver = BruteForce::new();
+    for round in 0..20 {
+        let n = 4 + round % 4;
+        // Sorted random angles on an ellipse give a strictly convex polygon.
+        let mut angles: Vec<f64> = (0..n).map(|_| next() * std::f64::consts::TAU).collect();
+        angles.sort_by(f64::total_cmp);
+        let points = angles.iter().map(|&a| (3.0 * a.cos(), a.sin())).collect();
+        let Ok(problem) = MinimumWeightTriangulation::try_new(points) else {
+            continue;
+        };
+        let value = solver.solve(&problem).unwrap();
+        assert!((value - dp_optimum(&problem)).abs() < 1e-9, "{problem:?}");
+    }
+}
+
+#[test]
+fn test_minimum_weight_triangulation_accepts_clockwise_order() {
+    let mut points = skewed_pentagon().positions().to_vec();
+    points.reverse();
+    let problem = MinimumWeightTriangulation::new(points);
+    let value = BruteForce::new().solve(&problem).unwrap();
+    let expected = BruteForce::new().solve(&skewed_pentagon()).unwrap();
+    assert!((value - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_minimum_weight_triangulation_rejects_invalid_polygons() {
+    // Too few points.
+    assert!(MinimumWeightTriangulation::try_new(vec![(0.0, 0.0), (1.0, 0.0)]).is_err());
+    // Collinear triple.
+    assert!(MinimumWeightTriangulation::try_new(vec![
+        (0.0, 0.0),
+        (1.0, 0.0),
+        (2.0, 0.0),
+        (1.0, 1.0)
+    ])
+    .is_err());
+    // Reflex vertex.
+    assert!(MinimumWeightTriangulation::try_new(vec![
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (1.0, 0.5),
+        (2.0, 2.0),
+        (0.0, 2.0),
+    ])
+    .is_err());
+    // Pentagram order turns consistently but winds twice.
+    let pentagram = (0..5)
+        .map(|k| {
+            let angle = std::f64::consts::TAU * (2 * k) as f64 / 5.0;
+            (angle.cos(), angle.sin())
+        })
+        .collect();
+    assert!(MinimumWeightTriangulation::try_new(pentagram).is_err());
+    // Non-finite coordinate.
+    assert!(
+        MinimumWeightTriangulation::try_new(vec![(0.0, 0.0), (1.0, 0.0), (f64::NAN, 1.0)]).is_err()
+    );
+}
+
+#[test]
+#[should_panic(expected = "strictly convex")]
+fn test_minimum_weight_triangulation_new_panics_on_reflex_vertex() {
+    MinimumWeightTriangulation::new(vec![
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (1.0, 0.5),
+        (2.0, 2.0),
+        (0.0, 2.0),
+    ]);
+}
+
+#[test]
+fn test_minimum_weight_triangulation_serialization() {
+    let problem = skewed_pentagon();
+    let json = serde_json::to_value(&problem).unwrap();
+    let back: MinimumWeightTriangulation = serde_json::from_value(json).unwrap();
+    assert_eq!(back.positions(), problem.positions());
+
+    let invalid = serde_json::json!({
+        "positions": [[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [0.0, 2.0]]
+    });
+    assert!(serde_json::from_value::<MinimumWeightTriangulation>(invalid).is_err());
+}
+
+#[test]
+fn test_minimum_weight_triangulation_triangle() {
+    let problem = MinimumWeightTriangulation::new(vec![(0.0, 0.0), (3.0, 0.0), (0.0, 4.0)]);
+    assert_eq!(problem.num_diagonals(), 0);
+    assert_eq!(problem.evaluate(&[]), Min(Some(12.0)));
+}
@@ -0,0 +1,96 @@
+use crate::models::misc::RectanglePacking;
+use crate::solvers::BruteForce;
+use crate::traits::Problem;
+use crate::types::Or;
+
+#[test]
+fn test_rectangle_packing_basic() {
+    let problem = RectanglePacking::new(vec![(1, 2), (2, 1), (2, 1)], 3, 2, false);
+    assert_eq!(problem.num_rectangles(), 3);
+    assert_eq!(problem.container_width(), 3);
+    assert_eq!(problem.container_height(), 2);
+    assert!(!problem.allow_rotation());
+    // dims: (W - w + 1, H - h + 1) per rectangle
+    assert_eq!(problem.dims(), vec![3, 1, 2, 2, 2, 2]);
+    assert_eq!(<RectanglePacking as Problem>::NAME, "RectanglePacking");
+    assert_eq!(<RectanglePacking as Problem>::variant(), vec![]);
+}
+
+#[test]
+fn test_rectangle_packing_two_dominoes_fit_in_square() {
+    let problem = RectanglePacking::new(vec![(1, 2), (1, 2)], 2, 2, false);
+    assert_eq!(problem.evaluate(&[0, 0, 1, 0]), Or(true));
+    // Same column overlaps
+    assert_eq!(problem.evaluate(&[0, 0, 0, 0]), Or(false));
+    assert!(BruteForce::new().find_witness(&problem).is_some());
+}
+
+#[test]
+fn test_rectangle_packing_three_dominoes_do_not_fit_in_square() {
+    // Total area 6 exceeds the container area 4.
+    let problem = RectanglePacking::new(vec![(1, 2), (1, 2), (1, 2)], 2, 2, false);
+    assert!(BruteForce::new().find_witness(&problem).is_none());
+
+    let rotatable = RectanglePacking::new(vec![(1, 2), (1, 2), (1, 2)], 2, 2, true);
+    assert!(BruteForce::new().find_witness(&rotatable).is_none());
+}
+
+#[test]
+fn test_rectangle_packing_rotation() {
+    // A 2x1 domino only fits a 1x2 container when rotated.
+    assert!(RectanglePacking::try_new(vec![(2, 1)], 1, 2, false).is_err());
+    let problem = RectanglePacking::new(vec![(2, 1)], 1, 2, true);
+    assert_eq!(problem.dims(), vec![1, 2, 2]);
+    assert_eq!(problem.evaluate(&[0, 0, 1]), Or(true));
+    assert_eq!(problem.evaluate(&[0, 0, 0]), Or(false));
+    assert_eq!(problem.evaluate(&[0, 1, 1]), Or(false));
+
+    // One rotated and one upright domino side by side in a 3x2 box.
+    let mixed = RectanglePacking::new(vec![(2, 1), (2, 1)], 3, 2, true);
+    assert_eq!(mixed.evaluate(&[0, 0, 1, 1, 0, 0]), Or(true));
+    assert_eq!(mixed.evaluate(&[0, 0, 1, 0, 0, 0]), Or(false));
+}
+
+#[test]
+fn test_rectangle_packing_rejects_invalid_config() {
+    let problem = RectanglePacking::new(vec![(1, 2), (1, 2)], 2, 2, false);
+    assert_eq!(problem.evaluate(&[0, 0, 1]), Or(false));
+    assert_eq!(problem.evaluate(&[0, 0, 1, 0, 0]), Or(false));
+    // Out of the container
+    assert_eq!(problem.evaluate(&[0, 0, 2, 0]), Or(false));
+    let rotatable = RectanglePacking::new(vec![(1, 2)], 2, 2, true);
+    assert_eq!(rotatable.evaluate(&[0, 0, 2]), Or(false));
+}
+
+#[test]
+fn test_rectangle_packing_rejects_invalid_instances() {
+    assert!(RectanglePacking::try_new(vec![], 2, 2, false).is_err());
+    assert!(RectanglePacking::try_new(vec![(1, 1)], 0, 2, false).is_err());
+    assert!(RectanglePacking::try_new(vec![(0, 1)], 2, 2, false).is_err());
+    assert!(RectanglePacking::try_new(vec![(3, 3)], 2, 2, true).is_err());
+}
+
+#[test]
+fn test_rectangle_packing_serialization() {
+    let problem = RectanglePacking::new(vec![(1, 2), (2, 1)], 3, 2, true);
+    let json = serde_json::to_value(&problem).unwrap();
+    let restored: RectanglePacking = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.rectangles(), problem.rectangles());
+    assert!(restored.allow_rotation());
+
+    // allow_rotation defaults to false
+    let restored: RectanglePacking = serde_json::from_value(serde_json::json!({
+        "rectangles": [[1, 2]],
+        "container_width": 2,
+        "container_height": 2,
+    }))
+    .unwrap();
+    assert!(!restored.allow_rotation());
+
+    let invalid = serde_json::json!({
+        "rectangles": [[3, 1]],
+        "container_width": 2,
+        "container_height": 2,
+    });
+    assert!(serde_json::from_value::<RectanglePacking>(invalid).is_err());
+}
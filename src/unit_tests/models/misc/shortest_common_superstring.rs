@@ -0,0 +1,113 @@
+use super::*;
+use crate::models::PermutationProblem;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+
+/// "CATG", "ATGC", "TGCA" with A=0, C=1, G=2, T=3.
+fn catg() -> ShortestCommonSuperstring {
+    ShortestCommonSuperstring::new(
+        4,
+        vec![vec![1, 0, 3, 2], vec![0, 3, 2, 1], vec![3, 2, 1, 0]],
+    )
+}
+
+#[test]
+fn test_shortestcommonsuperstring_basic() {
+    let problem = catg();
+    assert_eq!(problem.alphabet_size(), 4);
+    assert_eq!(problem.num_strings(), 3);
+    assert_eq!(problem.total_length(), 12);
+    assert_eq!(problem.dims(), vec![3; 3]);
+    assert_eq!(
+        <ShortestCommonSuperstring as Problem>::NAME,
+        "ShortestCommonSuperstring"
+    );
+    assert_eq!(<ShortestCommonSuperstring as Problem>::variant(), vec![]);
+}
+
+#[test]
+fn test_shortestcommonsuperstring_overlap() {
+    let problem = catg();
+    assert_eq!(problem.overlap(0, 1), 3); // CATG -> ATGC
+    assert_eq!(problem.overlap(1, 2), 3); // ATGC -> TGCA
+    assert_eq!(problem.overlap(2, 0), 2); // TGCA -> CATG
+    assert_eq!(problem.overlap(1, 0), 1); // ATGC -> CATG
+}
+
+#[test]
+fn test_shortestcommonsuperstring_evaluate() {
+    let problem = catg();
+    // CATG + ATGC + TGCA = CATGCA
+    assert_eq!(
+        problem.superstring(&[0, 1, 2]),
+        Some(vec![1, 0, 3, 2, 1, 0])
+    );
+    assert_eq!(problem.evaluate(&[0, 1, 2]), Min(Some(6)));
+    // TGCA + CATG + ATGC = TGCATGC
+    assert_eq!(problem.evaluate(&[2, 0, 1]), Min(Some(7)));
+}
+
+#[test]
+fn test_shortestcommonsuperstring_rejects_non_permutations() {
+    let problem = catg();
+    assert_eq!(problem.superstring(&[0, 0, 1]), None);
+    assert_eq!(problem.evaluate(&[0, 0, 1]), Min(None));
+    assert_eq!(problem.evaluate(&[0, 1]), Min(None));
+    assert_eq!(problem.evaluate(&[0, 1, 3]), Min(None));
+}
+
+#[test]
+fn test_shortestcommonsuperstring_skips_contained_strings() {
+    // "ABC" contains "B"; once "ABC" is merged, "B" is not appended again.
+    let problem = ShortestCommonSuperstring::new(3, vec![vec![0, 1, 2], vec![1]]);
+    assert_eq!(problem.evaluate(&[0, 1]), Min(Some(3)));
+    assert_eq!(problem.evaluate(&[1, 0]), Min(Some(4)));
+}
+
+#[test]
+fn test_shortestcommonsuperstring_brute_force() {
+    let problem = catg();
+    assert_eq!(BruteForce::new().solve(&problem), Min(Some(6)));
+
+    let orders = PermutationProblem::new(problem);
+    assert_eq!(BruteForce::new().solve_permutations(&orders), Min(Some(6)));
+    // CATG + TGCA = CATGCA already contains ATGC.
+    assert_eq!(
+        BruteForce::new().find_all_permutation_witnesses(&orders),
+        vec![vec![0, 1, 2], vec![0, 2, 1]]
+    );
+}
+
+#[test]
+fn test_shortestcommonsuperstring_brute_force_eight_strings() {
+    // The eight length-3 windows of the binary de Bruijn sequence 00010111
+    // (read cyclically) merge into a superstring of length 3 + 7 = 10.
+    let strings = (0..8)
+        .map(|w: usize| (0..3).map(|b| (w >> (2 - b)) & 1).collect())
+        .collect();
+    let problem = ShortestCommonSuperstring::new(2, strings);
+    let orders = PermutationProblem::new(problem);
+    assert_eq!(BruteForce::new().solve_permutations(&orders), Min(Some(10)));
+}
+
+#[test]
+fn test_shortestcommonsuperstring_serialization() {
+    let problem = catg();
+    let json = serde_json::to_value(&problem).unwrap();
+    let restored: ShortestCommonSuperstring = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.strings(), problem.strings());
+    assert_eq!(restored.evaluate(&[0, 1, 2]), Min(Some(6)));
+}
+
+#[test]
+#[should_panic(expected = "all symbols must be less than alphabet_size")]
+fn test_shortestcommonsuperstring_symbol_out_of_range() {
+    ShortestCommonSuperstring::new(2, vec![vec![0, 2]]);
+}
+
+#[test]
+#[should_panic(expected = "must have at least one string")]
+fn test_shortestcommonsuperstring_empty() {
+    ShortestCommonSuperstring::new(2, vec![]);
+}
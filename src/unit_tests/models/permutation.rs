@@ -0,0 +1,86 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::types::Min;
+
+/// Minimize the number of inversions, i.e. pairs `i < j` with `perm[i] > perm[j]`.
+#[derive(Debug, Clone)]
+struct Inversions {
+    n: usize,
+    /// Elements that may not be placed first.
+    forbidden_first: Vec<usize>,
+}
+
+impl PermutationConstraint for Inversions {
+    const NAME: &'static str = "Inversions";
+    type Value = Min<usize>;
+
+    fn num_elements(&self) -> usize {
+        self.n
+    }
+
+    fn cost(&self, perm: &[usize]) -> Min<usize> {
+        let inversions = (0..perm.len())
+            .flat_map(|i| (i + 1..perm.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| perm[i] > perm[j])
+            .count();
+        Min(Some(inversions))
+    }
+
+    fn is_feasible(&self, perm: &[usize]) -> bool {
+        perm.first()
+            .is_none_or(|first| !self.forbidden_first.contains(first))
+    }
+}
+
+fn inversions(n: usize) -> PermutationProblem<Inversions> {
+    PermutationProblem::new(Inversions {
+        n,
+        forbidden_first: vec![],
+    })
+}
+
+#[test]
+fn test_permutation_problem_evaluate() {
+    let problem = inversions(5);
+    assert_eq!(problem.dims(), vec![5; 5]);
+    assert_eq!(problem.evaluate(&[0, 1, 2, 3, 4]), Min(Some(0)));
+    assert_eq!(problem.evaluate(&[1, 0, 2, 4, 3]), Min(Some(2)));
+    assert_eq!(problem.evaluate(&[4, 3, 2, 1, 0]), Min(Some(10)));
+    // Repeated elements and wrong lengths are not permutations.
+    assert_eq!(problem.evaluate(&[0, 0, 2, 3, 4]), Min(None));
+    assert_eq!(problem.evaluate(&[0, 1, 2, 3]), Min(None));
+    assert_eq!(problem.evaluate(&[0, 1, 2, 3, 5]), Min(None));
+}
+
+#[test]
+fn test_permutation_problem_minimize_inversions_n5() {
+    let problem = inversions(5);
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve_permutations(&problem), Min(Some(0)));
+    assert_eq!(
+        solver.find_all_permutation_witnesses(&problem),
+        vec![vec![0, 1, 2, 3, 4]]
+    );
+    // Enumerating all 5^5 configurations reaches the same optimum.
+    assert_eq!(solver.solve(&problem), Min(Some(0)));
+    assert_eq!(
+        solver.find_all_witnesses(&problem),
+        solver.find_all_permutation_witnesses(&problem)
+    );
+}
+
+#[test]
+fn test_permutation_problem_feasibility_filter() {
+    // With 0 barred from the front, the best orderings lead with 1 and pay one inversion.
+    let problem = PermutationProblem::new(Inversions {
+        n: 5,
+        forbidden_first: vec![0],
+    });
+    let solver = BruteForce::new();
+    assert_eq!(problem.evaluate(&[0, 1, 2, 3, 4]), Min(None));
+    assert_eq!(solver.solve_permutations(&problem), Min(Some(1)));
+    assert_eq!(
+        solver.find_all_permutation_witnesses(&problem),
+        vec![vec![1, 0, 2, 3, 4]]
+    );
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::solvers::BruteForce;
+use crate::solvers::{BruteForce, Solver};
 use crate::traits::Problem;
 use crate::types::Min;
 include!("../../jl_helpers.rs");
@@ -124,3 +124,68 @@ fn test_setcovering_paper_example() {
     let best = solver.find_witness(&problem).unwrap();
     assert_eq!(problem.evaluate(&best).unwrap(), 2);
 }
+
+#[test]
+fn test_unit_coverage_requirements_match_classic_cover() {
+    let sets = vec![vec![0, 1, 2], vec![1, 3], vec![2, 3, 4], vec![0, 4]];
+    let classic = MinimumSetCovering::<i32>::new(5, sets.clone());
+    let multicover = MinimumSetCovering::<i32>::new(5, sets).with_coverage_requirements(vec![1; 5]);
+    assert!(multicover.is_multicover());
+
+    for config in crate::config::DimsIterator::new(classic.dims()) {
+        assert_eq!(classic.evaluate(&config), multicover.evaluate(&config));
+    }
+    let solver = BruteForce::new();
+    let classic_best: HashSet<Vec<usize>> =
+        solver.find_all_witnesses(&classic).into_iter().collect();
+    let multi_best: HashSet<Vec<usize>> =
+        solver.find_all_witnesses(&multicover).into_iter().collect();
+    assert_eq!(classic_best, multi_best);
+}
+
+#[test]
+fn test_coverage_requirement_two_grows_optimum() {
+    // Each element lies in two sets; {S_0, S_2} covers everything once.
+    let sets = vec![vec![0, 1], vec![1, 2], vec![2, 0]];
+    let classic = MinimumSetCovering::<i32>::new(3, sets.clone());
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&classic), Min(Some(2)));
+
+    // Element 0 must now be covered twice, which forces S_0 and S_2 and
+    // leaves element 1 to S_0; raising element 1 too forces all three sets.
+    let multicover =
+        MinimumSetCovering::<i32>::new(3, sets.clone()).with_coverage_requirements(vec![2, 2, 1]);
+    assert_eq!(solver.solve(&multicover), Min(Some(3)));
+    assert_eq!(solver.find_all_witnesses(&multicover), vec![vec![1, 1, 1]]);
+    assert_eq!(multicover.coverage_counts(&[1, 0, 1]), vec![2, 1, 1]);
+    assert!(!multicover.is_valid_solution(&[1, 0, 1]));
+    assert!(is_set_multicover(&sets, &[2, 2, 1], &[true, true, true]));
+    assert!(!is_set_multicover(&sets, &[2, 2, 1], &[true, false, true]));
+}
+
+#[test]
+fn test_coverage_counts_ignore_repeated_elements() {
+    let problem = MinimumSetCovering::<i32>::new(2, vec![vec![0, 0, 1], vec![1]])
+        .with_coverage_requirements(vec![2, 1]);
+    assert_eq!(problem.coverage_counts(&[1, 1]), vec![1, 2]);
+    assert!(!problem.is_valid_solution(&[1, 1]));
+    assert_eq!(problem.coverage_requirement(0), 2);
+}
+
+#[test]
+#[should_panic(expected = "coverage_requirements length must match universe_size")]
+fn test_coverage_requirements_length_mismatch() {
+    MinimumSetCovering::<i32>::new(3, vec![vec![0, 1, 2]]).with_coverage_requirements(vec![1, 1]);
+}
+
+#[test]
+fn test_coverage_requirements_serde() {
+    let classic = MinimumSetCovering::<i32>::new(2, vec![vec![0, 1]]);
+    let json = serde_json::to_value(&classic).unwrap();
+    assert!(json.get("coverage_requirements").is_none());
+
+    let multicover = classic.with_coverage_requirements(vec![1, 2]);
+    let json = serde_json::to_string(&multicover).unwrap();
+    let back: MinimumSetCovering<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.coverage_requirement(1), 2);
+}
@@ -163,3 +163,68 @@ fn test_graph_strategy_sanity() {
         assert_eq!(unique.len(), edges.len(), "Duplicate edges detected");
     }
 }
+
+/// Random simple graph on `n` vertices where each edge is present with probability 1/2.
+fn random_graph(rng: &mut rand::rngs::SmallRng, n: usize) -> SimpleGraph {
+    use rand::RngExt;
+
+    let edges = (0..n)
+        .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+        .filter(|_| rng.random_bool(0.5))
+        .collect();
+    SimpleGraph::new(n, edges)
+}
+
+/// Property: weighted MIS -> MVC preserves the optimum as `min VC = total weight - max IS`.
+#[test]
+fn reduction_is_to_vc_preserves_optimum() {
+    use crate::rules::test_helpers::assert_reduction_preserves_optimum;
+    use crate::rules::ReduceTo;
+    use rand::RngExt;
+
+    assert_reduction_preserves_optimum(
+        &|rng| {
+            let n = rng.random_range(1..=7);
+            let weights = (0..n).map(|_| rng.random_range(1..=5)).collect();
+            MaximumIndependentSet::new(random_graph(rng, n), weights)
+        },
+        &|source: &MaximumIndependentSet<SimpleGraph, i32>| {
+            ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(source)
+        },
+        &|source: &MaximumIndependentSet<SimpleGraph, i32>, max_is: Max<i32>| {
+            let total: i32 = source.weights().iter().sum();
+            Min(Some(total - max_is.0.unwrap()))
+        },
+        2024,
+        30,
+    );
+}
+
+/// Property: MaxCut -> SpinGlass preserves the optimum as `min energy = total weight - 2 * max cut`.
+#[test]
+fn reduction_maxcut_to_spinglass_preserves_optimum() {
+    use crate::models::graph::{MaxCut, SpinGlass};
+    use crate::rules::test_helpers::assert_reduction_preserves_optimum;
+    use crate::rules::ReduceTo;
+    use rand::RngExt;
+
+    assert_reduction_preserves_optimum(
+        &|rng| {
+            let n = rng.random_range(2..=7);
+            let graph = random_graph(rng, n);
+            let weights = (0..crate::topology::Graph::num_edges(&graph))
+                .map(|_| rng.random_range(-3..=5))
+                .collect();
+            MaxCut::new(graph, weights)
+        },
+        &|source: &MaxCut<SimpleGraph, i32>| {
+            ReduceTo::<SpinGlass<SimpleGraph, i32>>::reduce_to(source)
+        },
+        &|source: &MaxCut<SimpleGraph, i32>, max_cut: Max<i32>| {
+            let total: i32 = source.edges().iter().map(|&(_, _, w)| w).sum();
+            Min(Some(total - 2 * max_cut.0.unwrap()))
+        },
+        2024,
+        30,
+    );
+}
@@ -3,6 +3,7 @@ use crate::models::graph::MinimumVertexCover;
 use crate::models::misc::SubsetSum;
 use crate::registry::variant::find_variant_entry;
 use crate::registry::{load_dyn, serialize_any, DynProblem, LoadedDynProblem};
+use crate::solvers::SolverTelemetry;
 use crate::topology::SimpleGraph;
 use crate::types::Sum;
 use crate::{Problem, Solver};
@@ -25,6 +26,12 @@ fn solve_subset_sum_witness(any: &dyn Any) -> Option<(Vec<usize>, String)> {
     Some((config, eval))
 }
 
+fn solve_subset_sum_telemetry(any: &dyn Any) -> (String, SolverTelemetry) {
+    let p = any.downcast_ref::<SubsetSum>().unwrap();
+    let (value, telemetry) = crate::BruteForce::new().solve_with_telemetry(p);
+    (format!("{value:?}"), telemetry)
+}
+
 #[derive(Clone, serde::Serialize)]
 struct AggregateOnlyProblem {
     weights: Vec<u64>,
@@ -60,6 +67,12 @@ fn solve_aggregate_witness(_: &dyn Any) -> Option<(Vec<usize>, String)> {
     None
 }
 
+fn solve_aggregate_telemetry(any: &dyn Any) -> (String, SolverTelemetry) {
+    let p = any.downcast_ref::<AggregateOnlyProblem>().unwrap();
+    let (value, telemetry) = crate::BruteForce::new().solve_with_telemetry(p);
+    (format!("{value:?}"), telemetry)
+}
+
 #[test]
 fn test_dyn_problem_blanket_impl_exposes_problem_metadata() {
     let problem = MaximumIndependentSet::new(SimpleGraph::new(3, vec![(0, 1)]), vec![1i32; 3]);
@@ -88,6 +101,7 @@ fn test_loaded_dyn_problem_delegates_to_value_and_witness_fns() {
         Box::new(problem),
         solve_subset_sum_value,
         solve_subset_sum_witness,
+        solve_subset_sum_telemetry,
     );
 
     assert_eq!(loaded.solve_brute_force_value(), "Or(true)");
@@ -106,10 +120,14 @@ fn loaded_dyn_problem_returns_none_for_aggregate_only_witness() {
         }),
         solve_aggregate_value,
         solve_aggregate_witness,
+        solve_aggregate_telemetry,
     );
 
     assert_eq!(loaded.solve_brute_force_value(), "Sum(28)");
     assert!(loaded.solve_brute_force_witness().is_none());
+    let (value, telemetry) = loaded.solve_brute_force_telemetry();
+    assert_eq!(value, "Sum(28)");
+    assert_eq!(telemetry.evaluations, 8);
 }
 
 #[test]
@@ -129,6 +147,9 @@ fn test_load_dyn_formats_optimization_solve_values_as_max_min() {
     assert_eq!(loaded.solve_brute_force_value(), "Min(1)");
     let solved = loaded.solve_brute_force_witness().unwrap();
     assert_eq!(solved.1, "Min(1)");
+    let (value, telemetry) = loaded.solve_brute_force_telemetry();
+    assert_eq!(value, "Min(1)");
+    assert_eq!(telemetry.evaluations, 1 << 3);
 }
 
 #[test]
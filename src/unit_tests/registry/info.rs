@@ -8,6 +8,42 @@ fn test_complexity_class() {
     assert!(!ComplexityClass::P.is_hard());
 }
 
+#[test]
+fn test_complexity_class_from_name() {
+    assert_eq!(
+        ComplexityClass::from_name("NP-complete"),
+        Some(ComplexityClass::NpComplete)
+    );
+    assert_eq!(
+        ComplexityClass::from_name("np_hard"),
+        Some(ComplexityClass::NpHard)
+    );
+    assert_eq!(
+        ComplexityClass::from_name("PSPACE complete"),
+        Some(ComplexityClass::PspaceComplete)
+    );
+    assert_eq!(ComplexityClass::from_name("p"), Some(ComplexityClass::P));
+    assert_eq!(ComplexityClass::from_name("EXPTIME"), None);
+}
+
+#[test]
+fn test_problem_category() {
+    assert_eq!(
+        ProblemCategory::from_name("Graph"),
+        Some(ProblemCategory::Graph)
+    );
+    assert_eq!(ProblemCategory::from_name("geometry"), None);
+    assert_eq!(
+        ProblemCategory::from_module_path("problemreductions::models::formula::sat"),
+        Some(ProblemCategory::Formula)
+    );
+    assert_eq!(
+        ProblemCategory::from_module_path("problemreductions::rules::sat_ilp"),
+        None
+    );
+    assert_eq!(ProblemCategory::Algebraic.to_string(), "algebraic");
+}
+
 #[test]
 fn test_problem_info_builder() {
     let info = ProblemInfo::new("Independent Set", "Find a maximum weight independent set")
@@ -1,6 +1,7 @@
 use crate::registry::{
     find_problem_type, find_problem_type_by_alias, parse_catalog_problem_ref, problem_types,
-    ProblemRef, ProblemSchemaEntry,
+    problems_in_category, problems_with_complexity, ComplexityClass, ProblemCategory, ProblemRef,
+    ProblemSchemaEntry,
 };
 use std::collections::HashMap;
 
@@ -237,3 +238,85 @@ fn graph_defaults_are_catalog_defaults_for_registered_variants() {
         }
     }
 }
+
+#[test]
+fn problems_with_complexity_finds_np_complete_problems() {
+    let names: Vec<_> = problems_with_complexity(ComplexityClass::NpComplete)
+        .into_iter()
+        .map(|info| info.name)
+        .collect();
+    for expected in ["MaximumIndependentSet", "Satisfiability", "MaxCut"] {
+        assert!(
+            names.contains(&expected),
+            "{expected} should be NP-complete"
+        );
+    }
+    assert!(!names.contains(&"MaximumMatching"));
+    assert!(!names.contains(&"QuantifiedBooleanFormulas"));
+}
+
+#[test]
+fn problems_with_complexity_uses_explicit_entries() {
+    let pspace: Vec<_> = problems_with_complexity(ComplexityClass::PspaceComplete)
+        .into_iter()
+        .map(|info| info.name)
+        .collect();
+    assert!(pspace.contains(&"QuantifiedBooleanFormulas"));
+    assert!(pspace.contains(&"GeneralizedHex"));
+
+    let polynomial: Vec<_> = problems_with_complexity(ComplexityClass::P)
+        .into_iter()
+        .map(|info| info.name)
+        .collect();
+    assert!(polynomial.contains(&"MaximumMatching"));
+    assert!(!polynomial.contains(&"SimultaneousIncongruences"));
+}
+
+#[test]
+fn problems_with_complexity_covers_every_problem_once() {
+    let total: usize = ComplexityClass::ALL
+        .into_iter()
+        .map(|class| problems_with_complexity(class).len())
+        .sum();
+    assert_eq!(total, problem_types().len());
+    for info in problems_with_complexity(ComplexityClass::NpComplete) {
+        assert_eq!(info.complexity_class, ComplexityClass::NpComplete);
+    }
+}
+
+#[test]
+fn problems_in_category_filters_by_module() {
+    let graph: Vec<_> = problems_in_category(ProblemCategory::Graph)
+        .into_iter()
+        .map(|info| info.name)
+        .collect();
+    assert!(graph.contains(&"MaximumIndependentSet"));
+    assert!(graph.contains(&"MaximalIS"));
+    assert!(!graph.contains(&"Satisfiability"));
+
+    let formula: Vec<_> = problems_in_category(ProblemCategory::Formula)
+        .into_iter()
+        .map(|info| info.name)
+        .collect();
+    assert!(formula.contains(&"Satisfiability"));
+
+    let csp: Vec<_> = problems_in_category(ProblemCategory::Csp)
+        .into_iter()
+        .map(|info| info.name)
+        .collect();
+    assert_eq!(csp, vec!["CSP"]);
+
+    let total: usize = ProblemCategory::ALL
+        .into_iter()
+        .map(|category| problems_in_category(category).len())
+        .sum();
+    assert_eq!(total, problem_types().len());
+}
+
+#[test]
+fn problem_type_category_from_module_path() {
+    let mis = find_problem_type("MaximumIndependentSet").unwrap();
+    assert_eq!(mis.category(), Some(ProblemCategory::Graph));
+    let qubo = find_problem_type("QUBO").unwrap();
+    assert_eq!(qubo.category(), Some(ProblemCategory::Algebraic));
+}
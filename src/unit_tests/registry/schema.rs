@@ -128,3 +128,245 @@ fn test_decision_problem_variants_registered() {
         "DecisionMinimumDominatingSet default variant should be registered"
     );
 }
+
+/// Minimal validator for the JSON Schema keywords `problem_json_schema` emits.
+fn validate(schema: &serde_json::Value, value: &serde_json::Value, at: &str) -> Result<(), String> {
+    use serde_json::Value;
+
+    let Some(schema) = schema.as_object() else {
+        return Ok(());
+    };
+    let fail = |msg: String| Err(format!("{at}: {msg}"));
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        let ok = match expected {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            "number" => value.is_number(),
+            "integer" => {
+                value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|x| x.fract() == 0.0)
+            }
+            other => panic!("unsupported type keyword {other}"),
+        };
+        if !ok {
+            return fail(format!("expected {expected}, got {value}"));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            return fail(format!("expected {expected}, got {value}"));
+        }
+    }
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            return fail(format!("{value} is not one of {options:?}"));
+        }
+    }
+    if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64) {
+        if value.as_f64().is_some_and(|x| x < minimum) {
+            return fail(format!("{value} is below {minimum}"));
+        }
+    }
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        assert_eq!(pattern, "^[0-9]+$", "unsupported pattern");
+        let text = value.as_str().unwrap_or_default();
+        if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+            return fail(format!("{value} does not match {pattern}"));
+        }
+    }
+    for keyword in ["anyOf", "oneOf"] {
+        if let Some(branches) = schema.get(keyword).and_then(Value::as_array) {
+            let matched = branches
+                .iter()
+                .filter(|branch| validate(branch, value, at).is_ok())
+                .count();
+            if matched == 0 || (keyword == "oneOf" && matched > 1) {
+                return fail(format!("{value} matches {matched} {keyword} branches"));
+            }
+        }
+    }
+    if let Some(object) = value.as_object() {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for key in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let key = key.as_str().unwrap();
+            if !object.contains_key(key) {
+                return fail(format!("missing required property {key}"));
+            }
+        }
+        for (key, item) in object {
+            match properties.and_then(|p| p.get(key)) {
+                Some(property) => validate(property, item, &format!("{at}.{key}"))?,
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    return fail(format!("unexpected property {key}"));
+                }
+                None => {}
+            }
+        }
+    }
+    if let Some(items) = value.as_array() {
+        let len = items.len() as u64;
+        if schema
+            .get("minItems")
+            .and_then(Value::as_u64)
+            .is_some_and(|n| len < n)
+            || schema
+                .get("maxItems")
+                .and_then(Value::as_u64)
+                .is_some_and(|n| len > n)
+        {
+            return fail(format!("array of length {len} violates its size bounds"));
+        }
+        let prefix = schema
+            .get("prefixItems")
+            .and_then(Value::as_array)
+            .map_or(&[][..], Vec::as_slice);
+        for (i, item) in items.iter().enumerate() {
+            let item_schema = prefix.get(i).or_else(|| schema.get("items"));
+            if let Some(item_schema) = item_schema {
+                validate(item_schema, item, &format!("{at}[{i}]"))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn problem_file(
+    name: &str,
+    variant: &BTreeMap<String, String>,
+    data: serde_json::Value,
+) -> serde_json::Value {
+    serde_json::json!({ "type": name, "variant": variant, "data": data })
+}
+
+#[test]
+fn test_problem_json_schema_for_mis_default_variant() {
+    let variant = BTreeMap::from([
+        ("graph".to_string(), "SimpleGraph".to_string()),
+        ("weight".to_string(), "i32".to_string()),
+    ]);
+    let schema = problem_json_schema("MaximumIndependentSet", Some(&variant)).unwrap();
+    assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+    assert_eq!(
+        schema["properties"]["type"]["const"],
+        "MaximumIndependentSet"
+    );
+    let data = &schema["properties"]["data"];
+    assert_eq!(
+        data["properties"]["graph"]["properties"]["edges"]["items"]["prefixItems"][0]["type"],
+        "integer"
+    );
+    assert_eq!(data["properties"]["weights"]["items"]["type"], "integer");
+
+    let file = problem_file(
+        "MaximumIndependentSet",
+        &variant,
+        serde_json::json!({ "graph": { "num_vertices": 3, "edges": [[0, 1], [1, 2]] }, "weights": [1, 2, 3] }),
+    );
+    assert_eq!(validate(&schema, &file, "$"), Ok(()));
+
+    // Wrong field type: weights must be integers for the i32 variant.
+    let mut wrong = file.clone();
+    wrong["data"]["weights"] = serde_json::json!(["a", "b", "c"]);
+    assert!(validate(&schema, &wrong, "$")
+        .unwrap_err()
+        .contains("weights"));
+    // An edge must be a pair.
+    let mut wrong = file.clone();
+    wrong["data"]["graph"]["edges"] = serde_json::json!([[0, 1, 2]]);
+    assert!(validate(&schema, &wrong, "$").is_err());
+    // Missing field.
+    let mut wrong = file;
+    wrong["data"].as_object_mut().unwrap().remove("graph");
+    assert!(validate(&schema, &wrong, "$")
+        .unwrap_err()
+        .contains("graph"));
+}
+
+#[test]
+fn test_problem_json_schema_without_variant_accepts_every_variant() {
+    let schema = problem_json_schema("MaximumIndependentSet", None).unwrap();
+    let branches = schema["anyOf"].as_array().unwrap();
+    assert!(branches.len() > 1);
+
+    let udg = BTreeMap::from([
+        ("graph".to_string(), "UnitDiskGraph".to_string()),
+        ("weight".to_string(), "One".to_string()),
+    ]);
+    if find_variant_entry("MaximumIndependentSet", &udg).is_some() {
+        let file = problem_file(
+            "MaximumIndependentSet",
+            &udg,
+            serde_json::json!({
+                "graph": { "positions": [[0.0, 0.0], [0.5, 0.0]], "radius": 1.0, "edges": [[0, 1]] },
+                "weights": [1, 1],
+            }),
+        );
+        assert_eq!(validate(&schema, &file, "$"), Ok(()));
+        let only_udg = problem_json_schema("MaximumIndependentSet", Some(&udg)).unwrap();
+        assert_eq!(validate(&only_udg, &file, "$"), Ok(()));
+    }
+}
+
+#[test]
+fn test_problem_json_schema_unknown_problem_or_variant() {
+    assert!(problem_json_schema("NoSuchProblem", None).is_none());
+    let bogus = BTreeMap::from([("graph".to_string(), "NoSuchGraph".to_string())]);
+    assert!(problem_json_schema("MaximumIndependentSet", Some(&bogus)).is_none());
+}
+
+#[test]
+fn test_type_schema_parses_nested_types() {
+    let variant = BTreeMap::from([("weight".to_string(), "f64".to_string())]);
+    let schema = type_schema("Vec<(usize, Vec<W>)>", &variant);
+    assert_eq!(schema["items"]["minItems"], 2);
+    assert_eq!(schema["items"]["prefixItems"][1]["items"]["type"], "number");
+    let schema = type_schema("Vec<[usize; 3]>", &variant);
+    assert_eq!(schema["items"]["maxItems"], 3);
+    let schema = type_schema("Option<Vec<usize>>", &variant);
+    assert_eq!(schema["anyOf"][1]["type"], "null");
+    assert_eq!(
+        type_schema("SomethingUnknown", &variant),
+        serde_json::json!({})
+    );
+}
+
+#[test]
+fn test_type_schema_linear_constraint_comparisons() {
+    use crate::models::algebraic::LinearConstraint;
+
+    let schema = type_schema("LinearConstraint", &BTreeMap::new());
+    for constraint in [
+        LinearConstraint::le(vec![(0, 1.0)], 2.0),
+        LinearConstraint::range(vec![(0, 1.0)], 1.0, 2.0),
+    ] {
+        let json = serde_json::to_value(&constraint).unwrap();
+        assert_eq!(validate(&schema, &json, "$"), Ok(()));
+    }
+    let unknown_cmp = serde_json::json!({ "terms": [], "cmp": "Lt", "rhs": 1.0 });
+    assert!(validate(&schema, &unknown_cmp, "$").is_err());
+}
+
+#[cfg(feature = "example-db")]
+#[test]
+fn test_problem_json_schema_accepts_every_canonical_example() {
+    let db = crate::example_db::build_example_db().unwrap();
+    let failures: Vec<String> = db
+        .models
+        .iter()
+        .filter_map(|example| {
+            let schema = problem_json_schema(&example.problem, Some(&example.variant))?;
+            let file = problem_file(&example.problem, &example.variant, example.instance.clone());
+            validate(&schema, &file, "$")
+                .err()
+                .map(|err| format!("{} {:?}: {err}", example.problem, example.variant))
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -254,11 +254,18 @@ fn test_find_dominated_rules_returns_known_set() {
         ("KSatisfiability {k: \"K3\"}", "QUBO {weight: \"f64\"}"),
         // Knapsack -> ILP -> QUBO is better than the direct penalty reduction
         ("Knapsack", "QUBO {weight: \"f64\"}"),
+        // PaintShop -> SpinGlass -> QUBO matches the direct penalty reduction
+        ("PaintShop", "QUBO {weight: \"f64\"}"),
         // MaxMatching → MaxSetPacking → ILP is better than direct MaxMatching → ILP
         (
             "MaximumMatching {graph: \"SimpleGraph\", weight: \"i32\"}",
             "ILP {variable: \"bool\"}",
         ),
+        // MinEdgeDS → MinDS (line graph) → ILP is no larger than direct MinEdgeDS → ILP
+        (
+            "MinimumEdgeDominatingSet {graph: \"SimpleGraph\", weight: \"i32\"}",
+            "ILP {variable: \"bool\"}",
+        ),
         // ExactCoverBy3Sets → MaxSetPacking → ILP is better than direct ExactCoverBy3Sets → ILP
         ("ExactCoverBy3Sets", "ILP {variable: \"bool\"}"),
         // GraphPartitioning → MaxCut → SpinGlass → QUBO is better than direct GraphPartitioning → QUBO
@@ -271,6 +278,11 @@ fn test_find_dominated_rules_returns_known_set() {
             "KSatisfiability {k: \"K3\"}",
             "MinimumVertexCover {graph: \"SimpleGraph\", weight: \"i32\"}",
         ),
+        // Unit-weight MAX-2-SAT → weighted MAX-2-SAT (cast) → MaxCut matches the direct rule
+        (
+            "Maximum2Satisfiability {weight: \"One\"}",
+            "MaxCut {graph: \"SimpleGraph\", weight: \"i32\"}",
+        ),
     ]
     .into_iter()
     .collect();
@@ -0,0 +1,139 @@
+use super::*;
+use crate::expr::Expr;
+use crate::models::graph::{MaximumIndependentSet, MinimumVertexCover};
+use crate::rules::registry::EdgeCapabilities;
+use crate::rules::traits::{DynReductionResult, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+
+type Mis = MaximumIndependentSet<SimpleGraph, i32>;
+type Mvc = MinimumVertexCover<SimpleGraph, i32>;
+
+fn cycle_instances() -> Vec<Mis> {
+    (3..=7)
+        .map(|n| MaximumIndependentSet::new(SimpleGraph::cycle(n), vec![1; n]))
+        .collect()
+}
+
+/// Test-only reduction that covers n disjoint copies of an n-vertex graph,
+/// so its target has n^2 vertices.
+struct CopiesToMvc {
+    target: Mvc,
+}
+
+impl ReductionResult for CopiesToMvc {
+    type Source = Mis;
+    type Target = Mvc;
+
+    fn target_problem(&self) -> &Mvc {
+        &self.target
+    }
+
+    fn extract_solution(&self, target_solution: &[usize]) -> Vec<usize> {
+        target_solution[..self.target.num_vertices()].to_vec()
+    }
+}
+
+fn copies_reduce_fn(source: &dyn Any) -> Box<dyn DynReductionResult> {
+    let source = source.downcast_ref::<Mis>().unwrap();
+    let n = source.graph().num_vertices();
+    let edges = (0..n)
+        .flat_map(|copy| {
+            source
+                .graph()
+                .edges()
+                .into_iter()
+                .map(move |(u, v)| (copy * n + u, copy * n + v))
+        })
+        .collect();
+    Box::new(CopiesToMvc {
+        target: MinimumVertexCover::new(SimpleGraph::new(n * n, edges), vec![1; n * n]),
+    })
+}
+
+fn mis_size_fn(source: &dyn Any) -> ProblemSize {
+    let source = source.downcast_ref::<Mis>().unwrap();
+    ProblemSize::new(vec![
+        ("num_vertices", source.graph().num_vertices()),
+        ("num_edges", source.graph().num_edges()),
+    ])
+}
+
+/// The audit evaluates the declared overhead itself and never calls this.
+fn unused_overhead_eval_fn(_: &dyn Any) -> ProblemSize {
+    ProblemSize::new(vec![])
+}
+
+#[test]
+fn test_audit_registered_reduction_matches_declared_overhead() {
+    let report = audit_reduction::<Mis, Mvc>(&cycle_instances());
+    assert_eq!(report.source_name, "MaximumIndependentSet");
+    assert_eq!(report.target_name, "MinimumVertexCover");
+    assert_eq!(report.samples.len(), 5);
+    assert!(report.is_ok(), "{report}");
+    assert!(report.unmeasured.is_empty());
+    for field in &report.fields {
+        // The complement reduction keeps the graph, so the fit is exact.
+        assert!((field.fitted_factor - 1.0).abs() < 1e-9, "{report}");
+        assert!((field.max_ratio - 1.0).abs() < 1e-9, "{report}");
+    }
+}
+
+#[test]
+fn test_audit_flags_understated_overhead() {
+    // Declares an identity overhead although the target has n^2 vertices.
+    let entry = ReductionEntry {
+        source_name: "MaximumIndependentSet",
+        target_name: "MinimumVertexCover",
+        source_variant_fn: Mis::variant,
+        target_variant_fn: Mvc::variant,
+        overhead_fn: || {
+            ReductionOverhead::new(vec![
+                ("num_vertices", Expr::Var("num_vertices")),
+                (
+                    "num_edges",
+                    Expr::Var("num_vertices") * Expr::Var("num_edges"),
+                ),
+            ])
+        },
+        module_path: "test::audit",
+        reduce_fn: Some(copies_reduce_fn),
+        reduce_aggregate_fn: None,
+        capabilities: EdgeCapabilities::witness_only(),
+        overhead_eval_fn: unused_overhead_eval_fn,
+        source_size_fn: mis_size_fn,
+    };
+    let instances = cycle_instances();
+    let instances: Vec<&dyn Any> = instances.iter().map(|s| s as &dyn Any).collect();
+
+    let report = audit_entry(&entry, &instances, DEFAULT_AUDIT_TOLERANCE);
+    assert!(!report.is_ok());
+    let violations: Vec<&str> = report.violations().map(|f| f.field.as_str()).collect();
+    assert_eq!(violations, vec!["num_vertices"]);
+
+    // The largest instance (n = 7) is 7 times over the declared bound.
+    let vertices = &report.fields[0];
+    assert!((vertices.max_ratio - 7.0).abs() < 1e-9);
+    assert!(vertices.fitted_factor > DEFAULT_AUDIT_TOLERANCE);
+    assert!(report.to_string().contains("FAIL"));
+
+    // n * m edges match the (correct) declared edge count exactly.
+    assert!((report.fields[1].max_ratio - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_find_reduction_entry_requires_exact_variants() {
+    assert!(find_reduction_entry(
+        "MaximumIndependentSet",
+        &Mis::variant(),
+        "MinimumVertexCover",
+        &Mvc::variant()
+    )
+    .is_some());
+    assert!(find_reduction_entry(
+        "MaximumIndependentSet",
+        &Mis::variant(),
+        "MinimumVertexCover",
+        &[("graph", "PlanarGraph"), ("weight", "i32")]
+    )
+    .is_none());
+}
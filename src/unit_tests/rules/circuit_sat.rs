@@ -86,3 +86,51 @@ fn test_circuitsat_to_satisfiability_handles_multiple_outputs() {
         "multiple outputs in CircuitSAT -> Satisfiability",
     );
 }
+
+#[test]
+fn test_circuitsat_to_satisfiability_and_or_model_counts_match_dpll() {
+    use crate::solvers::BruteForce;
+
+    // r = (a AND b) OR c, together with r = NOT c: only a = b = 1, c = 0 works.
+    let satisfiable = CircuitSAT::new(Circuit::new(vec![
+        Assignment::new(
+            vec!["r".to_string()],
+            BooleanExpr::or(vec![
+                BooleanExpr::and(vec![BooleanExpr::var("a"), BooleanExpr::var("b")]),
+                BooleanExpr::var("c"),
+            ]),
+        ),
+        Assignment::new(
+            vec!["r".to_string()],
+            BooleanExpr::not(BooleanExpr::var("c")),
+        ),
+    ]));
+    // r = a AND b, r = a OR b and r = NOT a cannot hold at once.
+    let unsatisfiable = CircuitSAT::new(Circuit::new(vec![
+        Assignment::new(
+            vec!["r".to_string()],
+            BooleanExpr::and(vec![BooleanExpr::var("a"), BooleanExpr::var("b")]),
+        ),
+        Assignment::new(
+            vec!["r".to_string()],
+            BooleanExpr::or(vec![BooleanExpr::var("a"), BooleanExpr::var("b")]),
+        ),
+        Assignment::new(
+            vec!["r".to_string()],
+            BooleanExpr::not(BooleanExpr::var("a")),
+        ),
+    ]));
+
+    for (source, expected) in [(satisfiable, 1), (unsatisfiable, 0)] {
+        let reduction = ReduceTo::<Satisfiability>::reduce_to(&source);
+        let circuit_models = BruteForce::new().find_all_witnesses(&source).len() as u64;
+
+        // Tseitin variables are functionally determined by the inputs, so the
+        // CNF has exactly as many models as the circuit.
+        assert_eq!(circuit_models, expected);
+        assert_eq!(
+            reduction.target_problem().count_models(),
+            Some(circuit_models)
+        );
+    }
+}
@@ -18,6 +18,7 @@ where
         + From<i32>
         + std::ops::Mul<Output = W>
         + std::fmt::Debug
+        + num_traits::Bounded
         + NumericSize,
     <W as WeightElement>::Sum: std::fmt::Debug + serde::Serialize + serde::de::DeserializeOwned,
 {
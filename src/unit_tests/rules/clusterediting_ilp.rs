@@ -0,0 +1,87 @@
+use super::*;
+use crate::models::algebraic::{ObjectiveSense, ILP};
+use crate::models::graph::ClusterEditing;
+use crate::rules::ReduceTo;
+use crate::solvers::{BruteForce, ILPSolver};
+use crate::topology::SimpleGraph;
+use crate::traits::Problem;
+use crate::types::Min;
+
+fn canonical_instance() -> ClusterEditing<SimpleGraph, i32> {
+    let graph = SimpleGraph::new(5, vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    ClusterEditing::new(graph, vec![3, 3, 3, 1, 1, 2])
+}
+
+#[test]
+fn test_pair_index_is_lexicographic() {
+    let indices: Vec<usize> = (0..4)
+        .flat_map(|u| ((u + 1)..4).map(move |v| pair_index(4, u, v)))
+        .collect();
+    assert_eq!(indices, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_reduction_creates_expected_ilp_shape() {
+    let problem = canonical_instance();
+    let reduction: ReductionClusterEditingToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let ilp = reduction.target_problem();
+
+    // C(5, 2) pair variables, 3 * C(5, 3) transitivity constraints
+    assert_eq!(ilp.num_vars, 10);
+    assert_eq!(ilp.constraints.len(), 30);
+    assert_eq!(ilp.sense, ObjectiveSense::Minimize);
+    // Pairs (0,1) (0,2) (0,3) (0,4) (1,2) (1,3) (1,4) (2,3) (2,4) (3,4)
+    assert_eq!(
+        ilp.objective,
+        vec![
+            (0, -3.0),
+            (1, -3.0),
+            (2, 1.0),
+            (3, 1.0),
+            (4, -3.0),
+            (5, -1.0),
+            (6, 1.0),
+            (7, -1.0),
+            (8, 1.0),
+            (9, -2.0),
+        ]
+    );
+}
+
+#[test]
+fn test_clusterediting_to_ilp_closed_loop() {
+    let problem = canonical_instance();
+    let reduction: ReductionClusterEditingToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("ILP should be solvable");
+    let extracted = reduction.extract_solution(&ilp_solution);
+
+    assert_eq!(extracted, vec![0, 0, 0, 3, 3]);
+    assert_eq!(problem.evaluate(&extracted), Min(Some(2)));
+}
+
+#[test]
+fn test_solution_extraction_labels_by_smallest_member() {
+    let problem = canonical_instance();
+    let reduction: ReductionClusterEditingToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    // Clusters {0, 2} | {1, 4} | {3}
+    let mut target_solution = vec![0; 10];
+    target_solution[1] = 1; // (0, 2)
+    target_solution[6] = 1; // (1, 4)
+    assert_eq!(
+        reduction.extract_solution(&target_solution),
+        vec![0, 1, 0, 3, 1]
+    );
+}
+
+#[test]
+fn test_clusterediting_to_ilp_bf_vs_ilp() {
+    let problem = canonical_instance();
+    let reduction: ReductionClusterEditingToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    assert_eq!(
+        problem.evaluate(&BruteForce::new().find_witness(&problem).unwrap()),
+        Min(Some(2))
+    );
+    crate::rules::test_helpers::assert_bf_vs_ilp(&problem, &reduction);
+}
@@ -11,8 +11,8 @@ fn test_reduction_creates_valid_ilp() {
     // Check variable count: m + n + m*n + (m+n) = 2 + 2 + 4 + 4 = 12
     assert_eq!(ilp.num_vars, 12);
 
-    // Check constraint count: 3*m*n + 4*m + 4*n + 1 = 12 + 8 + 8 + 1 = 29
-    assert_eq!(ilp.constraints.len(), 29);
+    // Check constraint count: 3*m*n + 4*m + 4*n + 3 = 12 + 8 + 8 + 3 = 31
+    assert_eq!(ilp.constraints.len(), 31);
 
     assert_eq!(ilp.sense, ObjectiveSense::Minimize);
 }
@@ -102,36 +102,24 @@ fn test_factor_35() {
 
 #[test]
 fn test_factor_one() {
-    // 1 = 1 × 1
+    // 1 = 1 × 1 is trivial, so the ILP is infeasible
     let problem = Factoring::new(2, 2, 1);
     let reduction: ReductionFactoringToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
     let ilp = reduction.target_problem();
 
     let ilp_solver = ILPSolver::new();
-    let ilp_solution = ilp_solver.solve(ilp).expect("ILP should be solvable");
-    let extracted = reduction.extract_solution(&ilp_solution);
-
-    assert!(problem.is_valid_factorization(&extracted));
-
-    let (a, b) = problem.read_factors(&extracted);
-    assert_eq!(a * b, 1);
+    assert!(ilp_solver.solve(ilp).is_none());
 }
 
 #[test]
 fn test_factor_prime() {
-    // 7 is prime: 7 = 1 × 7 or 7 × 1
+    // 7 is prime: only the trivial 1 × 7 and 7 × 1 exist, so the ILP is infeasible
     let problem = Factoring::new(3, 3, 7);
     let reduction: ReductionFactoringToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
     let ilp = reduction.target_problem();
 
     let ilp_solver = ILPSolver::new();
-    let ilp_solution = ilp_solver.solve(ilp).expect("ILP should be solvable");
-    let extracted = reduction.extract_solution(&ilp_solution);
-
-    assert!(problem.is_valid_factorization(&extracted));
-
-    let (a, b) = problem.read_factors(&extracted);
-    assert_eq!(a * b, 7);
+    assert!(ilp_solver.solve(ilp).is_none());
 }
 
 #[test]
@@ -227,8 +215,8 @@ fn test_target_ilp_structure() {
     // num_vars = 3 + 4 + 12 + 7 = 26
     assert_eq!(ilp.num_vars, 26);
 
-    // num_constraints = 3*12 + 4*3 + 4*4 + 1 = 36 + 12 + 16 + 1 = 65
-    assert_eq!(ilp.constraints.len(), 65);
+    // num_constraints = 3*12 + 4*3 + 4*4 + 3 = 36 + 12 + 16 + 3 = 67
+    assert_eq!(ilp.constraints.len(), 67);
 }
 
 #[test]
@@ -263,14 +251,15 @@ fn test_asymmetric_bit_widths() {
 
 #[test]
 fn test_constraint_count_formula() {
-    // Verify constraint count matches formula: 3*m*n + 4*m + 4*n + 1
-    // (3*m*n McCormick + (m+n) bit equations + 1 final carry + (m+n) binary bounds + 2*(m+n) carry bounds)
+    // Verify constraint count matches formula: 3*m*n + 4*m + 4*n + 3
+    // (3*m*n McCormick + (m+n) bit equations + 1 final carry + (m+n) binary bounds + 2*(m+n) carry bounds
+    //  + 2 nontrivial-factor bounds)
     for (m, n) in [(2, 2), (3, 3), (2, 4), (4, 2)] {
         let problem = Factoring::new(m, n, 1);
         let reduction: ReductionFactoringToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
         let ilp = reduction.target_problem();
 
-        let expected = 3 * m * n + 4 * m + 4 * n + 1;
+        let expected = 3 * m * n + 4 * m + 4 * n + 3;
         assert_eq!(
             ilp.constraints.len(),
             expected,
@@ -304,3 +293,25 @@ fn test_factoring_to_ilp_bf_vs_ilp() {
     let reduction: ReductionFactoringToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
     crate::rules::test_helpers::assert_bf_vs_ilp(&problem, &reduction);
 }
+
+#[test]
+fn test_factoring_to_ilp_balanced_bits() {
+    // 3233 = 53 × 61 with both factors exactly 6 bits; 1 × 3233 is excluded.
+    let problem = Factoring::try_new(6, 12, 3233, Some(6), Some(6)).unwrap();
+    let unconstrained: ReductionFactoringToILP =
+        ReduceTo::<ILP<i32>>::reduce_to(&Factoring::new(6, 12, 3233));
+    let reduction: ReductionFactoringToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+    // One pin for each top bit plus six zero pins above q's width.
+    assert_eq!(
+        reduction.target_problem().constraints.len(),
+        unconstrained.target_problem().constraints.len() + 8
+    );
+
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("ILP should be solvable");
+    let extracted = reduction.extract_solution(&ilp_solution);
+    assert!(problem.is_valid_factorization(&extracted));
+    let (a, b) = problem.read_factors(&extracted);
+    assert!((a, b) == (53, 61) || (a, b) == (61, 53));
+}
@@ -0,0 +1,33 @@
+//! Golden-file tests for serialized reduction output.
+//!
+//! Each test reduces a fixed instance and compares the target against a
+//! canonical JSON file under `tests/data/golden/`. Regenerate with
+//! `UPDATE_GOLDEN=1 cargo test golden` after an intentional change.
+
+use crate::models::graph::{MaxCut, MaximumIndependentSet};
+use crate::rules::test_helpers::assert_reduction_matches_golden;
+use crate::topology::SimpleGraph;
+
+fn triangle() -> SimpleGraph {
+    SimpleGraph::new(3, vec![(0, 1), (1, 2), (0, 2)])
+}
+
+#[test]
+fn test_golden_maximumindependentset_to_qubo_triangle() {
+    let source = MaximumIndependentSet::new(triangle(), vec![1i32, 2, 3]);
+    assert_reduction_matches_golden(
+        &source,
+        "QUBO",
+        "tests/data/golden/maximumindependentset_to_qubo_triangle.json",
+    );
+}
+
+#[test]
+fn test_golden_maxcut_to_spinglass_triangle() {
+    let source = MaxCut::new(triangle(), vec![1i32, 2, 3]);
+    assert_reduction_matches_golden(
+        &source,
+        "SpinGlass",
+        "tests/data/golden/maxcut_to_spinglass_triangle.json",
+    );
+}
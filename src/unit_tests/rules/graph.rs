@@ -232,6 +232,66 @@ fn build_two_node_graph(
     }
 }
 
+#[test]
+fn test_find_paths_up_to_mode_limits_matching_paths_only() {
+    // Source -> Target is Turing-only; Source -> Middle -> Target is the one
+    // witness route. The limit must count witness paths, not every simple path.
+    let nodes = ["Source", "Middle", "Target"]
+        .into_iter()
+        .map(|name| VariantNode {
+            name,
+            variant: BTreeMap::new(),
+            complexity: "",
+        })
+        .collect();
+    let edge = |capabilities| ReductionEdgeData {
+        overhead: crate::rules::registry::ReductionOverhead::default(),
+        reduce_fn: None,
+        reduce_aggregate_fn: None,
+        capabilities,
+    };
+    let mut graph = DiGraph::new();
+    let source = graph.add_node(0);
+    let middle = graph.add_node(1);
+    let target = graph.add_node(2);
+    graph.add_edge(source, middle, edge(EdgeCapabilities::witness_only()));
+    graph.add_edge(middle, target, edge(EdgeCapabilities::witness_only()));
+    graph.add_edge(source, target, edge(EdgeCapabilities::turing()));
+    let graph = ReductionGraph {
+        graph,
+        nodes,
+        name_to_nodes: HashMap::from([
+            ("Source", vec![source]),
+            ("Middle", vec![middle]),
+            ("Target", vec![target]),
+        ]),
+        default_variants: HashMap::new(),
+    };
+
+    // The Turing-only direct path is enumerated first.
+    let first = all_simple_paths::<Vec<NodeIndex>, _, std::hash::RandomState>(
+        &graph.graph,
+        source,
+        target,
+        0,
+        None,
+    )
+    .next();
+    assert_eq!(first, Some(vec![source, target]));
+
+    let empty = BTreeMap::new();
+    let paths = graph.find_paths_up_to_mode(
+        "Source",
+        &empty,
+        "Target",
+        &empty,
+        ReductionMode::Witness,
+        1,
+    );
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].type_names(), vec!["Source", "Middle", "Target"]);
+}
+
 #[test]
 fn test_find_direct_path() {
     let graph = ReductionGraph::new();
@@ -888,7 +948,7 @@ fn test_nae_sat_to_maxcut_reduction_registered() {
 fn test_maximum2satisfiability_to_maxcut_reduction_registered() {
     let graph = ReductionGraph::new();
 
-    assert!(graph.has_direct_reduction::<Maximum2Satisfiability, MaxCut<SimpleGraph, i32>>());
+    assert!(graph.has_direct_reduction::<Maximum2Satisfiability<One>, MaxCut<SimpleGraph, i32>>());
 }
 
 #[test]
@@ -1650,6 +1710,23 @@ fn test_compute_source_size() {
     assert_eq!(size.get("num_edges"), Some(3));
 }
 
+#[test]
+fn test_compute_source_size_for_variant() {
+    let problem = MaximumIndependentSet::<SimpleGraph, One>::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        vec![One; 4],
+    );
+    let variant =
+        ReductionGraph::variant_to_map(&MaximumIndependentSet::<SimpleGraph, One>::variant());
+    let size = ReductionGraph::compute_source_size_for_variant(
+        "MaximumIndependentSet",
+        &variant,
+        &problem,
+    );
+    assert_eq!(size.get("num_vertices"), Some(4));
+    assert_eq!(size.get("num_edges"), Some(3));
+}
+
 #[test]
 fn test_compute_source_size_unknown_problem() {
     let problem = 42u32;
@@ -1730,3 +1807,57 @@ fn test_evaluate_path_overhead_multistep() {
     assert_eq!(final_size.get("num_vertices"), None);
     assert_eq!(final_size.get("num_edges"), None);
 }
+
+#[test]
+fn test_condensation_groups_mutual_reductions() {
+    let graph = ReductionGraph::new();
+    let condensed = graph.condensation();
+
+    let mis = condensed
+        .cluster_of(
+            MaximumIndependentSet::<SimpleGraph, i32>::NAME,
+            &ReductionGraph::variant_to_map(&MaximumIndependentSet::<SimpleGraph, i32>::variant()),
+        )
+        .expect("MIS should be a graph node");
+    let mvc = condensed
+        .cluster_of(
+            MinimumVertexCover::<SimpleGraph, i32>::NAME,
+            &ReductionGraph::variant_to_map(&MinimumVertexCover::<SimpleGraph, i32>::variant()),
+        )
+        .expect("MVC should be a graph node");
+    assert_eq!(mis, mvc, "IS and VC reduce to each other");
+    assert!(condensed.clusters[mis].is_nontrivial());
+
+    let sat = condensed
+        .cluster_of(
+            Satisfiability::NAME,
+            &ReductionGraph::variant_to_map(&Satisfiability::variant()),
+        )
+        .expect("SAT should be a graph node");
+    assert_ne!(sat, mis);
+    assert!(sat < mis, "SAT should come before IS in topological order");
+    assert!(condensed.reaches(sat, mis));
+    assert!(!condensed.reaches(mis, sat));
+}
+
+#[test]
+fn test_condensation_is_a_topologically_sorted_partition() {
+    let graph = ReductionGraph::new();
+    let condensed = graph.condensation();
+
+    let total: usize = condensed.clusters.iter().map(|c| c.members.len()).sum();
+    assert_eq!(total, graph.num_variant_nodes());
+    for &(src, dst) in &condensed.edges {
+        assert!(src < dst, "edge {src} -> {dst} violates topological order");
+    }
+    let mut deduped = condensed.edges.clone();
+    deduped.dedup();
+    assert_eq!(deduped, condensed.edges);
+
+    // Deterministic across rebuilds.
+    let again = ReductionGraph::new().condensation();
+    assert_eq!(
+        serde_json::to_value(&condensed).unwrap(),
+        serde_json::to_value(&again).unwrap()
+    );
+}
@@ -0,0 +1,270 @@
+use super::*;
+use crate::models::algebraic::{Comparison, ObjectiveSense, ILP};
+use crate::models::graph::{MaximumClique, MaximumMatching, MinimumDominatingSet};
+use crate::models::set::{MaximumSetPacking, MinimumSetCovering};
+use crate::rules::traits::{ReduceTo, ReductionResult};
+use crate::topology::{Graph, SimpleGraph};
+
+fn assert_same_ilp(actual: &ILP<bool>, expected: &ILP<bool>) {
+    assert_eq!(actual.num_vars, expected.num_vars);
+    assert_eq!(actual.constraints, expected.constraints);
+    assert_eq!(actual.objective, expected.objective);
+    assert_eq!(actual.sense, expected.sense);
+}
+
+fn reduce(problem: &impl ReduceTo<ILP<bool>>) -> ILP<bool> {
+    problem.reduce_to().target_problem().clone()
+}
+
+fn weight_objective(weights: &[i32]) -> Vec<(usize, f64)> {
+    weights
+        .iter()
+        .enumerate()
+        .map(|(i, &w)| (i, w as f64))
+        .collect()
+}
+
+fn graphs() -> Vec<SimpleGraph> {
+    vec![
+        SimpleGraph::new(1, vec![]),
+        SimpleGraph::new(3, vec![(0, 1), (1, 2), (0, 2)]),
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        SimpleGraph::new(5, vec![(0, 1), (0, 2), (0, 3), (3, 4)]),
+        SimpleGraph::new(6, vec![(0, 1), (1, 2), (3, 4)]),
+    ]
+}
+
+fn set_systems() -> Vec<(usize, Vec<Vec<usize>>)> {
+    vec![
+        (4, vec![vec![0, 1], vec![1, 2], vec![2, 3]]),
+        (5, vec![vec![0, 1, 2], vec![2, 3], vec![3, 4], vec![0, 4]]),
+        (3, vec![vec![0], vec![0, 1, 2], vec![1], vec![2]]),
+    ]
+}
+
+#[test]
+fn test_builder_allocates_consecutive_blocks() {
+    let mut b = IlpBuilder::<bool>::new();
+    let x = b.binary_vars(3);
+    let y = b.binary_vars(2);
+    assert_eq!(x.indices(), 0..3);
+    assert_eq!(y.at(0), 3);
+    assert_eq!(y.at(1), 4);
+    assert_eq!(y.len(), 2);
+    assert_eq!(b.num_vars(), 5);
+
+    let mut ints = IlpBuilder::<i32>::new();
+    assert_eq!(ints.int_vars(4).indices(), 0..4);
+    assert!(ints.int_vars(0).is_empty());
+}
+
+#[test]
+fn test_builder_constraints_and_objective() {
+    let mut b = IlpBuilder::<bool>::new();
+    let x = b.binary_vars(3);
+    b.add_le("a", vec![(x.at(0), 1.0), (x.at(1), 1.0)], 1.0)
+        .add_ge("b", vec![(x.at(2), 2.0)], 1.0)
+        .add_eq("c", vec![(x.at(1), 1.0), (x.at(2), -1.0)], 0.0)
+        .fix(x.at(0), 1.0)
+        .maximize(vec![(x.at(1), 3.0)]);
+    assert_eq!(b.num_constraints(), 4);
+
+    let ilp = b.build();
+    assert_eq!(ilp.num_vars, 3);
+    let cmps: Vec<Comparison> = ilp.constraints.iter().map(|c| c.cmp).collect();
+    assert_eq!(
+        cmps,
+        vec![
+            Comparison::Le,
+            Comparison::Ge,
+            Comparison::Eq,
+            Comparison::Eq
+        ]
+    );
+    assert_eq!(ilp.constraints[3].terms, vec![(0, 1.0)]);
+    assert_eq!(ilp.constraints[3].rhs, 1.0);
+    assert_eq!(ilp.objective, vec![(1, 3.0)]);
+    assert_eq!(ilp.sense, ObjectiveSense::Maximize);
+}
+
+#[test]
+fn test_builder_default_is_empty_minimization() {
+    let ilp = IlpBuilder::<bool>::default().build();
+    assert_eq!(ilp.num_vars, 0);
+    assert!(ilp.constraints.is_empty());
+    assert!(ilp.objective.is_empty());
+    assert_eq!(ilp.sense, ObjectiveSense::Minimize);
+}
+
+#[test]
+#[should_panic(expected = "constraint cover[7]: variable 2 out of range (2 variables allocated)")]
+fn test_builder_names_constraint_with_unallocated_variable() {
+    let mut b = IlpBuilder::<bool>::new();
+    b.binary_vars(2);
+    b.add_ge(format_args!("cover[{}]", 7), vec![(0, 1.0), (2, 1.0)], 1.0);
+}
+
+#[test]
+#[should_panic(expected = "objective: coefficient of variable 0 is NaN")]
+fn test_builder_rejects_non_finite_objective() {
+    let mut b = IlpBuilder::<bool>::new();
+    b.binary_vars(1);
+    b.minimize(vec![(0, f64::NAN)]);
+}
+
+#[test]
+#[should_panic(expected = "variable 2 out of range for a block of 2")]
+fn test_var_block_bounds_checked() {
+    let mut b = IlpBuilder::<bool>::new();
+    b.binary_vars(1);
+    b.binary_vars(2).at(2);
+}
+
+#[test]
+fn test_term_helpers() {
+    assert_eq!(unit_terms([2, 5]), vec![(2, 1.0), (5, 1.0)]);
+    assert_eq!(weighted_terms(&[4, -1]), vec![(0, 4.0), (1, -1.0)]);
+}
+
+// Golden comparisons: each refactored reduction must emit exactly the ILP the
+// previous hand-rolled encoding produced, constraint for constraint.
+
+#[test]
+fn test_golden_maximumsetpacking_ilp() {
+    for (_, sets) in set_systems() {
+        let weights: Vec<i32> = (1..=sets.len() as i32).collect();
+        let problem = MaximumSetPacking::with_weights(sets, weights.clone());
+
+        let universe = problem.universe_size();
+        let mut elem_to_sets: Vec<Vec<usize>> = vec![Vec::new(); universe];
+        for (i, set) in problem.sets().iter().enumerate() {
+            for &e in set {
+                elem_to_sets[e].push(i);
+            }
+        }
+        let constraints = elem_to_sets
+            .into_iter()
+            .filter(|sets| sets.len() > 1)
+            .map(|sets| LinearConstraint::le(sets.into_iter().map(|i| (i, 1.0)).collect(), 1.0))
+            .collect();
+        let expected = ILP::new(
+            problem.num_sets(),
+            constraints,
+            weight_objective(&weights),
+            ObjectiveSense::Maximize,
+        );
+
+        assert_same_ilp(&reduce(&problem), &expected);
+    }
+}
+
+#[test]
+fn test_golden_minimumsetcovering_ilp() {
+    for (universe, sets) in set_systems() {
+        let weights: Vec<i32> = (1..=sets.len() as i32).rev().collect();
+        let requirements = (0..universe).map(|e| 1 + e % 2).collect();
+        for problem in [
+            MinimumSetCovering::with_weights(universe, sets.clone(), weights.clone()),
+            MinimumSetCovering::with_weights(universe, sets.clone(), weights.clone())
+                .with_coverage_requirements(requirements),
+        ] {
+            let constraints = (0..universe)
+                .map(|element| {
+                    let terms = problem
+                        .sets()
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, set)| set.contains(&element))
+                        .map(|(j, _)| (j, 1.0))
+                        .collect();
+                    LinearConstraint::ge(terms, problem.coverage_requirement(element) as f64)
+                })
+                .collect();
+            let expected = ILP::new(
+                problem.num_sets(),
+                constraints,
+                weight_objective(&weights),
+                ObjectiveSense::Minimize,
+            );
+
+            assert_same_ilp(&reduce(&problem), &expected);
+        }
+    }
+}
+
+#[test]
+fn test_golden_minimumdominatingset_ilp() {
+    for graph in graphs() {
+        let n = graph.num_vertices();
+        let weights: Vec<i32> = (0..n as i32).map(|i| 2 * i + 1).collect();
+        let problem = MinimumDominatingSet::new(graph, weights.clone());
+
+        let constraints = (0..n)
+            .map(|v| {
+                let mut terms = vec![(v, 1.0)];
+                for neighbor in problem.neighbors(v) {
+                    terms.push((neighbor, 1.0));
+                }
+                LinearConstraint::ge(terms, 1.0)
+            })
+            .collect();
+        let expected = ILP::new(
+            n,
+            constraints,
+            weight_objective(&weights),
+            ObjectiveSense::Minimize,
+        );
+
+        assert_same_ilp(&reduce(&problem), &expected);
+    }
+}
+
+#[test]
+fn test_golden_maximumclique_ilp() {
+    for graph in graphs() {
+        let n = graph.num_vertices();
+        let weights: Vec<i32> = (0..n as i32).map(|i| 3 - i).collect();
+        let problem = MaximumClique::new(graph, weights.clone());
+
+        let mut constraints = Vec::new();
+        for u in 0..n {
+            for v in (u + 1)..n {
+                if !problem.graph().has_edge(u, v) {
+                    constraints.push(LinearConstraint::le(vec![(u, 1.0), (v, 1.0)], 1.0));
+                }
+            }
+        }
+        let expected = ILP::new(
+            n,
+            constraints,
+            weight_objective(&weights),
+            ObjectiveSense::Maximize,
+        );
+
+        assert_same_ilp(&reduce(&problem), &expected);
+    }
+}
+
+#[test]
+fn test_golden_maximummatching_ilp() {
+    for graph in graphs() {
+        let m = graph.num_edges();
+        let weights: Vec<i32> = (1..=m as i32).collect();
+        let problem = MaximumMatching::new(graph, weights.clone());
+
+        let v2e = problem.vertex_to_edges();
+        let constraints = (0..problem.graph().num_vertices())
+            .filter_map(|vertex| v2e.get(&vertex))
+            .filter(|edges| !edges.is_empty())
+            .map(|edges| LinearConstraint::le(edges.iter().map(|&e| (e, 1.0)).collect(), 1.0))
+            .collect();
+        let expected = ILP::new(
+            m,
+            constraints,
+            weight_objective(&weights),
+            ObjectiveSense::Maximize,
+        );
+
+        assert_same_ilp(&reduce(&problem), &expected);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::models::algebraic::{LinearConstraint, ObjectiveSense, ILP};
+use crate::models::algebraic::{Comparison, LinearConstraint, ObjectiveSense, ILP};
 use crate::rules::traits::{ReduceTo, ReductionResult};
 use crate::solvers::BruteForce;
 use crate::traits::Problem;
@@ -153,6 +153,32 @@ fn test_ilp_i32_to_ilp_bool_ge_constraint() {
     assert!((obj - 5.0).abs() < 1e-9, "optimal should be 5, got {obj}");
 }
 
+#[test]
+fn test_ilp_i32_to_ilp_bool_range_constraint() {
+    // Maximize x0 - x1, s.t. 2 <= x0 <= 5, 1 <= x1 <= 3.
+    // FBBT reads both sides of each range, so x0 gets 3 bits and x1 gets 2.
+    let source = ILP::<i32>::new(
+        2,
+        vec![
+            LinearConstraint::range(vec![(0, 1.0)], 2.0, 5.0),
+            LinearConstraint::range(vec![(1, 1.0)], 1.0, 3.0),
+        ],
+        vec![(0, 1.0), (1, -1.0)],
+        ObjectiveSense::Maximize,
+    );
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    assert_eq!(reduction.target_problem().num_vars, 5);
+    assert!(reduction
+        .target_problem()
+        .constraints
+        .iter()
+        .all(|c| matches!(c.cmp, Comparison::Range)));
+
+    let (config, obj) = solve_via_bool(&source).expect("should find optimal");
+    assert_eq!(config, vec![5, 1]);
+    assert!((obj - 4.0).abs() < 1e-9, "optimal should be 4, got {obj}");
+}
+
 #[test]
 fn test_ilp_i32_to_ilp_bool_infeasible() {
     // x0 >= 3 AND x0 <= 1 => infeasible
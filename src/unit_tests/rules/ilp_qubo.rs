@@ -160,6 +160,34 @@ fn test_ilp_to_qubo_le_with_slack() {
     assert_eq!(best.iter().sum::<usize>(), 2);
 }
 
+#[test]
+fn test_ilp_to_qubo_range_with_capped_slack() {
+    // 1 <= x0 + x1 + x2 + x3 <= 2 becomes x0 + x1 + x2 + x3 - s = 1 with
+    // s ∈ {0, 1}: a plain power-of-two slack for max_lhs - lo = 3 would let
+    // the sum reach 4.
+    let terms = vec![(0, 1.0), (1, 1.0), (2, 1.0), (3, 1.0)];
+    let solver = BruteForce::new();
+
+    for (sense, expected_sum) in [(ObjectiveSense::Maximize, 2), (ObjectiveSense::Minimize, 1)] {
+        let ilp = ILP::<bool>::new(
+            4,
+            vec![LinearConstraint::range(terms.clone(), 1.0, 2.0)],
+            vec![(0, 1.0), (1, 2.0), (2, 3.0), (3, 4.0)],
+            sense,
+        );
+        let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&ilp);
+        let qubo = reduction.target_problem();
+        assert_eq!(qubo.num_variables(), 5);
+
+        for sol in solver.find_all_witnesses(qubo) {
+            let extracted = reduction.extract_solution(&sol);
+            let values: Vec<i64> = extracted.iter().map(|&x| x as i64).collect();
+            assert!(ilp.is_feasible(&values));
+            assert_eq!(extracted.iter().sum::<usize>(), expected_sum);
+        }
+    }
+}
+
 #[test]
 fn test_ilp_to_qubo_structure() {
     let ilp = ILP::<bool>::new(
@@ -174,3 +202,113 @@ fn test_ilp_to_qubo_structure() {
     // Verify QUBO has appropriate structure
     assert!(qubo.num_variables() >= ilp.num_vars);
 }
+
+#[test]
+fn test_ilp_to_qubo_auto_penalty_integer_data() {
+    let ilp = ILP::<bool>::new(
+        2,
+        vec![LinearConstraint::le(vec![(0, 2.0), (1, 3.0)], 4.0)],
+        vec![(0, 1.0), (1, -2.5)],
+        ObjectiveSense::Minimize,
+    );
+    // δ = 1, so P = Σ|c_j| + 1
+    assert_eq!(ReductionILPToQUBO::auto_penalty(&ilp), 4.5);
+    let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&ilp);
+    assert_eq!(reduction.penalty(), 4.5);
+}
+
+#[test]
+fn test_ilp_to_qubo_auto_penalty_fixes_fractional_constraint() {
+    // maximize 10*x0 + 10*x1 s.t. 0.5*x0 + 0.5*x1 <= 0.5.
+    // A violation has residual 0.5, so the penalty is only P/4.
+    let ilp = ILP::<bool>::new(
+        2,
+        vec![LinearConstraint::le(vec![(0, 0.5), (1, 0.5)], 0.5)],
+        vec![(0, 10.0), (1, 10.0)],
+        ObjectiveSense::Maximize,
+    );
+    let solver = BruteForce::new();
+
+    // The fixed weight 1 + Σ|c| + Σ|b| = 21.5 is too small: choosing both
+    // variables gains 10 and costs only 21.5 / 4.
+    let fixed = ReductionILPToQUBO::with_penalty(&ilp, 21.5);
+    let witnesses = solver.find_all_witnesses(fixed.target_problem());
+    assert!(witnesses.iter().any(|sol| {
+        let values: Vec<i64> = fixed
+            .extract_solution(sol)
+            .iter()
+            .map(|&x| x as i64)
+            .collect();
+        !ilp.is_feasible(&values)
+    }));
+
+    // δ = 0.1, so P = (20 + 1) / 0.01
+    let auto = ReduceTo::<QUBO<f64>>::reduce_to(&ilp);
+    assert!((auto.penalty() - 2100.0).abs() < 1e-6);
+    let witnesses = solver.find_all_witnesses(auto.target_problem());
+    assert!(!witnesses.is_empty());
+    for sol in witnesses {
+        let extracted = auto.extract_solution(&sol);
+        let values: Vec<i64> = extracted.iter().map(|&x| x as i64).collect();
+        assert!(ilp.is_feasible(&values));
+        assert_eq!(extracted.iter().sum::<usize>(), 1);
+    }
+}
+
+fn off_grid_ilp() -> ILP<bool> {
+    // 1/3 lies on no grid 10^-d with d <= 6.
+    ILP::<bool>::new(
+        2,
+        vec![LinearConstraint::le(vec![(0, 1.0 / 3.0), (1, 1.0)], 1.0)],
+        vec![(0, 1.0), (1, 1.0)],
+        ObjectiveSense::Maximize,
+    )
+}
+
+#[test]
+fn test_ilp_to_qubo_guarantees_feasibility_on_grid() {
+    let ilp = off_grid_ilp();
+    let on_grid = ILP::<bool>::new(
+        2,
+        vec![LinearConstraint::le(vec![(0, 0.5), (1, 1.0)], 1.0)],
+        ilp.objective.clone(),
+        ObjectiveSense::Maximize,
+    );
+    // δ = 0.1 and Σ|c| = 2: P · 0.01 must exceed 2.
+    assert!(ReduceTo::<QUBO<f64>>::reduce_to(&on_grid).guarantees_feasibility());
+    assert!(ReductionILPToQUBO::with_penalty(&on_grid, 250.0).guarantees_feasibility());
+    assert!(!ReductionILPToQUBO::with_penalty(&on_grid, 150.0).guarantees_feasibility());
+}
+
+#[test]
+fn test_ilp_to_qubo_off_grid_falls_back_to_unit_slack() {
+    let ilp = off_grid_ilp();
+    let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&ilp);
+
+    // δ = 1 fallback: P = Σ|c| + 1, and no guarantee even for large penalties.
+    assert_eq!(ReductionILPToQUBO::auto_penalty(&ilp), 3.0);
+    assert_eq!(reduction.penalty(), 3.0);
+    assert!(!reduction.guarantees_feasibility());
+    assert!(!ReductionILPToQUBO::with_penalty(&ilp, 1e6).guarantees_feasibility());
+
+    // The slack range 1 - 0 = 1 takes one unit bit.
+    assert_eq!(reduction.target_problem().num_variables(), 3);
+}
+
+#[test]
+fn test_ilp_to_qubo_off_grid_range_slack_is_floored() {
+    // 0 <= x0 + 2/3 x1 <= 1.8 with Ax <= 5/3: rounding would allow a slack
+    // of 2 > 5/3, flooring caps it at 1.
+    let ilp = ILP::<bool>::new(
+        2,
+        vec![LinearConstraint::range(
+            vec![(0, 1.0), (1, 2.0 / 3.0)],
+            0.0,
+            1.8,
+        )],
+        vec![(0, 1.0), (1, 1.0)],
+        ObjectiveSense::Maximize,
+    );
+    let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&ilp);
+    assert_eq!(reduction.target_problem().num_variables(), 3);
+}
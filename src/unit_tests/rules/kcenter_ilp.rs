@@ -0,0 +1,73 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+
+fn path_distances(n: usize) -> Vec<Vec<u64>> {
+    (0..n)
+        .map(|i| (0..n).map(|j| i.abs_diff(j) as u64).collect())
+        .collect()
+}
+
+#[test]
+fn test_reduction_creates_valid_ilp() {
+    // Path on 4 vertices: radii 0, 1, 2, 3.
+    let problem = KCenter::<KN>::with_k(path_distances(4), 2);
+    let reduction: ReductionKCenterToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let ilp = reduction.target_problem();
+    // 4 center variables + 3 threshold variables
+    assert_eq!(ilp.num_vars, 7);
+    // 1 cardinality + 4 sites * 3 thresholds
+    assert_eq!(ilp.constraints.len(), 13);
+    assert_eq!(ilp.sense, ObjectiveSense::Minimize);
+    assert_eq!(ilp.objective, vec![(4, 1.0), (5, 1.0), (6, 1.0)]);
+}
+
+#[test]
+fn test_kcenter_to_ilp_optimum_matches_brute_force() {
+    for k in 1..=3 {
+        let problem = KCenter::<KN>::with_k(path_distances(5), k);
+        let reduction: ReductionKCenterToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+        let solver = BruteForce::new();
+
+        let ilp_witness = solver
+            .find_witness(reduction.target_problem())
+            .expect("ILP should be feasible");
+        let extracted = reduction.extract_solution(&ilp_witness);
+        assert_eq!(
+            problem.evaluate(&extracted),
+            solver.solve(&problem),
+            "k = {k}"
+        );
+
+        // The ILP objective equals the covering radius.
+        let Min(Some(radius)) = problem.evaluate(&extracted) else {
+            panic!("extracted solution must be feasible");
+        };
+        let objective: f64 = reduction
+            .target_problem()
+            .objective
+            .iter()
+            .map(|&(var, coef)| coef * ilp_witness[var] as f64)
+            .sum();
+        assert_eq!(objective, radius as f64, "k = {k}");
+    }
+}
+
+#[test]
+fn test_kcenter_to_ilp_uneven_radii() {
+    // Sites at positions 0, 1, 5, 11: radii steps have different widths.
+    let positions: [u64; 4] = [0, 1, 5, 11];
+    let distances: Vec<Vec<u64>> = positions
+        .iter()
+        .map(|&a| positions.iter().map(|&b| a.abs_diff(b)).collect())
+        .collect();
+    let problem = KCenter::<KN>::with_k(distances, 2);
+    let reduction: ReductionKCenterToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let solver = BruteForce::new();
+
+    let ilp_witness = solver.find_witness(reduction.target_problem()).unwrap();
+    let extracted = reduction.extract_solution(&ilp_witness);
+    assert_eq!(problem.evaluate(&extracted), Min(Some(4)));
+    assert_eq!(solver.solve(&problem), Min(Some(4)));
+}
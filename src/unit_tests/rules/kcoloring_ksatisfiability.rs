@@ -0,0 +1,91 @@
+use super::*;
+use crate::rules::test_helpers::assert_satisfaction_round_trip_from_satisfaction_target;
+use crate::solvers::{BruteForce, TwoSatSolver};
+use crate::traits::Problem;
+
+fn complete_graph(n: usize) -> SimpleGraph {
+    let edges = (0..n)
+        .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+        .collect();
+    SimpleGraph::new(n, edges)
+}
+
+#[test]
+fn test_kcoloring_to_ksatisfiability_closed_loop() {
+    // Triangular prism: 3-colorable
+    let source = KColoring::<K3, _>::new(SimpleGraph::new(
+        6,
+        vec![
+            (0, 1),
+            (1, 2),
+            (0, 2),
+            (3, 4),
+            (4, 5),
+            (3, 5),
+            (0, 3),
+            (1, 4),
+            (2, 5),
+        ],
+    ));
+    let reduction = ReduceTo::<KSatisfiability<K3>>::reduce_to(&source);
+
+    assert_satisfaction_round_trip_from_satisfaction_target(
+        &source,
+        &reduction,
+        "3-Coloring -> 3-SAT closed loop",
+    );
+}
+
+#[test]
+fn test_kcoloring_to_ksatisfiability_structure() {
+    let source = KColoring::<K3, _>::new(SimpleGraph::new(3, vec![(0, 1), (1, 2)]));
+    let reduction = ReduceTo::<KSatisfiability<K3>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.num_vars(), 3 * 3 + 1);
+    assert_eq!(target.num_clauses(), 7 * 3 + 6 * 2);
+    assert!(target.clauses().iter().all(|clause| clause.len() == 3));
+    assert!(!TwoSatSolver::is_two_cnf(target.clauses()));
+    assert_eq!(target.clauses()[0].literals, vec![1, 2, 3]);
+    assert_eq!(target.clauses()[1].literals, vec![-1, -2, 10]);
+    assert_eq!(target.clauses()[2].literals, vec![-1, -2, -10]);
+}
+
+#[test]
+fn test_kcoloring_to_ksatisfiability_colorable_graph_is_satisfiable() {
+    // C5 needs three colors
+    let source = KColoring::<K3, _>::new(SimpleGraph::new(
+        5,
+        vec![(0, 1), (1, 2), (2, 3), (3, 4), (0, 4)],
+    ));
+    let reduction = ReduceTo::<KSatisfiability<K3>>::reduce_to(&source);
+    let solver = BruteForce::new();
+
+    let witnesses = solver.find_all_witnesses(reduction.target_problem());
+    // 30 proper 3-colorings of C5, each with the padding variable free
+    assert_eq!(witnesses.len(), 2 * 30);
+    for witness in &witnesses {
+        assert!(source.evaluate(&reduction.extract_solution(witness)).0);
+    }
+}
+
+#[test]
+fn test_kcoloring_to_ksatisfiability_k4_is_unsatisfiable() {
+    let source = KColoring::<K3, _>::new(complete_graph(4));
+    let reduction = ReduceTo::<KSatisfiability<K3>>::reduce_to(&source);
+    let solver = BruteForce::new();
+
+    assert!(solver.find_witness(&source).is_none());
+    assert!(solver.find_witness(reduction.target_problem()).is_none());
+}
+
+#[test]
+fn test_kcoloring_to_ksatisfiability_extract_solution() {
+    let source = KColoring::<K3, _>::new(SimpleGraph::new(2, vec![(0, 1)]));
+    let reduction = ReduceTo::<KSatisfiability<K3>>::reduce_to(&source);
+
+    assert_eq!(
+        reduction.extract_solution(&[0, 0, 1, 1, 0, 0, 1]),
+        vec![2, 0]
+    );
+}
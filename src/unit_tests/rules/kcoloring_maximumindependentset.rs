@@ -0,0 +1,117 @@
+use super::*;
+use crate::rules::test_helpers::assert_satisfaction_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Max;
+
+fn triangle() -> SimpleGraph {
+    SimpleGraph::new(3, vec![(0, 1), (1, 2), (0, 2)])
+}
+
+#[test]
+fn test_kcoloring_to_maximumindependentset_closed_loop() {
+    let source = KColoring::<KN, _>::with_k(SimpleGraph::cycle(5), 3);
+    let reduction = ReduceTo::<MaximumIndependentSet<SimpleGraph, One>>::reduce_to(&source);
+
+    assert_satisfaction_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "KColoring->MaximumIndependentSet closed loop",
+    );
+}
+
+#[test]
+fn test_kcoloring_to_maximumindependentset_structure() {
+    let source = KColoring::<KN, _>::with_k(SimpleGraph::path(3), 2);
+    let reduction = ReduceTo::<MaximumIndependentSet<SimpleGraph, One>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    // 3 vertices x 2 colors; 3 color cliques of one edge, 2 source edges x 2 colors.
+    assert_eq!(target.graph().num_vertices(), 6);
+    assert_eq!(target.graph().num_edges(), 7);
+    assert!(target.graph().has_edge(0, 1)); // (0, 0) - (0, 1)
+    assert!(target.graph().has_edge(0, 2)); // (0, 0) - (1, 0)
+    assert!(target.graph().has_edge(3, 5)); // (1, 1) - (2, 1)
+    assert!(!target.graph().has_edge(0, 3)); // (0, 0) - (1, 1)
+}
+
+#[test]
+fn test_kcoloring_to_maximumindependentset_triangle() {
+    let solver = BruteForce::new();
+
+    // K = 3: the maximum independent set picks one pair per vertex.
+    let source = KColoring::<KN, _>::with_k(triangle(), 3);
+    let reduction = ReduceTo::<MaximumIndependentSet<SimpleGraph, One>>::reduce_to(&source);
+    assert_eq!(solver.solve(reduction.target_problem()), Max(Some(3)));
+    let witness = solver.find_witness(reduction.target_problem()).unwrap();
+    let coloring = reduction.extract_solution(&witness);
+    assert!(source.evaluate(&coloring));
+
+    // K = 2: some vertex is left without a color.
+    let source = KColoring::<KN, _>::with_k(triangle(), 2);
+    let reduction = ReduceTo::<MaximumIndependentSet<SimpleGraph, One>>::reduce_to(&source);
+    assert_eq!(solver.solve(reduction.target_problem()), Max(Some(2)));
+}
+
+#[test]
+fn test_kcoloring_to_maximumindependentset_matches_colorability() {
+    let solver = BruteForce::new();
+    let graphs = [
+        SimpleGraph::cycle(4),
+        SimpleGraph::cycle(5),
+        SimpleGraph::complete(4),
+        SimpleGraph::star(4),
+    ];
+
+    for graph in graphs {
+        let n = graph.num_vertices();
+        for k in 1..=3 {
+            let source = KColoring::<KN, _>::with_k(graph.clone(), k);
+            let colorable = solver.find_witness(&source).is_some();
+            let reduction = ReduceTo::<MaximumIndependentSet<SimpleGraph, One>>::reduce_to(&source);
+            let Max(Some(size)) = solver.solve(reduction.target_problem()) else {
+                panic!("independent sets always exist");
+            };
+            assert_eq!(size == n as i32, colorable, "n = {n}, k = {k}");
+        }
+    }
+}
+
+#[test]
+fn test_kcoloring_to_maximumindependentset_extract_solution() {
+    let source = KColoring::<KN, _>::with_k(triangle(), 3);
+    let reduction = ReduceTo::<MaximumIndependentSet<SimpleGraph, One>>::reduce_to(&source);
+
+    assert_eq!(
+        reduction.extract_solution(&[0, 0, 1, 1, 0, 0, 0, 1, 0]),
+        vec![2, 0, 1]
+    );
+    // A vertex without a selected pair defaults to color 0.
+    assert_eq!(
+        reduction.extract_solution(&[0, 0, 1, 0, 0, 0, 0, 1, 0]),
+        vec![2, 0, 1]
+    );
+}
+
+#[test]
+fn test_kcoloring_to_maximumindependentset_odd_cycle() {
+    let solver = BruteForce::new();
+    let n = 5;
+
+    // C5 is not bipartite: at most 4 of its vertices can receive one of 2 colors.
+    let source = KColoring::<KN, _>::with_k(SimpleGraph::cycle(n), 2);
+    let reduction = ReduceTo::<MaximumIndependentSet<SimpleGraph, One>>::reduce_to(&source);
+    assert_eq!(solver.solve(reduction.target_problem()), Max(Some(4)));
+
+    // With 3 colors every vertex gets one.
+    let source = KColoring::<KN, _>::with_k(SimpleGraph::cycle(n), 3);
+    let reduction = ReduceTo::<MaximumIndependentSet<SimpleGraph, One>>::reduce_to(&source);
+    assert_eq!(
+        solver.solve(reduction.target_problem()),
+        Max(Some(n as i32))
+    );
+    for witness in solver.find_all_witnesses(reduction.target_problem()) {
+        let coloring = reduction.extract_solution(&witness);
+        assert!(source.is_valid_solution(&coloring), "{coloring:?}");
+    }
+}
@@ -0,0 +1,147 @@
+use super::*;
+use crate::rules::test_helpers::assert_satisfaction_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+
+fn cycle(n: usize) -> SimpleGraph {
+    SimpleGraph::new(n, (0..n).map(|i| (i, (i + 1) % n)).collect())
+}
+
+#[test]
+fn test_kcoloring_to_minimumcliquecover_closed_loop() {
+    let source = KColoring::<KN, _>::with_k(cycle(5), 3);
+    let reduction = ReduceTo::<MinimumCliqueCover<SimpleGraph>>::reduce_to(&source);
+
+    assert_satisfaction_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "KColoring->MinimumCliqueCover closed loop",
+    );
+}
+
+#[test]
+fn test_kcoloring_to_minimumcliquecover_complement_structure() {
+    let source = KColoring::<KN, _>::with_k(SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]), 2);
+    let reduction = ReduceTo::<MinimumCliqueCover<SimpleGraph>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.num_vertices(), 4);
+    assert_eq!(target.num_edges(), 3);
+    assert!(target.graph().has_edge(0, 2));
+    assert!(target.graph().has_edge(0, 3));
+    assert!(target.graph().has_edge(1, 3));
+}
+
+#[test]
+fn test_kcoloring_to_minimumcliquecover_matches_chromatic_number() {
+    // The clique cover number of the complement equals the smallest K for
+    // which the source is K-colorable.
+    let solver = BruteForce::new();
+    let graphs = [
+        cycle(5),
+        cycle(6),
+        SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]),
+        SimpleGraph::new(5, vec![(0, 1), (0, 2), (1, 3), (2, 3), (2, 4), (3, 4)]),
+    ];
+
+    for graph in graphs {
+        let n = graph.num_vertices();
+        let chromatic = (1..=n)
+            .find(|&k| {
+                solver
+                    .find_witness(&KColoring::<KN, _>::with_k(graph.clone(), k))
+                    .is_some()
+            })
+            .unwrap();
+
+        let source = KColoring::<KN, _>::with_k(graph, chromatic);
+        let reduction = ReduceTo::<MinimumCliqueCover<SimpleGraph>>::reduce_to(&source);
+        assert_eq!(
+            solver.solve(reduction.target_problem()),
+            Min(Some(chromatic))
+        );
+    }
+}
+
+#[test]
+fn test_kcoloring_to_minimumcliquecover_extract_relabels_groups() {
+    let source = KColoring::<KN, _>::with_k(cycle(5), 3);
+    let reduction = ReduceTo::<MinimumCliqueCover<SimpleGraph>>::reduce_to(&source);
+
+    // Groups {0, 2}, {1, 3}, {4} with sparse ids
+    let extracted = reduction.extract_solution(&[4, 2, 4, 2, 0]);
+    assert_eq!(extracted, vec![0, 1, 0, 1, 2]);
+    assert!(source.evaluate(&extracted));
+}
+
+#[test]
+fn test_kcoloring_to_minimumcliquecover_unsat_exceeds_k() {
+    // C5 is not 2-colorable: its complement needs 3 cliques, so the
+    // extracted coloring uses a third color.
+    let source = KColoring::<KN, _>::with_k(cycle(5), 2);
+    let reduction = ReduceTo::<MinimumCliqueCover<SimpleGraph>>::reduce_to(&source);
+    let solver = BruteForce::new();
+
+    assert!(solver.find_witness(&source).is_none());
+    assert_eq!(solver.solve(reduction.target_problem()), Min(Some(3)));
+    let witness = solver.find_witness(reduction.target_problem()).unwrap();
+    assert!(!source.is_valid_solution(&reduction.extract_solution(&witness)));
+}
+
+#[test]
+fn test_minimumcliquecover_to_kcoloring_extracts_valid_cover() {
+    // Two triangles joined by the edge 2-3
+    let source = MinimumCliqueCover::new(SimpleGraph::new(
+        6,
+        vec![(0, 1), (0, 2), (1, 2), (2, 3), (3, 4), (3, 5), (4, 5)],
+    ));
+    let reduction = ReduceTo::<KColoring<KN, SimpleGraph>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.num_colors(), 6);
+    assert_eq!(target.num_edges(), 15 - 7);
+    for witness in BruteForce::new().find_all_witnesses(target) {
+        assert!(source.is_valid_solution(&reduction.extract_solution(&witness)));
+    }
+}
+
+#[test]
+fn test_minimumcliquecover_to_kcoloring_search_finds_optimum() {
+    // The least K whose complement coloring query is satisfiable is the
+    // clique cover number, and its witness is an optimal cover.
+    let solver = BruteForce::new();
+    let graphs = [
+        cycle(5),
+        cycle(6),
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        SimpleGraph::new(5, vec![(0, 1), (0, 2), (1, 2), (2, 3), (3, 4)]),
+    ];
+
+    for graph in graphs {
+        let source = MinimumCliqueCover::new(graph);
+        let (k, witness) = (1..=source.num_vertices())
+            .find_map(|k| {
+                let query = ReductionMinimumCliqueCoverToKColoring::with_num_cliques(&source, k);
+                solver
+                    .find_witness(query.target_problem())
+                    .map(|w| (k, query.extract_solution(&w)))
+            })
+            .unwrap();
+
+        assert_eq!(solver.solve(&source), Min(Some(k)));
+        assert_eq!(source.evaluate(&witness), Min(Some(k)));
+    }
+}
+
+#[test]
+fn test_minimumcliquecover_to_kcoloring_is_turing_edge() {
+    let graph = crate::rules::ReductionGraph::new();
+    let edge = graph
+        .outgoing_reductions("MinimumCliqueCover")
+        .into_iter()
+        .find(|e| e.target_name == "KColoring")
+        .expect("MinimumCliqueCover -> KColoring edge");
+    assert!(edge.capabilities.turing);
+    assert!(!edge.capabilities.witness);
+}
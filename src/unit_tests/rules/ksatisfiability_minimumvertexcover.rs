@@ -142,3 +142,50 @@ fn test_ksatisfiability_to_minimumvertexcover_structure() {
     let vc_size: usize = witness.unwrap().iter().sum();
     assert_eq!(vc_size, 4);
 }
+
+#[test]
+fn test_ksatisfiability_to_minimumvertexcover_satisfiable_hits_threshold() {
+    // (x1 v x2 v ~x3) ^ (~x1 v x2 v x3): satisfiable, n=3, m=2.
+    let ksat = KSatisfiability::<K3>::new(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2, -3]),
+            CNFClause::new(vec![-1, 2, 3]),
+        ],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&ksat);
+    assert_eq!(reduction.cover_size_threshold(), 7);
+
+    let solver = BruteForce::new();
+    let witness = solver.find_witness(reduction.target_problem()).unwrap();
+    assert_eq!(
+        witness.iter().sum::<usize>(),
+        reduction.cover_size_threshold()
+    );
+    assert!(ksat.evaluate(&reduction.extract_solution(&witness)));
+}
+
+#[test]
+fn test_ksatisfiability_to_minimumvertexcover_unsatisfiable_exceeds_threshold() {
+    // (x1 v x1 v x2) ^ (x1 v x1 v ~x2) ^ (~x1 v ~x1 v x2) ^ (~x1 v ~x1 v ~x2):
+    // every assignment of (x1, x2) falsifies one clause.
+    let ksat = KSatisfiability::<K3>::new(
+        2,
+        vec![
+            CNFClause::new(vec![1, 1, 2]),
+            CNFClause::new(vec![1, 1, -2]),
+            CNFClause::new(vec![-1, -1, 2]),
+            CNFClause::new(vec![-1, -1, -2]),
+        ],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&ksat);
+    assert_eq!(reduction.cover_size_threshold(), 10);
+
+    let solver = BruteForce::new();
+    let witness = solver.find_witness(reduction.target_problem()).unwrap();
+    assert_eq!(
+        witness.iter().sum::<usize>(),
+        reduction.cover_size_threshold() + 1
+    );
+    assert!(!ksat.evaluate(&reduction.extract_solution(&witness)));
+}
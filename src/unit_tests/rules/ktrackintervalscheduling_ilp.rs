@@ -0,0 +1,82 @@
+use super::*;
+use crate::models::algebraic::ObjectiveSense;
+use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, ILPSolver, Solver};
+use crate::traits::Problem;
+use crate::types::Max;
+
+fn small_instance() -> KTrackIntervalScheduling {
+    // [0, 3), [1, 4), [2, 5), [3, 6) with a heavy middle pair
+    KTrackIntervalScheduling::new(vec![0, 1, 2, 3], vec![3, 4, 5, 6], vec![1, 3, 3, 1], 2)
+}
+
+#[test]
+fn test_ktrackintervalscheduling_to_ilp_closed_loop() {
+    let source = small_instance();
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "KTrackIntervalScheduling -> ILP round trip",
+    );
+}
+
+#[test]
+fn test_reduction_shape() {
+    let source = small_instance();
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    let ilp = reduction.target_problem();
+
+    assert_eq!(ilp.num_vars, 8);
+    // 4 assignment rows + 2 tracks * 4 start times
+    assert_eq!(ilp.constraints.len(), 12);
+    assert_eq!(ilp.sense, ObjectiveSense::Maximize);
+    assert_eq!(
+        ilp.objective,
+        vec![
+            (0, 1.0),
+            (1, 1.0),
+            (2, 3.0),
+            (3, 3.0),
+            (4, 3.0),
+            (5, 3.0),
+            (6, 1.0),
+            (7, 1.0)
+        ]
+    );
+}
+
+#[test]
+fn test_extract_solution() {
+    let source = small_instance();
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    // Interval 1 on track 0, interval 2 on track 1, the rest rejected
+    let extracted = reduction.extract_solution(&[0, 0, 1, 0, 0, 1, 0, 0]);
+    assert_eq!(extracted, vec![0, 1, 2, 0]);
+    assert_eq!(source.evaluate(&extracted), Max(Some(6)));
+}
+
+#[test]
+fn test_ilp_solver_finds_optimum() {
+    let source = small_instance();
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("rejecting every interval is always feasible");
+    let extracted = reduction.extract_solution(&ilp_solution);
+
+    assert_eq!(source.evaluate(&extracted), Max(Some(6)));
+    assert_eq!(BruteForce::new().solve(&source), Max(Some(6)));
+}
+
+#[test]
+fn test_touching_intervals_share_a_track() {
+    // [0, 2), [2, 4), [4, 6) chain on one track
+    let source = KTrackIntervalScheduling::new(vec![0, 2, 4], vec![2, 4, 6], vec![1, 1, 1], 1);
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    let ilp_solution = ILPSolver::new().solve(reduction.target_problem()).unwrap();
+    assert_eq!(
+        source.evaluate(&reduction.extract_solution(&ilp_solution)),
+        Max(Some(3))
+    );
+}
@@ -0,0 +1,78 @@
+use super::*;
+use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+use crate::solvers::BruteForce;
+use crate::traits::Problem;
+use crate::types::Max;
+
+fn weighted_k4() -> MaxBisection<SimpleGraph, i32> {
+    MaxBisection::new(
+        SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]),
+        vec![5, 5, 5, 1, 1, 1],
+    )
+}
+
+#[test]
+fn test_maxbisection_to_qubo_closed_loop() {
+    let source = weighted_k4();
+    let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&source);
+
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MaxBisection->QUBO closed loop",
+    );
+}
+
+#[test]
+fn test_maxbisection_to_qubo_cycle_closed_loop() {
+    let source = MaxBisection::<_, i32>::unweighted(SimpleGraph::new(
+        6,
+        vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)],
+    ));
+    let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&source);
+
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MaxBisection(C6)->QUBO closed loop",
+    );
+}
+
+#[test]
+fn test_maxbisection_to_qubo_energy_matches_cut() {
+    // On balanced configurations H = -cut + P * 0, so energies mirror cut weights.
+    let source = weighted_k4();
+    let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&source);
+    let qubo = reduction.target_problem();
+    assert_eq!(qubo.num_vars(), 4);
+
+    let penalty = 18.0 + 1.0;
+    let offset = penalty * 4.0;
+    for mask in 0..16usize {
+        let config: Vec<usize> = (0..4).map(|i| (mask >> i) & 1).collect();
+        if let Max(Some(cut)) = source.evaluate(&config) {
+            assert_eq!(qubo.evaluate(&config) + offset, -(cut as f64));
+        }
+    }
+
+    let best = BruteForce::new().find_witness(qubo).unwrap();
+    assert_eq!(
+        source.evaluate(&reduction.extract_solution(&best)),
+        Max(Some(12))
+    );
+}
+
+#[cfg(feature = "example-db")]
+#[test]
+fn test_maxbisection_to_qubo_canonical_example_spec() {
+    let spec = canonical_rule_example_specs()
+        .into_iter()
+        .find(|spec| spec.id == "maxbisection_to_qubo")
+        .expect("missing canonical MaxBisection -> QUBO example spec");
+    let example = (spec.build)();
+
+    assert_eq!(example.source.problem, "MaxBisection");
+    assert_eq!(example.target.problem, "QUBO");
+    assert_eq!(example.target.instance["num_vars"], 4);
+    assert!(!example.solutions.is_empty());
+}
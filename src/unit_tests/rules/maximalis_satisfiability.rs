@@ -0,0 +1,157 @@
+use super::*;
+use crate::models::graph::maximal_is::is_maximal_independent_set;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::small_graphs;
+use crate::traits::Problem;
+use std::collections::HashSet;
+
+/// Collect all maximal independent sets of a graph by exhaustive enumeration.
+fn all_maximal_independent_sets(graph: &SimpleGraph) -> HashSet<Vec<usize>> {
+    let n = graph.num_vertices();
+    (0..1usize << n)
+        .map(|mask| (0..n).map(|v| (mask >> v) & 1).collect::<Vec<_>>())
+        .filter(|config| {
+            let selected: Vec<bool> = config.iter().map(|&x| x == 1).collect();
+            is_maximal_independent_set(graph, &selected)
+        })
+        .collect()
+}
+
+fn assert_witnesses_match_maximal_sets(graph: SimpleGraph) {
+    let n = graph.num_vertices();
+    let source = MaximalIS::new(graph.clone(), vec![1i32; n]);
+    let reduction = ReduceTo::<Satisfiability>::reduce_to(&source);
+
+    let extracted: HashSet<Vec<usize>> = BruteForce::new()
+        .find_all_witnesses(reduction.target_problem())
+        .iter()
+        .map(|w| reduction.extract_solution(w))
+        .collect();
+
+    assert_eq!(extracted, all_maximal_independent_sets(&graph));
+    for config in &extracted {
+        assert!(source.evaluate(config).is_valid());
+    }
+}
+
+#[test]
+fn test_maximalis_to_satisfiability_closed_loop() {
+    assert_witnesses_match_maximal_sets(SimpleGraph::path(5));
+}
+
+#[test]
+fn test_maximalis_to_satisfiability_bull() {
+    let (n, edges) = small_graphs::bull();
+    assert_witnesses_match_maximal_sets(SimpleGraph::new(n, edges));
+}
+
+#[test]
+fn test_maximalis_to_satisfiability_structure() {
+    // Path 0-1-2: 2 independence clauses + 3 maximality clauses.
+    let source = MaximalIS::new(SimpleGraph::path(3), vec![1i32; 3]);
+    let reduction = ReduceTo::<Satisfiability>::reduce_to(&source);
+    let sat = reduction.target_problem();
+
+    assert_eq!(sat.num_vars(), 3);
+    assert_eq!(sat.num_clauses(), 5);
+    assert_eq!(sat.num_literals(), 3 + 4 * 2);
+
+    let clauses: HashSet<Vec<i32>> = sat
+        .clauses()
+        .iter()
+        .map(|c| {
+            let mut lits = c.literals.clone();
+            lits.sort();
+            lits
+        })
+        .collect();
+    assert!(clauses.contains(&vec![-2, -1]));
+    assert!(clauses.contains(&vec![-3, -2]));
+    assert!(clauses.contains(&vec![1, 2]));
+    assert!(clauses.contains(&vec![1, 2, 3]));
+    assert!(clauses.contains(&vec![2, 3]));
+}
+
+#[test]
+fn test_maximalis_to_satisfiability_isolated_vertex() {
+    // An isolated vertex must be selected: its maximality clause is a unit clause.
+    let source = MaximalIS::new(SimpleGraph::new(3, vec![(0, 1)]), vec![1i32; 3]);
+    let reduction = ReduceTo::<Satisfiability>::reduce_to(&source);
+    let witnesses = BruteForce::new().find_all_witnesses(reduction.target_problem());
+
+    assert_eq!(witnesses.len(), 2);
+    for w in &witnesses {
+        assert_eq!(reduction.extract_solution(w)[2], 1);
+    }
+}
+
+/// Run the Turing search: enumerate maximal sets with blocking clauses and
+/// keep the heaviest one.
+fn turing_search(source: &MaximalIS<SimpleGraph, i32>) -> Vec<usize> {
+    let solver = BruteForce::new();
+    let mut found: Vec<Vec<usize>> = Vec::new();
+    loop {
+        let query = ReductionMaximalISToSAT::excluding(source, &found);
+        match solver.find_witness(query.target_problem()) {
+            Some(witness) => found.push(query.extract_solution(&witness)),
+            None => break,
+        }
+    }
+    found
+        .into_iter()
+        .max_by_key(|config| {
+            (0..config.len())
+                .filter(|&v| config[v] == 1)
+                .map(|v| source.weights()[v])
+                .sum::<i32>()
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_maximalis_to_satisfiability_excluding_blocks_found_sets() {
+    let graph = SimpleGraph::path(5);
+    let source = MaximalIS::new(graph.clone(), vec![1i32; 5]);
+    let found = vec![vec![1, 0, 1, 0, 1]];
+    let query = ReductionMaximalISToSAT::excluding(&source, &found);
+
+    let extracted: HashSet<Vec<usize>> = BruteForce::new()
+        .find_all_witnesses(query.target_problem())
+        .iter()
+        .map(|w| query.extract_solution(w))
+        .collect();
+    let mut expected = all_maximal_independent_sets(&graph);
+    expected.remove(&found[0]);
+    assert_eq!(extracted, expected);
+}
+
+#[test]
+fn test_maximalis_to_satisfiability_search_finds_optimum() {
+    // Star with a heavy center: a single query may return the leaves (weight
+    // 3), but the search must find the center (weight 10).
+    let star = MaximalIS::new(
+        SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3)]),
+        vec![10, 1, 1, 1],
+    );
+    let (n, edges) = small_graphs::bull();
+    let bull = MaximalIS::new(SimpleGraph::new(n, edges), vec![3, 1, 4, 1, 5]);
+    let path = MaximalIS::new(SimpleGraph::path(5), vec![2, 5, 1, 1, 2]);
+
+    let solver = BruteForce::new();
+    for source in [star, bull, path] {
+        let witness = turing_search(&source);
+        assert_eq!(source.evaluate(&witness), solver.solve(&source));
+    }
+}
+
+#[test]
+fn test_maximalis_to_satisfiability_is_turing_edge() {
+    let graph = crate::rules::ReductionGraph::new();
+    let edge = graph
+        .outgoing_reductions("MaximalIS")
+        .into_iter()
+        .find(|e| e.target_name == "Satisfiability")
+        .expect("MaximalIS -> Satisfiability edge");
+    assert!(edge.capabilities.turing);
+    assert!(!edge.capabilities.witness);
+}
@@ -0,0 +1,27 @@
+use super::*;
+use crate::models::formula::CNFClause;
+use crate::rules::traits::ReductionResult;
+use crate::rules::ReduceTo;
+use crate::solvers::BruteForce;
+use crate::traits::Problem;
+use crate::types::Max;
+
+#[test]
+fn test_maximum2satisfiability_one_to_i32_cast_closed_loop() {
+    let source = Maximum2Satisfiability::<One>::new(
+        2,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![-1]),
+            CNFClause::new(vec![-2, 1]),
+        ],
+    );
+
+    let reduction = ReduceTo::<Maximum2Satisfiability<i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+    assert_eq!(target.weights(), &[1, 1, 1]);
+
+    let target_solution = BruteForce::new().find_witness(target).unwrap();
+    let source_solution = reduction.extract_solution(&target_solution);
+    assert_eq!(source.evaluate(&source_solution), Max(Some(2)));
+}
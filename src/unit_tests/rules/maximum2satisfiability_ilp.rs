@@ -4,9 +4,10 @@ use crate::models::formula::CNFClause;
 use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
 use crate::solvers::{BruteForce, ILPSolver};
 use crate::traits::Problem;
+use crate::types::One;
 
-fn make_canonical_instance() -> Maximum2Satisfiability {
-    Maximum2Satisfiability::new(
+fn make_canonical_instance() -> Maximum2Satisfiability<One> {
+    Maximum2Satisfiability::<One>::new(
         4,
         vec![
             CNFClause::new(vec![1, 2]),
@@ -97,7 +98,7 @@ fn test_maximum2satisfiability_to_ilp_structure() {
 fn test_maximum2satisfiability_to_ilp_all_satisfiable() {
     // Simple instance where all clauses can be satisfied: (x1 OR x2) AND (x1 OR ~x2)
     // x1 = true satisfies both.
-    let problem = Maximum2Satisfiability::new(
+    let problem = Maximum2Satisfiability::<One>::new(
         2,
         vec![CNFClause::new(vec![1, 2]), CNFClause::new(vec![1, -2])],
     );
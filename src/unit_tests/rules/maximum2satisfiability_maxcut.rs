@@ -2,12 +2,13 @@ use super::*;
 use crate::models::formula::{CNFClause, Maximum2Satisfiability};
 use crate::models::graph::MaxCut;
 use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
 use crate::topology::SimpleGraph;
 use crate::traits::Problem;
-use crate::types::Max;
+use crate::types::{Max, One};
 
-fn make_issue_instance() -> Maximum2Satisfiability {
-    Maximum2Satisfiability::new(
+fn make_issue_instance() -> Maximum2Satisfiability<One> {
+    Maximum2Satisfiability::<One>::new(
         3,
         vec![
             CNFClause::new(vec![1, 2]),
@@ -65,7 +66,7 @@ fn test_maximum2satisfiability_to_maxcut_issue_affine_relation_on_all_partitions
             .map(|bit| (mask >> bit) & 1)
             .collect();
         let source_solution = reduction.extract_solution(&target_solution);
-        let satisfied = source.evaluate(&source_solution).unwrap() as i32;
+        let satisfied = source.evaluate(&source_solution).unwrap();
         let cut_weight = target.evaluate(&target_solution).unwrap();
 
         assert_eq!(
@@ -91,7 +92,7 @@ fn test_maximum2satisfiability_to_maxcut_extract_solution_uses_reference_vertex(
 
 #[test]
 fn test_maximum2satisfiability_to_maxcut_handles_duplicate_and_tautological_clauses() {
-    let source = Maximum2Satisfiability::new(
+    let source = Maximum2Satisfiability::<One>::new(
         2,
         vec![
             CNFClause::new(vec![1, 1]),
@@ -115,6 +116,62 @@ fn test_maximum2satisfiability_to_maxcut_handles_duplicate_and_tautological_clau
     );
 }
 
+fn make_weighted_instance() -> Maximum2Satisfiability<i32> {
+    Maximum2Satisfiability::with_weights(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![-1]),
+            CNFClause::new(vec![-2, 3]),
+            CNFClause::new(vec![-3]),
+        ],
+        vec![3, 2, 1, 4],
+    )
+}
+
+#[test]
+fn test_maximum2satisfiability_weighted_to_maxcut_closed_loop() {
+    let source = make_weighted_instance();
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&source);
+
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "weighted Maximum2Satisfiability -> MaxCut closed loop",
+    );
+}
+
+#[test]
+fn test_maximum2satisfiability_weighted_to_maxcut_optima_agree() {
+    let source = make_weighted_instance();
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    // Every partition satisfies 2 * satisfied_weight = C + cut_weight for a
+    // constant C, so the two BruteForce optima are related by the same shift.
+    let offset = |target_solution: &[usize]| {
+        let source_solution = reduction.extract_solution(target_solution);
+        2 * source.evaluate(&source_solution).unwrap() - target.evaluate(target_solution).unwrap()
+    };
+    let constant = offset(&vec![0; target.num_vertices()]);
+    for mask in 0..(1usize << target.num_vertices()) {
+        let target_solution: Vec<usize> = (0..target.num_vertices())
+            .map(|bit| (mask >> bit) & 1)
+            .collect();
+        assert_eq!(
+            offset(&target_solution),
+            constant,
+            "target config {target_solution:?}"
+        );
+    }
+
+    let solver = BruteForce::new();
+    let source_opt = solver.solve(&source).unwrap();
+    let target_opt = solver.solve(target).unwrap();
+    assert_eq!(source_opt, 9);
+    assert_eq!(2 * source_opt, constant + target_opt);
+}
+
 #[cfg(feature = "example-db")]
 #[test]
 fn test_maximum2satisfiability_to_maxcut_canonical_example_spec() {
@@ -147,3 +204,28 @@ fn test_maximum2satisfiability_to_maxcut_canonical_example_spec() {
         }]
     );
 }
+
+#[cfg(feature = "example-db")]
+#[test]
+fn test_maximum2satisfiability_i32_to_maxcut_canonical_example_spec() {
+    let spec = canonical_rule_example_specs()
+        .into_iter()
+        .find(|spec| spec.id == "maximum2satisfiability_i32_to_maxcut")
+        .expect("missing canonical weighted Maximum2Satisfiability -> MaxCut example spec");
+    let example = (spec.build)();
+
+    assert_eq!(example.source.problem, "Maximum2Satisfiability");
+    assert_eq!(example.source.variant["weight"], "i32");
+    assert_eq!(
+        example.source.instance["weights"],
+        serde_json::json!([3, 2, 1, 4])
+    );
+    assert_eq!(example.target.problem, "MaxCut");
+    assert_eq!(
+        example.solutions,
+        vec![crate::export::SolutionPair {
+            source_config: vec![0, 1, 0],
+            target_config: vec![0, 1, 0, 1],
+        }]
+    );
+}
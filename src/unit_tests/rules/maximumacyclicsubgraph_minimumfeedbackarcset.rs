@@ -0,0 +1,71 @@
+use super::*;
+use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::DirectedGraph;
+use crate::traits::Problem;
+
+fn weighted_graph() -> (DirectedGraph, Vec<i32>) {
+    // Two directed triangles sharing arc 1->2, plus a chord 0->3.
+    (
+        DirectedGraph::new(4, vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 1), (0, 3)]),
+        vec![3, 1, 2, 4, 2, 5],
+    )
+}
+
+#[test]
+fn test_maximumacyclicsubgraph_to_minimumfeedbackarcset_closed_loop() {
+    let (graph, weights) = weighted_graph();
+    let source = MaximumAcyclicSubgraph::new(graph, weights);
+    let reduction = ReduceTo::<MinimumFeedbackArcSet<i32>>::reduce_to(&source);
+
+    assert_eq!(reduction.target_problem().weights(), source.weights());
+    assert_eq!(reduction.target_problem().num_arcs(), source.num_arcs());
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MaximumAcyclicSubgraph -> MinimumFeedbackArcSet closed loop",
+    );
+}
+
+#[test]
+fn test_minimumfeedbackarcset_to_maximumacyclicsubgraph_closed_loop() {
+    let (graph, weights) = weighted_graph();
+    let source = MinimumFeedbackArcSet::new(graph, weights);
+    let reduction = ReduceTo::<MaximumAcyclicSubgraph<i32>>::reduce_to(&source);
+
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MinimumFeedbackArcSet -> MaximumAcyclicSubgraph closed loop",
+    );
+}
+
+#[test]
+fn test_maximumacyclicsubgraph_minimumfeedbackarcset_optima_sum_to_total_weight() {
+    let (graph, weights) = weighted_graph();
+    let total: i32 = weights.iter().sum();
+    let source = MaximumAcyclicSubgraph::new(graph, weights);
+    let reduction = ReduceTo::<MinimumFeedbackArcSet<i32>>::reduce_to(&source);
+
+    let solver = BruteForce::new();
+    let kept = solver.solve(&source).unwrap();
+    let removed = solver.solve(reduction.target_problem()).unwrap();
+    assert_eq!(kept + removed, total);
+
+    let target_solution = solver.find_witness(reduction.target_problem()).unwrap();
+    let source_solution = reduction.extract_solution(&target_solution);
+    assert_eq!(source.evaluate(&source_solution).unwrap(), kept);
+}
+
+#[cfg(feature = "example-db")]
+#[test]
+fn test_maximumacyclicsubgraph_minimumfeedbackarcset_canonical_examples() {
+    let specs = canonical_rule_example_specs();
+    assert_eq!(specs.len(), 2);
+    for spec in specs {
+        let example = (spec.build)();
+        let pair = &example.solutions[0];
+        let complemented: Vec<usize> = pair.source_config.iter().map(|&x| 1 - x).collect();
+        assert_eq!(pair.target_config, complemented, "{}", spec.id);
+    }
+}
@@ -0,0 +1,68 @@
+use super::*;
+use crate::models::algebraic::ObjectiveSense;
+use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, ILPSolver, Solver};
+use crate::topology::BipartiteGraph;
+use crate::traits::Problem;
+use crate::types::Max;
+
+fn small_instance() -> MaximumBalancedBiclique {
+    // L={0,1,2}, R={0,1,2}; K_{2,2} on {0,1} x {0,1}
+    MaximumBalancedBiclique::new(BipartiteGraph::new(
+        3,
+        3,
+        vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 1), (2, 2)],
+    ))
+}
+
+#[test]
+fn test_maximumbalancedbiclique_to_ilp_closed_loop() {
+    let source = small_instance();
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MaximumBalancedBiclique -> ILP round trip",
+    );
+}
+
+#[test]
+fn test_reduction_shape() {
+    let source = small_instance();
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    let ilp = reduction.target_problem();
+
+    assert_eq!(ilp.num_vars, 6);
+    // 1 balance row + 9 - 6 non-edge rows
+    assert_eq!(ilp.constraints.len(), 4);
+    assert_eq!(ilp.sense, ObjectiveSense::Maximize);
+    assert_eq!(ilp.objective, vec![(0, 1.0), (1, 1.0), (2, 1.0)]);
+}
+
+#[test]
+fn test_ilp_solver_finds_optimum() {
+    let source = small_instance();
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("the empty biclique is always feasible");
+    let extracted = reduction.extract_solution(&ilp_solution);
+
+    assert_eq!(source.evaluate(&extracted), Max(Some(2)));
+    assert_eq!(BruteForce::new().solve(&source), Max(Some(2)));
+}
+
+#[test]
+fn test_unbalanced_complete_graph() {
+    // K_{2,4}: every selection is complete, so balance alone caps the optimum
+    let edges = (0..2).flat_map(|l| (0..4).map(move |r| (l, r))).collect();
+    let source = MaximumBalancedBiclique::new(BipartiteGraph::new(2, 4, edges));
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+
+    assert_eq!(reduction.target_problem().constraints.len(), 1);
+    let ilp_solution = ILPSolver::new().solve(reduction.target_problem()).unwrap();
+    assert_eq!(
+        source.evaluate(&reduction.extract_solution(&ilp_solution)),
+        Max(Some(2))
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::models::algebraic::ObjectiveSense;
 use crate::solvers::ILPSolver;
 
 /// Check if a configuration represents a valid clique in the graph.
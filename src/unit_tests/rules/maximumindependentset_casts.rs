@@ -0,0 +1,86 @@
+use super::*;
+use crate::rules::traits::ReductionResult;
+use crate::rules::{MinimizeSteps, ReduceTo, ReductionGraph};
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::Graph;
+use crate::traits::Problem;
+use crate::types::ProblemSize;
+
+#[test]
+fn test_maximumindependentset_graph_casts_cover_both_weights() {
+    let graph = ReductionGraph::new();
+    let casts = [
+        (
+            MaximumIndependentSet::<TriangularSubgraph, i32>::variant(),
+            MaximumIndependentSet::<UnitDiskGraph, i32>::variant(),
+        ),
+        (
+            MaximumIndependentSet::<KingsSubgraph, One>::variant(),
+            MaximumIndependentSet::<UnitDiskGraph, One>::variant(),
+        ),
+        (
+            MaximumIndependentSet::<UnitDiskGraph, One>::variant(),
+            MaximumIndependentSet::<SimpleGraph, One>::variant(),
+        ),
+    ];
+    for (src, dst) in casts {
+        let path = graph
+            .find_cheapest_path(
+                "MaximumIndependentSet",
+                &ReductionGraph::variant_to_map(&src),
+                "MaximumIndependentSet",
+                &ReductionGraph::variant_to_map(&dst),
+                &ProblemSize::new(vec![]),
+                &MinimizeSteps,
+            )
+            .unwrap_or_else(|| panic!("missing cast {src:?} -> {dst:?}"));
+        assert_eq!(path.len(), 1, "{src:?} -> {dst:?} should be a direct cast");
+    }
+}
+
+#[test]
+fn test_triangular_instance_follows_simple_graph_path() {
+    let triangular = TriangularSubgraph::new(vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 0)]);
+    let source = MaximumIndependentSet::new(triangular.clone(), vec![2, 7, 1, 8, 3]);
+
+    let graph = ReductionGraph::new();
+    let path = graph
+        .find_cheapest_path(
+            "MaximumIndependentSet",
+            &ReductionGraph::variant_to_map(
+                &MaximumIndependentSet::<TriangularSubgraph, i32>::variant(),
+            ),
+            "MaximumIndependentSet",
+            &ReductionGraph::variant_to_map(&MaximumIndependentSet::<SimpleGraph, i32>::variant()),
+            &ProblemSize::new(vec![]),
+            &MinimizeSteps,
+        )
+        .expect("TriangularSubgraph should relax to SimpleGraph");
+    assert_eq!(path.len(), 2);
+
+    let chain = graph
+        .reduce_along_path(&path, &source as &dyn std::any::Any)
+        .expect("cast chain should execute");
+    let target: &MaximumIndependentSet<SimpleGraph, i32> = chain.target_problem();
+    assert_eq!(target.graph().edges(), triangular.edges());
+    assert_eq!(target.weights(), source.weights());
+
+    let solver = BruteForce::new();
+    let witness = solver.find_witness(target).unwrap();
+    let extracted = chain.extract_solution(&witness);
+    assert_eq!(extracted, witness);
+    assert_eq!(source.evaluate(&extracted), solver.solve(&source));
+}
+
+#[test]
+fn test_kings_cast_keeps_unit_weights() {
+    let kings = KingsSubgraph::new(vec![(0, 0), (0, 1), (1, 1)]);
+    let source = MaximumIndependentSet::new(kings, vec![One; 3]);
+    let reduction = ReduceTo::<MaximumIndependentSet<UnitDiskGraph, One>>::reduce_to(&source);
+    let target = reduction.target_problem();
+    assert_eq!(target.num_vertices(), 3);
+    assert_eq!(
+        BruteForce::new().solve(target),
+        BruteForce::new().solve(&source)
+    );
+}
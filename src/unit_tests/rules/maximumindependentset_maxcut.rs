@@ -0,0 +1,85 @@
+use super::*;
+use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Max;
+
+#[test]
+fn test_maximumindependentset_to_maxcut_triangle() {
+    // Unit-weight triangle: any single vertex is optimal.
+    let source = MaximumIndependentSet::new(SimpleGraph::cycle(3), vec![1; 3]);
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.num_vertices(), 4);
+    assert_eq!(target.num_edges(), 6);
+    assert_eq!(target.edge_weight(0, 1), Some(&1));
+    // Each vertex has degree 2, so its apex edge is 1 - 2 * 1.
+    assert_eq!(target.edge_weight(0, 3), Some(&-1));
+
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(target), Max(Some(1)));
+    for witness in solver.find_all_witnesses(target) {
+        let extracted = reduction.extract_solution(&witness);
+        assert_eq!(extracted.iter().sum::<usize>(), 1, "{witness:?}");
+    }
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MaximumIndependentSet -> MaxCut triangle",
+    );
+}
+
+#[test]
+fn test_maximumindependentset_to_maxcut_weighted_path() {
+    // Path 0-1-2-3 where the heavy middle vertices beat both endpoints.
+    let source = MaximumIndependentSet::new(SimpleGraph::path(4), vec![2, 5, 1, 3]);
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(target), Max(Some(8)));
+    let witnesses = solver.find_all_witnesses(target);
+    // Both labelings of the optimal partition extract to {1, 3}.
+    assert_eq!(witnesses.len(), 2);
+    for witness in &witnesses {
+        assert_eq!(reduction.extract_solution(witness), vec![0, 1, 0, 1]);
+    }
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MaximumIndependentSet -> MaxCut path",
+    );
+}
+
+#[test]
+fn test_maximumindependentset_to_maxcut_cut_equals_set_weight() {
+    let source = MaximumIndependentSet::new(SimpleGraph::cycle(5), vec![3, 1, 2, 1, 2]);
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    // For every independent set, cutting it away from the apex scores its weight.
+    for config in crate::config::DimsIterator::new(source.dims()) {
+        let value = source.evaluate(&config);
+        if value.is_valid() {
+            let mut cut = config.clone();
+            cut.push(0);
+            assert_eq!(target.evaluate(&cut), value, "{config:?}");
+        }
+    }
+}
+
+#[test]
+fn test_maximumindependentset_to_maxcut_edgeless_and_empty() {
+    let source = MaximumIndependentSet::new(SimpleGraph::empty(3), vec![4, 0, 2]);
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&source);
+    assert_eq!(
+        BruteForce::new().solve(reduction.target_problem()),
+        Max(Some(6))
+    );
+
+    let source = MaximumIndependentSet::new(SimpleGraph::empty(0), Vec::<i32>::new());
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&source);
+    assert_eq!(reduction.target_problem().num_vertices(), 1);
+    assert_eq!(reduction.extract_solution(&[0]), Vec::<usize>::new());
+}
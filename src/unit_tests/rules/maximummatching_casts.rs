@@ -0,0 +1,39 @@
+use super::*;
+use crate::rules::traits::ReductionResult;
+use crate::rules::ReduceTo;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::Graph;
+use crate::traits::Problem;
+
+#[test]
+fn test_maximummatching_kings_to_simple_cast_chain_closed_loop() {
+    let kings = KingsSubgraph::new(vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    let weights: Vec<i32> = (1..=kings.num_edges() as i32).collect();
+    let source = MaximumMatching::new(kings.clone(), weights.clone());
+
+    let to_udg = ReduceTo::<MaximumMatching<UnitDiskGraph, i32>>::reduce_to(&source);
+    let to_simple =
+        ReduceTo::<MaximumMatching<SimpleGraph, i32>>::reduce_to(to_udg.target_problem());
+    let target = to_simple.target_problem();
+    assert_eq!(target.graph().edges(), kings.edges());
+    assert_eq!(target.weights(), weights);
+
+    let solver = BruteForce::new();
+    let witness = solver.find_witness(target).unwrap();
+    let extracted = to_udg.extract_solution(&to_simple.extract_solution(&witness));
+    assert_eq!(extracted, witness);
+    assert_eq!(source.evaluate(&extracted), solver.solve(&source));
+}
+
+#[test]
+fn test_maximummatching_triangular_to_unitdisk_cast_preserves_value() {
+    let triangular = TriangularSubgraph::new(vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    let source = MaximumMatching::new(triangular.clone(), vec![1; triangular.num_edges()]);
+
+    let reduction = ReduceTo::<MaximumMatching<UnitDiskGraph, i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+    assert_eq!(target.graph().edges(), triangular.edges());
+
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(target), solver.solve(&source));
+}
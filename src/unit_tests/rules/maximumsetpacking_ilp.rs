@@ -1,5 +1,8 @@
 use super::*;
-use crate::solvers::{BruteForce, ILPSolver};
+use crate::models::algebraic::ObjectiveSense;
+use crate::models::graph::MaximumIndependentSet;
+use crate::solvers::{BruteForce, ILPSolver, Solver};
+use crate::topology::SimpleGraph;
 use crate::traits::Problem;
 use crate::types::Max;
 
@@ -134,3 +137,56 @@ fn test_maximumsetpacking_to_ilp_bf_vs_ilp() {
     let reduction: ReductionSPToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
     crate::rules::test_helpers::assert_bf_vs_ilp(&problem, &reduction);
 }
+
+#[test]
+fn test_element_rows_list_every_containing_set() {
+    let problem = MaximumSetPacking::<i32>::new(vec![vec![0, 1, 2], vec![2, 3], vec![0, 3, 4]]);
+    let reduction: ReductionSPToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let ilp = reduction.target_problem();
+
+    // Elements 0, 2, 3 are shared; 1 and 4 are private and need no row
+    let mut rows: Vec<Vec<usize>> = ilp
+        .constraints
+        .iter()
+        .map(|c| {
+            assert!((c.rhs - 1.0).abs() < 1e-9);
+            let mut vars: Vec<usize> = c.terms.iter().map(|&(v, _)| v).collect();
+            vars.sort_unstable();
+            vars
+        })
+        .collect();
+    rows.sort();
+    assert_eq!(rows, vec![vec![0, 1], vec![0, 2], vec![1, 2]]);
+}
+
+#[test]
+fn test_direct_ilp_matches_independent_set_path() {
+    // Overlapping weighted sets where the heaviest set is not in the optimum
+    let problem = MaximumSetPacking::with_weights(
+        vec![
+            vec![0, 1, 2],
+            vec![2, 3],
+            vec![3, 4, 5],
+            vec![0, 5],
+            vec![1, 4],
+        ],
+        vec![5, 3, 3, 3, 3],
+    );
+
+    let direct = ILPSolver::new()
+        .solve_reduced(&problem)
+        .expect("packing ILP is always feasible");
+
+    let to_is = ReduceTo::<MaximumIndependentSet<SimpleGraph, i32>>::reduce_to(&problem);
+    let is_solution = BruteForce::new()
+        .find_witness(to_is.target_problem())
+        .expect("empty set is independent");
+    let via_is = to_is.extract_solution(&is_solution);
+
+    let optimum = BruteForce::new().solve(&problem);
+    assert_eq!(problem.evaluate(&direct), optimum);
+    assert_eq!(problem.evaluate(&via_is), optimum);
+    assert_eq!(optimum, Max(Some(9)));
+    assert_eq!(direct, vec![0, 1, 0, 1, 1]);
+    assert_eq!(direct, via_is);
+}
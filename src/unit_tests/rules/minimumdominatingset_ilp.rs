@@ -1,4 +1,5 @@
 use super::*;
+use crate::models::algebraic::ObjectiveSense;
 use crate::solvers::{BruteForce, ILPSolver};
 use crate::traits::Problem;
 use crate::types::Min;
@@ -0,0 +1,61 @@
+use super::*;
+use crate::solvers::{BruteForce, ILPSolver};
+use crate::traits::Problem;
+use crate::types::Min;
+
+#[test]
+fn test_reduction_creates_valid_ilp() {
+    // Star K1,3: each constraint covers all three edges.
+    let problem = MinimumEdgeDominatingSet::new(
+        SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3)]),
+        vec![1i32; 3],
+    );
+    let reduction: ReductionEDSToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let ilp = reduction.target_problem();
+
+    assert_eq!(ilp.num_vars, 3, "Should have one variable per edge");
+    assert_eq!(
+        ilp.constraints.len(),
+        3,
+        "Should have one constraint per edge"
+    );
+    assert_eq!(ilp.sense, ObjectiveSense::Minimize, "Should minimize");
+    for constraint in &ilp.constraints {
+        assert_eq!(constraint.terms.len(), 3);
+        assert!((constraint.rhs - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_minimumedgedominatingset_to_ilp_closed_loop() {
+    let problem = MinimumEdgeDominatingSet::new(
+        SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]),
+        vec![1i32; 5],
+    );
+    let reduction: ReductionEDSToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("ILP should be solvable");
+    let extracted = reduction.extract_solution(&ilp_solution);
+    assert_eq!(problem.evaluate(&extracted), Min(Some(2)));
+
+    let bf_solutions = BruteForce::new().find_all_witnesses(&problem);
+    assert_eq!(
+        problem.evaluate(&bf_solutions[0]),
+        problem.evaluate(&extracted)
+    );
+}
+
+#[test]
+fn test_minimumedgedominatingset_to_ilp_weighted() {
+    let problem = MinimumEdgeDominatingSet::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        vec![1, 5, 1],
+    );
+    let reduction: ReductionEDSToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("ILP should be solvable");
+    assert_eq!(reduction.extract_solution(&ilp_solution), vec![1, 0, 1]);
+}
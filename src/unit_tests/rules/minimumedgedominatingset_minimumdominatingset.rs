@@ -0,0 +1,60 @@
+use super::*;
+use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::Graph;
+use crate::types::Min;
+
+#[test]
+fn test_line_graph_of_star_is_complete() {
+    let star = SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3)]);
+    let line = line_graph(&star);
+    assert_eq!(line.num_vertices(), 3);
+    assert_eq!(line.num_edges(), 3);
+
+    // P4 has line graph P3: edges (0,1) and (1,2) share vertex 1, (1,2) and (2,3) share 2.
+    let path = SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]);
+    let line = line_graph(&path);
+    assert_eq!(line.num_edges(), 2);
+    assert!(line.has_edge(0, 1));
+    assert!(line.has_edge(1, 2));
+    assert!(!line.has_edge(0, 2));
+}
+
+#[test]
+fn test_minimumedgedominatingset_to_minimumdominatingset_closed_loop() {
+    let source = MinimumEdgeDominatingSet::new(
+        SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]),
+        vec![1i32; 5],
+    );
+    let reduction = ReduceTo::<MinimumDominatingSet<SimpleGraph, i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+    assert_eq!(target.num_vertices(), 5);
+    assert_eq!(target.num_edges(), 5);
+
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MinimumEdgeDominatingSet->MinimumDominatingSet closed loop",
+    );
+}
+
+#[test]
+fn test_minimumedgedominatingset_to_minimumdominatingset_weighted() {
+    let source = MinimumEdgeDominatingSet::new(
+        SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (1, 4)]),
+        vec![3, 4, 1, 2],
+    );
+    let reduction = ReduceTo::<MinimumDominatingSet<SimpleGraph, i32>>::reduce_to(&source);
+    let solver = BruteForce::new();
+    assert_eq!(
+        solver.solve(reduction.target_problem()),
+        solver.solve(&source)
+    );
+    assert_eq!(solver.solve(&source), Min(Some(3)));
+
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MinimumEdgeDominatingSet->MinimumDominatingSet weighted",
+    );
+}
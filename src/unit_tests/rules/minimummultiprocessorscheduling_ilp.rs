@@ -0,0 +1,59 @@
+use super::*;
+use crate::solvers::{BruteForce, ILPSolver, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+
+#[test]
+fn test_reduction_creates_valid_ilp() {
+    let problem = MinimumMultiprocessorScheduling::new(vec![2, 3, 2], 2);
+    let reduction: ReductionMinMPSToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+    let ilp = reduction.target_problem();
+
+    // 3 tasks * 2 processors + makespan
+    assert_eq!(ilp.num_vars, 7);
+    // 3 assignment + 2 load + 6 binary bounds
+    assert_eq!(ilp.constraints.len(), 11);
+    assert_eq!(ilp.sense, ObjectiveSense::Minimize);
+    assert_eq!(ilp.objective, vec![(6, 1.0)]);
+}
+
+#[test]
+fn test_minimummultiprocessorscheduling_to_ilp_closed_loop() {
+    // jobs {3, 3, 2, 2, 2} on 2 machines: makespan 6
+    let problem = MinimumMultiprocessorScheduling::new(vec![3, 3, 2, 2, 2], 2);
+    let reduction: ReductionMinMPSToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("ILP should be solvable");
+    assert_eq!(ilp_solution[10], 6, "makespan variable");
+    let extracted = reduction.extract_solution(&ilp_solution);
+    assert_eq!(problem.evaluate(&extracted), Min(Some(6)));
+}
+
+#[test]
+fn test_minimummultiprocessorscheduling_to_ilp_bf_vs_ilp() {
+    let problem = MinimumMultiprocessorScheduling::new(vec![4, 5, 3, 2, 6], 3);
+    let reduction: ReductionMinMPSToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("ILP should be solvable");
+    let extracted = reduction.extract_solution(&ilp_solution);
+    assert_eq!(
+        problem.evaluate(&extracted),
+        BruteForce::new().solve(&problem)
+    );
+    assert_eq!(problem.evaluate(&extracted), Min(Some(7)));
+}
+
+#[test]
+fn test_solution_extraction() {
+    let problem = MinimumMultiprocessorScheduling::new(vec![1, 2, 3], 2);
+    let reduction: ReductionMinMPSToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+
+    // task 0 -> proc 0, task 1 -> proc 1, task 2 -> proc 0, makespan 4
+    let extracted = reduction.extract_solution(&[1, 0, 0, 1, 1, 0, 4]);
+    assert_eq!(extracted, vec![0, 1, 0]);
+    assert_eq!(problem.evaluate(&extracted), Min(Some(4)));
+}
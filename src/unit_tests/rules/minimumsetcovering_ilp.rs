@@ -1,4 +1,5 @@
 use super::*;
+use crate::models::algebraic::ObjectiveSense;
 use crate::solvers::{BruteForce, ILPSolver};
 use crate::traits::Problem;
 use crate::types::Min;
@@ -232,3 +233,18 @@ fn test_minimumsetcovering_to_ilp_bf_vs_ilp() {
     let reduction: ReductionSCToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
     crate::rules::test_helpers::assert_bf_vs_ilp(&problem, &reduction);
 }
+
+#[test]
+fn test_coverage_requirements_become_rhs() {
+    let problem = MinimumSetCovering::<i32>::new(3, vec![vec![0, 1], vec![1, 2], vec![2, 0]])
+        .with_coverage_requirements(vec![2, 2, 1]);
+    let reduction: ReductionSCToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let rhs: Vec<f64> = reduction
+        .target_problem()
+        .constraints
+        .iter()
+        .map(|c| c.rhs)
+        .collect();
+    assert_eq!(rhs, vec![2.0, 2.0, 1.0]);
+    crate::rules::test_helpers::assert_bf_vs_ilp(&problem, &reduction);
+}
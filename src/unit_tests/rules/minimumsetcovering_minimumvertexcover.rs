@@ -0,0 +1,100 @@
+use super::*;
+use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+
+#[test]
+fn test_minimumsetcovering_to_minimumvertexcover_structure() {
+    // Sets: S0={0,1}, S1={1,2}, S2={0,2}
+    let source = MinimumSetCovering::with_weights(
+        3,
+        vec![vec![0, 1], vec![1, 2], vec![0, 2]],
+        vec![4, 5, 6],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<HyperGraph, i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.num_vertices(), 3);
+    // Element e becomes the hyperedge of sets containing it
+    assert_eq!(
+        target.graph().edges(),
+        &[vec![0, 2], vec![0, 1], vec![1, 2]]
+    );
+    assert_eq!(target.weights(), &[4, 5, 6]);
+}
+
+#[test]
+fn test_minimumsetcovering_to_minimumvertexcover_closed_loop() {
+    let source = MinimumSetCovering::<i32>::new(
+        6,
+        vec![
+            vec![0, 1, 2],
+            vec![3, 4, 5],
+            vec![0, 3],
+            vec![1, 4],
+            vec![2, 5],
+        ],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<HyperGraph, i32>>::reduce_to(&source);
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "SC -> MVC<HyperGraph>",
+    );
+    assert_eq!(
+        BruteForce::new().solve(reduction.target_problem()),
+        Min(Some(2))
+    );
+}
+
+#[test]
+fn test_minimumsetcovering_to_minimumvertexcover_uncoverable_element() {
+    // Element 2 lies in no set: the empty hyperedge makes the cover infeasible
+    let source = MinimumSetCovering::<i32>::new(3, vec![vec![0], vec![1]]);
+    let reduction = ReduceTo::<MinimumVertexCover<HyperGraph, i32>>::reduce_to(&source);
+    assert_eq!(BruteForce::new().solve(&source), Min(None));
+    assert_eq!(
+        BruteForce::new().solve(reduction.target_problem()),
+        Min(None)
+    );
+}
+
+#[test]
+fn test_minimumsetcovering_to_minimumvertexcover_zero_requirement_drops_element() {
+    let source = MinimumSetCovering::<i32>::new(2, vec![vec![0], vec![1]])
+        .with_coverage_requirements(vec![1, 0]);
+    let reduction = ReduceTo::<MinimumVertexCover<HyperGraph, i32>>::reduce_to(&source);
+    assert_eq!(reduction.target_problem().graph().edges(), &[vec![0]]);
+    assert_eq!(
+        BruteForce::new().solve(reduction.target_problem()),
+        BruteForce::new().solve(&source)
+    );
+}
+
+#[test]
+#[should_panic(expected = "set multicover does not reduce to vertex cover")]
+fn test_minimumsetcovering_to_minimumvertexcover_rejects_multicover() {
+    let source = MinimumSetCovering::<i32>::new(2, vec![vec![0, 1], vec![1]])
+        .with_coverage_requirements(vec![1, 2]);
+    let _ = ReduceTo::<MinimumVertexCover<HyperGraph, i32>>::reduce_to(&source);
+}
+
+#[test]
+fn test_sc_hvc_round_trip_preserves_instance() {
+    let source = MinimumSetCovering::with_weights(
+        4,
+        vec![vec![0, 1], vec![1, 2, 3], vec![0, 3]],
+        vec![2, 3, 1],
+    );
+    let to_hvc = ReduceTo::<MinimumVertexCover<HyperGraph, i32>>::reduce_to(&source);
+    let back = ReduceTo::<MinimumSetCovering<i32>>::reduce_to(to_hvc.target_problem());
+    let back = back.target_problem();
+
+    assert_eq!(back.universe_size(), source.universe_size());
+    assert_eq!(back.sets(), source.sets());
+    assert_eq!(back.weights_ref(), source.weights_ref());
+    for config in [[1, 1, 0], [0, 1, 1], [1, 0, 1], [1, 0, 0]] {
+        assert_eq!(back.evaluate(&config), source.evaluate(&config));
+    }
+}
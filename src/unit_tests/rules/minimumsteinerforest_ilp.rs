@@ -0,0 +1,101 @@
+use super::*;
+use crate::models::algebraic::{ObjectiveSense, ILP};
+use crate::models::graph::MinimumSteinerForest;
+use crate::rules::ReduceTo;
+use crate::solvers::{BruteForce, ILPSolver};
+use crate::topology::SimpleGraph;
+use crate::traits::Problem;
+use crate::types::Min;
+
+fn canonical_instance() -> MinimumSteinerForest<SimpleGraph, i32> {
+    let graph = SimpleGraph::new(
+        6,
+        vec![(0, 1), (1, 2), (0, 3), (2, 5), (3, 4), (4, 5), (1, 4)],
+    );
+    MinimumSteinerForest::new(graph, vec![1, 1, 4, 4, 1, 1, 3], vec![(0, 2), (3, 5)])
+}
+
+#[test]
+fn test_reduction_creates_expected_ilp_shape() {
+    let problem = canonical_instance();
+    let reduction: ReductionSteinerForestToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let ilp = reduction.target_problem();
+
+    // 7 selectors + 2 pairs * 2 directions * 7 edges
+    assert_eq!(ilp.num_vars, 35);
+    // 2 pairs * 6 vertices conservation + 2 pairs * 2 * 7 capacity links
+    assert_eq!(ilp.constraints.len(), 40);
+    assert_eq!(ilp.sense, ObjectiveSense::Minimize);
+    assert_eq!(
+        ilp.objective,
+        vec![
+            (0, 1.0),
+            (1, 1.0),
+            (2, 4.0),
+            (3, 4.0),
+            (4, 1.0),
+            (5, 1.0),
+            (6, 3.0),
+        ]
+    );
+}
+
+#[test]
+fn test_minimumsteinerforest_to_ilp_closed_loop() {
+    let problem = canonical_instance();
+    let reduction: ReductionSteinerForestToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("ILP should be solvable");
+    let extracted = reduction.extract_solution(&ilp_solution);
+
+    assert_eq!(extracted, vec![1, 1, 0, 0, 1, 1, 0]);
+    assert_eq!(problem.evaluate(&extracted), Min(Some(4)));
+}
+
+#[test]
+fn test_minimumsteinerforest_to_ilp_disjoint_subpaths() {
+    // Path 0-1-2-3-4-5 with demands (0, 2) and (3, 5).
+    let problem =
+        MinimumSteinerForest::<_, i32>::unit_weights(SimpleGraph::path(6), vec![(0, 2), (3, 5)]);
+    let reduction: ReductionSteinerForestToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("ILP should be solvable");
+    assert_eq!(
+        reduction.extract_solution(&ilp_solution),
+        vec![1, 1, 0, 1, 1]
+    );
+}
+
+#[test]
+fn test_solution_extraction_reads_edge_selector_prefix() {
+    let problem = canonical_instance();
+    let reduction: ReductionSteinerForestToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    let mut target_solution = vec![0; 35];
+    for edge in [0, 1, 4, 5] {
+        target_solution[edge] = 1;
+    }
+    assert_eq!(
+        reduction.extract_solution(&target_solution),
+        vec![1, 1, 0, 0, 1, 1, 0]
+    );
+}
+
+#[test]
+#[should_panic(expected = "MinimumSteinerForest -> ILP requires strictly positive edge weights")]
+fn test_reduction_rejects_zero_weights() {
+    let problem = MinimumSteinerForest::new(SimpleGraph::path(3), vec![0, 1], vec![(0, 2)]);
+    let _ = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+}
+
+#[test]
+fn test_minimumsteinerforest_to_ilp_bf_vs_ilp() {
+    let problem = canonical_instance();
+    let reduction: ReductionSteinerForestToILP = ReduceTo::<ILP<bool>>::reduce_to(&problem);
+    assert_eq!(
+        problem.evaluate(&BruteForce::new().find_witness(&problem).unwrap()),
+        Min(Some(4))
+    );
+    crate::rules::test_helpers::assert_bf_vs_ilp(&problem, &reduction);
+}
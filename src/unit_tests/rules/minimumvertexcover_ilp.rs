@@ -1,8 +1,8 @@
 use crate::models::algebraic::{ObjectiveSense, ILP};
 use crate::models::graph::MinimumVertexCover;
-use crate::rules::{MinimizeSteps, ReductionChain, ReductionGraph, ReductionPath};
+use crate::rules::{MinimizeStepsThenOverhead, ReductionChain, ReductionGraph, ReductionPath};
 use crate::solvers::{BruteForce, ILPSolver};
-use crate::topology::SimpleGraph;
+use crate::topology::{Graph, SimpleGraph};
 use crate::traits::Problem;
 use crate::types::{Min, ProblemSize};
 
@@ -18,8 +18,12 @@ fn reduce_vc_to_ilp(
             &src,
             "ILP",
             &dst,
-            &ProblemSize::new(vec![]),
-            &MinimizeSteps,
+            // Size-aware tie-break between the equally short routes.
+            &ProblemSize::new(vec![
+                ("num_vertices", problem.graph().num_vertices()),
+                ("num_edges", problem.graph().num_edges()),
+            ]),
+            &MinimizeStepsThenOverhead,
         )
         .expect("Should find path MinimumVertexCover -> ILP");
     let chain = graph
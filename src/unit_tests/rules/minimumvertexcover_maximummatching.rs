@@ -0,0 +1,73 @@
+use super::*;
+use crate::rules::test_helpers::solve_optimization_problem;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::{Max, Min};
+
+fn k23() -> MinimumVertexCover<SimpleGraph, One> {
+    let graph = SimpleGraph::new(5, vec![(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4)]);
+    MinimumVertexCover::new(graph, vec![One; 5])
+}
+
+#[test]
+fn test_minimumvertexcover_to_maximummatching_k23() {
+    let source = k23();
+    let reduction = ReductionVCToMatching::try_new(&source).unwrap();
+    let target = reduction.target_problem();
+    assert_eq!(target.graph().num_edges(), 6);
+
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(target), Max(Some(2)));
+    assert_eq!(solver.solve(&source), Min(Some(2)));
+
+    // Every maximum matching yields the smaller side as a minimum cover.
+    for matching in solver.find_all_witnesses(target) {
+        let cover = reduction.extract_solution(&matching);
+        assert_eq!(cover, vec![1, 1, 0, 0, 0]);
+        assert_eq!(source.evaluate(&cover), Min(Some(2)));
+    }
+}
+
+#[test]
+fn test_minimumvertexcover_to_maximummatching_alternating_paths() {
+    // Path 0-1-2-3-4: left part {0, 2, 4}, maximum matching has 2 edges,
+    // so some left vertex stays unmatched and alternating paths decide the cover.
+    let source = MinimumVertexCover::new(
+        SimpleGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4)]),
+        vec![One; 5],
+    );
+    let reduction = ReductionVCToMatching::try_new(&source).unwrap();
+    let solver = BruteForce::new();
+    let optimum = solve_optimization_problem(&source).unwrap();
+
+    for matching in solver.find_all_witnesses(reduction.target_problem()) {
+        let cover = reduction.extract_solution(&matching);
+        assert_eq!(source.evaluate(&cover), source.evaluate(&optimum));
+    }
+}
+
+#[test]
+fn test_minimumvertexcover_to_maximummatching_disconnected() {
+    // An isolated vertex and a 4-cycle.
+    let source = MinimumVertexCover::new(
+        SimpleGraph::new(5, vec![(1, 2), (2, 3), (3, 4), (4, 1)]),
+        vec![One; 5],
+    );
+    let reduction = ReductionVCToMatching::try_new(&source).unwrap();
+    let matching = BruteForce::new()
+        .find_witness(reduction.target_problem())
+        .unwrap();
+    let cover = reduction.extract_solution(&matching);
+    assert_eq!(cover[0], 0);
+    assert_eq!(source.evaluate(&cover), Min(Some(2)));
+}
+
+#[test]
+fn test_minimumvertexcover_to_maximummatching_rejects_odd_cycle() {
+    let source = MinimumVertexCover::new(
+        SimpleGraph::new(3, vec![(0, 1), (1, 2), (0, 2)]),
+        vec![One; 3],
+    );
+    let err = ReductionVCToMatching::try_new(&source).unwrap_err();
+    assert!(err.to_string().contains("bipartite"), "{err}");
+}
@@ -0,0 +1,123 @@
+use super::*;
+use crate::rules::test_helpers::{
+    assert_optimization_round_trip_from_optimization_target, assert_reduction_preserves_optimum,
+};
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+use rand::RngExt;
+
+fn unit_vc(n: usize, edges: Vec<(usize, usize)>) -> MinimumVertexCover<SimpleGraph, i32> {
+    MinimumVertexCover::new(SimpleGraph::new(n, edges), vec![1i32; n])
+}
+
+fn reduce(source: &MinimumVertexCover<SimpleGraph, i32>) -> ReductionVCToDS {
+    ReduceTo::<MinimumDominatingSet<SimpleGraph, i32>>::reduce_to(source)
+}
+
+#[test]
+fn test_minimumvertexcover_to_minimumdominatingset_closed_loop() {
+    let (n, edges) = crate::topology::small_graphs::bull();
+    let source = unit_vc(n, edges);
+    let reduction = reduce(&source);
+
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MinimumVertexCover->MinimumDominatingSet closed loop",
+    );
+}
+
+#[test]
+fn test_minimumvertexcover_to_minimumdominatingset_structure() {
+    let source = unit_vc(3, vec![(0, 1), (1, 2)]);
+    let reduction = reduce(&source);
+    let target = reduction.target_problem();
+
+    // Path 0-1-2 plus subdivision vertices 3 (edge 0-1) and 4 (edge 1-2).
+    assert_eq!(target.graph().num_vertices(), 5);
+    assert_eq!(target.graph().num_edges(), 6);
+    assert!(target.graph().has_edge(0, 1));
+    assert!(target.graph().has_edge(0, 3));
+    assert!(target.graph().has_edge(1, 3));
+    assert!(target.graph().has_edge(1, 4));
+    assert!(target.graph().has_edge(2, 4));
+    assert!(!target.graph().has_edge(0, 4));
+}
+
+#[test]
+fn test_minimumvertexcover_to_minimumdominatingset_optima() {
+    let solver = BruteForce::new();
+    let (bull_n, bull_edges) = crate::topology::small_graphs::bull();
+    let cases = [
+        (SimpleGraph::path(5), 2),
+        (SimpleGraph::star(5), 1),
+        (SimpleGraph::new(bull_n, bull_edges), 2),
+    ];
+
+    for (graph, expected) in cases {
+        let n = graph.num_vertices();
+        let source = MinimumVertexCover::new(graph, vec![1i32; n]);
+        let reduction = reduce(&source);
+        assert_eq!(solver.solve(&source), Min(Some(expected)));
+        assert_eq!(
+            solver.solve(reduction.target_problem()),
+            Min(Some(expected))
+        );
+    }
+}
+
+#[test]
+fn test_minimumvertexcover_to_minimumdominatingset_isolated_vertices() {
+    // Edge 0-1 plus isolated vertices 2 and 3: each isolated vertex must
+    // dominate itself, adding 1 to the target optimum.
+    let source = unit_vc(4, vec![(0, 1)]);
+    let reduction = reduce(&source);
+    let solver = BruteForce::new();
+
+    assert_eq!(solver.solve(&source), Min(Some(1)));
+    assert_eq!(solver.solve(reduction.target_problem()), Min(Some(3)));
+
+    let witness = solver.find_witness(reduction.target_problem()).unwrap();
+    let cover = reduction.extract_solution(&witness);
+    assert_eq!(&cover[2..], &[0, 0]);
+    assert_eq!(source.evaluate(&cover), Min(Some(1)));
+}
+
+#[test]
+fn test_minimumvertexcover_to_minimumdominatingset_extract_replaces_subdivision() {
+    // Path 0-1-2 with vertex 1 heavier than its neighbors.
+    let source = MinimumVertexCover::new(SimpleGraph::path(3), vec![1, 5, 2]);
+    let reduction = reduce(&source);
+
+    // Subdivision vertices 3 (edge 0-1) and 4 (edge 1-2) map to the lighter
+    // endpoints 0 and 2.
+    assert_eq!(reduction.extract_solution(&[0, 0, 0, 1, 1]), vec![1, 0, 1]);
+    // A subdivision vertex whose edge is already covered is dropped.
+    assert_eq!(reduction.extract_solution(&[0, 1, 0, 1, 0]), vec![0, 1, 0]);
+}
+
+#[test]
+fn test_minimumvertexcover_to_minimumdominatingset_preserves_weighted_optimum() {
+    assert_reduction_preserves_optimum(
+        &|rng| {
+            let n = rng.random_range(1..=5);
+            let edges = (0..n)
+                .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+                .filter(|_| rng.random_bool(0.5))
+                .collect();
+            let weights = (0..n).map(|_| rng.random_range(1..=5)).collect();
+            MinimumVertexCover::new(SimpleGraph::new(n, edges), weights)
+        },
+        &reduce,
+        &|source: &MinimumVertexCover<SimpleGraph, i32>, min_vc: Min<i32>| {
+            let isolated: i32 = (0..source.graph().num_vertices())
+                .filter(|&v| source.graph().degree(v) == 0)
+                .map(|v| source.weights()[v])
+                .sum();
+            Min(Some(min_vc.0.unwrap() + isolated))
+        },
+        2024,
+        30,
+    );
+}
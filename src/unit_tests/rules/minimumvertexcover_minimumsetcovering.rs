@@ -1,6 +1,6 @@
 use super::*;
 use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
-use crate::solvers::BruteForce;
+use crate::solvers::{BruteForce, Solver};
 include!("../jl_helpers.rs");
 
 #[test]
@@ -161,3 +161,54 @@ fn test_jl_parity_rule_vc_to_setcovering() {
         assert_eq!(best_source, jl_parse_configs_set(&case["best_source"]));
     }
 }
+
+fn fano_plane() -> HyperGraph {
+    HyperGraph::new(
+        7,
+        vec![
+            vec![0, 1, 2],
+            vec![0, 3, 4],
+            vec![0, 5, 6],
+            vec![1, 3, 5],
+            vec![1, 4, 6],
+            vec![2, 3, 6],
+            vec![2, 4, 5],
+        ],
+    )
+}
+
+#[test]
+fn test_hypergraph_vc_to_sc_structure() {
+    let source = MinimumVertexCover::new(fano_plane(), vec![1, 2, 3, 4, 5, 6, 7]);
+    let reduction = ReduceTo::<MinimumSetCovering<i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.universe_size(), 7);
+    assert_eq!(target.num_sets(), 7);
+    // Each point of the Fano plane lies on three lines
+    assert_eq!(target.get_set(0), Some(&vec![0, 1, 2]));
+    assert_eq!(target.get_set(3), Some(&vec![1, 3, 5]));
+    assert_eq!(target.weights_ref(), &[1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_hypergraph_vc_to_sc_closed_loop() {
+    let source = MinimumVertexCover::new(fano_plane(), vec![1i32; 7]);
+    let reduction = ReduceTo::<MinimumSetCovering<i32>>::reduce_to(&source);
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "MVC<HyperGraph> -> SC (Fano plane)",
+    );
+    assert_eq!(
+        BruteForce::new().solve(reduction.target_problem()),
+        crate::types::Min(Some(3))
+    );
+}
+
+#[test]
+fn test_hypergraph_vc_to_sc_repeated_vertex_in_edge() {
+    let source = MinimumVertexCover::new(HyperGraph::new(2, vec![vec![0, 1, 0]]), vec![1i32; 2]);
+    let reduction = ReduceTo::<MinimumSetCovering<i32>>::reduce_to(&source);
+    assert_eq!(reduction.target_problem().get_set(0), Some(&vec![0]));
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
-use crate::solvers::BruteForce;
+use crate::solvers::{BruteForce, Solver};
 
 #[test]
 fn test_paintshop_to_qubo_closed_loop() {
@@ -79,6 +79,31 @@ fn test_paintshop_to_qubo_matrix_structure() {
     assert_eq!(m[3][3], 0.0);
 }
 
+#[test]
+fn test_paintshop_to_qubo_offset_recovers_min_switches() {
+    for labels in [
+        vec!["A", "B", "C", "A", "D", "B", "D", "C"],
+        vec!["a", "b", "b", "a"],
+        vec!["a", "a", "b", "b"],
+        vec!["a", "b", "a", "c", "b", "c"],
+    ] {
+        let source = PaintShop::new(labels);
+        let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&source);
+        let qubo = reduction.target_problem();
+
+        let seq = source.sequence_indices();
+        let is_first = source.is_first();
+        let offset = (0..seq.len().saturating_sub(1))
+            .filter(|&p| seq[p] == seq[p + 1] || is_first[p] != is_first[p + 1])
+            .count();
+
+        let solver = BruteForce::new();
+        let qubo_min = solver.solve(qubo).0.unwrap();
+        let source_min = solver.solve(&source).0.unwrap();
+        assert_eq!(qubo_min + offset as f64, source_min as f64);
+    }
+}
+
 #[test]
 fn test_paintshop_to_qubo_two_cars() {
     // Two cars, adjacent: a, b, b, a
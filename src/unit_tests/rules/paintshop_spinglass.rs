@@ -0,0 +1,96 @@
+use super::*;
+use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+
+fn assert_energy_matches_switches(source: &PaintShop) {
+    let reduction = ReduceTo::<SpinGlass<SimpleGraph, i32>>::reduce_to(source);
+    let sg = reduction.target_problem();
+    let n = source.num_cars();
+    for mask in 0..1usize << n {
+        let config: Vec<usize> = (0..n).map(|i| (mask >> i) & 1).collect();
+        let energy = sg.compute_energy(&SpinGlass::<SimpleGraph, i32>::config_to_spins(&config));
+        assert_eq!(
+            2 * source.count_switches(&config) as i32,
+            reduction.offset() + energy,
+            "config {config:?}"
+        );
+    }
+}
+
+#[test]
+fn test_paintshop_to_spinglass_closed_loop() {
+    let source = PaintShop::new(vec!["A", "B", "C", "A", "D", "B", "D", "C"]);
+    let reduction = ReduceTo::<SpinGlass<SimpleGraph, i32>>::reduce_to(&source);
+    let sg = reduction.target_problem();
+
+    assert_eq!(sg.num_spins(), 4);
+    assert!(sg.fields().iter().all(|&h| h == 0));
+
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "PaintShop->SpinGlass closed loop",
+    );
+}
+
+#[test]
+fn test_paintshop_to_spinglass_couplings() {
+    // A B C A D B D C: parities F F F S F S S S
+    let source = PaintShop::new(vec!["A", "B", "C", "A", "D", "B", "D", "C"]);
+    let reduction = ReduceTo::<SpinGlass<SimpleGraph, i32>>::reduce_to(&source);
+    let mut interactions = reduction.target_problem().interactions();
+    interactions.sort();
+    assert_eq!(
+        interactions,
+        vec![
+            ((0, 1), -1),
+            ((0, 2), 1),
+            ((0, 3), 1),
+            ((1, 2), -1),
+            ((2, 3), -1),
+        ]
+    );
+    // Seven adjacent pairs of distinct cars
+    assert_eq!(reduction.offset(), 7);
+}
+
+#[test]
+fn test_paintshop_to_spinglass_energy_identity() {
+    assert_energy_matches_switches(&PaintShop::new(vec![
+        "A", "B", "C", "A", "D", "B", "D", "C",
+    ]));
+    assert_energy_matches_switches(&PaintShop::new(vec!["a", "b", "b", "a"]));
+    assert_energy_matches_switches(&PaintShop::new(vec!["a", "a", "b", "b"]));
+    assert_energy_matches_switches(&PaintShop::new(vec!["a", "b", "a", "b"]));
+}
+
+#[test]
+fn test_paintshop_to_spinglass_cancelled_couplings_are_dropped() {
+    // a b a c b c: (a,b) and (b,a) cancel, as do (c,b) and (b,c)
+    let source = PaintShop::new(vec!["a", "b", "a", "c", "b", "c"]);
+    let reduction = ReduceTo::<SpinGlass<SimpleGraph, i32>>::reduce_to(&source);
+    assert_eq!(reduction.target_problem().interactions(), vec![((0, 2), 1)]);
+    assert_energy_matches_switches(&source);
+}
+
+#[test]
+fn test_paintshop_to_spinglass_empty_sequence() {
+    let source = PaintShop::new(Vec::<&str>::new());
+    let reduction = ReduceTo::<SpinGlass<SimpleGraph, i32>>::reduce_to(&source);
+    assert_eq!(reduction.target_problem().num_spins(), 0);
+    assert_eq!(reduction.offset(), 0);
+}
+
+#[cfg(feature = "example-db")]
+#[test]
+fn test_paintshop_to_spinglass_canonical_example_spec() {
+    let spec = canonical_rule_example_specs()
+        .into_iter()
+        .find(|spec| spec.id == "paintshop_to_spinglass")
+        .expect("missing canonical PaintShop -> SpinGlass example spec");
+    let example = (spec.build)();
+
+    assert_eq!(example.source.problem, "PaintShop");
+    assert_eq!(example.target.problem, "SpinGlass");
+    assert_eq!(example.source.instance["num_cars"], 4);
+    assert!(!example.solutions.is_empty());
+}
@@ -0,0 +1,47 @@
+use super::*;
+use crate::rules::test_helpers::assert_satisfaction_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+
+#[test]
+fn test_partition_to_minimummultiprocessorscheduling_closed_loop() {
+    let source = Partition::new(vec![3, 3, 2, 2, 2]);
+    let reduction = ReduceTo::<MinimumMultiprocessorScheduling>::reduce_to(&source);
+
+    assert_satisfaction_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "Partition -> MinimumMultiprocessorScheduling closed loop",
+    );
+}
+
+#[test]
+fn test_partition_to_minimummultiprocessorscheduling_structure() {
+    let source = Partition::new(vec![1, 2, 3, 4]);
+    let reduction = ReduceTo::<MinimumMultiprocessorScheduling>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.lengths(), &[1, 2, 3, 4]);
+    assert_eq!(target.num_processors(), 2);
+    assert_eq!(target.num_tasks(), source.num_elements());
+}
+
+#[test]
+fn test_partition_to_minimummultiprocessorscheduling_makespan_threshold() {
+    let solver = BruteForce::new();
+
+    // Balanced: makespan reaches half the total.
+    let source = Partition::new(vec![3, 1, 1, 2, 2, 1]);
+    let reduction = ReduceTo::<MinimumMultiprocessorScheduling>::reduce_to(&source);
+    assert_eq!(solver.solve(reduction.target_problem()), Min(Some(5)));
+    assert!(solver.find_witness(&source).is_some());
+
+    // Even sum but no partition: the best split of {2, 2, 6} is 6 vs 4.
+    let source = Partition::new(vec![2, 2, 6]);
+    let reduction = ReduceTo::<MinimumMultiprocessorScheduling>::reduce_to(&source);
+    assert_eq!(solver.solve(reduction.target_problem()), Min(Some(6)));
+    assert!(solver.find_witness(&source).is_none());
+    let witness = solver.find_witness(reduction.target_problem()).unwrap();
+    assert!(!source.evaluate(&reduction.extract_solution(&witness)).0);
+}
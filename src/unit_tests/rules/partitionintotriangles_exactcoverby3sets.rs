@@ -0,0 +1,98 @@
+use super::*;
+use crate::rules::test_helpers::assert_satisfaction_round_trip_from_satisfaction_target;
+use crate::solvers::BruteForce;
+use crate::traits::Problem;
+
+fn complete_graph(n: usize) -> SimpleGraph {
+    let edges = (0..n)
+        .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+        .collect();
+    SimpleGraph::new(n, edges)
+}
+
+#[test]
+fn test_partitionintotriangles_to_exactcoverby3sets_closed_loop() {
+    let source = PartitionIntoTriangles::new(SimpleGraph::new(
+        6,
+        vec![(0, 1), (0, 2), (1, 2), (3, 4), (3, 5), (4, 5), (0, 3)],
+    ));
+    let reduction = ReduceTo::<ExactCoverBy3Sets>::reduce_to(&source);
+
+    assert_eq!(
+        reduction.target_problem().subsets(),
+        &[[0, 1, 2], [3, 4, 5]]
+    );
+    assert_satisfaction_round_trip_from_satisfaction_target(
+        &source,
+        &reduction,
+        "PartitionIntoTriangles -> ExactCoverBy3Sets closed loop",
+    );
+}
+
+#[test]
+fn test_partitionintotriangles_to_exactcoverby3sets_k6() {
+    let source = PartitionIntoTriangles::new(complete_graph(6));
+    let reduction = ReduceTo::<ExactCoverBy3Sets>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    // Every triple of K6 is a triangle; C(6, 3) = 20
+    assert_eq!(target.universe_size(), 6);
+    assert_eq!(target.num_subsets(), 20);
+
+    let solver = BruteForce::new();
+    assert!(solver.find_witness(&source).is_some());
+    // Each of the 10 splits of V into two triples is an exact cover
+    let covers = solver.find_all_witnesses(target);
+    assert_eq!(covers.len(), 10);
+    for cover in &covers {
+        assert!(source.evaluate(&reduction.extract_solution(cover)).0);
+    }
+}
+
+#[test]
+fn test_partitionintotriangles_to_exactcoverby3sets_c6() {
+    let source = PartitionIntoTriangles::new(SimpleGraph::new(
+        6,
+        vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (0, 5)],
+    ));
+    let reduction = ReduceTo::<ExactCoverBy3Sets>::reduce_to(&source);
+
+    // C6 is triangle-free, so the target has no subsets at all
+    assert_eq!(reduction.target_problem().num_subsets(), 0);
+    let solver = BruteForce::new();
+    assert!(solver.find_witness(&source).is_none());
+    assert!(solver.find_witness(reduction.target_problem()).is_none());
+}
+
+#[test]
+fn test_partitionintotriangles_to_exactcoverby3sets_overlapping_triangles() {
+    // Two triangles sharing vertex 2 plus a disjoint triangle: no partition
+    let source = PartitionIntoTriangles::new(SimpleGraph::new(
+        6,
+        vec![(0, 1), (0, 2), (1, 2), (2, 3), (2, 4), (3, 4), (0, 5)],
+    ));
+    let reduction = ReduceTo::<ExactCoverBy3Sets>::reduce_to(&source);
+
+    assert_eq!(
+        reduction.target_problem().subsets(),
+        &[[0, 1, 2], [2, 3, 4]]
+    );
+    assert!(BruteForce::new()
+        .find_witness(reduction.target_problem())
+        .is_none());
+    assert!(BruteForce::new().find_witness(&source).is_none());
+}
+
+#[test]
+fn test_partitionintotriangles_to_exactcoverby3sets_extract_groups() {
+    let source = PartitionIntoTriangles::new(complete_graph(6));
+    let reduction = ReduceTo::<ExactCoverBy3Sets>::reduce_to(&source);
+    let subsets = reduction.target_problem().subsets();
+    let first = subsets.iter().position(|s| s == &[0, 3, 5]).unwrap();
+    let second = subsets.iter().position(|s| s == &[1, 2, 4]).unwrap();
+    let mut config = vec![0; subsets.len()];
+    config[first] = 1;
+    config[second] = 1;
+
+    assert_eq!(reduction.extract_solution(&config), vec![0, 1, 1, 0, 1, 0]);
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::io::{from_opb, to_opb};
+use crate::models::algebraic::{Comparison, PseudoBooleanConstraint};
+use crate::solvers::{BruteForce, ILPSolver, Solver};
+use crate::traits::Problem;
+
+#[test]
+fn test_pseudoboolean_to_ilp_structure() {
+    let source = PseudoBoolean::new(
+        3,
+        vec![(0, 1), (2, -4)],
+        vec![
+            PseudoBooleanConstraint::ge(vec![(0, 1), (1, 1), (2, 1)], 2),
+            PseudoBooleanConstraint::le(vec![(0, 3), (1, -2)], 1),
+            PseudoBooleanConstraint::eq(vec![(2, 1)], 1),
+        ],
+    );
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    let ilp = reduction.target_problem();
+
+    assert_eq!(ilp.num_vars, 3);
+    assert_eq!(ilp.sense, ObjectiveSense::Minimize);
+    assert_eq!(ilp.objective, vec![(0, 1.0), (2, -4.0)]);
+    let cmps: Vec<Comparison> = ilp.constraints.iter().map(|c| c.cmp).collect();
+    assert_eq!(cmps, vec![Comparison::Ge, Comparison::Le, Comparison::Eq]);
+    assert_eq!(ilp.constraints[1].terms, vec![(0, 3.0), (1, -2.0)]);
+    assert_eq!(ilp.constraints[1].rhs, 1.0);
+
+    // Every configuration keeps its feasibility and objective.
+    for config in [[0, 0, 1], [1, 1, 1], [0, 1, 1], [1, 1, 0]] {
+        let pb = source.evaluate(&config);
+        let lp = ilp.evaluate(&config);
+        assert_eq!(pb.0.map(|v| v as f64), lp.value, "config {config:?}");
+    }
+}
+
+#[test]
+fn test_pseudoboolean_opb_optimum_matches_ilp_solver() {
+    // A small set-cover-like instance written as an OPB file.
+    let opb = "\
+* #variable= 5 #constraint= 4
+min: +3 x1 +2 x2 +4 x3 +1 x4 +2 x5 ;
++1 x1 +1 x2 >= 1 ;
++1 x2 +1 x3 +1 x4 >= 1 ;
++1 x1 +1 x4 +1 x5 >= 2 ;
++1 x3 +1 x5 <= 1 ;
+";
+    let source = from_opb(opb).unwrap();
+    let written = to_opb(&source);
+    assert_eq!(to_opb(&from_opb(&written).unwrap()), written);
+
+    let brute_force = BruteForce::new().solve(&source);
+    let reduction = ReduceTo::<ILP<bool>>::reduce_to(&source);
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("ILP should be solvable");
+    let extracted = reduction.extract_solution(&ilp_solution);
+    assert_eq!(source.evaluate(&extracted), brute_force);
+}
@@ -0,0 +1,69 @@
+use super::*;
+use crate::models::algebraic::PseudoBooleanConstraint;
+use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+
+#[test]
+fn test_pseudoboolean_to_qubo_closed_loop() {
+    let source = PseudoBoolean::new(
+        3,
+        vec![(0, 1), (1, 2), (2, 3)],
+        vec![
+            PseudoBooleanConstraint::ge(vec![(0, 1), (1, 1), (2, 1)], 2),
+            PseudoBooleanConstraint::le(vec![(0, 1), (1, 1)], 1),
+        ],
+    );
+    let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&source);
+    assert_eq!(reduction.target_problem().num_vars(), 5);
+
+    assert_optimization_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "PseudoBoolean->QUBO closed loop",
+    );
+}
+
+#[test]
+fn test_pseudoboolean_to_qubo_negative_coefficients_and_equality() {
+    // minimize -2x0 - x1 + x2  s.t.  2x0 - x1 + x2 <= 1,  x1 + x2 = 1
+    let source = PseudoBoolean::new(
+        3,
+        vec![(0, -2), (1, -1), (2, 1)],
+        vec![
+            PseudoBooleanConstraint::le(vec![(0, 2), (1, -1), (2, 1)], 1),
+            PseudoBooleanConstraint::eq(vec![(1, 1), (2, 1)], 1),
+        ],
+    );
+    let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&source);
+    // 2x0 - x1 + x2 <= 1 has slack 0..=2: two bits
+    assert_eq!(reduction.target_problem().num_vars(), 5);
+
+    let solver = BruteForce::new();
+    let best = solver.find_all_witnesses(reduction.target_problem());
+    for target in &best {
+        let extracted = reduction.extract_solution(target);
+        assert_eq!(source.evaluate(&extracted), Min(Some(-3)));
+    }
+    assert_eq!(solver.solve(&source), Min(Some(-3)));
+}
+
+#[test]
+fn test_pseudoboolean_to_qubo_satisfaction() {
+    // Pure satisfaction: exactly one of x0, x1 and x1 implies x2.
+    let source = PseudoBoolean::new(
+        3,
+        vec![],
+        vec![
+            PseudoBooleanConstraint::eq(vec![(0, 1), (1, 1)], 1),
+            PseudoBooleanConstraint::ge(vec![(1, -1), (2, 1)], 0),
+        ],
+    );
+    let reduction = ReduceTo::<QUBO<f64>>::reduce_to(&source);
+    let best = BruteForce::new().find_all_witnesses(reduction.target_problem());
+    assert!(!best.is_empty());
+    for target in &best {
+        assert!(source.is_feasible(&reduction.extract_solution(target)));
+    }
+}
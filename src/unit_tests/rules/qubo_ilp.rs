@@ -75,3 +75,38 @@ fn test_qubo_to_ilp_3var() {
     let extracted = reduction.extract_solution(&best[0]);
     assert_eq!(extracted, vec![1, 0, 1]);
 }
+
+#[test]
+fn test_qubo_to_ilp_random_6var_matches_brute_force() {
+    use rand::rngs::SmallRng;
+    use rand::{RngExt, SeedableRng};
+
+    let n = 6;
+    for seed in 0..8 {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let mut matrix = vec![vec![0.0; n]; n];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for q in row.iter_mut().skip(i) {
+                // Leave about a third of the entries at zero to vary the sparsity.
+                if rng.random_range(0..3) > 0 {
+                    *q = rng.random_range(-5..=5) as f64;
+                }
+            }
+        }
+        let qubo = QUBO::from_matrix(matrix);
+        let reduction = ReduceTo::<ILP<bool>>::reduce_to(&qubo);
+        let ilp = reduction.target_problem();
+        let m = qubo.num_interactions();
+        assert_eq!(ilp.num_vars, n + m, "seed {seed}");
+        assert_eq!(ilp.constraints.len(), 3 * m, "seed {seed}");
+
+        let bf_solution = BruteForce::new().find_witness(&qubo).unwrap();
+        let ilp_solution = ILPSolver::new().solve(ilp).expect("ILP should be solvable");
+        let extracted = reduction.extract_solution(&ilp_solution);
+        assert_eq!(
+            qubo.evaluate(&extracted),
+            qubo.evaluate(&bf_solution),
+            "seed {seed}"
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use super::*;
+use crate::models::algebraic::{ObjectiveSense, ILP};
+use crate::solvers::{BruteForce, ILPSolver};
+use crate::traits::Problem;
+
+fn dominoes(count: usize, allow_rotation: bool) -> RectanglePacking {
+    RectanglePacking::new(vec![(1, 2); count], 2, 2, allow_rotation)
+}
+
+/// Lift a valid packing to an ILP assignment by choosing, for every pair,
+/// the first separation direction that holds.
+fn lift_packing(problem: &RectanglePacking, config: &[usize]) -> Vec<i64> {
+    let placements = problem.placements(config).unwrap();
+    let n = placements.len();
+    let mut values = vec![0i64; 3 * n];
+    for (i, vars) in config.chunks(problem.vars_per_rectangle()).enumerate() {
+        values[3 * i] = vars[0] as i64;
+        values[3 * i + 1] = vars[1] as i64;
+        values[3 * i + 2] = vars.get(2).copied().unwrap_or(0) as i64;
+    }
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (x_i, y_i, w_i, h_i) = placements[i];
+            let (x_j, y_j, w_j, h_j) = placements[j];
+            let directions = [
+                x_i + w_i <= x_j,
+                x_j + w_j <= x_i,
+                y_i + h_i <= y_j,
+                y_j + h_j <= y_i,
+            ];
+            let chosen = directions.iter().position(|&holds| holds).unwrap();
+            values.extend((0..4).map(|k| i64::from(k == chosen)));
+        }
+    }
+    values
+}
+
+#[test]
+fn test_rectanglepacking_to_ilp_structure() {
+    let problem = dominoes(2, false);
+    let reduction: ReductionRectanglePackingToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+    let ilp = reduction.target_problem();
+
+    // 3 per rectangle + 4 selectors for the single pair
+    assert_eq!(ilp.num_vars, 10);
+    // 3 per rectangle + 9 per pair
+    assert_eq!(ilp.constraints.len(), 15);
+    assert_eq!(ilp.sense, ObjectiveSense::Minimize);
+    assert!(ilp.objective.is_empty());
+}
+
+#[test]
+fn test_rectanglepacking_to_ilp_every_packing_is_feasible() {
+    for problem in [
+        dominoes(2, false),
+        dominoes(2, true),
+        RectanglePacking::new(vec![(1, 2), (2, 1), (2, 1)], 3, 2, true),
+    ] {
+        let reduction: ReductionRectanglePackingToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+        let ilp = reduction.target_problem();
+        let packings = BruteForce::new().find_all_witnesses(&problem);
+        assert!(!packings.is_empty());
+        for packing in packings {
+            let values = lift_packing(&problem, &packing);
+            assert!(ilp.is_feasible(&values), "packing {packing:?}");
+
+            let target: Vec<usize> = values.iter().map(|&v| v as usize).collect();
+            assert_eq!(reduction.extract_solution(&target), packing);
+        }
+    }
+}
+
+#[test]
+fn test_rectanglepacking_to_ilp_rejects_overlap_and_forbidden_rotation() {
+    let problem = dominoes(2, false);
+    let reduction: ReductionRectanglePackingToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+    let ilp = reduction.target_problem();
+
+    // Both dominoes in column 0, whichever selector is set.
+    for k in 0..4 {
+        let mut values = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        values[6 + k] = 1;
+        assert!(!ilp.is_feasible(&values), "selector {k}");
+    }
+
+    // Rotating a domino is forbidden without the rotation flag.
+    assert!(!ilp.is_feasible(&[0, 0, 1, 1, 0, 0, 1, 0, 0, 0]));
+}
+
+#[test]
+fn test_rectanglepacking_to_ilp_closed_loop() {
+    let problem = dominoes(2, false);
+    let reduction: ReductionRectanglePackingToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+    let ilp_solution = ILPSolver::new()
+        .solve(reduction.target_problem())
+        .expect("two dominoes fit in a 2x2 box");
+    let extracted = reduction.extract_solution(&ilp_solution);
+    assert!(problem.evaluate(&extracted).0);
+}
+
+#[test]
+fn test_rectanglepacking_to_ilp_infeasible() {
+    let problem = dominoes(3, true);
+    let reduction: ReductionRectanglePackingToILP = ReduceTo::<ILP<i32>>::reduce_to(&problem);
+    assert!(
+        ILPSolver::new().solve(reduction.target_problem()).is_none(),
+        "three dominoes cannot fit in a 2x2 box"
+    );
+}
@@ -136,8 +136,8 @@ fn test_jl_parity_factoring_to_spinglass_path() {
         )
         .expect("Should find path Factoring -> SpinGlass");
 
-    // Julia: Factoring(2, 1, 3) — factor 3 with 2-bit x 1-bit
-    let factoring = Factoring::new(2, 1, 3);
+    // Factor 6 = 2 × 3 with 2-bit factors (Julia's Factoring(2, 1, 3) only has 3 × 1)
+    let factoring = Factoring::new(2, 2, 6);
     let chain = graph
         .reduce_along_path(&rpath, &factoring as &dyn std::any::Any)
         .expect("Should reduce along path");
@@ -210,3 +210,71 @@ fn test_find_cheapest_path_with_problem_size() {
 
     assert!(!rpath.type_names().is_empty());
 }
+
+/// The type-erased path (`load_dyn` + `reduce_along_path`) must model, reduce,
+/// and evaluate Satisfiability -> MaximumIndependentSet exactly like the typed API.
+#[test]
+fn test_sat_to_mis_dynamic_path_matches_typed_reduction() {
+    use crate::models::formula::{CNFClause, Satisfiability};
+    use crate::models::graph::MaximumIndependentSet;
+    use crate::registry::load_dyn;
+    use crate::rules::traits::{ReduceTo, ReductionResult};
+    use crate::types::One;
+
+    // (x1 v x2) ^ (~x1 v x3) ^ (~x2 v ~x3)
+    let source = Satisfiability::new(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![-1, 3]),
+            CNFClause::new(vec![-2, -3]),
+        ],
+    );
+    let src_var = ReductionGraph::variant_to_map(&Satisfiability::variant());
+    let dst_var =
+        ReductionGraph::variant_to_map(&MaximumIndependentSet::<SimpleGraph, One>::variant());
+
+    let loaded = load_dyn(
+        "Satisfiability",
+        &src_var,
+        serde_json::to_value(&source).unwrap(),
+    )
+    .expect("Satisfiability should load dynamically");
+    assert_eq!(loaded.dims_dyn(), source.dims());
+
+    let graph = ReductionGraph::new();
+    let rpath = graph
+        .find_cheapest_path(
+            "Satisfiability",
+            &src_var,
+            "MaximumIndependentSet",
+            &dst_var,
+            &ProblemSize::new(vec![]),
+            &MinimizeSteps,
+        )
+        .expect("Should find path Satisfiability -> MaximumIndependentSet");
+    assert_eq!(rpath.len(), 1);
+    let chain = graph
+        .reduce_along_path(&rpath, loaded.as_any())
+        .expect("Should reduce along path");
+    let reduction = ReduceTo::<MaximumIndependentSet<SimpleGraph, One>>::reduce_to(&source);
+
+    let dynamic_target: &MaximumIndependentSet<SimpleGraph, One> = chain.target_problem();
+    let typed_target = reduction.target_problem();
+    assert_eq!(
+        serde_json::to_value(dynamic_target).unwrap(),
+        serde_json::to_value(typed_target).unwrap()
+    );
+
+    let witnesses = BruteForce::new().find_all_witnesses(typed_target);
+    assert!(!witnesses.is_empty());
+    for witness in &witnesses {
+        let typed_solution = reduction.extract_solution(witness);
+        assert_eq!(chain.extract_solution(witness), typed_solution);
+        assert_eq!(
+            loaded.evaluate_dyn(&typed_solution),
+            source.evaluate(&typed_solution).to_string()
+        );
+        assert!(source.evaluate(&typed_solution).0);
+    }
+}
@@ -42,6 +42,86 @@ fn test_reduction_overhead_default() {
     assert!(overhead.output_size.is_empty());
 }
 
+#[test]
+fn test_reduction_overhead_add_sums_matching_fields() {
+    let a = ReductionOverhead::new(vec![
+        ("num_vars", Expr::Var("n")),
+        ("num_constraints", Expr::Var("m")),
+    ]);
+    let b = ReductionOverhead::new(vec![
+        ("num_vars", Expr::Const(2.0) * Expr::Var("n")),
+        ("num_slack", Expr::Var("m")),
+    ]);
+
+    let sum = a + b;
+    let names: Vec<_> = sum.output_size.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, vec!["num_vars", "num_constraints", "num_slack"]);
+    assert_eq!(sum.get("num_vars").unwrap().to_string(), "n + 2 * n");
+
+    let output = sum.evaluate_output_size(&ProblemSize::new(vec![("n", 4), ("m", 5)]));
+    assert_eq!(output.get("num_vars"), Some(12));
+    assert_eq!(output.get("num_constraints"), Some(5));
+    assert_eq!(output.get("num_slack"), Some(5));
+}
+
+#[test]
+fn test_reduction_overhead_compose_sat_is_qubo() {
+    // SAT -> MIS: one vertex per literal occurrence (num_literals = Σ clause sizes)
+    let sat_to_is = ReductionOverhead::new(vec![
+        ("num_vertices", Expr::Var("num_literals")),
+        (
+            "num_edges",
+            Expr::pow(Expr::Var("num_literals"), Expr::Const(2.0)),
+        ),
+    ]);
+    // MIS -> QUBO: one binary variable per vertex
+    let is_to_qubo = ReductionOverhead::new(vec![("num_vars", Expr::Var("num_vertices"))]);
+
+    let composed = sat_to_is.compose(&is_to_qubo);
+    assert_eq!(composed.output_size.len(), 1);
+    assert_eq!(composed.get("num_vars"), Some(&Expr::Var("num_literals")));
+    assert_eq!(
+        composed.input_variable_names(),
+        HashSet::from(["num_literals"])
+    );
+
+    let sat_size = ProblemSize::new(vec![
+        ("num_vars", 3),
+        ("num_clauses", 2),
+        ("num_literals", 5),
+    ]);
+    assert_eq!(
+        composed.evaluate_output_size(&sat_size).get("num_vars"),
+        Some(5)
+    );
+}
+
+#[test]
+fn test_reduction_overhead_compose_substitutes_into_expressions() {
+    let first = ReductionOverhead::new(vec![
+        ("num_vertices", Expr::Const(2.0) * Expr::Var("num_vars")),
+        ("num_edges", Expr::Var("num_clauses")),
+    ]);
+    let second = ReductionOverhead::new(vec![
+        (
+            "num_vars",
+            Expr::Var("num_vertices") + Expr::Var("num_edges"),
+        ),
+        ("num_constraints", Expr::Var("num_colors")),
+    ]);
+
+    let composed = first.compose(&second);
+    assert_eq!(
+        composed.get("num_vars").unwrap().to_string(),
+        "2 * num_vars + num_clauses"
+    );
+    // Inputs the first step does not produce stay free
+    assert_eq!(
+        composed.get("num_constraints"),
+        Some(&Expr::Var("num_colors"))
+    );
+}
+
 #[test]
 fn test_reduction_entry_overhead() {
     let entry = ReductionEntry {
@@ -212,6 +292,48 @@ fn test_reduction_entries_registered() {
     );
 }
 
+#[test]
+fn test_graph_topology_cast_entries_have_identity_overhead() {
+    let entries: Vec<_> = inventory::iter::<ReductionEntry>().collect();
+    let problems = [
+        "MaximumIndependentSet",
+        "MinimumVertexCover",
+        "MinimumDominatingSet",
+        "MaxCut",
+        "MaximumMatching",
+    ];
+    let casts = [
+        ("KingsSubgraph", "UnitDiskGraph"),
+        ("TriangularSubgraph", "UnitDiskGraph"),
+        ("UnitDiskGraph", "SimpleGraph"),
+    ];
+
+    for problem in problems {
+        for (from, to) in casts {
+            let entry = entries
+                .iter()
+                .find(|e| {
+                    e.source_name == problem
+                        && e.target_name == problem
+                        && e.source_variant().contains(&("graph", from))
+                        && e.source_variant().contains(&("weight", "i32"))
+                        && e.target_variant().contains(&("graph", to))
+                })
+                .unwrap_or_else(|| panic!("missing cast {problem}/{from} -> {problem}/{to}"));
+
+            let overhead = entry.overhead();
+            assert!(!overhead.output_size.is_empty());
+            for (field, expr) in &overhead.output_size {
+                assert_eq!(
+                    expr,
+                    &Expr::Var(field),
+                    "{problem}/{from} -> {to}: {field} is not identity"
+                );
+            }
+        }
+    }
+}
+
 /// Build a ProblemSize from an overhead's input variables by calling the eval fn
 /// on the source problem instance and collecting field values via the overhead.
 ///
@@ -492,3 +614,38 @@ fn test_edge_capabilities_serde_roundtrip() {
     let back: EdgeCapabilities = serde_json::from_str(&json).unwrap();
     assert_eq!(caps, back);
 }
+
+#[test]
+fn test_indexed_queries_match_linear_scan() {
+    let entries = reduction_entries();
+    let names: HashSet<&str> = entries
+        .iter()
+        .flat_map(|e| [e.source_name, e.target_name])
+        .collect();
+    assert!(!names.is_empty());
+
+    for name in names {
+        let from_scan: Vec<*const ReductionEntry> = entries
+            .iter()
+            .filter(|e| e.source_name == name)
+            .map(|e| *e as *const _)
+            .collect();
+        let from_index: Vec<*const ReductionEntry> = reductions_from(name)
+            .iter()
+            .map(|e| *e as *const _)
+            .collect();
+        assert_eq!(from_index, from_scan, "reductions_from({name})");
+
+        let to_scan: Vec<*const ReductionEntry> = entries
+            .iter()
+            .filter(|e| e.target_name == name)
+            .map(|e| *e as *const _)
+            .collect();
+        let to_index: Vec<*const ReductionEntry> =
+            reductions_to(name).iter().map(|e| *e as *const _).collect();
+        assert_eq!(to_index, to_scan, "reductions_to({name})");
+    }
+
+    assert!(reductions_from("NoSuchProblem").is_empty());
+    assert!(reductions_to("NoSuchProblem").is_empty());
+}
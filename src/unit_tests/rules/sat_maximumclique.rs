@@ -0,0 +1,95 @@
+use super::*;
+use crate::models::formula::CNFClause;
+use crate::rules::test_helpers::assert_satisfaction_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::Graph;
+use crate::traits::Problem;
+use crate::types::{Max, Or};
+
+#[test]
+fn test_sat_to_maximumclique_structure() {
+    // (x1 OR x2) AND (NOT x1 OR x3)
+    // Vertices: 0:x1 1:x2 | 2:~x1 3:x3
+    let sat = Satisfiability::new(
+        3,
+        vec![CNFClause::new(vec![1, 2]), CNFClause::new(vec![-1, 3])],
+    );
+    let reduction = ReduceTo::<MaximumClique<SimpleGraph, i32>>::reduce_to(&sat);
+    let graph = reduction.target_problem().graph();
+
+    assert_eq!(graph.num_vertices(), 4);
+    // Cross-clause pairs: (0,2) is complementary and dropped.
+    let mut edges = graph.edges();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 3), (1, 2), (1, 3)]);
+    assert_eq!(reduction.num_clauses(), 2);
+    assert_eq!(reduction.literals()[2], BoolVar::new(0, true));
+}
+
+#[test]
+fn test_sat_to_maximumclique_satisfiable() {
+    // (x1 OR x2) AND (NOT x1 OR x3) AND (NOT x2 OR NOT x3)
+    let sat = Satisfiability::new(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![-1, 3]),
+            CNFClause::new(vec![-2, -3]),
+        ],
+    );
+    let reduction = ReduceTo::<MaximumClique<SimpleGraph, i32>>::reduce_to(&sat);
+    let solver = BruteForce::new();
+
+    assert_eq!(solver.solve(&sat), Or(true));
+    assert_eq!(solver.solve(reduction.target_problem()), Max(Some(3)));
+    assert_satisfaction_round_trip_from_optimization_target(
+        &sat,
+        &reduction,
+        "SAT->MaximumClique satisfiable",
+    );
+}
+
+#[test]
+fn test_sat_to_maximumclique_unsatisfiable() {
+    // (x1) AND (NOT x1) AND (x2): the first two clauses conflict.
+    let sat = Satisfiability::new(
+        2,
+        vec![
+            CNFClause::new(vec![1]),
+            CNFClause::new(vec![-1]),
+            CNFClause::new(vec![2]),
+        ],
+    );
+    let reduction = ReduceTo::<MaximumClique<SimpleGraph, i32>>::reduce_to(&sat);
+    let solver = BruteForce::new();
+
+    assert_eq!(solver.solve(&sat), Or(false));
+    let clique_number = solver.solve(reduction.target_problem());
+    assert_eq!(clique_number, Max(Some(2)));
+    assert!(clique_number.0.unwrap() < reduction.num_clauses() as i32);
+
+    // Extraction still yields an assignment, just not a satisfying one.
+    let witness = solver.find_witness(reduction.target_problem()).unwrap();
+    let assignment = reduction.extract_solution(&witness);
+    assert_eq!(assignment.len(), 2);
+    assert_eq!(sat.evaluate(&assignment), Or(false));
+}
+
+#[test]
+fn test_sat_to_maximumclique_extract_solution() {
+    // (NOT x1 OR x2) AND (x2): select ~x1 from the first clause and x2 from the second.
+    let sat = Satisfiability::new(
+        2,
+        vec![CNFClause::new(vec![-1, 2]), CNFClause::new(vec![2])],
+    );
+    let reduction = ReduceTo::<MaximumClique<SimpleGraph, i32>>::reduce_to(&sat);
+    assert_eq!(reduction.extract_solution(&[1, 0, 1]), vec![0, 1]);
+}
+
+#[test]
+fn test_sat_to_maximumclique_empty_formula() {
+    let sat = Satisfiability::new(2, vec![]);
+    let reduction = ReduceTo::<MaximumClique<SimpleGraph, i32>>::reduce_to(&sat);
+    assert_eq!(reduction.target_problem().graph().num_vertices(), 0);
+    assert_eq!(reduction.extract_solution(&[]), vec![0, 0]);
+}
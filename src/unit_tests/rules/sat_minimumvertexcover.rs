@@ -0,0 +1,204 @@
+use super::*;
+use crate::models::formula::CNFClause;
+use crate::rules::test_helpers::assert_satisfaction_round_trip_from_optimization_target;
+use crate::solvers::BruteForce;
+use crate::traits::Problem;
+
+fn mixed_width_formula() -> Satisfiability {
+    // (x1 v x2) ^ (~x1 v x2 v x3) ^ (~x2 v ~x3)
+    Satisfiability::new(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![-1, 2, 3]),
+            CNFClause::new(vec![-2, -3]),
+        ],
+    )
+}
+
+fn min_cover_size(reduction: &ReductionSATToVC) -> usize {
+    BruteForce::new()
+        .find_witness(reduction.target_problem())
+        .unwrap()
+        .iter()
+        .sum()
+}
+
+#[test]
+fn test_sat_to_minimumvertexcover_closed_loop() {
+    let sat = mixed_width_formula();
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+
+    assert_satisfaction_round_trip_from_optimization_target(
+        &sat,
+        &reduction,
+        "SAT -> MVC closed loop",
+    );
+}
+
+#[test]
+fn test_sat_to_minimumvertexcover_structure() {
+    let sat = mixed_width_formula();
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    let target = reduction.target_problem();
+
+    // 2*3 literal vertices + 7 clause vertices
+    assert_eq!(target.num_vertices(), 13);
+    // 3 truth-setting + (1 + 3 + 1) clique + 7 communication edges
+    assert_eq!(target.num_edges(), 15);
+    assert_eq!(reduction.cover_size_threshold(), 7);
+}
+
+#[test]
+fn test_sat_to_minimumvertexcover_satisfiable_3sat_hits_threshold() {
+    // (x1 v x2 v ~x3) ^ (~x1 v x2 v x3): n + 2m = 7
+    let sat = Satisfiability::new(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2, -3]),
+            CNFClause::new(vec![-1, 2, 3]),
+        ],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    assert_eq!(reduction.cover_size_threshold(), 7);
+
+    let witness = BruteForce::new()
+        .find_witness(reduction.target_problem())
+        .unwrap();
+    assert_eq!(witness.iter().sum::<usize>(), 7);
+    assert!(sat.evaluate(&reduction.extract_solution(&witness)));
+}
+
+#[test]
+fn test_sat_to_minimumvertexcover_unsatisfiable_3sat_misses_threshold() {
+    // All four sign patterns over (x1, x2), padded to width 3
+    let sat = Satisfiability::new(
+        2,
+        vec![
+            CNFClause::new(vec![1, 1, 2]),
+            CNFClause::new(vec![1, 1, -2]),
+            CNFClause::new(vec![-1, -1, 2]),
+            CNFClause::new(vec![-1, -1, -2]),
+        ],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    assert_eq!(reduction.cover_size_threshold(), 10);
+    assert_eq!(min_cover_size(&reduction), 11);
+}
+
+#[test]
+fn test_sat_to_minimumvertexcover_unit_clauses() {
+    // (x1) ^ (~x1 v x2) ^ (~x2) is unsatisfiable
+    let sat = Satisfiability::new(
+        2,
+        vec![
+            CNFClause::new(vec![1]),
+            CNFClause::new(vec![-1, 2]),
+            CNFClause::new(vec![-2]),
+        ],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    assert_eq!(reduction.cover_size_threshold(), 3);
+    assert_eq!(min_cover_size(&reduction), 4);
+
+    // Dropping (~x2) makes it satisfiable with x1 = x2 = 1
+    let sat = Satisfiability::new(
+        2,
+        vec![CNFClause::new(vec![1]), CNFClause::new(vec![-1, 2])],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    let witness = BruteForce::new()
+        .find_witness(reduction.target_problem())
+        .unwrap();
+    assert_eq!(witness.iter().sum::<usize>(), 3);
+    assert_eq!(reduction.extract_solution(&witness), vec![1, 1]);
+}
+
+#[test]
+fn test_sat_to_minimumvertexcover_empty_clause_misses_threshold() {
+    let sat = Satisfiability::new(1, vec![CNFClause::new(vec![1]), CNFClause::new(vec![])]);
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    assert_eq!(reduction.target_problem().num_vertices(), 3);
+    assert_eq!(reduction.cover_size_threshold(), 0);
+    assert_eq!(min_cover_size(&reduction), 1);
+}
+
+#[test]
+fn test_sat_to_minimumvertexcover_splits_long_clauses() {
+    // (x1 v x2 v x3 v x4) ^ (~x1) ^ (~x2) ^ (~x3)
+    let sat = Satisfiability::new(
+        4,
+        vec![
+            CNFClause::new(vec![1, 2, 3, 4]),
+            CNFClause::new(vec![-1]),
+            CNFClause::new(vec![-2]),
+            CNFClause::new(vec![-3]),
+        ],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    let target = reduction.target_problem();
+
+    // 4 + 1 auxiliary variables; the 4-clause becomes two 3-clauses.
+    assert_eq!(target.num_vertices(), 2 * 5 + 6 + 3);
+    assert_eq!(target.num_edges(), 5 + 2 * 3 + 6 + 3);
+    assert_eq!(reduction.cover_size_threshold(), 5 + 2 * 2);
+    assert_satisfaction_round_trip_from_optimization_target(
+        &sat,
+        &reduction,
+        "SAT -> MVC with a split clause",
+    );
+
+    // Adding (~x4) makes it unsatisfiable.
+    let mut clauses = sat.clauses().to_vec();
+    clauses.push(CNFClause::new(vec![-4]));
+    let sat = Satisfiability::new(4, clauses);
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    assert!(min_cover_size(&reduction) > reduction.cover_size_threshold());
+}
+
+#[test]
+fn test_sat_to_minimumvertexcover_size_is_linear_in_literals() {
+    // One clause over 12 variables: a clique would have 66 edges.
+    let sat = Satisfiability::new(12, vec![CNFClause::new((1..=12).collect())]);
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    let target = reduction.target_problem();
+
+    assert!(target.num_vertices() <= 7 * sat.num_literals());
+    assert!(target.num_edges() <= 8 * sat.num_literals());
+    assert_eq!(target.num_edges(), 21 + 10 * 3 + 30);
+}
+
+#[test]
+fn test_sat_to_minimumvertexcover_skips_unused_variables() {
+    // x2 and x4 occur in no clause.
+    let sat = Satisfiability::new(
+        4,
+        vec![CNFClause::new(vec![1, -3]), CNFClause::new(vec![3])],
+    );
+    let reduction = ReduceTo::<MinimumVertexCover<SimpleGraph, i32>>::reduce_to(&sat);
+    assert_eq!(reduction.target_problem().num_vertices(), 2 * 2 + 3);
+
+    let witness = BruteForce::new()
+        .find_witness(reduction.target_problem())
+        .unwrap();
+    assert_eq!(
+        witness.iter().sum::<usize>(),
+        reduction.cover_size_threshold()
+    );
+    assert_eq!(reduction.extract_solution(&witness), vec![1, 0, 1, 0]);
+}
+
+#[cfg(feature = "example-db")]
+#[test]
+fn test_sat_to_minimumvertexcover_canonical_example_spec() {
+    let spec = canonical_rule_example_specs()
+        .into_iter()
+        .find(|spec| spec.id == "satisfiability_to_minimumvertexcover")
+        .expect("missing canonical SAT -> MVC example spec");
+    let example = (spec.build)();
+
+    assert_eq!(example.source.problem, "Satisfiability");
+    assert_eq!(example.target.problem, "MinimumVertexCover");
+    let pair = &example.solutions[0];
+    assert_eq!(pair.target_config.iter().sum::<usize>(), 7);
+}
@@ -6,6 +6,7 @@ use crate::rules::test_helpers::{
 use crate::rules::traits::ReduceTo;
 use crate::solvers::{BruteForce, Solver};
 use crate::traits::Problem;
+use crate::types::One;
 
 #[test]
 fn test_satisfiability_to_maximum2satisfiability_structure() {
@@ -14,7 +15,7 @@ fn test_satisfiability_to_maximum2satisfiability_structure() {
         vec![CNFClause::new(vec![1, -2, 3]), CNFClause::new(vec![-1, 2])],
     );
 
-    let reduction = ReduceTo::<Maximum2Satisfiability>::reduce_to(&source);
+    let reduction = ReduceTo::<Maximum2Satisfiability<One>>::reduce_to(&source);
     let target = reduction.target_problem();
 
     assert_eq!(target.num_vars(), 7);
@@ -32,7 +33,7 @@ fn test_satisfiability_to_maximum2satisfiability_closed_loop() {
         vec![CNFClause::new(vec![1, -2, 3]), CNFClause::new(vec![-1, 2])],
     );
 
-    let reduction = ReduceTo::<Maximum2Satisfiability>::reduce_to(&source);
+    let reduction = ReduceTo::<Maximum2Satisfiability<One>>::reduce_to(&source);
     let target = reduction.target_problem();
 
     assert_satisfaction_round_trip_from_optimization_target(
@@ -48,7 +49,7 @@ fn test_satisfiability_to_maximum2satisfiability_closed_loop() {
 fn test_satisfiability_to_maximum2satisfiability_unsatisfiable_gap() {
     let source = Satisfiability::new(1, vec![CNFClause::new(vec![1]), CNFClause::new(vec![-1])]);
 
-    let reduction = ReduceTo::<Maximum2Satisfiability>::reduce_to(&source);
+    let reduction = ReduceTo::<Maximum2Satisfiability<One>>::reduce_to(&source);
     let target = reduction.target_problem();
 
     assert_eq!(BruteForce::new().solve(target).0, Some(55));
@@ -63,7 +64,7 @@ fn test_satisfiability_to_maximum2satisfiability_unsatisfiable_gap() {
 fn test_satisfiability_to_maximum2satisfiability_empty_clause() {
     let source = Satisfiability::new(1, vec![CNFClause::new(vec![])]);
 
-    let reduction = ReduceTo::<Maximum2Satisfiability>::reduce_to(&source);
+    let reduction = ReduceTo::<Maximum2Satisfiability<One>>::reduce_to(&source);
     let target = reduction.target_problem();
 
     assert_eq!(target.num_vars(), 4);
@@ -1,6 +1,10 @@
 use super::*;
 use crate::rules::test_helpers::assert_optimization_round_trip_from_optimization_target;
-use crate::solvers::BruteForce;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::{Max, Min};
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
 include!("../jl_helpers.rs");
 
 #[test]
@@ -40,17 +44,78 @@ fn test_solution_extraction_with_ancilla() {
     let sg = SpinGlass::<SimpleGraph, i32>::new(2, vec![((0, 1), 1)], vec![1, 0]);
     let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&sg);
 
-    // If ancilla is 0, don't flip
-    let mc_sol = vec![0, 1, 0];
+    // If ancilla is 1 (spin +1), don't flip
+    let mc_sol = vec![0, 1, 1];
     let extracted = reduction.extract_solution(&mc_sol);
     assert_eq!(extracted, vec![0, 1]);
 
-    // If ancilla is 1, flip all
-    let mc_sol = vec![0, 1, 1];
+    // If ancilla is 0, flip all
+    let mc_sol = vec![0, 1, 0];
     let extracted = reduction.extract_solution(&mc_sol);
     assert_eq!(extracted, vec![1, 0]); // flipped and ancilla removed
 }
 
+#[test]
+fn test_spinglass_to_maxcut_single_field() {
+    // H = s_0 is minimized by s_0 = -1 (config 0).
+    let sg = SpinGlass::<SimpleGraph, i32>::new(1, vec![], vec![1]);
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&sg);
+    let witness = BruteForce::new()
+        .find_witness(reduction.target_problem())
+        .unwrap();
+    let extracted = reduction.extract_solution(&witness);
+    assert_eq!(extracted, vec![0]);
+    assert_eq!(sg.evaluate(&extracted), Min(Some(-1)));
+}
+
+#[test]
+fn test_spinglass_to_maxcut_with_fields_reproduces_ground_state() {
+    // Frustrated triangle plus a pendant spin, with mixed-sign fields.
+    let sg = SpinGlass::<SimpleGraph, i32>::new(
+        4,
+        vec![((0, 1), 1), ((1, 2), 1), ((0, 2), 1), ((2, 3), -2)],
+        vec![2, -1, 0, 3],
+    );
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&sg);
+    let mc = reduction.target_problem();
+    assert_eq!(mc.graph().num_vertices(), 5);
+    assert_eq!(mc.graph().num_edges(), 4 + 3);
+
+    let solver = BruteForce::new();
+    let (ground_energy, ground_states) = solver.solve_with_witnesses(&sg);
+    let ground_states: HashSet<Vec<usize>> = ground_states.into_iter().collect();
+    let extracted: HashSet<Vec<usize>> = solver
+        .find_all_witnesses(mc)
+        .iter()
+        .map(|cut| reduction.extract_solution(cut))
+        .collect();
+    assert_eq!(extracted, ground_states);
+    for config in &extracted {
+        assert_eq!(sg.evaluate(config), ground_energy);
+    }
+}
+
+#[test]
+fn test_spinglass_to_maxcut_fields_round_trip() {
+    let sg = SpinGlass::<SimpleGraph, i32>::new(
+        5,
+        vec![
+            ((0, 1), -1),
+            ((1, 2), 2),
+            ((2, 3), 1),
+            ((3, 4), -3),
+            ((0, 4), 1),
+        ],
+        vec![-2, 1, 1, -1, 2],
+    );
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&sg);
+    assert_optimization_round_trip_from_optimization_target(
+        &sg,
+        &reduction,
+        "SpinGlass with fields -> MaxCut",
+    );
+}
+
 #[test]
 fn test_weighted_maxcut() {
     let mc = MaxCut::new(SimpleGraph::new(3, vec![(0, 1), (1, 2)]), vec![10, 20]);
@@ -191,3 +256,188 @@ fn test_jl_parity_rule_spinglass_to_maxcut() {
         assert_eq!(best_source, jl_parse_configs_set(&case["best_source"]));
     }
 }
+
+/// Minimal deterministic LCG so the randomized checks are reproducible.
+struct Lcg(u64);
+
+impl Lcg {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 33) as usize) % n
+    }
+
+    fn weight(&mut self) -> i32 {
+        self.below(7) as i32 - 3
+    }
+}
+
+fn random_spin_glass(rng: &mut Lcg, with_fields: bool) -> SpinGlass<SimpleGraph, i32> {
+    let n = 2 + rng.below(5);
+    let mut interactions = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if rng.below(2) == 0 {
+                interactions.push(((i, j), rng.weight()));
+            }
+        }
+    }
+    let fields = (0..n)
+        .map(|_| if with_fields { rng.weight() } else { 0 })
+        .collect();
+    SpinGlass::new(n, interactions, fields)
+}
+
+#[test]
+fn test_random_spinglass_ground_states_match_max_cuts() {
+    let solver = BruteForce::new();
+    let mut rng = Lcg(11);
+    for round in 0..60 {
+        let sg = random_spin_glass(&mut rng, round % 2 == 0);
+        let has_fields = sg.fields().iter().any(|&h| h != 0);
+        let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&sg);
+        let mc = reduction.target_problem();
+        assert_eq!(reduction.ancilla.is_some(), has_fields);
+        assert_eq!(
+            mc.graph().num_vertices(),
+            sg.num_spins() + usize::from(has_fields)
+        );
+
+        // cut = (sum of weights - H) / 2 at the optimum.
+        let total: i32 = mc.edge_weights().iter().sum();
+        let (energy, ground_states) = solver.solve_with_witnesses(&sg);
+        let max_cut = solver.solve(mc).unwrap();
+        assert_eq!(energy, Min(Some(total - 2 * max_cut)), "{sg:?}");
+
+        // Every maximum cut extracts to a ground state with the right signs,
+        // and every ground state is reached.
+        let ground_states: HashSet<Vec<usize>> = ground_states.into_iter().collect();
+        let extracted: HashSet<Vec<usize>> = solver
+            .find_all_witnesses(mc)
+            .iter()
+            .map(|cut| reduction.extract_solution(cut))
+            .collect();
+        assert_eq!(extracted, ground_states, "{sg:?}");
+    }
+}
+
+#[test]
+fn test_ancilla_gauge_is_canonical() {
+    let sg = SpinGlass::<SimpleGraph, i32>::new(3, vec![((0, 1), 1), ((1, 2), -2)], vec![1, 0, -1]);
+    let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&sg);
+    for cut in crate::config::DimsIterator::new(reduction.target_problem().dims()) {
+        let complement: Vec<usize> = cut.iter().map(|&x| 1 - x).collect();
+        let extracted = reduction.extract_solution(&cut);
+        assert_eq!(extracted, reduction.extract_solution(&complement));
+        // The ancilla's side decides the sign: cuts with s_a = +1 are read
+        // off unchanged.
+        if cut[3] == 1 {
+            assert_eq!(extracted, cut[..3].to_vec());
+        }
+    }
+}
+
+#[test]
+fn test_random_maxcut_to_spinglass_adds_no_fields() {
+    let solver = BruteForce::new();
+    let mut rng = Lcg(5);
+    for _ in 0..30 {
+        let n = 2 + rng.below(5);
+        let mut edges = Vec::new();
+        let mut weights = Vec::new();
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if rng.below(2) == 0 {
+                    edges.push((i, j));
+                    weights.push(rng.weight());
+                }
+            }
+        }
+        let total: i32 = weights.iter().sum();
+        let mc = MaxCut::new(SimpleGraph::new(n, edges), weights);
+        let reduction = ReduceTo::<SpinGlass<SimpleGraph, i32>>::reduce_to(&mc);
+        let sg = reduction.target_problem();
+        assert!(sg.fields().iter().all(|&h| h == 0));
+
+        let max_cut = solver.solve(&mc).unwrap();
+        assert_eq!(solver.solve(sg), Min(Some(total - 2 * max_cut)));
+        assert_optimization_round_trip_from_optimization_target(
+            &mc,
+            &reduction,
+            "random MaxCut -> SpinGlass",
+        );
+    }
+}
+
+fn random_signed_graph(rng: &mut SmallRng, n: usize) -> MaxCut<SimpleGraph, i32> {
+    let mut edges = Vec::new();
+    let mut weights = Vec::new();
+    for u in 0..n {
+        for v in u + 1..n {
+            if rng.random_bool(0.5) {
+                edges.push((u, v));
+                let magnitude = rng.random_range(1..=4);
+                weights.push(if rng.random_bool(0.5) {
+                    magnitude
+                } else {
+                    -magnitude
+                });
+            }
+        }
+    }
+    MaxCut::new(SimpleGraph::new(n, edges), weights)
+}
+
+#[test]
+fn test_signed_maxcut_matches_spinglass_ground_energy() {
+    let mut rng = SmallRng::seed_from_u64(17);
+    let solver = BruteForce::new();
+    for _ in 0..30 {
+        let n = rng.random_range(2..=7);
+        let mc = random_signed_graph(&mut rng, n);
+        let reduction = ReduceTo::<SpinGlass<SimpleGraph, i32>>::reduce_to(&mc);
+        let sg = reduction.target_problem();
+
+        // cut = (sum of weights - H) / 2 holds for mixed signs
+        let Max(Some(max_cut)) = solver.solve(&mc) else {
+            unreachable!()
+        };
+        let Min(Some(ground_energy)) = solver.solve(sg) else {
+            unreachable!()
+        };
+        let total: i32 = mc.edge_weights().iter().sum();
+        assert_eq!(2 * max_cut, total - ground_energy);
+
+        let positive: i32 = mc.edge_weights().iter().filter(|&&w| w > 0).sum();
+        assert_eq!(mc.frustration_index(), positive - max_cut);
+
+        let ground_states: HashSet<Vec<usize>> =
+            solver.find_all_witnesses(sg).into_iter().collect();
+        let max_cuts: HashSet<Vec<usize>> = solver.find_all_witnesses(&mc).into_iter().collect();
+        assert_eq!(ground_states, max_cuts);
+    }
+}
+
+#[test]
+fn test_signed_spinglass_to_maxcut_round_trip() {
+    let mut rng = SmallRng::seed_from_u64(29);
+    for _ in 0..30 {
+        let n = rng.random_range(2..=6);
+        let mc = random_signed_graph(&mut rng, n);
+        let couplings = mc
+            .edges()
+            .into_iter()
+            .map(|(u, v, w)| ((u, v), w))
+            .collect();
+        let fields = (0..n).map(|_| rng.random_range(-2..=2)).collect();
+        let sg = SpinGlass::<SimpleGraph, i32>::new(n, couplings, fields);
+        let reduction = ReduceTo::<MaxCut<SimpleGraph, i32>>::reduce_to(&sg);
+        assert_optimization_round_trip_from_optimization_target(
+            &sg,
+            &reduction,
+            "signed SpinGlass -> MaxCut",
+        );
+    }
+}
@@ -23,6 +23,7 @@ fn constraint_signature(constraint: &(Comparison, f64, Vec<(usize, f64)>)) -> St
         Comparison::Le => "<=",
         Comparison::Ge => ">=",
         Comparison::Eq => "=",
+        Comparison::Range => "in",
     };
     let terms = constraint
         .2
@@ -0,0 +1,94 @@
+use super::*;
+use crate::models::formula::{CNFClause, XorClause};
+use crate::rules::test_helpers::assert_satisfaction_round_trip_from_optimization_target;
+use crate::solvers::{BruteForce, Solver};
+use crate::traits::Problem;
+use crate::types::Min;
+
+#[test]
+fn test_xorsatisfiability_to_polyspinglass_closed_loop() {
+    let source = XorSatisfiability::new(
+        4,
+        vec![CNFClause::new(vec![1, 4]), CNFClause::new(vec![-1, -3])],
+        vec![XorClause::new(vec![1, 2, 3]), XorClause::new(vec![2, -4])],
+    );
+    let reduction = ReduceTo::<PolySpinGlass<i32>>::reduce_to(&source);
+
+    assert_satisfaction_round_trip_from_optimization_target(
+        &source,
+        &reduction,
+        "XorSatisfiability->PolySpinGlass closed loop",
+    );
+}
+
+#[test]
+fn test_xorsatisfiability_to_polyspinglass_parity_terms() {
+    // Pure XOR systems map to one multi-spin term per clause.
+    let source = XorSatisfiability::from_xor_clauses(
+        3,
+        vec![XorClause::new(vec![1, 2, 3]), XorClause::new(vec![2, -3])],
+    );
+    let reduction = ReduceTo::<PolySpinGlass<i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.num_spins(), 3);
+    assert_eq!(
+        target.interactions(),
+        vec![(vec![0, 1, 2], -1), (vec![1, 2], -1)]
+    );
+    assert_eq!(target.fields(), &[0, 0, 0]);
+    assert_eq!(reduction.satisfying_energy(), -2);
+}
+
+#[test]
+fn test_xorsatisfiability_to_polyspinglass_energy_counts_violations() {
+    // Every violated clause costs exactly M = 2^max(1, max clause size).
+    let source = XorSatisfiability::new(
+        3,
+        vec![CNFClause::new(vec![1, -2]), CNFClause::new(vec![3])],
+        vec![XorClause::new(vec![1, 2, 3]), XorClause::new(vec![-1, 2])],
+    );
+    let reduction = ReduceTo::<PolySpinGlass<i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+    let total = source.num_clauses() + source.num_xor_clauses();
+
+    for mask in 0..8usize {
+        let config: Vec<usize> = (0..3).map(|i| mask >> i & 1).collect();
+        let assignment: Vec<bool> = config.iter().map(|&x| x == 1).collect();
+        let violated = (total - source.count_satisfied(&assignment)) as i32;
+        assert_eq!(
+            target.evaluate(&config),
+            Min(Some(reduction.satisfying_energy() as i32 + 4 * violated))
+        );
+    }
+}
+
+#[test]
+fn test_xorsatisfiability_to_polyspinglass_repeated_variables() {
+    // x1 ⊕ x1 ⊕ x2 = 1 reduces to the single field on x2.
+    let source = XorSatisfiability::from_xor_clauses(2, vec![XorClause::new(vec![1, 1, 2])]);
+    let reduction = ReduceTo::<PolySpinGlass<i32>>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.num_interactions(), 0);
+    assert_eq!(target.fields(), &[0, -1]);
+    for witness in BruteForce::new().find_all_witnesses(target) {
+        assert!(source.evaluate(&reduction.extract_solution(&witness)));
+    }
+}
+
+#[test]
+fn test_xorsatisfiability_to_polyspinglass_unsatisfiable() {
+    // The inconsistent triangle of parities cannot reach the satisfying energy.
+    let source = XorSatisfiability::from_xor_clauses(
+        3,
+        vec![
+            XorClause::new(vec![1, 2]),
+            XorClause::new(vec![2, 3]),
+            XorClause::new(vec![1, 3]),
+        ],
+    );
+    let reduction = ReduceTo::<PolySpinGlass<i32>>::reduce_to(&source);
+    let ground = BruteForce::new().solve(reduction.target_problem());
+    assert_eq!(ground, Min(Some(reduction.satisfying_energy() as i32 + 2)));
+}
@@ -0,0 +1,85 @@
+use super::*;
+use crate::models::formula::XorClause;
+use crate::rules::test_helpers::assert_satisfaction_round_trip_from_satisfaction_target;
+use crate::solvers::BruteForce;
+use crate::traits::Problem;
+
+#[test]
+fn test_xorsatisfiability_to_satisfiability_closed_loop() {
+    let source = XorSatisfiability::new(
+        4,
+        vec![CNFClause::new(vec![-1, -2])],
+        vec![
+            XorClause::new(vec![1, 2, 3, 4]),
+            XorClause::new(vec![2, -3]),
+        ],
+    );
+    let reduction = ReduceTo::<Satisfiability>::reduce_to(&source);
+
+    assert_satisfaction_round_trip_from_satisfaction_target(
+        &source,
+        &reduction,
+        "XorSatisfiability->Satisfiability closed loop",
+    );
+}
+
+#[test]
+fn test_xorsatisfiability_to_satisfiability_short_xor_expansion() {
+    // x1 ⊕ x2 ⊕ x3 = 1 forbids the four even-parity assignments.
+    let source = XorSatisfiability::from_xor_clauses(3, vec![XorClause::new(vec![1, 2, 3])]);
+    let reduction = ReduceTo::<Satisfiability>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.num_vars(), 3);
+    assert_eq!(target.num_clauses(), 4);
+    let solver = BruteForce::new();
+    let mut target_solutions = solver.find_all_witnesses(target);
+    let mut source_solutions = solver.find_all_witnesses(&source);
+    target_solutions.sort();
+    source_solutions.sort();
+    assert_eq!(target_solutions, source_solutions);
+}
+
+#[test]
+fn test_xorsatisfiability_to_satisfiability_chains_long_xor() {
+    // A 6-literal XOR needs three chain variables and 4 * (6 - 2) clauses
+    // of at most three literals, instead of 2^5 clauses of six literals.
+    let source =
+        XorSatisfiability::from_xor_clauses(6, vec![XorClause::new(vec![1, -2, 3, 4, -5, 6])]);
+    let reduction = ReduceTo::<Satisfiability>::reduce_to(&source);
+    let target = reduction.target_problem();
+
+    assert_eq!(target.num_vars(), 9);
+    assert_eq!(target.num_clauses(), 16);
+    assert!(target.clauses().iter().all(|c| c.len() == 3));
+
+    // Every source solution extends uniquely, so the counts agree.
+    let solver = BruteForce::new();
+    assert_eq!(
+        solver.find_all_witnesses(target).len(),
+        solver.find_all_witnesses(&source).len()
+    );
+    for witness in solver.find_all_witnesses(target) {
+        assert!(source.evaluate(&reduction.extract_solution(&witness)));
+    }
+}
+
+#[test]
+fn test_xorsatisfiability_to_satisfiability_unsatisfiable() {
+    // x1 ⊕ x2 = 1, x2 ⊕ x3 = 1, x1 ⊕ x3 = 1 is inconsistent over GF(2),
+    // and the empty XOR clause is unsatisfiable on its own.
+    let solver = BruteForce::new();
+    for xor_clauses in [
+        vec![
+            XorClause::new(vec![1, 2]),
+            XorClause::new(vec![2, 3]),
+            XorClause::new(vec![1, 3]),
+        ],
+        vec![XorClause::new(vec![])],
+    ] {
+        let source = XorSatisfiability::from_xor_clauses(3, xor_clauses);
+        let reduction = ReduceTo::<Satisfiability>::reduce_to(&source);
+        assert!(solver.find_witness(&source).is_none());
+        assert!(solver.find_witness(reduction.target_problem()).is_none());
+    }
+}
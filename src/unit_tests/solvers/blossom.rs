@@ -0,0 +1,120 @@
+use super::*;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::SimpleGraph;
+use crate::traits::Problem;
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
+
+fn complete_edges(n: usize) -> Vec<(usize, usize)> {
+    (0..n)
+        .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+        .collect()
+}
+
+fn cycle_edges(n: usize) -> Vec<(usize, usize)> {
+    (0..n).map(|i| (i, (i + 1) % n)).collect()
+}
+
+/// Check that the blossom witness is a valid matching with the optimal weight.
+fn assert_matches_brute_force(problem: &MaximumMatching<SimpleGraph, i32>) {
+    let config = BlossomSolver::new().find_witness(problem);
+    assert!(problem.is_valid_solution(&config));
+    let expected = BruteForce::new().solve(problem);
+    assert_eq!(problem.evaluate(&config), expected);
+    assert_eq!(BlossomSolver::new().solve(problem), expected);
+}
+
+#[test]
+fn test_blossom_odd_cycles_unit_weights() {
+    for n in [3, 5, 7, 9] {
+        let problem = MaximumMatching::<_, i32>::unit_weights(SimpleGraph::new(n, cycle_edges(n)));
+        assert_eq!(
+            BlossomSolver::new().solve(&problem),
+            Max(Some(n as i32 / 2))
+        );
+        assert_matches_brute_force(&problem);
+    }
+}
+
+#[test]
+fn test_blossom_beats_greedy_on_weighted_odd_cycle() {
+    // Greedily taking the heaviest edge (1,2) leaves only a weight-1 edge,
+    // while the two weight-3 edges around it give the optimum 6.
+    let graph = SimpleGraph::new(5, cycle_edges(5));
+    let problem = MaximumMatching::new(graph, vec![3, 4, 3, 1, 1]);
+    assert_eq!(
+        BlossomSolver::new().find_witness(&problem),
+        vec![1, 0, 1, 0, 0]
+    );
+    assert_matches_brute_force(&problem);
+}
+
+#[test]
+fn test_blossom_two_triangles_joined_by_edge() {
+    // Two triangles sharing a bridge force blossom shrinking on both sides.
+    let edges = vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)];
+    let problem = MaximumMatching::<_, i32>::unit_weights(SimpleGraph::new(6, edges));
+    assert_eq!(BlossomSolver::new().solve(&problem), Max(Some(3)));
+    assert_matches_brute_force(&problem);
+}
+
+#[test]
+fn test_blossom_weighted_complete_graphs() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    for n in 2..=6 {
+        for _ in 0..5 {
+            let edges = complete_edges(n);
+            let weights = edges.iter().map(|_| rng.random_range(1..=20)).collect();
+            assert_matches_brute_force(&MaximumMatching::new(SimpleGraph::new(n, edges), weights));
+        }
+    }
+}
+
+#[test]
+fn test_blossom_random_sparse_graphs() {
+    let mut rng = SmallRng::seed_from_u64(13);
+    for _ in 0..40 {
+        let n = rng.random_range(1..=8);
+        let edges: Vec<(usize, usize)> = complete_edges(n)
+            .into_iter()
+            .filter(|_| rng.random_bool(0.4))
+            .collect();
+        let weights = edges.iter().map(|_| rng.random_range(1..=6)).collect();
+        assert_matches_brute_force(&MaximumMatching::new(SimpleGraph::new(n, edges), weights));
+    }
+}
+
+#[test]
+fn test_blossom_ignores_non_positive_weights() {
+    let graph = SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]);
+    let problem = MaximumMatching::new(graph, vec![-2, 5, 0]);
+    assert_eq!(BlossomSolver::new().find_witness(&problem), vec![0, 1, 0]);
+    assert_matches_brute_force(&problem);
+
+    let empty = MaximumMatching::<_, i32>::unit_weights(SimpleGraph::new(3, vec![]));
+    assert_eq!(BlossomSolver::new().solve(&empty), Max(Some(0)));
+}
+
+#[test]
+fn test_blossom_float_weights() {
+    let graph = SimpleGraph::new(4, cycle_edges(4));
+    let problem = MaximumMatching::new(graph, vec![1.5, 2.0, 1.5, 0.25]);
+    assert_eq!(
+        BlossomSolver::new().find_witness(&problem),
+        vec![1, 0, 1, 0]
+    );
+    assert_eq!(BlossomSolver::new().solve(&problem), Max(Some(3.0)));
+}
+
+#[test]
+fn test_blossom_large_instance_beyond_brute_force() {
+    // K_60 has 1770 edges; a perfect matching has 30 edges.
+    let problem = MaximumMatching::<_, i32>::unit_weights(SimpleGraph::new(60, complete_edges(60)));
+    let config = BlossomSolver::new().find_witness(&problem);
+    assert!(problem.is_valid_solution(&config));
+    assert_eq!(problem.evaluate(&config), Max(Some(30)));
+
+    // A long odd cycle matches all but one vertex.
+    let problem = MaximumMatching::<_, i32>::unit_weights(SimpleGraph::new(301, cycle_edges(301)));
+    assert_eq!(BlossomSolver::new().solve(&problem), Max(Some(150)));
+}
@@ -1,7 +1,11 @@
 use super::*;
+use crate::models::graph::{MaxCut, MaximumIndependentSet};
 use crate::solvers::Solver;
+use crate::topology::{Graph, SimpleGraph};
 use crate::traits::Problem;
-use crate::types::{Max, Min, Or, Sum};
+use crate::types::{Max, Min, One, Or, Sum};
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
 
 #[derive(Clone)]
 struct MaxSumProblem {
@@ -108,6 +112,84 @@ impl Problem for SumProblem {
     }
 }
 
+/// Picks one of several paths; the objective is the total edge cost.
+#[derive(Clone)]
+struct AdditivePathProblem {
+    paths: Vec<Vec<i32>>,
+}
+
+impl Problem for AdditivePathProblem {
+    const NAME: &'static str = "AdditivePathProblem";
+    type Value = Min<i32>;
+
+    fn dims(&self) -> Vec<usize> {
+        vec![self.paths.len()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Self::Value {
+        Min(Some(self.paths[config[0]].iter().sum()))
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        vec![("weight", "i32")]
+    }
+}
+
+/// Picks one of several paths; the objective is the largest edge cost.
+#[derive(Clone)]
+struct BottleneckPathProblem {
+    paths: Vec<Vec<i32>>,
+}
+
+impl Problem for BottleneckPathProblem {
+    const NAME: &'static str = "BottleneckPathProblem";
+    type Value = Min<i32>;
+
+    fn dims(&self) -> Vec<usize> {
+        vec![self.paths.len()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Self::Value {
+        Min(self.paths[config[0]].iter().copied().max())
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        vec![("weight", "i32")]
+    }
+}
+
+/// Minimizes the number of selected items first, then the total weight.
+#[derive(Clone)]
+struct LexicographicProblem {
+    weights: Vec<i32>,
+}
+
+impl Problem for LexicographicProblem {
+    const NAME: &'static str = "LexicographicProblem";
+    type Value = Min<Vec<i32>>;
+
+    fn dims(&self) -> Vec<usize> {
+        vec![2; self.weights.len()]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Self::Value {
+        if config.iter().all(|&c| c == 0) {
+            return Min(None);
+        }
+        let count = config.iter().filter(|&&c| c == 1).count() as i32;
+        let weight = config
+            .iter()
+            .zip(&self.weights)
+            .map(|(&c, &w)| if c == 1 { w } else { 0 })
+            .sum();
+        Min(Some(vec![count, weight]))
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        vec![("weight", "i32")]
+    }
+}
+
 #[test]
 fn test_solver_solves_max_value() {
     let problem = MaxSumProblem {
@@ -267,3 +349,185 @@ fn test_solver_trait_solve() {
 
     assert_eq!(Solver::solve(&solver, &problem), Max(Some(6)));
 }
+
+#[test]
+fn test_solver_bottleneck_optimum_differs_from_additive() {
+    // Path 0 costs 1 + 1 + 5 = 7 with bottleneck 5;
+    // path 1 costs 3 + 3 + 3 = 9 with bottleneck 3.
+    let paths = vec![vec![1, 1, 5], vec![3, 3, 3]];
+    let solver = BruteForce::new();
+
+    let additive = AdditivePathProblem {
+        paths: paths.clone(),
+    };
+    assert_eq!(solver.solve(&additive), Min(Some(7)));
+    assert_eq!(solver.find_all_witnesses(&additive), vec![vec![0]]);
+
+    let bottleneck = BottleneckPathProblem { paths };
+    assert_eq!(solver.solve(&bottleneck), Min(Some(3)));
+    assert_eq!(solver.find_all_witnesses(&bottleneck), vec![vec![1]]);
+}
+
+#[test]
+fn test_solver_lexicographic_objective() {
+    // Any single item beats every pair; among singletons the lightest wins.
+    let problem = LexicographicProblem {
+        weights: vec![4, -10, 2],
+    };
+    let solver = BruteForce::new();
+
+    assert_eq!(solver.solve(&problem), Min(Some(vec![1, -10])));
+    assert_eq!(solver.find_all_witnesses(&problem), vec![vec![0, 1, 0]]);
+}
+
+fn random_graph(rng: &mut SmallRng, n: usize) -> SimpleGraph {
+    let edges = (0..n)
+        .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+        .filter(|_| rng.random_bool(0.3))
+        .collect();
+    SimpleGraph::new(n, edges)
+}
+
+/// Check that the bitset and generic paths agree on value and witnesses.
+fn assert_bit_path_matches_generic<P>(problem: &P)
+where
+    P: Problem,
+    P::Value: Aggregate + PartialEq + std::fmt::Debug,
+{
+    let evaluate = bit_evaluator(problem).expect("bitset path should apply");
+    let n = problem.num_variables();
+
+    let total = solve_configs(problem);
+    assert_eq!(solve_bits(n, &evaluate), total);
+    assert_eq!(
+        fold_bit_witnesses(n, &evaluate),
+        fold_config_witnesses(problem)
+    );
+    assert_eq!(BruteForce::new().solve(problem), total);
+}
+
+#[test]
+fn test_bit_path_matches_generic_on_random_mis() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    for n in [0, 1, 5, 9, 12] {
+        let graph = random_graph(&mut rng, n);
+        let weights: Vec<i32> = (0..n).map(|_| rng.random_range(1..=9)).collect();
+        assert_bit_path_matches_generic(&MaximumIndependentSet::new(graph.clone(), weights));
+        assert_bit_path_matches_generic(&MaximumIndependentSet::new(graph, vec![One; n]));
+    }
+}
+
+#[test]
+fn test_bit_path_matches_generic_on_random_maxcut() {
+    let mut rng = SmallRng::seed_from_u64(13);
+    for n in [1, 4, 8, 12] {
+        let graph = random_graph(&mut rng, n);
+        let weights = (0..graph.num_edges())
+            .map(|_| rng.random_range(-3..=6))
+            .collect();
+        assert_bit_path_matches_generic(&MaxCut::<_, i32>::new(graph, weights));
+    }
+}
+
+#[test]
+fn test_bit_path_skipped_without_evaluator_or_non_binary_dims() {
+    let problem = MaxSumProblem {
+        weights: vec![1, 2, 3],
+    };
+    assert!(bit_evaluator(&problem).is_none());
+
+    let coloring = crate::models::graph::KColoring::<crate::variant::K3, _>::new(SimpleGraph::new(
+        3,
+        vec![(0, 1)],
+    ));
+    assert!(bit_evaluator(&coloring).is_none());
+}
+
+/// The two-pass search: compute the aggregate, then rescan for witnesses.
+fn two_pass_witnesses<P>(problem: &P) -> (P::Value, Vec<Vec<usize>>)
+where
+    P: Problem,
+    P::Value: Aggregate,
+{
+    let total = solve_configs(problem);
+    let witnesses = crate::config::fold_configs(&problem.dims(), Vec::new(), |mut acc, config| {
+        if P::Value::contributes_to_witnesses(&problem.evaluate(config), &total) {
+            acc.push(config.to_vec());
+        }
+        acc
+    });
+    (total, witnesses)
+}
+
+fn assert_single_pass_matches_two_pass<P>(problem: &P)
+where
+    P: Problem,
+    P::Value: Aggregate + PartialEq + std::fmt::Debug,
+{
+    assert_eq!(
+        BruteForce::new().solve_with_witnesses(problem),
+        two_pass_witnesses(problem)
+    );
+}
+
+#[test]
+fn test_single_pass_witnesses_match_two_pass() {
+    let mut rng = SmallRng::seed_from_u64(21);
+    for n in [0, 3, 7, 10] {
+        let graph = random_graph(&mut rng, n);
+        let weights: Vec<i32> = (0..n).map(|_| rng.random_range(1..=3)).collect();
+        assert_single_pass_matches_two_pass(&MaximumIndependentSet::new(graph.clone(), weights));
+        let edge_weights = (0..graph.num_edges())
+            .map(|_| rng.random_range(-2..=3))
+            .collect();
+        assert_single_pass_matches_two_pass(&MaxCut::<_, i32>::new(graph, edge_weights));
+
+        let weights: Vec<i32> = (0..n).map(|_| rng.random_range(-3..=3)).collect();
+        assert_single_pass_matches_two_pass(&MinSumProblem {
+            weights: weights.clone(),
+        });
+        assert_single_pass_matches_two_pass(&MaxSumProblem { weights });
+    }
+    assert_single_pass_matches_two_pass(&SatProblem {
+        num_vars: 3,
+        satisfying: vec![vec![0, 1, 1], vec![1, 0, 1]],
+    });
+    assert_single_pass_matches_two_pass(&SatProblem {
+        num_vars: 2,
+        satisfying: vec![],
+    });
+    assert_single_pass_matches_two_pass(&LexicographicProblem {
+        weights: vec![4, -10, 2, -10],
+    });
+    assert_single_pass_matches_two_pass(&BottleneckPathProblem {
+        paths: vec![vec![3, 5], vec![5, 1], vec![4, 4], vec![2, 5]],
+    });
+}
+
+#[test]
+fn test_single_pass_retains_only_running_optima() {
+    // 2^20 configurations, but the running maximum of "number of ones" only
+    // ever ties with a handful of configurations before it improves again.
+    let n = 20;
+    let problem = MaxSumProblem {
+        weights: vec![1; n],
+    };
+    let mut peak = 0;
+    let (total, witnesses) =
+        crate::config::fold_configs(&problem.dims(), WitnessFold::new(), |fold, config| {
+            let fold = fold.push(problem.evaluate(config), || config.to_vec());
+            peak = peak.max(fold.witnesses.len());
+            fold
+        })
+        .finish();
+
+    assert_eq!(total, Max(Some(n as i32)));
+    assert_eq!(witnesses, vec![vec![1; n]]);
+    assert!(peak <= n, "retained {peak} witnesses at once");
+
+    // Both cuts of the even cycle C20 are optimal; nothing else is kept.
+    let cycle = MaxCut::<_, i32>::unweighted(SimpleGraph::cycle(n));
+    let (total, witnesses) = BruteForce::new().solve_with_witnesses(&cycle);
+    assert_eq!(total, Max(Some(n as i32)));
+    assert_eq!(witnesses.len(), 2);
+}
@@ -0,0 +1,75 @@
+use super::*;
+use crate::models::graph::{MaximumClique, MaximumIndependentSet};
+use crate::topology::SimpleGraph;
+
+#[test]
+fn test_hamming_distance() {
+    assert_eq!(hamming_distance(&[0, 1, 1], &[0, 1, 1]), 0);
+    assert_eq!(hamming_distance(&[0, 1, 1], &[1, 1, 0]), 2);
+}
+
+#[test]
+fn test_optima_summary_edgeless_clique() {
+    // On an edgeless graph the maximum cliques are exactly the single vertices,
+    // so every pair of optima differs in two positions.
+    let problem = MaximumClique::new(SimpleGraph::new(4, vec![]), vec![1i32; 4]);
+    let summary = optima_summary(&problem, &BruteForce::new());
+
+    assert_eq!(summary.num_optima, 4);
+    assert!(!summary.is_unique());
+    assert_eq!(summary.min_distance, 2);
+    assert_eq!(summary.mean_distance, 2.0);
+    assert_eq!(summary.max_distance, 2);
+    assert_eq!(summary.representatives.len(), 4);
+    for rep in &summary.representatives {
+        assert_eq!(rep.iter().sum::<usize>(), 1);
+    }
+}
+
+#[test]
+fn test_optima_summary_unique_optimum() {
+    // The whole vertex set is the only maximum independent set of an edgeless graph.
+    let problem = MaximumIndependentSet::new(SimpleGraph::new(4, vec![]), vec![1i32; 4]);
+    let summary = optima_summary(&problem, &BruteForce::new());
+
+    assert_eq!(summary.num_optima, 1);
+    assert!(summary.is_unique());
+    assert_eq!(summary.min_distance, 0);
+    assert_eq!(summary.mean_distance, 0.0);
+    assert_eq!(summary.max_distance, 0);
+    assert_eq!(summary.representatives, vec![vec![1, 1, 1, 1]]);
+}
+
+#[test]
+fn test_optima_summary_mixed_distances() {
+    // Path 0-1-2-3: maximum independent sets {0,2}, {0,3}, {1,3}.
+    let problem = MaximumIndependentSet::new(
+        SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]),
+        vec![1i32; 4],
+    );
+    let summary = optima_summary(&problem, &BruteForce::new());
+
+    assert_eq!(summary.num_optima, 3);
+    assert_eq!(summary.min_distance, 2);
+    assert_eq!(summary.max_distance, 4);
+    assert!((summary.mean_distance - 8.0 / 3.0).abs() < 1e-12);
+    // Farthest-point selection pairs the first optimum with its complement.
+    assert_eq!(
+        summary.representatives[1],
+        summary.representatives[0]
+            .iter()
+            .map(|&x| 1 - x)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_farthest_points_limits_count() {
+    let configs: Vec<Vec<usize>> = (0..8)
+        .map(|i| (0..8).map(|j| usize::from(i == j)).collect())
+        .collect();
+    let picked = farthest_points(&configs, NUM_REPRESENTATIVES);
+    assert_eq!(picked.len(), NUM_REPRESENTATIVES);
+    assert_eq!(picked[0], configs[0]);
+    assert!(farthest_points(&[], 3).is_empty());
+}
@@ -0,0 +1,68 @@
+use super::*;
+use crate::models::graph::{MaximumIndependentSet, MinimumVertexCover};
+use crate::models::set::MinimumSetCovering;
+use crate::solvers::BruteForce;
+use crate::topology::SimpleGraph;
+use crate::types::{Max, Min};
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
+
+fn star(leaves: usize) -> SimpleGraph {
+    SimpleGraph::new(leaves + 1, (1..=leaves).map(|v| (0, v)).collect())
+}
+
+#[test]
+fn test_greedy_independent_set_on_star_picks_leaves() {
+    let problem = MaximumIndependentSet::new(star(5), vec![1i32; 6]);
+    let solver = GreedySolver::new();
+    assert_eq!(solver.find_witness(&problem), Some(vec![0, 1, 1, 1, 1, 1]));
+    assert_eq!(solver.solve(&problem), Max(Some(5)));
+}
+
+#[test]
+fn test_greedy_vertex_cover_on_star_picks_center() {
+    let problem = MinimumVertexCover::new(star(5), vec![1i32; 6]);
+    let solver = GreedySolver::new();
+    assert_eq!(solver.find_witness(&problem), Some(vec![1, 0, 0, 0, 0, 0]));
+    assert_eq!(solver.solve(&problem), Min(Some(1)));
+}
+
+#[test]
+fn test_greedy_set_cover_is_a_cover() {
+    // Sets {0,1,2} and {3,4,5} cover the universe; the others are redundant.
+    let problem = MinimumSetCovering::<i32>::new(
+        6,
+        vec![
+            vec![0, 1, 2],
+            vec![3, 4, 5],
+            vec![0, 3],
+            vec![1, 4],
+            vec![2, 5],
+        ],
+    );
+    let config = GreedySolver::new().find_witness(&problem).unwrap();
+    assert_eq!(problem.evaluate(&config), BruteForce::new().solve(&problem));
+}
+
+#[test]
+fn test_greedy_is_feasible_and_bounded_by_optimum() {
+    let mut rng = SmallRng::seed_from_u64(5);
+    for _ in 0..20 {
+        let n = 8;
+        let edges = (0..n)
+            .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+            .filter(|_| rng.random_bool(0.3))
+            .collect();
+        let weights = (0..n).map(|_| rng.random_range(1..=5)).collect();
+        let problem = MaximumIndependentSet::new(SimpleGraph::new(n, edges), weights);
+
+        let config = GreedySolver::new().find_witness(&problem).unwrap();
+        let Max(Some(value)) = problem.evaluate(&config) else {
+            panic!("greedy returned an infeasible set");
+        };
+        let Max(Some(optimum)) = BruteForce::new().solve(&problem) else {
+            unreachable!()
+        };
+        assert!(value <= optimum);
+    }
+}
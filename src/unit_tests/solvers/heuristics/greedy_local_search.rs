@@ -0,0 +1,101 @@
+use super::*;
+use crate::models::graph::{MaxCut, MaximumIndependentSet};
+use crate::solvers::BruteForce;
+use crate::topology::SimpleGraph;
+use crate::types::Max;
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
+
+fn random_max_cut(rng: &mut SmallRng, n: usize) -> MaxCut<SimpleGraph, i32> {
+    let edges: Vec<(usize, usize)> = (0..n)
+        .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+        .filter(|_| rng.random_bool(0.5))
+        .collect();
+    let weights = edges.iter().map(|_| rng.random_range(1..=5)).collect();
+    MaxCut::new(SimpleGraph::new(n, edges), weights)
+}
+
+#[test]
+fn test_greedy_local_search_bipartite_graph_cuts_every_edge() {
+    // Complete bipartite K_{3,3}: the maximum cut contains all 9 edges.
+    let edges = (0..3).flat_map(|u| (3..6).map(move |v| (u, v))).collect();
+    let problem = MaxCut::<_, i32>::unweighted(SimpleGraph::new(6, edges));
+    let solver = GreedyLocalSearch::new(1, 3);
+    assert_eq!(solver.solve(&problem), Max(Some(9)));
+}
+
+#[test]
+fn test_greedy_local_search_is_deterministic_for_seed() {
+    let mut rng = SmallRng::seed_from_u64(3);
+    let problem = random_max_cut(&mut rng, 12);
+    let solver = GreedyLocalSearch::new(99, 5);
+    assert_eq!(solver.find_witness(&problem), solver.find_witness(&problem));
+}
+
+#[test]
+fn test_greedy_local_search_valid_and_at_least_greedy_baseline() {
+    let mut rng = SmallRng::seed_from_u64(11);
+    for seed in 0..20 {
+        let problem = random_max_cut(&mut rng, 10);
+        let solver = GreedyLocalSearch::new(seed, 3);
+        let config = solver.find_witness(&problem).unwrap();
+        assert_eq!(config.len(), problem.num_vertices());
+        assert!(config.iter().all(|&side| side < 2));
+        let greedy = GreedySolver::new().solve(&problem);
+        assert!(problem.evaluate(&config).0 >= greedy.0, "seed {seed}");
+        assert_eq!(problem.evaluate(&config), solver.solve(&problem));
+    }
+}
+
+#[test]
+fn test_greedy_local_search_result_is_one_flip_optimal() {
+    let mut rng = SmallRng::seed_from_u64(5);
+    let problem = random_max_cut(&mut rng, 15);
+    let config = GreedyLocalSearch::new(0, 1).find_witness(&problem).unwrap();
+    let cut = problem.cut_size(&config);
+    for v in 0..config.len() {
+        let mut flipped = config.clone();
+        flipped[v] = 1 - flipped[v];
+        assert!(
+            problem.cut_size(&flipped) <= cut,
+            "flipping {v} improves the cut"
+        );
+    }
+}
+
+#[test]
+fn test_greedy_local_search_often_matches_brute_force() {
+    let mut rng = SmallRng::seed_from_u64(2024);
+    let trials = 30;
+    let matches = (0..trials as u64)
+        .filter(|&seed| {
+            let problem = random_max_cut(&mut rng, 8);
+            let heuristic = GreedyLocalSearch::new(seed, 5).solve(&problem);
+            let optimum = BruteForce::new().solve(&problem);
+            assert!(heuristic.0 <= optimum.0);
+            heuristic == optimum
+        })
+        .count();
+    assert!(
+        matches * 10 >= trials * 8,
+        "only {matches}/{trials} optimal"
+    );
+}
+
+#[test]
+fn test_greedy_local_search_empty_graph() {
+    let problem = MaxCut::<_, i32>::unweighted(SimpleGraph::new(0, vec![]));
+    let solver = GreedyLocalSearch::default();
+    assert_eq!(solver.find_witness(&problem), Some(vec![]));
+    assert_eq!(solver.solve(&problem), Max(Some(0)));
+}
+
+#[test]
+fn test_greedy_local_search_works_beyond_max_cut() {
+    // Star with center 0: the four leaves form the maximum independent set.
+    let graph = SimpleGraph::new(5, vec![(0, 1), (0, 2), (0, 3), (0, 4)]);
+    let problem = MaximumIndependentSet::new(graph, vec![1i32; 5]);
+    let solver = GreedyLocalSearch::new(0, 3);
+    assert_eq!(solver.find_witness(&problem), Some(vec![0, 1, 1, 1, 1]));
+    assert_eq!(solver.solve(&problem), Max(Some(4)));
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::models::algebraic::QUBO;
+use crate::models::graph::MaxCut;
+use crate::solvers::BruteForce;
+use crate::topology::SimpleGraph;
+use crate::traits::Problem;
+use crate::types::{Max, Min};
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
+
+fn random_max_cut(seed: u64, n: usize) -> MaxCut<SimpleGraph, i32> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let edges: Vec<(usize, usize)> = (0..n)
+        .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+        .filter(|_| rng.random_bool(0.2))
+        .collect();
+    let weights = edges.iter().map(|_| rng.random_range(1..=5)).collect();
+    MaxCut::new(SimpleGraph::new(n, edges), weights)
+}
+
+#[test]
+fn test_local_search_improves_random_start_on_max_cut() {
+    let problem = random_max_cut(17, 30);
+    let solver = LocalSearch::new(1000, 1, 3);
+
+    let Max(Some(start)) = problem.evaluate(&solver.initial_config(&problem, 0)) else {
+        unreachable!()
+    };
+    let config = solver.find_witness(&problem).unwrap();
+    let Max(Some(found)) = problem.evaluate(&config) else {
+        unreachable!()
+    };
+    assert!(found > start, "local search stayed at {start}");
+
+    // No single vertex move improves the local optimum.
+    for v in 0..30 {
+        let mut flipped = config.clone();
+        flipped[v] = 1 - flipped[v];
+        let Max(Some(value)) = problem.evaluate(&flipped) else {
+            unreachable!()
+        };
+        assert!(value <= found);
+    }
+}
+
+#[test]
+fn test_local_search_is_deterministic_for_seed() {
+    let problem = random_max_cut(4, 20);
+    let solver = LocalSearch::new(500, 3, 11);
+    assert_eq!(solver.find_witness(&problem), solver.find_witness(&problem));
+}
+
+#[test]
+fn test_local_search_two_flip_escapes_one_flip_optimum() {
+    // x0 = x1 = 1 is optimal, but from x = (0, 0) any single flip costs 1.
+    let problem = QUBO::new(vec![1.0, 1.0], vec![((0, 1), -3.0)]);
+    let mut config = vec![0, 0];
+    let mut value = Problem::evaluate(&problem, &config);
+    assert!(!one_flip(&problem, &[2, 2], &mut config, &mut value));
+    assert!(two_flip(&problem, &[2, 2], &mut config, &mut value));
+    assert_eq!(config, vec![1, 1]);
+    assert_eq!(value, Min(Some(-1.0)));
+}
+
+#[test]
+fn test_local_search_separable_qubo_is_optimal() {
+    let problem = QUBO::new(vec![-2.0, 3.0, -1.0, 0.5], vec![]);
+    let solver = LocalSearch::new(100, 2, 0);
+    assert_eq!(solver.solve(&problem), BruteForce::new().solve(&problem));
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+#[test]
+fn test_solution_quality_maximize() {
+    let quality = SolutionQuality::new(6.0, 8.0, ExtremumSense::Maximize);
+    assert_eq!(quality.gap(), 2.0);
+    assert_eq!(quality.relative_gap(), 0.25);
+    assert!(!quality.is_optimal());
+    assert_eq!(quality.to_string(), "value 6 vs upper bound 8 (gap 25.00%)");
+    assert!(SolutionQuality::new(8.0, 8.0, ExtremumSense::Maximize).is_optimal());
+}
+
+#[test]
+fn test_solution_quality_minimize() {
+    let quality = SolutionQuality::new(5.0, 4.0, ExtremumSense::Minimize);
+    assert_eq!(quality.gap(), 1.0);
+    assert_eq!(quality.relative_gap(), 0.25);
+    assert_eq!(quality.to_string(), "value 5 vs lower bound 4 (gap 25.00%)");
+}
+
+#[test]
+fn test_solution_quality_trivial_bound() {
+    let weights = [3.0, -1.0, 2.0, -4.0];
+    let max = SolutionQuality::with_trivial_bound(4.0, ExtremumSense::Maximize, weights);
+    assert_eq!(max.bound, 5.0);
+    let min = SolutionQuality::with_trivial_bound(-4.0, ExtremumSense::Minimize, weights);
+    assert_eq!(min.bound, -5.0);
+
+    // A zero bound: exact when the value is zero, unbounded otherwise.
+    let zero = SolutionQuality::with_trivial_bound(0.0, ExtremumSense::Minimize, [1.0]);
+    assert_eq!(zero.relative_gap(), 0.0);
+    assert!(zero.is_optimal());
+    let off = SolutionQuality::with_trivial_bound(2.0, ExtremumSense::Minimize, [1.0]);
+    assert_eq!(off.relative_gap(), f64::INFINITY);
+}
+
+#[cfg(feature = "ilp-solver")]
+#[test]
+fn test_solution_quality_against_lp_relaxation() {
+    use crate::models::set::MaximumSetPacking;
+
+    // Pairwise-intersecting sets: the best packing has weight 1, the LP relaxation 3/2.
+    let problem = MaximumSetPacking::<i32>::new(vec![vec![0, 1], vec![1, 2], vec![0, 2]]);
+    let quality = SolutionQuality::against_lp_relaxation(&problem, 1.0).unwrap();
+    assert_eq!(quality.sense, ExtremumSense::Maximize);
+    assert!((quality.bound - 1.5).abs() < 1e-6);
+    assert!(!quality.is_optimal());
+}
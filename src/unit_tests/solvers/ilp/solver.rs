@@ -102,6 +102,28 @@ fn test_ilp_empty_problem_with_infeasible_constraint_returns_none() {
     assert_eq!(solution, None);
 }
 
+#[test]
+fn test_ilp_solve_relaxation_bounds_integer_optimum() {
+    // Independent set on a triangle: the LP optimum puts 1/2 on every vertex.
+    let ilp = ILP::<bool>::new(
+        3,
+        vec![
+            LinearConstraint::le(vec![(0, 1.0), (1, 1.0)], 1.0),
+            LinearConstraint::le(vec![(1, 1.0), (2, 1.0)], 1.0),
+            LinearConstraint::le(vec![(0, 1.0), (2, 1.0)], 1.0),
+        ],
+        vec![(0, 1.0), (1, 1.0), (2, 1.0)],
+        ObjectiveSense::Maximize,
+    );
+    let solver = ILPSolver::new();
+    let relaxed = solver.solve_relaxation(&ilp).unwrap();
+    assert!((relaxed - 1.5).abs() < 1e-6, "relaxation value {relaxed}");
+
+    let integral = ilp.evaluate(&solver.solve(&ilp).unwrap()).unwrap();
+    assert!(integral <= relaxed + 1e-9);
+    assert_eq!(solver.solve_relaxation(&ILP::<bool>::empty()), Some(0.0));
+}
+
 #[test]
 fn test_ilp_equality_constraint() {
     // Minimize x0 subject to x0 + x1 == 1, binary vars
@@ -121,6 +143,150 @@ fn test_ilp_equality_constraint() {
     assert!((result.unwrap() - 0.0).abs() < 1e-9);
 }
 
+/// Knapsack-style ILP with a unique optimum [1, 0, 1, 1] of value 9.
+fn warm_start_ilp() -> ILP<bool> {
+    ILP::<bool>::new(
+        4,
+        vec![
+            LinearConstraint::le(vec![(0, 3.0), (1, 4.0), (2, 2.0), (3, 1.0)], 6.0),
+            LinearConstraint::range(vec![(0, 1.0), (1, 1.0), (2, 1.0), (3, 1.0)], 1.0, 3.0),
+        ],
+        vec![(0, 4.0), (1, 5.0), (2, 3.0), (3, 2.0)],
+        ObjectiveSense::Maximize,
+    )
+}
+
+#[test]
+fn test_ilp_solve_with_optimal_start() {
+    let ilp = warm_start_ilp();
+    let solver = ILPSolver::new();
+    let cold = solver.solve(&ilp).unwrap();
+    assert_eq!(cold, vec![1, 0, 1, 1]);
+
+    let warm = solver.solve_with_start(&ilp, &cold).unwrap();
+    assert_eq!(warm, cold);
+    assert_eq!(ilp.evaluate(&warm), ilp.evaluate(&cold));
+}
+
+#[test]
+fn test_ilp_solve_with_suboptimal_start_still_optimizes() {
+    let ilp = warm_start_ilp();
+    // Feasible but worse: only item 1 (value 5)
+    let warm = ILPSolver::new()
+        .solve_with_start(&ilp, &[0, 1, 0, 0])
+        .unwrap();
+    assert_eq!(warm, vec![1, 0, 1, 1]);
+}
+
+#[test]
+fn test_ilp_solve_with_infeasible_start_is_ignored() {
+    let ilp = warm_start_ilp();
+    let solver = ILPSolver::new();
+    // Over capacity (3 + 4 + 2 > 6)
+    assert_eq!(
+        solver.solve_with_start(&ilp, &[1, 1, 1, 0]),
+        Some(vec![1, 0, 1, 1])
+    );
+    // Outside the binary domain
+    assert_eq!(
+        solver.solve_with_start(&ilp, &[2, 0, 0, 0]),
+        Some(vec![1, 0, 1, 1])
+    );
+}
+
+#[test]
+#[should_panic(expected = "warm start has 3 values but the ILP has 4 variables")]
+fn test_ilp_solve_with_start_rejects_wrong_length() {
+    ILPSolver::new().solve_with_start(&warm_start_ilp(), &[0, 0, 0]);
+}
+
+#[test]
+fn test_ilp_iis_of_contradictory_bounds() {
+    // x0 >= 1 and x0 <= 0 clash; x0 + x1 <= 1 is unrelated
+    let ilp = ILP::<bool>::new(
+        2,
+        vec![
+            LinearConstraint::ge(vec![(0, 1.0)], 1.0),
+            LinearConstraint::le(vec![(0, 1.0), (1, 1.0)], 1.0),
+            LinearConstraint::le(vec![(0, 1.0)], 0.0),
+        ],
+        vec![(0, 1.0)],
+        ObjectiveSense::Minimize,
+    );
+    let iis = ILPSolver::new().irreducible_infeasible_subset(&ilp);
+    assert_eq!(iis, Some(vec![0, 2]));
+}
+
+#[test]
+fn test_ilp_iis_is_minimal() {
+    // x0 + x1 + x2 >= 3 with x0 + x1 <= 1 is already infeasible; x2 <= 0 is redundant
+    let ilp = ILP::<bool>::new(
+        3,
+        vec![
+            LinearConstraint::le(vec![(2, 1.0)], 0.0),
+            LinearConstraint::ge(vec![(0, 1.0), (1, 1.0), (2, 1.0)], 3.0),
+            LinearConstraint::le(vec![(0, 1.0), (1, 1.0)], 1.0),
+        ],
+        vec![],
+        ObjectiveSense::Maximize,
+    );
+    let solver = ILPSolver::new();
+    let iis = solver.irreducible_infeasible_subset(&ilp).unwrap();
+    assert_eq!(iis, vec![1, 2]);
+    for skip in 0..iis.len() {
+        let rest = iis
+            .iter()
+            .enumerate()
+            .filter(|&(k, _)| k != skip)
+            .map(|(_, &i)| ilp.constraints[i].clone())
+            .collect();
+        let relaxed = ILP::<bool>::new(3, rest, vec![], ObjectiveSense::Maximize);
+        assert!(solver.solve(&relaxed).is_some());
+    }
+}
+
+#[test]
+fn test_ilp_iis_of_feasible_ilp_is_none() {
+    let ilp = warm_start_ilp();
+    assert_eq!(ILPSolver::new().irreducible_infeasible_subset(&ilp), None);
+}
+
+#[test]
+fn test_ilp_range_constraint_matches_two_inequalities() {
+    // Minimize 2*x0 + x1 + 3*x2 subject to 4 <= x0 + 2*x1 + x2 <= 6, x in [0, 3]
+    let terms = vec![(0, 1.0), (1, 2.0), (2, 1.0)];
+    let bounds: Vec<_> = (0..3)
+        .map(|i| LinearConstraint::le(vec![(i, 1.0)], 3.0))
+        .collect();
+    let objective = vec![(0, 2.0), (1, 1.0), (2, 3.0)];
+    let mut ranged_constraints = bounds.clone();
+    ranged_constraints.push(LinearConstraint::range(terms.clone(), 4.0, 6.0));
+    let mut split_constraints = bounds;
+    split_constraints.push(LinearConstraint::ge(terms.clone(), 4.0));
+    split_constraints.push(LinearConstraint::le(terms, 6.0));
+    let ranged = ILP::<i32>::new(
+        3,
+        ranged_constraints,
+        objective.clone(),
+        ObjectiveSense::Minimize,
+    );
+    let split = ILP::<i32>::new(3, split_constraints, objective, ObjectiveSense::Minimize);
+
+    let solver = ILPSolver::new();
+    let ranged_solution = solver.solve(&ranged).unwrap();
+    let split_solution = solver.solve(&split).unwrap();
+
+    // The slack column for the ranged row is not part of the returned config.
+    assert_eq!(ranged_solution.len(), 3);
+    assert!(ranged.evaluate(&ranged_solution).is_valid());
+    assert_eq!(
+        ranged.evaluate(&ranged_solution),
+        split.evaluate(&split_solution)
+    );
+    // Optimal: x1 = 2 => objective = 2
+    assert_eq!(ranged_solution, vec![0, 2, 0]);
+}
+
 #[test]
 fn test_ilp_non_binary_bounds() {
     // Variables with larger ranges
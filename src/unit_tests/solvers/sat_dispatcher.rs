@@ -0,0 +1,118 @@
+use super::*;
+use crate::traits::Problem;
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
+
+#[test]
+fn test_dispatcher_routes_two_cnf_to_two_sat() {
+    let problem = Satisfiability::new(
+        2,
+        vec![CNFClause::new(vec![1, 2]), CNFClause::new(vec![-1])],
+    );
+    let dispatcher = SatDispatcher::new();
+    assert_eq!(dispatcher.strategy(&problem), SatStrategy::TwoSat);
+    assert_eq!(dispatcher.find_witness(&problem), Some(vec![0, 1]));
+}
+
+#[test]
+fn test_dispatcher_unit_propagation_conflict() {
+    // x1 ∧ x2 ∧ (¬x1 ∨ ¬x2 ∨ x3) ∧ ¬x3
+    let problem = Satisfiability::new(
+        3,
+        vec![
+            CNFClause::new(vec![1]),
+            CNFClause::new(vec![2]),
+            CNFClause::new(vec![-1, -2, 3]),
+            CNFClause::new(vec![-3]),
+        ],
+    );
+    let dispatcher = SatDispatcher::new();
+    assert_eq!(dispatcher.strategy(&problem), SatStrategy::UnitPropagation);
+    assert_eq!(dispatcher.find_witness(&problem), None);
+}
+
+#[test]
+fn test_dispatcher_out_of_range_variables_read_as_false() {
+    // x5 does not exist: (x1 ∨ x5) forces x1, (¬x5 ∨ x2 ∨ x3) is satisfied,
+    // and (¬x1 ∨ x2 ∨ x5) then forces x2
+    let problem = Satisfiability::new(
+        3,
+        vec![
+            CNFClause::new(vec![1, 5]),
+            CNFClause::new(vec![-5, 2, 3]),
+            CNFClause::new(vec![-1, 2, 5]),
+        ],
+    );
+    let dispatcher = SatDispatcher::new();
+    assert_eq!(dispatcher.strategy(&problem), SatStrategy::UnitPropagation);
+    let witness = dispatcher.find_witness(&problem).unwrap();
+    assert_eq!(witness, vec![1, 1, 0]);
+    assert!(problem.evaluate(&witness).0);
+
+    // (x4 ∨ x5 ∨ x0) has no in-range literal left
+    let problem = Satisfiability::new(3, vec![CNFClause::new(vec![4, 5, 0])]);
+    assert_eq!(dispatcher.find_witness(&problem), None);
+}
+
+#[test]
+fn test_dispatcher_unit_propagation_scales_past_brute_force() {
+    // x1 ∧ (x1 → x2) ∧ … ∧ (x_{n-1} → x_n) plus one 3-clause: propagation
+    // decides all 200 variables, far beyond what enumeration could visit.
+    let n = 200;
+    let mut clauses = vec![CNFClause::new(vec![1])];
+    clauses.extend((1..n as i32).map(|i| CNFClause::new(vec![-i, i + 1])));
+    clauses.push(CNFClause::new(vec![1, 2, 3]));
+    let problem = Satisfiability::new(n, clauses);
+    let dispatcher = SatDispatcher::new();
+    assert_eq!(dispatcher.strategy(&problem), SatStrategy::UnitPropagation);
+    let witness = dispatcher.find_witness(&problem).unwrap();
+    assert!(problem.evaluate(&witness).0);
+}
+
+#[test]
+fn test_dispatcher_falls_back_to_brute_force() {
+    // (x1 ∨ x2 ∨ x3) ∧ (¬x1 ∨ ¬x2 ∨ ¬x3): nothing to propagate
+    let problem = Satisfiability::new(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2, 3]),
+            CNFClause::new(vec![-1, -2, -3]),
+        ],
+    );
+    let dispatcher = SatDispatcher::new();
+    assert_eq!(dispatcher.strategy(&problem), SatStrategy::BruteForce);
+    let witness = dispatcher.find_witness(&problem).unwrap();
+    assert!(problem.evaluate(&witness).0);
+}
+
+#[test]
+fn test_dispatcher_agrees_with_brute_force() {
+    let mut rng = SmallRng::seed_from_u64(23);
+    for _ in 0..200 {
+        let num_vars = rng.random_range(1..=6);
+        let clauses = (0..rng.random_range(1..=10))
+            .map(|_| {
+                let len = rng.random_range(1..=3);
+                let literals = (0..len)
+                    .map(|_| {
+                        let var = rng.random_range(1..=num_vars as i32);
+                        if rng.random_bool(0.5) {
+                            var
+                        } else {
+                            -var
+                        }
+                    })
+                    .collect();
+                CNFClause::new(literals)
+            })
+            .collect();
+        let problem = Satisfiability::new(num_vars, clauses);
+
+        let witness = SatDispatcher::new().find_witness(&problem);
+        let expected = BruteForce::new().find_witness(&problem);
+        assert_eq!(witness.is_some(), expected.is_some());
+        if let Some(witness) = witness {
+            assert!(problem.evaluate(&witness).0);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::models::graph::MaximumIndependentSet;
+use crate::solvers::{BruteForce, GreedySolver, LocalSearch, Solver};
+use crate::topology::SimpleGraph;
+use crate::types::{Max, One};
+
+/// Maximize the sum of the chosen digits; `flavors` values per variable and
+/// no bitmask fast path, so the generic enumeration is exercised.
+#[derive(Clone)]
+struct DigitSum {
+    num_vars: usize,
+    flavors: usize,
+}
+
+impl Problem for DigitSum {
+    const NAME: &'static str = "DigitSum";
+    type Value = Max<usize>;
+
+    fn dims(&self) -> Vec<usize> {
+        vec![self.flavors; self.num_vars]
+    }
+
+    fn evaluate(&self, config: &[usize]) -> Self::Value {
+        Max(Some(config.iter().sum()))
+    }
+
+    fn variant() -> Vec<(&'static str, &'static str)> {
+        vec![]
+    }
+}
+
+fn path_mis(n: usize) -> MaximumIndependentSet<SimpleGraph, One> {
+    let edges = (0..n - 1).map(|i| (i, i + 1)).collect();
+    MaximumIndependentSet::new(SimpleGraph::new(n, edges), vec![One; n])
+}
+
+#[test]
+fn test_brute_force_telemetry_counts_every_binary_config() {
+    let problem = path_mis(6);
+    let (value, telemetry) = BruteForce::new().solve_with_telemetry(&problem);
+
+    assert_eq!(value, BruteForce::new().solve(&problem));
+    assert_eq!(telemetry.evaluations, 1 << 6);
+    assert_eq!(telemetry.pruned, 0);
+}
+
+#[test]
+fn test_brute_force_telemetry_counts_flavors_to_the_n() {
+    let binary = DigitSum {
+        num_vars: 5,
+        flavors: 2,
+    };
+    let (value, telemetry) = BruteForce::new().solve_with_telemetry(&binary);
+    assert_eq!(value, Max(Some(5)));
+    assert_eq!(telemetry.evaluations, 32);
+
+    let ternary = DigitSum {
+        num_vars: 4,
+        flavors: 3,
+    };
+    let (value, telemetry) = BruteForce::new().solve_with_telemetry(&ternary);
+    assert_eq!(value, Max(Some(8)));
+    assert_eq!(telemetry.evaluations, 81);
+}
+
+#[test]
+fn test_heuristic_telemetry_reports_fewer_evaluations_than_brute_force() {
+    let problem = path_mis(12);
+    let (_, exhaustive) = BruteForce::new().solve_with_telemetry(&problem);
+    let (_, greedy) = GreedySolver::new().solve_with_telemetry(&problem);
+    let (_, local) = LocalSearch::new(20, 1, 0).solve_with_telemetry(&problem);
+
+    assert_eq!(exhaustive.evaluations, 1 << 12);
+    assert!(greedy.evaluations > 0);
+    assert!(greedy.evaluations < exhaustive.evaluations);
+    assert!(local.evaluations > 0);
+    assert!(local.evaluations < exhaustive.evaluations);
+}
+
+#[test]
+fn test_counting_problem_clones_share_the_counter() {
+    let problem = DigitSum {
+        num_vars: 2,
+        flavors: 2,
+    };
+    let counted = CountingProblem::new(&problem);
+    let copy = counted.clone();
+
+    assert_eq!(counted.evaluate(&[1, 1]), Max(Some(2)));
+    copy.evaluate(&[0, 1]);
+    assert_eq!(counted.evaluations(), 2);
+    assert_eq!(copy.evaluations(), 2);
+}
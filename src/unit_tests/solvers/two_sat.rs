@@ -0,0 +1,135 @@
+use super::*;
+use crate::solvers::BruteForce;
+use crate::traits::Problem;
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
+
+fn random_two_cnf(rng: &mut SmallRng, num_vars: usize, num_clauses: usize) -> Vec<CNFClause> {
+    let literal = |rng: &mut SmallRng| {
+        let var = rng.random_range(1..=num_vars as i32);
+        if rng.random_bool(0.5) {
+            var
+        } else {
+            -var
+        }
+    };
+    (0..num_clauses)
+        .map(|_| CNFClause::new(vec![literal(rng), literal(rng)]))
+        .collect()
+}
+
+#[test]
+fn test_two_sat_forced_assignment() {
+    // (x1 ∨ x2) ∧ (¬x1 ∨ x2) ∧ (¬x2 ∨ x3) ∧ (¬x3 ∨ ¬x1)
+    let problem = KSatisfiability::<K2>::new(
+        3,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![-1, 2]),
+            CNFClause::new(vec![-2, 3]),
+            CNFClause::new(vec![-3, -1]),
+        ],
+    );
+    assert_eq!(
+        TwoSatSolver::new().find_witness(&problem),
+        Some(vec![0, 1, 1])
+    );
+}
+
+#[test]
+fn test_two_sat_contradiction() {
+    // (x1 ∨ x2) ∧ (x1 ∨ ¬x2) ∧ (¬x1 ∨ x2) ∧ (¬x1 ∨ ¬x2)
+    let problem = KSatisfiability::<K2>::new(
+        2,
+        vec![
+            CNFClause::new(vec![1, 2]),
+            CNFClause::new(vec![1, -2]),
+            CNFClause::new(vec![-1, 2]),
+            CNFClause::new(vec![-1, -2]),
+        ],
+    );
+    assert_eq!(TwoSatSolver::new().find_witness(&problem), None);
+}
+
+#[test]
+fn test_two_sat_long_implication_chain() {
+    // x1 ∧ (x1 → x2) ∧ … ∧ (x_{n-1} → x_n): the only model sets every variable
+    let n = 5000;
+    let mut clauses = vec![CNFClause::new(vec![1])];
+    clauses.extend((1..n as i32).map(|i| CNFClause::new(vec![-i, i + 1])));
+    let problem = Satisfiability::new(n, clauses);
+    let witness = TwoSatSolver::new()
+        .find_satisfiability_witness(&problem)
+        .unwrap();
+    assert_eq!(witness, vec![1; n]);
+    assert!(problem.evaluate(&witness).0);
+}
+
+#[test]
+fn test_two_sat_unsatisfiable_cycle() {
+    // x1 ∧ (x1 → x2) ∧ … ∧ (x_n → ¬x1)
+    let n = 5000;
+    let mut clauses = vec![CNFClause::new(vec![1])];
+    clauses.extend((1..n as i32).map(|i| CNFClause::new(vec![-i, i + 1])));
+    clauses.push(CNFClause::new(vec![-(n as i32), -1]));
+    let problem = Satisfiability::new(n, clauses);
+    assert_eq!(
+        TwoSatSolver::new().find_satisfiability_witness(&problem),
+        None
+    );
+}
+
+#[test]
+fn test_two_sat_empty_clause_is_unsatisfiable() {
+    let problem = Satisfiability::new(1, vec![CNFClause::new(vec![])]);
+    assert_eq!(
+        TwoSatSolver::new().find_satisfiability_witness(&problem),
+        None
+    );
+}
+
+#[test]
+fn test_two_sat_out_of_range_variables_read_as_false() {
+    // x3 does not exist: (x1 ∨ x3) forces x1, and (¬x3 ∨ x2) is satisfied
+    let problem = Satisfiability::new(
+        2,
+        vec![CNFClause::new(vec![1, 3]), CNFClause::new(vec![-3, 2])],
+    );
+    let witness = TwoSatSolver::new()
+        .find_satisfiability_witness(&problem)
+        .unwrap();
+    assert_eq!(witness[0], 1);
+    assert!(problem.evaluate(&witness).0);
+
+    // (x3 ∨ x0) has no in-range literal left
+    let problem = Satisfiability::new(2, vec![CNFClause::new(vec![3, 0])]);
+    assert_eq!(
+        TwoSatSolver::new().find_satisfiability_witness(&problem),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "at most two literals")]
+fn test_two_sat_rejects_three_literal_clause() {
+    let problem = Satisfiability::new(3, vec![CNFClause::new(vec![1, 2, 3])]);
+    TwoSatSolver::new().find_satisfiability_witness(&problem);
+}
+
+#[test]
+fn test_two_sat_agrees_with_brute_force() {
+    let mut rng = SmallRng::seed_from_u64(11);
+    for _ in 0..200 {
+        let num_vars = rng.random_range(1..=8);
+        let num_clauses = rng.random_range(1..=3 * num_vars);
+        let clauses = random_two_cnf(&mut rng, num_vars, num_clauses);
+        let problem = KSatisfiability::<K2>::new(num_vars, clauses);
+
+        let witness = TwoSatSolver::new().find_witness(&problem);
+        let expected = BruteForce::new().find_witness(&problem);
+        assert_eq!(witness.is_some(), expected.is_some());
+        if let Some(witness) = witness {
+            assert!(problem.evaluate(&witness).0);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+use crate::models::graph::{KColoring, MaximumIndependentSet};
+use crate::models::misc::Factoring;
+use crate::topology::{small_graphs, SimpleGraph};
+use crate::traits::Problem;
+use crate::types::One;
+use crate::variant::K3;
+
+fn petersen() -> SimpleGraph {
+    let (n, edges) = small_graphs::petersen();
+    SimpleGraph::new(n, edges)
+}
+
+#[test]
+fn test_graph_statistics_petersen() {
+    let stats = GraphStatistics::from_graph(&petersen());
+    assert_eq!(stats.num_vertices, 10);
+    assert_eq!(stats.num_edges, 15);
+    assert!((stats.density - 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(stats.min_degree, 3);
+    assert_eq!(stats.max_degree, 3);
+    assert!((stats.avg_degree - 3.0).abs() < 1e-12);
+    assert_eq!(stats.num_components, 1);
+    assert_eq!(stats.pathwidth_upper_bound, 5);
+}
+
+#[test]
+fn test_graph_statistics_components_and_isolated_vertices() {
+    // Two disjoint edges plus an isolated vertex.
+    let stats = GraphStatistics::from_graph(&SimpleGraph::new(5, vec![(0, 1), (2, 3)]));
+    assert_eq!(stats.num_components, 3);
+    assert_eq!(stats.min_degree, 0);
+    assert_eq!(stats.max_degree, 1);
+    assert_eq!(stats.pathwidth_upper_bound, 1);
+
+    let empty = GraphStatistics::from_graph(&SimpleGraph::empty(0));
+    assert_eq!(empty.num_components, 0);
+    assert_eq!(empty.density, 0.0);
+    assert_eq!(empty.avg_degree, 0.0);
+}
+
+#[test]
+fn test_problem_statistics_default_has_no_graph() {
+    let stats = Factoring::new(2, 3, 15).statistics();
+    assert_eq!(stats.num_variables, 5);
+    assert_eq!(stats.domain_sizes, BTreeMap::from([(2, 5)]));
+    assert!((stats.log2_search_space - 5.0).abs() < 1e-12);
+    assert!(stats.graph.is_none());
+}
+
+#[test]
+fn test_problem_statistics_graph_override() {
+    let mis = MaximumIndependentSet::new(petersen(), vec![One; 10]);
+    let stats = mis.statistics();
+    assert_eq!(stats.num_variables, 10);
+    assert_eq!(stats.graph.unwrap().max_degree, 3);
+
+    let coloring = KColoring::<K3, _>::new(petersen());
+    let stats = coloring.statistics();
+    assert_eq!(stats.domain_sizes, BTreeMap::from([(3, 10)]));
+    assert!((stats.log2_search_space - 10.0 * 3f64.log2()).abs() < 1e-9);
+    assert_eq!(stats.graph.unwrap().pathwidth_upper_bound, 5);
+}
+
+#[test]
+fn test_problem_statistics_json() {
+    let mis = MaximumIndependentSet::new(petersen(), vec![One; 10]);
+    let json = serde_json::to_value(mis.statistics()).unwrap();
+    assert_eq!(json["num_variables"], 10);
+    assert_eq!(json["domain_sizes"]["2"], 10);
+    assert_eq!(json["graph"]["num_edges"], 15);
+
+    let json = serde_json::to_value(Factoring::new(2, 2, 6).statistics()).unwrap();
+    assert!(json.get("graph").is_none());
+}
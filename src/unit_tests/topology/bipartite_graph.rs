@@ -1,4 +1,4 @@
-use crate::topology::{BipartiteGraph, Graph};
+use crate::topology::{BipartiteGraph, Graph, SimpleGraph};
 
 #[test]
 fn test_bipartite_graph_basic() {
@@ -58,3 +58,24 @@ fn test_bipartite_graph_invalid_left_index() {
 fn test_bipartite_graph_invalid_right_index() {
     BipartiteGraph::new(2, 2, vec![(0, 2)]);
 }
+
+#[test]
+fn test_bipartite_graph_part_vertices() {
+    let g = BipartiteGraph::new(2, 3, vec![(0, 0), (1, 2)]);
+    assert_eq!(g.left_vertices().collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(g.right_vertices().collect::<Vec<_>>(), vec![2, 3, 4]);
+    for v in g.right_vertices() {
+        assert!(g.neighbors(v).iter().all(|u| g.left_vertices().contains(u)));
+    }
+}
+
+#[test]
+fn test_bipartite_graph_roundtrip_through_simple_graph() {
+    let g = BipartiteGraph::new(2, 2, vec![(0, 0), (0, 1), (1, 1)]);
+    let simple = SimpleGraph::new(g.num_vertices(), g.edges());
+    assert!(simple.is_bipartite());
+    let back = simple.try_into_bipartite().unwrap();
+    assert_eq!(back.left_size(), 2);
+    assert_eq!(back.right_size(), 2);
+    assert_eq!(back.edges(), g.edges());
+}
@@ -77,6 +77,45 @@ fn test_simple_graph_neighbors() {
     assert_eq!(graph.neighbors(1), vec![0]);
 }
 
+#[test]
+fn test_simple_graph_neighbors_after_add_edge() {
+    let mut graph = SimpleGraph::new(4, vec![(0, 1), (1, 2)]);
+    graph.add_edge(2, 3);
+    graph.add_edge(0, 3);
+
+    assert_eq!(graph.num_edges(), 4);
+    assert!(graph.has_edge(3, 0));
+    for v in 0..4 {
+        let mut cached = graph.neighbor_slice(v).to_vec();
+        cached.sort();
+        let mut expected: Vec<usize> = graph
+            .edges()
+            .into_iter()
+            .filter_map(|(a, b)| match (a == v, b == v) {
+                (true, _) => Some(b),
+                (_, true) => Some(a),
+                _ => None,
+            })
+            .collect();
+        expected.sort();
+        assert_eq!(cached, expected, "vertex {v}");
+        assert_eq!(graph.neighbors(v), graph.neighbor_slice(v));
+        assert_eq!(graph.degree(v), expected.len());
+    }
+    // The cached lists match a graph built from scratch.
+    let rebuilt = SimpleGraph::new(4, graph.edges());
+    for v in 0..4 {
+        assert_eq!(rebuilt.neighbor_slice(v), graph.neighbor_slice(v));
+    }
+    assert!(graph.neighbor_slice(7).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "edge (1, 4) references vertex >= num_vertices")]
+fn test_simple_graph_add_edge_invalid() {
+    SimpleGraph::empty(3).add_edge(1, 4);
+}
+
 #[test]
 fn test_simple_graph_degree() {
     let graph = SimpleGraph::new(4, vec![(0, 1), (0, 2), (0, 3)]);
@@ -143,6 +182,9 @@ fn test_simplegraph_json_roundtrip() {
     assert_eq!(edges.len(), 3);
     let roundtrip: SimpleGraph = serde_json::from_value(json).unwrap();
     assert_eq!(graph, roundtrip);
+    for v in 0..4 {
+        assert_eq!(roundtrip.neighbor_slice(v), graph.neighbor_slice(v));
+    }
 }
 
 #[test]
@@ -153,3 +195,48 @@ fn test_simplegraph_json_format() {
     assert!(!json_str.contains("node_holes"));
     assert!(json_str.contains("num_vertices"));
 }
+
+#[test]
+fn test_simple_graph_is_bipartite() {
+    assert!(SimpleGraph::cycle(6).is_bipartite());
+    assert!(SimpleGraph::grid(3, 4).is_bipartite());
+    assert!(SimpleGraph::empty(3).is_bipartite());
+    assert!(!SimpleGraph::cycle(5).is_bipartite());
+    assert!(!SimpleGraph::complete(3).is_bipartite());
+    // A self-loop is an odd cycle of length one.
+    assert!(!SimpleGraph::new(2, vec![(0, 1), (1, 1)]).is_bipartite());
+}
+
+#[test]
+fn test_simple_graph_try_into_bipartite_even_cycle() {
+    // C6 alternates between {0, 2, 4} and {1, 3, 5}.
+    let g = SimpleGraph::cycle(6).try_into_bipartite().unwrap();
+    assert_eq!(g.left_size(), 3);
+    assert_eq!(g.right_size(), 3);
+    assert_eq!(g.num_edges(), 6);
+    // Vertex 2k maps to left k and vertex 2k+1 to right k.
+    let mut edges = g.left_edges().to_vec();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 0), (0, 2), (1, 0), (1, 1), (2, 1), (2, 2)]);
+}
+
+#[test]
+fn test_simple_graph_try_into_bipartite_components() {
+    // Star K_{1,3} plus an isolated vertex and a separate edge.
+    let graph = SimpleGraph::new(7, vec![(0, 1), (0, 2), (0, 3), (5, 6)]);
+    let g = graph.try_into_bipartite().unwrap();
+    // Left: {0, 4, 5}; right: {1, 2, 3, 6}.
+    assert_eq!(g.left_size(), 3);
+    assert_eq!(g.right_size(), 4);
+    assert_eq!(g.left_vertices(), 0..3);
+    assert_eq!(g.right_vertices(), 3..7);
+    assert_eq!(g.num_edges(), 4);
+    assert!(g.left_edges().contains(&(2, 3)));
+}
+
+#[test]
+fn test_simple_graph_try_into_bipartite_odd_cycle() {
+    let err = SimpleGraph::cycle(5).try_into_bipartite().unwrap_err();
+    assert!(matches!(err, crate::error::ProblemError::InvalidProblem(_)));
+    assert!(err.to_string().contains("not bipartite"));
+}
@@ -0,0 +1,99 @@
+use crate::topology::HyperGraph;
+
+#[test]
+fn test_hypergraph_creation_and_counts() {
+    let graph = HyperGraph::new(5, vec![vec![0, 1, 2], vec![2, 3], vec![4]]);
+
+    assert_eq!(graph.num_vertices(), 5);
+    assert_eq!(graph.num_edges(), 3);
+    assert_eq!(graph.edges(), &[vec![0, 1, 2], vec![2, 3], vec![4]]);
+    assert_eq!(graph.rank(), 3);
+    assert_eq!(graph.degree(2), 2);
+    assert_eq!(graph.degree(4), 1);
+    assert!(!graph.is_empty());
+}
+
+#[test]
+fn test_hypergraph_empty() {
+    let graph = HyperGraph::empty(3);
+    assert_eq!(graph.num_vertices(), 3);
+    assert_eq!(graph.num_edges(), 0);
+    assert_eq!(graph.rank(), 0);
+    assert!(HyperGraph::empty(0).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "references vertex >= num_vertices")]
+fn test_hypergraph_rejects_out_of_range_vertex() {
+    HyperGraph::new(3, vec![vec![0, 3]]);
+}
+
+#[test]
+fn test_hypergraph_serde_roundtrip() {
+    let graph = HyperGraph::new(4, vec![vec![0, 1, 2], vec![1, 3]]);
+    let json = serde_json::to_string(&graph).unwrap();
+    let restored: HyperGraph = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, graph);
+}
+
+#[test]
+fn test_hypergraph_incidence_matrix() {
+    let graph = HyperGraph::new(5, vec![vec![0, 1, 2], vec![2, 3], vec![4]]);
+    let matrix = graph.incidence_matrix();
+
+    assert_eq!(matrix.len(), graph.num_vertices());
+    assert!(matrix.iter().all(|row| row.len() == graph.num_edges()));
+    assert_eq!(matrix[2], vec![true, true, false]);
+    assert_eq!(matrix[4], vec![false, false, true]);
+    for (v, row) in matrix.iter().enumerate() {
+        assert_eq!(row.iter().filter(|&&x| x).count(), graph.degree(v));
+    }
+    assert_eq!(
+        HyperGraph::empty(2).incidence_matrix(),
+        vec![Vec::<bool>::new(); 2]
+    );
+}
+
+#[test]
+fn test_hypergraph_dual() {
+    let graph = HyperGraph::new(5, vec![vec![0, 1, 2], vec![2, 3], vec![4]]);
+    let dual = graph.dual();
+
+    assert_eq!(dual.num_vertices(), graph.num_edges());
+    assert_eq!(dual.num_edges(), graph.num_vertices());
+    assert_eq!(
+        dual.edges(),
+        &[vec![0], vec![0], vec![0, 1], vec![1], vec![2]]
+    );
+    assert_eq!(dual.degree(0), 3);
+
+    // The incidence matrix of the dual is the transpose
+    let matrix = graph.incidence_matrix();
+    let dual_matrix = dual.incidence_matrix();
+    for (v, row) in matrix.iter().enumerate() {
+        for (e, &incident) in row.iter().enumerate() {
+            assert_eq!(dual_matrix[e][v], incident);
+        }
+    }
+}
+
+#[test]
+fn test_hypergraph_dual_of_dual_sorts_edges() {
+    let graph = HyperGraph::new(4, vec![vec![3, 1], vec![0, 2, 1], vec![], vec![2]]);
+    let double_dual = graph.dual().dual();
+
+    assert_eq!(
+        double_dual,
+        HyperGraph::new(4, vec![vec![1, 3], vec![0, 1, 2], vec![], vec![2]])
+    );
+    assert_eq!(double_dual.incidence_matrix(), graph.incidence_matrix());
+}
+
+#[test]
+fn test_hypergraph_dual_isolated_vertex() {
+    let graph = HyperGraph::new(3, vec![vec![0, 1]]);
+    let dual = graph.dual();
+    assert_eq!(dual.num_vertices(), 1);
+    assert_eq!(dual.edges(), &[vec![0], vec![0], vec![]]);
+    assert_eq!(dual.dual().edges(), graph.edges());
+}
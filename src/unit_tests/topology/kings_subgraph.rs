@@ -0,0 +1,83 @@
+use super::*;
+use std::collections::BTreeSet;
+
+fn grid(rows: i32, cols: i32) -> KingsSubgraph {
+    KingsSubgraph::from_occupied((0..rows).flat_map(|r| (0..cols).map(move |c| (r, c))))
+}
+
+fn neighbor_cells(graph: &KingsSubgraph, v: usize) -> BTreeSet<(i32, i32)> {
+    graph
+        .neighbors(v)
+        .into_iter()
+        .map(|u| graph.positions()[u])
+        .collect()
+}
+
+#[test]
+fn test_kings_3x3_neighbors() {
+    let graph = grid(3, 3);
+    assert_eq!(graph.num_vertices(), 9);
+
+    // Center (1, 1) is vertex 4 and sees the whole ring around it.
+    let center: BTreeSet<_> = (0..3)
+        .flat_map(|r| (0..3).map(move |c| (r, c)))
+        .filter(|&cell| cell != (1, 1))
+        .collect();
+    assert_eq!(neighbor_cells(&graph, 4), center);
+
+    // Corner (0, 0) only has the three cells of its own quadrant.
+    assert_eq!(
+        neighbor_cells(&graph, 0),
+        BTreeSet::from([(0, 1), (1, 0), (1, 1)])
+    );
+    // Edge midpoint (0, 1) has five neighbors.
+    assert_eq!(
+        neighbor_cells(&graph, 1),
+        BTreeSet::from([(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)])
+    );
+}
+
+#[test]
+fn test_kings_num_edges_matches_neighbors() {
+    // An r x c king's graph has (r-1)c + r(c-1) + 2(r-1)(c-1) edges.
+    for (rows, cols) in [(1, 1), (1, 4), (2, 2), (3, 3), (3, 5)] {
+        let graph = grid(rows, cols);
+        let expected =
+            ((rows - 1) * cols + rows * (cols - 1) + 2 * (rows - 1) * (cols - 1)) as usize;
+        assert_eq!(graph.num_edges(), expected, "{rows}x{cols}");
+        assert_eq!(graph.edges().len(), expected);
+        let degree_sum: usize = (0..graph.num_vertices())
+            .map(|v| graph.neighbors(v).len())
+            .sum();
+        assert_eq!(degree_sum, 2 * expected);
+        for (u, v) in graph.edges() {
+            assert!(graph.has_edge(u, v) && graph.has_edge(v, u));
+        }
+    }
+}
+
+#[test]
+fn test_kings_distance_two_not_adjacent() {
+    let graph = KingsSubgraph::new(vec![(0, 0), (0, 2), (2, 2), (2, 1)]);
+    assert!(!graph.has_edge(0, 1));
+    assert!(!graph.has_edge(0, 2));
+    assert!(graph.has_edge(2, 3));
+    assert!(!graph.has_edge(1, 3));
+    assert_eq!(graph.neighbors(0), Vec::<usize>::new());
+    assert_eq!(graph.num_edges(), 1);
+}
+
+#[test]
+fn test_kings_from_occupied_drops_repeats() {
+    let graph = KingsSubgraph::from_occupied([(0, 0), (0, 1), (0, 0), (5, 5)]);
+    assert_eq!(graph.positions(), &[(0, 0), (0, 1), (5, 5)]);
+    assert_eq!(graph.edges(), vec![(0, 1)]);
+    assert!(!graph.has_edge(0, 0));
+}
+
+#[test]
+fn test_kings_neighbors_out_of_range() {
+    let graph = grid(2, 2);
+    assert!(graph.neighbors(4).is_empty());
+    assert!(!graph.has_edge(0, 4));
+}
@@ -1,17 +1,22 @@
-use crate::topology::{Graph, PlanarGraph};
+use crate::topology::{Graph, GraphCast, PlanarGraph, SimpleGraph};
+use crate::variant::CastToParent;
+
+fn k33_edges() -> Vec<(usize, usize)> {
+    (0..3).flat_map(|u| (3..6).map(move |v| (u, v))).collect()
+}
 
 #[test]
 fn test_planar_graph_basic() {
     // K4 is planar: 4 vertices, 6 edges, 6 <= 3*4 - 6 = 6
     let edges = vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
-    let g = PlanarGraph::new(4, edges);
+    let g = PlanarGraph::new(4, edges).unwrap();
     assert_eq!(g.num_vertices(), 4);
     assert_eq!(g.num_edges(), 6);
 }
 
 #[test]
 fn test_planar_graph_delegates_to_inner() {
-    let g = PlanarGraph::new(3, vec![(0, 1), (1, 2)]);
+    let g = PlanarGraph::new(3, vec![(0, 1), (1, 2)]).unwrap();
     assert!(g.has_edge(0, 1));
     assert!(!g.has_edge(0, 2));
     let mut n1 = g.neighbors(1);
@@ -20,7 +25,6 @@ fn test_planar_graph_delegates_to_inner() {
 }
 
 #[test]
-#[should_panic]
 fn test_planar_graph_rejects_k5() {
     // K5 has 10 edges, but 3*5 - 6 = 9. Fails necessary condition.
     let mut edges = Vec::new();
@@ -29,18 +33,68 @@ fn test_planar_graph_rejects_k5() {
             edges.push((i, j));
         }
     }
-    PlanarGraph::new(5, edges);
+    let err = PlanarGraph::new(5, edges).unwrap_err().to_string();
+    assert!(err.contains("at most 9 edges"), "{err}");
+}
+
+#[test]
+fn test_planar_graph_rejects_k33() {
+    // K3,3 has 9 <= 3*6 - 6 = 12 edges, so only the exact test rejects it.
+    let err = PlanarGraph::new(6, k33_edges()).unwrap_err().to_string();
+    assert!(err.contains("not planar"), "{err}");
+}
+
+#[test]
+fn test_planar_graph_unchecked_skips_validation() {
+    let g = PlanarGraph::new_unchecked(6, k33_edges());
+    assert_eq!(g.num_edges(), 9);
+    assert!(g.embedding().is_none());
+}
+
+#[test]
+fn test_planar_graph_embedding() {
+    let g = PlanarGraph::new(4, SimpleGraph::complete(4).edges()).unwrap();
+    let rotation = g.embedding().unwrap();
+    for (v, order) in rotation.iter().enumerate() {
+        let mut sorted = order.clone();
+        sorted.sort();
+        let mut neighbors = g.neighbors(v);
+        neighbors.sort();
+        assert_eq!(sorted, neighbors);
+    }
+}
+
+#[test]
+fn test_planar_graph_cast_preserves_structure() {
+    let g = PlanarGraph::new(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 2)]).unwrap();
+    let cast: SimpleGraph = g.cast_graph();
+    assert_eq!(&cast, g.inner());
+    assert_eq!(g.cast_to_parent(), cast);
+    assert_eq!(cast.num_vertices(), 5);
+    assert_eq!(cast.num_edges(), 6);
+}
+
+#[test]
+fn test_planar_graph_deserialize_validates() {
+    let g = PlanarGraph::new(3, vec![(0, 1), (1, 2)]).unwrap();
+    let json = serde_json::to_value(&g).unwrap();
+    let back: PlanarGraph = serde_json::from_value(json).unwrap();
+    assert_eq!(back.inner(), g.inner());
+
+    let k33 = serde_json::to_value(PlanarGraph::new_unchecked(6, k33_edges())).unwrap();
+    let err = serde_json::from_value::<PlanarGraph>(k33).unwrap_err();
+    assert!(err.to_string().contains("not planar"), "{err}");
 }
 
 #[test]
 fn test_planar_graph_empty() {
-    let g = PlanarGraph::new(3, vec![]);
+    let g = PlanarGraph::new(3, vec![]).unwrap();
     assert_eq!(g.num_vertices(), 3);
     assert_eq!(g.num_edges(), 0);
 }
 
 #[test]
 fn test_planar_graph_tree() {
-    let g = PlanarGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]);
+    let g = PlanarGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]).unwrap();
     assert_eq!(g.num_edges(), 3);
 }
@@ -0,0 +1,255 @@
+use super::*;
+use crate::topology::{Graph, SimpleGraph};
+use std::collections::HashSet;
+
+fn complete_bipartite(a: usize, b: usize) -> Vec<(usize, usize)> {
+    (0..a)
+        .flat_map(|u| (0..b).map(move |v| (u, a + v)))
+        .collect()
+}
+
+fn petersen() -> Vec<(usize, usize)> {
+    crate::topology::small_graphs::petersen().1
+}
+
+/// Assert that `planar_embedding` returns a rotation system of genus zero:
+/// each rotation permutes the neighbors, and tracing faces gives
+/// `V - E + F = 2` per connected component with edges.
+fn assert_planar_embedding(num_vertices: usize, edges: &[(usize, usize)]) {
+    let graph = SimpleGraph::new(num_vertices, edges.to_vec());
+    let rotation = planar_embedding(num_vertices, edges).expect("graph is planar");
+    assert_eq!(rotation.len(), num_vertices);
+    for (v, order) in rotation.iter().enumerate() {
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        let mut neighbors = graph.neighbors(v);
+        neighbors.sort_unstable();
+        assert_eq!(sorted, neighbors, "rotation at {v}");
+    }
+
+    let next = |(u, v): (usize, usize)| {
+        let around = &rotation[v];
+        let i = around.iter().position(|&x| x == u).unwrap();
+        (v, around[(i + 1) % around.len()])
+    };
+    let mut seen = HashSet::new();
+    let mut faces = 0;
+    for (u, v) in graph.edges() {
+        for dart in [(u, v), (v, u)] {
+            if seen.contains(&dart) {
+                continue;
+            }
+            faces += 1;
+            let mut d = dart;
+            while seen.insert(d) {
+                d = next(d);
+            }
+        }
+    }
+
+    let mut component: Vec<usize> = (0..num_vertices).collect();
+    fn find(component: &mut [usize], v: usize) -> usize {
+        if component[v] != v {
+            component[v] = find(component, component[v]);
+        }
+        component[v]
+    }
+    for (u, v) in graph.edges() {
+        let (a, b) = (find(&mut component, u), find(&mut component, v));
+        component[a] = b;
+    }
+    let touched: Vec<usize> = (0..num_vertices)
+        .filter(|&v| !rotation[v].is_empty())
+        .collect();
+    let roots: HashSet<usize> = touched.iter().map(|&v| find(&mut component, v)).collect();
+    let euler = touched.len() as isize - graph.num_edges() as isize + faces;
+    assert_eq!(euler, 2 * roots.len() as isize, "{edges:?}");
+}
+
+#[test]
+fn test_small_graphs_are_planar() {
+    assert!(is_planar(0, &[]));
+    assert!(is_planar(1, &[]));
+    assert!(is_planar(2, &[(0, 1)]));
+    assert!(SimpleGraph::complete(4).is_planar());
+    assert!(SimpleGraph::cycle(7).is_planar());
+    assert!(SimpleGraph::grid(4, 5).is_planar());
+}
+
+#[test]
+fn test_kuratowski_graphs_are_not_planar() {
+    assert!(!SimpleGraph::complete(5).is_planar());
+    // K3,3 meets the Euler bound 3n - 6 = 12, so only the embedding test
+    // rejects it.
+    assert!(!is_planar(6, &complete_bipartite(3, 3)));
+    assert!(!is_planar(10, &petersen()));
+}
+
+#[test]
+fn test_removing_any_edge_makes_kuratowski_graphs_planar() {
+    let k5 = SimpleGraph::complete(5).edges();
+    for skip in 0..k5.len() {
+        let edges: Vec<_> = k5
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != skip)
+            .map(|(_, &e)| e)
+            .collect();
+        assert!(is_planar(5, &edges), "K5 minus edge {skip}");
+    }
+    let k33 = complete_bipartite(3, 3);
+    for skip in 0..k33.len() {
+        let mut edges = k33.clone();
+        edges.remove(skip);
+        assert!(is_planar(6, &edges), "K3,3 minus edge {skip}");
+    }
+}
+
+#[test]
+fn test_subdivided_k33_is_not_planar() {
+    // Subdivide every edge of K3,3: 9 new vertices, 18 edges, far below the
+    // Euler bound.
+    let mut edges = Vec::new();
+    for (i, (u, v)) in complete_bipartite(3, 3).into_iter().enumerate() {
+        let mid = 6 + i;
+        edges.push((u, mid));
+        edges.push((mid, v));
+    }
+    assert!(!is_planar(15, &edges));
+}
+
+#[test]
+fn test_blocks_are_tested_independently() {
+    // Two K4s sharing a cut vertex, plus a pendant path: planar.
+    let mut edges = SimpleGraph::complete(4).edges();
+    edges.extend(
+        SimpleGraph::complete(4)
+            .edges()
+            .into_iter()
+            .map(|(u, v)| (u + 3, v + 3)),
+    );
+    edges.extend([(6, 7), (7, 8)]);
+    assert!(is_planar(9, &edges));
+
+    // Hanging K5 off a tree keeps the graph non-planar.
+    let mut edges = SimpleGraph::complete(5).edges();
+    edges.extend([(4, 5), (5, 6)]);
+    assert!(!is_planar(7, &edges));
+}
+
+#[test]
+fn test_parallel_edges_and_loops_are_ignored() {
+    assert!(is_planar(3, &[(0, 1), (1, 0), (1, 1), (1, 2)]));
+}
+
+#[test]
+fn test_wheels_and_octahedron_are_planar() {
+    // Wheel W_6: hub 0 and rim 1..=6.
+    let mut wheel: Vec<_> = (1..=6).map(|v| (0, v)).collect();
+    wheel.extend((1..=6).map(|v| (v, v % 6 + 1)));
+    assert!(is_planar(7, &wheel));
+
+    // Octahedron = K_{2,2,2}: maximal planar, 12 = 3 * 6 - 6 edges.
+    let octahedron: Vec<_> = (0..6)
+        .flat_map(|u| ((u + 1)..6).map(move |v| (u, v)))
+        .filter(|&(u, v)| v != u + 3)
+        .collect();
+    assert_eq!(octahedron.len(), 12);
+    assert!(is_planar(6, &octahedron));
+}
+
+/// Minimal deterministic LCG so the randomized checks are reproducible.
+struct Lcg(u64);
+
+impl Lcg {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 33) as usize) % n
+    }
+}
+
+#[test]
+fn test_random_planar_and_nonplanar_graphs() {
+    let mut rng = Lcg(7);
+    for _ in 0..50 {
+        // Stacked triangulation: repeatedly insert a vertex into a face.
+        let n = 4 + rng.below(12);
+        let mut edges = vec![(0, 1), (1, 2), (0, 2)];
+        let mut faces = vec![(0, 1, 2), (0, 1, 2)];
+        for v in 3..n {
+            let (a, b, c) = faces.swap_remove(rng.below(faces.len()));
+            edges.extend([(a, v), (b, v), (c, v)]);
+            faces.extend([(a, b, v), (b, c, v), (a, c, v)]);
+        }
+        // Shuffle labels and drop some edges; planarity is preserved.
+        let mut label: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            label.swap(i, rng.below(i + 1));
+        }
+        let planar: Vec<_> = edges
+            .iter()
+            .filter(|_| rng.below(4) != 0)
+            .map(|&(u, v)| (label[u], label[v]))
+            .collect();
+        assert!(is_planar(n, &planar), "{planar:?}");
+        let relabeled: Vec<_> = edges.iter().map(|&(u, v)| (label[u], label[v])).collect();
+        assert!(is_planar(n, &relabeled), "{relabeled:?}");
+        assert_planar_embedding(n, &planar);
+        assert_planar_embedding(n, &relabeled);
+
+        // A subdivided K5 stays non-planar whatever else is added.
+        let mut k5: Vec<(usize, usize)> = Vec::new();
+        let mut next = 5;
+        for (u, v) in SimpleGraph::complete(5).edges() {
+            let mid = next;
+            next += 1;
+            k5.extend([(u, mid), (mid, v)]);
+        }
+        for _ in 0..rng.below(10) {
+            k5.push((rng.below(next), rng.below(next)));
+        }
+        assert!(!is_planar(next, &k5), "{k5:?}");
+        assert!(planar_embedding(next, &k5).is_none());
+    }
+}
+
+#[test]
+fn test_planar_embeddings_have_genus_zero() {
+    assert_planar_embedding(0, &[]);
+    assert_planar_embedding(3, &[(0, 1)]);
+    assert_planar_embedding(4, &SimpleGraph::complete(4).edges());
+    assert_planar_embedding(20, &SimpleGraph::grid(4, 5).edges());
+    // Two triangles and a pendant path sharing cut vertices.
+    assert_planar_embedding(
+        7,
+        &[
+            (0, 1),
+            (1, 2),
+            (0, 2),
+            (2, 3),
+            (3, 4),
+            (2, 4),
+            (4, 5),
+            (5, 6),
+        ],
+    );
+    // Wheel W_6 and the octahedron, whose faces are all forced.
+    let mut wheel: Vec<_> = (1..=6).map(|v| (0, v)).collect();
+    wheel.extend((1..=6).map(|v| (v, v % 6 + 1)));
+    assert_planar_embedding(7, &wheel);
+    let octahedron: Vec<_> = (0..6)
+        .flat_map(|u| ((u + 1)..6).map(move |v| (u, v)))
+        .filter(|&(u, v)| v != u + 3)
+        .collect();
+    assert_planar_embedding(6, &octahedron);
+}
+
+#[test]
+fn test_nonplanar_graphs_have_no_embedding() {
+    assert!(planar_embedding(5, &SimpleGraph::complete(5).edges()).is_none());
+    assert!(planar_embedding(6, &complete_bipartite(3, 3)).is_none());
+    assert!(planar_embedding(10, &petersen()).is_none());
+}
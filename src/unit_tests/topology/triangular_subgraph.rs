@@ -0,0 +1,83 @@
+use super::*;
+use std::collections::BTreeSet;
+
+fn patch(rows: i32, cols: i32) -> TriangularSubgraph {
+    TriangularSubgraph::new(
+        (0..rows)
+            .flat_map(|r| (0..cols).map(move |c| (r, c)))
+            .collect(),
+    )
+}
+
+fn neighbor_cells(graph: &TriangularSubgraph, v: usize) -> BTreeSet<(i32, i32)> {
+    graph
+        .neighbors(v)
+        .into_iter()
+        .map(|u| graph.positions()[u])
+        .collect()
+}
+
+fn index_of(graph: &TriangularSubgraph, cell: (i32, i32)) -> usize {
+    graph.positions().iter().position(|&p| p == cell).unwrap()
+}
+
+#[test]
+fn test_triangular_interior_has_six_neighbors() {
+    let graph = patch(5, 5);
+
+    // Even column: adjacent columns contribute rows r and r + 1.
+    let v = index_of(&graph, (2, 2));
+    assert_eq!(
+        neighbor_cells(&graph, v),
+        BTreeSet::from([(1, 2), (3, 2), (2, 1), (3, 1), (2, 3), (3, 3)])
+    );
+
+    // Odd column: adjacent columns contribute rows r - 1 and r.
+    let v = index_of(&graph, (2, 1));
+    assert_eq!(
+        neighbor_cells(&graph, v),
+        BTreeSet::from([(1, 1), (3, 1), (1, 0), (2, 0), (1, 2), (2, 2)])
+    );
+}
+
+#[test]
+fn test_triangular_neighbors_are_unit_distance() {
+    let graph = patch(4, 4);
+    let coords = graph.coordinates().unwrap();
+    for (u, v) in graph.edges() {
+        let (dx, dy) = (coords[u].0 - coords[v].0, coords[u].1 - coords[v].1);
+        assert!(((dx * dx + dy * dy).sqrt() - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_triangular_boundary_neighbors() {
+    let graph = patch(3, 3);
+    // Corner (0, 0) in an even column: (1, 0) below and (0, 1), (1, 1) beside.
+    let v = index_of(&graph, (0, 0));
+    assert_eq!(
+        neighbor_cells(&graph, v),
+        BTreeSet::from([(1, 0), (0, 1), (1, 1)])
+    );
+    // Corner (0, 1) in an odd column loses the row above it.
+    let v = index_of(&graph, (0, 1));
+    assert_eq!(
+        neighbor_cells(&graph, v),
+        BTreeSet::from([(1, 1), (0, 0), (0, 2)])
+    );
+}
+
+#[test]
+fn test_triangular_num_edges_matches_neighbors() {
+    let graph = patch(3, 3);
+    // 3 columns x 2 vertical edges + 2 column gaps x 5 diagonal edges.
+    assert_eq!(graph.num_edges(), 16);
+    assert_eq!(graph.edges().len(), 16);
+    let degree_sum: usize = (0..graph.num_vertices())
+        .map(|v| graph.neighbors(v).len())
+        .sum();
+    assert_eq!(degree_sum, 32);
+    for (u, v) in graph.edges() {
+        assert!(graph.has_edge(u, v) && graph.has_edge(v, u));
+    }
+}
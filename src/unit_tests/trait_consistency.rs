@@ -90,6 +90,10 @@ fn test_all_problems_implement_trait_correctly() {
         ),
         "BalancedCompleteBipartiteSubgraph",
     );
+    check_problem_trait(
+        &MaximumBalancedBiclique::new(BipartiteGraph::new(2, 2, vec![(0, 0), (1, 1)])),
+        "MaximumBalancedBiclique",
+    );
     check_problem_trait(&Factoring::new(6, 2, 2), "Factoring");
     check_problem_trait(&Partition::new(vec![3, 1, 1, 2, 2, 1]), "Partition");
     check_problem_trait(
@@ -206,6 +210,10 @@ fn test_all_problems_implement_trait_correctly() {
         &JobShopScheduling::new(2, vec![vec![(0, 1), (1, 1)], vec![(1, 1), (0, 1)]], 2),
         "JobShopScheduling",
     );
+    check_problem_trait(
+        &KTrackIntervalScheduling::new(vec![0, 1], vec![2, 3], vec![1, 1], 2),
+        "KTrackIntervalScheduling",
+    );
     check_problem_trait(
         &SequencingToMinimizeWeightedTardiness::new(vec![3, 4, 2], vec![2, 3, 1], vec![5, 8, 4], 4),
         "SequencingToMinimizeWeightedTardiness",
@@ -1,5 +1,6 @@
 use super::*;
 use crate::types::Aggregate;
+use std::cmp::Ordering;
 
 #[test]
 fn test_max_identity_and_combine() {
@@ -277,6 +278,85 @@ fn test_extremum_display() {
     assert_eq!(format!("{}", Extremum::<i32>::minimize(None)), "Min(None)");
 }
 
+#[test]
+fn test_extremum_sense_minimize_prefers_smaller() {
+    let sense = ExtremumSense::Minimize;
+    assert!(sense.is_better(&2, &5));
+    assert!(!sense.is_better(&5, &2));
+    assert_eq!(sense.compare(&2, &5), Ordering::Greater);
+    assert_eq!(sense.compare(&5, &2), Ordering::Less);
+
+    // Ties are neither better nor worse.
+    assert!(!sense.is_better(&3, &3));
+    assert!(sense.is_at_least_as_good(&3, &3));
+    assert_eq!(sense.compare(&3, &3), Ordering::Equal);
+}
+
+#[test]
+fn test_extremum_sense_maximize_prefers_larger() {
+    let sense = ExtremumSense::Maximize;
+    assert!(sense.is_better(&5.0, &2.0));
+    assert!(!sense.is_better(&2.0, &5.0));
+    assert!(!sense.is_at_least_as_good(&2.0, &5.0));
+    assert!(!sense.is_better(&3.0, &3.0));
+    assert!(sense.is_at_least_as_good(&3.0, &3.0));
+}
+
+#[test]
+fn test_extremum_sense_best_of() {
+    assert_eq!(ExtremumSense::Minimize.best_of([4, 1, 7, 1]), Some(1));
+    assert_eq!(ExtremumSense::Maximize.best_of([4, 1, 7, 1]), Some(7));
+    assert_eq!(ExtremumSense::Maximize.best_of(Vec::<i32>::new()), None);
+
+    // Ties keep the first occurrence.
+    let values = [(3, 'a'), (1, 'b'), (3, 'c')];
+    let best = ExtremumSense::Maximize
+        .best_of(values.iter().map(|&(v, tag)| Keyed(v, tag)))
+        .unwrap();
+    assert_eq!(best.1, 'a');
+}
+
+/// Compares only the key, so ties between distinct payloads are observable.
+#[derive(Debug)]
+struct Keyed(i32, char);
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot compare values")]
+fn test_extremum_sense_compare_nan_panics() {
+    ExtremumSense::Minimize.compare(&f64::NAN, &1.0);
+}
+
+#[test]
+fn test_extremum_is_better_than() {
+    assert!(Extremum::minimize(Some(1)).is_better_than(&Extremum::minimize(Some(2))));
+    assert!(!Extremum::minimize(Some(2)).is_better_than(&Extremum::minimize(Some(1))));
+    assert!(Extremum::maximize(Some(2)).is_better_than(&Extremum::maximize(Some(1))));
+    assert!(!Extremum::maximize(Some(2)).is_better_than(&Extremum::maximize(Some(2))));
+
+    // Feasible beats infeasible; infeasible values tie.
+    assert!(Extremum::minimize(Some(9)).is_better_than(&Extremum::minimize(None)));
+    assert!(!Extremum::<i32>::minimize(None).is_better_than(&Extremum::minimize(Some(9))));
+    assert!(!Extremum::<i32>::minimize(None).is_better_than(&Extremum::minimize(None)));
+}
+
+#[test]
+#[should_panic(expected = "different senses")]
+fn test_extremum_is_better_than_mixed_senses_panics() {
+    Extremum::minimize(Some(1)).is_better_than(&Extremum::maximize(Some(2)));
+}
+
 #[test]
 #[should_panic(expected = "called unwrap on invalid Extremum value")]
 fn test_extremum_unwrap_panics() {
@@ -0,0 +1,73 @@
+use crate::models::graph::MaximumIndependentSet;
+use crate::solvers::{BruteForce, Solver};
+use crate::topology::SimpleGraph;
+use crate::traits::Problem;
+use crate::types::{Aggregate, Max, Sum, WeightElement};
+use num_bigint::BigInt;
+
+fn big(value: &str) -> BigInt {
+    value.parse().unwrap()
+}
+
+#[test]
+fn test_bigint_weight_element() {
+    assert!(!BigInt::IS_UNIT);
+    assert_eq!(
+        big("12345678901234567890").to_sum(),
+        big("12345678901234567890")
+    );
+}
+
+#[test]
+fn test_bigint_weighted_mis_beyond_i32() {
+    // Path 0 - 1 - 2 - 3: the endpoints and the heavy vertex 2 are far above i32::MAX
+    let graph = SimpleGraph::new(4, vec![(0, 1), (1, 2), (2, 3)]);
+    let weights = vec![
+        big("3000000000"),
+        big("4000000000"),
+        big("5000000000"),
+        big("1000000000"),
+    ];
+    let problem = MaximumIndependentSet::new(graph, weights);
+
+    let solver = BruteForce::new();
+    assert_eq!(solver.solve(&problem), Max(Some(big("8000000000"))));
+    assert_eq!(solver.find_witness(&problem), Some(vec![1, 0, 1, 0]));
+}
+
+#[test]
+fn test_bigint_sums_stay_exact() {
+    // 10^20 + 1 has no exact f64 representation, and exceeds i64
+    let graph = SimpleGraph::new(3, vec![]);
+    let weights = vec![big("100000000000000000000"), big("1"), big("-2")];
+    let problem = MaximumIndependentSet::new(graph, weights);
+
+    assert_eq!(
+        problem.evaluate(&[1, 1, 0]),
+        Max(Some(big("100000000000000000001")))
+    );
+    assert_eq!(
+        BruteForce::new().solve(&problem),
+        Max(Some(big("100000000000000000001")))
+    );
+    assert_eq!(
+        Sum(big("100000000000000000000")).combine(Sum(big("1"))),
+        Sum(big("100000000000000000001"))
+    );
+}
+
+#[test]
+fn test_bigint_variant_and_serialization() {
+    assert_eq!(
+        MaximumIndependentSet::<SimpleGraph, BigInt>::variant(),
+        vec![("graph", "SimpleGraph"), ("weight", "BigInt")]
+    );
+
+    let problem = MaximumIndependentSet::new(
+        SimpleGraph::new(2, vec![(0, 1)]),
+        vec![big("-98765432109876543210"), big("98765432109876543210")],
+    );
+    let json = serde_json::to_string(&problem).unwrap();
+    let restored: MaximumIndependentSet<SimpleGraph, BigInt> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.weights(), problem.weights());
+}
@@ -216,11 +216,12 @@ fn test_full_pipeline_bull_unweighted() {
     );
 }
 
-#[test]
-fn test_full_pipeline_house_unweighted() {
-    use super::common::{is_independent_set, solve_mis_config};
+/// Map a standard graph, solve MIS on the grid, and check that
+/// `map_config_back` recovers a maximum independent set of the source graph.
+fn assert_full_pipeline_unweighted_is_maximum(name: &str) {
+    use super::common::{is_independent_set, solve_mis, solve_mis_config};
 
-    let (n, edges) = smallgraph("house").unwrap();
+    let (n, edges) = smallgraph(name).unwrap();
     let result = ksg::map_unweighted(n, &edges);
 
     let grid_edges = result.edges();
@@ -231,27 +232,29 @@ fn test_full_pipeline_house_unweighted() {
 
     assert!(
         is_independent_set(&edges, &original_config),
-        "House: mapped back config should be a valid independent set"
+        "{name}: mapped back config should be a valid independent set"
+    );
+    let extracted_size = original_config.iter().filter(|&&x| x > 0).count();
+    assert_eq!(
+        extracted_size,
+        solve_mis(n, &edges),
+        "{name}: mapped back config should be a maximum independent set"
     );
 }
 
 #[test]
-fn test_full_pipeline_petersen_unweighted() {
-    use super::common::{is_independent_set, solve_mis_config};
-
-    let (n, edges) = smallgraph("petersen").unwrap();
-    let result = ksg::map_unweighted(n, &edges);
-
-    let grid_edges = result.edges();
-    let num_grid = result.positions.len();
-    let grid_config = solve_mis_config(num_grid, &grid_edges);
+fn test_full_pipeline_house_unweighted() {
+    assert_full_pipeline_unweighted_is_maximum("house");
+}
 
-    let original_config = result.map_config_back(&grid_config);
+#[test]
+fn test_full_pipeline_petersen_unweighted() {
+    assert_full_pipeline_unweighted_is_maximum("petersen");
+}
 
-    assert!(
-        is_independent_set(&edges, &original_config),
-        "Petersen: mapped back config should be a valid independent set"
-    );
+#[test]
+fn test_full_pipeline_cubical_unweighted() {
+    assert_full_pipeline_unweighted_is_maximum("cubical");
 }
 
 #[test]
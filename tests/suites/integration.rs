@@ -237,7 +237,7 @@ mod all_problems_solvable {
 
     #[test]
     fn test_factoring_solvable() {
-        let problem = Factoring::new(15, 2, 2);
+        let problem = Factoring::new(2, 3, 15);
         let solver = BruteForce::new();
         let solutions = solver.find_all_witnesses(&problem);
         assert!(!solutions.is_empty());
@@ -448,7 +448,7 @@ mod edge_cases {
     #[test]
     fn test_trivial_factoring() {
         // Factor 4 = 2 * 2
-        let problem = Factoring::new(4, 2, 2);
+        let problem = Factoring::new(2, 2, 4);
         let solver = BruteForce::new();
         let solutions = solver.find_all_witnesses(&problem);
 
@@ -10,7 +10,7 @@ use problemreductions::rules::{Minimize, ReductionGraph};
 #[cfg(feature = "ilp-solver")]
 use problemreductions::solvers::ILPSolver;
 use problemreductions::topology::{Graph, SimpleGraph};
-use problemreductions::types::{Min, Or};
+use problemreductions::types::{Min, One, Or};
 use problemreductions::variant::{K2, K3};
 
 /// Tests for MaximumIndependentSet <-> MinimumVertexCover reductions.
@@ -358,7 +358,7 @@ mod max2sat_maxcut_reductions {
 
     #[test]
     fn test_maximum2satisfiability_to_maxcut_closed_loop() {
-        let source = Maximum2Satisfiability::new(
+        let source = Maximum2Satisfiability::<One>::new(
             3,
             vec![
                 CNFClause::new(vec![1, 2]),